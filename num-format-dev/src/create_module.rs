@@ -8,12 +8,18 @@ use crate::utils::Format;
 pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::Error> {
     let variant_names = data.keys().map(|s| Ident::new(s, Span::call_site()));
 
+    let mut approximately_signs = Vec::new();
+    let mut compact_suffixes = Vec::new();
     let mut decimals = Vec::new();
+    let mut decimal_patterns = Vec::new();
+    let mut exponential_symbols = Vec::new();
     let mut groupings = Vec::new();
     let mut infinities = Vec::new();
     let mut minus_signs = Vec::new();
     let mut nans = Vec::new();
+    let mut percent_signs = Vec::new();
     let mut plus_signs = Vec::new();
+    let mut range_patterns = Vec::new();
     let mut separators = Vec::new();
     let mut from_strs = Vec::new();
     let mut names = Vec::new();
@@ -21,6 +27,27 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
     for (variant_name, format) in data.iter() {
         let key = Ident::new(variant_name, Span::call_site());
 
+        // approximately_signs
+        let value = Literal::string(&format.apx);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #key => #value,
+            },
+        );
+        approximately_signs.push(group);
+
+        // compact_suffixes
+        let suffixes = format.cmp.iter().map(|(s, _)| Literal::string(s));
+        let shifts = format.cmp.iter().map(|(_, n)| Literal::i32_unsuffixed(*n));
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #key => &[#((#suffixes, #shifts)),*],
+            },
+        );
+        compact_suffixes.push(group);
+
         // decimals
         let value = Literal::string(&format.dec.to_string());
         let group = Group::new(
@@ -31,6 +58,26 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
         );
         decimals.push(group);
 
+        // decimal_patterns
+        let value = Literal::string(&format.pat);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #key => #value,
+            },
+        );
+        decimal_patterns.push(group);
+
+        // exponential_symbols
+        let value = Literal::string(&format.exp);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #key => #value,
+            },
+        );
+        exponential_symbols.push(group);
+
         // groupings
         let value = format.grp.to_ident();
         let group = Group::new(
@@ -71,6 +118,16 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
         );
         nans.push(group);
 
+        // percent_signs
+        let value = Literal::string(&format.pct);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #key => #value,
+            },
+        );
+        percent_signs.push(group);
+
         // plus_signs
         let value = Literal::string(&format.pos);
         let group = Group::new(
@@ -81,6 +138,16 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
         );
         plus_signs.push(group);
 
+        // range_patterns
+        let value = Literal::string(&format.rng);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #key => #value,
+            },
+        );
+        range_patterns.push(group);
+
         // separtors
         let value = Literal::string(&format.sep.to_string());
         let group = Group::new(
@@ -139,6 +206,7 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
         use crate::error::Error;
         use crate::format::Format;
         use crate::grouping::Grouping;
+        use crate::parsing::ParseFormat;
         use crate::strings::{
             DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr
         };
@@ -197,6 +265,24 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
                 &AVAILABLE_NAMES
             }
 
+            /// Returns the locale's approximately sign (e.g. `~` for English or `≈` for German).
+            pub fn approximately_sign(&self) -> &'static str {
+                use self::Locale::*;
+                match self {
+                    #(#approximately_signs)*
+                }
+            }
+
+            /// Returns the suffixes of the locale's compact notation (e.g. `K` in `1.2K` for
+            /// English, or `Mio.` in `1,2 Mio.` for German), short and long ones alike, along with
+            /// the powers of ten they stand for, longest suffix first.
+            pub fn compact_suffixes(&self) -> &'static [(&'static str, i32)] {
+                use self::Locale::*;
+                match self {
+                    #(#compact_suffixes)*
+                }
+            }
+
             /// Returns the locale's decimal representation.
             pub fn decimal(&self) -> &'static str {
                 use self::Locale::*;
@@ -205,6 +291,27 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
                 }
             }
 
+            /// Returns the locale's pattern for decimal numbers (e.g. `#,##,##0.###` for
+            /// `en_IN`), which can be parsed into a [`NumberPattern`] for a [`Patterned`].
+            ///
+            /// [`NumberPattern`]: struct.NumberPattern.html
+            /// [`Patterned`]: struct.Patterned.html
+            pub fn decimal_pattern(&self) -> &'static str {
+                use self::Locale::*;
+                match self {
+                    #(#decimal_patterns)*
+                }
+            }
+
+            /// Returns the locale's symbol for the exponent of scientific notation (e.g. `E` for
+            /// English, as in `1.5E3`).
+            pub fn exponential_symbol(&self) -> &'static str {
+                use self::Locale::*;
+                match self {
+                    #(#exponential_symbols)*
+                }
+            }
+
             /// Returns the locale's [`Grouping`].
             ///
             /// [`Grouping`]: enum.Grouping.html
@@ -248,6 +355,14 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
                 }
             }
 
+            /// Returns the locale's percent sign.
+            pub fn percent_sign(&self) -> &'static str {
+                use self::Locale::*;
+                match self {
+                    #(#percent_signs)*
+                }
+            }
+
             /// Returns the locale's plus sign representation.
             pub fn plus_sign(&self) -> &'static str {
                 use self::Locale::*;
@@ -256,6 +371,15 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
                 }
             }
 
+            /// Returns the locale's pattern for a range of numbers, in which `{0}` stands for the
+            /// start and `{1}` for the end (e.g. `{0}–{1}` for English).
+            pub fn range_pattern(&self) -> &'static str {
+                use self::Locale::*;
+                match self {
+                    #(#range_patterns)*
+                }
+            }
+
             /// Returns the locale's separator representation, if any.
             pub fn separator(&self) -> &'static str {
                 use self::Locale::*;
@@ -300,6 +424,14 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
             fn separator(&self) -> SeparatorStr<'_> {
                 SeparatorStr::new(self.separator()).unwrap()
             }
+
+        }
+
+        impl ParseFormat for Locale {
+            #[inline(always)]
+            fn locale(&self) -> Option<Locale> {
+                Some(*self)
+            }
         }
 
         impl FromStr for Locale {
@@ -0,0 +1,210 @@
+use indexmap::IndexMap;
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::utils::{Format, PluralRanges, PluralRules, CATEGORIES};
+
+/// Takes the maps returned from `parse_data`, `parse_plurals`, and `parse_plural_ranges` and
+/// turns them into a rust module that selects the cardinal, ordinal, and range plural categories
+/// of each locale.
+pub fn create_plural_module(
+    data: &IndexMap<String, Format>,
+    cardinals: &IndexMap<String, PluralRules>,
+    ordinals: &IndexMap<String, PluralRules>,
+    ranges: &IndexMap<String, PluralRanges>,
+) -> Result<String, anyhow::Error> {
+    let (cardinal_arms, cardinal_functions) = category_functions(data, cardinals, "cardinal");
+    let (ordinal_arms, ordinal_functions) = category_functions(data, ordinals, "ordinal");
+    let (range_arms, range_functions) = range_functions(data, ranges);
+
+    let token_stream = quote! {
+        //!Note: This module was autogenerated by num-format-dev.
+        #![allow(clippy::all)]
+
+        use crate::locale::Locale;
+        use crate::plural::{PluralCategory, PluralOperands};
+
+        impl Locale {
+            /// Returns the locale's cardinal plural category for the given operands.
+            pub(crate) fn cardinal_category(&self, op: &PluralOperands) -> PluralCategory {
+                use self::Locale::*;
+                match self {
+                    #(#cardinal_arms)*
+                }
+            }
+
+            /// Returns the locale's ordinal plural category for the given operands.
+            pub(crate) fn ordinal_category(&self, op: &PluralOperands) -> PluralCategory {
+                use self::Locale::*;
+                match self {
+                    #(#ordinal_arms)*
+                }
+            }
+
+            /// Returns the locale's plural category for a range from a value in the `start`
+            /// category to a value in the `end` category.
+            #[cfg(feature = "std")]
+            pub(crate) fn range_category(
+                &self,
+                start: PluralCategory,
+                end: PluralCategory,
+            ) -> PluralCategory {
+                use self::Locale::*;
+                match self {
+                    #(#range_arms)*
+                }
+            }
+        }
+
+        #(#cardinal_functions)*
+
+        #(#ordinal_functions)*
+
+        #(#range_functions)*
+    };
+
+    let s = format!("{}", &token_stream);
+    Ok(s)
+}
+
+/// Returns the match arms and functions (named after `kind`, e.g. `cardinal_0`) that select the
+/// plural category of each locale with the given rules.
+fn category_functions(
+    data: &IndexMap<String, Format>,
+    plurals: &IndexMap<String, PluralRules>,
+    kind: &str,
+) -> (Vec<Group>, Vec<TokenStream>) {
+    // Group the locales by the rules they use, so each set of rules is only generated once
+    let mut rule_sets: IndexMap<String, (Option<&PluralRules>, Vec<Ident>)> = IndexMap::new();
+    for (variant_name, format) in data.iter() {
+        let rules = find_rules(&format.identifier, plurals);
+        rule_sets
+            .entry(format!("{:?}", rules))
+            .or_insert_with(|| (rules, Vec::new()))
+            .1
+            .push(Ident::new(variant_name, Span::call_site()));
+    }
+
+    let mut arms = Vec::new();
+    let mut functions = Vec::new();
+    for (i, (rules, variant_names)) in rule_sets.values().enumerate() {
+        let function = Ident::new(&format!("{}_{}", kind, i), Span::call_site());
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #(#variant_names)|* => #function(op),
+            },
+        );
+        arms.push(group);
+
+        let mut checks = Vec::new();
+        if let Some(rules) = rules {
+            for category in CATEGORIES.iter() {
+                if let Some(condition) = rules.get(*category) {
+                    let variant = category_ident(category);
+                    let condition = condition.to_tokens();
+                    checks.push(quote! {
+                        if #condition {
+                            return PluralCategory::#variant;
+                        }
+                    });
+                }
+            }
+        }
+        let op = if checks.is_empty() {
+            quote! { _op }
+        } else {
+            quote! { op }
+        };
+        functions.push(quote! {
+            fn #function(#op: &PluralOperands) -> PluralCategory {
+                #(#checks)*
+                PluralCategory::Other
+            }
+        });
+    }
+    (arms, functions)
+}
+
+/// Returns the match arms and functions (e.g. `range_0`) that select the plural category of a
+/// range for each locale. Ranges missing from the data are in the "other" category.
+fn range_functions(
+    data: &IndexMap<String, Format>,
+    ranges: &IndexMap<String, PluralRanges>,
+) -> (Vec<Group>, Vec<TokenStream>) {
+    // Group the locales by the ranges they use that aren't in the "other" category, so each
+    // set of ranges is only generated once
+    let mut range_sets: IndexMap<Vec<(&str, &str, &str)>, Vec<Ident>> = IndexMap::new();
+    for (variant_name, format) in data.iter() {
+        let cases = find_rules(&format.identifier, ranges)
+            .iter()
+            .flat_map(|ranges| ranges.iter())
+            .filter(|(_, category)| *category != "other")
+            .map(|((start, end), category)| (start.as_str(), end.as_str(), category.as_str()))
+            .collect::<Vec<_>>();
+        range_sets
+            .entry(cases)
+            .or_default()
+            .push(Ident::new(variant_name, Span::call_site()));
+    }
+
+    let mut arms = Vec::new();
+    let mut functions = Vec::new();
+    for (i, (cases, variant_names)) in range_sets.iter().enumerate() {
+        let function = Ident::new(&format!("range_{}", i), Span::call_site());
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #(#variant_names)|* => #function(start, end),
+            },
+        );
+        arms.push(group);
+
+        let case_arms = cases.iter().map(|(start, end, category)| {
+            let start = category_ident(start);
+            let end = category_ident(end);
+            let category = category_ident(category);
+            quote! {
+                (PluralCategory::#start, PluralCategory::#end) => PluralCategory::#category,
+            }
+        });
+        functions.push(if cases.is_empty() {
+            quote! {
+                fn #function(_start: PluralCategory, _end: PluralCategory) -> PluralCategory {
+                    PluralCategory::Other
+                }
+            }
+        } else {
+            quote! {
+                fn #function(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+                    match (start, end) {
+                        #(#case_arms)*
+                        _ => PluralCategory::Other,
+                    }
+                }
+            }
+        });
+    }
+    (arms, functions)
+}
+
+/// Finds the rules for a locale identifier (e.g. "pt-PT"), falling back to its language
+/// (e.g. "pt").
+pub(crate) fn find_rules<'a, T>(identifier: &str, rules: &'a IndexMap<String, T>) -> Option<&'a T> {
+    let mut identifier = identifier;
+    loop {
+        if let Some(rules) = rules.get(identifier) {
+            return Some(rules);
+        }
+        match identifier.rfind('-') {
+            Some(i) => identifier = &identifier[..i],
+            None => return None,
+        }
+    }
+}
+
+fn category_ident(category: &str) -> Ident {
+    let mut s = category.to_string();
+    s[..1].make_ascii_uppercase();
+    Ident::new(&s, Span::call_site())
+}
@@ -0,0 +1,364 @@
+use anyhow::anyhow;
+use indexmap::IndexMap;
+use proc_macro2::{Delimiter, Group, Ident, Literal, Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::utils::{Part, RuleGroup, RuleSet, Target};
+
+/// The public rule set ordinals are written with.
+const ORDINAL_RULE_SET: &str = "%digits-ordinal";
+
+/// The public rule set numbers are spelled out with.
+const SPELLOUT_RULE_SET: &str = "%spellout-numbering";
+
+// Deeper recursion than this would need numbers with more digits than a u128 has
+const MAX_DEPTH: usize = 64;
+
+/// Takes the maps returned from `parse_rbnf` for the "OrdinalRules" and "SpelloutRules" groups
+/// and turns them into a rust module containing the rule sets each locale writes ordinals and
+/// spelled out numbers with.
+pub fn create_rbnf_module(
+    ordinals: &IndexMap<String, RuleGroup>,
+    spellouts: &IndexMap<String, RuleGroup>,
+) -> Result<String, anyhow::Error> {
+    // Deduplicate the groups, then number the rule sets reachable from the ones used
+    let mut groups: Vec<&RuleGroup> = Vec::new();
+    let mut sets: IndexMap<(usize, &str), &RuleSet> = IndexMap::new();
+    let mut ordinal_sets: IndexMap<usize, Vec<Ident>> = IndexMap::new();
+    let mut spellout_sets: IndexMap<usize, Vec<Ident>> = IndexMap::new();
+    for (rules, name, locale_sets) in [
+        (ordinals, ORDINAL_RULE_SET, &mut ordinal_sets),
+        (spellouts, SPELLOUT_RULE_SET, &mut spellout_sets),
+    ] {
+        for (variant_name, group) in rules.iter() {
+            let g = match groups.iter().position(|other| *other == group) {
+                Some(g) => g,
+                None => {
+                    groups.push(group);
+                    groups.len() - 1
+                }
+            };
+            let index = add_set(&mut sets, g, group, name)?;
+            locale_sets
+                .entry(index)
+                .or_default()
+                .push(Ident::new(variant_name, Span::call_site()));
+        }
+    }
+
+    let mut rule_sets = Vec::new();
+    for ((g, _), set) in sets.iter() {
+        rule_sets.push(rule_set_tokens(set, |name| {
+            sets.get_index_of(&(*g, name)).unwrap()
+        }));
+    }
+
+    // Any integer up to the magnitude of i128::MIN (i.e. any u128) can be written
+    let mut memo = IndexMap::new();
+    let mut max_ordinal_len = Len::default();
+    for index in ordinal_sets.keys() {
+        for negative in [false, true] {
+            let len = max_len(&sets, *index, negative, u128::MAX, MAX_DEPTH, &mut memo)?;
+            max_ordinal_len = max_ordinal_len.max(len);
+        }
+    }
+    let mut max_spellout_len = Len::default();
+    for index in spellout_sets.keys() {
+        for negative in [false, true] {
+            let len = max_len(&sets, *index, negative, u128::MAX, MAX_DEPTH, &mut memo)?;
+            max_spellout_len = max_spellout_len.max(len);
+        }
+    }
+
+    let mut ordinal_arms = Vec::new();
+    for (index, variant_names) in ordinal_sets.iter() {
+        let index = Literal::usize_unsuffixed(*index);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #(#variant_names)|* => &RULE_SETS[#index],
+            },
+        );
+        ordinal_arms.push(group);
+    }
+    let mut spellout_arms = Vec::new();
+    for (index, variant_names) in spellout_sets.iter() {
+        let index = Literal::usize_unsuffixed(*index);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #(#variant_names)|* => Some(&RULE_SETS[#index]),
+            },
+        );
+        spellout_arms.push(group);
+    }
+
+    let max_ordinal_len = max_ordinal_len.tokens();
+    let max_spellout_len = max_spellout_len.tokens();
+    let rule_sets_len = Literal::usize_unsuffixed(rule_sets.len());
+    let token_stream = quote! {
+        //!Note: This module was autogenerated by num-format-dev.
+
+        use crate::locale::Locale;
+        use crate::rbnf::{Part, Rule, RuleSet, Target};
+        use crate::strings::{MAX_MIN_LEN, MAX_SEP_LEN};
+
+        /// The length of the longest ordinal of any integer type.
+        pub(crate) const MAX_ORDINAL_LEN: usize = #max_ordinal_len;
+
+        /// The length of the longest spelled out number of any integer type.
+        pub(crate) const MAX_SPELLOUT_LEN: usize = #max_spellout_len;
+
+        /// The rule sets of every locale, which refer to each other by index.
+        pub(crate) static RULE_SETS: [RuleSet; #rule_sets_len] = [#(#rule_sets),*];
+
+        impl Locale {
+            /// Returns the rule set the locale writes ordinals (e.g. "1st") with.
+            pub(crate) fn ordinal_rules(&self) -> &'static RuleSet {
+                use self::Locale::*;
+                match self {
+                    #(#ordinal_arms)*
+                }
+            }
+
+            /// Returns the rule set the locale spells out numbers (e.g. "twenty-one") with, if
+            /// its language has one.
+            pub(crate) fn spellout_rules(&self) -> Option<&'static RuleSet> {
+                use self::Locale::*;
+                match self {
+                    #(#spellout_arms)*
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    let s = format!("{}", &token_stream);
+    Ok(s)
+}
+
+/// Numbers the named rule set of the group, and the rule sets it refers to, returning its index.
+fn add_set<'a>(
+    sets: &mut IndexMap<(usize, &'a str), &'a RuleSet>,
+    g: usize,
+    group: &'a RuleGroup,
+    name: &str,
+) -> Result<usize, anyhow::Error> {
+    let (name, set) = group
+        .get_key_value(name)
+        .ok_or_else(|| anyhow!("missing rule set {:?}", name))?;
+    if let Some(index) = sets.get_index_of(&(g, name.as_str())) {
+        return Ok(index);
+    }
+    let (index, _) = sets.insert_full((g, name.as_str()), set);
+    for reference in set.references() {
+        let _ = add_set(sets, g, group, reference)?;
+    }
+    Ok(index)
+}
+
+fn rule_set_tokens<L>(set: &RuleSet, lookup: L) -> TokenStream
+where
+    L: Fn(&str) -> usize,
+{
+    let negative = match &set.negative {
+        Some(parts) => {
+            let parts = parts_tokens(parts, &lookup);
+            quote! { Some(&[#(#parts),*]) }
+        }
+        None => quote! { None },
+    };
+    let rules = set.rules.iter().map(|rule| {
+        let base = Literal::u64_unsuffixed(rule.base);
+        let divisor = Literal::u64_unsuffixed(rule.divisor);
+        let parts = parts_tokens(&rule.parts, &lookup);
+        quote! {
+            Rule { base: #base, divisor: #divisor, parts: &[#(#parts),*] }
+        }
+    });
+    quote! {
+        RuleSet { negative: #negative, rules: &[#(#rules),*] }
+    }
+}
+
+fn parts_tokens<L>(parts: &[Part], lookup: &L) -> Vec<TokenStream>
+where
+    L: Fn(&str) -> usize,
+{
+    let target_tokens = |target: &Target| match target {
+        Target::Owner => quote! { Target::Owner },
+        Target::Set(name) => {
+            let index = Literal::usize_unsuffixed(lookup(name));
+            quote! { Target::Set(#index) }
+        }
+        Target::Digits(grouped) => quote! { Target::Digits(#grouped) },
+    };
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::Text(text) => {
+                let text = Literal::string(text);
+                quote! { Part::Text(#text) }
+            }
+            Part::Minus => quote! { Part::Minus },
+            Part::Quotient(target) => {
+                let target = target_tokens(target);
+                quote! { Part::Quotient(#target) }
+            }
+            Part::Remainder(target) => {
+                let target = target_tokens(target);
+                quote! { Part::Remainder(#target) }
+            }
+            Part::Same(target) => {
+                let target = target_tokens(target);
+                quote! { Part::Same(#target) }
+            }
+            Part::Plural(ordinal, forms) => {
+                let forms = forms
+                    .iter()
+                    .map(|form| Literal::string(form.as_deref().unwrap_or("")));
+                if *ordinal {
+                    quote! { Part::Ordinal(&[#(#forms),*]) }
+                } else {
+                    quote! { Part::Cardinal(&[#(#forms),*]) }
+                }
+            }
+        })
+        .collect()
+}
+
+/// The most that rule sets can write: bytes of text and digits, separators, and minus signs.
+#[derive(Copy, Clone, Debug, Default)]
+struct Len {
+    bytes: usize,
+    separators: usize,
+    minus_signs: usize,
+}
+
+impl Len {
+    fn add(self, other: Len) -> Len {
+        Len {
+            bytes: self.bytes + other.bytes,
+            separators: self.separators + other.separators,
+            minus_signs: self.minus_signs + other.minus_signs,
+        }
+    }
+
+    fn max(self, other: Len) -> Len {
+        Len {
+            bytes: self.bytes.max(other.bytes),
+            separators: self.separators.max(other.separators),
+            minus_signs: self.minus_signs.max(other.minus_signs),
+        }
+    }
+
+    /// Returns the length as an expression in the lengths of separators and minus signs.
+    fn tokens(&self) -> TokenStream {
+        let mut terms = vec![Literal::usize_unsuffixed(self.bytes).to_token_stream()];
+        for (count, len) in [
+            (self.separators, quote! { MAX_SEP_LEN }),
+            (self.minus_signs, quote! { MAX_MIN_LEN }),
+        ] {
+            match count {
+                0 => {}
+                1 => terms.push(len),
+                _ => {
+                    let count = Literal::usize_unsuffixed(count);
+                    terms.push(quote! { #count * #len });
+                }
+            }
+        }
+        quote! { #(#terms)+* }
+    }
+}
+
+/// Returns the most that the numbered rule set can write for integers up to `max` (negated if
+/// `negative` is `true`), following at most `depth` substitutions.
+fn max_len(
+    sets: &IndexMap<(usize, &str), &RuleSet>,
+    index: usize,
+    negative: bool,
+    max: u128,
+    depth: usize,
+    memo: &mut IndexMap<(usize, bool, u128), Len>,
+) -> Result<Len, anyhow::Error> {
+    if depth == 0 {
+        return Err(anyhow!("rule set {} recurses without end", index));
+    }
+    if let Some(len) = memo.get(&(index, negative, max)) {
+        return Ok(*len);
+    }
+    let ((g, _), set) = sets.get_index(index).unwrap();
+
+    // A rule applies from its base value up to the next rule's, or up to the one after that if
+    // the next rule rolls back to it
+    let ranges: Vec<(&[Part], u64, u128)> = match (&set.negative, negative) {
+        (Some(parts), true) => vec![(parts, 1, max)],
+        _ => set
+            .rules
+            .iter()
+            .enumerate()
+            .take_while(|(_, rule)| u128::from(rule.base) <= max)
+            .map(|(i, rule)| {
+                let end = match set.rules.get(i + 2) {
+                    Some(after_next) => max.min(u128::from(after_next.base) - 1),
+                    None => max,
+                };
+                (rule.parts.as_slice(), rule.divisor, end)
+            })
+            .collect(),
+    };
+
+    let mut longest = Len::default();
+    for (parts, divisor, end) in ranges {
+        let mut len = Len::default();
+        for part in parts {
+            let (target, target_negative, target_max) = match part {
+                Part::Text(text) => {
+                    len.bytes += text.len();
+                    continue;
+                }
+                Part::Minus => {
+                    len.minus_signs += 1;
+                    continue;
+                }
+                Part::Plural(_, forms) => {
+                    len.bytes += forms.iter().flatten().map(|form| form.len()).max().unwrap();
+                    continue;
+                }
+                // Only a set without a negative rule passes negative numbers on
+                Part::Same(target) => (target, negative && set.negative.is_none(), end),
+                Part::Quotient(target) => (target, false, end / u128::from(divisor)),
+                Part::Remainder(target) => (target, false, end.min(u128::from(divisor) - 1)),
+            };
+            let target_len = match target {
+                Target::Owner => {
+                    max_len(sets, index, target_negative, target_max, depth - 1, memo)?
+                }
+                Target::Set(name) => {
+                    let target = sets.get_index_of(&(*g, name.as_str())).unwrap();
+                    max_len(sets, target, target_negative, target_max, depth - 1, memo)?
+                }
+                Target::Digits(grouped) => {
+                    let digits = target_max.to_string().len();
+                    // Indian grouping has the most separators: one after the last three digits,
+                    // then one after every two
+                    let separators = if *grouped && digits > 3 {
+                        (digits - 2) / 2
+                    } else {
+                        0
+                    };
+                    Len {
+                        bytes: digits,
+                        separators,
+                        minus_signs: target_negative as usize,
+                    }
+                }
+            };
+            len = len.add(target_len);
+        }
+        longest = longest.max(len);
+    }
+    let _ = memo.insert((index, negative, max), longest);
+    Ok(longest)
+}
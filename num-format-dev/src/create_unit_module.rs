@@ -0,0 +1,101 @@
+use indexmap::{IndexMap, IndexSet};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Span};
+use quote::quote;
+
+use crate::parse_units::{UnitPatterns, UNITS, WIDTHS};
+
+/// Takes the map returned from `parse_units` and turns it into a rust module containing the
+/// unit patterns of each locale.
+pub fn create_unit_module(
+    units: &IndexMap<String, Vec<UnitPatterns>>,
+) -> Result<String, anyhow::Error> {
+    // Deduplicate the patterns, then the per-locale tables of pattern indices
+    let mut patterns: IndexSet<&UnitPatterns> = IndexSet::new();
+    let mut tables: IndexMap<Vec<u16>, Vec<Ident>> = IndexMap::new();
+    for (variant_name, unit_patterns) in units.iter() {
+        let mut table = Vec::new();
+        for p in unit_patterns.iter() {
+            let (index, _) = patterns.insert_full(p);
+            table.push(index as u16);
+        }
+        tables
+            .entry(table)
+            .or_default()
+            .push(Ident::new(variant_name, Span::call_site()));
+    }
+
+    let patterns_len = Literal::usize_unsuffixed(patterns.len());
+    let patterns = patterns.iter().map(|p| {
+        let strings = p
+            .iter()
+            .map(|s| Literal::string(s.as_ref().map(|s| s.as_str()).unwrap_or("")));
+        quote! { [#(#strings),*] }
+    });
+
+    let tables_len = Literal::usize_unsuffixed(tables.len());
+    let table_len = Literal::usize_unsuffixed(WIDTHS.len() * UNITS.len());
+    let mut arms = Vec::new();
+    let mut rows = Vec::new();
+    for (i, (table, variant_names)) in tables.iter().enumerate() {
+        let i = Literal::usize_unsuffixed(i);
+        let group = Group::new(
+            Delimiter::None,
+            quote! {
+                #(#variant_names)|* => #i,
+            },
+        );
+        arms.push(group);
+        let table = table.iter().map(|index| Literal::u16_unsuffixed(*index));
+        rows.push(quote! { [#(#table),*] });
+    }
+
+    let units_len = Literal::usize_unsuffixed(UNITS.len());
+    let unit_arms = UNITS.iter().enumerate().map(|(i, (_, name))| {
+        let name = Ident::new(name, Span::call_site());
+        let i = Literal::usize_unsuffixed(i);
+        quote! { MeasureUnit::#name => #i, }
+    });
+    let width_arms = WIDTHS.iter().enumerate().map(|(i, (_, name))| {
+        let name = Ident::new(name, Span::call_site());
+        let i = Literal::usize_unsuffixed(i);
+        quote! { UnitWidth::#name => #i, }
+    });
+
+    let token_stream = quote! {
+        //!Note: This module was autogenerated by num-format-dev.
+
+        use crate::locale::Locale;
+        use crate::measure::{MeasureUnit, UnitWidth};
+
+        /// Unit patterns, indexed by plural category (an empty pattern means "use other").
+        static PATTERNS: [[&str; 6]; #patterns_len] = [#(#patterns),*];
+
+        /// Indices into `PATTERNS`, ordered by width, then unit.
+        static TABLES: [[u16; #table_len]; #tables_len] = [#(#rows),*];
+
+        impl Locale {
+            /// Returns the locale's patterns for the given unit and width, indexed by plural
+            /// category.
+            pub(crate) fn unit_patterns(
+                &self,
+                unit: MeasureUnit,
+                width: UnitWidth,
+            ) -> &'static [&'static str; 6] {
+                use self::Locale::*;
+                let table = match self {
+                    #(#arms)*
+                };
+                let unit = match unit {
+                    #(#unit_arms)*
+                };
+                let width = match width {
+                    #(#width_arms)*
+                };
+                &PATTERNS[TABLES[table][width * #units_len + unit] as usize]
+            }
+        }
+    };
+
+    let s = format!("{}", &token_stream);
+    Ok(s)
+}
@@ -16,8 +16,20 @@
 #![recursion_limit = "256"]
 
 mod create_module;
+mod create_plural_module;
+mod create_rbnf_module;
+mod create_unit_module;
 mod parse_data;
+mod parse_plurals;
+mod parse_rbnf;
+mod parse_units;
 mod utils;
 
 pub use self::create_module::create_module;
+pub use self::create_plural_module::create_plural_module;
+pub use self::create_rbnf_module::create_rbnf_module;
+pub use self::create_unit_module::create_unit_module;
 pub use self::parse_data::parse_data;
+pub use self::parse_plurals::{parse_plural_ranges, parse_plurals};
+pub use self::parse_rbnf::{parse_rbnf, SPELLOUT_LANGUAGES};
+pub use self::parse_units::parse_units;
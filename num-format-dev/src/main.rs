@@ -1,15 +1,45 @@
 use std::fs::File;
 use std::io::Write;
 
-use num_format_dev::{create_module, parse_data};
+use num_format_dev::{
+    create_module, create_plural_module, create_rbnf_module, create_unit_module, parse_data,
+    parse_plural_ranges, parse_plurals, parse_rbnf, parse_units, SPELLOUT_LANGUAGES,
+};
 
 const DATA_DIR: &str = "./num-format-dev/cldr-numbers-full";
+const PLURALS_PATH: &str = "./num-format-dev/cldr-core/supplemental/plurals.json";
+const ORDINALS_PATH: &str = "./num-format-dev/cldr-core/supplemental/ordinals.json";
+const PLURAL_RANGES_PATH: &str = "./num-format-dev/cldr-core/supplemental/pluralRanges.json";
+const RBNF_DIR: &str = "./num-format-dev/cldr-rbnf";
+const UNITS_DIR: &str = "./num-format-dev/cldr-units-full";
 const OUT_PATH: &str = "./num-format/src/locale.rs";
+const PLURAL_OUT_PATH: &str = "./num-format/src/plural_rules.rs";
+const UNIT_OUT_PATH: &str = "./num-format/src/unit_patterns.rs";
+const RBNF_OUT_PATH: &str = "./num-format/src/rbnf_rules.rs";
 
 fn main() -> Result<(), anyhow::Error> {
     let data = parse_data(DATA_DIR)?;
     let s = create_module(&data)?;
     let mut f = File::create(OUT_PATH)?;
     f.write_all(s.as_bytes())?;
+
+    let cardinals = parse_plurals(PLURALS_PATH, "cardinal")?;
+    let ordinals = parse_plurals(ORDINALS_PATH, "ordinal")?;
+    let ranges = parse_plural_ranges(PLURAL_RANGES_PATH)?;
+    let s = create_plural_module(&data, &cardinals, &ordinals, &ranges)?;
+    let mut f = File::create(PLURAL_OUT_PATH)?;
+    f.write_all(s.as_bytes())?;
+
+    let units = parse_units(UNITS_DIR, &data)?;
+    let s = create_unit_module(&units)?;
+    let mut f = File::create(UNIT_OUT_PATH)?;
+    f.write_all(s.as_bytes())?;
+
+    let ordinal_rules = parse_rbnf(RBNF_DIR, &data, "OrdinalRules", None)?;
+    let spellout_rules = parse_rbnf(RBNF_DIR, &data, "SpelloutRules", Some(&SPELLOUT_LANGUAGES))?;
+    let s = create_rbnf_module(&ordinal_rules, &spellout_rules)?;
+    let mut f = File::create(RBNF_OUT_PATH)?;
+    f.write_all(s.as_bytes())?;
+
     Ok(())
 }
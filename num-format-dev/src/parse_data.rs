@@ -8,8 +8,12 @@ use walkdir::WalkDir;
 
 use crate::utils::{Format, Grouping};
 
+const MAX_APX_LEN: usize = 16;
+const MAX_CMP_LEN: usize = 64;
 const MAX_MIN_LEN: usize = 8;
+const MAX_PAT_LEN: usize = 32;
 const MAX_POS_LEN: usize = 8;
+const MAX_RNG_LEN: usize = 16;
 
 /// Walks a directory containing CLDR json files and collects the data they contain into a map.
 pub fn parse_data<P>(data_dir: P) -> Result<IndexMap<String, Format>, anyhow::Error>
@@ -52,21 +56,35 @@ where
         let symbols = &value["numbers"][&symbols_lookup].to_string();
         let symbols: Symbols = serde_json::from_str(symbols).unwrap();
 
-        // Grouping
+        // Decimal pattern and grouping
         let decimal_formats_lookup =
             format!("decimalFormats-numberSystem-{}", default_numbering_system);
         let decimal_formats = &value["numbers"][&decimal_formats_lookup];
-        let grp = decimal_formats["standard"]
+        let decimal_pattern = decimal_formats["standard"].as_str().unwrap();
+        let grp = decimal_pattern.parse::<Grouping>().unwrap();
+
+        // Compact suffixes
+        let cmp = compact_suffixes(decimal_formats);
+
+        // Range pattern
+        let misc_patterns_lookup =
+            format!("miscPatterns-numberSystem-{}", default_numbering_system);
+        let range_pattern = value["numbers"][&misc_patterns_lookup]["range"]
             .as_str()
-            .unwrap()
-            .parse::<Grouping>()
             .unwrap();
 
         // Format
         let format = Format {
             identifier: identifier.to_string(),
 
+            apx: {
+                let s = symbols.approximately_sign;
+                assert!(s.len() <= MAX_APX_LEN);
+                s
+            },
+            cmp,
             dec: symbols.decimal,
+            exp: symbols.exponential,
             grp,
             inf: symbols.infinity,
             min: {
@@ -75,11 +93,23 @@ where
                 s
             },
             nan: symbols.nan,
+            pat: {
+                let s = decimal_pattern.to_string();
+                assert!(s.len() <= MAX_PAT_LEN);
+                s
+            },
+            pct: symbols.percent_sign,
             pos: {
                 let s = symbols.plus_sign.to_string();
                 assert!(s.len() <= MAX_POS_LEN);
                 s
             },
+            rng: {
+                let s = range_pattern.to_string();
+                assert!(s.len() <= MAX_RNG_LEN);
+                assert!(s.contains("{0}") && s.contains("{1}"));
+                s
+            },
             sep: symbols.group,
         };
 
@@ -89,6 +119,57 @@ where
     Ok(data)
 }
 
+/// Collects the suffixes of the short and long compact decimal formats (e.g. `0 Mio'.'` and
+/// `00 Millionen` for 10^6 and 10^7 in German) along with the powers of ten they stand for,
+/// longest suffix first. Patterns with text before their digits or no suffix (i.e. `0`, which
+/// means there's no compact form) are skipped, and where a suffix stands for different powers
+/// (as `करोड` does in Nepali's data), the short pattern for the smallest power wins.
+fn compact_suffixes(decimal_formats: &serde_json::Value) -> Vec<(String, i32)> {
+    let mut suffixes: Vec<(String, i32)> = Vec::new();
+
+    for width in &["short", "long"] {
+        let patterns = match decimal_formats[width]["decimalFormat"].as_object() {
+            Some(patterns) => patterns,
+            None => continue,
+        };
+        for (key, pattern) in patterns {
+            // Keys are e.g. "1000000-count-other"
+            let power = key.split('-').next().unwrap().len() as i32 - 1;
+
+            // Only the positive subpattern
+            let pattern = pattern.as_str().unwrap().split(';').next().unwrap();
+            let zeros = pattern.len() - pattern.trim_start_matches('0').len();
+            if zeros == 0 {
+                continue;
+            }
+            let suffix = unquote(&pattern[zeros..]).trim().to_string();
+            assert!(suffix.len() <= MAX_CMP_LEN);
+            if suffix.is_empty() || suffixes.iter().any(|(s, _)| s == &suffix) {
+                continue;
+            }
+            suffixes.push((suffix, power - (zeros as i32 - 1)));
+        }
+    }
+
+    suffixes.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then(a.cmp(b)));
+    suffixes
+}
+
+/// Removes the quotes of literal text in a pattern (e.g. `Mio'.'`), where `''` stands for a
+/// quote itself.
+fn unquote(s: &str) -> String {
+    let mut buf = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            buf.push(c);
+        } else if chars.peek() == Some(&'\'') {
+            buf.push(chars.next().unwrap());
+        }
+    }
+    buf
+}
+
 fn make_variant_name(identifier: &str) -> String {
     let mut buf = String::new();
 
@@ -115,6 +196,7 @@ fn make_variant_name(identifier: &str) -> String {
 #[derive(Debug, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Symbols {
+    approximately_sign: String,
     decimal: char,
     exponential: String,
     group: char,
@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::Path;
+
+use indexmap::IndexMap;
+
+use crate::utils::{Condition, PluralRanges, PluralRules, CATEGORIES};
+
+/// Reads a CLDR supplemental plurals json file (e.g. "plurals.json" or "ordinals.json") and
+/// collects the rules of the given type (e.g. "cardinal" or "ordinal") for each language.
+pub fn parse_plurals<P>(path: P, kind: &str) -> Result<IndexMap<String, PluralRules>, anyhow::Error>
+where
+    P: AsRef<Path>,
+{
+    let contents = fs::read_to_string(path.as_ref())?;
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+    let lookup = format!("plurals-type-{}", kind);
+    let languages = value["supplemental"][&lookup]
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("missing {:?}", lookup))?;
+
+    let mut data = IndexMap::new();
+    for (language, rules) in languages {
+        let mut conditions = PluralRules::new();
+        for category in CATEGORIES.iter() {
+            let key = format!("pluralRule-count-{}", category);
+            if let Some(rule) = rules[&key].as_str() {
+                let condition = rule.parse::<Condition>()?;
+                if !condition.is_empty() {
+                    let _ = conditions.insert(category.to_string(), condition);
+                }
+            }
+        }
+        let _ = data.insert(language.to_string(), conditions);
+    }
+    Ok(data)
+}
+
+/// Reads the CLDR supplemental "pluralRanges.json" file and collects the category of each
+/// range of categories (e.g. "one+other") for each language.
+pub fn parse_plural_ranges<P>(path: P) -> Result<IndexMap<String, PluralRanges>, anyhow::Error>
+where
+    P: AsRef<Path>,
+{
+    let contents = fs::read_to_string(path.as_ref())?;
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+    let languages = value["supplemental"]["plurals"]
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("missing \"plurals\""))?;
+
+    let mut data = IndexMap::new();
+    for (language, ranges) in languages {
+        let ranges = ranges
+            .as_object()
+            .ok_or_else(|| anyhow::anyhow!("invalid plural ranges for {:?}", language))?;
+        let mut categories = PluralRanges::new();
+        for (key, category) in ranges {
+            let (start, end) = key
+                .split_once('+')
+                .ok_or_else(|| anyhow::anyhow!("invalid plural range {:?}", key))?;
+            let category = category
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("invalid plural range {:?}", key))?;
+            for c in [start, end, category].iter() {
+                if !CATEGORIES.contains(c) {
+                    anyhow::bail!("invalid plural category {:?}", c);
+                }
+            }
+            let _ = categories.insert((start.to_string(), end.to_string()), category.to_string());
+        }
+        let _ = data.insert(language.to_string(), categories);
+    }
+    Ok(data)
+}
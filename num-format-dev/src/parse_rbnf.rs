@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use indexmap::IndexMap;
+
+use crate::utils::{Format, RuleGroup, RuleSet};
+
+/// The languages whose spellout rules are generated.
+pub const SPELLOUT_LANGUAGES: [&str; 7] = ["de", "en", "es", "fr", "it", "nl", "pt"];
+
+/// Reads the CLDR rbnf json file of each locale in the map returned from `parse_data` and
+/// collects the rule sets of the given group (e.g. "OrdinalRules"). If `languages` is given,
+/// only the locales of those languages are read.
+pub fn parse_rbnf<P>(
+    data_dir: P,
+    data: &IndexMap<String, Format>,
+    group: &str,
+    languages: Option<&[&str]>,
+) -> Result<IndexMap<String, RuleGroup>, anyhow::Error>
+where
+    P: AsRef<Path>,
+{
+    let mut rbnf = IndexMap::new();
+    for (variant_name, format) in data.iter() {
+        let language = format.identifier.split('-').next().unwrap();
+        if languages.is_some_and(|languages| !languages.contains(&language)) {
+            continue;
+        }
+
+        // Fall back to the language, then to root, if there is no data for the locale itself
+        let mut identifier = format.identifier.as_str();
+        let path = loop {
+            let path = data_dir
+                .as_ref()
+                .join("rbnf")
+                .join(format!("{}.json", identifier));
+            if path.is_file() {
+                break path;
+            }
+            match identifier.rfind('-') {
+                Some(i) => identifier = &identifier[..i],
+                None if identifier != "root" => identifier = "root",
+                None => return Err(anyhow!("no rbnf data for {:?}", format.identifier)),
+            }
+        };
+
+        let contents = fs::read_to_string(&path)?;
+        let value: serde_json::Value = serde_json::from_str(&contents)?;
+        let sets = value["rbnf"]["rbnf"][group]
+            .as_object()
+            .ok_or_else(|| anyhow!("no {} for {:?}", group, identifier))?;
+
+        let mut rule_group = RuleGroup::new();
+        for (name, rules) in sets.iter() {
+            let rules: Vec<(String, String)> = serde_json::from_value(rules.clone())?;
+            let rule_set = RuleSet::parse(&rules)
+                .map_err(|e| anyhow!("{} {} of {:?}: {}", group, name, identifier, e))?;
+            let _ = rule_group.insert(name.to_string(), rule_set);
+        }
+        let _ = rbnf.insert(variant_name.to_string(), rule_group);
+    }
+    Ok(rbnf)
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use indexmap::IndexMap;
+
+use crate::utils::{Format, CATEGORIES};
+
+/// The units to collect patterns for, as (CLDR key, `MeasureUnit` variant name).
+pub const UNITS: [(&str, &str); 18] = [
+    ("length-kilometer", "Kilometer"),
+    ("length-meter", "Meter"),
+    ("length-centimeter", "Centimeter"),
+    ("length-millimeter", "Millimeter"),
+    ("length-mile", "Mile"),
+    ("length-yard", "Yard"),
+    ("length-foot", "Foot"),
+    ("length-inch", "Inch"),
+    ("mass-kilogram", "Kilogram"),
+    ("mass-gram", "Gram"),
+    ("mass-pound", "Pound"),
+    ("mass-ounce", "Ounce"),
+    ("speed-kilometer-per-hour", "KilometerPerHour"),
+    ("speed-meter-per-second", "MeterPerSecond"),
+    ("speed-mile-per-hour", "MilePerHour"),
+    ("temperature-celsius", "Celsius"),
+    ("temperature-fahrenheit", "Fahrenheit"),
+    ("concentr-percent", "Percent"),
+];
+
+/// The unit widths, as (CLDR key, `UnitWidth` variant name).
+pub const WIDTHS: [(&str, &str); 3] = [("long", "Long"), ("short", "Short"), ("narrow", "Narrow")];
+
+/// A unit pattern (e.g. "{0} km") for each plural category, if the locale has one.
+pub type UnitPatterns = [Option<String>; 6];
+
+/// Reads the CLDR "units.json" file of each locale in the map returned from `parse_data` and
+/// collects the patterns of each width and unit in [`UNITS`], ordered by width, then unit.
+pub fn parse_units<P>(
+    data_dir: P,
+    data: &IndexMap<String, Format>,
+) -> Result<IndexMap<String, Vec<UnitPatterns>>, anyhow::Error>
+where
+    P: AsRef<Path>,
+{
+    let mut units = IndexMap::new();
+    for (variant_name, format) in data.iter() {
+        // Fall back to the language if there is no data for the locale itself
+        let mut identifier = format.identifier.as_str();
+        let path = loop {
+            let path = data_dir
+                .as_ref()
+                .join("main")
+                .join(identifier)
+                .join("units.json");
+            if path.is_file() {
+                break path;
+            }
+            match identifier.rfind('-') {
+                Some(i) => identifier = &identifier[..i],
+                None => return Err(anyhow!("no unit data for {:?}", format.identifier)),
+            }
+        };
+
+        let contents = fs::read_to_string(&path)?;
+        let value: serde_json::Value = serde_json::from_str(&contents)?;
+        let value = &value["main"][identifier]["units"];
+
+        let mut patterns = Vec::new();
+        for (width, _) in WIDTHS.iter() {
+            for (unit, _) in UNITS.iter() {
+                let unit_value = &value[width][unit];
+                let mut unit_patterns: UnitPatterns = Default::default();
+                for (i, category) in CATEGORIES.iter().enumerate() {
+                    let key = format!("unitPattern-count-{}", category);
+                    unit_patterns[i] = unit_value[&key].as_str().map(|s| s.to_string());
+                }
+                if unit_patterns[5].is_none() {
+                    return Err(anyhow!("no {} {} pattern for {:?}", width, unit, identifier));
+                }
+                patterns.push(unit_patterns);
+            }
+        }
+        let _ = units.insert(variant_name.to_string(), patterns);
+    }
+    Ok(units)
+}
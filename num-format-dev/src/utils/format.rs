@@ -4,11 +4,17 @@ use crate::utils::Grouping;
 pub struct Format {
     pub(crate) identifier: String,
 
+    pub(crate) apx: String,
+    pub(crate) cmp: Vec<(String, i32)>,
     pub(crate) dec: char,
+    pub(crate) exp: String,
     pub(crate) grp: Grouping,
     pub(crate) inf: String,
     pub(crate) min: String,
     pub(crate) nan: String,
+    pub(crate) pat: String,
+    pub(crate) pct: String,
     pub(crate) pos: String,
+    pub(crate) rng: String,
     pub(crate) sep: char,
 }
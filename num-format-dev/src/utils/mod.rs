@@ -1,5 +1,9 @@
 mod format;
 mod grouping;
+mod plural_rules;
+mod rbnf;
 
 pub use self::format::Format;
 pub use self::grouping::Grouping;
+pub use self::plural_rules::{Condition, PluralRanges, PluralRules, CATEGORIES};
+pub use self::rbnf::{Part, RuleGroup, RuleSet, Target};
@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+use indexmap::IndexMap;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// The plural categories, in the order they are checked by generated code.
+pub const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// A locale's plural rules: the condition for each category other than "other".
+pub type PluralRules = IndexMap<String, Condition>;
+
+/// A locale's plural ranges: the category of a range for each pair of start and end categories.
+pub type PluralRanges = IndexMap<(String, String), String>;
+
+/// A CLDR plural rule condition (e.g. `v = 0 and i % 10 = 1`), which is a disjunction of
+/// conjunctions of relations.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Condition(Vec<Vec<Relation>>);
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct Relation {
+    operand: char,
+    modulus: Option<u64>,
+    negated: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Condition {
+    /// Returns `true` if the condition is empty (i.e. the rule for "other").
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Turns the condition into a boolean rust expression over a `PluralOperands` named `op`.
+    pub fn to_tokens(&self) -> TokenStream {
+        let ors = self.0.iter().map(|ands| {
+            let parens = self.0.len() > 1 && ands.len() > 1;
+            let ands = ands.iter().map(|relation| {
+                let (tokens, compound) = relation.to_tokens();
+                if compound && (relation.negated || self.0.len() > 1) {
+                    quote! { (#tokens) }
+                } else {
+                    tokens
+                }
+            });
+            if parens {
+                quote! { (#(#ands)&&*) }
+            } else {
+                quote! { #(#ands)&&* }
+            }
+        });
+        quote! { #(#ors)||* }
+    }
+}
+
+impl Relation {
+    /// Returns the relation as a boolean rust expression, and whether that expression combines
+    /// several tests.
+    fn to_tokens(&self) -> (TokenStream, bool) {
+        // `n` is the absolute value, which only equals an integer if it has no fraction
+        let field = match self.operand {
+            'n' | 'i' => quote! { op.i },
+            'v' => quote! { op.v },
+            'w' => quote! { op.w },
+            'f' => quote! { op.f },
+            't' => quote! { op.t },
+            'c' | 'e' => quote! { op.e },
+            _ => unreachable!(),
+        };
+        let (expr, reference) = match self.modulus {
+            Some(modulus) => {
+                let modulus = Literal::u64_unsuffixed(modulus);
+                (quote! { #field % #modulus }, quote! { &(#field % #modulus) })
+            }
+            None => (field.clone(), quote! { &#field }),
+        };
+        let not = if self.negated { quote! { ! } } else { quote! {} };
+        let test = match self.ranges.as_slice() {
+            [(a, b)] if a == b => {
+                let a = Literal::u64_unsuffixed(*a);
+                if self.negated {
+                    quote! { #expr != #a }
+                } else {
+                    quote! { #expr == #a }
+                }
+            }
+            [(a, b)] => {
+                let a = Literal::u64_unsuffixed(*a);
+                let b = Literal::u64_unsuffixed(*b);
+                quote! { #not(#a..=#b).contains(#reference) }
+            }
+            ranges => {
+                let patterns = ranges.iter().map(|(a, b)| {
+                    let a = Literal::u64_unsuffixed(*a);
+                    if a.to_string() == b.to_string() {
+                        quote! { #a }
+                    } else {
+                        let b = Literal::u64_unsuffixed(*b);
+                        quote! { #a..=#b }
+                    }
+                });
+                quote! { #not matches!(#expr, #(#patterns)|*) }
+            }
+        };
+        match (self.operand, self.negated) {
+            ('n', false) => (quote! { op.t == 0 && #test }, true),
+            ('n', true) => (quote! { op.t != 0 || #test }, true),
+            _ => (test, false),
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Drop the samples (e.g. "@integer 1, 21, 31")
+        let s = s.split('@').next().unwrap().trim();
+        if s.is_empty() {
+            return Ok(Condition(Vec::new()));
+        }
+        let ors = s
+            .split(" or ")
+            .map(|and| {
+                and.split(" and ")
+                    .map(|relation| relation.parse::<Relation>())
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Condition(ors))
+    }
+}
+
+impl FromStr for Relation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (expr, negated, ranges) = if let Some(i) = s.find("!=") {
+            (&s[..i], true, &s[i + 2..])
+        } else if let Some(i) = s.find('=') {
+            (&s[..i], false, &s[i + 1..])
+        } else {
+            bail!("invalid plural relation: {:?}", s);
+        };
+
+        let mut expr = expr.split('%').map(str::trim);
+        let operand = expr.next().unwrap();
+        let operand = match operand {
+            "n" | "i" | "v" | "w" | "f" | "t" | "c" | "e" => operand.chars().next().unwrap(),
+            _ => bail!("invalid plural operand: {:?}", operand),
+        };
+        let modulus = expr.next().map(|m| m.parse::<u64>()).transpose()?;
+
+        let ranges = ranges
+            .split(',')
+            .map(|range| {
+                let mut bounds = range.trim().split("..");
+                let a = bounds.next().unwrap().parse::<u64>()?;
+                let b = match bounds.next() {
+                    Some(b) => b.parse::<u64>()?,
+                    None => a,
+                };
+                Ok((a, b))
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()
+            .map_err(|e| anyhow!("invalid plural relation {:?}: {}", s, e))?;
+
+        Ok(Relation {
+            operand,
+            modulus,
+            negated,
+            ranges,
+        })
+    }
+}
@@ -0,0 +1,300 @@
+use anyhow::{anyhow, bail};
+use indexmap::IndexMap;
+
+use super::CATEGORIES;
+
+/// The rule sets of one RBNF group (e.g. "OrdinalRules") of one locale, by name (e.g.
+/// "%digits-ordinal" or the private "%%dord-mascabbrev").
+pub type RuleGroup = IndexMap<String, RuleSet>;
+
+/// An RBNF rule set: the rule for negative numbers, if any, and the rules for non-negative
+/// integers, ordered by base value. Fraction, infinity, and NaN rules are dropped.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RuleSet {
+    /// The parts of the "-x" rule, which are given the absolute value.
+    pub negative: Option<Vec<Part>>,
+    /// The rules for non-negative integers.
+    pub rules: Vec<Rule>,
+}
+
+/// An RBNF rule for the integers from its base value up to the next rule's base value.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Rule {
+    /// The smallest integer the rule applies to.
+    pub base: u64,
+    /// The power of the radix that quotient and remainder substitutions divide by.
+    pub divisor: u64,
+    /// The text and substitutions of the rule, in order.
+    pub parts: Vec<Part>,
+}
+
+/// A piece of an RBNF rule's text.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Part {
+    /// Literal text.
+    Text(String),
+    /// The minus sign of negative rules (U+2212), which is written as the format's minus sign.
+    Minus,
+    /// `<<`: the number divided by the rule's divisor.
+    Quotient(Target),
+    /// `>>`: the remainder of the number divided by the rule's divisor.
+    Remainder(Target),
+    /// `==`: the number itself.
+    Same(Target),
+    /// `$(ordinal,...)$` or `$(cardinal,...)$`: text chosen by the plural category of the
+    /// number divided by the rule's divisor, indexed by category (`None` means "use other").
+    Plural(bool, [Option<String>; 6]),
+}
+
+/// What a substitution is written with.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Target {
+    /// The rule set the rule belongs to.
+    Owner,
+    /// The named rule set.
+    Set(String),
+    /// The digits, grouped if `true` (e.g. `#,##0`) or not (e.g. `0`).
+    Digits(bool),
+}
+
+impl RuleSet {
+    /// Parses a rule set from its rules, given as (descriptor, body) pairs in the CLDR json
+    /// layout (e.g. `("100", "←← hundred[ →→];")`).
+    pub fn parse(rules: &[(String, String)]) -> Result<RuleSet, anyhow::Error> {
+        let mut rule_set = RuleSet {
+            negative: None,
+            rules: Vec::new(),
+        };
+        let mut next_base = 0;
+        for (descriptor, body) in rules.iter() {
+            let descriptor = descriptor.trim();
+            if let "x.x" | "x,x" | "0.x" | "x.0" | "Inf" | "NaN" = descriptor {
+                continue;
+            }
+            let (parts, optional) = parse_body(body)?;
+            if descriptor == "-x" {
+                if optional.is_some() {
+                    bail!("optional text in negative rule {:?}", body);
+                }
+                rule_set.negative = Some(negative_parts(parts));
+                continue;
+            }
+
+            let (base, radix, decrements) = parse_descriptor(descriptor, next_base)?;
+            let mut exponent = 0;
+            while radix
+                .checked_pow(exponent + 1)
+                .is_some_and(|pow| pow <= base)
+            {
+                exponent += 1;
+            }
+            let exponent = exponent
+                .checked_sub(decrements)
+                .ok_or_else(|| anyhow!("invalid descriptor {:?}", descriptor))?;
+            let divisor = radix.pow(exponent);
+
+            // Text in brackets is only written if the remainder isn't zero, which is the same as
+            // a rule without it at the base value and a rule with it from the next integer on
+            match optional {
+                Some((start, end)) => {
+                    let mut without = parts.clone();
+                    let _ = without.drain(start..end);
+                    rule_set.rules.push(Rule {
+                        base,
+                        divisor,
+                        parts: without,
+                    });
+                    rule_set.rules.push(Rule {
+                        base: base + 1,
+                        divisor,
+                        parts,
+                    });
+                }
+                None => rule_set.rules.push(Rule {
+                    base,
+                    divisor,
+                    parts,
+                }),
+            }
+            next_base = base + 1;
+        }
+        if rule_set.rules.is_empty() {
+            bail!("no integer rules in {:?}", rules);
+        }
+        Ok(rule_set)
+    }
+
+    /// Returns the names of the other rule sets the rule set refers to.
+    pub fn references(&self) -> impl Iterator<Item = &str> {
+        let rules = self.rules.iter().map(|rule| &rule.parts);
+        self.negative
+            .iter()
+            .chain(rules)
+            .flatten()
+            .filter_map(|part| match part {
+                Part::Quotient(Target::Set(name))
+                | Part::Remainder(Target::Set(name))
+                | Part::Same(Target::Set(name)) => Some(name.as_str()),
+                _ => None,
+            })
+    }
+}
+
+/// Parses a rule descriptor of the form `base[/radix][>...]` (e.g. `"1,000"` or `"100/20>"`),
+/// where an empty descriptor means the integer after the previous rule.
+fn parse_descriptor(descriptor: &str, next_base: u64) -> Result<(u64, u64, u32), anyhow::Error> {
+    if descriptor.is_empty() {
+        return Ok((next_base, 10, 0));
+    }
+    let trimmed = descriptor.trim_end_matches('>');
+    let decrements = (descriptor.len() - trimmed.len()) as u32;
+    let (base, radix) = match trimmed.find('/') {
+        Some(i) => (&trimmed[..i], trimmed[i + 1..].parse()?),
+        None => (trimmed, 10),
+    };
+    let base = base.replace(',', "").parse()?;
+    Ok((base, radix, decrements))
+}
+
+/// The parts of a rule body, and the range of the parts in brackets, if any.
+type Body = (Vec<Part>, Option<(usize, usize)>);
+
+/// Parses a rule body (e.g. `"←← hundred[ →→];"`) into parts.
+fn parse_body(body: &str) -> Result<Body, anyhow::Error> {
+    let body = body.trim_end().trim_end_matches(';');
+    // A leading apostrophe only serves to keep leading whitespace
+    let body = body.strip_prefix('\'').unwrap_or(body);
+    let body = body.replace('←', "<").replace('→', ">");
+
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut optional_start = None;
+    let mut optional = None;
+    let mut rest = body.as_str();
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("$(") {
+            let end = rest
+                .find(")$")
+                .ok_or_else(|| anyhow!("unterminated plural in {:?}", body))?;
+            flush(&mut text, &mut parts);
+            parts.push(parse_plural(&rest[2..end])?);
+            rest = &rest[end + 2..];
+        } else if c == '[' || c == ']' {
+            flush(&mut text, &mut parts);
+            if c == '[' {
+                optional_start = Some(parts.len());
+            } else {
+                let start = optional_start
+                    .take()
+                    .ok_or_else(|| anyhow!("unbalanced brackets in {:?}", body))?;
+                optional = Some((start, parts.len()));
+            }
+            rest = &rest[1..];
+        } else if c == '<' || c == '>' || c == '=' {
+            let end = rest[1..]
+                .find(c)
+                .ok_or_else(|| anyhow!("unterminated substitution in {:?}", body))?
+                + 1;
+            if c == '>' && rest[end + 1..].starts_with('>') {
+                bail!("unsupported substitution in {:?}", body);
+            }
+            flush(&mut text, &mut parts);
+            let target = parse_target(&rest[1..end])?;
+            parts.push(match c {
+                '<' => Part::Quotient(target),
+                '>' => Part::Remainder(target),
+                _ => Part::Same(target),
+            });
+            rest = &rest[end + 1..];
+        } else {
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    flush(&mut text, &mut parts);
+    if optional_start.is_some() {
+        bail!("unbalanced brackets in {:?}", body);
+    }
+    Ok((parts, optional))
+}
+
+/// Moves the text collected so far, if any, into a part.
+fn flush(text: &mut String, parts: &mut Vec<Part>) {
+    if !text.is_empty() {
+        parts.push(Part::Text(std::mem::take(text)));
+    }
+}
+
+/// Parses what's between a substitution's tokens (e.g. `""`, `"%%th"`, or `"#,##0"`).
+fn parse_target(s: &str) -> Result<Target, anyhow::Error> {
+    if s.is_empty() {
+        Ok(Target::Owner)
+    } else if s.starts_with('%') {
+        Ok(Target::Set(s.to_string()))
+    } else if s.bytes().all(|b| b"#,.0".contains(&b)) {
+        // Integers have no fraction digits, so only the integer part of the pattern matters
+        let integer = s.split('.').next().unwrap();
+        Ok(Target::Digits(integer.contains(',')))
+    } else {
+        Err(anyhow!("unsupported number pattern {:?}", s))
+    }
+}
+
+/// Parses the inside of a plural (e.g. `"ordinal,one{st}two{nd}few{rd}other{th}"`).
+fn parse_plural(s: &str) -> Result<Part, anyhow::Error> {
+    let (kind, mut rest) = s
+        .split_once(',')
+        .ok_or_else(|| anyhow!("invalid plural {:?}", s))?;
+    let ordinal = match kind {
+        "ordinal" => true,
+        "cardinal" => false,
+        _ => bail!("invalid plural {:?}", s),
+    };
+    let mut forms: [Option<String>; 6] = Default::default();
+    while !rest.is_empty() {
+        let open = rest
+            .find('{')
+            .ok_or_else(|| anyhow!("invalid plural {:?}", s))?;
+        let close = rest
+            .find('}')
+            .ok_or_else(|| anyhow!("invalid plural {:?}", s))?;
+        let category = &rest[..open];
+        let i = CATEGORIES
+            .iter()
+            .position(|c| *c == category)
+            .ok_or_else(|| anyhow!("invalid plural category {:?}", category))?;
+        forms[i] = Some(rest[open + 1..close].to_string());
+        rest = &rest[close + 1..];
+    }
+    if forms[5].is_none() {
+        bail!("plural without other {:?}", s);
+    }
+    Ok(Part::Plural(ordinal, forms))
+}
+
+/// Turns the parts of a negative rule, whose `>>` means the absolute value, into parts that
+/// write the absolute value with `Same`, with the minus sign as its own part.
+fn negative_parts(parts: Vec<Part>) -> Vec<Part> {
+    let mut out = Vec::new();
+    for part in parts {
+        match part {
+            Part::Text(text) => {
+                let mut pieces = text.split('−');
+                if let Some(first) = pieces.next() {
+                    if !first.is_empty() {
+                        out.push(Part::Text(first.to_string()));
+                    }
+                }
+                for piece in pieces {
+                    out.push(Part::Minus);
+                    if !piece.is_empty() {
+                        out.push(Part::Text(piece.to_string()));
+                    }
+                }
+            }
+            Part::Remainder(target) => out.push(Part::Same(target)),
+            part => out.push(part),
+        }
+    }
+    out
+}
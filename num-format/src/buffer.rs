@@ -4,7 +4,7 @@ use core::ops::Deref;
 use core::str;
 
 use crate::constants::MAX_BUF_LEN;
-use crate::format::Format;
+use crate::format::{Format, Plain};
 use crate::to_formatted_str::ToFormattedStr;
 
 /// <b><u>A key type</u></b>. Represents a stack-allocated buffer you can use to get a
@@ -81,7 +81,7 @@ impl Buffer {
         F: Format,
         N: ToFormattedStr,
     {
-        n.read_to_buffer(self, format)
+        n.read_to_buffer(self, &Plain(format))
     }
 
     #[inline(never)]
@@ -215,7 +215,7 @@ mod serialization {
             assert_eq!("1,000", buf.as_str());
 
             // should fail
-            let mut should_fail = ArrayString::<1024>::new();
+            let mut should_fail = ArrayString::<{ 2 * MAX_BUF_LEN + 4 }>::new();
             should_fail.push_str("[0");
             for _ in 0..MAX_BUF_LEN {
                 should_fail.push_str(",0");
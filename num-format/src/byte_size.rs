@@ -0,0 +1,132 @@
+/// Type for specifying the unit prefixes used by a [`ByteSize`].
+///
+/// [`ByteSize`]: struct.ByteSize.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum ByteUnits {
+    /// Decimal (SI) prefixes, in powers of 1000 (e.g. 1.6 GB)
+    Si,
+    /// Binary (IEC) prefixes, in powers of 1024 (e.g. 1.5 GiB)
+    Iec,
+}
+
+impl ByteUnits {
+    /// Returns the number of bytes in one unit of the next larger size (1000 or 1024).
+    pub fn base(self) -> u64 {
+        match self {
+            ByteUnits::Si => 1000,
+            ByteUnits::Iec => 1024,
+        }
+    }
+
+    /// Returns the unit symbols, from bytes up to exabytes.
+    pub(crate) fn symbols(self) -> &'static [&'static str] {
+        match self {
+            ByteUnits::Si => &["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+            ByteUnits::Iec => &["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+        }
+    }
+}
+
+/// Type for representing a number of bytes as a human-readable size (e.g. `1.5 GiB`).
+/// Implements [`ToFormattedStr`].
+///
+/// The size is written in the largest unit that keeps the number at or above one, rounded
+/// (half up) to the given number of fraction digits, followed by a space and the unit symbol.
+/// Sizes below one kilobyte (1000 or 1024 bytes) are always written as a whole number of bytes.
+/// The number is written with the grouping and decimal of the [`Format`] it is formatted with.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, ByteSize, ByteUnits, Locale};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&ByteSize::new(1_610_612_736, ByteUnits::Iec, 1), &Locale::de);
+///     assert_eq!("1,5 GiB", buf.as_str());
+///
+///     buf.write_formatted(&ByteSize::new(1_610_612_736, ByteUnits::Si, 2), &Locale::en);
+///     assert_eq!("1.61 GB", buf.as_str());
+///
+///     buf.write_formatted(&ByteSize::new(512, ByteUnits::Si, 2), &Locale::en);
+///     assert_eq!("512 B", buf.as_str());
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct ByteSize {
+    bytes: u64,
+    prec: u8,
+    units: ByteUnits,
+}
+
+impl ByteSize {
+    /// The largest supported precision. Larger precisions are treated as this value.
+    pub const MAX_PRECISION: u8 = 19;
+
+    /// Constructs a [`ByteSize`] for the given number of bytes, written in the given
+    /// [`ByteUnits`] with `precision` fraction digits (at most [`MAX_PRECISION`]). The precision
+    /// does not apply to sizes below one kilobyte, which are written as whole bytes.
+    ///
+    /// [`ByteSize`]: struct.ByteSize.html
+    /// [`ByteUnits`]: enum.ByteUnits.html
+    /// [`MAX_PRECISION`]: #associatedconstant.MAX_PRECISION
+    pub fn new(bytes: u64, units: ByteUnits, precision: u8) -> ByteSize {
+        ByteSize {
+            bytes,
+            prec: precision.min(Self::MAX_PRECISION),
+            units,
+        }
+    }
+
+    /// Returns the number of bytes.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the number of fraction digits written for sizes of one kilobyte and above.
+    pub fn precision(&self) -> u8 {
+        self.prec
+    }
+
+    /// Returns the [`ByteUnits`] the size is written in.
+    ///
+    /// [`ByteUnits`]: enum.ByteUnits.html
+    pub fn units(&self) -> ByteUnits {
+        self.units
+    }
+
+    /// Returns the index of the unit to write the size in and the size in that unit, multiplied
+    /// by `10^precision` and rounded.
+    pub(crate) fn scaled(&self) -> (usize, u128) {
+        let base = u128::from(self.units.base());
+        let bytes = u128::from(self.bytes);
+        let symbols = self.units.symbols();
+        if bytes < base {
+            return (0, bytes);
+        }
+
+        let multiplier = 10u128.pow(u32::from(self.prec));
+        let mut unit = 0;
+        let mut divisor = 1u128;
+        while unit + 1 < symbols.len() && bytes >= divisor * base {
+            unit += 1;
+            divisor *= base;
+        }
+        loop {
+            let (int, rem) = (bytes / divisor, bytes % divisor);
+            let frac = (rem * multiplier + divisor / 2) / divisor;
+            let n = int * multiplier + frac;
+            // Rounding may carry into the next unit (e.g. 1023.96 KiB -> 1.0 MiB)
+            if n >= base * multiplier && unit + 1 < symbols.len() {
+                unit += 1;
+                divisor *= base;
+                continue;
+            }
+            return (unit, n);
+        }
+    }
+}
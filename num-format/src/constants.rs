@@ -1,21 +1,67 @@
-use crate::strings::{MAX_MIN_LEN, MAX_SEP_LEN};
+use crate::rbnf_rules::{MAX_ORDINAL_LEN, MAX_SPELLOUT_LEN};
+use crate::strings::{MAX_DEC_LEN, MAX_MIN_LEN, MAX_SEP_LEN};
 
 // Want this to be as large as the largest possible string representation of any type
-// that implements ToFormattedStr, which is currently i128's Grouping::Indian representation.
-// The max len of an i128 formatted string is ...
+// that implements ToFormattedStr, which is the largest of the lengths below. Measures, ranges,
+// radixed integers, and numbers written with format or printf specs, which put one or two
+// floats into a pattern, write up to 128 digits with a separator between each, or pad a number
+// out to any width, only implement ToFormattedString, and are written in pieces.
+pub(crate) const MAX_BUF_LEN: usize = max(
+    MAX_INT_BUF_LEN,
+    max(
+        max(MAX_FLOAT_BUF_LEN, MAX_PATTERN_BUF_LEN),
+        max(
+            MAX_ORDINAL_BUF_LEN,
+            max(MAX_ROMAN_BUF_LEN, MAX_SPELLOUT_BUF_LEN),
+        ),
+    ),
+);
+
+// The max len of an i128 formatted string (i.e. its Grouping::Indian representation) is ...
 // 39 digits + 18 separators (each potentially 8 bytes) + 1 minus sign (potentially 8 bytes)
-pub(crate) const MAX_BUF_LEN: usize = 39 + 18 * MAX_SEP_LEN + MAX_MIN_LEN;
+pub(crate) const MAX_INT_BUF_LEN: usize = 39 + 18 * MAX_SEP_LEN + MAX_MIN_LEN;
 
-pub(crate) const U8_MAX_LEN: usize = 3;
-pub(crate) const U16_MAX_LEN: usize = 5;
-pub(crate) const U32_MAX_LEN: usize = 10;
-pub(crate) const USIZE_MAX_LEN: usize = 20;
-pub(crate) const U64_MAX_LEN: usize = 20;
-pub(crate) const U128_MAX_LEN: usize = 39;
+// An f64 needs at most 309 integer digits (f64::MAX) or 324 fraction digits (the smallest
+// subnormal), but never both at once. Fraction grouping is checked against MAX_FRAC_BUF_LEN
+// when a FractionGrouped is constructed, which fits 324 digits in groups of three ...
+// 324 digits + 107 separators (each potentially 8 bytes)
+pub(crate) const F64_MAX_INT_DIGITS: usize = 309;
+pub(crate) const F64_MAX_FRAC_DIGITS: usize = 324;
+pub(crate) const MAX_FRAC_BUF_LEN: usize = F64_MAX_FRAC_DIGITS + 107 * MAX_SEP_LEN;
+
+// The max len of an f64 formatted string is the larger of ...
+// 309 digits + 154 separators + 1 decimal + 1 fraction digit, or
+// 1 digit + 1 decimal + a grouped fraction,
+// plus 1 minus sign (potentially 8 bytes)
+pub(crate) const MAX_FLOAT_BUF_LEN: usize = MAX_MIN_LEN
+    + MAX_DEC_LEN
+    + max(
+        F64_MAX_INT_DIGITS + 154 * MAX_SEP_LEN + 1,
+        1 + MAX_FRAC_BUF_LEN,
+    );
+
+// Patterned numbers are checked against MAX_BUF_LEN when they are constructed, and the longest
+// f64 written with a CLDR pattern is one with a percent pattern of Indian grouping ...
+// 311 digits (f64::MAX times a hundred) + 154 separators + 1 decimal + 1 minus sign + 1 percent
+pub(crate) const MAX_PATTERN_BUF_LEN: usize =
+    F64_MAX_INT_DIGITS + 2 + 154 * MAX_SEP_LEN + MAX_DEC_LEN + MAX_MIN_LEN + 1;
 
-pub(crate) const I8_MAX_LEN: usize = 4;
-pub(crate) const I16_MAX_LEN: usize = 6;
-pub(crate) const I32_MAX_LEN: usize = 11;
-pub(crate) const ISIZE_MAX_LEN: usize = 20;
-pub(crate) const I64_MAX_LEN: usize = 20;
-pub(crate) const I128_MAX_LEN: usize = 40;
+// The max lens of ordinals and spelled out numbers are worked out from their rules when the
+// rules are generated
+pub(crate) const MAX_ORDINAL_BUF_LEN: usize = MAX_ORDINAL_LEN;
+pub(crate) const MAX_SPELLOUT_BUF_LEN: usize = MAX_SPELLOUT_LEN;
+
+// The max len of a Roman numeral is that of 3,888,888 with a vinculum ...
+// 15 letters (each followed by a 2 byte overline) + 12 letters, unless the number can't be
+// represented, in which case it's written like any other integer
+pub(crate) const MAX_ROMAN_BUF_LEN: usize = max(15 * 3 + 12, MAX_INT_BUF_LEN);
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+pub(crate) const U128_MAX_LEN: usize = 39;
@@ -1,8 +1,13 @@
+use crate::parsing::ParseFormat;
 use crate::strings::{
     DecString, DecimalStr, InfString, InfinityStr, MinString, MinusSignStr, NanStr, NanString,
     PlusSignStr, PlusString, SepString, SeparatorStr,
 };
+#[cfg(feature = "with-serde")]
+use crate::Error;
 use crate::{CustomFormatBuilder, Format, Grouping, Locale};
+#[cfg(feature = "with-serde")]
+use core::convert::TryFrom;
 
 /// Type for representing your own custom formats. Implements [`Format`].
 ///
@@ -28,16 +33,53 @@ use crate::{CustomFormatBuilder, Format, Grouping, Locale};
 /// [`Format`]: trait.Format.html
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(try_from = "CustomFormatFields"))]
 pub struct CustomFormat {
     pub(crate) dec: DecString,
     pub(crate) grp: Grouping,
     pub(crate) inf: InfString,
+    #[cfg_attr(feature = "with-serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) loc: Option<Locale>,
     pub(crate) min: MinString,
     pub(crate) nan: NanString,
     pub(crate) plus: PlusString,
     pub(crate) sep: SepString,
 }
 
+// The fields of a serialized `CustomFormat`, which are checked by building them into one
+#[cfg(feature = "with-serde")]
+#[derive(Deserialize)]
+struct CustomFormatFields {
+    dec: DecString,
+    grp: Grouping,
+    inf: InfString,
+    #[serde(default)]
+    loc: Option<Locale>,
+    min: MinString,
+    nan: NanString,
+    plus: PlusString,
+    sep: SepString,
+}
+
+#[cfg(feature = "with-serde")]
+impl TryFrom<CustomFormatFields> for CustomFormat {
+    type Error = Error;
+
+    fn try_from(fields: CustomFormatFields) -> Result<Self, Self::Error> {
+        let unchecked = CustomFormat {
+            dec: fields.dec,
+            grp: fields.grp,
+            inf: fields.inf,
+            loc: fields.loc,
+            min: fields.min,
+            nan: fields.nan,
+            plus: fields.plus,
+            sep: fields.sep,
+        };
+        CustomFormatBuilder::from(unchecked).build()
+    }
+}
+
 impl CustomFormat {
     /// Constructs a [`CustomFormatBuilder`].
     ///
@@ -70,6 +112,14 @@ impl CustomFormat {
         &self.inf
     }
 
+    /// Returns the [`Locale`] whose symbols (e.g. the percent sign and exponential symbol) this
+    /// format recognizes around numbers when parsing, if any.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Option<Locale> {
+        self.loc
+    }
+
     /// Returns this format's representation of minus signs.
     pub fn minus_sign(&self) -> &str {
         &self.min
@@ -135,12 +185,20 @@ impl Format for CustomFormat {
     }
 }
 
+impl ParseFormat for CustomFormat {
+    #[inline(always)]
+    fn locale(&self) -> Option<Locale> {
+        self.locale()
+    }
+}
+
 impl From<Locale> for CustomFormat {
     fn from(locale: Locale) -> Self {
         Self {
             dec: DecString::new(locale.decimal()).unwrap(),
             grp: locale.grouping(),
             inf: InfString::new(locale.infinity()).unwrap(),
+            loc: Some(locale),
             min: MinString::new(locale.minus_sign()).unwrap(),
             nan: NanString::new(locale.nan()).unwrap(),
             plus: PlusString::new(locale.plus_sign()).unwrap(),
@@ -160,6 +218,7 @@ mod system {
                 dec: DecString::new(locale.decimal()).unwrap(),
                 grp: locale.grouping(),
                 inf: InfString::new(locale.infinity()).unwrap(),
+                loc: ParseFormat::locale(&locale),
                 min: MinString::new(locale.minus_sign()).unwrap(),
                 nan: NanString::new(locale.nan()).unwrap(),
                 plus: PlusString::new(locale.plus_sign()).unwrap(),
@@ -181,4 +240,23 @@ mod tests {
             r#"{"dec":".","grp":"Standard","inf":"∞","min":"-","nan":"NaN","plus":"+","sep":","}"#;
         assert_eq!(expected, &s);
     }
+
+    #[test]
+    fn test_deserialization_of_older_formats() {
+        // Formats serialized before the field for locales
+        let s =
+            r#"{"dec":".","grp":"Standard","inf":"∞","min":"-","nan":"NaN","plus":"+","sep":","}"#;
+        let format: CustomFormat = serde_json::from_str(s).unwrap();
+        assert_eq!(CustomFormat::builder().build().unwrap(), format);
+
+        let s =
+            r#"{"dec":",","grp":"Indian","inf":"∞","min":"-","nan":"NaN","plus":"+","sep":"."}"#;
+        let format: CustomFormat = serde_json::from_str(s).unwrap();
+        assert_eq!(",", format.decimal());
+        assert_eq!(None, format.locale());
+
+        let s = r#"{"dec":{"Ok":"."},"grp":"Standard","inf":{"Ok":"∞"},"min":{"Ok":"-"},"nan":{"Ok":"NaN"},"plus":{"Ok":"+"},"sep":{"Ok":","}}"#;
+        let builder: CustomFormatBuilder = serde_json::from_str(s).unwrap();
+        assert_eq!(CustomFormat::builder(), builder);
+    }
 }
@@ -1,8 +1,8 @@
 use crate::custom_format::CustomFormat;
 use crate::error::Error;
-use crate::format::Format;
 use crate::grouping::Grouping;
 use crate::locale::Locale;
+use crate::parsing::ParseFormat;
 use crate::strings::{DecString, InfString, MinString, NanString, PlusString, SepString};
 
 /// Type for building [`CustomFormat`]s.
@@ -14,6 +14,8 @@ pub struct CustomFormatBuilder {
     dec: Result<DecString, Error>,
     grp: Grouping,
     inf: Result<InfString, Error>,
+    #[cfg_attr(feature = "with-serde", serde(default))]
+    loc: Option<Locale>,
     min: Result<MinString, Error>,
     nan: Result<NanString, Error>,
     plus: Result<PlusString, Error>,
@@ -26,6 +28,7 @@ impl CustomFormatBuilder {
             dec: DecString::new(Locale::en.decimal()),
             grp: Locale::en.grouping(),
             inf: InfString::new(Locale::en.infinity()),
+            loc: None,
             min: MinString::new(Locale::en.minus_sign()),
             nan: NanString::new(Locale::en.nan()),
             plus: PlusString::new(Locale::en.plus_sign()),
@@ -51,6 +54,7 @@ impl CustomFormatBuilder {
             dec: self.dec?,
             grp: self.grp,
             inf: self.inf?,
+            loc: self.loc,
             min: self.min?,
             nan: self.nan?,
             plus: self.plus?,
@@ -70,11 +74,12 @@ impl CustomFormatBuilder {
     /// Sets all fields based on the provided format.
     pub fn format<F>(mut self, value: &F) -> Self
     where
-        F: Format,
+        F: ParseFormat,
     {
         self.dec = DecString::new(value.decimal());
         self.grp = value.grouping();
         self.inf = InfString::new(value.infinity());
+        self.loc = value.locale();
         self.min = MinString::new(value.minus_sign());
         self.nan = NanString::new(value.nan());
         self.plus = PlusString::new(value.plus_sign());
@@ -99,6 +104,15 @@ impl CustomFormatBuilder {
         self
     }
 
+    /// Sets the [`Locale`] whose symbols (e.g. the percent sign and exponential symbol) to
+    /// recognize around numbers when parsing. Unless set, English is used.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(mut self, value: Locale) -> Self {
+        self.loc = Some(value);
+        self
+    }
+
     /// Sets the string representation of a minus sign.
     pub fn minus_sign<S>(mut self, s: S) -> Self
     where
@@ -0,0 +1,127 @@
+#![cfg(feature = "std")]
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::Error;
+
+/// Type for representing an exact decimal number given as a string of digits (e.g. `"-1234.5678"`).
+/// Implements [`ToFormattedString`].
+///
+/// Unlike floats, a [`Decimal`] keeps every digit it was given, including trailing zeros after the
+/// decimal point, so it is well suited to formatting values such as amounts of money or results
+/// read from a database.
+///
+/// # Example
+/// ```rust
+/// use num_format::{CustomFormat, Decimal, Error, FractionGrouped, Locale, ToFormattedString};
+///
+/// fn main() -> Result<(), Error> {
+///     let decimal: Decimal = "-1234567.1250".parse()?;
+///     assert_eq!("-1.234.567,1250", decimal.to_formatted_string(&Locale::de));
+///
+///     let format = CustomFormat::builder().separator(" ").build()?;
+///     let grouped = FractionGrouped::new(decimal, 3, " ")?;
+///     assert_eq!("-1 234 567.125 0", grouped.to_formatted_string(&format));
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Decimal`]: struct.Decimal.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Decimal {
+    digits: String,
+    int_len: usize,
+    negative: bool,
+}
+
+impl Decimal {
+    /// Constructs a [`Decimal`] from a string of the form `[+-]digits[.digits]`, where either the
+    /// integer or the fraction digits (but not both) may be omitted.
+    ///
+    /// Leading zeros of the integer part are dropped; trailing zeros of the fraction part are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not of the form described above.
+    ///
+    /// [`Decimal`]: struct.Decimal.html
+    pub fn new<S>(s: S) -> Result<Decimal, Error>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int_digits, frac_digits) = match unsigned.find('.') {
+            Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+            None => (unsigned, ""),
+        };
+        let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+        if (int_digits.is_empty() && frac_digits.is_empty())
+            || !is_digits(int_digits)
+            || !is_digits(frac_digits)
+        {
+            return Err(Error::parse_number(s));
+        }
+
+        let int_digits = int_digits.trim_start_matches('0');
+        let int_digits = if int_digits.is_empty() {
+            "0"
+        } else {
+            int_digits
+        };
+        let mut digits = String::with_capacity(int_digits.len() + frac_digits.len());
+        digits.push_str(int_digits);
+        digits.push_str(frac_digits);
+        let negative = negative && digits.bytes().any(|b| b != b'0');
+
+        Ok(Decimal {
+            digits,
+            int_len: int_digits.len(),
+            negative,
+        })
+    }
+
+    /// Returns the digits before the decimal point (always at least one digit).
+    pub fn integer_digits(&self) -> &str {
+        &self.digits[..self.int_len]
+    }
+
+    /// Returns the digits after the decimal point (possibly empty).
+    pub fn fraction_digits(&self) -> &str {
+        &self.digits[self.int_len..]
+    }
+
+    /// Returns `true` if the number is less than zero; `false` otherwise.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        f.write_str(self.integer_digits())?;
+        if !self.fraction_digits().is_empty() {
+            f.write_str(".")?;
+            f.write_str(self.fraction_digits())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::new(s)
+    }
+}
@@ -0,0 +1,208 @@
+use core::time::Duration;
+
+/// Type for specifying the unit an [`Elapsed`] is written in.
+///
+/// [`Elapsed`]: struct.Elapsed.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum DurationUnit {
+    /// Nanoseconds (e.g. 12 ns)
+    Nanoseconds,
+    /// Microseconds (e.g. 12 µs)
+    Microseconds,
+    /// Milliseconds (e.g. 12 ms)
+    Milliseconds,
+    /// Seconds (e.g. 12 s)
+    Seconds,
+    /// Minutes (e.g. 12 min)
+    Minutes,
+    /// Hours (e.g. 12 h)
+    Hours,
+    /// Days of 24 hours (e.g. 12 d)
+    Days,
+}
+
+impl DurationUnit {
+    pub(crate) const ALL: [DurationUnit; 7] = [
+        DurationUnit::Nanoseconds,
+        DurationUnit::Microseconds,
+        DurationUnit::Milliseconds,
+        DurationUnit::Seconds,
+        DurationUnit::Minutes,
+        DurationUnit::Hours,
+        DurationUnit::Days,
+    ];
+
+    /// Returns the number of nanoseconds in one of this unit.
+    pub fn nanos(self) -> u128 {
+        match self {
+            DurationUnit::Nanoseconds => 1,
+            DurationUnit::Microseconds => 1_000,
+            DurationUnit::Milliseconds => 1_000_000,
+            DurationUnit::Seconds => 1_000_000_000,
+            DurationUnit::Minutes => 60_000_000_000,
+            DurationUnit::Hours => 3_600_000_000_000,
+            DurationUnit::Days => 86_400_000_000_000,
+        }
+    }
+
+    /// Returns the unit symbol (e.g. `"ms"`).
+    pub fn symbol(self) -> &'static str {
+        match self {
+            DurationUnit::Nanoseconds => "ns",
+            DurationUnit::Microseconds => "µs",
+            DurationUnit::Milliseconds => "ms",
+            DurationUnit::Seconds => "s",
+            DurationUnit::Minutes => "min",
+            DurationUnit::Hours => "h",
+            DurationUnit::Days => "d",
+        }
+    }
+
+    /// Returns the next larger unit, if any.
+    pub(crate) fn next(self) -> Option<DurationUnit> {
+        DurationUnit::ALL.get(self as usize + 1).copied()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub(crate) enum Style {
+    Single(Option<DurationUnit>),
+    Compound(DurationUnit),
+}
+
+/// Type for writing a [`Duration`] in human-readable units. Implements [`ToFormattedStr`].
+///
+/// An [`Elapsed`] is written either in a single unit (e.g. `1,234.5 ms`), with a fixed number of
+/// fraction digits, or as a compound of whole units (e.g. `2 h 5 min 3 s`). The numbers are
+/// written with the grouping and decimal of the [`Format`] they are formatted with; the unit
+/// symbols are the same in every locale.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+///
+/// use num_format::{Buffer, DurationUnit, Elapsed, Locale};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+///
+///     let elapsed = Elapsed::new(Duration::from_micros(1_234_500), 1);
+///     buf.write_formatted(&elapsed, &Locale::en);
+///     assert_eq!("1.2 s", buf.as_str());
+///
+///     let elapsed = Elapsed::in_unit(Duration::from_micros(1_234_500), DurationUnit::Milliseconds, 1);
+///     buf.write_formatted(&elapsed, &Locale::en);
+///     assert_eq!("1,234.5 ms", buf.as_str());
+///
+///     let elapsed = Elapsed::compound(Duration::from_secs(7503), DurationUnit::Seconds);
+///     buf.write_formatted(&elapsed, &Locale::en);
+///     assert_eq!("2 h 5 min 3 s", buf.as_str());
+/// }
+/// ```
+///
+/// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+/// [`Elapsed`]: struct.Elapsed.html
+/// [`Format`]: trait.Format.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Elapsed {
+    dur: Duration,
+    prec: u8,
+    style: Style,
+}
+
+impl Elapsed {
+    /// The largest supported precision. Larger precisions are treated as this value.
+    pub const MAX_PRECISION: u8 = 9;
+
+    /// Constructs an [`Elapsed`] written in the largest unit that keeps the number at or above
+    /// one (or in seconds, for a zero duration), rounded (half up) to `precision` fraction digits
+    /// (at most [`MAX_PRECISION`]).
+    ///
+    /// [`Elapsed`]: struct.Elapsed.html
+    /// [`MAX_PRECISION`]: #associatedconstant.MAX_PRECISION
+    pub fn new(duration: Duration, precision: u8) -> Elapsed {
+        Elapsed {
+            dur: duration,
+            prec: precision.min(Self::MAX_PRECISION),
+            style: Style::Single(None),
+        }
+    }
+
+    /// Constructs an [`Elapsed`] always written in `unit`, rounded (half up) to `precision`
+    /// fraction digits (at most [`MAX_PRECISION`]).
+    ///
+    /// [`Elapsed`]: struct.Elapsed.html
+    /// [`MAX_PRECISION`]: #associatedconstant.MAX_PRECISION
+    pub fn in_unit(duration: Duration, unit: DurationUnit, precision: u8) -> Elapsed {
+        Elapsed {
+            dur: duration,
+            prec: precision.min(Self::MAX_PRECISION),
+            style: Style::Single(Some(unit)),
+        }
+    }
+
+    /// Constructs an [`Elapsed`] written as whole days, hours, minutes, and so on down to
+    /// `smallest_unit`, separated by spaces. Units with a value of zero are left out, and the
+    /// remainder below `smallest_unit` is dropped.
+    ///
+    /// [`Elapsed`]: struct.Elapsed.html
+    pub fn compound(duration: Duration, smallest_unit: DurationUnit) -> Elapsed {
+        Elapsed {
+            dur: duration,
+            prec: 0,
+            style: Style::Compound(smallest_unit),
+        }
+    }
+
+    /// Returns the [`Duration`].
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn duration(&self) -> Duration {
+        self.dur
+    }
+
+    /// Returns the number of fraction digits written (always `0` for compound output).
+    pub fn precision(&self) -> u8 {
+        self.prec
+    }
+
+    pub(crate) fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the unit to write a single-unit duration in and the duration in that unit,
+    /// multiplied by `10^precision` and rounded.
+    pub(crate) fn scaled(&self, unit: Option<DurationUnit>) -> (DurationUnit, u128) {
+        let nanos = self.dur.as_nanos();
+        let multiplier = 10u128.pow(u32::from(self.prec));
+        let round = |unit: DurationUnit| {
+            let divisor = unit.nanos();
+            let (int, rem) = (nanos / divisor, nanos % divisor);
+            int * multiplier + (rem * multiplier + divisor / 2) / divisor
+        };
+        if let Some(unit) = unit {
+            return (unit, round(unit));
+        }
+        if nanos == 0 {
+            return (DurationUnit::Seconds, 0);
+        }
+
+        let mut unit = DurationUnit::Nanoseconds;
+        while let Some(next) = unit.next() {
+            if nanos < next.nanos() {
+                break;
+            }
+            unit = next;
+        }
+        let n = round(unit);
+        // Rounding may carry into the next unit (e.g. 999.96 ms -> 1.0 s)
+        match unit.next() {
+            Some(next) if n >= next.nanos() / unit.nanos() * multiplier => (next, round(next)),
+            _ => (unit, n),
+        }
+    }
+}
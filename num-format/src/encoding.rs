@@ -0,0 +1,171 @@
+#![cfg(any(feature = "with-encoding", all(feature = "with-system-locale", unix)))]
+
+#[cfg(feature = "with-encoding")]
+use encoding_rs::DecoderResult;
+
+/// Type for representing a character encoding (e.g. Windows-1252) that bytes can be parsed
+/// under, as with [`ParseFormattedBytes::parse_formatted_encoded`].
+///
+/// # Example
+/// ```rust
+/// use num_format::{Encoding, Locale};
+/// use num_format::parsing::ParseFormattedBytes;
+///
+/// fn main() {
+///     let latin_1 = Encoding::for_label(b"ISO-8859-1").unwrap();
+///     assert_eq!("windows-1252", latin_1.name());
+///
+///     // "1 234 567" with U+00A0 as the separator, encoded as Latin-1
+///     let bytes = b"1\xa0234\xa0567";
+///     let n: u32 = bytes.parse_formatted_encoded(&Locale::fr, latin_1).unwrap();
+///     assert_eq!(1_234_567, n);
+/// }
+/// ```
+///
+/// [`ParseFormattedBytes::parse_formatted_encoded`]: parsing/trait.ParseFormattedBytes.html#method.parse_formatted_encoded
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Encoding(pub(crate) &'static encoding_rs::Encoding);
+
+impl Encoding {
+    /// Returns the encoding with the given label (e.g. `b"latin1"` or `b"Shift_JIS"`), as
+    /// labels are understood by the [WHATWG Encoding Standard] and by libc's `nl_langinfo`, or
+    /// `None` if there is no such encoding.
+    ///
+    /// [WHATWG Encoding Standard]: https://encoding.spec.whatwg.org/#names-and-labels
+    #[cfg(feature = "with-encoding")]
+    pub fn for_label(label: &[u8]) -> Option<Encoding> {
+        lookup(label).map(Encoding)
+    }
+
+    /// Returns the canonical name of the encoding (e.g. `"windows-1252"`).
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    /// Returns `true` if the encoding writes ASCII characters as their ASCII bytes and never
+    /// uses ASCII bytes for anything else; `false` otherwise.
+    #[cfg(feature = "with-encoding")]
+    pub(crate) fn is_ascii_compatible(&self) -> bool {
+        self.0.is_ascii_compatible()
+    }
+
+    #[cfg(feature = "with-encoding")]
+    pub(crate) fn is_utf_8(&self) -> bool {
+        self.0 == encoding_rs::UTF_8
+    }
+
+    /// Decodes `bytes` up to their first malformed sequence, returning the text and the byte
+    /// offset of the malformed sequence (if any).
+    #[cfg(feature = "with-encoding")]
+    pub(crate) fn decode_valid(&self, bytes: &[u8]) -> (String, Option<usize>) {
+        let mut decoder = self.0.new_decoder_without_bom_handling();
+        let mut text = String::new();
+        let mut read = 0;
+
+        loop {
+            let rest = &bytes[read..];
+            let len = decoder.max_utf8_buffer_length_without_replacement(rest.len());
+            text.reserve(len.unwrap_or(rest.len()));
+            let (result, n) = decoder.decode_to_string_without_replacement(rest, &mut text, true);
+            read += n;
+            match result {
+                DecoderResult::InputEmpty => return (text, None),
+                DecoderResult::OutputFull => continue,
+                DecoderResult::Malformed(bad, extra) => {
+                    let end = read - usize::from(extra);
+                    return (text, Some(end.saturating_sub(usize::from(bad))));
+                }
+            }
+        }
+    }
+
+    /// Decodes `bytes` up to their first malformed sequence or up to and including the first
+    /// character for which `is_end` returns `true`, returning the text and the byte offset of
+    /// the malformed sequence (if any).
+    #[cfg(feature = "with-encoding")]
+    pub(crate) fn decode_until<E>(&self, bytes: &[u8], is_end: E) -> (String, Option<usize>)
+    where
+        E: Fn(char) -> bool,
+    {
+        let mut decoder = self.0.new_decoder_without_bom_handling();
+        // Not sized for all of `bytes`, which may be decoded only up to their start
+        let mut text = String::new();
+
+        // Feeding the decoder one byte at a time tells where the first end character is
+        for (i, byte) in bytes.iter().enumerate() {
+            let decoded = text.len();
+            let last = i + 1 == bytes.len();
+            let (result, read) = decode_byte(&mut decoder, byte, last, &mut text);
+            if let DecoderResult::Malformed(bad, extra) = result {
+                let end = i + read - usize::from(extra);
+                return (text, Some(end.saturating_sub(usize::from(bad))));
+            }
+            if text[decoded..].chars().any(&is_end) {
+                break;
+            }
+        }
+
+        (text, None)
+    }
+
+    /// Returns the byte offset into `bytes` of the byte offset `position` into the text decoded
+    /// from them (i.e. how many bytes decode to the first `position` bytes of text), which is
+    /// worked out by decoding them again, since it's only needed once per parse.
+    #[cfg(feature = "with-encoding")]
+    pub(crate) fn byte_offset(&self, bytes: &[u8], position: usize) -> usize {
+        let mut decoder = self.0.new_decoder_without_bom_handling();
+        let mut text = String::new();
+
+        for (i, byte) in bytes.iter().enumerate() {
+            if text.len() >= position {
+                return i;
+            }
+            // The bytes up to `position` decoded without error the first time
+            let _ = decode_byte(&mut decoder, byte, i + 1 == bytes.len(), &mut text);
+        }
+        bytes.len()
+    }
+}
+
+#[cfg(feature = "with-encoding")]
+impl From<&'static encoding_rs::Encoding> for Encoding {
+    fn from(encoding: &'static encoding_rs::Encoding) -> Encoding {
+        Encoding(encoding)
+    }
+}
+
+/// Feeds `byte` into `decoder`, appending what it decodes to `text`, and returns the result and
+/// how many bytes were read.
+#[cfg(feature = "with-encoding")]
+fn decode_byte(
+    decoder: &mut encoding_rs::Decoder,
+    byte: &u8,
+    last: bool,
+    text: &mut String,
+) -> (DecoderResult, usize) {
+    if let Some(len) = decoder.max_utf8_buffer_length_without_replacement(1) {
+        text.reserve(len);
+    }
+    decoder.decode_to_string_without_replacement(core::slice::from_ref(byte), text, last)
+}
+
+/// Looks up an encoding by label, accepting the names glibc gives some encodings besides
+/// those of the WHATWG Encoding Standard.
+pub(crate) fn lookup(label: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    if let Some(encoding) = encoding_rs::Encoding::for_label_no_replacement(label) {
+        return Some(encoding);
+    }
+
+    // Helpful: https://github.com/servo/libparserutils/blob/master/build/Aliases
+    let encoding = match label {
+        b"Big5HKSCS" => encoding_rs::BIG5,
+        b"CP949" => encoding_rs::EUC_KR,
+        // See https://en.wikipedia.org/wiki/GB_18030 and
+        // https://www.ibm.com/support/knowledgecenter/en/ssw_aix_72/com.ibm.aix.nlsgdrf/ibm-eucCN.htm
+        b"eucCN" => encoding_rs::GB18030,
+        b"eucJP" => encoding_rs::EUC_JP,
+        b"eucKR" => encoding_rs::EUC_KR,
+        _ => return None,
+    };
+    Some(encoding)
+}
@@ -1,6 +1,7 @@
 use core::fmt;
 
 use crate::error_kind::ErrorKind;
+use crate::parsing::ParseReason;
 #[cfg(not(feature = "std"))]
 use crate::strings::ErrString;
 
@@ -56,6 +57,59 @@ impl Error {
         }
     }
 
+    pub(crate) fn parse_format_code<S>(input: S) -> Error
+    where
+        S: AsRef<str>,
+    {
+        #[cfg(feature = "std")]
+        return Error {
+            kind: ErrorKind::ParseFormatCode(input.as_ref().into()),
+        };
+
+        #[cfg(not(feature = "std"))]
+        return Error {
+            kind: ErrorKind::ParseFormatCode(ErrString::truncated(input.as_ref()).into()),
+        };
+    }
+
+    pub(crate) fn parse_format_spec<S>(input: S) -> Error
+    where
+        S: AsRef<str>,
+    {
+        #[cfg(feature = "std")]
+        return Error {
+            kind: ErrorKind::ParseFormatSpec(input.as_ref().into()),
+        };
+
+        #[cfg(not(feature = "std"))]
+        return Error {
+            kind: ErrorKind::ParseFormatSpec(ErrString::truncated(input.as_ref()).into()),
+        };
+    }
+
+    pub(crate) fn parse_formatted<S>(input: S, position: usize, reason: ParseReason) -> Error
+    where
+        S: AsRef<str>,
+    {
+        #[cfg(feature = "std")]
+        return Error {
+            kind: ErrorKind::ParseFormatted {
+                input: input.as_ref().into(),
+                position,
+                reason,
+            },
+        };
+
+        #[cfg(not(feature = "std"))]
+        return Error {
+            kind: ErrorKind::ParseFormatted {
+                input: ErrString::truncated(input.as_ref()).into(),
+                position,
+                reason,
+            },
+        };
+    }
+
     pub(crate) fn parse_locale<S>(input: S) -> Error
     where
         S: AsRef<str>,
@@ -86,6 +140,36 @@ impl Error {
         };
     }
 
+    pub(crate) fn parse_pattern<S>(input: S) -> Error
+    where
+        S: AsRef<str>,
+    {
+        #[cfg(feature = "std")]
+        return Error {
+            kind: ErrorKind::ParsePattern(input.as_ref().into()),
+        };
+
+        #[cfg(not(feature = "std"))]
+        return Error {
+            kind: ErrorKind::ParsePattern(ErrString::truncated(input.as_ref()).into()),
+        };
+    }
+
+    pub(crate) fn parse_printf_spec<S>(input: S) -> Error
+    where
+        S: AsRef<str>,
+    {
+        #[cfg(feature = "std")]
+        return Error {
+            kind: ErrorKind::ParsePrintfSpec(input.as_ref().into()),
+        };
+
+        #[cfg(not(feature = "std"))]
+        return Error {
+            kind: ErrorKind::ParsePrintfSpec(ErrString::truncated(input.as_ref()).into()),
+        };
+    }
+
     #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
     pub(crate) fn system_invalid_return<S, T>(function_name: S, message: T) -> Error
     where
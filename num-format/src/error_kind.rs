@@ -3,6 +3,7 @@ use core::fmt;
 #[cfg(not(feature = "std"))]
 use arrayvec::ArrayString;
 
+use crate::parsing::ParseReason;
 #[cfg(not(feature = "std"))]
 use crate::strings::MAX_ERR_LEN;
 
@@ -31,6 +32,44 @@ pub enum ErrorKind {
     /// Other miscellaneous error.
     Other(ArrayString<MAX_ERR_LEN>),
 
+    #[cfg(feature = "std")]
+    /// Failed to parse input into a valid (and supported) spreadsheet number format code.
+    ParseFormatCode(String),
+
+    #[cfg(not(feature = "std"))]
+    /// Failed to parse input into a valid (and supported) spreadsheet number format code.
+    ParseFormatCode(ArrayString<MAX_ERR_LEN>),
+
+    #[cfg(feature = "std")]
+    /// Failed to parse input into a valid (and supported) format specification.
+    ParseFormatSpec(String),
+
+    #[cfg(not(feature = "std"))]
+    /// Failed to parse input into a valid (and supported) format specification.
+    ParseFormatSpec(ArrayString<MAX_ERR_LEN>),
+
+    #[cfg(feature = "std")]
+    /// Failed to parse a formatted number, at the given byte offset of the input.
+    ParseFormatted {
+        /// The input.
+        input: String,
+        /// Byte offset of the input where parsing failed.
+        position: usize,
+        /// Why parsing failed.
+        reason: ParseReason,
+    },
+
+    #[cfg(not(feature = "std"))]
+    /// Failed to parse a formatted number, at the given byte offset of the input.
+    ParseFormatted {
+        /// The input (truncated if too long).
+        input: ArrayString<MAX_ERR_LEN>,
+        /// Byte offset of the input where parsing failed.
+        position: usize,
+        /// Why parsing failed.
+        reason: ParseReason,
+    },
+
     #[cfg(feature = "std")]
     /// Failed to parse input into a valid locale.
     ParseLocale(String),
@@ -47,6 +86,22 @@ pub enum ErrorKind {
     /// Failed to parse input into a number.
     ParseNumber(ArrayString<MAX_ERR_LEN>),
 
+    #[cfg(feature = "std")]
+    /// Failed to parse input into a valid (and supported) number pattern.
+    ParsePattern(String),
+
+    #[cfg(not(feature = "std"))]
+    /// Failed to parse input into a valid (and supported) number pattern.
+    ParsePattern(ArrayString<MAX_ERR_LEN>),
+
+    #[cfg(feature = "std")]
+    /// Failed to parse input into a valid (and supported) C `printf` conversion specification.
+    ParsePrintfSpec(String),
+
+    #[cfg(not(feature = "std"))]
+    /// Failed to parse input into a valid (and supported) C `printf` conversion specification.
+    ParsePrintfSpec(ArrayString<MAX_ERR_LEN>),
+
     #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
     /// Call to C standard library or Windows API unexpectedly returned invalid data.
     SystemInvalidReturn {
@@ -86,10 +141,42 @@ impl fmt::Display for ErrorKind {
 
             Other(ref message) => write!(f, "{}", message),
 
+            ParseFormatCode(ref input) => write!(
+                f,
+                "Failed to parse {} into a valid number format code.",
+                input
+            ),
+
+            ParseFormatSpec(ref input) => write!(
+                f,
+                "Failed to parse {} into a valid format specification.",
+                input
+            ),
+
+            ParseFormatted {
+                ref input,
+                position,
+                reason,
+            } => write!(
+                f,
+                "Failed to parse {} into a number at byte {}: {}.",
+                input, position, reason
+            ),
+
             ParseLocale(ref input) => write!(f, "Failed to parse {} into a valid locale.", input),
 
             ParseNumber(ref input) => write!(f, "Failed to parse {} into a number.", input),
 
+            ParsePattern(ref input) => {
+                write!(f, "Failed to parse {} into a valid number pattern.", input)
+            }
+
+            ParsePrintfSpec(ref input) => write!(
+                f,
+                "Failed to parse {} into a valid printf conversion specification.",
+                input
+            ),
+
             #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
             SystemInvalidReturn { message, .. } => write!(f, "{}", message),
 
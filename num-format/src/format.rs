@@ -1,5 +1,6 @@
 use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
 use crate::Grouping;
+use crate::NumberPattern;
 use crate::DIGIT_TABLE;
 
 use std::ptr;
@@ -71,3 +72,139 @@ pub trait Format {
     /// Returns the string representation of a thousands separator.
     fn separator(&self) -> SeparatorStr<'_>;
 }
+
+/// A [`Format`] as values are written with it, along with what value wrappers add to it (e.g.
+/// the fraction grouping of a [`FractionGrouped`] or the pattern of a [`Patterned`]). Not
+/// nameable outside of the crate; the entry points write with the formats they are given as is.
+///
+/// [`Format`]: trait.Format.html
+/// [`FractionGrouped`]: ../struct.FractionGrouped.html
+/// [`Patterned`]: ../struct.Patterned.html
+pub trait WriteFormat: Format {
+    /// Returns the number of fraction digits in each group and the separator between groups,
+    /// or `(0, "")` if fraction digits are not grouped.
+    fn fraction_grouping(&self) -> (usize, &str) {
+        (0, "")
+    }
+
+    /// Returns the [`NumberPattern`] to write integers, floats, and decimals with, if any.
+    ///
+    /// [`NumberPattern`]: ../struct.NumberPattern.html
+    fn number_pattern(&self) -> Option<&NumberPattern> {
+        None
+    }
+}
+
+/// A [`Format`] that values are written with as is.
+///
+/// [`Format`]: trait.Format.html
+#[derive(Debug)]
+pub(crate) struct Plain<'a, F>(pub(crate) &'a F);
+
+impl<F> Format for Plain<'_, F>
+where
+    F: Format,
+{
+    fn decimal(&self) -> DecimalStr<'_> {
+        self.0.decimal()
+    }
+
+    fn grouping(&self) -> Grouping {
+        self.0.grouping()
+    }
+
+    fn infinity(&self) -> InfinityStr<'_> {
+        self.0.infinity()
+    }
+
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        self.0.minus_sign()
+    }
+
+    fn nan(&self) -> NanStr<'_> {
+        self.0.nan()
+    }
+
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        self.0.plus_sign()
+    }
+
+    fn separator(&self) -> SeparatorStr<'_> {
+        self.0.separator()
+    }
+}
+
+impl<F> WriteFormat for Plain<'_, F> where F: Format {}
+
+/// Returns the separator the format writes between groups of integer digits, the size of the
+/// last group, and the size of the groups before it, or `None` if it doesn't group digits.
+pub(crate) fn int_groups<F: Format>(format: &F) -> Option<(&str, usize, usize)> {
+    let separator = format.separator().into_str();
+    match format.grouping() {
+        _ if separator.is_empty() => None,
+        Grouping::Standard => Some((separator, 3, 3)),
+        Grouping::Indian => Some((separator, 3, 2)),
+        Grouping::Posix => None,
+    }
+}
+
+/// Writes a decimal number, given as its ASCII integer and fraction digits, into `w` piece by
+/// piece, using the format's minus sign, grouping, decimal, and fraction grouping. The decimal
+/// is omitted if there are no fraction digits.
+pub(crate) fn write_decimal<F, W>(
+    is_negative: bool,
+    int_digits: &str,
+    frac_digits: &str,
+    format: &F,
+    mut w: W,
+) where
+    F: WriteFormat,
+    W: FnMut(&str),
+{
+    if is_negative {
+        w(format.minus_sign().into_str());
+    }
+
+    let separator = format.separator().into_str();
+    let (step, tail) = match format.grouping() {
+        Grouping::Standard => (3, 3),
+        Grouping::Indian => (2, 3),
+        Grouping::Posix => (0, 0),
+    };
+    if separator.is_empty() || step == 0 || int_digits.len() <= tail {
+        w(int_digits);
+    } else {
+        let head_len = int_digits.len() - tail;
+        let first = match head_len % step {
+            0 => step,
+            n => n,
+        };
+        w(&int_digits[..first]);
+        let mut i = first;
+        while i < head_len {
+            w(separator);
+            w(&int_digits[i..i + step]);
+            i += step;
+        }
+        w(separator);
+        w(&int_digits[head_len..]);
+    }
+
+    if frac_digits.is_empty() {
+        return;
+    }
+    w(format.decimal().into_str());
+
+    let (frac_step, frac_separator) = format.fraction_grouping();
+    if frac_separator.is_empty() || frac_step == 0 {
+        w(frac_digits);
+    } else {
+        let mut i = 0;
+        while i + frac_step < frac_digits.len() {
+            w(&frac_digits[i..i + frac_step]);
+            w(frac_separator);
+            i += frac_step;
+        }
+        w(&frac_digits[i..]);
+    }
+}
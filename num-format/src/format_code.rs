@@ -0,0 +1,653 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use arrayvec::ArrayString;
+
+use crate::error::Error;
+
+// Spreadsheets limit number format codes to 255 characters
+pub(crate) const MAX_CODE_LEN: usize = 255;
+
+// The largest fixed denominator of a fraction (e.g. `# ?/16`) and the most digits a fraction's
+// denominator can have
+const MAX_DENOMINATOR: u32 = 999_999_999;
+const MAX_DENOMINATOR_DIGITS: usize = 9;
+
+const COLORS: [&str; 8] = [
+    "Black", "Blue", "Cyan", "Green", "Magenta", "Red", "White", "Yellow",
+];
+
+/// Type for representing a spreadsheet number format code (e.g. `#,##0.00_);[Red](#,##0.00)`,
+/// `0.0%`, `0.00E+00`, or `# ?/?`), as used by Excel and other spreadsheets.
+///
+/// A code has up to four sections separated by semicolons: one for positive numbers, one for
+/// negative numbers, one for zero, and one for text. With one section, negative numbers are
+/// written with a minus sign in front of the section; with two, the first is also used for
+/// zero. Negative numbers written with a section of their own are written without a minus
+/// sign. Sections can instead start with conditions (e.g. `[>=1000]`), in which case a number
+/// is written with the first section whose condition it meets (or that has no condition), with
+/// a minus sign if it's negative, and with `General` if there is no such section.
+///
+/// Each section is made up of:
+///
+/// - digit placeholders: `0` (a digit, even if it's an insignificant zero), `#` (a digit, but
+///   not an insignificant zero), and `?` (a digit, or a space in place of an insignificant
+///   zero). Integer digits that don't fit into the placeholders are written by the first one.
+/// - `.`, which writes the decimal of the [`Format`].
+/// - `,` between integer placeholders, which groups integer digits with the grouping and
+///   separator of the [`Format`], and `,` after the last integer placeholder, which divides the
+///   number by a thousand.
+/// - `%`, which multiplies the number by a hundred and writes a percent sign.
+/// - `E+`, `E-`, `e+`, or `e-` followed by digit placeholders for scientific notation. `E+`
+///   writes the sign of the exponent even if it's positive. If the integer placeholders
+///   include `#`, the exponent is a multiple of their number (e.g. `##0.0E+0`).
+/// - `/` between digit placeholders for fractions (e.g. `# ?/?` or `?/8`), with the numerator
+///   placeholders after any integer placeholders. The denominator is the closest one with up
+///   to as many digits as its placeholders, or the number after the slash.
+/// - `General`, which writes the number in up to eleven characters, like a spreadsheet does
+///   in a column of the default width.
+/// - `@`, where the text section writes text.
+/// - literal text: text in double quotes, a character after a backslash, any of
+///   `$-+()/:!^&'~{}<>=` and space, and any non-ASCII character. `_` followed by a character
+///   writes a space, `*` followed by a character (a fill to the width of the cell) writes
+///   nothing, and `[$€-407]` writes `€`.
+/// - `[Red]`, `[Blue]`, ... or `[Color10]`, which set the color of the section (see
+///   [`color`]).
+///
+/// Like spreadsheets, numbers are first rounded to fifteen significant digits, then rounded
+/// half away from zero. Infinity and NaN are written with the symbols of the [`Format`]. Codes
+/// for dates and times are not supported.
+///
+/// Numbers are written with a code by wrapping them in [`Coded`].
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Coded, Error, FormatCode, Locale};
+///
+/// fn main() -> Result<(), Error> {
+///     let code = FormatCode::new("#,##0.00_);[Red](#,##0.00)")?;
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&Coded::new(1234.5, code), &Locale::en);
+///     assert_eq!("1,234.50 ", buf.as_str());
+///
+///     buf.write_formatted(&Coded::new(-1234.5, code), &Locale::de);
+///     assert_eq!("(1.234,50)", buf.as_str());
+///     assert_eq!(Some("Red"), code.color(-1234.5));
+///
+///     buf.write_formatted(&Coded::new(0.1234, FormatCode::new("0.0%")?), &Locale::en);
+///     assert_eq!("12.3%", buf.as_str());
+///
+///     buf.write_formatted(&Coded::new(12345, FormatCode::new("0.00E+00")?), &Locale::en);
+///     assert_eq!("1.23E+04", buf.as_str());
+///
+///     buf.write_formatted(&Coded::new(5.25, FormatCode::new("# ?/?")?), &Locale::en);
+///     assert_eq!("5 1/4", buf.as_str());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Coded`]: struct.Coded.html
+/// [`color`]: struct.FormatCode.html#method.color
+/// [`Format`]: trait.Format.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    all(feature = "with-serde", feature = "std"),
+    derive(Serialize, Deserialize),
+    serde(try_from = "&str", into = "String")
+)]
+pub struct FormatCode {
+    pub(crate) code: ArrayString<MAX_CODE_LEN>,
+    // the start and end of each section in the code
+    pub(crate) sections: [(u8, u8); 4],
+    pub(crate) len: u8,
+}
+
+impl FormatCode {
+    /// Parses a [`FormatCode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code is longer than 255 bytes, or if it's not a valid (and
+    /// supported) number format code.
+    ///
+    /// [`FormatCode`]: struct.FormatCode.html
+    pub fn new<S>(s: S) -> Result<FormatCode, Error>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        if s.is_empty() {
+            return Err(Error::parse_format_code(s));
+        }
+        let code = ArrayString::from(s).map_err(|_| Error::capacity(s.len(), MAX_CODE_LEN))?;
+        let mut format_code = FormatCode {
+            code,
+            sections: [(0, 0); 4],
+            len: 0,
+        };
+
+        let mut start = 0;
+        let mut tokens = Tokens::new(s);
+        loop {
+            let token = tokens.next().map(|token| token.ok_or(()));
+            let end = match token {
+                Some(Ok(Token::Separator)) => tokens.pos - 1,
+                Some(Ok(_)) => continue,
+                Some(Err(())) => return Err(Error::parse_format_code(s)),
+                None => s.len(),
+            };
+            if format_code.len == 4 {
+                return Err(Error::parse_format_code(s));
+            }
+            format_code.sections[format_code.len as usize] = (start as u8, end as u8);
+            format_code.len += 1;
+            if Layout::new(&s[start..end]).is_none() {
+                return Err(Error::parse_format_code(s));
+            }
+            if token.is_none() {
+                break;
+            }
+            start = end + 1;
+        }
+
+        Ok(format_code)
+    }
+
+    /// Returns the code as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the name of the color (e.g. `Red` or `Color10`) of the section `value` is
+    /// written with, if the section has one.
+    pub fn color(&self, value: f64) -> Option<&str> {
+        let (index, _) = self.select(value)?;
+        self.layout(index).color.map(|(start, end)| {
+            let (offset, _) = self.sections[index];
+            &self.code[offset as usize + start..offset as usize + end]
+        })
+    }
+
+    /// Returns the number of sections.
+    pub fn section_count(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Writes `text` with the text section of the code (e.g. `"Name: "@`), or as is if the
+    /// code has no text section.
+    pub fn write_text<W>(&self, text: &str, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        let index = self.len as usize - 1;
+        if !self.layout(index).text {
+            return w.write_str(text);
+        }
+        for token in Tokens::new(self.section(index)).flatten() {
+            match token {
+                Token::Literal(s) => w.write_str(s)?,
+                Token::Text => w.write_str(text)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the text of a section.
+    pub(crate) fn section(&self, index: usize) -> &str {
+        let (start, end) = self.sections[index];
+        &self.code[start as usize..end as usize]
+    }
+
+    /// Returns the layout of a section.
+    pub(crate) fn layout(&self, index: usize) -> Layout {
+        Layout::new(self.section(index)).unwrap()
+    }
+
+    /// Returns the index of the section to write `value` with, and whether or not to write a
+    /// minus sign in front of it, or `None` if no section's condition is met.
+    pub(crate) fn select(&self, value: f64) -> Option<(usize, bool)> {
+        let is_negative = value < 0.0;
+        let mut count = self.len as usize;
+        if count > 1 && self.layout(count - 1).text || count == 4 {
+            count -= 1;
+        }
+
+        let is_conditional = (0..count.min(2)).any(|i| self.layout(i).condition.is_some());
+        if is_conditional {
+            return (0..count).find_map(|i| match self.layout(i).condition {
+                Some(condition) if !condition.matches(value) => None,
+                _ => Some((i, is_negative)),
+            });
+        }
+
+        let selection = match count {
+            1 => (0, is_negative),
+            2 if is_negative => (1, false),
+            2 => (0, false),
+            _ if is_negative => (1, false),
+            _ if value == 0.0 => (2, false),
+            _ => (0, false),
+        };
+        Some(selection)
+    }
+}
+
+impl fmt::Display for FormatCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FormatCode {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        FormatCode::new(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FormatCode> for String {
+    fn from(code: FormatCode) -> Self {
+        code.to_string()
+    }
+}
+
+impl FromStr for FormatCode {
+    type Err = Error;
+
+    /// Same as [`FormatCode::new`].
+    ///
+    /// [`FormatCode::new`]: struct.FormatCode.html#method.new
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FormatCode::new(s)
+    }
+}
+
+/// Type for representing a number written with a spreadsheet [`FormatCode`] (e.g. `-1234.5`
+/// with `#,##0.00_);[Red](#,##0.00)`). Implements [`ToFormattedStr`] for `f32`, `f64`, and all
+/// the integer types in the standard library.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Coded, FormatCode, Locale};
+///
+/// fn main() {
+///     let code = FormatCode::new("[>=1000]#,##0,\"K\";0").unwrap();
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&Coded::new(25_300, code), &Locale::en);
+///     assert_eq!("25K", buf.as_str());
+///
+///     buf.write_formatted(&Coded::new(999, code), &Locale::en);
+///     assert_eq!("999", buf.as_str());
+/// }
+/// ```
+///
+/// [`FormatCode`]: struct.FormatCode.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    all(feature = "with-serde", feature = "std"),
+    derive(Serialize, Deserialize)
+)]
+pub struct Coded<N> {
+    value: N,
+    code: FormatCode,
+}
+
+impl<N> Coded<N> {
+    /// Constructs a [`Coded`] for the given value and code.
+    ///
+    /// [`Coded`]: struct.Coded.html
+    pub fn new(value: N, code: FormatCode) -> Coded<N> {
+        Coded { value, code }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`FormatCode`].
+    ///
+    /// [`FormatCode`]: struct.FormatCode.html
+    pub fn code(&self) -> &FormatCode {
+        &self.code
+    }
+}
+
+/// A condition at the start of a section (e.g. `[>=1000]`).
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Condition {
+    op: &'static str,
+    value: f64,
+}
+
+impl Condition {
+    fn matches(self, value: f64) -> bool {
+        match self.op {
+            "<" => value < self.value,
+            "<=" => value <= self.value,
+            ">" => value > self.value,
+            ">=" => value >= self.value,
+            "=" => value == self.value,
+            _ => value != self.value,
+        }
+    }
+}
+
+/// A piece of a section.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Token<'a> {
+    /// `0`, `#`, or `?`
+    Digit(u8),
+    Point,
+    Comma,
+    Percent,
+    Exponent {
+        uppercase: bool,
+        plus: bool,
+    },
+    Slash,
+    General,
+    Text,
+    Literal(&'a str),
+    Color,
+    Condition(Condition),
+    Separator,
+}
+
+/// Iterator over the tokens of a code, which yields `None` for anything that's not valid.
+pub(crate) struct Tokens<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(s: &'a str) -> Tokens<'a> {
+        Tokens { s, pos: 0 }
+    }
+
+    /// Returns the next character as a `&str`, if any.
+    fn next_char(&mut self) -> Option<&'a str> {
+        let c = self.s[self.pos..].chars().next()?;
+        let start = self.pos;
+        self.pos += c.len_utf8();
+        Some(&self.s[start..self.pos])
+    }
+
+    fn bracket(&mut self) -> Option<Token<'a>> {
+        let len = self.s[self.pos..].find(']')?;
+        let content = &self.s[self.pos..self.pos + len];
+        self.pos += len + 1;
+
+        // A currency symbol and locale (e.g. `[$€-407]`)
+        if let Some(currency) = content.strip_prefix('$') {
+            let end = currency.find('-').unwrap_or(currency.len());
+            return Some(Token::Literal(&currency[..end]));
+        }
+
+        // A condition (e.g. `[>=1000]`)
+        for op in &["<=", ">=", "<>", "<", ">", "="] {
+            if let Some(value) = content.strip_prefix(op) {
+                let value = value.trim().parse().ok()?;
+                return Some(Token::Condition(Condition { op, value }));
+            }
+        }
+
+        // A color (e.g. `[Red]` or `[Color10]`)
+        let is_color = COLORS
+            .iter()
+            .any(|color| color.eq_ignore_ascii_case(content))
+            || content.len() > 5
+                && content[..5].eq_ignore_ascii_case("color")
+                && matches!(content[5..].parse::<u8>(), Ok(1..=56));
+        if is_color {
+            return Some(Token::Color);
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Option<Token<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos;
+        let c = self.next_char()?;
+        let token = match c.as_bytes()[0] {
+            b'0' | b'#' | b'?' => Some(Token::Digit(c.as_bytes()[0])),
+            b'.' => Some(Token::Point),
+            b',' => Some(Token::Comma),
+            b'%' => Some(Token::Percent),
+            b'/' => Some(Token::Slash),
+            b'@' => Some(Token::Text),
+            b';' => Some(Token::Separator),
+            b'"' => self.s[self.pos..].find('"').map(|len| {
+                let literal = &self.s[self.pos..self.pos + len];
+                self.pos += len + 1;
+                Token::Literal(literal)
+            }),
+            b'\\' => self.next_char().map(Token::Literal),
+            b'_' => self.next_char().map(|_| Token::Literal(" ")),
+            b'*' => self.next_char().map(|_| Token::Literal("")),
+            b'E' | b'e' => match self.next_char() {
+                Some(sign @ "+") | Some(sign @ "-") => Some(Token::Exponent {
+                    uppercase: c == "E",
+                    plus: sign == "+",
+                }),
+                _ => None,
+            },
+            b'G' | b'g' => {
+                let end = start + "General".len();
+                if self.s.len() >= end && self.s[start..end].eq_ignore_ascii_case("General") {
+                    self.pos = end;
+                    Some(Token::General)
+                } else {
+                    None
+                }
+            }
+            b'[' => self.bracket(),
+            b'1'..=b'9'
+            | b' '
+            | b'$'
+            | b'-'
+            | b'+'
+            | b'('
+            | b')'
+            | b':'
+            | b'!'
+            | b'^'
+            | b'&'
+            | b'\''
+            | b'~'
+            | b'{'
+            | b'}'
+            | b'<'
+            | b'>'
+            | b'=' => Some(Token::Literal(c)),
+            _ if !c.is_ascii() => Some(Token::Literal(c)),
+            _ => None,
+        };
+        Some(token)
+    }
+}
+
+/// Where the digit placeholders of a section are, which says how a number is written with it.
+/// Positions are the indices of tokens.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct Layout {
+    pub(crate) color: Option<(usize, usize)>,
+    pub(crate) condition: Option<Condition>,
+    pub(crate) general: bool,
+    pub(crate) text: bool,
+    // the number of digit placeholders for the integer, fraction, and exponent
+    pub(crate) int_count: usize,
+    pub(crate) frac_count: usize,
+    pub(crate) exp_count: usize,
+    // whether or not there is a `#` among the integer placeholders
+    pub(crate) int_hash: bool,
+    pub(crate) grouping: bool,
+    // the power of ten the number is multiplied by
+    pub(crate) scale: i32,
+    pub(crate) point: Option<usize>,
+    pub(crate) exponent: Option<usize>,
+    // the slash of a fraction, where its numerator starts, the number of numerator and
+    // denominator placeholders, and its fixed denominator (if any)
+    pub(crate) slash: Option<usize>,
+    pub(crate) numerator: usize,
+    pub(crate) num_count: usize,
+    pub(crate) den_count: usize,
+    pub(crate) denominator: Option<u32>,
+}
+
+impl Layout {
+    /// Works out the layout of a section, returning `None` if it's not valid.
+    pub(crate) fn new(section: &str) -> Option<Layout> {
+        let mut layout = Layout::default();
+
+        // Find the decimal point, exponent, and slash, and the conditions and colors
+        let mut tokens = Tokens::new(section);
+        let mut index = 0;
+        let mut has_digits = false;
+        while let Some(token) = tokens.next() {
+            let pos = tokens.pos;
+            match token? {
+                Token::Digit(_) => has_digits = true,
+                Token::Point if layout.point.is_none() && layout.exponent.is_none() => {
+                    layout.point = Some(index)
+                }
+                Token::Exponent { .. } if layout.exponent.is_none() && has_digits => {
+                    layout.exponent = Some(index)
+                }
+                Token::Slash if has_digits && layout.slash.is_none() => layout.slash = Some(index),
+                Token::Slash if layout.slash.is_some() => return None,
+                Token::Point | Token::Exponent { .. } => return None,
+                Token::General => layout.general = true,
+                Token::Text => layout.text = true,
+                Token::Color if layout.color.is_none() => {
+                    let end = section[..pos].rfind(']').unwrap_or(0);
+                    let open = section[..end].rfind('[').unwrap_or(0);
+                    layout.color = Some((open + 1, end));
+                }
+                Token::Condition(condition) if layout.condition.is_none() => {
+                    layout.condition = Some(condition)
+                }
+                Token::Color | Token::Condition(_) | Token::Separator => return None,
+                _ => {}
+            }
+            index += 1;
+        }
+        if (layout.general || layout.text) && has_digits || layout.general && layout.text {
+            return None;
+        }
+
+        // A fraction has its numerator placeholders just before the slash, and a denominator
+        // of placeholders or a number just after it
+        if let Some(slash) = layout.slash {
+            if layout.point.is_some() || layout.exponent.is_some() {
+                return None;
+            }
+            let mut numerator = slash;
+            let mut denominator: Option<u32> = None;
+            for (i, token) in Tokens::new(section).flatten().enumerate() {
+                match token {
+                    Token::Digit(_) if i < slash => {
+                        if numerator == slash || numerator + layout.num_count != i {
+                            numerator = i;
+                            layout.num_count = 0;
+                        }
+                        layout.num_count += 1;
+                    }
+                    Token::Literal(s) if i == slash + 1 && is_digit(s) => {
+                        denominator = Some(u32::from(s.as_bytes()[0] - b'0'));
+                    }
+                    Token::Digit(c) if i > slash && denominator.is_some() => {
+                        if c != b'0' || layout.den_count > 0 {
+                            return None;
+                        }
+                        denominator = denominator?.checked_mul(10);
+                    }
+                    Token::Literal(s) if i > slash && denominator.is_some() => {
+                        if is_digit(s) && layout.den_count == 0 {
+                            let digit = u32::from(s.as_bytes()[0] - b'0');
+                            denominator = denominator?.checked_mul(10)?.checked_add(digit);
+                        } else {
+                            layout.den_count = 1;
+                        }
+                    }
+                    Token::Digit(_) if i > slash => layout.den_count += 1,
+                    _ => {}
+                }
+            }
+            if numerator + layout.num_count != slash {
+                return None;
+            }
+            layout.numerator = numerator;
+            match denominator {
+                Some(denominator) => {
+                    if denominator == 0 || denominator > MAX_DENOMINATOR {
+                        return None;
+                    }
+                    layout.den_count = 0;
+                    layout.denominator = Some(denominator);
+                }
+                None if layout.den_count == 0 || layout.den_count > MAX_DENOMINATOR_DIGITS => {
+                    return None
+                }
+                None => {}
+            }
+        }
+
+        // Count the other placeholders, and find out what each comma does
+        let int_end = layout
+            .point
+            .or(layout.exponent)
+            .or(layout.slash.map(|_| layout.numerator))
+            .unwrap_or(usize::MAX);
+        let mut last_int = None;
+        let (mut commas, mut frac_commas) = (0, 0);
+        for (i, token) in Tokens::new(section).flatten().enumerate() {
+            match token {
+                Token::Digit(c) if i < int_end => {
+                    layout.int_count += 1;
+                    layout.int_hash |= c == b'#';
+                    layout.grouping |= commas > 0 && last_int.is_some();
+                    last_int = Some(i);
+                    commas = 0;
+                }
+                Token::Digit(_) if matches!(layout.exponent, Some(exponent) if i > exponent) => {
+                    layout.exp_count += 1
+                }
+                Token::Digit(_) if matches!(layout.point, Some(point) if i > point) => {
+                    layout.frac_count += 1;
+                    frac_commas = 0;
+                }
+                Token::Comma if i < int_end => commas += 1,
+                Token::Comma if matches!(layout.point, Some(point) if i > point) => {
+                    frac_commas += 1
+                }
+                Token::Percent => layout.scale += 2,
+                _ => {}
+            }
+        }
+        if last_int.is_some() {
+            layout.scale -= 3 * commas;
+        }
+        if layout.frac_count > 0 {
+            layout.scale -= 3 * frac_commas;
+        }
+        if layout.exponent.is_some() && layout.exp_count == 0 {
+            return None;
+        }
+        Some(layout)
+    }
+}
+
+/// Returns whether or not a literal is a single digit.
+pub(crate) fn is_digit(s: &str) -> bool {
+    s.len() == 1 && s.as_bytes()[0].is_ascii_digit()
+}
@@ -0,0 +1,519 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::Error;
+
+/// Type for specifying where a [`FormatSpec`] puts the padding that fills a number out to its
+/// width.
+///
+/// [`FormatSpec`]: struct.FormatSpec.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum SpecAlign {
+    /// Padding after the number (`<`)
+    Left,
+    /// Padding before the number (`>`), the default
+    Right,
+    /// Padding on both sides of the number, with the extra character on the right (`^`)
+    Center,
+    /// Padding between the sign (and prefix) and the digits (`=`), as in `-000042`
+    AfterSign,
+}
+
+/// Type for specifying when a [`FormatSpec`] writes a sign.
+///
+/// [`FormatSpec`]: struct.FormatSpec.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum SpecSign {
+    /// A sign for negative numbers only (`-`), the default
+    Negative,
+    /// A sign for both positive and negative numbers (`+`)
+    Always,
+    /// A space for positive numbers and a sign for negative numbers (` `)
+    Space,
+}
+
+/// Type for specifying how a [`FormatSpec`] presents a number (the spec's type).
+///
+/// [`FormatSpec`]: struct.FormatSpec.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum SpecType {
+    /// Integers in base 10 (`d`)
+    Decimal,
+    /// Numbers with the symbols and grouping of a [`Format`]; integers as with `d` and floats
+    /// as with `g` (`n`)
+    ///
+    /// [`Format`]: trait.Format.html
+    Number,
+    /// Fixed-point notation (`f`, or `F` for `NAN` and `INF`)
+    Fixed {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Scientific notation (`e`, or `E` for an uppercase exponent)
+    Exponent {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Fixed-point or scientific notation depending on the exponent, without trailing zeros
+    /// (`g`, or `G` for uppercase)
+    General {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Fixed-point notation of the number times a hundred, followed by a percent sign (`%`)
+    Percent,
+    /// Integers in base 16 (`x`, or `X` for uppercase)
+    Hex {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Integers in base 2 (`b`)
+    Binary,
+    /// Integers in base 8 (`o`)
+    Octal,
+}
+
+impl SpecType {
+    /// Returns `true` if the type is only for integers (`d`, `x`, `X`, `b`, or `o`).
+    pub fn is_integer(self) -> bool {
+        matches!(
+            self,
+            SpecType::Decimal | SpecType::Hex { .. } | SpecType::Binary | SpecType::Octal
+        )
+    }
+
+    fn from_char(c: char) -> Option<SpecType> {
+        let ty = match c {
+            'd' => SpecType::Decimal,
+            'n' => SpecType::Number,
+            'f' | 'F' => SpecType::Fixed {
+                uppercase: c == 'F',
+            },
+            'e' | 'E' => SpecType::Exponent {
+                uppercase: c == 'E',
+            },
+            'g' | 'G' => SpecType::General {
+                uppercase: c == 'G',
+            },
+            '%' => SpecType::Percent,
+            'x' | 'X' => SpecType::Hex {
+                uppercase: c == 'X',
+            },
+            'b' => SpecType::Binary,
+            'o' => SpecType::Octal,
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            SpecType::Decimal => 'd',
+            SpecType::Number => 'n',
+            SpecType::Fixed { uppercase } => upper('f', uppercase),
+            SpecType::Exponent { uppercase } => upper('e', uppercase),
+            SpecType::General { uppercase } => upper('g', uppercase),
+            SpecType::Percent => '%',
+            SpecType::Hex { uppercase } => upper('x', uppercase),
+            SpecType::Binary => 'b',
+            SpecType::Octal => 'o',
+        }
+    }
+}
+
+/// Type for representing a format specification in Python's format specification
+/// mini-language (e.g. `>12,.2f` or `_d`), the part after the colon in `"{:>12,.2f}"`.
+///
+/// A spec is written `[[fill]align][sign][#][0][width][grouping][.precision][type]`:
+///
+/// - `fill` is any character, and `align` is one of `<`, `>`, `^`, or `=` (see [`SpecAlign`]).
+/// - `sign` is one of `-`, `+`, or ` ` (see [`SpecSign`]).
+/// - `#` asks for the alternate form: a `0x`, `0b`, or `0o` prefix for integers written in
+///   another base, and a decimal point that is always written for floats (trailing zeros are
+///   kept by `g` too).
+/// - `0` pads with zeros after the sign, unless a fill or alignment says otherwise.
+/// - `width` is the minimum number of characters to write.
+/// - `grouping` is `,` or `_`, the separator written between groups of three integer digits
+///   (four for `x`, `b`, and `o`, which only take `_`).
+/// - `precision` is the number of fraction digits for `f`, `e`, and `%`, and of significant
+///   digits for `g`, `n`, and floats without a type.
+/// - `type` is one of `d`, `n`, `f`, `F`, `e`, `E`, `g`, `G`, `%`, `x`, `X`, `b`, or `o` (see
+///   [`SpecType`]).
+///
+/// Numbers are written with a spec by wrapping them in [`Specified`]. They come out just as
+/// Python's `format` writes them, except that the `n` type takes its minus sign, plus sign,
+/// grouping, separator, decimal, and NaN and infinity symbols from the [`Format`] the number is
+/// written with, rather than from the C locale.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, FormatSpec, Locale, Specified, ToFormattedString};
+///
+/// fn main() -> Result<(), Error> {
+///     let spec = FormatSpec::new(">12,.2f")?;
+///     let s = Specified::new(1234567.891, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("1,234,567.89", s);
+///
+///     let spec: FormatSpec = "*^+9_d".parse()?;
+///     let s = Specified::new(12345, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("*+12_345*", s);
+///
+///     let spec = FormatSpec::new("#010x")?;
+///     let s = Specified::new(255u8, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("0x000000ff", s);
+///
+///     let spec = FormatSpec::new("n")?;
+///     let s = Specified::new(-1234567, spec).to_formatted_string(&Locale::de);
+///     assert_eq!("-1.234.567", s);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`SpecAlign`]: enum.SpecAlign.html
+/// [`SpecSign`]: enum.SpecSign.html
+/// [`Specified`]: struct.Specified.html
+/// [`SpecType`]: enum.SpecType.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    all(feature = "with-serde", feature = "std"),
+    derive(Serialize, Deserialize),
+    serde(try_from = "&str", into = "String")
+)]
+pub struct FormatSpec {
+    pub(crate) align: Option<SpecAlign>,
+    pub(crate) alt: bool,
+    pub(crate) fill: Option<char>,
+    pub(crate) grp: Option<char>,
+    pub(crate) prec: Option<usize>,
+    pub(crate) sign: SpecSign,
+    pub(crate) ty: Option<SpecType>,
+    pub(crate) width: usize,
+    pub(crate) zero: bool,
+}
+
+impl FormatSpec {
+    /// Parses a [`FormatSpec`] from Python's format specification mini-language.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spec is not valid, or if it asks for something Python rejects
+    /// (e.g. a precision for `d`, `,` for `x`, or a grouping for `n`).
+    ///
+    /// [`FormatSpec`]: struct.FormatSpec.html
+    pub fn new<S>(s: S) -> Result<FormatSpec, Error>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        parse(s).ok_or_else(|| Error::parse_format_spec(s))
+    }
+
+    /// Returns the alignment, if one is given.
+    pub fn align(&self) -> Option<SpecAlign> {
+        self.align
+    }
+
+    /// Returns `true` if the alternate form (`#`) is asked for; `false` otherwise.
+    pub fn alternate(&self) -> bool {
+        self.alt
+    }
+
+    /// Returns the fill character, if one is given.
+    pub fn fill(&self) -> Option<char> {
+        self.fill
+    }
+
+    /// Returns the grouping separator (`,` or `_`), if one is given.
+    pub fn grouping(&self) -> Option<char> {
+        self.grp
+    }
+
+    /// Returns the precision, if one is given.
+    pub fn precision(&self) -> Option<usize> {
+        self.prec
+    }
+
+    /// Returns the [`SpecSign`].
+    ///
+    /// [`SpecSign`]: enum.SpecSign.html
+    pub fn sign(&self) -> SpecSign {
+        self.sign
+    }
+
+    /// Returns the [`SpecType`], if one is given.
+    ///
+    /// [`SpecType`]: enum.SpecType.html
+    pub fn spec_type(&self) -> Option<SpecType> {
+        self.ty
+    }
+
+    /// Returns the minimum number of characters to write (`0` if no width is given).
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns `true` if zero padding (`0`) is asked for; `false` otherwise.
+    pub fn zero_padded(&self) -> bool {
+        self.zero
+    }
+
+    /// Returns the fill character and alignment that padding is written with, which depend on
+    /// zero padding if they are not given.
+    pub(crate) fn padding(&self) -> (char, SpecAlign) {
+        let fill = self.fill.unwrap_or(if self.zero { '0' } else { ' ' });
+        let align = self.align.unwrap_or(if self.zero {
+            SpecAlign::AfterSign
+        } else {
+            SpecAlign::Right
+        });
+        (fill, align)
+    }
+}
+
+impl fmt::Display for FormatSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(fill) = self.fill {
+            write!(f, "{}", fill)?;
+        }
+        if let Some(align) = self.align {
+            let c = match align {
+                SpecAlign::Left => '<',
+                SpecAlign::Right => '>',
+                SpecAlign::Center => '^',
+                SpecAlign::AfterSign => '=',
+            };
+            write!(f, "{}", c)?;
+        }
+        match self.sign {
+            SpecSign::Negative => {}
+            SpecSign::Always => write!(f, "+")?,
+            SpecSign::Space => write!(f, " ")?,
+        }
+        if self.alt {
+            write!(f, "#")?;
+        }
+        if self.zero {
+            write!(f, "0")?;
+        }
+        if self.width != 0 {
+            write!(f, "{}", self.width)?;
+        }
+        if let Some(grp) = self.grp {
+            write!(f, "{}", grp)?;
+        }
+        if let Some(prec) = self.prec {
+            write!(f, ".{}", prec)?;
+        }
+        if let Some(ty) = self.ty {
+            write!(f, "{}", ty.to_char())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for FormatSpec {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        FormatSpec::new(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FormatSpec> for String {
+    fn from(spec: FormatSpec) -> Self {
+        spec.to_string()
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = Error;
+
+    /// Same as [`FormatSpec::new`].
+    ///
+    /// [`FormatSpec::new`]: struct.FormatSpec.html#method.new
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FormatSpec::new(s)
+    }
+}
+
+/// Type for representing a number written with a Python [`FormatSpec`] (e.g. `1234567.891`
+/// with `>12,.2f`). Implements [`ToFormattedString`] for `f32`, `f64`, and all the integer types
+/// in the standard library.
+///
+/// Like Python, integers written with a float type (e.g. `f` or `%`) are converted into an
+/// `f64` first. Unlike Python, which rejects them, floats written with an integer type (`d`,
+/// `x`, `X`, `b`, or `o`) are written as if the spec had no type, and integers written with a
+/// precision but without a float type are written as if the spec had no precision.
+///
+/// # Example
+/// ```rust
+/// use num_format::{FormatSpec, Locale, Specified, ToFormattedString};
+///
+/// fn main() {
+///     let spec = FormatSpec::new("+.1%").unwrap();
+///     let s = Specified::new(0.256, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("+25.6%", s);
+///
+///     let spec = FormatSpec::new("*>80").unwrap();
+///     let s = Specified::new(1, spec).to_formatted_string(&Locale::en);
+///     assert_eq!(80, s.len());
+/// }
+/// ```
+///
+/// [`FormatSpec`]: struct.FormatSpec.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    all(feature = "with-serde", feature = "std"),
+    derive(Serialize, Deserialize)
+)]
+pub struct Specified<N> {
+    value: N,
+    spec: FormatSpec,
+}
+
+impl<N> Specified<N> {
+    /// Constructs a [`Specified`] for the given value and spec.
+    ///
+    /// [`Specified`]: struct.Specified.html
+    pub fn new(value: N, spec: FormatSpec) -> Specified<N> {
+        Specified { value, spec }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`FormatSpec`].
+    ///
+    /// [`FormatSpec`]: struct.FormatSpec.html
+    pub fn spec(&self) -> &FormatSpec {
+        &self.spec
+    }
+}
+
+fn upper(c: char, uppercase: bool) -> char {
+    if uppercase {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    }
+}
+
+fn parse(s: &str) -> Option<FormatSpec> {
+    let mut chars = s.chars().peekable();
+    let mut spec = FormatSpec {
+        align: None,
+        alt: false,
+        fill: None,
+        grp: None,
+        prec: None,
+        sign: SpecSign::Negative,
+        ty: None,
+        width: 0,
+        zero: false,
+    };
+
+    // [[fill]align]
+    let mut lookahead = s.chars();
+    let first = lookahead.next();
+    let second = lookahead.next();
+    if let (Some(fill), Some(align)) = (first, second.and_then(to_align)) {
+        spec.fill = Some(fill);
+        spec.align = Some(align);
+        chars.next();
+        chars.next();
+    } else if let Some(align) = first.and_then(to_align) {
+        spec.align = Some(align);
+        chars.next();
+    }
+
+    // [sign][#][0]
+    if let Some(sign) = chars.peek().and_then(|c| match c {
+        '-' => Some(SpecSign::Negative),
+        '+' => Some(SpecSign::Always),
+        ' ' => Some(SpecSign::Space),
+        _ => None,
+    }) {
+        spec.sign = sign;
+        chars.next();
+    }
+    if chars.peek() == Some(&'#') {
+        spec.alt = true;
+        chars.next();
+    }
+    if spec.fill.is_none() && chars.peek() == Some(&'0') {
+        spec.zero = true;
+        chars.next();
+    }
+
+    // [width][grouping][.precision]
+    spec.width = parse_int(&mut chars)?.unwrap_or(0);
+    if let Some(&c) = chars.peek() {
+        if c == ',' || c == '_' {
+            spec.grp = Some(c);
+            chars.next();
+        }
+    }
+    if chars.peek() == Some(&'.') {
+        chars.next();
+        spec.prec = Some(parse_int(&mut chars)??);
+    }
+
+    // [type]
+    if let Some(c) = chars.next() {
+        spec.ty = Some(SpecType::from_char(c)?);
+    }
+    if chars.next().is_some() {
+        return None;
+    }
+
+    // Reject what Python rejects
+    match (spec.ty, spec.grp) {
+        (Some(SpecType::Number), Some(_)) => return None,
+        (Some(SpecType::Hex { .. }), Some(','))
+        | (Some(SpecType::Binary), Some(','))
+        | (Some(SpecType::Octal), Some(',')) => return None,
+        _ => {}
+    }
+    if spec.prec.is_some() && matches!(spec.ty, Some(ty) if ty.is_integer()) {
+        return None;
+    }
+    Some(spec)
+}
+
+/// Parses a run of ASCII digits, returning `Some(None)` if there are none and `None` if they
+/// overflow.
+fn parse_int<I>(chars: &mut core::iter::Peekable<I>) -> Option<Option<usize>>
+where
+    I: Iterator<Item = char>,
+{
+    let mut n: Option<usize> = None;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        let value = n
+            .unwrap_or(0)
+            .checked_mul(10)?
+            .checked_add(digit as usize)?;
+        n = Some(value);
+        chars.next();
+    }
+    Some(n)
+}
+
+fn to_align(c: char) -> Option<SpecAlign> {
+    match c {
+        '<' => Some(SpecAlign::Left),
+        '>' => Some(SpecAlign::Right),
+        '^' => Some(SpecAlign::Center),
+        '=' => Some(SpecAlign::AfterSign),
+        _ => None,
+    }
+}
@@ -0,0 +1,226 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use crate::buffer::Buffer;
+use crate::error::Error;
+use crate::parsing::{FromFormattedStr, ParseFormat, ParseFormatted};
+use crate::to_formatted_str::ToFormattedStr;
+
+/// Trait for marker types that stand for a format chosen at compile time (e.g. [`Locale::en`]),
+/// which is what [`Formatted`] formats and parses with.
+///
+/// # Example
+/// ```rust
+/// use num_format::{CustomFormat, Grouping, Locale, StaticFormat};
+///
+/// struct German;
+///
+/// impl StaticFormat for German {
+///     type Format = Locale;
+///
+///     fn format() -> Locale {
+///         Locale::de
+///     }
+/// }
+///
+/// struct Apostrophes;
+///
+/// impl StaticFormat for Apostrophes {
+///     type Format = CustomFormat;
+///
+///     fn format() -> CustomFormat {
+///         CustomFormat::builder()
+///             .grouping(Grouping::Standard)
+///             .separator("'")
+///             .build()
+///             .unwrap()
+///     }
+/// }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// [`Formatted`]: struct.Formatted.html
+/// [`Locale::en`]: enum.Locale.html#variant.en
+pub trait StaticFormat {
+    /// The type of the format.
+    type Format: ParseFormat;
+
+    /// Returns the format.
+    fn format() -> Self::Format;
+}
+
+/// Type for representing a number that is written and read with the format of a
+/// [`StaticFormat`], so that it works with any API built on [`Display`] and [`FromStr`] (e.g.
+/// `to_string` and `str::parse`).
+///
+/// [`Display`] writes the number like [`Buffer::write_formatted`] does (and respects the
+/// width, fill, and alignment of the formatter), and [`FromStr`] reads it like
+/// [`parse_formatted`] does. What `to_string` writes can usually be read back, but not always:
+/// padding added by a width isn't accepted, and a custom format whose strings are ambiguous (e.g.
+/// a separator that is also its decimal, or that contains digits) may write numbers that are
+/// rejected or read back as a different number.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Formatted, Locale, StaticFormat};
+///
+/// struct German;
+///
+/// impl StaticFormat for German {
+///     type Format = Locale;
+///
+///     fn format() -> Locale {
+///         Locale::de
+///     }
+/// }
+///
+/// fn main() {
+///     let n: Formatted<u32, German> = "1.234.567".parse().unwrap();
+///     assert_eq!(1_234_567, *n.value());
+///     assert_eq!("1.234.567", n.to_string());
+///     assert_eq!("   -1.234", format!("{:>9}", Formatted::<i32, German>::new(-1234)));
+///
+///     assert!("1,234,567".parse::<Formatted<u32, German>>().is_err());
+/// }
+/// ```
+///
+/// [`Buffer::write_formatted`]: struct.Buffer.html#method.write_formatted
+/// [`Display`]: https://doc.rust-lang.org/stable/std/fmt/trait.Display.html
+/// [`FromStr`]: https://doc.rust-lang.org/stable/std/str/trait.FromStr.html
+/// [`parse_formatted`]: parsing/trait.ParseFormatted.html#method.parse_formatted
+/// [`StaticFormat`]: trait.StaticFormat.html
+pub struct Formatted<N, F> {
+    value: N,
+    format: PhantomData<fn() -> F>,
+}
+
+impl<N, F> Formatted<N, F> {
+    /// Constructs a [`Formatted`] from a number.
+    ///
+    /// [`Formatted`]: struct.Formatted.html
+    pub fn new(value: N) -> Formatted<N, F> {
+        Formatted {
+            value,
+            format: PhantomData,
+        }
+    }
+
+    /// Returns the number.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the number, consuming the [`Formatted`].
+    ///
+    /// [`Formatted`]: struct.Formatted.html
+    pub fn into_value(self) -> N {
+        self.value
+    }
+}
+
+impl<N, F> fmt::Display for Formatted<N, F>
+where
+    N: ToFormattedStr,
+    F: StaticFormat,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Buffer::new();
+        buf.write_formatted(&self.value, &F::format());
+        f.pad(buf.as_str())
+    }
+}
+
+impl<N, F> FromStr for Formatted<N, F>
+where
+    N: FromFormattedStr,
+    F: StaticFormat,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse_formatted(&F::format()).map(Formatted::new)
+    }
+}
+
+impl<N, F> From<N> for Formatted<N, F> {
+    fn from(value: N) -> Formatted<N, F> {
+        Formatted::new(value)
+    }
+}
+
+// The traits below are implemented by hand so that they don't require anything of the marker
+// type.
+
+impl<N, F> Clone for Formatted<N, F>
+where
+    N: Clone,
+{
+    fn clone(&self) -> Self {
+        Formatted::new(self.value.clone())
+    }
+}
+
+impl<N, F> Copy for Formatted<N, F> where N: Copy {}
+
+impl<N, F> fmt::Debug for Formatted<N, F>
+where
+    N: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Formatted").field(&self.value).finish()
+    }
+}
+
+impl<N, F> Default for Formatted<N, F>
+where
+    N: Default,
+{
+    fn default() -> Self {
+        Formatted::new(N::default())
+    }
+}
+
+impl<N, F> PartialEq for Formatted<N, F>
+where
+    N: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<N, F> Eq for Formatted<N, F> where N: Eq {}
+
+impl<N, F> PartialOrd for Formatted<N, F>
+where
+    N: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<N, F> Ord for Formatted<N, F>
+where
+    N: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<N, F> Hash for Formatted<N, F>
+where
+    N: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.value.hash(state)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::constants::{F64_MAX_FRAC_DIGITS, MAX_FRAC_BUF_LEN};
+use crate::error::Error;
+use crate::strings::SepString;
+
+/// Type for representing a number whose fraction digits are written in groups (e.g.
+/// `3.141 592 653`), as recommended by ISO 80000-1 and the SI brochure. Implements
+/// [`ToFormattedStr`] for everything that does (and [`ToFormattedString`] for [`Decimal`],
+/// [`Measure`], [`Range`], and [`Approximately`]).
+///
+/// The integer digits are grouped as the [`Format`] says, and the fraction digits in groups of
+/// `size` from the decimal point on, separated by `separator`.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Error, FractionGrouped, Locale, Scaled};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&FractionGrouped::new(1234.56789, 3, "\u{202f}")?, &Locale::en);
+///     assert_eq!("1,234.567\u{202f}89", buf.as_str());
+///
+///     let value = FractionGrouped::new(Scaled::new(3_141_592_653u64, 9), 3, " ")?;
+///     buf.write_formatted(&value, &Locale::fr);
+///     assert_eq!("3,141 592 653", buf.as_str());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Approximately`]: struct.Approximately.html
+/// [`Decimal`]: struct.Decimal.html
+/// [`Format`]: trait.Format.html
+/// [`Measure`]: struct.Measure.html
+/// [`Range`]: struct.Range.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FractionGrouped<T> {
+    value: T,
+    size: u8,
+    sep: SepString,
+}
+
+impl<T> FractionGrouped<T> {
+    /// Constructs a [`FractionGrouped`] for the given value, which writes its fraction digits in
+    /// groups of `size` (ungrouped if `size` is `0`) separated by `separator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the separator is longer than 8 bytes, or if the longest possible
+    /// grouped fraction (that of the smallest `f64`) would not fit into a [`Buffer`].
+    ///
+    /// [`Buffer`]: struct.Buffer.html
+    /// [`FractionGrouped`]: struct.FractionGrouped.html
+    pub fn new<S>(value: T, size: u8, separator: S) -> Result<FractionGrouped<T>, Error>
+    where
+        S: AsRef<str>,
+    {
+        let sep = SepString::new(separator)?;
+        let separators = match size {
+            0 => 0,
+            size => (F64_MAX_FRAC_DIGITS - 1) / size as usize,
+        };
+        let len = F64_MAX_FRAC_DIGITS + separators * sep.len();
+        if len > MAX_FRAC_BUF_LEN {
+            return Err(Error::capacity(len, MAX_FRAC_BUF_LEN));
+        }
+        Ok(FractionGrouped { value, size, sep })
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the number of fraction digits in each group, or `0` if fraction digits are not
+    /// grouped.
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    /// Returns the separator between groups of fraction digits.
+    pub fn separator(&self) -> &str {
+        &self.sep
+    }
+}
@@ -1,4 +1,102 @@
-mod integers;
+// Implements ToFormattedString for types that are written in pieces (see WritePieces) because
+// they may not fit into a Buffer
+#[cfg(feature = "std")]
+macro_rules! impl_to_formatted_string_by_pieces {
+    ($($type:ty),*) => {
+        $(
+            impl crate::to_formatted_string::ToFormattedString for $type {
+                #[inline(never)]
+                fn read_to_fmt_writer<F, W>(
+                    &self,
+                    w: W,
+                    format: &F,
+                ) -> Result<usize, core::fmt::Error>
+                where
+                    F: crate::format::WriteFormat,
+                    W: core::fmt::Write,
+                {
+                    crate::to_formatted_string::write_pieces_to_fmt(self, w, format)
+                }
+
+                #[inline(never)]
+                fn read_to_io_writer<F, W>(
+                    &self,
+                    w: W,
+                    format: &F,
+                ) -> Result<usize, std::io::Error>
+                where
+                    F: crate::format::WriteFormat,
+                    W: std::io::Write,
+                {
+                    crate::to_formatted_string::write_pieces_to_io(self, w, format)
+                }
+            }
+
+            impl crate::private::Sealed for $type {}
+        )*
+    };
+}
+
+// Implements ToFormattedString for a value wrapper around types that are only written to strings
+// and writers, by writing the value with the format wrapped by `$with`
+#[cfg(feature = "std")]
+macro_rules! impl_to_formatted_string_by_wrapping {
+    ($wrapper:ident, $with:path, $type:ty $(, $param:ident)?) => {
+        impl$(<$param>)? crate::to_formatted_string::ToFormattedString for $wrapper<$type>
+        where
+            $type: crate::to_formatted_string::ToFormattedString,
+        {
+            #[inline(never)]
+            fn read_to_fmt_writer<F, W>(
+                &self,
+                w: W,
+                format: &F,
+            ) -> Result<usize, core::fmt::Error>
+            where
+                F: crate::format::WriteFormat,
+                W: core::fmt::Write,
+            {
+                self.value().read_to_fmt_writer(w, &$with(self, format))
+            }
+
+            #[inline(never)]
+            fn read_to_io_writer<F, W>(
+                &self,
+                w: W,
+                format: &F,
+            ) -> Result<usize, std::io::Error>
+            where
+                F: crate::format::WriteFormat,
+                W: std::io::Write,
+            {
+                self.value().read_to_io_writer(w, &$with(self, format))
+            }
+        }
+    };
+}
+
+mod byte_size;
+mod decimal;
+mod elapsed;
 mod floats;
+mod format_code;
+#[cfg(feature = "std")]
+mod format_spec;
+mod fraction_grouped;
+mod integers;
+#[cfg(feature = "std")]
+mod measure;
+mod metric;
 #[cfg(feature = "with-num-bigint")]
 mod num;
+mod ordinal;
+mod patterned;
+#[cfg(feature = "std")]
+mod printf;
+#[cfg(feature = "std")]
+mod radixed;
+#[cfg(feature = "std")]
+mod range;
+mod roman;
+mod scaled;
+mod spell_out;
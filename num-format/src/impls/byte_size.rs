@@ -0,0 +1,31 @@
+use crate::buffer::Buffer;
+use crate::byte_size::ByteSize;
+use crate::constants::MAX_BUF_LEN;
+use crate::format::WriteFormat;
+use crate::to_formatted_str::ToFormattedStr;
+
+use super::scaled::write_scaled;
+
+impl ToFormattedStr for ByteSize {
+    #[doc(hidden)]
+    #[inline(never)]
+    fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+    where
+        F: WriteFormat,
+    {
+        let (unit, n) = self.scaled();
+        let scale = if unit == 0 { 0 } else { self.precision() };
+        let symbol = self.units().symbols()[unit];
+
+        // Write the unit symbol at the end of the buffer, then the number in front of it
+        let symbol_start = MAX_BUF_LEN - symbol.len() - 1;
+        buf.inner[symbol_start] = b' ';
+        buf.inner[symbol_start + 1..].copy_from_slice(symbol.as_bytes());
+        let c = write_scaled(false, n, scale, buf, symbol_start, format) + symbol.len() + 1;
+        buf.pos = MAX_BUF_LEN - c;
+        buf.end = MAX_BUF_LEN;
+        c
+    }
+}
+
+impl crate::private::Sealed for ByteSize {}
@@ -0,0 +1,63 @@
+#![cfg(feature = "std")]
+
+use std::fmt;
+use std::io;
+
+use crate::decimal::Decimal;
+use crate::format::write_decimal;
+use crate::format::WriteFormat;
+use crate::number_pattern::write_pattern;
+use crate::ToFormattedString;
+
+impl ToFormattedString for Decimal {
+    #[inline(never)]
+    fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
+    where
+        F: WriteFormat,
+        W: io::Write,
+    {
+        let mut result = Ok(());
+        let mut c = 0;
+        write(self, format, |s| {
+            if result.is_ok() {
+                result = w.write_all(s.as_bytes());
+                c += s.len();
+            }
+        });
+        result.map(|_| c)
+    }
+
+    #[inline(never)]
+    fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
+    where
+        F: WriteFormat,
+        W: fmt::Write,
+    {
+        let mut result = Ok(());
+        let mut c = 0;
+        write(self, format, |s| {
+            if result.is_ok() {
+                result = w.write_str(s);
+                c += s.len();
+            }
+        });
+        result.map(|_| c)
+    }
+}
+
+impl crate::private::Sealed for Decimal {}
+
+/// Writes the decimal into `w` piece by piece, with the format's number pattern if it has one.
+fn write<F, W>(decimal: &Decimal, format: &F, w: W)
+where
+    F: WriteFormat,
+    W: FnMut(&str),
+{
+    let is_negative = decimal.is_negative();
+    let int_digits = decimal.integer_digits();
+    let frac_digits = decimal.fraction_digits();
+    match format.number_pattern() {
+        Some(pattern) => write_pattern(is_negative, int_digits, frac_digits, pattern, format, w),
+        None => write_decimal(is_negative, int_digits, frac_digits, format, w),
+    }
+}
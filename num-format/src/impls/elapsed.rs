@@ -0,0 +1,80 @@
+use crate::buffer::Buffer;
+use crate::constants::MAX_BUF_LEN;
+use crate::elapsed::{DurationUnit, Elapsed, Style};
+use crate::format::WriteFormat;
+use crate::to_formatted_str::ToFormattedStr;
+
+use super::scaled::write_scaled;
+
+impl ToFormattedStr for Elapsed {
+    #[doc(hidden)]
+    #[inline(never)]
+    fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+    where
+        F: WriteFormat,
+    {
+        let mut end = MAX_BUF_LEN;
+        match self.style() {
+            Style::Single(unit) => {
+                let (unit, n) = self.scaled(unit);
+                end = write_unit(buf, end, n, self.precision(), unit, format);
+            }
+            Style::Compound(smallest_unit) => {
+                // Write the units from the smallest up, carrying the rest into the next unit
+                let mut rest = self.duration().as_nanos() / smallest_unit.nanos();
+                let mut unit = smallest_unit;
+                loop {
+                    let (n, larger, next) = match unit.next() {
+                        Some(next) => {
+                            let ratio = next.nanos() / unit.nanos();
+                            (rest % ratio, rest / ratio, Some(next))
+                        }
+                        None => (rest, 0, None),
+                    };
+                    // Zeros are left out, unless the whole duration is zero
+                    if n != 0 || (larger == 0 && end == MAX_BUF_LEN) {
+                        if end != MAX_BUF_LEN {
+                            end -= 1;
+                            buf.inner[end] = b' ';
+                        }
+                        end = write_unit(buf, end, n, 0, unit, format);
+                    }
+                    match next {
+                        Some(next) if larger != 0 => {
+                            unit = next;
+                            rest = larger;
+                        }
+                        _ => break,
+                    }
+                }
+            }
+        }
+        buf.pos = end;
+        buf.end = MAX_BUF_LEN;
+        MAX_BUF_LEN - end
+    }
+}
+
+impl crate::private::Sealed for Elapsed {}
+
+// helper functions
+
+/// Writes `n / 10^scale`, a space, and the unit symbol so that they end at `end`, returning the
+/// index they start at.
+fn write_unit<F>(
+    buf: &mut Buffer,
+    end: usize,
+    n: u128,
+    scale: u8,
+    unit: DurationUnit,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    let symbol = unit.symbol();
+    let start = end - symbol.len() - 1;
+    buf.inner[start] = b' ';
+    buf.inner[start + 1..end].copy_from_slice(symbol.as_bytes());
+    start - write_scaled(false, n, scale, buf, start, format)
+}
@@ -1,6 +1,11 @@
 #![allow(trivial_numeric_casts)]
 
-use crate::format::Format;
+use crate::constants::{F64_MAX_FRAC_DIGITS, MAX_BUF_LEN};
+use crate::format::WriteFormat;
+use crate::number_pattern::{
+    write_pattern_nonfinite, write_pattern_to_buffer, write_to_buffer, NumberPattern,
+};
+use crate::ryu::{decimal32, decimal64, decimal_digits, format_decimal, Float};
 
 use crate::to_formatted_str::ToFormattedStr;
 
@@ -10,38 +15,123 @@ use crate::to_formatted_str::ToFormattedStr;
 fn run_core_algorithm_ryu<Fl, Fmt>(f: Fl, buf: &mut crate::Buffer, format: &Fmt) -> usize
 where
     Fl: crate::ryu::Float<Buffer = (*mut u8, usize)>,
-    Fmt: Format,
+    Fmt: WriteFormat,
 {
+    // Reset our position to the end of the buffer
+    buf.pos = MAX_BUF_LEN;
+    buf.end = MAX_BUF_LEN;
+
     let s = crate::ryu::float::format_float(f, buf.inner.as_mut_ptr(), buf.pos, format);
     let s_len = s.len();
     buf.pos -= s_len;
     s_len
 }
 
-// float 32bit
+#[inline(never)]
+fn run_decimal_algorithm<Fmt>(
+    is_negative: bool,
+    mantissa: u64,
+    exponent: i32,
+    buf: &mut crate::Buffer,
+    format: &Fmt,
+) -> usize
+where
+    Fmt: WriteFormat,
+{
+    buf.pos = format_decimal(
+        buf.inner.as_mut_ptr(),
+        MAX_BUF_LEN,
+        is_negative,
+        mantissa,
+        exponent,
+        format,
+    );
+    buf.end = MAX_BUF_LEN;
+    MAX_BUF_LEN - buf.pos
+}
 
-impl ToFormattedStr for f32 {
-    #[doc(hidden)]
-    #[inline(never)]
-    fn read_to_buffer<'a, Fmt>(&self, buf: &'a mut crate::Buffer, format: &Fmt) -> usize
-    where
-        Fmt: Format,
-    {
-        run_core_algorithm_ryu(*self, buf, format)
-    }
+#[inline(never)]
+fn run_pattern_algorithm<Fmt>(
+    is_negative: bool,
+    decimal: Option<(u64, i32)>,
+    is_nan: bool,
+    buf: &mut crate::Buffer,
+    pattern: &NumberPattern,
+    format: &Fmt,
+) -> usize
+where
+    Fmt: WriteFormat,
+{
+    let (mantissa, exponent) = match decimal {
+        Some(decimal) => decimal,
+        None => {
+            let symbol = if is_nan {
+                format.nan().into_str()
+            } else {
+                format.infinity().into_str()
+            };
+            return write_to_buffer(buf, |w| {
+                write_pattern_nonfinite(is_negative, symbol, pattern, format, w)
+            });
+        }
+    };
+    let mut digits = [b'0'; F64_MAX_FRAC_DIGITS];
+    let (int_digits, frac_digits) = decimal_digits(mantissa, exponent, &mut digits);
+    write_pattern_to_buffer(is_negative, int_digits, frac_digits, pattern, buf, format)
 }
 
-impl crate::private::Sealed for f32 {}
-
-impl ToFormattedStr for f64 {
-    #[doc(hidden)]
-    #[inline(never)]
-    fn read_to_buffer<'a, Fmt>(&self, buf: &'a mut crate::Buffer, format: &Fmt) -> usize
-    where
-        Fmt: Format,
-    {
-        run_core_algorithm_ryu(*self, buf, format)
-    }
+macro_rules! impl_float {
+    ($type:ty, $decimal:ident) => {
+        impl ToFormattedStr for $type {
+            #[doc(hidden)]
+            #[inline(never)]
+            fn read_to_buffer<'a, Fmt>(&self, buf: &'a mut crate::Buffer, format: &Fmt) -> usize
+            where
+                Fmt: WriteFormat,
+            {
+                if let Some(pattern) = format.number_pattern() {
+                    let f = *self;
+                    let is_negative = !f.is_nan() && f.is_sign_negative();
+                    let decimal = if f.is_nonfinite() {
+                        None
+                    } else {
+                        Some($decimal(&f.parse_ieee_data()))
+                    };
+                    return run_pattern_algorithm(
+                        is_negative,
+                        decimal,
+                        f.is_nan(),
+                        buf,
+                        pattern,
+                        format,
+                    );
+                }
+                if format.fraction_grouping().0 != 0 {
+                    let f = *self;
+                    if !f.is_nonfinite() {
+                        let ieee = f.parse_ieee_data();
+                        let (mantissa, exponent) = $decimal(&ieee);
+                        return run_decimal_algorithm(
+                            ieee.is_negative,
+                            mantissa,
+                            exponent,
+                            buf,
+                            format,
+                        );
+                    }
+                }
+                run_core_algorithm_ryu(*self, buf, format)
+            }
+        }
+
+        impl crate::private::Sealed for $type {}
+    };
 }
 
-impl crate::private::Sealed for f64 {}
+// float 32bit
+
+impl_float!(f32, decimal32);
+
+// float 64bit
+
+impl_float!(f64, decimal64);
@@ -0,0 +1,574 @@
+#![allow(trivial_numeric_casts)]
+
+use core::fmt::Write;
+
+use arrayvec::ArrayString;
+
+use crate::buffer::Buffer;
+use crate::format::{int_groups, WriteFormat};
+use crate::format_code::{is_digit, Coded, FormatCode, Layout, Token, Tokens};
+use crate::number_pattern::write_to_buffer;
+use crate::ryu::{decimal32, decimal64, Float};
+use crate::to_formatted_str::ToFormattedStr;
+
+// Spreadsheets keep fifteen significant digits
+const SIGNIFICANT_DIGITS: isize = 15;
+
+// Enough room for the integer digits, or for the numerator of an improper fraction
+const MAX_DIGITS_LEN: usize = 330;
+
+macro_rules! impl_coded_float {
+    ($type:ty, $decimal:ident) => {
+        impl ToFormattedStr for Coded<$type> {
+            #[doc(hidden)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let f = *self.value();
+                let decimal = if f.is_nonfinite() {
+                    Decimal::new(0, 0)
+                } else {
+                    let (mantissa, exponent) = $decimal(&f.parse_ieee_data());
+                    Decimal::new(mantissa as u128, exponent as isize)
+                };
+                run_code_algorithm(f as f64, decimal, buf, self.code(), format)
+            }
+        }
+
+        impl crate::private::Sealed for Coded<$type> {}
+    };
+}
+
+macro_rules! impl_coded_integer {
+    ($type:ty) => {
+        impl ToFormattedStr for Coded<$type> {
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                let f = if is_negative { -(n as f64) } else { n as f64 };
+                run_code_algorithm(f, Decimal::new(n, 0), buf, self.code(), format)
+            }
+        }
+
+        impl crate::private::Sealed for Coded<$type> {}
+    };
+}
+
+impl_coded_float!(f32, decimal32);
+impl_coded_float!(f64, decimal64);
+
+impl_coded_integer!(u8);
+impl_coded_integer!(u16);
+impl_coded_integer!(u32);
+impl_coded_integer!(usize);
+impl_coded_integer!(u64);
+impl_coded_integer!(u128);
+
+impl_coded_integer!(i8);
+impl_coded_integer!(i16);
+impl_coded_integer!(i32);
+impl_coded_integer!(isize);
+impl_coded_integer!(i64);
+impl_coded_integer!(i128);
+
+// helper functions
+
+/// The magnitude of a number as its significant digits `0.d1d2d3... × 10^point`, without
+/// trailing zeros (and without any digits for zero).
+#[derive(Copy, Clone)]
+struct Decimal {
+    digits: [u8; 40],
+    len: usize,
+    point: isize,
+}
+
+impl Decimal {
+    /// Constructs the [`Decimal`] for `mantissa × 10^exponent`, rounded to the significant
+    /// digits spreadsheets keep.
+    fn new(mantissa: u128, exponent: isize) -> Decimal {
+        let mut text = ArrayString::<40>::new();
+        write!(text, "{}", mantissa).unwrap();
+        let trimmed = text.trim_end_matches('0');
+        let mut decimal = Decimal {
+            digits: [b'0'; 40],
+            len: trimmed.len(),
+            point: 0,
+        };
+        decimal.digits[..trimmed.len()].copy_from_slice(trimmed.as_bytes());
+        if !trimmed.is_empty() {
+            decimal.point = text.len() as isize + exponent;
+        }
+        decimal.round(SIGNIFICANT_DIGITS);
+        decimal
+    }
+
+    fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the digit `i` places after the first significant one.
+    fn digit(&self, i: isize) -> u8 {
+        if 0 <= i && i < self.len as isize {
+            self.digits[i as usize]
+        } else {
+            b'0'
+        }
+    }
+
+    /// Writes the digits from `start` to `end` (relative to the first significant one).
+    fn write_digits<const CAP: usize>(&self, start: isize, end: isize, s: &mut ArrayString<CAP>) {
+        for i in start..end {
+            s.push(self.digit(i) as char);
+        }
+    }
+
+    /// Rounds half away from zero to the first `keep` significant digits.
+    fn round(&mut self, keep: isize) {
+        if keep >= self.len as isize {
+            return;
+        }
+        let round_up = keep >= 0 && self.digits[keep as usize] >= b'5';
+        self.len = keep.max(0) as usize;
+        if round_up {
+            loop {
+                if self.len == 0 {
+                    self.digits[0] = b'1';
+                    self.len = 1;
+                    self.point += 1;
+                    break;
+                }
+                if self.digits[self.len - 1] == b'9' {
+                    self.len -= 1;
+                } else {
+                    self.digits[self.len - 1] += 1;
+                    break;
+                }
+            }
+        }
+        while self.len > 0 && self.digits[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+        if self.len == 0 {
+            self.point = 0;
+        }
+    }
+
+    /// Rounds half away from zero to `frac` fraction digits.
+    fn round_frac(&mut self, frac: usize) {
+        self.round(self.point + frac as isize);
+    }
+}
+
+/// The digits to write into the placeholders of a section.
+struct Digits {
+    int: ArrayString<MAX_DIGITS_LEN>,
+    frac: ArrayString<{ crate::format_code::MAX_CODE_LEN }>,
+    exponent: i32,
+    exp_digits: ArrayString<8>,
+    numerator: ArrayString<MAX_DIGITS_LEN>,
+    denominator: ArrayString<16>,
+    // whether or not the fraction of a mixed number is left blank, because it's zero
+    blank: bool,
+    // the number as `General`
+    general: (ArrayString<16>, ArrayString<16>, ArrayString<8>),
+}
+
+impl Digits {
+    fn new() -> Digits {
+        Digits {
+            int: ArrayString::new(),
+            frac: ArrayString::new(),
+            exponent: 0,
+            exp_digits: ArrayString::new(),
+            numerator: ArrayString::new(),
+            denominator: ArrayString::new(),
+            blank: false,
+            general: (ArrayString::new(), ArrayString::new(), ArrayString::new()),
+        }
+    }
+}
+
+#[inline(never)]
+fn run_code_algorithm<F>(
+    value: f64,
+    mut decimal: Decimal,
+    buf: &mut Buffer,
+    code: &FormatCode,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    let minus_sign = format.minus_sign().into_str();
+    let (section, layout, minus) = match code.select(value) {
+        Some((index, minus)) if value.is_finite() => {
+            (code.section(index), code.layout(index), minus)
+        }
+        _ => ("General", Layout::new("General").unwrap(), value < 0.0),
+    };
+
+    // Infinity and NaN, and numbers that overflow once they're scaled (e.g. `f64::MAX` as a
+    // percentage), aren't written with a code
+    let mut scaled = value;
+    for _ in 0..layout.scale {
+        scaled *= 10.0;
+    }
+    if !decimal.is_zero() {
+        decimal.point += layout.scale as isize;
+    }
+    if !scaled.is_finite() {
+        let symbol = if value.is_nan() {
+            format.nan().into_str()
+        } else {
+            format.infinity().into_str()
+        };
+        return write_to_buffer(buf, |w| {
+            if scaled < 0.0 {
+                w(minus_sign);
+            }
+            w(symbol);
+        });
+    }
+
+    let mut digits = Digits::new();
+    if layout.general || layout.text {
+        digits.general = general(decimal);
+    } else if layout.exponent.is_some() {
+        exponent_digits(decimal, &layout, &mut digits);
+    } else if layout.slash.is_some() {
+        fraction_digits(decimal, &layout, &mut digits);
+    } else {
+        decimal.round_frac(layout.frac_count);
+        decimal.write_digits(0, decimal.point, &mut digits.int);
+        decimal.write_digits(
+            decimal.point,
+            decimal.point + layout.frac_count as isize,
+            &mut digits.frac,
+        );
+    }
+
+    let group = if layout.grouping {
+        int_groups(format)
+    } else {
+        None
+    };
+    write_to_buffer(buf, |w| {
+        if minus {
+            w(minus_sign);
+        }
+        let (mut int_index, mut frac_index, mut exp_index) = (0, 0, 0);
+        let (mut num_index, mut den_index) = (0, 0);
+        let mut in_denominator = false;
+        for (i, token) in Tokens::new(section).flatten().enumerate() {
+            match token {
+                Token::Literal(s) if in_denominator && is_digit(s) => {
+                    w(if digits.blank { " " } else { s })
+                }
+                Token::Literal(s) => {
+                    in_denominator = false;
+                    w(s)
+                }
+                Token::Percent => w("%"),
+                Token::Slash => {
+                    in_denominator = true;
+                    w(if digits.blank { " " } else { "/" })
+                }
+                Token::General | Token::Text => {
+                    let (int, frac, exponent) = &digits.general;
+                    w(int);
+                    if !frac.is_empty() {
+                        w(format.decimal().into_str());
+                        w(frac);
+                    }
+                    w(exponent);
+                }
+                Token::Point => {
+                    if layout.int_count == 0 {
+                        w(&digits.int);
+                    }
+                    w(format.decimal().into_str());
+                }
+                Token::Exponent { uppercase, plus } => {
+                    w(if uppercase { "E" } else { "e" });
+                    if digits.exponent < 0 {
+                        w("-");
+                    } else if plus {
+                        w("+");
+                    }
+                }
+                Token::Digit(c) if matches!(layout.exponent, Some(exponent) if i > exponent) => {
+                    write_right(c, exp_index, layout.exp_count, &digits.exp_digits, None, w);
+                    exp_index += 1;
+                }
+                Token::Digit(c) if matches!(layout.point, Some(point) if i > point) => {
+                    let significant = digits.frac[frac_index..].bytes().any(|b| b != b'0');
+                    match c {
+                        b'0' => w(&digits.frac[frac_index..frac_index + 1]),
+                        _ if significant => w(&digits.frac[frac_index..frac_index + 1]),
+                        b'?' => w(" "),
+                        _ => {}
+                    }
+                    frac_index += 1;
+                }
+                Token::Digit(c) if matches!(layout.slash, Some(slash) if i > slash) => {
+                    if layout.denominator.is_some() {
+                        w(if digits.blank { " " } else { "0" });
+                    } else if digits.blank {
+                        w(" ");
+                    } else {
+                        write_left(c, den_index, layout.den_count, &digits.denominator, w);
+                    }
+                    den_index += 1;
+                }
+                Token::Digit(c) if layout.slash.is_some() && i >= layout.numerator => {
+                    if digits.blank {
+                        w(" ");
+                    } else {
+                        write_right(c, num_index, layout.num_count, &digits.numerator, None, w);
+                    }
+                    num_index += 1;
+                }
+                Token::Digit(c) => {
+                    write_right(c, int_index, layout.int_count, &digits.int, group, w);
+                    int_index += 1;
+                }
+                _ => {}
+            }
+        }
+    })
+}
+
+/// Writes the `index`th of `count` right-aligned digit placeholders `c` for `digits`, where the
+/// first placeholder writes any digits that don't fit into the rest.
+fn write_right(
+    c: u8,
+    index: usize,
+    count: usize,
+    digits: &str,
+    group: Option<(&str, usize, usize)>,
+    w: &mut dyn FnMut(&str),
+) {
+    // Writes a separator after a digit with `right` digits to its right
+    let separate = |right: usize, w: &mut dyn FnMut(&str)| {
+        if let Some((separator, last, rest)) = group {
+            if right == last || right > last && matches!((right - last) % rest, 0) {
+                w(separator);
+            }
+        }
+    };
+
+    let len = digits.len();
+    let right = count - 1 - index;
+    if index == 0 && len > count {
+        for j in 0..=len - count {
+            w(&digits[j..j + 1]);
+            separate(len - 1 - j, w);
+        }
+    } else if right < len {
+        w(&digits[len - 1 - right..len - right]);
+        separate(right, w);
+    } else if c == b'0' {
+        w("0");
+        separate(right, w);
+    } else if c == b'?' {
+        w(" ");
+    }
+}
+
+/// Writes the `index`th of `count` left-aligned digit placeholders `c` for `digits`, where the
+/// last placeholder writes any digits that don't fit into the rest.
+fn write_left(c: u8, index: usize, count: usize, digits: &str, w: &mut dyn FnMut(&str)) {
+    if index + 1 == count && index < digits.len() {
+        w(&digits[index..]);
+    } else if index < digits.len() {
+        w(&digits[index..index + 1]);
+    } else if c != b'#' {
+        w(" ");
+    }
+}
+
+/// Works out the mantissa and exponent of a number in scientific notation. If the integer
+/// placeholders include `#`, the exponent is a multiple of their number, otherwise the mantissa
+/// has as many integer digits as there are placeholders.
+fn exponent_digits(decimal: Decimal, layout: &Layout, digits: &mut Digits) {
+    let (step, max_int) = if layout.int_hash && layout.int_count > 1 {
+        (layout.int_count as isize, layout.int_count as isize)
+    } else {
+        (1, layout.int_count as isize)
+    };
+    let mut exponent = if decimal.is_zero() {
+        0
+    } else if step > 1 {
+        (decimal.point - 1).div_euclid(step) * step
+    } else {
+        decimal.point - max_int
+    };
+
+    // Rounding can add an integer digit to the mantissa (e.g. 9.99 to 10.0), which moves the
+    // exponent up
+    let mantissa = loop {
+        let mut mantissa = decimal;
+        if !mantissa.is_zero() {
+            mantissa.point -= exponent;
+        }
+        mantissa.round_frac(layout.frac_count);
+        if mantissa.point <= max_int {
+            break mantissa;
+        }
+        exponent += step;
+    };
+
+    mantissa.write_digits(0, mantissa.point, &mut digits.int);
+    mantissa.write_digits(
+        mantissa.point,
+        mantissa.point + layout.frac_count as isize,
+        &mut digits.frac,
+    );
+    digits.exponent = exponent as i32;
+    write!(digits.exp_digits, "{}", exponent.abs()).unwrap();
+}
+
+/// Works out the integer, numerator, and denominator of a number as a fraction, with the
+/// closest denominator up to the number of denominator placeholders (or the fixed one). A
+/// fraction without integer placeholders is improper.
+fn fraction_digits(decimal: Decimal, layout: &Layout, digits: &mut Digits) {
+    decimal.write_digits(0, decimal.point, &mut digits.int);
+    let start = decimal.point.max(0);
+    let mut frac = ArrayString::<48>::from("0.").unwrap();
+    decimal.write_digits(start, decimal.len as isize, &mut frac);
+    write!(frac, "e{}", decimal.point - start).unwrap();
+    let frac: f64 = frac.parse().unwrap();
+
+    let (mut numerator, denominator) = match layout.denominator {
+        Some(denominator) => ((frac * denominator as f64 + 0.5) as u64, denominator as u64),
+        None => {
+            let max = 10u64.pow(layout.den_count as u32) - 1;
+            closest_fraction(frac, max)
+        }
+    };
+    if numerator == denominator {
+        numerator = 0;
+        increment(&mut digits.int);
+    }
+
+    if layout.int_count > 0 {
+        digits.blank = numerator == 0;
+        if digits.blank && digits.int.is_empty() {
+            digits.int.push('0');
+        }
+        write!(digits.numerator, "{}", numerator).unwrap();
+    } else {
+        // The numerator of an improper fraction is `int × denominator + numerator`
+        let mut carry = numerator;
+        let mut reversed = ArrayString::<MAX_DIGITS_LEN>::new();
+        for digit in digits.int.bytes().rev() {
+            let n = u64::from(digit - b'0') * denominator + carry;
+            reversed.push((b'0' + (n % 10) as u8) as char);
+            carry = n / 10;
+        }
+        while carry > 0 || reversed.is_empty() {
+            reversed.push((b'0' + (carry % 10) as u8) as char);
+            carry /= 10;
+        }
+        for digit in reversed.chars().rev() {
+            digits.numerator.push(digit);
+        }
+        digits.int.clear();
+    }
+    write!(digits.denominator, "{}", denominator).unwrap();
+}
+
+/// Returns the closest fraction to `x` (from 0 to 1) with a denominator up to `max`, from its
+/// continued fraction.
+fn closest_fraction(x: f64, max: u64) -> (u64, u64) {
+    let (mut p0, mut q0, mut p1, mut q1) = (0u64, 1u64, 1u64, 0u64);
+    let mut r = x;
+    loop {
+        let a = r as u64;
+        let q2 = a.saturating_mul(q1).saturating_add(q0);
+        if q2 > max {
+            // The best fraction is either the last convergent or a semiconvergent between it
+            // and the one before
+            let k = (max - q0) / q1;
+            let (p, q) = (p0 + k * p1, q0 + k * q1);
+            let error = |p: u64, q: u64| (x - p as f64 / q as f64).abs();
+            return if error(p, q) < error(p1, q1) {
+                (p, q)
+            } else {
+                (p1, q1)
+            };
+        }
+        let p2 = a * p1 + p0;
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+        let rest = r - a as f64;
+        if rest < 1e-12 {
+            return (p1, q1);
+        }
+        r = 1.0 / rest;
+    }
+}
+
+/// Adds one to a string of digits.
+fn increment(digits: &mut ArrayString<MAX_DIGITS_LEN>) {
+    let mut bytes = [b'0'; MAX_DIGITS_LEN];
+    let len = digits.len();
+    bytes[1..=len].copy_from_slice(digits.as_bytes());
+    let mut i = len;
+    while bytes[i] == b'9' {
+        bytes[i] = b'0';
+        i -= 1;
+    }
+    bytes[i] += 1;
+    let start = if bytes[0] == b'0' { 1 } else { 0 };
+    digits.clear();
+    for &b in &bytes[start..=len] {
+        digits.push(b as char);
+    }
+}
+
+/// Returns the integer digits, fraction digits, and exponent of a number written with
+/// `General`, which uses up to eleven characters like a spreadsheet does in a column of the
+/// default width, switching to scientific notation for very large and very small numbers.
+fn general(decimal: Decimal) -> (ArrayString<16>, ArrayString<16>, ArrayString<8>) {
+    let mut int = ArrayString::new();
+    let mut frac = ArrayString::new();
+    let mut exponent = ArrayString::new();
+
+    let mut fixed = decimal;
+    let int_len = decimal.point.max(1);
+    fixed.round_frac((10 - int_len).max(0) as usize);
+    if decimal.is_zero() {
+        int.push('0');
+    } else if (-8..=11).contains(&decimal.point) && fixed.point <= 11 {
+        if fixed.point <= 0 {
+            int.push('0');
+        }
+        fixed.write_digits(0, fixed.point, &mut int);
+        fixed.write_digits(fixed.point, fixed.len as isize, &mut frac);
+    } else {
+        let mut mantissa = decimal;
+        mantissa.round(6);
+        mantissa.write_digits(0, 1, &mut int);
+        mantissa.write_digits(1, mantissa.len as isize, &mut frac);
+        let e = mantissa.point - 1;
+        let sign = if e < 0 { '-' } else { '+' };
+        write!(exponent, "E{}{:02}", sign, e.abs()).unwrap();
+    }
+    (int, frac, exponent)
+}
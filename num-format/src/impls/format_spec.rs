@@ -0,0 +1,496 @@
+#![allow(trivial_numeric_casts)]
+
+use core::fmt::Write;
+use core::str;
+
+use arrayvec::ArrayString;
+
+use crate::constants::{F64_MAX_FRAC_DIGITS, U128_MAX_LEN};
+use crate::format::{int_groups, WriteFormat};
+use crate::format_spec::{FormatSpec, SpecAlign, SpecSign, SpecType, Specified};
+use crate::ryu::{decimal32, decimal64, decimal_digits, Float};
+use crate::to_formatted_string::WritePieces;
+
+use super::printf::{fixed, scientific};
+
+const ZEROS: &str = "0000000000000000";
+
+macro_rules! impl_specified_float {
+    ($type:ty, $decimal:ident) => {
+        impl WritePieces for Specified<$type> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let f = *self.value();
+                let shortest = if f.is_nonfinite() {
+                    (0, 0)
+                } else {
+                    $decimal(&f.parse_ieee_data())
+                };
+                let is_negative = !f.is_nan() && f.is_sign_negative();
+                let value = FloatValue {
+                    is_negative,
+                    abs: (f as f64).abs(),
+                    shortest,
+                };
+                run_float_algorithm(value, self.spec(), format, w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Specified<$type>);
+    };
+}
+
+macro_rules! impl_specified_integer {
+    ($type:ty) => {
+        impl WritePieces for Specified<$type> {
+            #[allow(unused_comparisons)]
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                run_integer_algorithm(is_negative, n, self.spec(), format, w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Specified<$type>);
+    };
+}
+
+impl_specified_float!(f32, decimal32);
+impl_specified_float!(f64, decimal64);
+
+impl_specified_integer!(u8);
+impl_specified_integer!(u16);
+impl_specified_integer!(u32);
+impl_specified_integer!(usize);
+impl_specified_integer!(u64);
+impl_specified_integer!(u128);
+
+impl_specified_integer!(i8);
+impl_specified_integer!(i16);
+impl_specified_integer!(i32);
+impl_specified_integer!(isize);
+impl_specified_integer!(i64);
+impl_specified_integer!(i128);
+
+// helper functions
+
+/// A float to write, as its sign, its magnitude, and the shortest decimal representation of its
+/// magnitude as `(mantissa, exponent)`.
+#[derive(Copy, Clone)]
+struct FloatValue {
+    is_negative: bool,
+    abs: f64,
+    shortest: (u64, i32),
+}
+
+/// The pieces of a number written with a spec, before padding.
+struct Parts<'a> {
+    sign: &'a str,
+    prefix: &'static str,
+    int_digits: &'a str,
+    // the separator between groups of integer digits, the size of the last group, and the size
+    // of the groups before it
+    group: Option<(&'a str, usize, usize)>,
+    point: &'a str,
+    frac_digits: &'a str,
+    exponent: &'a str,
+    suffix: &'static str,
+}
+
+impl<'a> Parts<'a> {
+    /// Returns the number of characters the integer digits take up after `zeros` leading zeros
+    /// are added to them.
+    fn int_chars(&self, zeros: usize) -> usize {
+        let len = zeros + self.int_digits.len();
+        match self.group {
+            Some((separator, last, rest)) if len > last => {
+                let seps = 1 + (len - last - 1) / rest;
+                len + seps * separator.chars().count()
+            }
+            _ => len,
+        }
+    }
+
+    /// Returns the number of characters the parts take up, other than the integer digits.
+    fn other_chars(&self) -> usize {
+        [
+            self.sign,
+            self.prefix,
+            self.point,
+            self.frac_digits,
+            self.exponent,
+            self.suffix,
+        ]
+        .iter()
+        .map(|s| s.chars().count())
+        .sum()
+    }
+
+    /// Writes the integer digits after `zeros` leading zeros, grouping them all.
+    fn write_int(&self, zeros: usize, w: &mut dyn FnMut(&str)) {
+        let digits = self.int_digits;
+        let len = zeros + digits.len();
+        let mut i = 0;
+        while i < len {
+            // Write up to the next group boundary
+            let end = match self.group {
+                Some((_, last, rest)) if len - i > last => {
+                    let before_last = len - last - i;
+                    match before_last % rest {
+                        0 => i + rest,
+                        n => i + n,
+                    }
+                }
+                _ => len,
+            };
+            let mut j = i;
+            while j < end.min(zeros) {
+                let n = (end.min(zeros) - j).min(ZEROS.len());
+                w(&ZEROS[..n]);
+                j += n;
+            }
+            if end > zeros {
+                w(&digits[j - zeros..end - zeros]);
+            }
+            if let (Some((separator, _, _)), true) = (self.group, end < len) {
+                w(separator);
+            }
+            i = end;
+        }
+    }
+}
+
+/// Writes the parts into `w` with the spec's padding.
+fn write_parts(parts: &Parts<'_>, spec: &FormatSpec, w: &mut dyn FnMut(&str)) {
+    let (fill, align) = spec.padding();
+    let width = spec.width();
+    let other_chars = parts.other_chars();
+
+    // Zero padding after the sign goes into the integer digits, so that it's grouped too (e.g.
+    // `0,001,234`), which can make the number a character wider than asked for
+    let mut zeros = 0;
+    if fill == '0' && align == SpecAlign::AfterSign {
+        while other_chars + parts.int_chars(zeros) < width {
+            zeros += 1;
+        }
+    }
+
+    let len = other_chars + parts.int_chars(zeros);
+    let padding = width.saturating_sub(len);
+    let (before, after_sign, after) = match align {
+        SpecAlign::Left => (0, 0, padding),
+        SpecAlign::Right => (padding, 0, 0),
+        SpecAlign::Center => (padding / 2, 0, padding - padding / 2),
+        SpecAlign::AfterSign => (0, padding, 0),
+    };
+    let mut fill_buf = [0u8; 4];
+    let fill = &*fill.encode_utf8(&mut fill_buf);
+
+    for _ in 0..before {
+        w(fill);
+    }
+    w(parts.sign);
+    w(parts.prefix);
+    for _ in 0..after_sign {
+        w(fill);
+    }
+    parts.write_int(zeros, w);
+    w(parts.point);
+    w(parts.frac_digits);
+    w(parts.exponent);
+    w(parts.suffix);
+    for _ in 0..after {
+        w(fill);
+    }
+}
+
+/// Returns the sign to write in front of a number.
+fn sign<'a, F: WriteFormat>(is_negative: bool, spec: &FormatSpec, format: &'a F) -> &'a str {
+    let is_number = spec.spec_type() == Some(SpecType::Number);
+    match (is_negative, spec.sign()) {
+        (true, _) if is_number => format.minus_sign().into_str(),
+        (true, _) => "-",
+        (false, SpecSign::Always) if is_number => format.plus_sign().into_str(),
+        (false, SpecSign::Always) => "+",
+        (false, SpecSign::Space) => " ",
+        (false, SpecSign::Negative) => "",
+    }
+}
+
+/// Returns how the integer digits of a number are grouped.
+fn group<'a, F: WriteFormat>(spec: &FormatSpec, format: &'a F) -> Option<(&'a str, usize, usize)> {
+    if spec.spec_type() == Some(SpecType::Number) {
+        return int_groups(format);
+    }
+    match spec.grouping() {
+        Some(',') => Some((",", 3, 3)),
+        Some(_) => Some(("_", 3, 3)),
+        None => None,
+    }
+}
+
+#[inline(never)]
+fn run_integer_algorithm<F>(
+    is_negative: bool,
+    mut n: u128,
+    spec: &FormatSpec,
+    format: &F,
+    w: &mut dyn FnMut(&str),
+) where
+    F: WriteFormat,
+{
+    const LOWER: &[u8; 16] = b"0123456789abcdef";
+    const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+    // Integers written with a float type are converted into floats, just like in Python
+    let (bits, uppercase, prefix) = match spec.spec_type() {
+        Some(SpecType::Fixed { .. })
+        | Some(SpecType::Exponent { .. })
+        | Some(SpecType::General { .. })
+        | Some(SpecType::Percent) => {
+            let value = FloatValue {
+                is_negative,
+                abs: n as f64,
+                shortest: (0, 0),
+            };
+            return run_float_algorithm(value, spec, format, w);
+        }
+        Some(SpecType::Hex { uppercase }) => (4, uppercase, if uppercase { "0X" } else { "0x" }),
+        Some(SpecType::Binary) => (1, false, "0b"),
+        Some(SpecType::Octal) => (3, false, "0o"),
+        Some(SpecType::Decimal) | Some(SpecType::Number) | None => (0, false, ""),
+    };
+
+    let mut digits = [0u8; 128];
+    let int_digits = if bits == 0 {
+        crate::itoa::format(n, digits.as_mut_ptr(), U128_MAX_LEN)
+    } else {
+        let table = if uppercase { UPPER } else { LOWER };
+        let mask = (1u128 << bits) - 1;
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = table[(n & mask) as usize];
+            n >>= bits;
+            if n == 0 {
+                break;
+            }
+        }
+        unsafe { str::from_utf8_unchecked(&digits[start..]) }
+    };
+
+    let parts = Parts {
+        sign: sign(is_negative, spec, format),
+        prefix: if spec.alternate() { prefix } else { "" },
+        int_digits,
+        group: match group(spec, format) {
+            Some((separator, _, _)) if bits != 0 => Some((separator, 4, 4)),
+            group => group,
+        },
+        point: "",
+        frac_digits: "",
+        exponent: "",
+        suffix: "",
+    };
+    write_parts(&parts, spec, w)
+}
+
+/// How a float is written, after working out what its spec's type asks for.
+#[derive(Copy, Clone)]
+enum Notation {
+    /// Fixed-point notation with the given number of fraction digits
+    Fixed(usize),
+    /// Scientific notation with the given number of fraction digits
+    Exponent(usize),
+    /// Fixed-point or scientific notation with the given number of significant digits, with
+    /// scientific notation used for exponents from `max_exponent` up
+    General { digits: usize, max_exponent: i32 },
+    /// The shortest representation, like Python's `repr`
+    Shortest,
+}
+
+#[inline(never)]
+fn run_float_algorithm<F>(value: FloatValue, spec: &FormatSpec, format: &F, w: &mut dyn FnMut(&str))
+where
+    F: WriteFormat,
+{
+    let ty = spec.spec_type();
+    let precision = spec.precision();
+    let alternate = spec.alternate();
+    let is_number = ty == Some(SpecType::Number);
+    let (notation, uppercase) = match ty {
+        Some(SpecType::Fixed { uppercase }) => (Notation::Fixed(precision.unwrap_or(6)), uppercase),
+        Some(SpecType::Percent) => (Notation::Fixed(precision.unwrap_or(6)), false),
+        Some(SpecType::Exponent { uppercase }) => {
+            (Notation::Exponent(precision.unwrap_or(6)), uppercase)
+        }
+        Some(SpecType::General { uppercase }) => {
+            let digits = precision.unwrap_or(6).max(1);
+            let max_exponent = digits as i32;
+            (
+                Notation::General {
+                    digits,
+                    max_exponent,
+                },
+                uppercase,
+            )
+        }
+        Some(SpecType::Number) => {
+            let digits = precision.unwrap_or(6).max(1);
+            let max_exponent = digits as i32;
+            (
+                Notation::General {
+                    digits,
+                    max_exponent,
+                },
+                false,
+            )
+        }
+        // Floats written with an integer type are written as if the spec had no type
+        _ => match precision {
+            Some(precision) => {
+                let digits = precision.max(1);
+                let max_exponent = digits as i32 - 1;
+                (
+                    Notation::General {
+                        digits,
+                        max_exponent,
+                    },
+                    false,
+                )
+            }
+            None => (Notation::Shortest, false),
+        },
+    };
+    let suffix = if ty == Some(SpecType::Percent) {
+        "%"
+    } else {
+        ""
+    };
+    let abs = if ty == Some(SpecType::Percent) {
+        value.abs * 100.0
+    } else {
+        value.abs
+    };
+
+    if !abs.is_finite() {
+        let symbol = match (abs.is_nan(), is_number, uppercase) {
+            (true, true, _) => format.nan().into_str(),
+            (false, true, _) => format.infinity().into_str(),
+            (true, false, false) => "nan",
+            (true, false, true) => "NAN",
+            (false, false, false) => "inf",
+            (false, false, true) => "INF",
+        };
+        let parts = Parts {
+            sign: sign(value.is_negative, spec, format),
+            prefix: "",
+            int_digits: symbol,
+            group: None,
+            point: "",
+            frac_digits: "",
+            exponent: "",
+            suffix,
+        };
+        return write_parts(&parts, spec, w);
+    }
+
+    // Work out the digits: those of the shortest representation come from ryu, and those of
+    // any other come from core::fmt, which rounds the exact value of the float half to even
+    let mut text = String::new();
+    let mut shortest = [b'0'; F64_MAX_FRAC_DIGITS];
+    let (int_digits, mut frac_digits, exponent) = match notation {
+        Notation::Fixed(precision) => {
+            let (int_digits, frac_digits) = fixed(abs, precision, &mut text);
+            (int_digits, frac_digits, None)
+        }
+        Notation::Exponent(precision) => {
+            let (int_digits, frac_digits, exponent) = scientific(abs, precision, &mut text);
+            (int_digits, frac_digits, Some(exponent))
+        }
+        Notation::General {
+            digits,
+            max_exponent,
+        } => {
+            let (_, _, exponent) = scientific(abs, digits - 1, &mut text);
+            text.clear();
+            if -4 <= exponent && exponent < max_exponent {
+                let precision = (digits as i32 - 1 - exponent) as usize;
+                let (int_digits, frac_digits) = fixed(abs, precision, &mut text);
+                (int_digits, frac_digits, None)
+            } else {
+                let (int_digits, frac_digits, exponent) = scientific(abs, digits - 1, &mut text);
+                (int_digits, frac_digits, Some(exponent))
+            }
+        }
+        Notation::Shortest => {
+            let (mantissa, exponent) = value.shortest;
+            let (int_digits, frac_digits) = decimal_digits(mantissa, exponent, &mut shortest);
+            let len = int_digits.trim_start_matches('0').len();
+            let exponent = if len > 0 {
+                len as i32 - 1
+            } else {
+                let zeros = frac_digits.len() - frac_digits.trim_start_matches('0').len();
+                -(zeros as i32) - 1
+            };
+            if mantissa == 0 || (-4..16).contains(&exponent) {
+                (int_digits, frac_digits, None)
+            } else {
+                write!(text, "{}", mantissa).unwrap();
+                let text = text.trim_end_matches('0');
+                let (int_digits, frac_digits) = text.split_at(1);
+                (int_digits, frac_digits, Some(exponent))
+            }
+        }
+    };
+
+    // Trailing zeros are left out of general notation, but not of the alternate form, and the
+    // shortest representation in fixed-point notation always has a fraction digit
+    if let Notation::General { .. } = notation {
+        if !alternate {
+            frac_digits = frac_digits.trim_end_matches('0');
+        }
+        let has_float_type = matches!(ty, Some(ty) if !ty.is_integer());
+        if !has_float_type && exponent.is_none() && frac_digits.is_empty() {
+            frac_digits = "0";
+        }
+    }
+
+    let mut exponent_buf = ArrayString::<8>::new();
+    if let Some(exponent) = exponent {
+        let e = if uppercase { 'E' } else { 'e' };
+        let sign = if exponent < 0 { '-' } else { '+' };
+        write!(exponent_buf, "{}{}{:02}", e, sign, exponent.abs()).unwrap();
+    }
+
+    let point = if !frac_digits.is_empty() || alternate {
+        if is_number {
+            format.decimal().into_str()
+        } else {
+            "."
+        }
+    } else {
+        ""
+    };
+    let parts = Parts {
+        sign: sign(value.is_negative, spec, format),
+        prefix: "",
+        int_digits,
+        group: group(spec, format),
+        point,
+        frac_digits,
+        exponent: &exponent_buf,
+        suffix,
+    };
+    write_parts(&parts, spec, w)
+}
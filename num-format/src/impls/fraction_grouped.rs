@@ -0,0 +1,98 @@
+use crate::buffer::Buffer;
+use crate::format::{Format, WriteFormat};
+use crate::fraction_grouped::FractionGrouped;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+use crate::to_formatted_str::ToFormattedStr;
+use crate::{Grouping, NumberPattern};
+
+/// The format a [`FractionGrouped`] value is written with: the given format, with the value's
+/// fraction grouping in place of its own.
+///
+/// [`FractionGrouped`]: ../../struct.FractionGrouped.html
+struct Grouped<'a, F> {
+    format: &'a F,
+    size: usize,
+    separator: &'a str,
+}
+
+impl<'a, F> Grouped<'a, F> {
+    fn new<T>(value: &'a FractionGrouped<T>, format: &'a F) -> Self {
+        Grouped {
+            format,
+            size: value.size() as usize,
+            separator: value.separator(),
+        }
+    }
+}
+
+impl<F> Format for Grouped<'_, F>
+where
+    F: WriteFormat,
+{
+    fn decimal(&self) -> DecimalStr<'_> {
+        self.format.decimal()
+    }
+
+    fn grouping(&self) -> Grouping {
+        self.format.grouping()
+    }
+
+    fn infinity(&self) -> InfinityStr<'_> {
+        self.format.infinity()
+    }
+
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        self.format.minus_sign()
+    }
+
+    fn nan(&self) -> NanStr<'_> {
+        self.format.nan()
+    }
+
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        self.format.plus_sign()
+    }
+
+    fn separator(&self) -> SeparatorStr<'_> {
+        self.format.separator()
+    }
+}
+
+impl<F> WriteFormat for Grouped<'_, F>
+where
+    F: WriteFormat,
+{
+    fn fraction_grouping(&self) -> (usize, &str) {
+        (self.size, self.separator)
+    }
+
+    fn number_pattern(&self) -> Option<&NumberPattern> {
+        self.format.number_pattern()
+    }
+}
+
+impl<T> ToFormattedStr for FractionGrouped<T>
+where
+    T: ToFormattedStr,
+{
+    #[doc(hidden)]
+    #[inline(never)]
+    fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+    where
+        F: WriteFormat,
+    {
+        self.value()
+            .read_to_buffer(buf, &Grouped::new(self, format))
+    }
+}
+
+impl<T> crate::private::Sealed for FractionGrouped<T> {}
+
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(FractionGrouped, Grouped::new, crate::Decimal);
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(FractionGrouped, Grouped::new, crate::Measure<T>, T);
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(FractionGrouped, Grouped::new, crate::Range<T>, T);
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(FractionGrouped, Grouped::new, crate::Approximately<T>, T);
@@ -5,8 +5,9 @@ use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZ
 
 use crate::buffer::Buffer;
 use crate::constants::*;
-use crate::format::{write_one_byte_with_sep, write_two_bytes_with_sep, Format, Sep};
+use crate::format::{write_one_byte_with_sep, write_two_bytes_with_sep, Sep, WriteFormat};
 use crate::grouping::Grouping;
+use crate::number_pattern::{write_pattern_to_buffer, NumberPattern};
 
 use crate::to_formatted_str::ToFormattedStr;
 
@@ -15,10 +16,13 @@ use crate::to_formatted_str::ToFormattedStr;
 impl ToFormattedStr for u8 {
     #[doc(hidden)]
     #[inline(never)]
-    fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, _: &F) -> usize
+    fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
     where
-        F: Format,
+        F: WriteFormat,
     {
+        if let Some(pattern) = format.number_pattern() {
+            return run_pattern_algorithm(false, *self as u128, buf, pattern, format);
+        }
         let s = crate::itoa::format(*self, buf.inner.as_mut_ptr(), buf.pos);
         let s_len = s.len();
         buf.pos -= s_len;
@@ -27,26 +31,29 @@ impl ToFormattedStr for u8 {
 }
 
 macro_rules! impl_unsigned {
-    ($type:ty, $max_len:expr) => {
+    ($type:ty) => {
         impl ToFormattedStr for $type {
             #[doc(hidden)]
             #[inline(never)]
             fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
             where
-                F: Format,
+                F: WriteFormat,
             {
                 let n = *self as u128;
+                if let Some(pattern) = format.number_pattern() {
+                    return run_pattern_algorithm(false, n, buf, pattern, format);
+                }
                 run_core_algorithm(n, buf, format)
             }
         }
     };
 }
 
-impl_unsigned!(u16, U16_MAX_LEN);
-impl_unsigned!(u32, U32_MAX_LEN);
-impl_unsigned!(usize, USIZE_MAX_LEN);
-impl_unsigned!(u64, U64_MAX_LEN);
-impl_unsigned!(u128, U128_MAX_LEN);
+impl_unsigned!(u16);
+impl_unsigned!(u32);
+impl_unsigned!(usize);
+impl_unsigned!(u64);
+impl_unsigned!(u128);
 
 impl crate::private::Sealed for u8 {}
 impl crate::private::Sealed for u16 {}
@@ -58,14 +65,18 @@ impl crate::private::Sealed for u128 {}
 // signed integers
 
 macro_rules! impl_signed {
-    ($type:ty, $max_len:expr) => {
+    ($type:ty, $unsigned_type:ty) => {
         impl ToFormattedStr for $type {
             #[doc(hidden)]
             #[inline(never)]
             fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
             where
-                F: Format,
+                F: WriteFormat,
             {
+                if let Some(pattern) = format.number_pattern() {
+                    let n = (*self as i128).unsigned_abs();
+                    return run_pattern_algorithm(self.is_negative(), n, buf, pattern, format);
+                }
                 if self.is_negative() {
                     let n = (!(*self as u128)).wrapping_add(1); // make positive by adding 1 to the 2s complement
                     let c = run_core_algorithm(n, buf, format);
@@ -86,12 +97,12 @@ macro_rules! impl_signed {
     };
 }
 
-impl_signed!(i8, I8_MAX_LEN);
-impl_signed!(i16, I16_MAX_LEN);
-impl_signed!(i32, I32_MAX_LEN);
-impl_signed!(isize, ISIZE_MAX_LEN);
-impl_signed!(i64, I64_MAX_LEN);
-impl_signed!(i128, I128_MAX_LEN);
+impl_signed!(i8, u8);
+impl_signed!(i16, u16);
+impl_signed!(i32, u32);
+impl_signed!(isize, usize);
+impl_signed!(i64, u64);
+impl_signed!(i128, u128);
 
 impl crate::private::Sealed for i8 {}
 impl crate::private::Sealed for i16 {}
@@ -105,10 +116,13 @@ impl crate::private::Sealed for i128 {}
 impl ToFormattedStr for NonZeroU8 {
     #[doc(hidden)]
     #[inline(never)]
-    fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, _: &F) -> usize
+    fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
     where
-        F: Format,
+        F: WriteFormat,
     {
+        if let Some(pattern) = format.number_pattern() {
+            return run_pattern_algorithm(false, self.get() as u128, buf, pattern, format);
+        }
         let s = crate::itoa::format(self.get(), buf.inner.as_mut_ptr(), buf.pos);
         let s_len = s.len();
         buf.pos -= s_len;
@@ -117,26 +131,29 @@ impl ToFormattedStr for NonZeroU8 {
 }
 
 macro_rules! impl_non_zero {
-    ($type:ty, $related_type:ty, $max_len:expr) => {
+    ($type:ty, $related_type:ty) => {
         impl ToFormattedStr for $type {
             #[doc(hidden)]
             #[inline(never)]
             fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
             where
-                F: Format,
+                F: WriteFormat,
             {
                 let n = self.get() as u128;
+                if let Some(pattern) = format.number_pattern() {
+                    return run_pattern_algorithm(false, n, buf, pattern, format);
+                }
                 run_core_algorithm(n, buf, format)
             }
         }
     };
 }
 
-impl_non_zero!(NonZeroU16, u16, U16_MAX_LEN);
-impl_non_zero!(NonZeroU32, u32, U32_MAX_LEN);
-impl_non_zero!(NonZeroUsize, usize, USIZE_MAX_LEN);
-impl_non_zero!(NonZeroU64, u64, U64_MAX_LEN);
-impl_non_zero!(NonZeroU128, u128, U128_MAX_LEN);
+impl_non_zero!(NonZeroU16, u16);
+impl_non_zero!(NonZeroU32, u32);
+impl_non_zero!(NonZeroUsize, usize);
+impl_non_zero!(NonZeroU64, u64);
+impl_non_zero!(NonZeroU128, u128);
 
 impl crate::private::Sealed for NonZeroU8 {}
 impl crate::private::Sealed for NonZeroU16 {}
@@ -150,7 +167,7 @@ impl crate::private::Sealed for NonZeroU128 {}
 #[inline(never)]
 fn run_core_algorithm<F>(mut n: u128, buf: &mut Buffer, format: &F) -> usize
 where
-    F: Format,
+    F: WriteFormat,
 {
     // Bail out early if we can just use itoa
     // (i.e. if we don't have a separator)
@@ -217,3 +234,19 @@ fn write_two_bytes(buf: &mut Buffer, sep: &mut Sep<'_>, table_index: isize) {
     let index = buf.pos as isize;
     buf.pos = write_two_bytes_with_sep(buf.as_mut_ptr(), index, sep, table_index) as usize;
 }
+
+#[inline(never)]
+fn run_pattern_algorithm<F>(
+    is_negative: bool,
+    n: u128,
+    buf: &mut Buffer,
+    pattern: &NumberPattern,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    let mut digits = [0u8; U128_MAX_LEN];
+    let int_digits = crate::itoa::format(n, digits.as_mut_ptr(), digits.len());
+    write_pattern_to_buffer(is_negative, int_digits, "", pattern, buf, format)
+}
@@ -0,0 +1,130 @@
+#![allow(trivial_numeric_casts)]
+
+use crate::buffer::Buffer;
+use crate::constants::MAX_BUF_LEN;
+use crate::format::WriteFormat;
+use crate::locale::Locale;
+use crate::measure::{Measure, MeasureUnit, UnitWidth};
+use crate::plural::{PluralCategory, PluralOperands};
+use crate::ryu::{decimal32, decimal64, format_decimal, Float};
+use crate::to_formatted_string::WritePieces;
+
+use super::scaled::write_scaled;
+
+macro_rules! impl_measure_float {
+    ($type:ty, $decimal:ident) => {
+        impl WritePieces for Measure<$type> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let f = *self.value();
+                if f.is_nonfinite() {
+                    let pattern = unit_pattern(self, PluralOperands::nonfinite());
+                    return write_pattern(pattern, f.format_nonfinite(), w);
+                }
+
+                let ieee = f.parse_ieee_data();
+                let (mantissa, exponent) = $decimal(&ieee);
+                let operands = PluralOperands::from_float(mantissa, exponent);
+                let pattern = unit_pattern(self, operands);
+                let mut buf = Buffer::new();
+                let ptr = buf.as_mut_ptr();
+                buf.pos = format_decimal(
+                    ptr,
+                    MAX_BUF_LEN,
+                    ieee.is_negative,
+                    mantissa,
+                    exponent,
+                    format,
+                );
+                write_pattern(pattern, buf.as_str(), w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Measure<$type>);
+    };
+}
+
+macro_rules! impl_measure_integer {
+    ($type:ty) => {
+        impl WritePieces for Measure<$type> {
+            #[allow(unused_comparisons)]
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                let operands = PluralOperands::from(n);
+                let pattern = unit_pattern(self, operands);
+                let mut buf = Buffer::new();
+                buf.pos =
+                    MAX_BUF_LEN - write_scaled(is_negative, n, 0, &mut buf, MAX_BUF_LEN, format);
+                write_pattern(pattern, buf.as_str(), w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Measure<$type>);
+    };
+}
+
+impl_measure_float!(f32, decimal32);
+impl_measure_float!(f64, decimal64);
+
+impl_measure_integer!(u8);
+impl_measure_integer!(u16);
+impl_measure_integer!(u32);
+impl_measure_integer!(usize);
+impl_measure_integer!(u64);
+impl_measure_integer!(u128);
+
+impl_measure_integer!(i8);
+impl_measure_integer!(i16);
+impl_measure_integer!(i32);
+impl_measure_integer!(isize);
+impl_measure_integer!(i64);
+impl_measure_integer!(i128);
+
+// helper functions
+
+/// Returns the unit pattern (e.g. `"{0} km"`) of the measure's locale for its unit, width, and
+/// plural category.
+fn unit_pattern<N>(measure: &Measure<N>, operands: PluralOperands) -> &'static str {
+    let locale = measure.locale();
+    let category = locale.plural_category(operands);
+    category_unit_pattern(locale, measure.unit(), measure.width(), category)
+}
+
+/// Returns the locale's unit pattern for the unit, width, and plural category, falling back to
+/// the pattern of the "other" category if the locale has none for the category.
+pub(crate) fn category_unit_pattern(
+    locale: Locale,
+    unit: MeasureUnit,
+    width: UnitWidth,
+    category: PluralCategory,
+) -> &'static str {
+    let patterns = locale.unit_patterns(unit, width);
+    match patterns[category as usize] {
+        "" => patterns[PluralCategory::Other as usize],
+        pattern => pattern,
+    }
+}
+
+/// Passes the pattern to `w` with the number in place of its placeholder. Patterns without a
+/// placeholder (e.g. Arabic "متران" for two meters) are written without the number.
+fn write_pattern(pattern: &str, number: &str, w: &mut dyn FnMut(&str)) {
+    match pattern.find("{0}") {
+        Some(i) => {
+            w(&pattern[..i]);
+            w(number);
+            w(&pattern[i + 3..]);
+        }
+        None => w(pattern),
+    }
+}
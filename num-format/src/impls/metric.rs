@@ -0,0 +1,164 @@
+#![allow(trivial_numeric_casts)]
+
+use crate::buffer::Buffer;
+use crate::constants::MAX_BUF_LEN;
+use crate::format::WriteFormat;
+use crate::metric::{Metric, SI_PREFIXES};
+use crate::ryu::{decimal32, decimal64, format_decimal, Float};
+use crate::to_formatted_str::ToFormattedStr;
+
+use super::scaled::write_scaled;
+
+macro_rules! impl_metric_float {
+    ($type:ty, $decimal:ident) => {
+        impl ToFormattedStr for Metric<$type> {
+            #[doc(hidden)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let f = *self.value();
+                if f.is_nonfinite() {
+                    let end = write_suffix(buf, "", self.unit());
+                    let s = f.format_nonfinite();
+                    buf.inner[end - s.len()..end].copy_from_slice(s.as_bytes());
+                    buf.pos = end - s.len();
+                    buf.end = MAX_BUF_LEN;
+                    return MAX_BUF_LEN - buf.pos;
+                }
+
+                let ieee = f.parse_ieee_data();
+                let (mantissa, exponent) = $decimal(&ieee);
+                let e = if mantissa == 0 {
+                    0
+                } else {
+                    exponent + count_digits(u128::from(mantissa)) - 1
+                };
+                let k = prefix_exponent(e);
+                let end = write_suffix(buf, SI_PREFIXES[(k + 10) as usize], self.unit());
+                let ptr = buf.inner.as_mut_ptr();
+                let start = format_decimal(
+                    ptr,
+                    end,
+                    ieee.is_negative,
+                    mantissa,
+                    exponent - 3 * k,
+                    format,
+                );
+                buf.pos = start;
+                buf.end = MAX_BUF_LEN;
+                MAX_BUF_LEN - start
+            }
+        }
+
+        impl crate::private::Sealed for Metric<$type> {}
+    };
+}
+
+macro_rules! impl_metric_integer {
+    ($type:ty) => {
+        impl ToFormattedStr for Metric<$type> {
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                run_metric_integer_algorithm(is_negative, n, self.unit(), buf, format)
+            }
+        }
+
+        impl crate::private::Sealed for Metric<$type> {}
+    };
+}
+
+impl_metric_float!(f32, decimal32);
+impl_metric_float!(f64, decimal64);
+
+impl_metric_integer!(u8);
+impl_metric_integer!(u16);
+impl_metric_integer!(u32);
+impl_metric_integer!(usize);
+impl_metric_integer!(u64);
+impl_metric_integer!(u128);
+
+impl_metric_integer!(i8);
+impl_metric_integer!(i16);
+impl_metric_integer!(i32);
+impl_metric_integer!(isize);
+impl_metric_integer!(i64);
+impl_metric_integer!(i128);
+
+// helper functions
+
+#[inline(never)]
+fn run_metric_integer_algorithm<F>(
+    is_negative: bool,
+    n: u128,
+    unit: &str,
+    buf: &mut Buffer,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    let k = if n == 0 {
+        0
+    } else {
+        prefix_exponent(count_digits(n) - 1)
+    };
+    let end = write_suffix(buf, SI_PREFIXES[(k + 10) as usize], unit);
+
+    // Drop the trailing zeros that would otherwise be written after the decimal point
+    let mut n = n;
+    let mut scale = (3 * k) as u8;
+    while scale > 0 {
+        let (quotient, digit) = (n / 10, n % 10);
+        if digit != 0 {
+            break;
+        }
+        n = quotient;
+        scale -= 1;
+    }
+    let c = write_scaled(is_negative, n, scale, buf, end, format);
+    buf.pos = end - c;
+    buf.end = MAX_BUF_LEN;
+    MAX_BUF_LEN - buf.pos
+}
+
+/// Returns the power of 1000 to scale a value by, given the power of ten of its leading digit.
+fn prefix_exponent(e: i32) -> i32 {
+    let k = if e < 0 { (e - 2) / 3 } else { e / 3 };
+    k.clamp(-10, 10)
+}
+
+fn count_digits(mut n: u128) -> i32 {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Writes a space, the prefix, and the unit at the end of the buffer, returning the index they
+/// start at. Writes nothing if both the prefix and the unit are empty.
+fn write_suffix(buf: &mut Buffer, prefix: &str, unit: &str) -> usize {
+    if prefix.is_empty() && unit.is_empty() {
+        return MAX_BUF_LEN;
+    }
+    let start = MAX_BUF_LEN - unit.len() - prefix.len() - 1;
+    buf.inner[start] = b' ';
+    buf.inner[start + 1..start + 1 + prefix.len()].copy_from_slice(prefix.as_bytes());
+    buf.inner[start + 1 + prefix.len()..].copy_from_slice(unit.as_bytes());
+    start
+}
@@ -6,15 +6,24 @@ use std::ops::Neg;
 
 use num_bigint::{BigInt, BigUint, Sign};
 
-use crate::{Format, Grouping, ToFormattedString};
+use crate::format::WriteFormat;
+use crate::number_pattern::{write_pattern, NumberPattern};
+use crate::plural::PluralOperands;
+use crate::rbnf::{RuleValue, RuleWriter};
+use crate::{Grouping, Locale, RadixFormat, Radixed, SpellOut, ToFormattedString};
 
 impl ToFormattedString for BigInt {
     #[inline(never)]
     fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: io::Write,
     {
+        if let Some(pattern) = format.number_pattern() {
+            let s = pattern_algorithm(self.sign(), self.magnitude(), format, pattern);
+            w.write_all(s.as_bytes())?;
+            return Ok(s.len());
+        }
         match self.sign() {
             Sign::Minus => {
                 let minus_sign = format.minus_sign().into_str();
@@ -34,9 +43,14 @@ impl ToFormattedString for BigInt {
     #[inline(never)]
     fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: fmt::Write,
     {
+        if let Some(pattern) = format.number_pattern() {
+            let s = pattern_algorithm(self.sign(), self.magnitude(), format, pattern);
+            w.write_str(&s)?;
+            return Ok(s.len());
+        }
         match self.sign() {
             Sign::Minus => {
                 let minus_sign = format.minus_sign().into_str();
@@ -56,22 +70,32 @@ impl ToFormattedString for BigInt {
 
 impl ToFormattedString for BigUint {
     #[inline(never)]
-    fn read_to_io_writer<F, W>(&self, w: W, format: &F) -> Result<usize, io::Error>
+    fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: io::Write,
     {
+        if let Some(pattern) = format.number_pattern() {
+            let s = pattern_algorithm(Sign::Plus, self, format, pattern);
+            w.write_all(s.as_bytes())?;
+            return Ok(s.len());
+        }
         let s = self.to_string();
         let c = io_algorithm(s, w, format)?;
         Ok(c)
     }
 
     #[inline(never)]
-    fn read_to_fmt_writer<F, W>(&self, w: W, format: &F) -> Result<usize, fmt::Error>
+    fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: fmt::Write,
     {
+        if let Some(pattern) = format.number_pattern() {
+            let s = pattern_algorithm(Sign::Plus, self, format, pattern);
+            w.write_str(&s)?;
+            return Ok(s.len());
+        }
         let s = self.to_string();
         let c = fmt_algorithm(s, w, format)?;
         Ok(c)
@@ -81,11 +105,231 @@ impl ToFormattedString for BigUint {
 impl crate::private::Sealed for BigInt {}
 impl crate::private::Sealed for BigUint {}
 
+impl ToFormattedString for Radixed<BigInt> {
+    #[inline(never)]
+    fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
+    where
+        F: WriteFormat,
+        W: io::Write,
+    {
+        let value = self.value();
+        let s = radix_algorithm(value.sign(), value.magnitude(), format, self.format());
+        w.write_all(s.as_bytes())?;
+        Ok(s.len())
+    }
+
+    #[inline(never)]
+    fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
+    where
+        F: WriteFormat,
+        W: fmt::Write,
+    {
+        let value = self.value();
+        let s = radix_algorithm(value.sign(), value.magnitude(), format, self.format());
+        w.write_str(&s)?;
+        Ok(s.len())
+    }
+}
+
+impl ToFormattedString for Radixed<BigUint> {
+    #[inline(never)]
+    fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
+    where
+        F: WriteFormat,
+        W: io::Write,
+    {
+        let s = radix_algorithm(Sign::Plus, self.value(), format, self.format());
+        w.write_all(s.as_bytes())?;
+        Ok(s.len())
+    }
+
+    #[inline(never)]
+    fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
+    where
+        F: WriteFormat,
+        W: fmt::Write,
+    {
+        let s = radix_algorithm(Sign::Plus, self.value(), format, self.format());
+        w.write_str(&s)?;
+        Ok(s.len())
+    }
+}
+
+impl crate::private::Sealed for Radixed<BigInt> {}
+impl crate::private::Sealed for Radixed<BigUint> {}
+
+impl ToFormattedString for SpellOut<BigInt> {
+    #[inline(never)]
+    fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
+    where
+        F: WriteFormat,
+        W: io::Write,
+    {
+        let value = self.value();
+        match spell_out_algorithm(value.sign(), value.magnitude(), self.locale(), format) {
+            Some(s) => {
+                w.write_all(s.as_bytes())?;
+                Ok(s.len())
+            }
+            None => value.read_to_io_writer(w, format),
+        }
+    }
+
+    #[inline(never)]
+    fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
+    where
+        F: WriteFormat,
+        W: fmt::Write,
+    {
+        let value = self.value();
+        match spell_out_algorithm(value.sign(), value.magnitude(), self.locale(), format) {
+            Some(s) => {
+                w.write_str(&s)?;
+                Ok(s.len())
+            }
+            None => value.read_to_fmt_writer(w, format),
+        }
+    }
+}
+
+impl ToFormattedString for SpellOut<BigUint> {
+    #[inline(never)]
+    fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
+    where
+        F: WriteFormat,
+        W: io::Write,
+    {
+        match spell_out_algorithm(Sign::Plus, self.value(), self.locale(), format) {
+            Some(s) => {
+                w.write_all(s.as_bytes())?;
+                Ok(s.len())
+            }
+            None => self.value().read_to_io_writer(w, format),
+        }
+    }
+
+    #[inline(never)]
+    fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
+    where
+        F: WriteFormat,
+        W: fmt::Write,
+    {
+        match spell_out_algorithm(Sign::Plus, self.value(), self.locale(), format) {
+            Some(s) => {
+                w.write_str(&s)?;
+                Ok(s.len())
+            }
+            None => self.value().read_to_fmt_writer(w, format),
+        }
+    }
+}
+
+impl crate::private::Sealed for SpellOut<BigInt> {}
+impl crate::private::Sealed for SpellOut<BigUint> {}
+
+impl RuleValue for BigUint {
+    fn is_at_least(&self, base: u64) -> bool {
+        *self >= BigUint::from(base)
+    }
+
+    fn div_rem(&self, divisor: u64) -> (BigUint, BigUint) {
+        (self / divisor, self % divisor)
+    }
+
+    fn plural_operands(&self) -> PluralOperands {
+        self.to_string().parse().unwrap()
+    }
+
+    fn with_digits<G>(&self, f: G)
+    where
+        G: FnOnce(&str),
+    {
+        f(&self.to_string())
+    }
+}
+
+#[inline(never)]
+fn radix_algorithm<F>(sign: Sign, magnitude: &BigUint, format: &F, radix: &RadixFormat) -> String
+where
+    F: WriteFormat,
+{
+    let mut digits = magnitude.to_str_radix(radix.radix().base());
+    if radix.uppercase() {
+        digits.make_ascii_uppercase();
+    }
+
+    let mut s = String::new();
+    if sign == Sign::Minus {
+        s.push_str(format.minus_sign().into_str());
+    }
+    s.push_str(radix.prefix());
+
+    let group_size = radix.group_size() as usize;
+    if group_size == 0 {
+        s.push_str(&digits);
+        return s;
+    }
+
+    let first = match digits.len() % group_size {
+        0 => group_size,
+        n => n,
+    };
+    let (head, tail) = digits.split_at(first);
+    s.push_str(head);
+    for group in tail.as_bytes().chunks(group_size) {
+        s.push_str(radix.separator());
+        s.push_str(std::str::from_utf8(group).unwrap());
+    }
+
+    s
+}
+
+#[inline(never)]
+fn pattern_algorithm<F>(
+    sign: Sign,
+    magnitude: &BigUint,
+    format: &F,
+    pattern: &NumberPattern,
+) -> String
+where
+    F: WriteFormat,
+{
+    let digits = magnitude.to_string();
+    let mut s = String::new();
+    write_pattern(sign == Sign::Minus, &digits, "", pattern, format, |piece| {
+        s.push_str(piece)
+    });
+    s
+}
+
+/// Returns the number spelled out with the rules of the locale, or `None` if the locale's
+/// language has no spell-out rules.
+#[inline(never)]
+fn spell_out_algorithm<F>(
+    sign: Sign,
+    magnitude: &BigUint,
+    locale: Locale,
+    format: &F,
+) -> Option<String>
+where
+    F: WriteFormat,
+{
+    let rules = locale.spellout_rules()?;
+    let mut s = String::new();
+    let mut writer = RuleWriter {
+        locale,
+        format,
+        w: |piece: &str| s.push_str(piece),
+    };
+    writer.write(rules, sign == Sign::Minus, magnitude);
+    Some(s)
+}
+
 #[inline(never)]
 fn io_algorithm<F, W>(s: String, mut w: W, format: &F) -> Result<usize, io::Error>
 where
     W: io::Write,
-    F: Format,
+    F: WriteFormat,
 {
     let separator = format.separator().into_str();
     let grouping = format.grouping();
@@ -145,7 +389,7 @@ where
 fn fmt_algorithm<F, W>(s: String, mut w: W, format: &F) -> Result<usize, fmt::Error>
 where
     W: fmt::Write,
-    F: Format,
+    F: WriteFormat,
 {
     let separator = format.separator().into_str();
     let grouping = format.grouping();
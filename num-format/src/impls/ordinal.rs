@@ -0,0 +1,63 @@
+#![allow(trivial_numeric_casts)]
+
+use crate::buffer::Buffer;
+use crate::format::WriteFormat;
+use crate::locale::Locale;
+use crate::ordinal::Ordinal;
+use crate::rbnf::write_to_buffer;
+use crate::to_formatted_str::ToFormattedStr;
+
+macro_rules! impl_ordinal {
+    ($type:ty) => {
+        impl ToFormattedStr for Ordinal<$type> {
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                write_ordinal(is_negative, n, self.locale(), buf, format)
+            }
+        }
+
+        impl crate::private::Sealed for Ordinal<$type> {}
+    };
+}
+
+impl_ordinal!(u8);
+impl_ordinal!(u16);
+impl_ordinal!(u32);
+impl_ordinal!(usize);
+impl_ordinal!(u64);
+impl_ordinal!(u128);
+
+impl_ordinal!(i8);
+impl_ordinal!(i16);
+impl_ordinal!(i32);
+impl_ordinal!(isize);
+impl_ordinal!(i64);
+impl_ordinal!(i128);
+
+// helper functions
+
+/// Writes the ordinal at the end of the buffer, returning the number of bytes written.
+fn write_ordinal<F>(
+    is_negative: bool,
+    n: u128,
+    locale: Locale,
+    buf: &mut Buffer,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    write_to_buffer(locale.ordinal_rules(), locale, is_negative, n, buf, format)
+}
@@ -0,0 +1,99 @@
+use crate::buffer::Buffer;
+use crate::format::{Format, WriteFormat};
+use crate::patterned::Patterned;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+use crate::to_formatted_str::ToFormattedStr;
+use crate::{Grouping, NumberPattern};
+
+/// The format a [`Patterned`] value is written with: the given format, with the value's pattern.
+///
+/// [`Patterned`]: ../../struct.Patterned.html
+struct WithPattern<'a, F> {
+    format: &'a F,
+    pattern: &'a NumberPattern,
+}
+
+impl<'a, F> WithPattern<'a, F> {
+    fn new<T>(value: &'a Patterned<T>, format: &'a F) -> Self {
+        WithPattern {
+            format,
+            pattern: value.pattern(),
+        }
+    }
+}
+
+impl<F> Format for WithPattern<'_, F>
+where
+    F: WriteFormat,
+{
+    fn decimal(&self) -> DecimalStr<'_> {
+        self.format.decimal()
+    }
+
+    fn grouping(&self) -> Grouping {
+        self.format.grouping()
+    }
+
+    fn infinity(&self) -> InfinityStr<'_> {
+        self.format.infinity()
+    }
+
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        self.format.minus_sign()
+    }
+
+    fn nan(&self) -> NanStr<'_> {
+        self.format.nan()
+    }
+
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        self.format.plus_sign()
+    }
+
+    fn separator(&self) -> SeparatorStr<'_> {
+        self.format.separator()
+    }
+}
+
+impl<F> WriteFormat for WithPattern<'_, F>
+where
+    F: WriteFormat,
+{
+    fn fraction_grouping(&self) -> (usize, &str) {
+        self.format.fraction_grouping()
+    }
+
+    fn number_pattern(&self) -> Option<&NumberPattern> {
+        Some(self.pattern)
+    }
+}
+
+impl<T> ToFormattedStr for Patterned<T>
+where
+    T: ToFormattedStr,
+{
+    #[doc(hidden)]
+    #[inline(never)]
+    fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+    where
+        F: WriteFormat,
+    {
+        self.value()
+            .read_to_buffer(buf, &WithPattern::new(self, format))
+    }
+}
+
+impl<T> crate::private::Sealed for Patterned<T> {}
+
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(Patterned, WithPattern::new, crate::Decimal);
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(Patterned, WithPattern::new, crate::Measure<T>, T);
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(Patterned, WithPattern::new, crate::Range<T>, T);
+#[cfg(feature = "std")]
+impl_to_formatted_string_by_wrapping!(Patterned, WithPattern::new, crate::Approximately<T>, T);
+#[cfg(feature = "with-num-bigint")]
+impl_to_formatted_string_by_wrapping!(Patterned, WithPattern::new, num_bigint::BigInt);
+#[cfg(feature = "with-num-bigint")]
+impl_to_formatted_string_by_wrapping!(Patterned, WithPattern::new, num_bigint::BigUint);
@@ -0,0 +1,297 @@
+#![allow(trivial_numeric_casts)]
+
+use core::fmt::Write;
+
+use arrayvec::ArrayString;
+
+use crate::format::{int_groups, WriteFormat};
+use crate::printf::{Printf, PrintfConversion, PrintfSpec};
+use crate::to_formatted_string::WritePieces;
+
+macro_rules! impl_printf_float {
+    ($type:ty) => {
+        impl WritePieces for Printf<$type> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let f = *self.value();
+                let abs = (f as f64).abs();
+                run_float_algorithm(f.is_sign_negative(), abs, self.spec(), format, w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Printf<$type>);
+    };
+}
+
+macro_rules! impl_printf_integer {
+    ($type:ty, $unsigned:ty) => {
+        impl WritePieces for Printf<$type> {
+            #[allow(unused_comparisons)]
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                let unsigned = value as $unsigned as u128;
+                run_integer_algorithm(is_negative, n, unsigned, self.spec(), format, w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Printf<$type>);
+    };
+}
+
+impl_printf_float!(f32);
+impl_printf_float!(f64);
+
+impl_printf_integer!(u8, u8);
+impl_printf_integer!(u16, u16);
+impl_printf_integer!(u32, u32);
+impl_printf_integer!(usize, usize);
+impl_printf_integer!(u64, u64);
+impl_printf_integer!(u128, u128);
+
+impl_printf_integer!(i8, u8);
+impl_printf_integer!(i16, u16);
+impl_printf_integer!(i32, u32);
+impl_printf_integer!(isize, usize);
+impl_printf_integer!(i64, u64);
+impl_printf_integer!(i128, u128);
+
+// helper functions
+
+/// Pushes the integer digits onto `body`, grouping them if asked to.
+fn push_int(digits: &str, group: Option<(&str, usize, usize)>, body: &mut String) {
+    let len = digits.len();
+    for (i, c) in digits.char_indices() {
+        body.push(c);
+        let right = len - 1 - i;
+        if let (Some((separator, last, rest)), true) = (group, right > 0) {
+            if right == last || right > last && matches!((right - last) % rest, 0) {
+                body.push_str(separator);
+            }
+        }
+    }
+}
+
+/// Returns the sign to write in front of a number.
+fn sign(is_negative: bool, spec: &PrintfSpec) -> &'static str {
+    if is_negative {
+        "-"
+    } else if spec.plus_sign() {
+        "+"
+    } else if spec.space_sign() {
+        " "
+    } else {
+        ""
+    }
+}
+
+/// Writes the sign (or `0x` prefix), zeros, and body of a number into `w`, padded out to the
+/// spec's width, where zero padding (unlike the separators of the body) isn't grouped. Like
+/// glibc, the width counts the bytes of integers but the characters of floats.
+fn write_padded(
+    sign: &str,
+    zeros: usize,
+    body: &str,
+    is_float: bool,
+    zero_padded: bool,
+    spec: &PrintfSpec,
+    w: &mut dyn FnMut(&str),
+) {
+    let body_len = if is_float {
+        body.chars().count()
+    } else {
+        body.len()
+    };
+    let len = sign.len() + zeros + body_len;
+    let padding = spec.width().saturating_sub(len);
+    if !spec.left_justified() && !zero_padded {
+        for _ in 0..padding {
+            w(" ");
+        }
+    }
+    w(sign);
+    if !spec.left_justified() && zero_padded {
+        for _ in 0..padding {
+            w("0");
+        }
+    }
+    for _ in 0..zeros {
+        w("0");
+    }
+    w(body);
+    if spec.left_justified() {
+        for _ in 0..padding {
+            w(" ");
+        }
+    }
+}
+
+#[inline(never)]
+fn run_integer_algorithm<F>(
+    is_negative: bool,
+    n: u128,
+    unsigned: u128,
+    spec: &PrintfSpec,
+    format: &F,
+    w: &mut dyn FnMut(&str),
+) where
+    F: WriteFormat,
+{
+    // Integers written with a float conversion are converted into floats, just like in C, and
+    // unsigned conversions have no sign, which leaves room for the `0x` prefix of hexadecimal
+    let (sign, n, hex) = match spec.conversion() {
+        PrintfConversion::Signed => (sign(is_negative, spec), n, None),
+        PrintfConversion::Unsigned => ("", unsigned, None),
+        PrintfConversion::Hex { uppercase } => ("", unsigned, Some(uppercase)),
+        _ => return run_float_algorithm(is_negative, n as f64, spec, format, w),
+    };
+
+    // A precision of zero writes no digits at all for zero
+    let mut digits = ArrayString::<40>::new();
+    match hex {
+        _ if n == 0 && spec.precision() == Some(0) => {}
+        Some(true) => write!(digits, "{:X}", n).unwrap(),
+        Some(false) => write!(digits, "{:x}", n).unwrap(),
+        None => write!(digits, "{}", n).unwrap(),
+    }
+    let sign = match hex {
+        Some(uppercase) if spec.alternate() && n != 0 => {
+            if uppercase {
+                "0X"
+            } else {
+                "0x"
+            }
+        }
+        _ => sign,
+    };
+
+    // Like glibc, hexadecimal digits are grouped too, the precision counts the separators
+    // between groups of digits, and its zeros aren't grouped
+    let mut body = String::new();
+    let group = if spec.grouped() {
+        int_groups(format)
+    } else {
+        None
+    };
+    push_int(&digits, group, &mut body);
+    let zeros = spec.precision().unwrap_or(0).saturating_sub(body.len());
+
+    let zero_padded = spec.zero_padded() && spec.precision().is_none();
+    write_padded(sign, zeros, &body, false, zero_padded, spec, w)
+}
+
+#[inline(never)]
+fn run_float_algorithm<F>(
+    is_negative: bool,
+    abs: f64,
+    spec: &PrintfSpec,
+    format: &F,
+    w: &mut dyn FnMut(&str),
+) where
+    F: WriteFormat,
+{
+    // Floats written with an integer conversion, which C leaves undefined, are written as with
+    // `g`
+    let precision = spec.precision().unwrap_or(6);
+    let alternate = spec.alternate();
+    let (conversion, uppercase) = match spec.conversion() {
+        PrintfConversion::Fixed { uppercase } => (spec.conversion(), uppercase),
+        PrintfConversion::Exponent { uppercase } => (spec.conversion(), uppercase),
+        PrintfConversion::General { uppercase } => (spec.conversion(), uppercase),
+        _ => (PrintfConversion::General { uppercase: false }, false),
+    };
+    let sign = sign(is_negative, spec);
+
+    if !abs.is_finite() {
+        let symbol = match (abs.is_nan(), uppercase) {
+            (true, false) => "nan",
+            (true, true) => "NAN",
+            (false, false) => "inf",
+            (false, true) => "INF",
+        };
+        return write_padded(sign, 0, symbol, true, false, spec, w);
+    }
+
+    let mut text = String::new();
+    let (int_digits, mut frac_digits, exponent) = match conversion {
+        PrintfConversion::Exponent { .. } => {
+            let (int_digits, frac_digits, exponent) = scientific(abs, precision, &mut text);
+            (int_digits, frac_digits, Some(exponent))
+        }
+        PrintfConversion::General { .. } => {
+            let digits = precision.max(1);
+            let (_, _, exponent) = scientific(abs, digits - 1, &mut text);
+            text.clear();
+            if -4 <= exponent && exponent < digits as i32 {
+                let precision = (digits as i32 - 1 - exponent) as usize;
+                let (int_digits, frac_digits) = fixed(abs, precision, &mut text);
+                (int_digits, frac_digits, None)
+            } else {
+                let (int_digits, frac_digits, exponent) = scientific(abs, digits - 1, &mut text);
+                (int_digits, frac_digits, Some(exponent))
+            }
+        }
+        _ => {
+            let (int_digits, frac_digits) = fixed(abs, precision, &mut text);
+            (int_digits, frac_digits, None)
+        }
+    };
+
+    // `g` leaves out trailing zeros, but not in the alternate form
+    if let (PrintfConversion::General { .. }, false) = (conversion, alternate) {
+        frac_digits = frac_digits.trim_end_matches('0');
+    }
+
+    let mut body = String::new();
+    let group = if spec.grouped() {
+        int_groups(format)
+    } else {
+        None
+    };
+    push_int(int_digits, group, &mut body);
+    if !frac_digits.is_empty() || alternate {
+        body.push_str(format.decimal().into_str());
+    }
+    body.push_str(frac_digits);
+    if let Some(exponent) = exponent {
+        let e = if uppercase { 'E' } else { 'e' };
+        let sign = if exponent < 0 { '-' } else { '+' };
+        write!(body, "{}{}{:02}", e, sign, exponent.abs()).unwrap();
+    }
+
+    let zero_padded = spec.zero_padded();
+    write_padded(sign, 0, &body, true, zero_padded, spec, w)
+}
+
+/// Writes `abs` in fixed-point notation with `precision` fraction digits into `text`, returning
+/// its integer and fraction digits.
+pub(crate) fn fixed(abs: f64, precision: usize, text: &mut String) -> (&str, &str) {
+    write!(text, "{:.*}", precision, abs).unwrap();
+    match text.find('.') {
+        Some(index) => (&text[..index], &text[index + 1..]),
+        None => (&text[..], ""),
+    }
+}
+
+/// Writes `abs` in scientific notation with `precision` fraction digits into `text`, returning
+/// its integer and fraction digits and its exponent.
+pub(crate) fn scientific(abs: f64, precision: usize, text: &mut String) -> (&str, &str, i32) {
+    write!(text, "{:.*e}", precision, abs).unwrap();
+    let index = text.find('e').unwrap();
+    let exponent = text[index + 1..].parse().unwrap();
+    let mantissa = &text[..index];
+    match mantissa.find('.') {
+        Some(point) => (&mantissa[..point], &mantissa[point + 1..], exponent),
+        None => (mantissa, "", exponent),
+    }
+}
@@ -0,0 +1,90 @@
+#![allow(trivial_numeric_casts)]
+
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use core::str;
+
+use crate::format::WriteFormat;
+use crate::radix_format::RadixFormat;
+use crate::radixed::Radixed;
+use crate::to_formatted_string::WritePieces;
+
+macro_rules! impl_radixed {
+    ($type:ty, $n:ident => $to_u128:expr) => {
+        impl WritePieces for Radixed<$type> {
+            fn write_pieces<F>(&self, _format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                let $n = *self.value();
+                run_radix_algorithm($to_u128, self.format(), w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(Radixed<$type>);
+    };
+}
+
+impl_radixed!(u8, n => n as u128);
+impl_radixed!(u16, n => n as u128);
+impl_radixed!(u32, n => n as u128);
+impl_radixed!(usize, n => n as u128);
+impl_radixed!(u64, n => n as u128);
+impl_radixed!(u128, n => n);
+
+// write the two's complement bit pattern, as the standard library does
+impl_radixed!(i8, n => n as u8 as u128);
+impl_radixed!(i16, n => n as u16 as u128);
+impl_radixed!(i32, n => n as u32 as u128);
+impl_radixed!(isize, n => n as usize as u128);
+impl_radixed!(i64, n => n as u64 as u128);
+impl_radixed!(i128, n => n as u128);
+
+impl_radixed!(NonZeroU8, n => n.get() as u128);
+impl_radixed!(NonZeroU16, n => n.get() as u128);
+impl_radixed!(NonZeroU32, n => n.get() as u128);
+impl_radixed!(NonZeroUsize, n => n.get() as u128);
+impl_radixed!(NonZeroU64, n => n.get() as u128);
+impl_radixed!(NonZeroU128, n => n.get());
+
+// helper functions
+
+#[inline(never)]
+fn run_radix_algorithm(mut n: u128, format: &RadixFormat, w: &mut dyn FnMut(&str)) {
+    const LOWER: &[u8; 16] = b"0123456789abcdef";
+    const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+    let table = if format.uppercase() { UPPER } else { LOWER };
+    let bits = format.radix().bits();
+    let mask = (1u128 << bits) - 1;
+
+    // Write digits from least to most significant into the end of `digits`
+    let mut digits = [0u8; 128];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = table[(n & mask) as usize];
+        n >>= bits;
+        if n == 0 {
+            break;
+        }
+    }
+    let digits = unsafe { str::from_utf8_unchecked(&digits[start..]) };
+
+    // Then pass them on from most to least significant, separating every `group_size` digits
+    w(format.prefix());
+    let group_size = match format.group_size() as usize {
+        0 => digits.len(),
+        group_size => group_size,
+    };
+    let mut end = match digits.len() % group_size {
+        0 => group_size,
+        first => first,
+    };
+    w(&digits[..end]);
+    while end < digits.len() {
+        w(format.separator());
+        w(&digits[end..end + group_size]);
+        end += group_size;
+    }
+}
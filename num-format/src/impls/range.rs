@@ -0,0 +1,379 @@
+#![allow(trivial_numeric_casts)]
+
+use crate::buffer::Buffer;
+use crate::constants::MAX_BUF_LEN;
+use crate::format::WriteFormat;
+use crate::locale::Locale;
+use crate::measure::{Measure, MeasureUnit, UnitWidth};
+use crate::plural::{PluralCategory, PluralOperands};
+use crate::range::{Approximately, Range};
+use crate::ryu::{decimal32, decimal64, format_decimal, Float};
+use crate::to_formatted_string::WritePieces;
+
+use super::measure::category_unit_pattern;
+use super::scaled::write_scaled;
+
+/// A number, or a measure of one, that can be the start or end of a [`Range`] or the value of
+/// an [`Approximately`].
+///
+/// [`Approximately`]: struct.Approximately.html
+/// [`Range`]: struct.Range.html
+pub(crate) trait RangeValue {
+    /// Returns the unit, width, and locale of a measure, or `None` for a number.
+    fn unit(&self) -> Option<(MeasureUnit, UnitWidth, Locale)>;
+
+    /// Returns `true` if the number is written with a minus sign.
+    fn is_negative(&self) -> bool;
+
+    /// Returns the plural operands of the number as written.
+    fn operands(&self) -> PluralOperands;
+
+    /// Writes the number without its minus sign into the buffer.
+    fn write_magnitude<F>(&self, buf: &mut Buffer, format: &F)
+    where
+        F: WriteFormat;
+}
+
+impl<N> RangeValue for Measure<N>
+where
+    N: RangeValue,
+{
+    fn unit(&self) -> Option<(MeasureUnit, UnitWidth, Locale)> {
+        Some((self.unit(), self.width(), self.locale()))
+    }
+
+    fn is_negative(&self) -> bool {
+        self.value().is_negative()
+    }
+
+    fn operands(&self) -> PluralOperands {
+        self.value().operands()
+    }
+
+    fn write_magnitude<F>(&self, buf: &mut Buffer, format: &F)
+    where
+        F: WriteFormat,
+    {
+        self.value().write_magnitude(buf, format)
+    }
+}
+
+macro_rules! impl_range_value_float {
+    ($type:ty, $decimal:ident) => {
+        impl RangeValue for $type {
+            fn unit(&self) -> Option<(MeasureUnit, UnitWidth, Locale)> {
+                None
+            }
+
+            fn is_negative(&self) -> bool {
+                !self.is_nan() && self.is_sign_negative()
+            }
+
+            fn operands(&self) -> PluralOperands {
+                if self.is_nonfinite() {
+                    return PluralOperands::nonfinite();
+                }
+                let (mantissa, exponent) = $decimal(&self.parse_ieee_data());
+                PluralOperands::from_float(mantissa, exponent)
+            }
+
+            fn write_magnitude<F>(&self, buf: &mut Buffer, format: &F)
+            where
+                F: WriteFormat,
+            {
+                if self.is_nonfinite() {
+                    let s = self.format_nonfinite().trim_start_matches('-');
+                    buf.pos = MAX_BUF_LEN - s.len();
+                    buf.inner[buf.pos..].copy_from_slice(s.as_bytes());
+                    return;
+                }
+                let (mantissa, exponent) = $decimal(&self.parse_ieee_data());
+                let ptr = buf.as_mut_ptr();
+                buf.pos = format_decimal(ptr, MAX_BUF_LEN, false, mantissa, exponent, format);
+            }
+        }
+    };
+}
+
+macro_rules! impl_range_value_integer {
+    ($type:ty) => {
+        impl RangeValue for $type {
+            fn unit(&self) -> Option<(MeasureUnit, UnitWidth, Locale)> {
+                None
+            }
+
+            #[allow(unused_comparisons)]
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+
+            fn operands(&self) -> PluralOperands {
+                PluralOperands::from(magnitude(*self as i128, self.is_negative()))
+            }
+
+            fn write_magnitude<F>(&self, buf: &mut Buffer, format: &F)
+            where
+                F: WriteFormat,
+            {
+                let n = magnitude(*self as i128, self.is_negative());
+                buf.pos = MAX_BUF_LEN - write_scaled(false, n, 0, buf, MAX_BUF_LEN, format);
+            }
+        }
+    };
+}
+
+impl_range_value_float!(f32, decimal32);
+impl_range_value_float!(f64, decimal64);
+
+impl_range_value_integer!(u8);
+impl_range_value_integer!(u16);
+impl_range_value_integer!(u32);
+impl_range_value_integer!(usize);
+impl_range_value_integer!(u64);
+impl_range_value_integer!(i8);
+impl_range_value_integer!(i16);
+impl_range_value_integer!(i32);
+impl_range_value_integer!(isize);
+impl_range_value_integer!(i64);
+impl_range_value_integer!(i128);
+
+// u128 doesn't fit in an i128, but is never negative
+impl RangeValue for u128 {
+    fn unit(&self) -> Option<(MeasureUnit, UnitWidth, Locale)> {
+        None
+    }
+
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    fn operands(&self) -> PluralOperands {
+        PluralOperands::from(*self)
+    }
+
+    fn write_magnitude<F>(&self, buf: &mut Buffer, format: &F)
+    where
+        F: WriteFormat,
+    {
+        buf.pos = MAX_BUF_LEN - write_scaled(false, *self, 0, buf, MAX_BUF_LEN, format);
+    }
+}
+
+macro_rules! impl_range {
+    ($type:ty) => {
+        impl WritePieces for Range<$type> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                write_range(self.start(), self.end(), self.locale(), format, w)
+            }
+        }
+
+        impl WritePieces for Range<Measure<$type>> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                write_range(self.start(), self.end(), self.locale(), format, w)
+            }
+        }
+
+        impl WritePieces for Approximately<$type> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                write_approximately(self.value(), self.locale(), format, w)
+            }
+        }
+
+        impl WritePieces for Approximately<Measure<$type>> {
+            fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+            where
+                F: WriteFormat,
+            {
+                write_approximately(self.value(), self.locale(), format, w)
+            }
+        }
+
+        impl_to_formatted_string_by_pieces!(
+            Range<$type>,
+            Range<Measure<$type>>,
+            Approximately<$type>,
+            Approximately<Measure<$type>>
+        );
+    };
+}
+
+impl_range!(f32);
+impl_range!(f64);
+
+impl_range!(u8);
+impl_range!(u16);
+impl_range!(u32);
+impl_range!(usize);
+impl_range!(u64);
+impl_range!(u128);
+
+impl_range!(i8);
+impl_range!(i16);
+impl_range!(i32);
+impl_range!(isize);
+impl_range!(i64);
+impl_range!(i128);
+
+// helper functions
+
+/// Returns the magnitude of a signed integer.
+fn magnitude(value: i128, is_negative: bool) -> u128 {
+    if is_negative {
+        (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+    } else {
+        value as u128
+    }
+}
+
+/// Passes the range from `start` to `end` in the range pattern of the locale to `w`, collapsing
+/// the unit and minus sign they share the way CLDR's "auto" range collapse does.
+fn write_range<V, F>(start: &V, end: &V, locale: Locale, format: &F, w: &mut dyn FnMut(&str))
+where
+    V: RangeValue,
+    F: WriteFormat,
+{
+    // A range whose start and end are written the same is written as approximately the start
+    let mut starts = Buffer::new();
+    start.write_magnitude(&mut starts, format);
+    let mut ends = Buffer::new();
+    end.write_magnitude(&mut ends, format);
+    if starts.as_str() == ends.as_str()
+        && start.is_negative() == end.is_negative()
+        && start.unit() == end.unit()
+    {
+        return write_approximately(start, locale, format, w);
+    }
+
+    // A shared unit is written once, in the plural category of the range, and a shared minus
+    // sign is only written once if it is longer than one character
+    let minus_sign = format.minus_sign().into_str();
+    let collapse_unit = start.unit() == end.unit();
+    let collapse_sign =
+        collapse_unit && start.is_negative() && end.is_negative() && minus_sign.chars().count() > 1;
+    let unit_pattern = match (collapse_unit, start.unit()) {
+        (true, Some((unit, width, unit_locale))) => {
+            let category = unit_locale.range_category(
+                unit_locale.plural_category(start.operands()),
+                unit_locale.plural_category(end.operands()),
+            );
+            match category_unit_pattern(unit_locale, unit, width, category) {
+                pattern if pattern.contains("{0}") => pattern,
+                _ => category_unit_pattern(unit_locale, unit, width, PluralCategory::Other),
+            }
+        }
+        _ => "{0}",
+    };
+    let (unit_prefix, unit_suffix) = split_pattern(unit_pattern, "{0}");
+
+    // Put spaces around the separator if the start has a minus sign or unit of its own
+    let start_pattern = value_pattern(start, !collapse_unit);
+    let spaced =
+        (start.is_negative() && !collapse_sign && !minus_sign.is_empty()) || start_pattern != "{0}";
+
+    let range_pattern = locale.range_pattern();
+    let (range_prefix, rest) = split_pattern(range_pattern, "{0}");
+    let (separator, range_suffix) = split_pattern(rest, "{1}");
+
+    w(unit_prefix);
+    if collapse_sign {
+        w(minus_sign);
+    }
+    w(range_prefix);
+    write_value(start, &starts, start_pattern, !collapse_sign, "", format, w);
+    if spaced && !separator.starts_with(is_pattern_white_space) {
+        w(" ");
+    }
+    w(separator);
+    if spaced && !separator.ends_with(is_pattern_white_space) {
+        w(" ");
+    }
+    let end_pattern = value_pattern(end, !collapse_unit);
+    write_value(end, &ends, end_pattern, !collapse_sign, "", format, w);
+    w(range_suffix);
+    w(unit_suffix);
+}
+
+/// Passes the value with the approximately sign of the locale to `w`.
+fn write_approximately<V, F>(value: &V, locale: Locale, format: &F, w: &mut dyn FnMut(&str))
+where
+    V: RangeValue,
+    F: WriteFormat,
+{
+    let mut buf = Buffer::new();
+    value.write_magnitude(&mut buf, format);
+    let pattern = value_pattern(value, true);
+    let approximately_sign = locale.approximately_sign();
+    write_value(value, &buf, pattern, true, approximately_sign, format, w);
+}
+
+/// Returns the unit pattern of a measure in its locale and the plural category of its value, or
+/// `"{0}"` for a number or if the unit isn't written with the value.
+fn value_pattern<V>(value: &V, with_unit: bool) -> &'static str
+where
+    V: RangeValue,
+{
+    match value.unit() {
+        Some((unit, width, locale)) if with_unit => {
+            let category = locale.plural_category(value.operands());
+            category_unit_pattern(locale, unit, width, category)
+        }
+        _ => "{0}",
+    }
+}
+
+/// Passes the value (whose magnitude is in `number`) in the unit pattern to `w`, with
+/// `prefix` and then its minus sign (if `with_sign`) before the number. Patterns without a
+/// placeholder are written without the number, as for a [`Measure`].
+///
+/// [`Measure`]: struct.Measure.html
+fn write_value<V, F>(
+    value: &V,
+    number: &Buffer,
+    pattern: &str,
+    with_sign: bool,
+    prefix: &str,
+    format: &F,
+    w: &mut dyn FnMut(&str),
+) where
+    V: RangeValue,
+    F: WriteFormat,
+{
+    let i = match pattern.find("{0}") {
+        Some(i) => i,
+        None => return w(pattern),
+    };
+    w(&pattern[..i]);
+    w(prefix);
+    if with_sign && value.is_negative() {
+        w(format.minus_sign().into_str());
+    }
+    w(number.as_str());
+    w(&pattern[i + 3..]);
+}
+
+/// Splits a pattern around a placeholder (e.g. `"{0}"`), which all of the generated range
+/// patterns contain.
+fn split_pattern<'a>(pattern: &'a str, placeholder: &str) -> (&'a str, &'a str) {
+    match pattern.find(placeholder) {
+        Some(i) => (&pattern[..i], &pattern[i + placeholder.len()..]),
+        None => (pattern, ""),
+    }
+}
+
+/// Returns `true` for the characters ICU considers to be white space in patterns, around which
+/// no spaces are added.
+fn is_pattern_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\t'..='\r' | ' ' | '\u{85}' | '\u{200e}' | '\u{200f}' | '\u{2028}' | '\u{2029}'
+    )
+}
@@ -0,0 +1,67 @@
+#![allow(trivial_numeric_casts)]
+
+use crate::buffer::Buffer;
+use crate::constants::MAX_BUF_LEN;
+use crate::format::WriteFormat;
+use crate::roman::{write_roman, Roman, MAX_ROMAN, MAX_ROMAN_VINCULUM};
+use crate::to_formatted_str::ToFormattedStr;
+
+macro_rules! impl_roman {
+    ($type:ty) => {
+        impl ToFormattedStr for Roman<$type> {
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let max = if self.is_vinculum() {
+                    MAX_ROMAN_VINCULUM
+                } else {
+                    MAX_ROMAN
+                };
+                if value < 1 || value as u128 > max as u128 {
+                    return value.read_to_buffer(buf, format);
+                }
+                write_roman_to_buffer(value as u32, self.is_lowercase(), self.is_vinculum(), buf)
+            }
+        }
+
+        impl crate::private::Sealed for Roman<$type> {}
+    };
+}
+
+impl_roman!(u8);
+impl_roman!(u16);
+impl_roman!(u32);
+impl_roman!(usize);
+impl_roman!(u64);
+impl_roman!(u128);
+
+impl_roman!(i8);
+impl_roman!(i16);
+impl_roman!(i32);
+impl_roman!(isize);
+impl_roman!(i64);
+impl_roman!(i128);
+
+// helper functions
+
+/// Writes the Roman numeral at the end of the buffer, returning the number of bytes written.
+fn write_roman_to_buffer(n: u32, lowercase: bool, vinculum: bool, buf: &mut Buffer) -> usize {
+    // Measure, then write so that the output ends at the end of the buffer
+    let mut len = 0;
+    write_roman(n, lowercase, vinculum, |s| len += s.len());
+    let start = MAX_BUF_LEN - len;
+    let mut index = start;
+    write_roman(n, lowercase, vinculum, |s| {
+        buf.inner[index..index + s.len()].copy_from_slice(s.as_bytes());
+        index += s.len();
+    });
+
+    buf.pos = start;
+    buf.end = MAX_BUF_LEN;
+    len
+}
@@ -0,0 +1,105 @@
+#![allow(trivial_numeric_casts)]
+
+use core::str;
+
+use crate::buffer::Buffer;
+use crate::constants::{MAX_BUF_LEN, U128_MAX_LEN};
+use crate::format::{write_decimal, WriteFormat};
+use crate::scaled::Scaled;
+use crate::to_formatted_str::ToFormattedStr;
+
+macro_rules! impl_scaled {
+    ($type:ty) => {
+        impl ToFormattedStr for Scaled<$type> {
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                let c = write_scaled(is_negative, n, self.scale(), buf, MAX_BUF_LEN, format);
+                buf.pos = MAX_BUF_LEN - c;
+                buf.end = MAX_BUF_LEN;
+                c
+            }
+        }
+
+        impl crate::private::Sealed for Scaled<$type> {}
+    };
+}
+
+impl_scaled!(u8);
+impl_scaled!(u16);
+impl_scaled!(u32);
+impl_scaled!(usize);
+impl_scaled!(u64);
+impl_scaled!(u128);
+
+impl_scaled!(i8);
+impl_scaled!(i16);
+impl_scaled!(i32);
+impl_scaled!(isize);
+impl_scaled!(i64);
+impl_scaled!(i128);
+
+// helper functions
+
+/// Writes `n / 10^scale` into `buf` so that it ends at `end`, returning the number of bytes
+/// written. Does not update the buffer's `pos` or `end`.
+#[inline(never)]
+pub(crate) fn write_scaled<F>(
+    is_negative: bool,
+    mut n: u128,
+    scale: u8,
+    buf: &mut Buffer,
+    end: usize,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    // Collect the digits, left-padded with enough zeros to fill the scale
+    let mut digits = [b'0'; u8::MAX as usize + U128_MAX_LEN];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    let scale = scale as usize;
+    let len = digits.len() - start;
+    let (int_digits, frac_digits): (&[u8], &[u8]) = if scale == 0 {
+        (&digits[start..], &[])
+    } else if len > scale {
+        let (int_digits, frac_digits) = digits.split_at(digits.len() - scale);
+        (&int_digits[start..], frac_digits)
+    } else {
+        (b"0", &digits[digits.len() - scale..])
+    };
+    let int_digits = unsafe { str::from_utf8_unchecked(int_digits) };
+    let frac_digits = unsafe { str::from_utf8_unchecked(frac_digits) };
+
+    // Measure, then write so that the output ends at `end`
+    let mut len = 0;
+    write_decimal(is_negative, int_digits, frac_digits, format, |s| {
+        len += s.len()
+    });
+    let mut index = end - len;
+    write_decimal(is_negative, int_digits, frac_digits, format, |s| {
+        buf.inner[index..index + s.len()].copy_from_slice(s.as_bytes());
+        index += s.len();
+    });
+
+    len
+}
@@ -0,0 +1,51 @@
+#![allow(trivial_numeric_casts)]
+
+use crate::buffer::Buffer;
+use crate::format::WriteFormat;
+use crate::rbnf::write_to_buffer;
+use crate::spell_out::SpellOut;
+use crate::to_formatted_str::ToFormattedStr;
+
+macro_rules! impl_spell_out {
+    ($type:ty) => {
+        impl ToFormattedStr for SpellOut<$type> {
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            #[inline(never)]
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
+            where
+                F: WriteFormat,
+            {
+                let value = *self.value();
+                let locale = self.locale();
+                let rules = match locale.spellout_rules() {
+                    Some(rules) => rules,
+                    None => return value.read_to_buffer(buf, format),
+                };
+                let is_negative = value < 0;
+                let n = if is_negative {
+                    (!(value as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    value as u128
+                };
+                write_to_buffer(rules, locale, is_negative, n, buf, format)
+            }
+        }
+
+        impl crate::private::Sealed for SpellOut<$type> {}
+    };
+}
+
+impl_spell_out!(u8);
+impl_spell_out!(u16);
+impl_spell_out!(u32);
+impl_spell_out!(usize);
+impl_spell_out!(u64);
+impl_spell_out!(u128);
+
+impl_spell_out!(i8);
+impl_spell_out!(i16);
+impl_spell_out!(i32);
+impl_spell_out!(isize);
+impl_spell_out!(i64);
+impl_spell_out!(i128);
@@ -8,7 +8,7 @@ use no_panic::no_panic;
 // Adaptation of the original implementation at
 // https://github.com/rust-lang/rust/blob/b8214dc6c6fc20d0a660fb5700dca9ebf51ebe89/src/libcore/fmt/num.rs#L188-L266
 macro_rules! impl_Integer {
-    ($($max_len:expr => $t:ident),* as $conv_fn:ident) => {$(
+    ($($t:ident),* as $conv_fn:ident) => {$(
         impl super::Integer for $t {
             type Buffer = (*mut u8, usize); // ptr, pos
 
@@ -80,20 +80,15 @@ macro_rules! impl_Integer {
     )*};
 }
 
-impl_Integer!(
-    I8_MAX_LEN => i8,
-    U8_MAX_LEN => u8,
-    I16_MAX_LEN => i16,
-    U16_MAX_LEN => u16
-    as u32);
+impl_Integer!(i8, u8, i16, u16 as u32);
 
-//impl_Integer!(I64_MAX_LEN => i64, U64_MAX_LEN => u64 as u64);
+//impl_Integer!(i64, u64 as u64);
 
 #[cfg(target_pointer_width = "16")]
-impl_Integer!(I16_MAX_LEN => isize, U16_MAX_LEN => usize as u16);
+impl_Integer!(isize, usize as u16);
 
 #[cfg(target_pointer_width = "32")]
-impl_Integer!(I32_MAX_LEN => isize, U32_MAX_LEN => usize as u32);
+impl_Integer!(isize, usize as u32);
 
 #[cfg(target_pointer_width = "64")]
-impl_Integer!(I64_MAX_LEN => isize, U64_MAX_LEN => usize as u64);
+impl_Integer!(isize, usize as u64);
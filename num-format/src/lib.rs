@@ -188,6 +188,7 @@ fn main() -> Result<(), Error> {
 | Available features   | What to put in your `Cargo.toml`                                      |
 | :------------------- | :-------------------------------------------------------------------- |
 | `no_std`             | `num-format = { version = "0.4.3", default-features = false }`          |
+| `with-encoding`      | `num-format = { version = "0.4.3", features = ["with-encoding"] }`      |
 | `with-num-bigint`    | `num-format = { version = "0.4.3", features = ["with-num-bigint"] }`    |
 | `with-serde`         | `num-format = { version = "0.4.3", features = ["with-serde"] }`         |
 | `with-system-locale` | `num-format = { version = "0.4.3", features = ["with-system-locale"] }` |
@@ -261,21 +262,51 @@ pub mod itoa;
 pub mod ryu;
 
 mod buffer;
+mod byte_size;
 mod constants;
 mod custom_format;
 mod custom_format_builder;
+mod decimal;
+mod elapsed;
+mod encoding;
 mod error;
 mod error_kind;
 mod format;
+mod format_code;
+mod format_spec;
+mod formatted;
+mod fraction_grouped;
 mod grouping;
 mod impls;
 mod locale;
+mod measure;
+mod metric;
+mod number_pattern;
+mod ordinal;
+mod patterned;
 pub mod parsing;
+mod plural;
+mod plural_rules;
+mod printf;
+mod radix;
+mod radix_format;
+mod radix_format_builder;
+mod radixed;
+mod range;
+mod rbnf;
+mod rbnf_rules;
+mod roman;
+mod scaled;
+#[cfg(all(feature = "with-serde", feature = "std"))]
+pub mod serde_formatted;
+mod spell_out;
 mod strings;
 
 #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
 mod system_locale;
 mod to_formatted_str;
+#[cfg(feature = "std")]
+mod unit_patterns;
 
 #[cfg(feature = "std")]
 mod to_formatted_string;
@@ -284,14 +315,39 @@ mod to_formatted_string;
 mod write_formatted;
 
 pub use self::buffer::Buffer;
+pub use self::byte_size::{ByteSize, ByteUnits};
 pub use self::custom_format::CustomFormat;
 pub use self::custom_format_builder::CustomFormatBuilder;
+#[cfg(feature = "std")]
+pub use self::decimal::Decimal;
 pub use self::digit_table::DIGIT_TABLE;
+pub use self::elapsed::{DurationUnit, Elapsed};
+#[cfg(feature = "with-encoding")]
+pub use self::encoding::Encoding;
 pub use self::error::Error;
 pub use self::error_kind::ErrorKind;
 pub use self::format::Format;
+pub use self::format_code::{Coded, FormatCode};
+pub use self::format_spec::{FormatSpec, SpecAlign, SpecSign, SpecType, Specified};
+pub use self::formatted::{Formatted, StaticFormat};
+pub use self::fraction_grouped::FractionGrouped;
 pub use self::grouping::Grouping;
 pub use self::locale::Locale;
+pub use self::measure::{Measure, MeasureUnit, UnitWidth};
+pub use self::metric::Metric;
+pub use self::number_pattern::NumberPattern;
+pub use self::ordinal::Ordinal;
+pub use self::patterned::Patterned;
+pub use self::plural::{PluralCategory, PluralOperands};
+pub use self::printf::{Printf, PrintfConversion, PrintfSpec};
+pub use self::radix::Radix;
+pub use self::radix_format::RadixFormat;
+pub use self::radix_format_builder::RadixFormatBuilder;
+pub use self::radixed::Radixed;
+pub use self::range::{Approximately, Range};
+pub use self::roman::Roman;
+pub use self::scaled::Scaled;
+pub use self::spell_out::SpellOut;
 
 #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
 pub use self::system_locale::SystemLocale;
@@ -2,6 +2,7 @@
 use crate::error::Error;
 use crate::format::Format;
 use crate::grouping::Grouping;
+use crate::parsing::ParseFormat;
 use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
 use core::str::FromStr;
 const AVAILABLE_NAMES: [&str; 542usize] = [
@@ -1139,6 +1140,5133 @@ impl Locale {
     pub fn available_names() -> &'static [&'static str] {
         &AVAILABLE_NAMES
     }
+    #[doc = r" Returns the locale's approximately sign (e.g. `~` for English or `≈` for German)."]
+    pub fn approximately_sign(&self) -> &'static str {
+        use self::Locale::*;
+        match self {
+            af => "~",
+            af_NA => "~",
+            agq => "~",
+            ak => "~",
+            am => "~",
+            ar => "~",
+            ar_AE => "~",
+            ar_BH => "~",
+            ar_DJ => "~",
+            ar_DZ => "~",
+            ar_EG => "~",
+            ar_EH => "~",
+            ar_ER => "~",
+            ar_IL => "~",
+            ar_IQ => "~",
+            ar_JO => "~",
+            ar_KM => "~",
+            ar_KW => "~",
+            ar_LB => "~",
+            ar_LY => "~",
+            ar_MA => "~",
+            ar_MR => "~",
+            ar_OM => "~",
+            ar_PS => "~",
+            ar_QA => "~",
+            ar_SA => "~",
+            ar_SD => "~",
+            ar_SO => "~",
+            ar_SS => "~",
+            ar_SY => "~",
+            ar_TD => "~",
+            ar_TN => "~",
+            ar_YE => "~",
+            as_ => "~",
+            asa => "~",
+            ast => "~",
+            az => "~",
+            az_Cyrl => "~",
+            az_Latn => "~",
+            bas => "~",
+            be => "≈",
+            bem => "~",
+            bez => "~",
+            bg => "~",
+            bm => "~",
+            bn => "~",
+            bn_IN => "~",
+            bo => "~",
+            bo_IN => "~",
+            br => "~",
+            brx => "~",
+            bs => "≈",
+            bs_Cyrl => "~",
+            bs_Latn => "≈",
+            ca => "~",
+            ca_AD => "~",
+            ca_ES_VALENCIA => "~",
+            ca_FR => "~",
+            ca_IT => "~",
+            ccp => "~",
+            ccp_IN => "~",
+            ce => "~",
+            ceb => "~",
+            cgg => "~",
+            chr => "~",
+            ckb => "~",
+            ckb_IR => "~",
+            cs => "~",
+            cu => "~",
+            cy => "~",
+            da => "~",
+            da_GL => "~",
+            dav => "~",
+            de => "≈",
+            de_AT => "≈",
+            de_BE => "≈",
+            de_CH => "≈",
+            de_IT => "≈",
+            de_LI => "≈",
+            de_LU => "≈",
+            dje => "~",
+            dsb => "≈",
+            dua => "~",
+            dyo => "~",
+            dz => "~",
+            ebu => "~",
+            ee => "~",
+            ee_TG => "~",
+            el => "~",
+            el_CY => "~",
+            en => "~",
+            en_001 => "~",
+            en_150 => "~",
+            en_AE => "~",
+            en_AG => "~",
+            en_AI => "~",
+            en_AS => "~",
+            en_AT => "~",
+            en_AU => "~",
+            en_BB => "~",
+            en_BE => "~",
+            en_BI => "~",
+            en_BM => "~",
+            en_BS => "~",
+            en_BW => "~",
+            en_BZ => "~",
+            en_CA => "~",
+            en_CC => "~",
+            en_CH => "~",
+            en_CK => "~",
+            en_CM => "~",
+            en_CX => "~",
+            en_CY => "~",
+            en_DE => "~",
+            en_DG => "~",
+            en_DK => "~",
+            en_DM => "~",
+            en_ER => "~",
+            en_FI => "~",
+            en_FJ => "~",
+            en_FK => "~",
+            en_FM => "~",
+            en_GB => "~",
+            en_GD => "~",
+            en_GG => "~",
+            en_GH => "~",
+            en_GI => "~",
+            en_GM => "~",
+            en_GU => "~",
+            en_GY => "~",
+            en_HK => "~",
+            en_IE => "~",
+            en_IL => "~",
+            en_IM => "~",
+            en_IN => "~",
+            en_IO => "~",
+            en_JE => "~",
+            en_JM => "~",
+            en_KE => "~",
+            en_KI => "~",
+            en_KN => "~",
+            en_KY => "~",
+            en_LC => "~",
+            en_LR => "~",
+            en_LS => "~",
+            en_MG => "~",
+            en_MH => "~",
+            en_MO => "~",
+            en_MP => "~",
+            en_MS => "~",
+            en_MT => "~",
+            en_MU => "~",
+            en_MW => "~",
+            en_MY => "~",
+            en_NA => "~",
+            en_NF => "~",
+            en_NG => "~",
+            en_NL => "~",
+            en_NR => "~",
+            en_NU => "~",
+            en_NZ => "~",
+            en_PG => "~",
+            en_PH => "~",
+            en_PK => "~",
+            en_PN => "~",
+            en_PR => "~",
+            en_PW => "~",
+            en_RW => "~",
+            en_SB => "~",
+            en_SC => "~",
+            en_SD => "~",
+            en_SE => "~",
+            en_SG => "~",
+            en_SH => "~",
+            en_SI => "~",
+            en_SL => "~",
+            en_SS => "~",
+            en_SX => "~",
+            en_SZ => "~",
+            en_TC => "~",
+            en_TK => "~",
+            en_TO => "~",
+            en_TT => "~",
+            en_TV => "~",
+            en_TZ => "~",
+            en_UG => "~",
+            en_UM => "~",
+            en_US_POSIX => "~",
+            en_VC => "~",
+            en_VG => "~",
+            en_VI => "~",
+            en_VU => "~",
+            en_WS => "~",
+            en_ZA => "~",
+            en_ZM => "~",
+            en_ZW => "~",
+            eo => "~",
+            es => "~",
+            es_419 => "~",
+            es_AR => "~",
+            es_BO => "~",
+            es_BR => "~",
+            es_BZ => "~",
+            es_CL => "~",
+            es_CO => "~",
+            es_CR => "~",
+            es_CU => "~",
+            es_DO => "~",
+            es_EA => "~",
+            es_EC => "~",
+            es_GQ => "~",
+            es_GT => "~",
+            es_HN => "~",
+            es_IC => "~",
+            es_MX => "~",
+            es_NI => "~",
+            es_PA => "~",
+            es_PE => "~",
+            es_PH => "~",
+            es_PR => "~",
+            es_PY => "~",
+            es_SV => "~",
+            es_US => "~",
+            es_UY => "~",
+            es_VE => "~",
+            et => "≈",
+            eu => "~",
+            ewo => "~",
+            fa => "~",
+            fa_AF => "~",
+            ff => "~",
+            ff_Latn => "~",
+            ff_Latn_BF => "~",
+            ff_Latn_CM => "~",
+            ff_Latn_GH => "~",
+            ff_Latn_GM => "~",
+            ff_Latn_GN => "~",
+            ff_Latn_GW => "~",
+            ff_Latn_LR => "~",
+            ff_Latn_MR => "~",
+            ff_Latn_NE => "~",
+            ff_Latn_NG => "~",
+            ff_Latn_SL => "~",
+            fi => "~",
+            fil => "~",
+            fo => "~",
+            fo_DK => "~",
+            fr => "≃",
+            fr_BE => "≃",
+            fr_BF => "≃",
+            fr_BI => "≃",
+            fr_BJ => "≃",
+            fr_BL => "≃",
+            fr_CA => "≈",
+            fr_CD => "≃",
+            fr_CF => "≃",
+            fr_CG => "≃",
+            fr_CH => "≃",
+            fr_CI => "≃",
+            fr_CM => "≃",
+            fr_DJ => "≃",
+            fr_DZ => "≃",
+            fr_GA => "≃",
+            fr_GF => "≃",
+            fr_GN => "≃",
+            fr_GP => "≃",
+            fr_GQ => "≃",
+            fr_HT => "≃",
+            fr_KM => "≃",
+            fr_LU => "≃",
+            fr_MA => "≃",
+            fr_MC => "≃",
+            fr_MF => "≃",
+            fr_MG => "≃",
+            fr_ML => "≃",
+            fr_MQ => "≃",
+            fr_MR => "≃",
+            fr_MU => "≃",
+            fr_NC => "≃",
+            fr_NE => "≃",
+            fr_PF => "≃",
+            fr_PM => "≃",
+            fr_RE => "≃",
+            fr_RW => "≃",
+            fr_SC => "≃",
+            fr_SN => "≃",
+            fr_SY => "≃",
+            fr_TD => "≃",
+            fr_TG => "≃",
+            fr_TN => "≃",
+            fr_VU => "≃",
+            fr_WF => "≃",
+            fr_YT => "≃",
+            fur => "~",
+            fy => "~",
+            ga => "~",
+            ga_GB => "~",
+            gd => "~",
+            gl => "~",
+            gsw => "~",
+            gsw_FR => "~",
+            gsw_LI => "~",
+            gu => "~",
+            guz => "~",
+            gv => "~",
+            ha => "~",
+            ha_GH => "~",
+            ha_NE => "~",
+            haw => "~",
+            he => "~",
+            hi => "~",
+            hr => "~",
+            hr_BA => "~",
+            hsb => "≈",
+            hu => "~",
+            hy => "~",
+            ia => "~",
+            id => "~",
+            ig => "~",
+            ii => "~",
+            is => "~",
+            it => "~",
+            it_CH => "~",
+            it_SM => "~",
+            it_VA => "~",
+            ja => "約",
+            jgo => "~",
+            jmc => "~",
+            jv => "~",
+            ka => "≈",
+            kab => "~",
+            kam => "~",
+            kde => "~",
+            kea => "~",
+            khq => "~",
+            ki => "~",
+            kk => "~",
+            kkj => "~",
+            kl => "~",
+            kln => "~",
+            km => "~",
+            kn => "~",
+            ko => "~",
+            ko_KP => "~",
+            kok => "~",
+            ks => "~",
+            ksb => "~",
+            ksf => "~",
+            ksh => "~",
+            ku => "~",
+            kw => "~",
+            ky => "~",
+            lag => "~",
+            lb => "~",
+            lg => "~",
+            lkt => "~",
+            ln => "~",
+            ln_AO => "~",
+            ln_CF => "~",
+            ln_CG => "~",
+            lo => "~",
+            lrc => "~",
+            lrc_IQ => "~",
+            lt => "∼",
+            lu => "~",
+            luo => "~",
+            luy => "~",
+            lv => "~",
+            mas => "~",
+            mas_TZ => "~",
+            mer => "~",
+            mfe => "~",
+            mg => "~",
+            mgh => "~",
+            mgo => "~",
+            mi => "~",
+            mk => "≈",
+            ml => "~",
+            mn => "~",
+            mr => "~",
+            ms => "~",
+            ms_BN => "~",
+            ms_SG => "~",
+            mt => "~",
+            mua => "~",
+            my => "~",
+            mzn => "~",
+            naq => "~",
+            nb => "ca.",
+            nb_SJ => "ca.",
+            nd => "~",
+            nds => "~",
+            nds_NL => "~",
+            ne => "~",
+            ne_IN => "~",
+            nl => "~",
+            nl_AW => "~",
+            nl_BE => "~",
+            nl_BQ => "~",
+            nl_CW => "~",
+            nl_SR => "~",
+            nl_SX => "~",
+            nmg => "~",
+            nn => "≈",
+            nnh => "~",
+            nus => "~",
+            nyn => "~",
+            om => "~",
+            om_KE => "~",
+            or => "~",
+            os => "~",
+            os_RU => "~",
+            pa => "~",
+            pa_Arab => "~",
+            pa_Guru => "~",
+            pl => "~",
+            prg => "~",
+            ps => "~",
+            ps_PK => "~",
+            pt => "~",
+            pt_AO => "~",
+            pt_CH => "~",
+            pt_CV => "~",
+            pt_GQ => "~",
+            pt_GW => "~",
+            pt_LU => "~",
+            pt_MO => "~",
+            pt_MZ => "~",
+            pt_PT => "~",
+            pt_ST => "~",
+            pt_TL => "~",
+            qu => "~",
+            qu_BO => "~",
+            qu_EC => "~",
+            rm => "≈",
+            rn => "~",
+            ro => "~",
+            ro_MD => "~",
+            rof => "~",
+            root => "~",
+            ru => "≈",
+            ru_BY => "≈",
+            ru_KG => "≈",
+            ru_KZ => "≈",
+            ru_MD => "≈",
+            ru_UA => "≈",
+            rw => "~",
+            rwk => "~",
+            sah => "~",
+            saq => "~",
+            sbp => "~",
+            sd => "~",
+            se => "~",
+            se_FI => "~",
+            se_SE => "~",
+            seh => "~",
+            ses => "~",
+            sg => "~",
+            shi => "~",
+            shi_Latn => "~",
+            shi_Tfng => "~",
+            si => "~",
+            sk => "~",
+            sl => "~",
+            smn => "~",
+            sn => "~",
+            so => "~",
+            so_DJ => "~",
+            so_ET => "~",
+            so_KE => "~",
+            sq => "≈",
+            sq_MK => "≈",
+            sq_XK => "≈",
+            sr => "~",
+            sr_Cyrl => "~",
+            sr_Cyrl_BA => "~",
+            sr_Cyrl_ME => "~",
+            sr_Cyrl_XK => "~",
+            sr_Latn => "~",
+            sr_Latn_BA => "~",
+            sr_Latn_ME => "~",
+            sr_Latn_XK => "~",
+            sv => "~",
+            sv_AX => "~",
+            sv_FI => "~",
+            sw => "~",
+            sw_CD => "~",
+            sw_KE => "~",
+            sw_UG => "~",
+            ta => "~",
+            ta_LK => "~",
+            ta_MY => "~",
+            ta_SG => "~",
+            te => "~",
+            teo => "~",
+            teo_KE => "~",
+            tg => "~",
+            th => "~",
+            ti => "~",
+            ti_ER => "~",
+            tk => "~",
+            to => "~",
+            tr => "~",
+            tr_CY => "~",
+            tt => "~",
+            twq => "~",
+            tzm => "~",
+            ug => "~",
+            uk => "~",
+            ur => "~",
+            ur_IN => "~",
+            uz => "~",
+            uz_Arab => "~",
+            uz_Cyrl => "~",
+            uz_Latn => "~",
+            vai => "~",
+            vai_Latn => "~",
+            vai_Vaii => "~",
+            vi => "~",
+            vo => "~",
+            vun => "~",
+            wae => "~",
+            wo => "~",
+            xh => "~",
+            xog => "~",
+            yav => "~",
+            yi => "~",
+            yo => "dáàṣì",
+            yo_BJ => "dáàshì",
+            yue => "~",
+            yue_Hans => "~",
+            yue_Hant => "~",
+            zgh => "~",
+            zh => "~",
+            zh_Hans => "~",
+            zh_Hans_HK => "~",
+            zh_Hans_MO => "~",
+            zh_Hans_SG => "~",
+            zh_Hant => "~",
+            zh_Hant_HK => "~",
+            zh_Hant_MO => "~",
+            zu => "~",
+        }
+    }
+    #[doc = r" Returns the suffixes of the locale's compact notation (e.g. `K` in `1.2K` for"]
+    #[doc = r" English, or `Mio.` in `1,2 Mio.` for German), short and long ones alike, along with"]
+    #[doc = r" the powers of ten they stand for, longest suffix first."]
+    pub fn compact_suffixes(&self) -> &'static [(&'static str, i32)] {
+        use self::Locale::*;
+        match self {
+            af => &[
+                ("biljoen", 12),
+                ("duisend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("mjd", 9),
+                ("bn", 12),
+                ("k", 3),
+                ("m", 6),
+            ],
+            af_NA => &[
+                ("biljoen", 12),
+                ("duisend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("mjd", 9),
+                ("bn", 12),
+                ("k", 3),
+                ("m", 6),
+            ],
+            agq => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ak => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            am => &[
+                ("ትሪሊዮን", 12),
+                ("ሚሊዮን", 6),
+                ("ቢሊዮን", 9),
+                ("ሚ", 6),
+                ("ሺ", 3),
+                ("ቢ", 9),
+                ("ት", 12),
+            ],
+            ar => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_AE => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_BH => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_DJ => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_DZ => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_EG => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_EH => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_ER => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_IL => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_IQ => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_JO => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_KM => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_KW => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_LB => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_LY => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_MA => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_MR => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_OM => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_PS => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_QA => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_SA => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_SD => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_SO => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_SS => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_SY => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_TD => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_TN => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            ar_YE => &[
+                ("ترليون", 12),
+                ("ملايين", 6),
+                ("مليار", 9),
+                ("مليون", 6),
+                ("آلاف", 3),
+                ("ألف", 3),
+            ],
+            as_ => &[
+                ("শত পৰ\u{9be}ৰ\u{9cd}দ\u{9cd}ধ", 12),
+                ("শত কোটি", 9),
+                ("শঃ\u{a0}কোঃ", 9),
+                ("নিয\u{9c1}ত", 6),
+                ("হ\u{9be}জ\u{9be}ৰ", 3),
+                ("শঃ\u{a0}কঃ", 9),
+                ("শঃ\u{a0}পঃ", 12),
+                ("নিঃ", 6),
+                ("ল\u{9be}খ", 5),
+            ],
+            asa => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ast => &[
+                ("millares", 3),
+                ("millones", 6),
+                ("millón", 6),
+                ("millar", 3),
+                ("G", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            az => &[
+                ("milyard", 9),
+                ("trilyon", 12),
+                ("milyon", 6),
+                ("mlrd", 9),
+                ("trln", 12),
+                ("min", 3),
+                ("mln", 6),
+                ("K", 3),
+            ],
+            az_Cyrl => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            az_Latn => &[
+                ("milyard", 9),
+                ("trilyon", 12),
+                ("milyon", 6),
+                ("mlrd", 9),
+                ("trln", 12),
+                ("min", 3),
+                ("mln", 6),
+                ("K", 3),
+            ],
+            bas => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            be => &[
+                ("мільярдаў", 9),
+                ("трыльёнаў", 12),
+                ("мільярда", 9),
+                ("мільярды", 9),
+                ("мільёнаў", 6),
+                ("трыльёна", 12),
+                ("трыльёны", 12),
+                ("мільярд", 9),
+                ("мільёна", 6),
+                ("мільёны", 6),
+                ("трыльён", 12),
+                ("мільён", 6),
+                ("тысяча", 3),
+                ("тысячы", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            bem => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            bez => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            bg => &[
+                ("милиарда", 9),
+                ("трилиона", 12),
+                ("милиард", 9),
+                ("милиона", 6),
+                ("трилион", 12),
+                ("милион", 6),
+                ("хиляди", 3),
+                ("млрд.", 9),
+                ("трлн.", 12),
+                ("млн.", 6),
+                ("хил.", 3),
+            ],
+            bm => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            bn => &[
+                ("ল\u{9be}খ কোটি", 12),
+                ("হ\u{9be}জ\u{9be}র", 3),
+                ("ল\u{9be}.কো.", 12),
+                ("শত\u{a0}কো", 9),
+                ("কোটি", 7),
+                ("ল\u{9be}খ", 5),
+                ("কো", 7),
+                ("ল\u{9be}", 5),
+                ("হ\u{9be}", 3),
+            ],
+            bn_IN => &[
+                ("ল\u{9be}খ কোটি", 12),
+                ("হ\u{9be}জ\u{9be}র", 3),
+                ("ল\u{9be}.কো.", 12),
+                ("শত\u{a0}কো", 9),
+                ("কোটি", 7),
+                ("ল\u{9be}খ", 5),
+                ("কো", 7),
+                ("ল\u{9be}", 5),
+                ("হ\u{9be}", 3),
+            ],
+            bo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            bo_IN => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            br => &[
+                ("a v/bilionoù", 12),
+                ("a v/milionoù", 6),
+                ("a viliardoù", 9),
+                ("a viliadoù", 3),
+                ("v/bilion", 12),
+                ("v/milion", 6),
+                ("miliard", 9),
+                ("viliard", 9),
+                ("bilion", 12),
+                ("miliad", 3),
+                ("milion", 6),
+                ("viliad", 3),
+                ("G", 9),
+                ("M", 6),
+                ("T", 12),
+                ("k", 3),
+            ],
+            brx => &[
+                ("त\u{94d}रिलियन", 12),
+                ("बिलियन", 9),
+                ("निज\u{941}त", 6),
+                ("रोजा", 3),
+                ("एम", 6),
+                ("क\u{947}", 3),
+                ("ति", 12),
+                ("बि", 9),
+            ],
+            bs => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("biliona", 12),
+                ("hiljada", 3),
+                ("hiljade", 3),
+                ("miliona", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("hilj.", 3),
+                ("bil.", 12),
+                ("mil.", 6),
+                ("mlr.", 9),
+            ],
+            bs_Cyrl => &[("млрд", 9), ("бил", 12), ("мил", 6), ("хиљ", 3)],
+            bs_Latn => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("biliona", 12),
+                ("hiljada", 3),
+                ("hiljade", 3),
+                ("miliona", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("hilj.", 3),
+                ("bil.", 12),
+                ("mil.", 6),
+                ("mlr.", 9),
+            ],
+            ca => &[
+                ("milers de milions", 9),
+                ("miler de milions", 9),
+                ("bilions", 12),
+                ("milions", 6),
+                ("bilió", 12),
+                ("milers", 3),
+                ("milió", 6),
+                ("miler", 3),
+                ("kM", 9),
+                ("B", 12),
+                ("M", 6),
+                ("k", 3),
+            ],
+            ca_AD => &[
+                ("milers de milions", 9),
+                ("miler de milions", 9),
+                ("bilions", 12),
+                ("milions", 6),
+                ("bilió", 12),
+                ("milers", 3),
+                ("milió", 6),
+                ("miler", 3),
+                ("kM", 9),
+                ("B", 12),
+                ("M", 6),
+                ("k", 3),
+            ],
+            ca_ES_VALENCIA => &[
+                ("milers de milions", 9),
+                ("miler de milions", 9),
+                ("bilions", 12),
+                ("milions", 6),
+                ("bilió", 12),
+                ("milers", 3),
+                ("milió", 6),
+                ("miler", 3),
+                ("kM", 9),
+                ("B", 12),
+                ("M", 6),
+                ("k", 3),
+            ],
+            ca_FR => &[
+                ("milers de milions", 9),
+                ("miler de milions", 9),
+                ("bilions", 12),
+                ("milions", 6),
+                ("bilió", 12),
+                ("milers", 3),
+                ("milió", 6),
+                ("miler", 3),
+                ("kM", 9),
+                ("B", 12),
+                ("M", 6),
+                ("k", 3),
+            ],
+            ca_IT => &[
+                ("milers de milions", 9),
+                ("miler de milions", 9),
+                ("bilions", 12),
+                ("milions", 6),
+                ("bilió", 12),
+                ("milers", 3),
+                ("milió", 6),
+                ("miler", 3),
+                ("kM", 9),
+                ("B", 12),
+                ("M", 6),
+                ("k", 3),
+            ],
+            ccp => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ccp_IN => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ce => &[
+                ("миллиард", 9),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("эзар", 3),
+                ("млн", 6),
+            ],
+            ceb => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            cgg => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            chr => &[
+                ("ᎢᏯᏔᎳᏗᏅᏛ", 9),
+                ("ᎢᏯᏦᎠᏗᏅᏛ", 12),
+                ("ᎢᏳᏆᏗᏅᏛ", 6),
+                ("ᎢᏯᎦᏴᎵ", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            ckb => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ckb_IR => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            cs => &[
+                ("bilionů", 12),
+                ("miliarda", 9),
+                ("miliardy", 9),
+                ("milionů", 6),
+                ("bilionu", 12),
+                ("biliony", 12),
+                ("miliard", 9),
+                ("milionu", 6),
+                ("miliony", 6),
+                ("tisíce", 3),
+                ("bilion", 12),
+                ("milion", 6),
+                ("tisíc", 3),
+                ("bil.", 12),
+                ("mil.", 6),
+                ("mld.", 9),
+                ("tis.", 3),
+            ],
+            cu => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            cy => &[
+                ("triliwn", 12),
+                ("biliwn", 9),
+                ("miliwn", 6),
+                ("mil", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            da => &[
+                ("milliarder", 9),
+                ("billioner", 12),
+                ("millioner", 6),
+                ("milliard", 9),
+                ("billion", 12),
+                ("million", 6),
+                ("tusind", 3),
+                ("bio.", 12),
+                ("mia.", 9),
+                ("mio.", 6),
+                ("t", 3),
+            ],
+            da_GL => &[
+                ("milliarder", 9),
+                ("billioner", 12),
+                ("millioner", 6),
+                ("milliard", 9),
+                ("billion", 12),
+                ("million", 6),
+                ("tusind", 3),
+                ("bio.", 12),
+                ("mia.", 9),
+                ("mio.", 6),
+                ("t", 3),
+            ],
+            dav => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            de => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            de_AT => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            de_BE => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            de_CH => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            de_IT => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            de_LI => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            de_LU => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Billion", 12),
+                ("Million", 6),
+                ("Tausend", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            dje => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            dsb => &[
+                ("miliardow", 9),
+                ("miliarźe", 9),
+                ("bilionow", 12),
+                ("miliarda", 9),
+                ("miliardy", 9),
+                ("milionow", 6),
+                ("biliona", 12),
+                ("biliony", 12),
+                ("miliona", 6),
+                ("miliony", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("tysac", 3),
+                ("bil.", 12),
+                ("mio.", 6),
+                ("mrd.", 9),
+                ("tys.", 3),
+            ],
+            dua => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            dyo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            dz => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ebu => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ee => &[("triliɔn", 12), ("B", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ee_TG => &[("triliɔn", 12), ("B", 9), ("K", 3), ("M", 6), ("T", 12)],
+            el => &[
+                ("τρισεκατομμύρια", 12),
+                ("τρισεκατομμύριο", 12),
+                ("δισεκατομμύρια", 9),
+                ("δισεκατομμύριο", 9),
+                ("εκατομμύρια", 6),
+                ("εκατομμύριο", 6),
+                ("χιλιάδες", 3),
+                ("χιλιάδα", 3),
+                ("τρισ.", 12),
+                ("δισ.", 9),
+                ("χιλ.", 3),
+                ("εκ.", 6),
+            ],
+            el_CY => &[
+                ("τρισεκατομμύρια", 12),
+                ("τρισεκατομμύριο", 12),
+                ("δισεκατομμύρια", 9),
+                ("δισεκατομμύριο", 9),
+                ("εκατομμύρια", 6),
+                ("εκατομμύριο", 6),
+                ("χιλιάδες", 3),
+                ("χιλιάδα", 3),
+                ("τρισ.", 12),
+                ("δισ.", 9),
+                ("χιλ.", 3),
+                ("εκ.", 6),
+            ],
+            en => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_001 => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_150 => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_AE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_AG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_AI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_AS => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_AT => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_AU => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BB => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BS => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BW => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_BZ => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CA => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CC => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CH => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CK => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CX => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_CY => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_DE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_DG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_DK => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_DM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_ER => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_FI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_FJ => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_FK => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_FM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GB => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GD => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GH => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GU => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_GY => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_HK => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_IE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_IL => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_IM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_IN => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("LCr", 12),
+                ("TCr", 10),
+                ("Cr", 7),
+                ("L", 5),
+                ("T", 3),
+            ],
+            en_IO => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_JE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_JM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_KE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_KI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_KN => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_KY => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_LC => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_LR => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_LS => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MH => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MO => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MP => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MS => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MT => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MU => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MW => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_MY => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NA => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NF => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NL => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NR => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NU => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_NZ => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_PG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_PH => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_PK => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_PN => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_PR => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_PW => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_RW => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SB => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SC => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SD => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SE => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SH => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SL => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SS => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SX => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_SZ => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_TC => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_TK => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_TO => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_TT => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_TV => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_TZ => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_UG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_UM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_US_POSIX => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_VC => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_VG => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_VI => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_VU => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_WS => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_ZA => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_ZM => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            en_ZW => &[
+                ("thousand", 3),
+                ("trillion", 12),
+                ("billion", 9),
+                ("million", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            eo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            es => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("M", 6),
+            ],
+            es_419 => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_AR => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_BO => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_BR => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_BZ => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_CL => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_CO => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_CR => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_CU => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_DO => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_EA => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("M", 6),
+            ],
+            es_EC => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_GQ => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("M", 6),
+            ],
+            es_GT => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_HN => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_IC => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("M", 6),
+            ],
+            es_MX => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_NI => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_PA => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_PE => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_PH => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("M", 6),
+            ],
+            es_PR => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_PY => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_SV => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_US => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+            ],
+            es_UY => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            es_VE => &[
+                ("mil millones", 9),
+                ("billones", 12),
+                ("millones", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("mil\u{a0}M", 9),
+                ("mil", 3),
+                ("B", 12),
+                ("K", 3),
+                ("M", 6),
+                ("k", 3),
+            ],
+            et => &[
+                ("miljardit", 9),
+                ("triljonit", 12),
+                ("miljonit", 6),
+                ("miljard", 9),
+                ("triljon", 12),
+                ("miljon", 6),
+                ("tuhat", 3),
+                ("trln", 12),
+                ("mld", 9),
+                ("mln", 6),
+                ("tuh", 3),
+            ],
+            eu => &[("bilioi", 12), ("milioi", 6), ("B", 12), ("M", 6)],
+            ewo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            fa => &[
+                ("هزارمیلیارد", 12),
+                ("تریلیون", 12),
+                ("میلیارد", 9),
+                ("میلیون", 6),
+                ("هزار", 3),
+            ],
+            fa_AF => &[
+                ("هزارمیلیارد", 12),
+                ("تریلیون", 12),
+                ("میلیارد", 9),
+                ("میلیون", 6),
+                ("هزار", 3),
+            ],
+            ff => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_BF => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_CM => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_GH => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_GM => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_GN => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_GW => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_LR => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_MR => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_NE => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_NG => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ff_Latn_SL => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            fi => &[
+                ("biljoonaa", 12),
+                ("miljardia", 9),
+                ("miljoonaa", 6),
+                ("biljoona", 12),
+                ("miljardi", 9),
+                ("miljoona", 6),
+                ("tuhatta", 3),
+                ("bilj.", 12),
+                ("milj.", 6),
+                ("tuhat", 3),
+                ("mrd.", 9),
+                ("t.", 3),
+            ],
+            fil => &[
+                ("na trilyon", 12),
+                ("na bilyon", 9),
+                ("na milyon", 6),
+                ("na libo", 3),
+                ("trilyon", 12),
+                ("bilyon", 9),
+                ("milyon", 6),
+                ("libo", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            fo => &[
+                ("billiónir", 12),
+                ("milliardir", 9),
+                ("milliónir", 6),
+                ("billión", 12),
+                ("milliard", 9),
+                ("millión", 6),
+                ("túsund", 3),
+                ("bió.", 12),
+                ("mió.", 6),
+                ("tús.", 3),
+                ("mia.", 9),
+            ],
+            fo_DK => &[
+                ("billiónir", 12),
+                ("milliardir", 9),
+                ("milliónir", 6),
+                ("billión", 12),
+                ("milliard", 9),
+                ("millión", 6),
+                ("túsund", 3),
+                ("bió.", 12),
+                ("mió.", 6),
+                ("tús.", 3),
+                ("mia.", 9),
+            ],
+            fr => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_BE => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_BF => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_BI => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_BJ => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_BL => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_CA => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("million", 6),
+                ("mille", 3),
+                ("G", 9),
+                ("M", 6),
+                ("T", 12),
+                ("k", 3),
+            ],
+            fr_CD => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_CF => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_CG => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_CH => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_CI => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_CM => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_DJ => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_DZ => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_GA => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_GF => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_GN => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_GP => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_GQ => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_HT => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_KM => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_LU => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MA => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MC => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MF => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MG => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_ML => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MQ => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MR => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_MU => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_NC => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_NE => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_PF => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_PM => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_RE => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_RW => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_SC => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_SN => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_SY => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_TD => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_TG => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_TN => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_VU => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_WF => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fr_YT => &[
+                ("milliards", 9),
+                ("billions", 12),
+                ("milliard", 9),
+                ("millions", 6),
+                ("billion", 12),
+                ("millier", 3),
+                ("million", 6),
+                ("mille", 3),
+                ("Bn", 12),
+                ("Md", 9),
+                ("M", 6),
+                ("k", 3),
+            ],
+            fur => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            fy => &[
+                ("biljoen", 12),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("tûzen", 3),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            ga => &[
+                ("dtrilliún", 12),
+                ("thrilliún", 12),
+                ("bhilliún", 9),
+                ("mbilliún", 9),
+                ("mhilliún", 6),
+                ("trilliún", 12),
+                ("billiún", 9),
+                ("milliún", 6),
+                ("mhíle", 3),
+                ("míle", 3),
+                ("B", 9),
+                ("M", 6),
+                ("T", 12),
+                ("k", 3),
+            ],
+            ga_GB => &[
+                ("dtrilliún", 12),
+                ("thrilliún", 12),
+                ("bhilliún", 9),
+                ("mbilliún", 9),
+                ("mhilliún", 6),
+                ("trilliún", 12),
+                ("billiún", 9),
+                ("milliún", 6),
+                ("mhíle", 3),
+                ("míle", 3),
+                ("B", 9),
+                ("M", 6),
+                ("T", 12),
+                ("k", 3),
+            ],
+            gd => &[
+                ("trilleanan", 12),
+                ("billeanan", 9),
+                ("milleanan", 6),
+                ("thrillean", 12),
+                ("bhillean", 9),
+                ("mhillean", 6),
+                ("mìltean", 3),
+                ("trillean", 12),
+                ("billean", 9),
+                ("millean", 6),
+                ("mhìle", 3),
+                ("mìle", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            gl => &[
+                ("billóns", 12),
+                ("millóns", 6),
+                ("billón", 12),
+                ("millón", 6),
+                ("B", 12),
+                ("M", 6),
+            ],
+            gsw => &[
+                ("Billioone", 12),
+                ("Milliarde", 9),
+                ("Millioone", 6),
+                ("Billioon", 12),
+                ("Millioon", 6),
+                ("Tuusig", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+                ("Tsg.", 3),
+            ],
+            gsw_FR => &[
+                ("Billioone", 12),
+                ("Milliarde", 9),
+                ("Millioone", 6),
+                ("Billioon", 12),
+                ("Millioon", 6),
+                ("Tuusig", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+                ("Tsg.", 3),
+            ],
+            gsw_LI => &[
+                ("Billioone", 12),
+                ("Milliarde", 9),
+                ("Millioone", 6),
+                ("Billioon", 12),
+                ("Millioon", 6),
+                ("Tuusig", 3),
+                ("Bio.", 12),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+                ("Tsg.", 3),
+            ],
+            gu => &[
+                ("મહાપદ\u{acd}મ", 12),
+                ("નિખર\u{acd}વ", 11),
+                ("કરોડ", 7),
+                ("જલધિ", 14),
+                ("શ\u{a82}ક\u{ac1}", 13),
+                ("હજાર", 3),
+                ("અબજ", 9),
+                ("લાખ", 5),
+            ],
+            guz => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            gv => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ha => &[("B", 9), ("D", 3), ("M", 6), ("T", 12)],
+            ha_GH => &[("B", 9), ("D", 3), ("M", 6), ("T", 12)],
+            ha_NE => &[("B", 9), ("D", 3), ("M", 6), ("T", 12)],
+            haw => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            he => &[
+                ("B\u{200f}", 9),
+                ("K\u{200f}", 3),
+                ("M\u{200f}", 6),
+                ("T\u{200f}", 12),
+            ],
+            hi => &[
+                ("करोड\u{93c}", 7),
+                ("हज\u{93c}ार", 3),
+                ("अरब", 9),
+                ("खरब", 11),
+                ("नील", 13),
+                ("लाख", 5),
+                ("अ॰", 9),
+                ("क॰", 7),
+                ("ख॰", 11),
+            ],
+            hr => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("bilijuna", 12),
+                ("milijuna", 6),
+                ("bilijun", 12),
+                ("milijun", 6),
+                ("tisuća", 3),
+                ("tisuće", 3),
+                ("bil.", 12),
+                ("mil.", 6),
+                ("mlr.", 9),
+                ("tis.", 3),
+            ],
+            hr_BA => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("bilijuna", 12),
+                ("milijuna", 6),
+                ("bilijun", 12),
+                ("milijun", 6),
+                ("tisuća", 3),
+                ("tisuće", 3),
+                ("bil.", 12),
+                ("mil.", 6),
+                ("mlr.", 9),
+                ("tis.", 3),
+            ],
+            hsb => &[
+                ("miliardźe", 9),
+                ("miliardow", 9),
+                ("bilionaj", 12),
+                ("bilionow", 12),
+                ("miliarda", 9),
+                ("miliardy", 9),
+                ("milionaj", 6),
+                ("milionow", 6),
+                ("biliony", 12),
+                ("miliony", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("tysac", 3),
+                ("bil.", 12),
+                ("mio.", 6),
+                ("mrd.", 9),
+                ("tys.", 3),
+            ],
+            hu => &[
+                ("milliárd", 9),
+                ("billió", 12),
+                ("millió", 6),
+                ("ezer", 3),
+                ("Mrd", 9),
+                ("B", 12),
+                ("E", 3),
+                ("M", 6),
+            ],
+            hy => &[
+                ("միլիարդ", 9),
+                ("տրիլիոն", 12),
+                ("միլիոն", 6),
+                ("հազար", 3),
+                ("մլրդ", 9),
+                ("տրլն", 12),
+                ("հզր", 3),
+                ("մլն", 6),
+            ],
+            ia => &[
+                ("milliardos", 9),
+                ("billiones", 12),
+                ("milliardo", 9),
+                ("milliones", 6),
+                ("billion", 12),
+                ("million", 6),
+                ("milles", 3),
+                ("mille", 3),
+                ("bln", 12),
+                ("mil", 3),
+                ("mld", 9),
+                ("mln", 6),
+            ],
+            id => &[
+                ("triliun", 12),
+                ("miliar", 9),
+                ("juta", 6),
+                ("ribu", 3),
+                ("jt", 6),
+                ("rb", 3),
+                ("M", 9),
+                ("T", 12),
+            ],
+            ig => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ii => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            is => &[
+                ("milljarðar", 9),
+                ("milljarður", 9),
+                ("billjónir", 12),
+                ("milljónir", 6),
+                ("billjón", 12),
+                ("milljón", 6),
+                ("þúsund", 3),
+                ("ma.", 9),
+                ("þ.", 3),
+                ("bn", 12),
+                ("m.", 6),
+            ],
+            it => &[
+                ("mille miliardi", 12),
+                ("mila miliardi", 12),
+                ("miliardi", 9),
+                ("miliardo", 9),
+                ("milione", 6),
+                ("milioni", 6),
+                ("mila", 3),
+                ("Bln", 12),
+                ("Mln", 6),
+                ("Mrd", 9),
+            ],
+            it_CH => &[
+                ("mille miliardi", 12),
+                ("mila miliardi", 12),
+                ("miliardi", 9),
+                ("miliardo", 9),
+                ("milione", 6),
+                ("milioni", 6),
+                ("mila", 3),
+                ("Bln", 12),
+                ("Mln", 6),
+                ("Mrd", 9),
+            ],
+            it_SM => &[
+                ("mille miliardi", 12),
+                ("mila miliardi", 12),
+                ("miliardi", 9),
+                ("miliardo", 9),
+                ("milione", 6),
+                ("milioni", 6),
+                ("mila", 3),
+                ("Bln", 12),
+                ("Mln", 6),
+                ("Mrd", 9),
+            ],
+            it_VA => &[
+                ("mille miliardi", 12),
+                ("mila miliardi", 12),
+                ("miliardi", 9),
+                ("miliardo", 9),
+                ("milione", 6),
+                ("milioni", 6),
+                ("mila", 3),
+                ("Bln", 12),
+                ("Mln", 6),
+                ("Mrd", 9),
+            ],
+            ja => &[("万", 4), ("億", 8), ("兆", 12)],
+            jgo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            jmc => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            jv => &[
+                ("trilyun", 12),
+                ("milyar", 9),
+                ("yuta", 6),
+                ("èwu", 3),
+                ("È", 3),
+                ("M", 9),
+                ("T", 12),
+                ("Y", 6),
+            ],
+            ka => &[
+                ("მილიარდი", 9),
+                ("ტრილიონი", 12),
+                ("მილიონი", 6),
+                ("ათასი", 3),
+                ("მლრდ.", 9),
+                ("მლნ.", 6),
+                ("მლრ.", 9),
+                ("ტრლ.", 12),
+                ("ათ.", 3),
+            ],
+            kab => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kam => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kde => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kea => &[
+                ("mil milhãu", 9),
+                ("bilhãu", 12),
+                ("milhãu", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("MM", 9),
+                ("M", 6),
+            ],
+            khq => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ki => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kk => &[
+                ("миллиард", 9),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("млн", 6),
+                ("мың", 3),
+                ("м.", 3),
+            ],
+            kkj => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kl => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kln => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            km => &[
+                ("ទ\u{17d2}រ\u{17b8}លាន", 12),
+                ("ប\u{17ca}\u{17b8}លាន", 9),
+                ("ពាន\u{17cb}", 3),
+                ("លាន", 6),
+            ],
+            kn => &[
+                ("ಟ\u{ccd}ರ\u{cbf}ಲ\u{cbf}ಯನ\u{ccd}\u{200c}", 12),
+                ("ಬ\u{cbf}ಲ\u{cbf}ಯನ\u{ccd}", 9),
+                ("ಮ\u{cbf}ಲ\u{cbf}ಯನ\u{ccd}", 6),
+                ("ಸಾವ\u{cbf}ರ", 3),
+                ("ಟ\u{ccd}ರ\u{cbf}", 12),
+                ("ಬ\u{cbf}", 9),
+                ("ಮ\u{cbf}", 6),
+                ("ಸಾ", 3),
+            ],
+            ko => &[("만", 4), ("억", 8), ("조", 12), ("천", 3)],
+            ko_KP => &[("만", 4), ("억", 8), ("조", 12), ("천", 3)],
+            kok => &[
+                ("ट\u{94d}रिलियन", 12),
+                ("दशलक\u{94d}ष", 6),
+                ("अब\u{94d}ज", 9),
+                ("हजार", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            ks => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ksb => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ksf => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ksh => &[
+                ("Milliarden", 9),
+                ("Billionen", 12),
+                ("Milliarde", 9),
+                ("Millionen", 6),
+                ("Milljarde", 9),
+                ("Milljuhne", 6),
+                ("Billjuhn", 12),
+                ("Milljard", 9),
+                ("Billion", 12),
+                ("Dousend", 3),
+                ("Million", 6),
+                ("Bio", 12),
+                ("Mio", 6),
+                ("Mrd", 9),
+                ("tsd", 3),
+            ],
+            ku => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            kw => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ky => &[
+                ("миллиард", 9),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("трлн", 12),
+                ("миң", 3),
+                ("млд", 9),
+                ("млн", 6),
+            ],
+            lag => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            lb => &[
+                ("Billiounen", 12),
+                ("Milliarden", 9),
+                ("Milliounen", 6),
+                ("Billioun", 12),
+                ("Milliard", 9),
+                ("Millioun", 6),
+                ("Dausend", 3),
+                ("Bio.", 12),
+                ("Dsd.", 3),
+                ("Mio.", 6),
+                ("Mrd.", 9),
+            ],
+            lg => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            lkt => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ln => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ln_AO => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ln_CF => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ln_CG => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            lo => &[
+                ("ລ\u{ec9}ານລ\u{ec9}ານ", 12),
+                ("ລ\u{ec9}ານ", 6),
+                ("ກ\u{eb5}ບ", 3),
+                ("ຕ\u{eb7}\u{ec9}", 9),
+                ("ພ\u{eb1}ນ", 3),
+                ("ແສນ", 5),
+                ("ລລ", 12),
+            ],
+            lrc => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            lrc_IQ => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            lt => &[
+                ("tūkstančiai", 3),
+                ("tūkstančių", 3),
+                ("tūkstančio", 3),
+                ("tūkstantis", 3),
+                ("milijardai", 9),
+                ("milijardas", 9),
+                ("milijardų", 9),
+                ("trilijonai", 12),
+                ("trilijonas", 12),
+                ("trilijonų", 12),
+                ("milijardo", 9),
+                ("milijonai", 6),
+                ("milijonas", 6),
+                ("milijonų", 6),
+                ("trilijono", 12),
+                ("milijono", 6),
+                ("tūkst.", 3),
+                ("mlrd.", 9),
+                ("trln.", 12),
+                ("mln.", 6),
+            ],
+            lu => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            luo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            luy => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            lv => &[
+                ("tūkstotis", 3),
+                ("tūkstoši", 3),
+                ("tūkstošu", 3),
+                ("miljardi", 9),
+                ("miljards", 9),
+                ("miljardu", 9),
+                ("triljoni", 12),
+                ("triljons", 12),
+                ("triljonu", 12),
+                ("miljoni", 6),
+                ("miljons", 6),
+                ("miljonu", 6),
+                ("tūkst.", 3),
+                ("mljrd.", 9),
+                ("trilj.", 12),
+                ("milj.", 6),
+            ],
+            mas => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mas_TZ => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mer => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mfe => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mg => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mgh => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mgo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mi => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mk => &[
+                ("милијарда", 9),
+                ("милијарди", 9),
+                ("билиони", 12),
+                ("милиони", 6),
+                ("билион", 12),
+                ("илјада", 3),
+                ("илјади", 3),
+                ("милион", 6),
+                ("милј.", 9),
+                ("бил.", 12),
+                ("илј.", 3),
+                ("мил.", 6),
+                ("ми.", 9),
+                ("мј.", 9),
+                ("М", 6),
+            ],
+            ml => &[
+                ("ലക\u{d4d}ഷം കോടി", 9),
+                ("ട\u{d4d}രില\u{d4d}യൺ", 12),
+                ("ദശലക\u{d4d}ഷം", 6),
+                ("ആയിരം", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            mn => &[
+                ("их наяд", 12),
+                ("тэрбум", 9),
+                ("мянга", 3),
+                ("сая", 6),
+                ("ИН", 12),
+                ("Т", 9),
+            ],
+            mr => &[
+                ("अब\u{94d}ज", 9),
+                ("कोटी", 7),
+                ("खर\u{94d}व", 11),
+                ("पद\u{94d}म", 13),
+                ("हजार", 3),
+                ("लाख", 5),
+                ("ह", 3),
+            ],
+            ms => &[
+                ("trilion", 12),
+                ("bilion", 9),
+                ("juta", 6),
+                ("ribu", 3),
+                ("B", 9),
+                ("J", 6),
+                ("K", 3),
+                ("T", 12),
+            ],
+            ms_BN => &[
+                ("trilion", 12),
+                ("bilion", 9),
+                ("juta", 6),
+                ("ribu", 3),
+                ("B", 9),
+                ("J", 6),
+                ("K", 3),
+                ("T", 12),
+            ],
+            ms_SG => &[
+                ("trilion", 12),
+                ("bilion", 9),
+                ("juta", 6),
+                ("ribu", 3),
+                ("B", 9),
+                ("J", 6),
+                ("K", 3),
+                ("T", 12),
+            ],
+            mt => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            mua => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            my => &[
+                ("သောင\u{103a}း", 4),
+                ("ကောဋ\u{102d}", 14),
+                ("ထောင\u{103a}", 3),
+                ("သ\u{102d}န\u{103a}း", 5),
+                ("က\u{102f}ဋေ", 7),
+                ("သန\u{103a}း", 6),
+            ],
+            mzn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            naq => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            nb => &[
+                ("milliarder", 9),
+                ("billioner", 12),
+                ("millioner", 6),
+                ("milliard", 9),
+                ("billion", 12),
+                ("million", 6),
+                ("bill.", 12),
+                ("mill.", 6),
+                ("tusen", 3),
+                ("mrd.", 9),
+                ("k", 3),
+            ],
+            nb_SJ => &[
+                ("milliarder", 9),
+                ("billioner", 12),
+                ("millioner", 6),
+                ("milliard", 9),
+                ("billion", 12),
+                ("million", 6),
+                ("bill.", 12),
+                ("mill.", 6),
+                ("tusen", 3),
+                ("mrd.", 9),
+                ("k", 3),
+            ],
+            nd => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            nds => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            nds_NL => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ne => &[
+                ("करोड", 7),
+                ("हजार", 3),
+                ("अरब", 9),
+                ("खरब", 11),
+                ("लाख", 5),
+                ("श\u{902}ख", 13),
+            ],
+            ne_IN => &[
+                ("करोड", 7),
+                ("हजार", 3),
+                ("अरब", 9),
+                ("खरब", 11),
+                ("लाख", 5),
+                ("श\u{902}ख", 13),
+            ],
+            nl => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nl_AW => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nl_BE => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nl_BQ => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nl_CW => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nl_SR => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nl_SX => &[
+                ("biljoen", 12),
+                ("duizend", 3),
+                ("miljard", 9),
+                ("miljoen", 6),
+                ("bln.", 12),
+                ("mld.", 9),
+                ("mln.", 6),
+                ("K", 3),
+            ],
+            nmg => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            nn => &[
+                ("milliardar", 9),
+                ("billionar", 12),
+                ("millionar", 6),
+                ("milliard", 9),
+                ("billion", 12),
+                ("million", 6),
+                ("bill.", 12),
+                ("mill.", 6),
+                ("tusen", 3),
+                ("mrd.", 9),
+                ("k", 3),
+            ],
+            nnh => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            nus => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            nyn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            om => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            om_KE => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            or => &[
+                ("ଲକ\u{b4d}ଷକୋଟ\u{b3f}", 12),
+                ("ଶହକୋଟ\u{b3f}", 9),
+                ("ନ\u{b3f}ୟ\u{b41}ତ", 6),
+                ("ଟ\u{b4d}ର\u{b3f}", 12),
+                ("ହଜ\u{b3e}ର", 3),
+                ("ନ\u{b3f}", 6),
+                ("ବ\u{b3f}", 9),
+                ("ହ", 3),
+            ],
+            os => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            os_RU => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            pa => &[
+                ("ਹਜ\u{a3c}ਾਰ", 3),
+                ("ਕਰ\u{a4b}ੜ", 7),
+                ("ਅਰਬ", 9),
+                ("ਖਰਬ", 11),
+                ("ਨੀਲ", 13),
+                ("ਲ\u{a71}ਖ", 5),
+            ],
+            pa_Arab => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            pa_Guru => &[
+                ("ਹਜ\u{a3c}ਾਰ", 3),
+                ("ਕਰ\u{a4b}ੜ", 7),
+                ("ਅਰਬ", 9),
+                ("ਖਰਬ", 11),
+                ("ਨੀਲ", 13),
+                ("ਲ\u{a71}ਖ", 5),
+            ],
+            pl => &[
+                ("miliardów", 9),
+                ("bilionów", 12),
+                ("milionów", 6),
+                ("miliarda", 9),
+                ("miliardy", 9),
+                ("tysiąca", 3),
+                ("tysiące", 3),
+                ("tysięcy", 3),
+                ("biliona", 12),
+                ("biliony", 12),
+                ("miliard", 9),
+                ("miliona", 6),
+                ("miliony", 6),
+                ("tysiąc", 3),
+                ("bilion", 12),
+                ("milion", 6),
+                ("tys.", 3),
+                ("bln", 12),
+                ("mld", 9),
+                ("mln", 6),
+            ],
+            prg => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ps => &[("B", 9), ("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ps_PK => &[("B", 9), ("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            pt => &[
+                ("trilhões", 12),
+                ("bilhões", 9),
+                ("milhões", 6),
+                ("trilhão", 12),
+                ("bilhão", 9),
+                ("milhão", 6),
+                ("mil", 3),
+                ("tri", 12),
+                ("bi", 9),
+                ("mi", 6),
+            ],
+            pt_AO => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_CH => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_CV => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_GQ => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_GW => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_LU => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_MO => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_MZ => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_PT => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_ST => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            pt_TL => &[
+                ("mil milhões", 9),
+                ("biliões", 12),
+                ("milhões", 6),
+                ("bilião", 12),
+                ("milhão", 6),
+                ("mil", 3),
+                ("Bi", 12),
+                ("mM", 9),
+                ("M", 6),
+            ],
+            qu => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            qu_BO => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            qu_EC => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            rm => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            rn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ro => &[
+                ("de trilioane", 12),
+                ("de miliarde", 9),
+                ("de milioane", 6),
+                ("trilioane", 12),
+                ("miliarde", 9),
+                ("milioane", 6),
+                ("miliard", 9),
+                ("trilion", 12),
+                ("de mii", 3),
+                ("milion", 6),
+                ("tril.", 12),
+                ("mil.", 6),
+                ("mld.", 9),
+                ("mie", 3),
+                ("mii", 3),
+                ("K", 3),
+            ],
+            ro_MD => &[
+                ("de trilioane", 12),
+                ("de miliarde", 9),
+                ("de milioane", 6),
+                ("trilioane", 12),
+                ("miliarde", 9),
+                ("milioane", 6),
+                ("miliard", 9),
+                ("trilion", 12),
+                ("de mii", 3),
+                ("milion", 6),
+                ("tril.", 12),
+                ("mil.", 6),
+                ("mld.", 9),
+                ("mie", 3),
+                ("mii", 3),
+                ("K", 3),
+            ],
+            rof => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            root => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ru => &[
+                ("миллиардов", 9),
+                ("триллионов", 12),
+                ("миллиарда", 9),
+                ("миллионов", 6),
+                ("триллиона", 12),
+                ("миллиард", 9),
+                ("миллиона", 6),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("тысяча", 3),
+                ("тысячи", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            ru_BY => &[
+                ("миллиардов", 9),
+                ("триллионов", 12),
+                ("миллиарда", 9),
+                ("миллионов", 6),
+                ("триллиона", 12),
+                ("миллиард", 9),
+                ("миллиона", 6),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("тысяча", 3),
+                ("тысячи", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            ru_KG => &[
+                ("миллиардов", 9),
+                ("триллионов", 12),
+                ("миллиарда", 9),
+                ("миллионов", 6),
+                ("триллиона", 12),
+                ("миллиард", 9),
+                ("миллиона", 6),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("тысяча", 3),
+                ("тысячи", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            ru_KZ => &[
+                ("миллиардов", 9),
+                ("триллионов", 12),
+                ("миллиарда", 9),
+                ("миллионов", 6),
+                ("триллиона", 12),
+                ("миллиард", 9),
+                ("миллиона", 6),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("тысяча", 3),
+                ("тысячи", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            ru_MD => &[
+                ("миллиардов", 9),
+                ("триллионов", 12),
+                ("миллиарда", 9),
+                ("миллионов", 6),
+                ("триллиона", 12),
+                ("миллиард", 9),
+                ("миллиона", 6),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("тысяча", 3),
+                ("тысячи", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            ru_UA => &[
+                ("миллиардов", 9),
+                ("триллионов", 12),
+                ("миллиарда", 9),
+                ("миллионов", 6),
+                ("триллиона", 12),
+                ("миллиард", 9),
+                ("миллиона", 6),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("тысяча", 3),
+                ("тысячи", 3),
+                ("тысяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыс.", 3),
+                ("млн", 6),
+            ],
+            rw => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            rwk => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            sah => &[
+                ("миллиард", 9),
+                ("мөлүйүөн", 6),
+                ("триллион", 12),
+                ("тыһыынча", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тыһ.", 3),
+                ("мөл", 6),
+            ],
+            saq => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            sbp => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            sd => &[
+                ("ٽرلين", 12),
+                ("بلين", 9),
+                ("ملين", 6),
+                ("هزار", 3),
+                ("ھزار", 3),
+            ],
+            se => &[
+                ("miljardit", 9),
+                ("biljonat", 12),
+                ("duháhat", 3),
+                ("miljardi", 9),
+                ("miljonat", 6),
+                ("biljona", 12),
+                ("miljona", 6),
+                ("duhát", 3),
+                ("bn", 12),
+                ("dt", 3),
+                ("md", 9),
+                ("mn", 6),
+            ],
+            se_FI => &[
+                ("biljovdnat", 12),
+                ("miljárdat", 9),
+                ("biljovdna", 12),
+                ("miljárda", 9),
+                ("duháhat", 3),
+                ("miljonat", 6),
+                ("bn", 12),
+                ("dt", 3),
+                ("md", 9),
+                ("mn", 6),
+            ],
+            se_SE => &[
+                ("miljardit", 9),
+                ("biljonat", 12),
+                ("duháhat", 3),
+                ("miljardi", 9),
+                ("miljonat", 6),
+                ("biljona", 12),
+                ("miljona", 6),
+                ("duhát", 3),
+                ("bn", 12),
+                ("dt", 3),
+                ("md", 9),
+                ("mn", 6),
+            ],
+            seh => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ses => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            sg => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            shi => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            shi_Latn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            shi_Tfng => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            si => &[],
+            sk => &[
+                ("biliónov", 12),
+                ("miliónov", 6),
+                ("bilióna", 12),
+                ("bilióny", 12),
+                ("miliarda", 9),
+                ("miliardy", 9),
+                ("miliárd", 9),
+                ("milióna", 6),
+                ("milióny", 6),
+                ("bilión", 12),
+                ("milión", 6),
+                ("tisíca", 3),
+                ("tisíce", 3),
+                ("tisíc", 3),
+                ("bil.", 12),
+                ("mil.", 6),
+                ("mld.", 9),
+                ("tis.", 3),
+            ],
+            sl => &[
+                ("bilijonov", 12),
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("milijonov", 6),
+                ("bilijona", 12),
+                ("bilijoni", 12),
+                ("milijard", 9),
+                ("milijona", 6),
+                ("milijone", 6),
+                ("milijoni", 6),
+                ("bilijon", 12),
+                ("milijon", 6),
+                ("tisoč", 3),
+                ("bil.", 12),
+                ("mio.", 6),
+                ("mrd.", 9),
+                ("tis.", 3),
+            ],
+            smn => &[
+                ("tuhháát", 3),
+                ("biljovn", 12),
+                ("miljard", 9),
+                ("miljovn", 6),
+                ("G", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            sn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            so => &[
+                ("Tirilyan", 12),
+                ("Bilyan", 9),
+                ("Milyan", 6),
+                ("Kun", 3),
+                ("kun", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            so_DJ => &[
+                ("Tirilyan", 12),
+                ("Bilyan", 9),
+                ("Milyan", 6),
+                ("Kun", 3),
+                ("kun", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            so_ET => &[
+                ("Tirilyan", 12),
+                ("Bilyan", 9),
+                ("Milyan", 6),
+                ("Kun", 3),
+                ("kun", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            so_KE => &[
+                ("Tirilyan", 12),
+                ("Bilyan", 9),
+                ("Milyan", 6),
+                ("Kun", 3),
+                ("kun", 3),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            sq => &[
+                ("miliard", 9),
+                ("bilion", 12),
+                ("milion", 6),
+                ("mijë", 3),
+                ("bln", 12),
+                ("mld", 9),
+                ("mln", 6),
+            ],
+            sq_MK => &[
+                ("miliard", 9),
+                ("bilion", 12),
+                ("milion", 6),
+                ("mijë", 3),
+                ("bln", 12),
+                ("mld", 9),
+                ("mln", 6),
+            ],
+            sq_XK => &[
+                ("miliard", 9),
+                ("bilion", 12),
+                ("milion", 6),
+                ("mijë", 3),
+                ("bln", 12),
+                ("mld", 9),
+                ("mln", 6),
+            ],
+            sr => &[
+                ("милијарда", 9),
+                ("милијарде", 9),
+                ("милијарди", 9),
+                ("билиона", 12),
+                ("милиона", 6),
+                ("билион", 12),
+                ("милион", 6),
+                ("хиљада", 3),
+                ("хиљаде", 3),
+                ("млрд.", 9),
+                ("бил.", 12),
+                ("мил.", 6),
+                ("хиљ.", 3),
+            ],
+            sr_Cyrl => &[
+                ("милијарда", 9),
+                ("милијарде", 9),
+                ("милијарди", 9),
+                ("билиона", 12),
+                ("милиона", 6),
+                ("билион", 12),
+                ("милион", 6),
+                ("хиљада", 3),
+                ("хиљаде", 3),
+                ("млрд.", 9),
+                ("бил.", 12),
+                ("мил.", 6),
+                ("хиљ.", 3),
+            ],
+            sr_Cyrl_BA => &[
+                ("милијарда", 9),
+                ("милијарде", 9),
+                ("милијарди", 9),
+                ("билиона", 12),
+                ("милиона", 6),
+                ("билион", 12),
+                ("милион", 6),
+                ("хиљада", 3),
+                ("хиљаде", 3),
+                ("млрд.", 9),
+                ("бил.", 12),
+                ("мил.", 6),
+                ("хиљ.", 3),
+            ],
+            sr_Cyrl_ME => &[
+                ("милијарда", 9),
+                ("милијарде", 9),
+                ("милијарди", 9),
+                ("билиона", 12),
+                ("милиона", 6),
+                ("билион", 12),
+                ("милион", 6),
+                ("хиљада", 3),
+                ("хиљаде", 3),
+                ("млрд.", 9),
+                ("бил.", 12),
+                ("мил.", 6),
+                ("хиљ.", 3),
+            ],
+            sr_Cyrl_XK => &[
+                ("милијарда", 9),
+                ("милијарде", 9),
+                ("милијарди", 9),
+                ("билиона", 12),
+                ("милиона", 6),
+                ("билион", 12),
+                ("милион", 6),
+                ("хиљада", 3),
+                ("хиљаде", 3),
+                ("млрд.", 9),
+                ("бил.", 12),
+                ("мил.", 6),
+                ("хиљ.", 3),
+            ],
+            sr_Latn => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("biliona", 12),
+                ("hiljada", 3),
+                ("hiljade", 3),
+                ("miliona", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("hilj.", 3),
+                ("mlrd.", 9),
+                ("bil.", 12),
+                ("mil.", 6),
+            ],
+            sr_Latn_BA => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("biliona", 12),
+                ("hiljada", 3),
+                ("hiljade", 3),
+                ("miliona", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("hilj.", 3),
+                ("mlrd.", 9),
+                ("bil.", 12),
+                ("mil.", 6),
+            ],
+            sr_Latn_ME => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("biliona", 12),
+                ("hiljada", 3),
+                ("hiljade", 3),
+                ("miliona", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("hilj.", 3),
+                ("mlrd.", 9),
+                ("bil.", 12),
+                ("mil.", 6),
+            ],
+            sr_Latn_XK => &[
+                ("milijarda", 9),
+                ("milijarde", 9),
+                ("milijardi", 9),
+                ("biliona", 12),
+                ("hiljada", 3),
+                ("hiljade", 3),
+                ("miliona", 6),
+                ("bilion", 12),
+                ("milion", 6),
+                ("hilj.", 3),
+                ("mlrd.", 9),
+                ("bil.", 12),
+                ("mil.", 6),
+            ],
+            sv => &[
+                ("miljarder", 9),
+                ("biljoner", 12),
+                ("miljoner", 6),
+                ("miljard", 9),
+                ("biljon", 12),
+                ("miljon", 6),
+                ("tusen", 3),
+                ("bn", 12),
+                ("md", 9),
+                ("mn", 6),
+                ("tn", 3),
+            ],
+            sv_AX => &[
+                ("miljarder", 9),
+                ("biljoner", 12),
+                ("miljoner", 6),
+                ("miljard", 9),
+                ("biljon", 12),
+                ("miljon", 6),
+                ("tusen", 3),
+                ("bn", 12),
+                ("md", 9),
+                ("mn", 6),
+                ("tn", 3),
+            ],
+            sv_FI => &[
+                ("miljarder", 9),
+                ("biljoner", 12),
+                ("miljoner", 6),
+                ("miljard", 9),
+                ("biljon", 12),
+                ("miljon", 6),
+                ("tusen", 3),
+                ("bn", 12),
+                ("md", 9),
+                ("mn", 6),
+                ("tn", 3),
+            ],
+            sw => &[("B", 9), ("M", 6), ("T", 12)],
+            sw_CD => &[("B", 9), ("M", 6), ("T", 12)],
+            sw_KE => &[],
+            sw_UG => &[("B", 9), ("M", 6), ("T", 12)],
+            ta => &[
+                ("டிரில\u{bcd}லியன\u{bcd}", 12),
+                ("பில\u{bcd}லியன\u{bcd}", 9),
+                ("மில\u{bcd}லியன\u{bcd}", 6),
+                ("ஆயிரம\u{bcd}", 3),
+                ("டி", 12),
+                ("பி", 9),
+                ("மி", 6),
+                ("ஆ", 3),
+            ],
+            ta_LK => &[
+                ("டிரில\u{bcd}லியன\u{bcd}", 12),
+                ("பில\u{bcd}லியன\u{bcd}", 9),
+                ("மில\u{bcd}லியன\u{bcd}", 6),
+                ("ஆயிரம\u{bcd}", 3),
+                ("டி", 12),
+                ("பி", 9),
+                ("மி", 6),
+                ("ஆ", 3),
+            ],
+            ta_MY => &[
+                ("டிரில\u{bcd}லியன\u{bcd}", 12),
+                ("பில\u{bcd}லியன\u{bcd}", 9),
+                ("மில\u{bcd}லியன\u{bcd}", 6),
+                ("ஆயிரம\u{bcd}", 3),
+                ("டி", 12),
+                ("பி", 9),
+                ("மி", 6),
+                ("ஆ", 3),
+            ],
+            ta_SG => &[
+                ("டிரில\u{bcd}லியன\u{bcd}", 12),
+                ("பில\u{bcd}லியன\u{bcd}", 9),
+                ("மில\u{bcd}லியன\u{bcd}", 6),
+                ("ஆயிரம\u{bcd}", 3),
+                ("டி", 12),
+                ("பி", 9),
+                ("மி", 6),
+                ("ஆ", 3),
+            ],
+            te => &[
+                ("ట\u{c4d}ర\u{c3f}ల\u{c3f}యన\u{c4d}లు", 12),
+                ("ట\u{c4d}ర\u{c3f}ల\u{c3f}యన\u{c4d}", 12),
+                ("బ\u{c3f}ల\u{c3f}యన\u{c4d}లు", 9),
+                ("మ\u{c3f}ల\u{c3f}యన\u{c4d}లు", 6),
+                ("బ\u{c3f}ల\u{c3f}యన\u{c4d}", 9),
+                ("మ\u{c3f}ల\u{c3f}యన\u{c4d}", 6),
+                ("ట\u{c4d}ర\u{c3f}", 12),
+                ("వ\u{c47}య\u{c3f}", 3),
+                ("వ\u{c47}లు", 3),
+                ("బ\u{c3f}", 9),
+                ("మ\u{c3f}", 6),
+                ("వ\u{c47}", 3),
+            ],
+            teo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            teo_KE => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            tg => &[
+                ("миллиард", 9),
+                ("триллион", 12),
+                ("миллион", 6),
+                ("ҳазор", 3),
+                ("млрд.", 9),
+                ("трлн.", 12),
+                ("млн.", 6),
+                ("ҳзр.", 3),
+            ],
+            th => &[
+                ("หม\u{e37}\u{e48}นล\u{e49}าน", 10),
+                ("ล\u{e49}านล\u{e49}าน", 12),
+                ("พ\u{e31}นล\u{e49}าน", 9),
+                ("แสนล\u{e49}าน", 11),
+                ("หม\u{e37}\u{e48}น", 4),
+                ("ล\u{e49}าน", 6),
+                ("พ\u{e31}น", 3),
+                ("แสน", 5),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            ti => &[
+                ("ትሪልዮን", 12),
+                ("ሚልዮን", 6),
+                ("ቢልዮን", 9),
+                ("ሽሕ", 3),
+                ("ሚ", 6),
+                ("ሽ", 3),
+                ("ቢ", 9),
+                ("ት", 12),
+            ],
+            ti_ER => &[
+                ("ትሪልዮን", 12),
+                ("ሚልዮን", 6),
+                ("ቢልዮን", 9),
+                ("ሽሕ", 3),
+                ("ሚ", 6),
+                ("ሽ", 3),
+                ("ቢ", 9),
+                ("ት", 12),
+            ],
+            tk => &[
+                ("milliard", 9),
+                ("trillion", 12),
+                ("million", 6),
+                ("müň", 3),
+                ("mlrd", 9),
+                ("trln", 12),
+                ("mln", 6),
+            ],
+            to => &[
+                ("miliona", 6),
+                ("piliona", 9),
+                ("tiliona", 12),
+                ("kilu", 5),
+                ("mano", 4),
+                ("afe", 3),
+                ("M", 6),
+                ("P", 9),
+                ("T", 12),
+                ("a", 3),
+                ("k", 5),
+                ("m", 4),
+            ],
+            tr => &[
+                ("trilyon", 12),
+                ("milyar", 9),
+                ("milyon", 6),
+                ("bin", 3),
+                ("Mn", 6),
+                ("Mr", 9),
+                ("Tn", 12),
+                ("B", 3),
+            ],
+            tr_CY => &[
+                ("trilyon", 12),
+                ("milyar", 9),
+                ("milyon", 6),
+                ("bin", 3),
+                ("Mn", 6),
+                ("Mr", 9),
+                ("Tn", 12),
+                ("B", 3),
+            ],
+            tt => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            twq => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            tzm => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            ug => &[
+                ("تىرىليون", 12),
+                ("مىليارد", 9),
+                ("مىليون", 6),
+                ("مىڭ", 3),
+                ("T", 12),
+            ],
+            uk => &[
+                ("трильйонів", 12),
+                ("мільйонів", 6),
+                ("мільярдів", 9),
+                ("трильйона", 12),
+                ("трильйони", 12),
+                ("мільйона", 6),
+                ("мільйони", 6),
+                ("мільярда", 9),
+                ("мільярди", 9),
+                ("трильйон", 12),
+                ("мільйон", 6),
+                ("мільярд", 9),
+                ("тисяча", 3),
+                ("тисячі", 3),
+                ("тисяч", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("тис.", 3),
+                ("млн", 6),
+            ],
+            ur => &[
+                ("ٹریلین", 12),
+                ("لاکھ", 5),
+                ("کروڑ", 7),
+                ("کھرب", 11),
+                ("ہزار", 3),
+                ("ارب", 9),
+            ],
+            ur_IN => &[
+                ("ٹریلین", 12),
+                ("لاکھ", 5),
+                ("کروڑ", 7),
+                ("کھرب", 11),
+                ("ہزار", 3),
+                ("ارب", 9),
+            ],
+            uz => &[
+                ("milliard", 9),
+                ("trillion", 12),
+                ("million", 6),
+                ("ming", 3),
+                ("mlrd", 9),
+                ("trln", 12),
+                ("mln", 6),
+            ],
+            uz_Arab => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            uz_Cyrl => &[
+                ("миллиард", 9),
+                ("миллион", 6),
+                ("трилион", 12),
+                ("минг", 3),
+                ("млрд", 9),
+                ("трлн", 12),
+                ("млн", 6),
+            ],
+            uz_Latn => &[
+                ("milliard", 9),
+                ("trillion", 12),
+                ("million", 6),
+                ("ming", 3),
+                ("mlrd", 9),
+                ("trln", 12),
+                ("mln", 6),
+            ],
+            vai => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            vai_Latn => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            vai_Vaii => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            vi => &[
+                ("nghìn tỷ", 12),
+                ("triệu", 6),
+                ("nghìn", 3),
+                ("tỷ", 9),
+                ("NT", 12),
+                ("Tr", 6),
+                ("N", 3),
+                ("T", 9),
+            ],
+            vo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            vun => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            wae => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            wo => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            xh => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            xog => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            yav => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            yi => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            yo => &[
+                ("mílíọ\u{300}nù", 6),
+                ("tiriliọ\u{300}nù", 12),
+                ("ẹgbẹ\u{300}rún", 3),
+                ("bilíọ\u{300}nù", 9),
+                ("G", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            yo_BJ => &[
+                ("mílíɔ\u{300}nù", 6),
+                ("tiriliɔ\u{300}nù", 12),
+                ("bilíɔ\u{300}nù", 9),
+                ("ɛgbɛ\u{300}rún", 3),
+                ("G", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            yue => &[("億", 8), ("兆", 12), ("千", 3), ("萬", 4)],
+            yue_Hans => &[("万", 4), ("亿", 8), ("兆", 12)],
+            yue_Hant => &[("億", 8), ("兆", 12), ("千", 3), ("萬", 4)],
+            zgh => &[("G", 9), ("K", 3), ("M", 6), ("T", 12)],
+            zh => &[("万亿", 12), ("万", 4), ("亿", 8)],
+            zh_Hans => &[("万亿", 12), ("万", 4), ("亿", 8)],
+            zh_Hans_HK => &[("万亿", 12), ("万", 4), ("亿", 8)],
+            zh_Hans_MO => &[("万亿", 12), ("万", 4), ("亿", 8)],
+            zh_Hans_SG => &[("万亿", 12), ("万", 4), ("亿", 8)],
+            zh_Hant => &[("億", 8), ("兆", 12), ("萬", 4)],
+            zh_Hant_HK => &[
+                ("億", 8),
+                ("兆", 12),
+                ("萬", 4),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            zh_Hant_MO => &[
+                ("億", 8),
+                ("兆", 12),
+                ("萬", 4),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+            zu => &[
+                ("isigidi sezigidi", 9),
+                ("isigidintathu", 12),
+                ("inkulungwane", 3),
+                ("isigidi", 6),
+                ("B", 9),
+                ("K", 3),
+                ("M", 6),
+                ("T", 12),
+            ],
+        }
+    }
     #[doc = r" Returns the locale's decimal representation."]
     pub fn decimal(&self) -> &'static str {
         use self::Locale::*;
@@ -1687,6 +6815,1107 @@ impl Locale {
             zu => ".",
         }
     }
+    #[doc = r" Returns the locale's pattern for decimal numbers (e.g. `#,##,##0.###` for"]
+    #[doc = r" `en_IN`), which can be parsed into a [`NumberPattern`] for a [`Patterned`]."]
+    #[doc = r""]
+    #[doc = r" [`NumberPattern`]: struct.NumberPattern.html"]
+    #[doc = r" [`Patterned`]: struct.Patterned.html"]
+    pub fn decimal_pattern(&self) -> &'static str {
+        use self::Locale::*;
+        match self {
+            af => "#,##0.###",
+            af_NA => "#,##0.###",
+            agq => "#,##0.###",
+            ak => "#,##0.###",
+            am => "#,##0.###",
+            ar => "#,##0.###",
+            ar_AE => "#,##0.###",
+            ar_BH => "#,##0.###",
+            ar_DJ => "#,##0.###",
+            ar_DZ => "#,##0.###",
+            ar_EG => "#,##0.###",
+            ar_EH => "#,##0.###",
+            ar_ER => "#,##0.###",
+            ar_IL => "#,##0.###",
+            ar_IQ => "#,##0.###",
+            ar_JO => "#,##0.###",
+            ar_KM => "#,##0.###",
+            ar_KW => "#,##0.###",
+            ar_LB => "#,##0.###",
+            ar_LY => "#,##0.###",
+            ar_MA => "#,##0.###",
+            ar_MR => "#,##0.###",
+            ar_OM => "#,##0.###",
+            ar_PS => "#,##0.###",
+            ar_QA => "#,##0.###",
+            ar_SA => "#,##0.###",
+            ar_SD => "#,##0.###",
+            ar_SO => "#,##0.###",
+            ar_SS => "#,##0.###",
+            ar_SY => "#,##0.###",
+            ar_TD => "#,##0.###",
+            ar_TN => "#,##0.###",
+            ar_YE => "#,##0.###",
+            as_ => "#,##,##0.###",
+            asa => "#,##0.###",
+            ast => "#,##0.###",
+            az => "#,##0.###",
+            az_Cyrl => "#,##0.###",
+            az_Latn => "#,##0.###",
+            bas => "#,##0.###",
+            be => "#,##0.###",
+            bem => "#,##0.###",
+            bez => "#,##0.###",
+            bg => "#,##0.###",
+            bm => "#,##0.###",
+            bn => "#,##,##0.###",
+            bn_IN => "#,##,##0.###",
+            bo => "#,##0.###",
+            bo_IN => "#,##0.###",
+            br => "#,##0.###",
+            brx => "#,##,##0.###",
+            bs => "#,##0.###",
+            bs_Cyrl => "#,##0.###",
+            bs_Latn => "#,##0.###",
+            ca => "#,##0.###",
+            ca_AD => "#,##0.###",
+            ca_ES_VALENCIA => "#,##0.###",
+            ca_FR => "#,##0.###",
+            ca_IT => "#,##0.###",
+            ccp => "#,##,##0.###",
+            ccp_IN => "#,##,##0.###",
+            ce => "#,##0.###",
+            ceb => "#,##0.###",
+            cgg => "#,##0.###",
+            chr => "#,##0.###",
+            ckb => "#,##0.###",
+            ckb_IR => "#,##0.###",
+            cs => "#,##0.###",
+            cu => "#,##0.###",
+            cy => "#,##0.###",
+            da => "#,##0.###",
+            da_GL => "#,##0.###",
+            dav => "#,##0.###",
+            de => "#,##0.###",
+            de_AT => "#,##0.###",
+            de_BE => "#,##0.###",
+            de_CH => "#,##0.###",
+            de_IT => "#,##0.###",
+            de_LI => "#,##0.###",
+            de_LU => "#,##0.###",
+            dje => "#,##0.###",
+            dsb => "#,##0.###",
+            dua => "#,##0.###",
+            dyo => "#,##0.###",
+            dz => "#,##,##0.###",
+            ebu => "#,##0.###",
+            ee => "#,##0.###",
+            ee_TG => "#,##0.###",
+            el => "#,##0.###",
+            el_CY => "#,##0.###",
+            en => "#,##0.###",
+            en_001 => "#,##0.###",
+            en_150 => "#,##0.###",
+            en_AE => "#,##0.###",
+            en_AG => "#,##0.###",
+            en_AI => "#,##0.###",
+            en_AS => "#,##0.###",
+            en_AT => "#,##0.###",
+            en_AU => "#,##0.###",
+            en_BB => "#,##0.###",
+            en_BE => "#,##0.###",
+            en_BI => "#,##0.###",
+            en_BM => "#,##0.###",
+            en_BS => "#,##0.###",
+            en_BW => "#,##0.###",
+            en_BZ => "#,##0.###",
+            en_CA => "#,##0.###",
+            en_CC => "#,##0.###",
+            en_CH => "#,##0.###",
+            en_CK => "#,##0.###",
+            en_CM => "#,##0.###",
+            en_CX => "#,##0.###",
+            en_CY => "#,##0.###",
+            en_DE => "#,##0.###",
+            en_DG => "#,##0.###",
+            en_DK => "#,##0.###",
+            en_DM => "#,##0.###",
+            en_ER => "#,##0.###",
+            en_FI => "#,##0.###",
+            en_FJ => "#,##0.###",
+            en_FK => "#,##0.###",
+            en_FM => "#,##0.###",
+            en_GB => "#,##0.###",
+            en_GD => "#,##0.###",
+            en_GG => "#,##0.###",
+            en_GH => "#,##0.###",
+            en_GI => "#,##0.###",
+            en_GM => "#,##0.###",
+            en_GU => "#,##0.###",
+            en_GY => "#,##0.###",
+            en_HK => "#,##0.###",
+            en_IE => "#,##0.###",
+            en_IL => "#,##0.###",
+            en_IM => "#,##0.###",
+            en_IN => "#,##,##0.###",
+            en_IO => "#,##0.###",
+            en_JE => "#,##0.###",
+            en_JM => "#,##0.###",
+            en_KE => "#,##0.###",
+            en_KI => "#,##0.###",
+            en_KN => "#,##0.###",
+            en_KY => "#,##0.###",
+            en_LC => "#,##0.###",
+            en_LR => "#,##0.###",
+            en_LS => "#,##0.###",
+            en_MG => "#,##0.###",
+            en_MH => "#,##0.###",
+            en_MO => "#,##0.###",
+            en_MP => "#,##0.###",
+            en_MS => "#,##0.###",
+            en_MT => "#,##0.###",
+            en_MU => "#,##0.###",
+            en_MW => "#,##0.###",
+            en_MY => "#,##0.###",
+            en_NA => "#,##0.###",
+            en_NF => "#,##0.###",
+            en_NG => "#,##0.###",
+            en_NL => "#,##0.###",
+            en_NR => "#,##0.###",
+            en_NU => "#,##0.###",
+            en_NZ => "#,##0.###",
+            en_PG => "#,##0.###",
+            en_PH => "#,##0.###",
+            en_PK => "#,##0.###",
+            en_PN => "#,##0.###",
+            en_PR => "#,##0.###",
+            en_PW => "#,##0.###",
+            en_RW => "#,##0.###",
+            en_SB => "#,##0.###",
+            en_SC => "#,##0.###",
+            en_SD => "#,##0.###",
+            en_SE => "#,##0.###",
+            en_SG => "#,##0.###",
+            en_SH => "#,##0.###",
+            en_SI => "#,##0.###",
+            en_SL => "#,##0.###",
+            en_SS => "#,##0.###",
+            en_SX => "#,##0.###",
+            en_SZ => "#,##0.###",
+            en_TC => "#,##0.###",
+            en_TK => "#,##0.###",
+            en_TO => "#,##0.###",
+            en_TT => "#,##0.###",
+            en_TV => "#,##0.###",
+            en_TZ => "#,##0.###",
+            en_UG => "#,##0.###",
+            en_UM => "#,##0.###",
+            en_US_POSIX => "0.######",
+            en_VC => "#,##0.###",
+            en_VG => "#,##0.###",
+            en_VI => "#,##0.###",
+            en_VU => "#,##0.###",
+            en_WS => "#,##0.###",
+            en_ZA => "#,##0.###",
+            en_ZM => "#,##0.###",
+            en_ZW => "#,##0.###",
+            eo => "#,##0.###",
+            es => "#,##0.###",
+            es_419 => "#,##0.###",
+            es_AR => "#,##0.###",
+            es_BO => "#,##0.###",
+            es_BR => "#,##0.###",
+            es_BZ => "#,##0.###",
+            es_CL => "#,##0.###",
+            es_CO => "#,##0.###",
+            es_CR => "#,##0.###",
+            es_CU => "#,##0.###",
+            es_DO => "#,##0.###",
+            es_EA => "#,##0.###",
+            es_EC => "#,##0.###",
+            es_GQ => "#,##0.###",
+            es_GT => "#,##0.###",
+            es_HN => "#,##0.###",
+            es_IC => "#,##0.###",
+            es_MX => "#,##0.###",
+            es_NI => "#,##0.###",
+            es_PA => "#,##0.###",
+            es_PE => "#,##0.###",
+            es_PH => "#,##0.###",
+            es_PR => "#,##0.###",
+            es_PY => "#,##0.###",
+            es_SV => "#,##0.###",
+            es_US => "#,##0.###",
+            es_UY => "#,##0.###",
+            es_VE => "#,##0.###",
+            et => "#,##0.###",
+            eu => "#,##0.###",
+            ewo => "#,##0.###",
+            fa => "#,##0.###",
+            fa_AF => "#,##0.###",
+            ff => "#,##0.###",
+            ff_Latn => "#,##0.###",
+            ff_Latn_BF => "#,##0.###",
+            ff_Latn_CM => "#,##0.###",
+            ff_Latn_GH => "#,##0.###",
+            ff_Latn_GM => "#,##0.###",
+            ff_Latn_GN => "#,##0.###",
+            ff_Latn_GW => "#,##0.###",
+            ff_Latn_LR => "#,##0.###",
+            ff_Latn_MR => "#,##0.###",
+            ff_Latn_NE => "#,##0.###",
+            ff_Latn_NG => "#,##0.###",
+            ff_Latn_SL => "#,##0.###",
+            fi => "#,##0.###",
+            fil => "#,##0.###",
+            fo => "#,##0.###",
+            fo_DK => "#,##0.###",
+            fr => "#,##0.###",
+            fr_BE => "#,##0.###",
+            fr_BF => "#,##0.###",
+            fr_BI => "#,##0.###",
+            fr_BJ => "#,##0.###",
+            fr_BL => "#,##0.###",
+            fr_CA => "#,##0.###",
+            fr_CD => "#,##0.###",
+            fr_CF => "#,##0.###",
+            fr_CG => "#,##0.###",
+            fr_CH => "#,##0.###",
+            fr_CI => "#,##0.###",
+            fr_CM => "#,##0.###",
+            fr_DJ => "#,##0.###",
+            fr_DZ => "#,##0.###",
+            fr_GA => "#,##0.###",
+            fr_GF => "#,##0.###",
+            fr_GN => "#,##0.###",
+            fr_GP => "#,##0.###",
+            fr_GQ => "#,##0.###",
+            fr_HT => "#,##0.###",
+            fr_KM => "#,##0.###",
+            fr_LU => "#,##0.###",
+            fr_MA => "#,##0.###",
+            fr_MC => "#,##0.###",
+            fr_MF => "#,##0.###",
+            fr_MG => "#,##0.###",
+            fr_ML => "#,##0.###",
+            fr_MQ => "#,##0.###",
+            fr_MR => "#,##0.###",
+            fr_MU => "#,##0.###",
+            fr_NC => "#,##0.###",
+            fr_NE => "#,##0.###",
+            fr_PF => "#,##0.###",
+            fr_PM => "#,##0.###",
+            fr_RE => "#,##0.###",
+            fr_RW => "#,##0.###",
+            fr_SC => "#,##0.###",
+            fr_SN => "#,##0.###",
+            fr_SY => "#,##0.###",
+            fr_TD => "#,##0.###",
+            fr_TG => "#,##0.###",
+            fr_TN => "#,##0.###",
+            fr_VU => "#,##0.###",
+            fr_WF => "#,##0.###",
+            fr_YT => "#,##0.###",
+            fur => "#,##0.###",
+            fy => "#,##0.###",
+            ga => "#,##0.###",
+            ga_GB => "#,##0.###",
+            gd => "#,##0.###",
+            gl => "#,##0.###",
+            gsw => "#,##0.###",
+            gsw_FR => "#,##0.###",
+            gsw_LI => "#,##0.###",
+            gu => "#,##,##0.###",
+            guz => "#,##0.###",
+            gv => "#,##0.###",
+            ha => "#,##0.###",
+            ha_GH => "#,##0.###",
+            ha_NE => "#,##0.###",
+            haw => "#,##0.###",
+            he => "#,##0.###",
+            hi => "#,##,##0.###",
+            hr => "#,##0.###",
+            hr_BA => "#,##0.###",
+            hsb => "#,##0.###",
+            hu => "#,##0.###",
+            hy => "#,##0.###",
+            ia => "#,##0.###",
+            id => "#,##0.###",
+            ig => "#,##0.###",
+            ii => "#,##0.###",
+            is => "#,##0.###",
+            it => "#,##0.###",
+            it_CH => "#,##0.###",
+            it_SM => "#,##0.###",
+            it_VA => "#,##0.###",
+            ja => "#,##0.###",
+            jgo => "#,##0.###",
+            jmc => "#,##0.###",
+            jv => "#,##0.###",
+            ka => "#,##0.###",
+            kab => "#,##0.###",
+            kam => "#,##0.###",
+            kde => "#,##0.###",
+            kea => "#,##0.###",
+            khq => "#,##0.###",
+            ki => "#,##0.###",
+            kk => "#,##0.###",
+            kkj => "#,##0.###",
+            kl => "#,##0.###",
+            kln => "#,##0.###",
+            km => "#,##0.###",
+            kn => "#,##0.###",
+            ko => "#,##0.###",
+            ko_KP => "#,##0.###",
+            kok => "#,##0.###",
+            ks => "#,##,##0.###",
+            ksb => "#,##0.###",
+            ksf => "#,##0.###",
+            ksh => "#,##0.###",
+            ku => "#,##0.###",
+            kw => "#,##0.###",
+            ky => "#,##0.###",
+            lag => "#,##0.###",
+            lb => "#,##0.###",
+            lg => "#,##0.###",
+            lkt => "#,##0.###",
+            ln => "#,##0.###",
+            ln_AO => "#,##0.###",
+            ln_CF => "#,##0.###",
+            ln_CG => "#,##0.###",
+            lo => "#,##0.###",
+            lrc => "#,##0.###",
+            lrc_IQ => "#,##0.###",
+            lt => "#,##0.###",
+            lu => "#,##0.###",
+            luo => "#,##0.###",
+            luy => "#,##0.###",
+            lv => "#,##0.###",
+            mas => "#,##0.###",
+            mas_TZ => "#,##0.###",
+            mer => "#,##0.###",
+            mfe => "#,##0.###",
+            mg => "#,##0.###",
+            mgh => "#,##0.###",
+            mgo => "#,##0.###",
+            mi => "#,##0.###",
+            mk => "#,##0.###",
+            ml => "#,##,##0.###",
+            mn => "#,##0.###",
+            mr => "#,##,##0.###",
+            ms => "#,##0.###",
+            ms_BN => "#,##0.###",
+            ms_SG => "#,##0.###",
+            mt => "#,##0.###",
+            mua => "#,##0.###",
+            my => "#,##0.###",
+            mzn => "#,##0.###",
+            naq => "#,##0.###",
+            nb => "#,##0.###",
+            nb_SJ => "#,##0.###",
+            nd => "#,##0.###",
+            nds => "#,##0.###",
+            nds_NL => "#,##0.###",
+            ne => "#,##,##0.###",
+            ne_IN => "#,##,##0.###",
+            nl => "#,##0.###",
+            nl_AW => "#,##0.###",
+            nl_BE => "#,##0.###",
+            nl_BQ => "#,##0.###",
+            nl_CW => "#,##0.###",
+            nl_SR => "#,##0.###",
+            nl_SX => "#,##0.###",
+            nmg => "#,##0.###",
+            nn => "#,##0.###",
+            nnh => "#,##0.###",
+            nus => "#,##0.###",
+            nyn => "#,##0.###",
+            om => "#,##0.###",
+            om_KE => "#,##0.###",
+            or => "#,##,##0.###",
+            os => "#,##0.###",
+            os_RU => "#,##0.###",
+            pa => "#,##,##0.###",
+            pa_Arab => "#,##0.###",
+            pa_Guru => "#,##,##0.###",
+            pl => "#,##0.###",
+            prg => "#,##0.###",
+            ps => "#,##0.###",
+            ps_PK => "#,##0.###",
+            pt => "#,##0.###",
+            pt_AO => "#,##0.###",
+            pt_CH => "#,##0.###",
+            pt_CV => "#,##0.###",
+            pt_GQ => "#,##0.###",
+            pt_GW => "#,##0.###",
+            pt_LU => "#,##0.###",
+            pt_MO => "#,##0.###",
+            pt_MZ => "#,##0.###",
+            pt_PT => "#,##0.###",
+            pt_ST => "#,##0.###",
+            pt_TL => "#,##0.###",
+            qu => "#,##0.###",
+            qu_BO => "#,##0.###",
+            qu_EC => "#,##0.###",
+            rm => "#,##0.###",
+            rn => "#,##0.###",
+            ro => "#,##0.###",
+            ro_MD => "#,##0.###",
+            rof => "#,##0.###",
+            root => "#,##0.###",
+            ru => "#,##0.###",
+            ru_BY => "#,##0.###",
+            ru_KG => "#,##0.###",
+            ru_KZ => "#,##0.###",
+            ru_MD => "#,##0.###",
+            ru_UA => "#,##0.###",
+            rw => "#,##0.###",
+            rwk => "#,##0.###",
+            sah => "#,##0.###",
+            saq => "#,##0.###",
+            sbp => "#,##0.###",
+            sd => "#,##0.###",
+            se => "#,##0.###",
+            se_FI => "#,##0.###",
+            se_SE => "#,##0.###",
+            seh => "#,##0.###",
+            ses => "#,##0.###",
+            sg => "#,##0.###",
+            shi => "#,##0.###",
+            shi_Latn => "#,##0.###",
+            shi_Tfng => "#,##0.###",
+            si => "#,##0.###",
+            sk => "#,##0.###",
+            sl => "#,##0.###",
+            smn => "#,##0.###",
+            sn => "#,##0.###",
+            so => "#,##0.###",
+            so_DJ => "#,##0.###",
+            so_ET => "#,##0.###",
+            so_KE => "#,##0.###",
+            sq => "#,##0.###",
+            sq_MK => "#,##0.###",
+            sq_XK => "#,##0.###",
+            sr => "#,##0.###",
+            sr_Cyrl => "#,##0.###",
+            sr_Cyrl_BA => "#,##0.###",
+            sr_Cyrl_ME => "#,##0.###",
+            sr_Cyrl_XK => "#,##0.###",
+            sr_Latn => "#,##0.###",
+            sr_Latn_BA => "#,##0.###",
+            sr_Latn_ME => "#,##0.###",
+            sr_Latn_XK => "#,##0.###",
+            sv => "#,##0.###",
+            sv_AX => "#,##0.###",
+            sv_FI => "#,##0.###",
+            sw => "#,##0.###",
+            sw_CD => "#,##0.###",
+            sw_KE => "#,##0.###",
+            sw_UG => "#,##0.###",
+            ta => "#,##,##0.###",
+            ta_LK => "#,##,##0.###",
+            ta_MY => "#,##0.###",
+            ta_SG => "#,##0.###",
+            te => "#,##,##0.###",
+            teo => "#,##0.###",
+            teo_KE => "#,##0.###",
+            tg => "#,##0.###",
+            th => "#,##0.###",
+            ti => "#,##0.###",
+            ti_ER => "#,##0.###",
+            tk => "#,##0.###",
+            to => "#,##0.###",
+            tr => "#,##0.###",
+            tr_CY => "#,##0.###",
+            tt => "#,##0.###",
+            twq => "#,##0.###",
+            tzm => "#,##0.###",
+            ug => "#,##0.###",
+            uk => "#,##0.###",
+            ur => "#,##0.###",
+            ur_IN => "#,##0.###",
+            uz => "#,##0.###",
+            uz_Arab => "#,##0.###",
+            uz_Cyrl => "#,##0.###",
+            uz_Latn => "#,##0.###",
+            vai => "#,##0.###",
+            vai_Latn => "#,##0.###",
+            vai_Vaii => "#,##0.###",
+            vi => "#,##0.###",
+            vo => "#,##0.###",
+            vun => "#,##0.###",
+            wae => "#,##0.###",
+            wo => "#,##0.###",
+            xh => "#,##0.###",
+            xog => "#,##0.###",
+            yav => "#,##0.###",
+            yi => "#,##0.###",
+            yo => "#,##0.###",
+            yo_BJ => "#,##0.###",
+            yue => "#,##0.###",
+            yue_Hans => "#,##0.###",
+            yue_Hant => "#,##0.###",
+            zgh => "#,##0.###",
+            zh => "#,##0.###",
+            zh_Hans => "#,##0.###",
+            zh_Hans_HK => "#,##0.###",
+            zh_Hans_MO => "#,##0.###",
+            zh_Hans_SG => "#,##0.###",
+            zh_Hant => "#,##0.###",
+            zh_Hant_HK => "#,##0.###",
+            zh_Hant_MO => "#,##0.###",
+            zu => "#,##0.###",
+        }
+    }
+    #[doc = r" Returns the locale's symbol for the exponent of scientific notation (e.g. `E` for"]
+    #[doc = r" English, as in `1.5E3`)."]
+    pub fn exponential_symbol(&self) -> &'static str {
+        use self::Locale::*;
+        match self {
+            af => "E",
+            af_NA => "E",
+            agq => "E",
+            ak => "E",
+            am => "E",
+            ar => "E",
+            ar_AE => "E",
+            ar_BH => "E",
+            ar_DJ => "E",
+            ar_DZ => "E",
+            ar_EG => "E",
+            ar_EH => "E",
+            ar_ER => "E",
+            ar_IL => "E",
+            ar_IQ => "E",
+            ar_JO => "E",
+            ar_KM => "E",
+            ar_KW => "E",
+            ar_LB => "E",
+            ar_LY => "E",
+            ar_MA => "E",
+            ar_MR => "E",
+            ar_OM => "E",
+            ar_PS => "E",
+            ar_QA => "E",
+            ar_SA => "E",
+            ar_SD => "E",
+            ar_SO => "E",
+            ar_SS => "E",
+            ar_SY => "E",
+            ar_TD => "E",
+            ar_TN => "E",
+            ar_YE => "E",
+            as_ => "E",
+            asa => "E",
+            ast => "E",
+            az => "E",
+            az_Cyrl => "E",
+            az_Latn => "E",
+            bas => "E",
+            be => "E",
+            bem => "E",
+            bez => "E",
+            bg => "E",
+            bm => "E",
+            bn => "E",
+            bn_IN => "E",
+            bo => "E",
+            bo_IN => "E",
+            br => "E",
+            brx => "E",
+            bs => "E",
+            bs_Cyrl => "E",
+            bs_Latn => "E",
+            ca => "E",
+            ca_AD => "E",
+            ca_ES_VALENCIA => "E",
+            ca_FR => "E",
+            ca_IT => "E",
+            ccp => "E",
+            ccp_IN => "E",
+            ce => "E",
+            ceb => "E",
+            cgg => "E",
+            chr => "E",
+            ckb => "E",
+            ckb_IR => "E",
+            cs => "E",
+            cu => "E",
+            cy => "E",
+            da => "E",
+            da_GL => "E",
+            dav => "E",
+            de => "E",
+            de_AT => "E",
+            de_BE => "E",
+            de_CH => "E",
+            de_IT => "E",
+            de_LI => "E",
+            de_LU => "E",
+            dje => "E",
+            dsb => "E",
+            dua => "E",
+            dyo => "E",
+            dz => "E",
+            ebu => "E",
+            ee => "E",
+            ee_TG => "E",
+            el => "E",
+            el_CY => "E",
+            en => "E",
+            en_001 => "E",
+            en_150 => "E",
+            en_AE => "E",
+            en_AG => "E",
+            en_AI => "E",
+            en_AS => "E",
+            en_AT => "E",
+            en_AU => "E",
+            en_BB => "E",
+            en_BE => "E",
+            en_BI => "E",
+            en_BM => "E",
+            en_BS => "E",
+            en_BW => "E",
+            en_BZ => "E",
+            en_CA => "E",
+            en_CC => "E",
+            en_CH => "E",
+            en_CK => "E",
+            en_CM => "E",
+            en_CX => "E",
+            en_CY => "E",
+            en_DE => "E",
+            en_DG => "E",
+            en_DK => "E",
+            en_DM => "E",
+            en_ER => "E",
+            en_FI => "E",
+            en_FJ => "E",
+            en_FK => "E",
+            en_FM => "E",
+            en_GB => "E",
+            en_GD => "E",
+            en_GG => "E",
+            en_GH => "E",
+            en_GI => "E",
+            en_GM => "E",
+            en_GU => "E",
+            en_GY => "E",
+            en_HK => "E",
+            en_IE => "E",
+            en_IL => "E",
+            en_IM => "E",
+            en_IN => "E",
+            en_IO => "E",
+            en_JE => "E",
+            en_JM => "E",
+            en_KE => "E",
+            en_KI => "E",
+            en_KN => "E",
+            en_KY => "E",
+            en_LC => "E",
+            en_LR => "E",
+            en_LS => "E",
+            en_MG => "E",
+            en_MH => "E",
+            en_MO => "E",
+            en_MP => "E",
+            en_MS => "E",
+            en_MT => "E",
+            en_MU => "E",
+            en_MW => "E",
+            en_MY => "E",
+            en_NA => "E",
+            en_NF => "E",
+            en_NG => "E",
+            en_NL => "E",
+            en_NR => "E",
+            en_NU => "E",
+            en_NZ => "E",
+            en_PG => "E",
+            en_PH => "E",
+            en_PK => "E",
+            en_PN => "E",
+            en_PR => "E",
+            en_PW => "E",
+            en_RW => "E",
+            en_SB => "E",
+            en_SC => "E",
+            en_SD => "E",
+            en_SE => "E",
+            en_SG => "E",
+            en_SH => "E",
+            en_SI => "E",
+            en_SL => "E",
+            en_SS => "E",
+            en_SX => "E",
+            en_SZ => "E",
+            en_TC => "E",
+            en_TK => "E",
+            en_TO => "E",
+            en_TT => "E",
+            en_TV => "E",
+            en_TZ => "E",
+            en_UG => "E",
+            en_UM => "E",
+            en_US_POSIX => "E",
+            en_VC => "E",
+            en_VG => "E",
+            en_VI => "E",
+            en_VU => "E",
+            en_WS => "E",
+            en_ZA => "E",
+            en_ZM => "E",
+            en_ZW => "E",
+            eo => "E",
+            es => "E",
+            es_419 => "E",
+            es_AR => "E",
+            es_BO => "E",
+            es_BR => "E",
+            es_BZ => "E",
+            es_CL => "E",
+            es_CO => "E",
+            es_CR => "E",
+            es_CU => "E",
+            es_DO => "E",
+            es_EA => "E",
+            es_EC => "E",
+            es_GQ => "E",
+            es_GT => "E",
+            es_HN => "E",
+            es_IC => "E",
+            es_MX => "E",
+            es_NI => "E",
+            es_PA => "E",
+            es_PE => "E",
+            es_PH => "E",
+            es_PR => "E",
+            es_PY => "E",
+            es_SV => "E",
+            es_US => "E",
+            es_UY => "E",
+            es_VE => "E",
+            et => "E",
+            eu => "E",
+            ewo => "E",
+            fa => "E",
+            fa_AF => "E",
+            ff => "E",
+            ff_Latn => "E",
+            ff_Latn_BF => "E",
+            ff_Latn_CM => "E",
+            ff_Latn_GH => "E",
+            ff_Latn_GM => "E",
+            ff_Latn_GN => "E",
+            ff_Latn_GW => "E",
+            ff_Latn_LR => "E",
+            ff_Latn_MR => "E",
+            ff_Latn_NE => "E",
+            ff_Latn_NG => "E",
+            ff_Latn_SL => "E",
+            fi => "E",
+            fil => "E",
+            fo => "E",
+            fo_DK => "E",
+            fr => "E",
+            fr_BE => "E",
+            fr_BF => "E",
+            fr_BI => "E",
+            fr_BJ => "E",
+            fr_BL => "E",
+            fr_CA => "E",
+            fr_CD => "E",
+            fr_CF => "E",
+            fr_CG => "E",
+            fr_CH => "E",
+            fr_CI => "E",
+            fr_CM => "E",
+            fr_DJ => "E",
+            fr_DZ => "E",
+            fr_GA => "E",
+            fr_GF => "E",
+            fr_GN => "E",
+            fr_GP => "E",
+            fr_GQ => "E",
+            fr_HT => "E",
+            fr_KM => "E",
+            fr_LU => "E",
+            fr_MA => "E",
+            fr_MC => "E",
+            fr_MF => "E",
+            fr_MG => "E",
+            fr_ML => "E",
+            fr_MQ => "E",
+            fr_MR => "E",
+            fr_MU => "E",
+            fr_NC => "E",
+            fr_NE => "E",
+            fr_PF => "E",
+            fr_PM => "E",
+            fr_RE => "E",
+            fr_RW => "E",
+            fr_SC => "E",
+            fr_SN => "E",
+            fr_SY => "E",
+            fr_TD => "E",
+            fr_TG => "E",
+            fr_TN => "E",
+            fr_VU => "E",
+            fr_WF => "E",
+            fr_YT => "E",
+            fur => "E",
+            fy => "E",
+            ga => "E",
+            ga_GB => "E",
+            gd => "E",
+            gl => "E",
+            gsw => "E",
+            gsw_FR => "E",
+            gsw_LI => "E",
+            gu => "E",
+            guz => "E",
+            gv => "E",
+            ha => "E",
+            ha_GH => "E",
+            ha_NE => "E",
+            haw => "E",
+            he => "E",
+            hi => "E",
+            hr => "E",
+            hr_BA => "E",
+            hsb => "E",
+            hu => "E",
+            hy => "E",
+            ia => "E",
+            id => "E",
+            ig => "E",
+            ii => "E",
+            is => "E",
+            it => "E",
+            it_CH => "E",
+            it_SM => "E",
+            it_VA => "E",
+            ja => "E",
+            jgo => "E",
+            jmc => "E",
+            jv => "E",
+            ka => "E",
+            kab => "E",
+            kam => "E",
+            kde => "E",
+            kea => "E",
+            khq => "E",
+            ki => "E",
+            kk => "E",
+            kkj => "E",
+            kl => "E",
+            kln => "E",
+            km => "E",
+            kn => "E",
+            ko => "E",
+            ko_KP => "E",
+            kok => "E",
+            ks => "E",
+            ksb => "E",
+            ksf => "E",
+            ksh => "E",
+            ku => "E",
+            kw => "E",
+            ky => "E",
+            lag => "E",
+            lb => "E",
+            lg => "E",
+            lkt => "E",
+            ln => "E",
+            ln_AO => "E",
+            ln_CF => "E",
+            ln_CG => "E",
+            lo => "E",
+            lrc => "E",
+            lrc_IQ => "E",
+            lt => "E",
+            lu => "E",
+            luo => "E",
+            luy => "E",
+            lv => "E",
+            mas => "E",
+            mas_TZ => "E",
+            mer => "E",
+            mfe => "E",
+            mg => "E",
+            mgh => "E",
+            mgo => "E",
+            mi => "E",
+            mk => "E",
+            ml => "E",
+            mn => "E",
+            mr => "E",
+            ms => "E",
+            ms_BN => "E",
+            ms_SG => "E",
+            mt => "E",
+            mua => "E",
+            my => "E",
+            mzn => "E",
+            naq => "E",
+            nb => "E",
+            nb_SJ => "E",
+            nd => "E",
+            nds => "E",
+            nds_NL => "E",
+            ne => "E",
+            ne_IN => "E",
+            nl => "E",
+            nl_AW => "E",
+            nl_BE => "E",
+            nl_BQ => "E",
+            nl_CW => "E",
+            nl_SR => "E",
+            nl_SX => "E",
+            nmg => "E",
+            nn => "E",
+            nnh => "E",
+            nus => "E",
+            nyn => "E",
+            om => "E",
+            om_KE => "E",
+            or => "E",
+            os => "E",
+            os_RU => "E",
+            pa => "E",
+            pa_Arab => "E",
+            pa_Guru => "E",
+            pl => "E",
+            prg => "E",
+            ps => "E",
+            ps_PK => "E",
+            pt => "E",
+            pt_AO => "E",
+            pt_CH => "E",
+            pt_CV => "E",
+            pt_GQ => "E",
+            pt_GW => "E",
+            pt_LU => "E",
+            pt_MO => "E",
+            pt_MZ => "E",
+            pt_PT => "E",
+            pt_ST => "E",
+            pt_TL => "E",
+            qu => "E",
+            qu_BO => "E",
+            qu_EC => "E",
+            rm => "E",
+            rn => "E",
+            ro => "E",
+            ro_MD => "E",
+            rof => "E",
+            root => "E",
+            ru => "E",
+            ru_BY => "E",
+            ru_KG => "E",
+            ru_KZ => "E",
+            ru_MD => "E",
+            ru_UA => "E",
+            rw => "E",
+            rwk => "E",
+            sah => "E",
+            saq => "E",
+            sbp => "E",
+            sd => "E",
+            se => "E",
+            se_FI => "E",
+            se_SE => "E",
+            seh => "E",
+            ses => "E",
+            sg => "E",
+            shi => "E",
+            shi_Latn => "E",
+            shi_Tfng => "E",
+            si => "E",
+            sk => "E",
+            sl => "E",
+            smn => "E",
+            sn => "E",
+            so => "E",
+            so_DJ => "E",
+            so_ET => "E",
+            so_KE => "E",
+            sq => "E",
+            sq_MK => "E",
+            sq_XK => "E",
+            sr => "E",
+            sr_Cyrl => "E",
+            sr_Cyrl_BA => "E",
+            sr_Cyrl_ME => "E",
+            sr_Cyrl_XK => "E",
+            sr_Latn => "E",
+            sr_Latn_BA => "E",
+            sr_Latn_ME => "E",
+            sr_Latn_XK => "E",
+            sv => "E",
+            sv_AX => "E",
+            sv_FI => "E",
+            sw => "E",
+            sw_CD => "E",
+            sw_KE => "E",
+            sw_UG => "E",
+            ta => "E",
+            ta_LK => "E",
+            ta_MY => "E",
+            ta_SG => "E",
+            te => "E",
+            teo => "E",
+            teo_KE => "E",
+            tg => "E",
+            th => "E",
+            ti => "E",
+            ti_ER => "E",
+            tk => "E",
+            to => "E",
+            tr => "E",
+            tr_CY => "E",
+            tt => "E",
+            twq => "E",
+            tzm => "E",
+            ug => "E",
+            uk => "E",
+            ur => "E",
+            ur_IN => "E",
+            uz => "E",
+            uz_Arab => "E",
+            uz_Cyrl => "E",
+            uz_Latn => "E",
+            vai => "E",
+            vai_Latn => "E",
+            vai_Vaii => "E",
+            vi => "E",
+            vo => "E",
+            vun => "E",
+            wae => "E",
+            wo => "E",
+            xh => "E",
+            xog => "E",
+            yav => "E",
+            yi => "E",
+            yo => "E",
+            yo_BJ => "E",
+            yue => "E",
+            yue_Hans => "E",
+            yue_Hant => "E",
+            zgh => "E",
+            zh => "E",
+            zh_Hans => "E",
+            zh_Hans_HK => "E",
+            zh_Hans_MO => "E",
+            zh_Hans_SG => "E",
+            zh_Hant => "E",
+            zh_Hant_HK => "E",
+            zh_Hant_MO => "E",
+            zu => "E",
+        }
+    }
     #[doc = r" Returns the locale's [`Grouping`]."]
     #[doc = r""]
     #[doc = r" [`Grouping`]: enum.Grouping.html"]
@@ -4430,6 +10659,554 @@ impl Locale {
             zu => "NaN",
         }
     }
+    #[doc = r" Returns the locale's percent sign."]
+    pub fn percent_sign(&self) -> &'static str {
+        use self::Locale::*;
+        match self {
+            af => "%",
+            af_NA => "%",
+            agq => "%",
+            ak => "%",
+            am => "%",
+            ar => "%",
+            ar_AE => "%",
+            ar_BH => "%",
+            ar_DJ => "%",
+            ar_DZ => "%",
+            ar_EG => "%",
+            ar_EH => "%",
+            ar_ER => "%",
+            ar_IL => "%",
+            ar_IQ => "%",
+            ar_JO => "%",
+            ar_KM => "%",
+            ar_KW => "%",
+            ar_LB => "%",
+            ar_LY => "%",
+            ar_MA => "%",
+            ar_MR => "%",
+            ar_OM => "%",
+            ar_PS => "%",
+            ar_QA => "%",
+            ar_SA => "%",
+            ar_SD => "%",
+            ar_SO => "%",
+            ar_SS => "%",
+            ar_SY => "%",
+            ar_TD => "%",
+            ar_TN => "%",
+            ar_YE => "%",
+            as_ => "%",
+            asa => "%",
+            ast => "%",
+            az => "%",
+            az_Cyrl => "%",
+            az_Latn => "%",
+            bas => "%",
+            be => "%",
+            bem => "%",
+            bez => "%",
+            bg => "%",
+            bm => "%",
+            bn => "%",
+            bn_IN => "%",
+            bo => "%",
+            bo_IN => "%",
+            br => "%",
+            brx => "%",
+            bs => "%",
+            bs_Cyrl => "%",
+            bs_Latn => "%",
+            ca => "%",
+            ca_AD => "%",
+            ca_ES_VALENCIA => "%",
+            ca_FR => "%",
+            ca_IT => "%",
+            ccp => "%",
+            ccp_IN => "%",
+            ce => "%",
+            ceb => "%",
+            cgg => "%",
+            chr => "%",
+            ckb => "%",
+            ckb_IR => "%",
+            cs => "%",
+            cu => "%",
+            cy => "%",
+            da => "%",
+            da_GL => "%",
+            dav => "%",
+            de => "%",
+            de_AT => "%",
+            de_BE => "%",
+            de_CH => "%",
+            de_IT => "%",
+            de_LI => "%",
+            de_LU => "%",
+            dje => "%",
+            dsb => "%",
+            dua => "%",
+            dyo => "%",
+            dz => "%",
+            ebu => "%",
+            ee => "%",
+            ee_TG => "%",
+            el => "%",
+            el_CY => "%",
+            en => "%",
+            en_001 => "%",
+            en_150 => "%",
+            en_AE => "%",
+            en_AG => "%",
+            en_AI => "%",
+            en_AS => "%",
+            en_AT => "%",
+            en_AU => "%",
+            en_BB => "%",
+            en_BE => "%",
+            en_BI => "%",
+            en_BM => "%",
+            en_BS => "%",
+            en_BW => "%",
+            en_BZ => "%",
+            en_CA => "%",
+            en_CC => "%",
+            en_CH => "%",
+            en_CK => "%",
+            en_CM => "%",
+            en_CX => "%",
+            en_CY => "%",
+            en_DE => "%",
+            en_DG => "%",
+            en_DK => "%",
+            en_DM => "%",
+            en_ER => "%",
+            en_FI => "%",
+            en_FJ => "%",
+            en_FK => "%",
+            en_FM => "%",
+            en_GB => "%",
+            en_GD => "%",
+            en_GG => "%",
+            en_GH => "%",
+            en_GI => "%",
+            en_GM => "%",
+            en_GU => "%",
+            en_GY => "%",
+            en_HK => "%",
+            en_IE => "%",
+            en_IL => "%",
+            en_IM => "%",
+            en_IN => "%",
+            en_IO => "%",
+            en_JE => "%",
+            en_JM => "%",
+            en_KE => "%",
+            en_KI => "%",
+            en_KN => "%",
+            en_KY => "%",
+            en_LC => "%",
+            en_LR => "%",
+            en_LS => "%",
+            en_MG => "%",
+            en_MH => "%",
+            en_MO => "%",
+            en_MP => "%",
+            en_MS => "%",
+            en_MT => "%",
+            en_MU => "%",
+            en_MW => "%",
+            en_MY => "%",
+            en_NA => "%",
+            en_NF => "%",
+            en_NG => "%",
+            en_NL => "%",
+            en_NR => "%",
+            en_NU => "%",
+            en_NZ => "%",
+            en_PG => "%",
+            en_PH => "%",
+            en_PK => "%",
+            en_PN => "%",
+            en_PR => "%",
+            en_PW => "%",
+            en_RW => "%",
+            en_SB => "%",
+            en_SC => "%",
+            en_SD => "%",
+            en_SE => "%",
+            en_SG => "%",
+            en_SH => "%",
+            en_SI => "%",
+            en_SL => "%",
+            en_SS => "%",
+            en_SX => "%",
+            en_SZ => "%",
+            en_TC => "%",
+            en_TK => "%",
+            en_TO => "%",
+            en_TT => "%",
+            en_TV => "%",
+            en_TZ => "%",
+            en_UG => "%",
+            en_UM => "%",
+            en_US_POSIX => "%",
+            en_VC => "%",
+            en_VG => "%",
+            en_VI => "%",
+            en_VU => "%",
+            en_WS => "%",
+            en_ZA => "%",
+            en_ZM => "%",
+            en_ZW => "%",
+            eo => "%",
+            es => "%",
+            es_419 => "%",
+            es_AR => "%",
+            es_BO => "%",
+            es_BR => "%",
+            es_BZ => "%",
+            es_CL => "%",
+            es_CO => "%",
+            es_CR => "%",
+            es_CU => "%",
+            es_DO => "%",
+            es_EA => "%",
+            es_EC => "%",
+            es_GQ => "%",
+            es_GT => "%",
+            es_HN => "%",
+            es_IC => "%",
+            es_MX => "%",
+            es_NI => "%",
+            es_PA => "%",
+            es_PE => "%",
+            es_PH => "%",
+            es_PR => "%",
+            es_PY => "%",
+            es_SV => "%",
+            es_US => "%",
+            es_UY => "%",
+            es_VE => "%",
+            et => "%",
+            eu => "%",
+            ewo => "%",
+            fa => "%",
+            fa_AF => "%",
+            ff => "%",
+            ff_Latn => "%",
+            ff_Latn_BF => "%",
+            ff_Latn_CM => "%",
+            ff_Latn_GH => "%",
+            ff_Latn_GM => "%",
+            ff_Latn_GN => "%",
+            ff_Latn_GW => "%",
+            ff_Latn_LR => "%",
+            ff_Latn_MR => "%",
+            ff_Latn_NE => "%",
+            ff_Latn_NG => "%",
+            ff_Latn_SL => "%",
+            fi => "%",
+            fil => "%",
+            fo => "%",
+            fo_DK => "%",
+            fr => "%",
+            fr_BE => "%",
+            fr_BF => "%",
+            fr_BI => "%",
+            fr_BJ => "%",
+            fr_BL => "%",
+            fr_CA => "%",
+            fr_CD => "%",
+            fr_CF => "%",
+            fr_CG => "%",
+            fr_CH => "%",
+            fr_CI => "%",
+            fr_CM => "%",
+            fr_DJ => "%",
+            fr_DZ => "%",
+            fr_GA => "%",
+            fr_GF => "%",
+            fr_GN => "%",
+            fr_GP => "%",
+            fr_GQ => "%",
+            fr_HT => "%",
+            fr_KM => "%",
+            fr_LU => "%",
+            fr_MA => "%",
+            fr_MC => "%",
+            fr_MF => "%",
+            fr_MG => "%",
+            fr_ML => "%",
+            fr_MQ => "%",
+            fr_MR => "%",
+            fr_MU => "%",
+            fr_NC => "%",
+            fr_NE => "%",
+            fr_PF => "%",
+            fr_PM => "%",
+            fr_RE => "%",
+            fr_RW => "%",
+            fr_SC => "%",
+            fr_SN => "%",
+            fr_SY => "%",
+            fr_TD => "%",
+            fr_TG => "%",
+            fr_TN => "%",
+            fr_VU => "%",
+            fr_WF => "%",
+            fr_YT => "%",
+            fur => "%",
+            fy => "%",
+            ga => "%",
+            ga_GB => "%",
+            gd => "%",
+            gl => "%",
+            gsw => "%",
+            gsw_FR => "%",
+            gsw_LI => "%",
+            gu => "%",
+            guz => "%",
+            gv => "%",
+            ha => "%",
+            ha_GH => "%",
+            ha_NE => "%",
+            haw => "%",
+            he => "%",
+            hi => "%",
+            hr => "%",
+            hr_BA => "%",
+            hsb => "%",
+            hu => "%",
+            hy => "%",
+            ia => "%",
+            id => "%",
+            ig => "%",
+            ii => "%",
+            is => "%",
+            it => "%",
+            it_CH => "%",
+            it_SM => "%",
+            it_VA => "%",
+            ja => "%",
+            jgo => "%",
+            jmc => "%",
+            jv => "%",
+            ka => "%",
+            kab => "%",
+            kam => "%",
+            kde => "%",
+            kea => "%",
+            khq => "%",
+            ki => "%",
+            kk => "%",
+            kkj => "%",
+            kl => "%",
+            kln => "%",
+            km => "%",
+            kn => "%",
+            ko => "%",
+            ko_KP => "%",
+            kok => "%",
+            ks => "%",
+            ksb => "%",
+            ksf => "%",
+            ksh => "%",
+            ku => "%",
+            kw => "%",
+            ky => "%",
+            lag => "%",
+            lb => "%",
+            lg => "%",
+            lkt => "%",
+            ln => "%",
+            ln_AO => "%",
+            ln_CF => "%",
+            ln_CG => "%",
+            lo => "%",
+            lrc => "%",
+            lrc_IQ => "%",
+            lt => "%",
+            lu => "%",
+            luo => "%",
+            luy => "%",
+            lv => "%",
+            mas => "%",
+            mas_TZ => "%",
+            mer => "%",
+            mfe => "%",
+            mg => "%",
+            mgh => "%",
+            mgo => "%",
+            mi => "%",
+            mk => "%",
+            ml => "%",
+            mn => "%",
+            mr => "%",
+            ms => "%",
+            ms_BN => "%",
+            ms_SG => "%",
+            mt => "%",
+            mua => "%",
+            my => "%",
+            mzn => "%",
+            naq => "%",
+            nb => "%",
+            nb_SJ => "%",
+            nd => "%",
+            nds => "%",
+            nds_NL => "%",
+            ne => "%",
+            ne_IN => "%",
+            nl => "%",
+            nl_AW => "%",
+            nl_BE => "%",
+            nl_BQ => "%",
+            nl_CW => "%",
+            nl_SR => "%",
+            nl_SX => "%",
+            nmg => "%",
+            nn => "%",
+            nnh => "%",
+            nus => "%",
+            nyn => "%",
+            om => "%",
+            om_KE => "%",
+            or => "%",
+            os => "%",
+            os_RU => "%",
+            pa => "%",
+            pa_Arab => "%",
+            pa_Guru => "%",
+            pl => "%",
+            prg => "%",
+            ps => "%",
+            ps_PK => "%",
+            pt => "%",
+            pt_AO => "%",
+            pt_CH => "%",
+            pt_CV => "%",
+            pt_GQ => "%",
+            pt_GW => "%",
+            pt_LU => "%",
+            pt_MO => "%",
+            pt_MZ => "%",
+            pt_PT => "%",
+            pt_ST => "%",
+            pt_TL => "%",
+            qu => "%",
+            qu_BO => "%",
+            qu_EC => "%",
+            rm => "%",
+            rn => "%",
+            ro => "%",
+            ro_MD => "%",
+            rof => "%",
+            root => "%",
+            ru => "%",
+            ru_BY => "%",
+            ru_KG => "%",
+            ru_KZ => "%",
+            ru_MD => "%",
+            ru_UA => "%",
+            rw => "%",
+            rwk => "%",
+            sah => "%",
+            saq => "%",
+            sbp => "%",
+            sd => "%",
+            se => "%",
+            se_FI => "%",
+            se_SE => "%",
+            seh => "%",
+            ses => "%",
+            sg => "%",
+            shi => "%",
+            shi_Latn => "%",
+            shi_Tfng => "%",
+            si => "%",
+            sk => "%",
+            sl => "%",
+            smn => "%",
+            sn => "%",
+            so => "%",
+            so_DJ => "%",
+            so_ET => "%",
+            so_KE => "%",
+            sq => "%",
+            sq_MK => "%",
+            sq_XK => "%",
+            sr => "%",
+            sr_Cyrl => "%",
+            sr_Cyrl_BA => "%",
+            sr_Cyrl_ME => "%",
+            sr_Cyrl_XK => "%",
+            sr_Latn => "%",
+            sr_Latn_BA => "%",
+            sr_Latn_ME => "%",
+            sr_Latn_XK => "%",
+            sv => "%",
+            sv_AX => "%",
+            sv_FI => "%",
+            sw => "%",
+            sw_CD => "%",
+            sw_KE => "%",
+            sw_UG => "%",
+            ta => "%",
+            ta_LK => "%",
+            ta_MY => "%",
+            ta_SG => "%",
+            te => "%",
+            teo => "%",
+            teo_KE => "%",
+            tg => "%",
+            th => "%",
+            ti => "%",
+            ti_ER => "%",
+            tk => "%",
+            to => "%",
+            tr => "%",
+            tr_CY => "%",
+            tt => "%",
+            twq => "%",
+            tzm => "%",
+            ug => "%",
+            uk => "%",
+            ur => "%",
+            ur_IN => "%",
+            uz => "%",
+            uz_Arab => "%",
+            uz_Cyrl => "%",
+            uz_Latn => "%",
+            vai => "%",
+            vai_Latn => "%",
+            vai_Vaii => "%",
+            vi => "%",
+            vo => "%",
+            vun => "%",
+            wae => "%",
+            wo => "%",
+            xh => "%",
+            xog => "%",
+            yav => "%",
+            yi => "%",
+            yo => "%",
+            yo_BJ => "%",
+            yue => "%",
+            yue_Hans => "%",
+            yue_Hant => "%",
+            zgh => "%",
+            zh => "%",
+            zh_Hans => "%",
+            zh_Hans_HK => "%",
+            zh_Hans_MO => "%",
+            zh_Hans_SG => "%",
+            zh_Hant => "%",
+            zh_Hant_HK => "%",
+            zh_Hant_MO => "%",
+            zu => "%",
+        }
+    }
     #[doc = r" Returns the locale's plus sign representation."]
     pub fn plus_sign(&self) -> &'static str {
         use self::Locale::*;
@@ -4978,6 +11755,555 @@ impl Locale {
             zu => "+",
         }
     }
+    #[doc = r" Returns the locale's pattern for a range of numbers, in which `{0}` stands for the"]
+    #[doc = r" start and `{1}` for the end (e.g. `{0}–{1}` for English)."]
+    pub fn range_pattern(&self) -> &'static str {
+        use self::Locale::*;
+        match self {
+            af => "{0}–{1}",
+            af_NA => "{0}–{1}",
+            agq => "{0}–{1}",
+            ak => "{0}–{1}",
+            am => "{0}–{1}",
+            ar => "{0}–{1}",
+            ar_AE => "{0}–{1}",
+            ar_BH => "{0}–{1}",
+            ar_DJ => "{0}–{1}",
+            ar_DZ => "{0}–{1}",
+            ar_EG => "{0}–{1}",
+            ar_EH => "{0}–{1}",
+            ar_ER => "{0}–{1}",
+            ar_IL => "{0}–{1}",
+            ar_IQ => "{0}–{1}",
+            ar_JO => "{0}–{1}",
+            ar_KM => "{0}–{1}",
+            ar_KW => "{0}–{1}",
+            ar_LB => "{0}–{1}",
+            ar_LY => "{0}–{1}",
+            ar_MA => "{0}–{1}",
+            ar_MR => "{0}–{1}",
+            ar_OM => "{0}–{1}",
+            ar_PS => "{0}–{1}",
+            ar_QA => "{0}–{1}",
+            ar_SA => "{0}–{1}",
+            ar_SD => "{0}–{1}",
+            ar_SO => "{0}–{1}",
+            ar_SS => "{0}–{1}",
+            ar_SY => "{0}–{1}",
+            ar_TD => "{0}–{1}",
+            ar_TN => "{0}–{1}",
+            ar_YE => "{0}–{1}",
+            as_ => "{0}–{1}",
+            asa => "{0}–{1}",
+            ast => "{0}–{1}",
+            az => "{0}–{1}",
+            az_Cyrl => "{0}–{1}",
+            az_Latn => "{0}–{1}",
+            bas => "{0}–{1}",
+            be => "{0}–{1}",
+            bem => "{0}–{1}",
+            bez => "{0}–{1}",
+            bg => "{0} – {1}",
+            bm => "{0}–{1}",
+            bn => "{0}–{1}",
+            bn_IN => "{0}–{1}",
+            bo => "{0}–{1}",
+            bo_IN => "{0}–{1}",
+            br => "{0}–{1}",
+            brx => "{0}–{1}",
+            bs => "{0} – {1}",
+            bs_Cyrl => "{0}–{1}",
+            bs_Latn => "{0} – {1}",
+            ca => "{0}-{1}",
+            ca_AD => "{0}-{1}",
+            ca_ES_VALENCIA => "{0}-{1}",
+            ca_FR => "{0}-{1}",
+            ca_IT => "{0}-{1}",
+            ccp => "{0}–{1}",
+            ccp_IN => "{0}–{1}",
+            ce => "{0}–{1}",
+            ceb => "{0}–{1}",
+            cgg => "{0}–{1}",
+            chr => "{0}–{1}",
+            ckb => "{0}–{1}",
+            ckb_IR => "{0}–{1}",
+            cs => "{0}–{1}",
+            cu => "{0}–{1}",
+            cy => "{0}–{1}",
+            da => "{0}-{1}",
+            da_GL => "{0}-{1}",
+            dav => "{0}–{1}",
+            de => "{0}–{1}",
+            de_AT => "{0}–{1}",
+            de_BE => "{0}–{1}",
+            de_CH => "{0}–{1}",
+            de_IT => "{0}–{1}",
+            de_LI => "{0}–{1}",
+            de_LU => "{0}–{1}",
+            dje => "{0}–{1}",
+            dsb => "{0}–{1}",
+            dua => "{0}–{1}",
+            dyo => "{0}–{1}",
+            dz => "{0}–{1}",
+            ebu => "{0}–{1}",
+            ee => "{0}–{1}",
+            ee_TG => "{0}–{1}",
+            el => "{0}–{1}",
+            el_CY => "{0}–{1}",
+            en => "{0}–{1}",
+            en_001 => "{0}–{1}",
+            en_150 => "{0}–{1}",
+            en_AE => "{0}–{1}",
+            en_AG => "{0}–{1}",
+            en_AI => "{0}–{1}",
+            en_AS => "{0}–{1}",
+            en_AT => "{0}–{1}",
+            en_AU => "{0}–{1}",
+            en_BB => "{0}–{1}",
+            en_BE => "{0}–{1}",
+            en_BI => "{0}–{1}",
+            en_BM => "{0}–{1}",
+            en_BS => "{0}–{1}",
+            en_BW => "{0}–{1}",
+            en_BZ => "{0}–{1}",
+            en_CA => "{0}–{1}",
+            en_CC => "{0}–{1}",
+            en_CH => "{0}–{1}",
+            en_CK => "{0}–{1}",
+            en_CM => "{0}–{1}",
+            en_CX => "{0}–{1}",
+            en_CY => "{0}–{1}",
+            en_DE => "{0}–{1}",
+            en_DG => "{0}–{1}",
+            en_DK => "{0}–{1}",
+            en_DM => "{0}–{1}",
+            en_ER => "{0}–{1}",
+            en_FI => "{0}–{1}",
+            en_FJ => "{0}–{1}",
+            en_FK => "{0}–{1}",
+            en_FM => "{0}–{1}",
+            en_GB => "{0}–{1}",
+            en_GD => "{0}–{1}",
+            en_GG => "{0}–{1}",
+            en_GH => "{0}–{1}",
+            en_GI => "{0}–{1}",
+            en_GM => "{0}–{1}",
+            en_GU => "{0}–{1}",
+            en_GY => "{0}–{1}",
+            en_HK => "{0}–{1}",
+            en_IE => "{0}–{1}",
+            en_IL => "{0}–{1}",
+            en_IM => "{0}–{1}",
+            en_IN => "{0}–{1}",
+            en_IO => "{0}–{1}",
+            en_JE => "{0}–{1}",
+            en_JM => "{0}–{1}",
+            en_KE => "{0}–{1}",
+            en_KI => "{0}–{1}",
+            en_KN => "{0}–{1}",
+            en_KY => "{0}–{1}",
+            en_LC => "{0}–{1}",
+            en_LR => "{0}–{1}",
+            en_LS => "{0}–{1}",
+            en_MG => "{0}–{1}",
+            en_MH => "{0}–{1}",
+            en_MO => "{0}–{1}",
+            en_MP => "{0}–{1}",
+            en_MS => "{0}–{1}",
+            en_MT => "{0}–{1}",
+            en_MU => "{0}–{1}",
+            en_MW => "{0}–{1}",
+            en_MY => "{0}–{1}",
+            en_NA => "{0}–{1}",
+            en_NF => "{0}–{1}",
+            en_NG => "{0}–{1}",
+            en_NL => "{0}–{1}",
+            en_NR => "{0}–{1}",
+            en_NU => "{0}–{1}",
+            en_NZ => "{0}–{1}",
+            en_PG => "{0}–{1}",
+            en_PH => "{0}–{1}",
+            en_PK => "{0}–{1}",
+            en_PN => "{0}–{1}",
+            en_PR => "{0}–{1}",
+            en_PW => "{0}–{1}",
+            en_RW => "{0}–{1}",
+            en_SB => "{0}–{1}",
+            en_SC => "{0}–{1}",
+            en_SD => "{0}–{1}",
+            en_SE => "{0}–{1}",
+            en_SG => "{0}–{1}",
+            en_SH => "{0}–{1}",
+            en_SI => "{0}–{1}",
+            en_SL => "{0}–{1}",
+            en_SS => "{0}–{1}",
+            en_SX => "{0}–{1}",
+            en_SZ => "{0}–{1}",
+            en_TC => "{0}–{1}",
+            en_TK => "{0}–{1}",
+            en_TO => "{0}–{1}",
+            en_TT => "{0}–{1}",
+            en_TV => "{0}–{1}",
+            en_TZ => "{0}–{1}",
+            en_UG => "{0}–{1}",
+            en_UM => "{0}–{1}",
+            en_US_POSIX => "{0}–{1}",
+            en_VC => "{0}–{1}",
+            en_VG => "{0}–{1}",
+            en_VI => "{0}–{1}",
+            en_VU => "{0}–{1}",
+            en_WS => "{0}–{1}",
+            en_ZA => "{0}–{1}",
+            en_ZM => "{0}–{1}",
+            en_ZW => "{0}–{1}",
+            eo => "{0}–{1}",
+            es => "{0}-{1}",
+            es_419 => "{0}-{1}",
+            es_AR => "{0}-{1}",
+            es_BO => "{0}-{1}",
+            es_BR => "{0}-{1}",
+            es_BZ => "{0}-{1}",
+            es_CL => "{0}-{1}",
+            es_CO => "{0}-{1}",
+            es_CR => "{0}-{1}",
+            es_CU => "{0}-{1}",
+            es_DO => "{0}-{1}",
+            es_EA => "{0}-{1}",
+            es_EC => "{0}-{1}",
+            es_GQ => "{0}-{1}",
+            es_GT => "{0}-{1}",
+            es_HN => "{0}-{1}",
+            es_IC => "{0}-{1}",
+            es_MX => "{0}-{1}",
+            es_NI => "{0}-{1}",
+            es_PA => "{0}-{1}",
+            es_PE => "{0}-{1}",
+            es_PH => "{0}-{1}",
+            es_PR => "{0}-{1}",
+            es_PY => "{0}-{1}",
+            es_SV => "{0}-{1}",
+            es_US => "{0}-{1}",
+            es_UY => "{0}-{1}",
+            es_VE => "{0}-{1}",
+            et => "{0}‒{1}",
+            eu => "{0}-{1}",
+            ewo => "{0}–{1}",
+            fa => "{0}–{1}",
+            fa_AF => "{0}–{1}",
+            ff => "{0}–{1}",
+            ff_Latn => "{0}–{1}",
+            ff_Latn_BF => "{0}–{1}",
+            ff_Latn_CM => "{0}–{1}",
+            ff_Latn_GH => "{0}–{1}",
+            ff_Latn_GM => "{0}–{1}",
+            ff_Latn_GN => "{0}–{1}",
+            ff_Latn_GW => "{0}–{1}",
+            ff_Latn_LR => "{0}–{1}",
+            ff_Latn_MR => "{0}–{1}",
+            ff_Latn_NE => "{0}–{1}",
+            ff_Latn_NG => "{0}–{1}",
+            ff_Latn_SL => "{0}–{1}",
+            fi => "{0}–{1}",
+            fil => "{0}-{1}",
+            fo => "{0}–{1}",
+            fo_DK => "{0}–{1}",
+            fr => "{0}–{1}",
+            fr_BE => "{0}–{1}",
+            fr_BF => "{0}–{1}",
+            fr_BI => "{0}–{1}",
+            fr_BJ => "{0}–{1}",
+            fr_BL => "{0}–{1}",
+            fr_CA => "{0}–{1}",
+            fr_CD => "{0}–{1}",
+            fr_CF => "{0}–{1}",
+            fr_CG => "{0}–{1}",
+            fr_CH => "{0}–{1}",
+            fr_CI => "{0}–{1}",
+            fr_CM => "{0}–{1}",
+            fr_DJ => "{0}–{1}",
+            fr_DZ => "{0}–{1}",
+            fr_GA => "{0}–{1}",
+            fr_GF => "{0}–{1}",
+            fr_GN => "{0}–{1}",
+            fr_GP => "{0}–{1}",
+            fr_GQ => "{0}–{1}",
+            fr_HT => "{0}–{1}",
+            fr_KM => "{0}–{1}",
+            fr_LU => "{0}–{1}",
+            fr_MA => "{0}–{1}",
+            fr_MC => "{0}–{1}",
+            fr_MF => "{0}–{1}",
+            fr_MG => "{0}–{1}",
+            fr_ML => "{0}–{1}",
+            fr_MQ => "{0}–{1}",
+            fr_MR => "{0}–{1}",
+            fr_MU => "{0}–{1}",
+            fr_NC => "{0}–{1}",
+            fr_NE => "{0}–{1}",
+            fr_PF => "{0}–{1}",
+            fr_PM => "{0}–{1}",
+            fr_RE => "{0}–{1}",
+            fr_RW => "{0}–{1}",
+            fr_SC => "{0}–{1}",
+            fr_SN => "{0}–{1}",
+            fr_SY => "{0}–{1}",
+            fr_TD => "{0}–{1}",
+            fr_TG => "{0}–{1}",
+            fr_TN => "{0}–{1}",
+            fr_VU => "{0}–{1}",
+            fr_WF => "{0}–{1}",
+            fr_YT => "{0}–{1}",
+            fur => "{0}–{1}",
+            fy => "{0}-{1}",
+            ga => "{0}–{1}",
+            ga_GB => "{0}–{1}",
+            gd => "{0}–{1}",
+            gl => "{0}–{1}",
+            gsw => "{0}–{1}",
+            gsw_FR => "{0}–{1}",
+            gsw_LI => "{0}–{1}",
+            gu => "{0}-{1}",
+            guz => "{0}–{1}",
+            gv => "{0}–{1}",
+            ha => "{0}–{1}",
+            ha_GH => "{0}–{1}",
+            ha_NE => "{0}–{1}",
+            haw => "{0}–{1}",
+            he => "{0}–{1}",
+            hi => "{0}–{1}",
+            hr => "{0} – {1}",
+            hr_BA => "{0} – {1}",
+            hsb => "{0}–{1}",
+            hu => "{0}–{1}",
+            hy => "{0}–{1}",
+            ia => "{0}–{1}",
+            id => "{0}–{1}",
+            ig => "{0}–{1}",
+            ii => "{0}–{1}",
+            is => "{0}–{1}",
+            it => "{0}-{1}",
+            it_CH => "{0}-{1}",
+            it_SM => "{0}-{1}",
+            it_VA => "{0}-{1}",
+            ja => "{0}～{1}",
+            jgo => "{0}–{1}",
+            jmc => "{0}–{1}",
+            jv => "{0} – {1}",
+            ka => "{0}-{1}",
+            kab => "{0}–{1}",
+            kam => "{0}–{1}",
+            kde => "{0}–{1}",
+            kea => "{0} – {1}",
+            khq => "{0}–{1}",
+            ki => "{0}–{1}",
+            kk => "{0}–{1}",
+            kkj => "{0}–{1}",
+            kl => "{0}–{1}",
+            kln => "{0}–{1}",
+            km => "{0}–{1}",
+            kn => "{0}–{1}",
+            ko => "{0}~{1}",
+            ko_KP => "{0}~{1}",
+            kok => "{0}–{1}",
+            ks => "{0}–{1}",
+            ksb => "{0}–{1}",
+            ksf => "{0}–{1}",
+            ksh => "{0}–{1}",
+            ku => "{0}–{1}",
+            kw => "{0}–{1}",
+            ky => "{0}–{1}",
+            lag => "{0}–{1}",
+            lb => "{0}–{1}",
+            lg => "{0}–{1}",
+            lkt => "{0}–{1}",
+            ln => "{0}–{1}",
+            ln_AO => "{0}–{1}",
+            ln_CF => "{0}–{1}",
+            ln_CG => "{0}–{1}",
+            lo => "{0}–{1}",
+            lrc => "{0}–{1}",
+            lrc_IQ => "{0}–{1}",
+            lt => "{0}–{1}",
+            lu => "{0}–{1}",
+            luo => "{0}–{1}",
+            luy => "{0}–{1}",
+            lv => "{0}–{1}",
+            mas => "{0}–{1}",
+            mas_TZ => "{0}–{1}",
+            mer => "{0}–{1}",
+            mfe => "{0}–{1}",
+            mg => "{0}–{1}",
+            mgh => "{0}–{1}",
+            mgo => "{0}–{1}",
+            mi => "{0}–{1}",
+            mk => "{0}\u{2009}–\u{2009}{1}",
+            ml => "{0}-{1}",
+            mn => "{0}–{1}",
+            mr => "{0}–{1}",
+            ms => "{0}–{1}",
+            ms_BN => "{0}–{1}",
+            ms_SG => "{0}–{1}",
+            mt => "{0}–{1}",
+            mua => "{0}–{1}",
+            my => "{0} - {1}",
+            mzn => "{0}–{1}",
+            naq => "{0}–{1}",
+            nb => "{0}–{1}",
+            nb_SJ => "{0}–{1}",
+            nd => "{0}–{1}",
+            nds => "{0}–{1}",
+            nds_NL => "{0}–{1}",
+            ne => "{0}–{1}",
+            ne_IN => "{0}–{1}",
+            nl => "{0}-{1}",
+            nl_AW => "{0}-{1}",
+            nl_BE => "{0}-{1}",
+            nl_BQ => "{0}-{1}",
+            nl_CW => "{0}-{1}",
+            nl_SR => "{0}-{1}",
+            nl_SX => "{0}-{1}",
+            nmg => "{0}–{1}",
+            nn => "{0}–{1}",
+            nnh => "{0}–{1}",
+            nus => "{0}–{1}",
+            nyn => "{0}–{1}",
+            om => "{0}–{1}",
+            om_KE => "{0}–{1}",
+            or => "{0}–{1}",
+            os => "{0}–{1}",
+            os_RU => "{0}–{1}",
+            pa => "{0}–{1}",
+            pa_Arab => "{0}–{1}",
+            pa_Guru => "{0}–{1}",
+            pl => "{0}–{1}",
+            prg => "{0}–{1}",
+            ps => "{0}–{1}",
+            ps_PK => "{0}–{1}",
+            pt => "{0}–{1}",
+            pt_AO => "{0} - {1}",
+            pt_CH => "{0} - {1}",
+            pt_CV => "{0} - {1}",
+            pt_GQ => "{0} - {1}",
+            pt_GW => "{0} - {1}",
+            pt_LU => "{0} - {1}",
+            pt_MO => "{0} - {1}",
+            pt_MZ => "{0} - {1}",
+            pt_PT => "{0} - {1}",
+            pt_ST => "{0} - {1}",
+            pt_TL => "{0} - {1}",
+            qu => "{0}–{1}",
+            qu_BO => "{0}–{1}",
+            qu_EC => "{0}–{1}",
+            rm => "{0}–{1}",
+            rn => "{0}–{1}",
+            ro => "{0} - {1}",
+            ro_MD => "{0} - {1}",
+            rof => "{0}–{1}",
+            root => "{0}–{1}",
+            ru => "{0}–{1}",
+            ru_BY => "{0}–{1}",
+            ru_KG => "{0}–{1}",
+            ru_KZ => "{0}–{1}",
+            ru_MD => "{0}–{1}",
+            ru_UA => "{0}–{1}",
+            rw => "{0}–{1}",
+            rwk => "{0}–{1}",
+            sah => "{0}–{1}",
+            saq => "{0}–{1}",
+            sbp => "{0}–{1}",
+            sd => "{0}–{1}",
+            se => "{0}–{1}",
+            se_FI => "{0}–{1}",
+            se_SE => "{0}–{1}",
+            seh => "{0}–{1}",
+            ses => "{0}–{1}",
+            sg => "{0}–{1}",
+            shi => "{0}–{1}",
+            shi_Latn => "{0}–{1}",
+            shi_Tfng => "{0}–{1}",
+            si => "{0}–{1}",
+            sk => "{0} – {1}",
+            sl => "{0}–{1}",
+            smn => "{0}–{1}",
+            sn => "{0}–{1}",
+            so => "{0}–{1}",
+            so_DJ => "{0}–{1}",
+            so_ET => "{0}–{1}",
+            so_KE => "{0}–{1}",
+            sq => "{0}-{1}",
+            sq_MK => "{0}-{1}",
+            sq_XK => "{0}-{1}",
+            sr => "{0}–{1}",
+            sr_Cyrl => "{0}–{1}",
+            sr_Cyrl_BA => "{0}–{1}",
+            sr_Cyrl_ME => "{0}–{1}",
+            sr_Cyrl_XK => "{0}–{1}",
+            sr_Latn => "{0}–{1}",
+            sr_Latn_BA => "{0}–{1}",
+            sr_Latn_ME => "{0}–{1}",
+            sr_Latn_XK => "{0}–{1}",
+            sv => "{0}‒{1}",
+            sv_AX => "{0}‒{1}",
+            sv_FI => "{0}‒{1}",
+            sw => "{0}–{1}",
+            sw_CD => "{0}–{1}",
+            sw_KE => "{0}–{1}",
+            sw_UG => "{0}–{1}",
+            ta => "{0}–{1}",
+            ta_LK => "{0}–{1}",
+            ta_MY => "{0}–{1}",
+            ta_SG => "{0}–{1}",
+            te => "{0}–{1}",
+            teo => "{0}–{1}",
+            teo_KE => "{0}–{1}",
+            tg => "{0}–{1}",
+            th => "{0}-{1}",
+            ti => "{0}–{1}",
+            ti_ER => "{0}–{1}",
+            tk => "{0}–{1}",
+            to => "{0}—{1}",
+            tr => "{0}–{1}",
+            tr_CY => "{0}–{1}",
+            tt => "{0}-{1}",
+            twq => "{0}–{1}",
+            tzm => "{0}–{1}",
+            ug => "{0}–{1}",
+            uk => "{0}–{1}",
+            ur => "{0}–{1}",
+            ur_IN => "{0}–{1}",
+            uz => "{0}–{1}",
+            uz_Arab => "{0}–{1}",
+            uz_Cyrl => "{0}–{1}",
+            uz_Latn => "{0}–{1}",
+            vai => "{0}–{1}",
+            vai_Latn => "{0}–{1}",
+            vai_Vaii => "{0}–{1}",
+            vi => "{0}-{1}",
+            vo => "{0}–{1}",
+            vun => "{0}–{1}",
+            wae => "{0}–{1}",
+            wo => "{0}–{1}",
+            xh => "{0}–{1}",
+            xog => "{0}–{1}",
+            yav => "{0}–{1}",
+            yi => "{0}–{1}",
+            yo => "{0}–{1}",
+            yo_BJ => "{0}–{1}",
+            yue => "{0}-{1}",
+            yue_Hans => "{0}-{1}",
+            yue_Hant => "{0}-{1}",
+            zgh => "{0}–{1}",
+            zh => "{0}-{1}",
+            zh_Hans => "{0}-{1}",
+            zh_Hans_HK => "{0}-{1}",
+            zh_Hans_MO => "{0}-{1}",
+            zh_Hans_SG => "{0}-{1}",
+            zh_Hant => "{0}-{1}",
+            zh_Hant_HK => "{0}-{1}",
+            zh_Hant_MO => "{0}-{1}",
+            zu => "{0}–{1}",
+        }
+    }
     #[doc = r" Returns the locale's separator representation, if any."]
     pub fn separator(&self) -> &'static str {
         use self::Locale::*;
@@ -5557,6 +12883,12 @@ impl Format for Locale {
         SeparatorStr::new(self.separator()).unwrap()
     }
 }
+impl ParseFormat for Locale {
+    #[inline(always)]
+    fn locale(&self) -> Option<Locale> {
+        Some(*self)
+    }
+}
 impl FromStr for Locale {
     type Err = Error;
     #[doc = r" Same as [`from_name`]."]
@@ -0,0 +1,141 @@
+use crate::locale::Locale;
+
+/// Type for specifying the unit a [`Measure`] is written in.
+///
+/// [`Measure`]: struct.Measure.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum MeasureUnit {
+    /// Kilometers (e.g. 12 km)
+    Kilometer,
+    /// Meters (e.g. 12 m)
+    Meter,
+    /// Centimeters (e.g. 12 cm)
+    Centimeter,
+    /// Millimeters (e.g. 12 mm)
+    Millimeter,
+    /// Miles (e.g. 12 mi)
+    Mile,
+    /// Yards (e.g. 12 yd)
+    Yard,
+    /// Feet (e.g. 12 ft)
+    Foot,
+    /// Inches (e.g. 12 in)
+    Inch,
+    /// Kilograms (e.g. 12 kg)
+    Kilogram,
+    /// Grams (e.g. 12 g)
+    Gram,
+    /// Pounds (e.g. 12 lb)
+    Pound,
+    /// Ounces (e.g. 12 oz)
+    Ounce,
+    /// Kilometers per hour (e.g. 12 km/h)
+    KilometerPerHour,
+    /// Meters per second (e.g. 12 m/s)
+    MeterPerSecond,
+    /// Miles per hour (e.g. 12 mph)
+    MilePerHour,
+    /// Degrees Celsius (e.g. 12°C)
+    Celsius,
+    /// Degrees Fahrenheit (e.g. 12°F)
+    Fahrenheit,
+    /// Percent, of a value that is already a percentage (e.g. 12% for 12)
+    Percent,
+}
+
+/// Type for specifying how much room the unit of a [`Measure`] takes up.
+///
+/// [`Measure`]: struct.Measure.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum UnitWidth {
+    /// The unit's full name (e.g. 12 kilometers)
+    Long,
+    /// The unit's abbreviation (e.g. 12 km)
+    Short,
+    /// The unit's shortest abbreviation, possibly without a space (e.g. 12km)
+    Narrow,
+}
+
+/// Type for representing a quantity written with a unit in the language of a [`Locale`] (e.g.
+/// `12.5 km`, `3,5 kg`, or `72 Kilometer pro Stunde`). Implements [`ToFormattedString`] for
+/// `f32`, `f64`, and all the integer types in the standard library (but not [`ToFormattedStr`],
+/// as a float in a unit pattern may not fit into a [`Buffer`]).
+///
+/// The value is written with the minus sign, grouping, and decimal of the [`Format`] it is
+/// formatted with, then put into the CLDR unit pattern of the measure's [`Locale`] for the unit,
+/// width, and plural category of the value as written. Since
+/// floats are always written with a fraction (e.g. `1.0`), a float may take a different plural
+/// form than the equal integer.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Locale, Measure, MeasureUnit, ToFormattedString, UnitWidth};
+///
+/// fn main() {
+///     let distance = Measure::new(12.5, MeasureUnit::Kilometer, UnitWidth::Short, Locale::en);
+///     assert_eq!("12.5 km", distance.to_formatted_string(&Locale::en));
+///
+///     let mass = Measure::new(3.5, MeasureUnit::Kilogram, UnitWidth::Short, Locale::de);
+///     assert_eq!("3,5 kg", mass.to_formatted_string(&Locale::de));
+///
+///     let distance = Measure::new(1, MeasureUnit::Mile, UnitWidth::Long, Locale::en);
+///     assert_eq!("1 mile", distance.to_formatted_string(&Locale::en));
+///
+///     let distance = Measure::new(1500, MeasureUnit::Mile, UnitWidth::Long, Locale::en);
+///     assert_eq!("1,500 miles", distance.to_formatted_string(&Locale::en));
+/// }
+/// ```
+///
+/// [`Buffer`]: struct.Buffer.html
+/// [`Format`]: trait.Format.html
+/// [`Locale`]: enum.Locale.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Measure<N> {
+    value: N,
+    unit: MeasureUnit,
+    width: UnitWidth,
+    locale: Locale,
+}
+
+impl<N> Measure<N> {
+    /// Constructs a [`Measure`] for the given value, unit, and width, written with the unit
+    /// patterns of the given [`Locale`].
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Measure`]: struct.Measure.html
+    pub fn new(value: N, unit: MeasureUnit, width: UnitWidth, locale: Locale) -> Measure<N> {
+        Measure {
+            value,
+            unit,
+            width,
+            locale,
+        }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the unit.
+    pub fn unit(&self) -> MeasureUnit {
+        self.unit
+    }
+
+    /// Returns the width of the unit.
+    pub fn width(&self) -> UnitWidth {
+        self.width
+    }
+
+    /// Returns the [`Locale`] whose unit patterns the value is written with.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::Error;
+use crate::strings::UnitString;
+
+// SI prefixes from quecto (10^-30) to quetta (10^30), indexed by (exponent / 3) + 10
+pub(crate) const SI_PREFIXES: [&str; 21] = [
+    "q", "r", "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    "R", "Q",
+];
+
+/// Type for representing a physical quantity written with an SI metric prefix (e.g. `4.7 kΩ`,
+/// `12.3 µs`, or `2.1 GW`). Implements [`ToFormattedStr`] for `f32`, `f64`, and all the integer
+/// types in the standard library.
+///
+/// The value is scaled by the power of 1000 that brings it into the range `1..1000` (as far as
+/// the prefixes from quecto, `q`, to quetta, `Q`, allow) and written with the minus sign,
+/// grouping, and decimal of the [`Format`] it is formatted with, followed by a space, the prefix,
+/// and the unit. The prefixes themselves are the same in every locale. Scaling is exact: floats
+/// are written with the same shortest digits they would be written with unscaled.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Error, Locale, Metric};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&Metric::new(4700.0, "Ω")?, &Locale::de);
+///     assert_eq!("4,7 kΩ", buf.as_str());
+///
+///     buf.write_formatted(&Metric::new(12.3e-6, "s")?, &Locale::en);
+///     assert_eq!("12.3 µs", buf.as_str());
+///
+///     buf.write_formatted(&Metric::new(2_100_000_000u64, "W")?, &Locale::en);
+///     assert_eq!("2.1 GW", buf.as_str());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Metric<N> {
+    value: N,
+    unit: UnitString,
+}
+
+impl<N> Metric<N> {
+    /// Constructs a [`Metric`] for the given value and unit symbol (which may be empty).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the unit is longer than 16 bytes.
+    ///
+    /// [`Metric`]: struct.Metric.html
+    pub fn new<S>(value: N, unit: S) -> Result<Metric<N>, Error>
+    where
+        S: AsRef<str>,
+    {
+        Ok(Metric {
+            value,
+            unit: UnitString::new(unit)?,
+        })
+    }
+
+    /// Returns the unscaled value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the unit symbol written after the prefix.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+}
@@ -0,0 +1,752 @@
+use core::fmt;
+use core::str::FromStr;
+
+use arrayvec::ArrayString;
+
+use crate::buffer::Buffer;
+use crate::constants::MAX_BUF_LEN;
+use crate::error::Error;
+use crate::format::{Format, Plain, WriteFormat};
+use crate::strings::{MAX_DEC_LEN, MAX_MIN_LEN, MAX_PLUS_LEN, MAX_SEP_LEN};
+
+pub(crate) const MAX_AFX_LEN: usize = 32;
+
+type AfxString = ArrayString<MAX_AFX_LEN>;
+
+/// Type for representing a decimal format pattern, such as `#,##0.00;(#,##0.00)` or
+/// `#,##,##0.###`, in the syntax used by CLDR and ICU.
+///
+/// A pattern sets the sizes of the digit groups, the minimum number of integer digits and the
+/// minimum and maximum numbers of fraction digits, text written before and after the number
+/// (with a separate prefix and suffix for negative numbers after a `;`), and whether the number
+/// is written as a percentage (`%`) or in per mille (`‰`). Wrap integers, floats, and
+/// [`Decimal`]s in a [`Patterned`] to have them written with it.
+///
+/// In the number part of a pattern, `0` is a digit that is always written, `#` is a digit that
+/// is only written if needed, `,` separates groups of integer digits, and `.` is the decimal
+/// point. Numbers are rounded half to even to the maximum number of fraction digits. In the
+/// prefixes and suffixes, `-` and `+` stand for the format's minus and plus signs, and text in
+/// single quotes (with `''` for a single quote) is written as is. Significant digits (`@`),
+/// scientific notation (`E`), padding (`*`), currency signs (`¤`), and rounding increments
+/// (e.g. `#,##0.05`) are not supported.
+///
+/// A pattern is displayed in a normalized form, so that parsing what is displayed gives back
+/// the same pattern.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, NumberPattern};
+///
+/// fn main() -> Result<(), Error> {
+///     let pattern: NumberPattern = "#,##0.00;(#,##0.00)".parse()?;
+///     assert_eq!(1, pattern.minimum_integer_digits());
+///     assert_eq!(2, pattern.maximum_fraction_digits());
+///     assert_eq!(3, pattern.grouping_size());
+///     assert_eq!(Some("("), pattern.negative_prefix());
+///     assert_eq!("#,##0.00;(#,##0.00)", pattern.to_string());
+///
+///     let pattern = NumberPattern::new("#,###,##0.###")?;
+///     assert_eq!("#,##0.###", pattern.to_string());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Decimal`]: struct.Decimal.html
+/// [`Patterned`]: struct.Patterned.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct NumberPattern {
+    dec_shown: bool,
+    grp: u8,
+    max_frac: u16,
+    min_frac: u16,
+    min_int: u16,
+    neg: Option<(AfxString, AfxString)>,
+    pre: AfxString,
+    scale: u8,
+    sec_grp: u8,
+    suf: AfxString,
+}
+
+impl NumberPattern {
+    /// Parses a [`NumberPattern`] from a pattern string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not valid or uses a feature that is not supported, or
+    /// if one of its prefixes or suffixes is longer than 32 bytes.
+    ///
+    /// [`NumberPattern`]: struct.NumberPattern.html
+    pub fn new<S>(s: S) -> Result<NumberPattern, Error>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        let mut parser = Parser { s, pos: 0 };
+
+        let (pre, number, suf) = parser.subpattern()?;
+        let number = number.ok_or_else(|| Error::parse_pattern(s))?;
+        let neg = if parser.eat(';') && !parser.is_done() {
+            let (neg_pre, _, neg_suf) = parser.subpattern()?;
+            Some((neg_pre, neg_suf))
+        } else {
+            None
+        };
+        if !parser.is_done() {
+            return Err(Error::parse_pattern(s));
+        }
+
+        // Numbers are scaled by an unquoted percent or per mille sign anywhere in the prefixes
+        // and suffixes
+        let mut scale = 0;
+        let affixes = [
+            Some(&pre),
+            Some(&suf),
+            neg.as_ref().map(|n| &n.0),
+            neg.as_ref().map(|n| &n.1),
+        ];
+        for affix in affixes.iter().flatten() {
+            for_each_char(affix, |c, quoted| {
+                let symbol_scale = match c {
+                    '%' if !quoted => 2,
+                    '‰' if !quoted => 3,
+                    _ => return Ok(()),
+                };
+                if scale != 0 && scale != symbol_scale {
+                    return Err(Error::parse_pattern(s));
+                }
+                scale = symbol_scale;
+                Ok(())
+            })?;
+        }
+
+        // A negative subpattern that says the same as the default one is left out
+        let neg = neg.filter(|(neg_pre, neg_suf)| {
+            !(neg_pre.len() == pre.len() + 1
+                && neg_pre.starts_with('-')
+                && neg_pre[1..] == pre[..]
+                && neg_suf == &suf)
+        });
+
+        Ok(NumberPattern {
+            dec_shown: number.dec_shown,
+            grp: number.grp,
+            max_frac: number.max_frac,
+            min_frac: number.min_frac,
+            min_int: number.min_int,
+            neg,
+            pre,
+            scale,
+            sec_grp: number.sec_grp,
+            suf,
+        })
+    }
+
+    /// Returns whether the decimal is written even if there are no fraction digits (as in
+    /// `#,##0.`).
+    pub fn decimal_always_shown(&self) -> bool {
+        self.dec_shown
+    }
+
+    /// Returns the number of integer digits in the group next to the decimal, or `0` if integer
+    /// digits are not grouped.
+    pub fn grouping_size(&self) -> u8 {
+        self.grp
+    }
+
+    /// Returns the maximum number of fraction digits. Numbers with more are rounded half to even.
+    pub fn maximum_fraction_digits(&self) -> u16 {
+        self.max_frac
+    }
+
+    /// Returns the minimum number of fraction digits, which are padded with zeros.
+    pub fn minimum_fraction_digits(&self) -> u16 {
+        self.min_frac
+    }
+
+    /// Returns the minimum number of integer digits, which are padded with zeros.
+    pub fn minimum_integer_digits(&self) -> u16 {
+        self.min_int
+    }
+
+    /// Returns what numbers are multiplied by before they are written: 100 for a percentage,
+    /// 1000 for per mille, and 1 otherwise.
+    pub fn multiplier(&self) -> u16 {
+        10u16.pow(self.scale as u32)
+    }
+
+    /// Returns the prefix of negative numbers as written in the pattern, if the pattern has a
+    /// negative subpattern. Otherwise, negative numbers are written with a minus sign before the
+    /// positive prefix.
+    pub fn negative_prefix(&self) -> Option<&str> {
+        self.neg.as_ref().map(|(pre, _)| pre.as_str())
+    }
+
+    /// Returns the suffix of negative numbers as written in the pattern, if the pattern has a
+    /// negative subpattern. Otherwise, negative numbers are written with the positive suffix.
+    pub fn negative_suffix(&self) -> Option<&str> {
+        self.neg.as_ref().map(|(_, suf)| suf.as_str())
+    }
+
+    /// Returns the prefix of positive numbers as written in the pattern.
+    pub fn positive_prefix(&self) -> &str {
+        &self.pre
+    }
+
+    /// Returns the suffix of positive numbers as written in the pattern.
+    pub fn positive_suffix(&self) -> &str {
+        &self.suf
+    }
+
+    /// Returns the number of integer digits in each group after the first one, which is the
+    /// same as the [`grouping_size`] unless the pattern has two different group sizes (e.g. 2
+    /// for `#,##,##0`).
+    ///
+    /// [`grouping_size`]: struct.NumberPattern.html#method.grouping_size
+    pub fn secondary_grouping_size(&self) -> u8 {
+        self.sec_grp
+    }
+
+    /// Returns the length of the longest number in decimal digits this pattern can write with
+    /// any format, if it has at most `int_digits` integer digits before it is multiplied.
+    pub(crate) fn max_len(&self, int_digits: usize) -> usize {
+        let affix_len = |affix: &str| {
+            let mut len = 0;
+            let _ = for_each_char(affix, |c, quoted| {
+                len += match c {
+                    '-' if !quoted => MAX_MIN_LEN,
+                    '+' if !quoted => MAX_PLUS_LEN,
+                    c => c.len_utf8(),
+                };
+                Ok(())
+            });
+            len
+        };
+        let pos_len = affix_len(&self.pre) + affix_len(&self.suf);
+        let neg_len = match self.neg {
+            Some((ref pre, ref suf)) => affix_len(pre) + affix_len(suf),
+            None => MAX_MIN_LEN + pos_len,
+        };
+
+        let int_digits = (int_digits + self.scale as usize).max(self.min_int as usize);
+        let separators = match (self.grp as usize, self.sec_grp as usize) {
+            (0, _) => 0,
+            (grp, _) if int_digits <= grp => 0,
+            (grp, sec_grp) => 1 + (int_digits - grp - 1) / sec_grp,
+        };
+        pos_len.max(neg_len)
+            + int_digits
+            + separators * MAX_SEP_LEN
+            + MAX_DEC_LEN
+            + self.max_frac as usize
+    }
+}
+
+impl fmt::Display for NumberPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pre)?;
+        self.fmt_number(f)?;
+        f.write_str(&self.suf)?;
+        if let Some((ref pre, ref suf)) = self.neg {
+            f.write_str(";")?;
+            f.write_str(pre)?;
+            self.fmt_number(f)?;
+            f.write_str(suf)?;
+        }
+        Ok(())
+    }
+}
+
+impl NumberPattern {
+    fn fmt_number(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use core::fmt::Write;
+
+        let grp = self.grp as usize;
+        let sec_grp = self.sec_grp as usize;
+        let min_int = self.min_int as usize;
+
+        // Write enough digits to show both group sizes, or just a `#` if there are none
+        let int_digits = match (grp, sec_grp) {
+            (0, _) => min_int.max(1),
+            (grp, sec_grp) if sec_grp == grp => min_int.max(grp + 1),
+            (grp, sec_grp) => min_int.max(grp + sec_grp + 1),
+        };
+        for i in (0..int_digits).rev() {
+            f.write_char(if i < min_int { '0' } else { '#' })?;
+            if i != 0 && is_group_boundary(i, grp, sec_grp) {
+                f.write_char(',')?;
+            }
+        }
+
+        if self.max_frac != 0 || self.dec_shown {
+            f.write_char('.')?;
+        }
+        for i in 0..self.max_frac {
+            f.write_char(if i < self.min_frac { '0' } else { '#' })?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NumberPattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NumberPattern::new(s)
+    }
+}
+
+/// The settings in the number part of a subpattern.
+struct Number {
+    dec_shown: bool,
+    grp: u8,
+    max_frac: u16,
+    min_frac: u16,
+    min_int: u16,
+    sec_grp: u8,
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos == self.s.len()
+    }
+
+    fn error(&self) -> Error {
+        Error::parse_pattern(self.s)
+    }
+
+    /// Parses a prefix, an optional number part, and a suffix.
+    fn subpattern(&mut self) -> Result<(AfxString, Option<Number>, AfxString), Error> {
+        let pre = self.affix()?;
+        let number = match self.peek() {
+            Some(c) if is_number_char(c) => Some(self.number()?),
+            _ => None,
+        };
+        if self.peek() == Some('E') {
+            return Err(self.error());
+        }
+        let suf = self.affix()?;
+        match self.peek() {
+            Some(c) if is_number_char(c) => Err(self.error()),
+            _ => Ok((pre, number, suf)),
+        }
+    }
+
+    /// Parses text up to the number part, the end of the subpattern, or the end of the pattern.
+    fn affix(&mut self) -> Result<AfxString, Error> {
+        let start = self.pos;
+        let mut quoted = false;
+        while let Some(c) = self.peek() {
+            match c {
+                '\'' => quoted = !quoted,
+                _ if quoted => (),
+                ';' => break,
+                '*' | '¤' => return Err(self.error()),
+                c if is_number_char(c) => break,
+                _ => (),
+            }
+            self.pos += c.len_utf8();
+        }
+        if quoted {
+            return Err(self.error());
+        }
+        let affix = &self.s[start..self.pos];
+        AfxString::from(affix).map_err(|_| Error::capacity(affix.len(), MAX_AFX_LEN))
+    }
+
+    /// Parses the number part (e.g. `#,##0.00`).
+    fn number(&mut self) -> Result<Number, Error> {
+        // Integer digits, with the sizes of the last two groups
+        let mut optional = 0usize;
+        let mut required = 0usize;
+        let mut groups = [None, None];
+        let mut group_len = 0usize;
+        let mut has_separator = false;
+        loop {
+            match self.peek() {
+                Some('#') if required == 0 => optional += 1,
+                Some('0') => required += 1,
+                Some(',') if group_len != 0 || !has_separator => {
+                    if optional + required == 0 {
+                        return Err(self.error());
+                    }
+                    if has_separator {
+                        groups = [groups[1], Some(group_len)];
+                    }
+                    has_separator = true;
+                    group_len = 0;
+                    self.pos += 1;
+                    continue;
+                }
+                Some(c) if is_number_char(c) && c != '.' => return Err(self.error()),
+                _ => break,
+            }
+            group_len += 1;
+            self.pos += 1;
+        }
+        if has_separator {
+            groups = [groups[1], Some(group_len)];
+        }
+        let (grp, sec_grp) = match groups {
+            [_, None] => (0, 0),
+            [None, Some(grp)] => (grp, grp),
+            [Some(sec_grp), Some(grp)] => (grp, sec_grp),
+        };
+        if grp == 0 && has_separator || grp > 255 || sec_grp > 255 {
+            return Err(self.error());
+        }
+
+        // Fraction digits
+        let mut dec_shown = false;
+        let mut min_frac = 0usize;
+        let mut max_frac = 0usize;
+        if self.eat('.') {
+            loop {
+                match self.peek() {
+                    Some('0') if min_frac == max_frac => min_frac += 1,
+                    Some('#') => (),
+                    Some(c) if is_number_char(c) => return Err(self.error()),
+                    _ => break,
+                }
+                max_frac += 1;
+                self.pos += 1;
+            }
+            dec_shown = max_frac == 0;
+        }
+
+        // As in ICU, a pattern with no integer digits (e.g. `.##`) writes no integer digits
+        // for numbers less than one, but always writes a fraction digit, and a pattern with no
+        // required digits at all (e.g. `#.##`) writes one integer digit
+        let mut min_int = required;
+        if optional + required == 0 {
+            min_frac = min_frac.max(1);
+            max_frac = max_frac.max(1);
+        } else if required == 0 && min_frac == 0 {
+            min_int = 1;
+        }
+        if min_int > u16::MAX as usize || max_frac > u16::MAX as usize {
+            return Err(self.error());
+        }
+
+        Ok(Number {
+            dec_shown,
+            grp: grp as u8,
+            max_frac: max_frac as u16,
+            min_frac: min_frac as u16,
+            min_int: min_int as u16,
+            sec_grp: sec_grp as u8,
+        })
+    }
+}
+
+fn is_number_char(c: char) -> bool {
+    matches!(c, '#' | '0'..='9' | ',' | '.' | '@')
+}
+
+/// Returns whether there is a separator before the integer digit with `i` digits after it.
+fn is_group_boundary(i: usize, grp: usize, sec_grp: usize) -> bool {
+    grp != 0 && (i == grp || i > grp && matches!((i - grp) % sec_grp, 0))
+}
+
+/// Calls `f` with each character an affix stands for and whether it is quoted, leaving out the
+/// quotes (except for doubled ones, which stand for a single quote).
+fn for_each_char<G>(affix: &str, mut f: G) -> Result<(), Error>
+where
+    G: FnMut(char, bool) -> Result<(), Error>,
+{
+    let mut quoted = false;
+    let mut chars = affix.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                let _ = chars.next();
+                f('\'', quoted)?;
+            }
+            '\'' => quoted = !quoted,
+            c => f(c, quoted)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes an affix into `w`, replacing minus and plus signs with the format's.
+fn write_affix<F, W>(affix: &str, format: &F, w: &mut W)
+where
+    F: Format,
+    W: FnMut(&str),
+{
+    let mut quoted = false;
+    let mut start = 0;
+    let mut chars = affix.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let symbol = match c {
+            '\'' => {
+                w(&affix[start..i]);
+                start = i + 1;
+                match chars.peek() {
+                    Some(&(_, '\'')) => {
+                        let _ = chars.next();
+                        w("'");
+                        start = i + 2;
+                    }
+                    _ => quoted = !quoted,
+                }
+                continue;
+            }
+            '-' if !quoted => format.minus_sign().into_str(),
+            '+' if !quoted => format.plus_sign().into_str(),
+            _ => continue,
+        };
+        w(&affix[start..i]);
+        w(symbol);
+        start = i + 1;
+    }
+    w(&affix[start..]);
+}
+
+/// Writes the prefix, then calls `body` to write the number, then writes the suffix.
+fn write_affixed<F, W, G>(is_negative: bool, pattern: &NumberPattern, format: &F, mut w: W, body: G)
+where
+    F: Format,
+    W: FnMut(&str),
+    G: FnOnce(&mut W),
+{
+    let (pre, suf) = match pattern.neg {
+        Some((ref pre, ref suf)) if is_negative => (pre.as_str(), suf.as_str()),
+        _ => (pattern.pre.as_str(), pattern.suf.as_str()),
+    };
+    if is_negative && pattern.neg.is_none() {
+        w(format.minus_sign().into_str());
+    }
+    write_affix(pre, format, &mut w);
+    body(&mut w);
+    write_affix(suf, format, &mut w);
+}
+
+/// Writes a decimal number, given as its ASCII integer and fraction digits, into `w` piece by
+/// piece, as the pattern says, using the format's symbols and fraction grouping.
+pub(crate) fn write_pattern<F, W>(
+    is_negative: bool,
+    int_digits: &str,
+    frac_digits: &str,
+    pattern: &NumberPattern,
+    format: &F,
+    w: W,
+) where
+    F: WriteFormat,
+    W: FnMut(&str),
+{
+    let digits = Digits::new(int_digits, frac_digits, pattern);
+    write_affixed(is_negative, pattern, format, w, |w| {
+        let grp = pattern.grp as usize;
+        let sec_grp = pattern.sec_grp as usize;
+        let separator = format.separator().into_str();
+
+        // Integer digits, padded with zeros to the minimum
+        let int_len = digits.int_len(pattern.min_int as usize);
+        let frac_len = digits.frac_len(pattern.min_frac as usize);
+        let int_len = if int_len == 0 && frac_len == 0 {
+            1
+        } else {
+            int_len
+        };
+        for i in (0..int_len).rev() {
+            w(digits.get(digits.point as isize - 1 - i as isize));
+            if i != 0 && is_group_boundary(i, grp, sec_grp) {
+                w(separator);
+            }
+        }
+
+        if frac_len == 0 && !pattern.dec_shown {
+            return;
+        }
+        w(format.decimal().into_str());
+
+        let (frac_step, frac_separator) = format.fraction_grouping();
+        for i in 0..frac_len {
+            if frac_step != 0 && i != 0 && i % frac_step == 0 {
+                w(frac_separator);
+            }
+            w(digits.get((digits.point + i) as isize));
+        }
+    });
+}
+
+/// Writes a decimal number into the buffer as the pattern says (see [`write_pattern`]), and
+/// returns the number of bytes written. Fraction digits whose separators would not fit into the
+/// buffer are written ungrouped.
+pub(crate) fn write_pattern_to_buffer<F>(
+    is_negative: bool,
+    int_digits: &str,
+    frac_digits: &str,
+    pattern: &NumberPattern,
+    buf: &mut Buffer,
+    format: &F,
+) -> usize
+where
+    F: WriteFormat,
+{
+    let mut len = 0;
+    write_pattern(is_negative, int_digits, frac_digits, pattern, format, |s| {
+        len += s.len()
+    });
+    if len > MAX_BUF_LEN {
+        let format = Plain(format);
+        return write_to_buffer(buf, |w| {
+            write_pattern(is_negative, int_digits, frac_digits, pattern, &format, w)
+        });
+    }
+    write_to_buffer(buf, |w| {
+        write_pattern(is_negative, int_digits, frac_digits, pattern, format, w)
+    })
+}
+
+/// Writes a symbol for a number that is not finite (i.e. infinity or NaN) into `w`, with the
+/// prefix and suffix of the pattern.
+pub(crate) fn write_pattern_nonfinite<F, W>(
+    is_negative: bool,
+    symbol: &str,
+    pattern: &NumberPattern,
+    format: &F,
+    w: W,
+) where
+    F: Format,
+    W: FnMut(&str),
+{
+    write_affixed(is_negative, pattern, format, w, |w| w(symbol));
+}
+
+/// Writes into the buffer with `write`, which is called twice: once to measure, and once to
+/// write. Returns the number of bytes written.
+pub(crate) fn write_to_buffer<G>(buf: &mut Buffer, write: G) -> usize
+where
+    G: Fn(&mut dyn FnMut(&str)),
+{
+    let mut len = 0;
+    write(&mut |s| len += s.len());
+
+    buf.pos = MAX_BUF_LEN - len;
+    buf.end = MAX_BUF_LEN;
+    let mut index = buf.pos;
+    write(&mut |s| {
+        buf.inner[index..index + s.len()].copy_from_slice(s.as_bytes());
+        index += s.len();
+    });
+    len
+}
+
+/// The digits of a number after it is multiplied and rounded as a pattern says, without
+/// copying them. Digits are numbered from the first integer digit given, with the decimal
+/// point before digit `point`.
+struct Digits<'a> {
+    int_digits: &'a [u8],
+    frac_digits: &'a [u8],
+    point: usize,
+    // the digits from `end` on are zero, as are those before `start`
+    start: isize,
+    end: usize,
+    // rounding up adds one to the digit at `bump` and zeros the digits after it
+    bump: Option<isize>,
+}
+
+impl<'a> Digits<'a> {
+    fn new(int_digits: &'a str, frac_digits: &'a str, pattern: &NumberPattern) -> Digits<'a> {
+        let int_digits = int_digits.as_bytes();
+        let frac_digits = frac_digits.as_bytes();
+        let mut digits = Digits {
+            int_digits,
+            frac_digits,
+            point: int_digits.len() + pattern.scale as usize,
+            start: 0,
+            end: int_digits.len() + frac_digits.len(),
+            bump: None,
+        };
+
+        // Round half to even to the maximum number of fraction digits
+        let cut = digits.point + pattern.max_frac as usize;
+        if cut < digits.end {
+            let first = digits.given(cut);
+            let rest_nonzero = (cut + 1..digits.end).any(|i| digits.given(i) != b'0');
+            let odd = cut > 0 && (digits.given(cut - 1) - b'0') % 2 == 1;
+            digits.end = cut;
+            if first > b'5' || first == b'5' && (rest_nonzero || odd) {
+                let mut i = cut as isize - 1;
+                while i >= 0 && digits.given(i as usize) == b'9' {
+                    i -= 1;
+                }
+                digits.bump = Some(i);
+            }
+        }
+
+        // Leave out leading zeros
+        let mut start = digits.bump.map_or(0, |bump| bump.min(0));
+        while start < digits.point as isize && digits.get(start) == "0" {
+            start += 1;
+        }
+        digits.start = start;
+        digits
+    }
+
+    /// Returns a digit as given, before rounding.
+    fn given(&self, i: usize) -> u8 {
+        let int_len = self.int_digits.len();
+        if i < int_len {
+            self.int_digits[i]
+        } else if i - int_len < self.frac_digits.len() {
+            self.frac_digits[i - int_len]
+        } else {
+            b'0'
+        }
+    }
+
+    /// Returns a digit after rounding.
+    fn get(&self, i: isize) -> &'static str {
+        const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let digit = match self.bump {
+            Some(bump) if i == bump => {
+                if i < 0 {
+                    1
+                } else {
+                    self.given(i as usize) - b'0' + 1
+                }
+            }
+            Some(bump) if i > bump => 0,
+            _ if i < 0 || i as usize >= self.end => 0,
+            _ => self.given(i as usize) - b'0',
+        };
+        DIGITS[digit as usize]
+    }
+
+    /// Returns the number of integer digits to write.
+    fn int_len(&self, min_int: usize) -> usize {
+        ((self.point as isize - self.start) as usize).max(min_int)
+    }
+
+    /// Returns the number of fraction digits to write, leaving out trailing zeros.
+    fn frac_len(&self, min_frac: usize) -> usize {
+        let mut end = self.end.max(self.point);
+        while end > self.point + min_frac && self.get(end as isize - 1) == "0" {
+            end -= 1;
+        }
+        (end - self.point).max(min_frac)
+    }
+}
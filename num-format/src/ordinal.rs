@@ -0,0 +1,66 @@
+use crate::locale::Locale;
+
+/// Type for representing an integer written as an ordinal in the language of a [`Locale`] (e.g.
+/// `1st`, `1er`, or `1.º`). Implements [`ToFormattedStr`] for all the integer types in the
+/// standard library.
+///
+/// The ordinal is written with the CLDR rule-based number format rules for ordinals of the
+/// ordinal's [`Locale`], which pick the suffix (or prefix) by the language's ordinal plural
+/// category (see [`Locale::ordinal_plural_category`]) or by the number's last digits. The
+/// number itself is written with the minus sign and grouping of the [`Format`]. Locales whose language has no ordinal rules write the number followed by a period
+/// (e.g. `1.` in German).
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Locale, Ordinal};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&Ordinal::new(22, Locale::en), &Locale::en);
+///     assert_eq!("22nd", buf.as_str());
+///
+///     buf.write_formatted(&Ordinal::new(1, Locale::fr), &Locale::fr);
+///     assert_eq!("1er", buf.as_str());
+///
+///     buf.write_formatted(&Ordinal::new(3, Locale::es), &Locale::es);
+///     assert_eq!("3.º", buf.as_str());
+///
+///     // The ordinal's locale picks the suffix, the format writes the number
+///     buf.write_formatted(&Ordinal::new(1001, Locale::en), &Locale::de);
+///     assert_eq!("1.001st", buf.as_str());
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`Locale`]: enum.Locale.html
+/// [`Locale::ordinal_plural_category`]: enum.Locale.html#method.ordinal_plural_category
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Ordinal<N> {
+    value: N,
+    locale: Locale,
+}
+
+impl<N> Ordinal<N> {
+    /// Constructs an [`Ordinal`] for the given value, written with the ordinal rules of the given
+    /// [`Locale`].
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Ordinal`]: struct.Ordinal.html
+    pub fn new(value: N, locale: Locale) -> Ordinal<N> {
+        Ordinal { value, locale }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`Locale`] whose ordinal rules the value is written with.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+}
@@ -12,12 +12,50 @@
 //! }
 //! ```
 
+use core::fmt;
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::Range;
 use core::str;
 
-use crate::constants::*;
+#[cfg(feature = "std")]
+use crate::decimal::Decimal;
+#[cfg(feature = "with-encoding")]
+use crate::encoding::Encoding;
 use crate::error::Error;
+use crate::error_kind::ErrorKind;
 use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::roman::{parse_roman, Roman};
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+use crate::Locale;
+
+/// Trait for the [`Format`]s that formatted strings can be parsed with, which adds what parsing
+/// needs beyond the symbols of the format. Implemented for [`CustomFormat`], [`Locale`],
+/// `SystemLocale`, and [`Lenient`]; a [`Format`] of your own can be parsed with once it
+/// implements this trait too, which may be as simple as `impl ParseFormat for MyFormat {}`.
+///
+/// [`CustomFormat`]: ../struct.CustomFormat.html
+/// [`Format`]: ../trait.Format.html
+/// [`Lenient`]: struct.Lenient.html
+/// [`Locale`]: ../enum.Locale.html
+pub trait ParseFormat: Format {
+    /// Returns the [`Locale`] whose symbols (e.g. the percent sign and exponential symbol) to
+    /// recognize around numbers, if any. Defaults to `None`, in which case English is used.
+    ///
+    /// [`Locale`]: ../enum.Locale.html
+    fn locale(&self) -> Option<Locale> {
+        None
+    }
+
+    /// Returns the [`Lenience`] to parse formatted numbers with, which tells what else counts as
+    /// the format's separator, decimal, and signs. Defaults to [`Lenience::CLDR`].
+    ///
+    /// [`Lenience`]: struct.Lenience.html
+    /// [`Lenience::CLDR`]: struct.Lenience.html#associatedconstant.CLDR
+    fn lenience(&self) -> Lenience<'_> {
+        Lenience::CLDR
+    }
+}
 
 /// Trait that provides string-like types with a [`parse_formatted`]
 /// method, allowing conversion from a formatted string into a number.
@@ -41,8 +79,57 @@ pub trait ParseFormatted {
     /// [Examples]: trait.ParseFormatted.html#examples
     fn parse_formatted<F, N>(&self, format: &F) -> Result<N, Error>
     where
-        F: Format,
+        F: ParseFormat,
+        N: FromFormattedStr;
+
+    /// Converts the longest formatted number at the start of `self` into a number, returning it
+    /// along with the number of bytes it takes up (e.g. `1,234` and 5 for `"1,234 bytes"` under
+    /// [`Locale::en`]). Returns an error if `self` doesn't start with a number.
+    ///
+    /// # Examples
+    /// ```
+    /// use num_format::Locale;
+    /// use num_format::parsing::ParseFormatted;
+    ///
+    /// fn main() {
+    ///     let s = "1,234 bytes received";
+    ///     let (n, len) = s.parse_formatted_prefix::<_, u32>(&Locale::en).unwrap();
+    ///     assert_eq!(n, 1_234);
+    ///     assert_eq!(&s[len..], " bytes received");
+    /// }
+    /// ```
+    ///
+    /// [`Locale::en`]: ../enum.Locale.html#variant.en
+    fn parse_formatted_prefix<F, N>(&self, format: &F) -> Result<(N, usize), Error>
+    where
+        F: ParseFormat,
         N: FromFormattedStr;
+
+    /// Returns an iterator over the formatted numbers in `self` (typically free text), each of
+    /// them the longest one [`parse_formatted_prefix`] finds where it starts, maybe followed by
+    /// the format's decimal mark and fraction digits. Numbers only start where a run of digits
+    /// does, and a number followed by more digits (e.g. `1,2345`, which isn't grouped like one)
+    /// is skipped along with them.
+    ///
+    /// # Examples
+    /// ```
+    /// use num_format::Locale;
+    /// use num_format::parsing::ParseFormatted;
+    ///
+    /// fn main() {
+    ///     let s = "Von 1.234,5 Punkten blieben -12 übrig.";
+    ///     let numbers: Vec<_> = s.find_formatted(&Locale::de).collect();
+    ///     assert_eq!(numbers[0].range(), 4..11);
+    ///     assert_eq!(numbers[0].decimal().to_string(), "1234.5");
+    ///     assert_eq!(numbers[1].as_str(), "-12");
+    ///     assert_eq!(numbers[1].parse::<i32>().unwrap(), -12);
+    /// }
+    /// ```
+    ///
+    /// [`parse_formatted_prefix`]: trait.ParseFormatted.html#method.parse_formatted_prefix
+    fn find_formatted<'a, F>(&'a self, format: &'a F) -> FindFormatted<'a, F>
+    where
+        F: ParseFormat;
 }
 
 impl<S> ParseFormatted for S
@@ -51,102 +138,1542 @@ where
 {
     fn parse_formatted<F, N>(&self, format: &F) -> Result<N, Error>
     where
-        F: Format,
+        F: ParseFormat,
         N: FromFormattedStr,
     {
         FromFormattedStr::from_formatted_str(self.as_ref(), format)
     }
+
+    fn parse_formatted_prefix<F, N>(&self, format: &F) -> Result<(N, usize), Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr,
+    {
+        FromFormattedStr::from_formatted_prefix(self.as_ref(), format)
+    }
+
+    fn find_formatted<'a, F>(&'a self, format: &'a F) -> FindFormatted<'a, F>
+    where
+        F: ParseFormat,
+    {
+        FindFormatted {
+            format,
+            index: 0,
+            s: self.as_ref(),
+        }
+    }
+}
+
+/// Iterator over the formatted numbers in a string, created by
+/// [`ParseFormatted::find_formatted`].
+///
+/// [`ParseFormatted::find_formatted`]: trait.ParseFormatted.html#method.find_formatted
+#[derive(Clone, Debug)]
+pub struct FindFormatted<'a, F> {
+    format: &'a F,
+    index: usize,
+    s: &'a str,
+}
+
+impl<'a, F> Iterator for FindFormatted<'a, F>
+where
+    F: ParseFormat,
+{
+    type Item = Found<'a, F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let minus_sign = self.format.minus_sign().into_str();
+        let plus_sign = self.format.plus_sign().into_str();
+        let lenience = self.format.lenience();
+
+        while let Some(c) = self.s[self.index..].chars().next() {
+            let start = self.index;
+            let rest = &self.s[start..];
+            self.index += c.len_utf8();
+
+            // A sign right after a letter or digit is a hyphen or dash (e.g. in "5-7") instead,
+            // and a digit right after a digit is in the middle of digits already passed over
+            let previous = self.s[..start].chars().next_back();
+            if matches!(previous, Some(c) if c.is_ascii_digit()) {
+                continue;
+            }
+            let after_word = matches!(previous, Some(c) if c.is_alphanumeric());
+            let is_sign = lenience.match_len(rest, minus_sign).is_some()
+                || lenience.match_len(rest, plus_sign).is_some();
+            if !c.is_ascii_digit() && (after_word || !is_sign) {
+                continue;
+            }
+            let (is_negative, len, _) = match scan_integer(rest, self.format, true, |_| {}) {
+                Ok(scanned) => scanned,
+                Err(_) => continue,
+            };
+
+            let mut end = start + len;
+            let mut fraction = None;
+            let fraction_len = scan_fraction(&self.s[end..], self.format);
+            if fraction_len > 0 {
+                fraction = Some(end);
+                end += fraction_len;
+            }
+            self.index = end;
+
+            // Digits right after a number mean that it isn't grouped like one (e.g. "1,2345"),
+            // so none of them are a number
+            if self.s[end..].starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+            return Some(Found {
+                format: self.format,
+                fraction,
+                is_negative,
+                range: start..end,
+                s: self.s,
+            });
+        }
+        None
+    }
+}
+
+/// A formatted number found in a string by [`FindFormatted`].
+///
+/// [`FindFormatted`]: struct.FindFormatted.html
+#[derive(Clone, Debug)]
+pub struct Found<'a, F> {
+    format: &'a F,
+    fraction: Option<usize>,
+    is_negative: bool,
+    range: Range<usize>,
+    s: &'a str,
+}
+
+impl<'a, F> Found<'a, F>
+where
+    F: ParseFormat,
+{
+    /// Returns the number as written.
+    pub fn as_str(&self) -> &'a str {
+        &self.s[self.range.clone()]
+    }
+
+    /// Returns whether the number has a decimal mark and fraction digits.
+    pub fn has_fraction(&self) -> bool {
+        self.fraction.is_some()
+    }
+
+    /// Returns whether the number starts with the format's minus sign.
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Parses the number, which fails for numbers with a fraction (see [`decimal`] for those)
+    /// and numbers that don't fit into `N`.
+    ///
+    /// [`decimal`]: struct.Found.html#method.decimal
+    pub fn parse<N>(&self) -> Result<N, Error>
+    where
+        N: FromFormattedStr,
+    {
+        N::from_formatted_str(self.as_str(), self.format)
+    }
+
+    /// Returns the byte range of the number in the string it was found in.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns the number as a [`Decimal`], which holds all its digits whatever their number.
+    ///
+    /// [`Decimal`]: ../struct.Decimal.html
+    pub fn decimal(&self) -> Decimal {
+        let int_end = self.fraction.unwrap_or(self.range.end);
+        let mut digits = String::with_capacity(self.range.len() + 1);
+        if self.is_negative {
+            digits.push('-');
+        }
+        digits.extend(
+            self.s[self.range.start..int_end]
+                .chars()
+                .filter(char::is_ascii_digit),
+        );
+        if let Some(mark) = self.fraction {
+            digits.push('.');
+            digits.extend(
+                self.s[mark..self.range.end]
+                    .chars()
+                    .filter(char::is_ascii_digit),
+            );
+        }
+        // The digits are always of the form `Decimal::new` takes
+        Decimal::new(digits).unwrap()
+    }
+}
+
+/// Trait that provides byte slices with [`parse_formatted`] and [`parse_formatted_prefix`]
+/// methods, allowing conversion from formatted (UTF-8) bytes into a number without first
+/// converting them into a string. Bytes that are all ASCII skip UTF-8 validation altogether.
+///
+/// With the `with-encoding` feature, bytes in a legacy encoding (e.g. Latin-1 or Windows-1252)
+/// can be parsed too, with [`parse_formatted_encoded`] and [`parse_formatted_prefix_encoded`].
+///
+/// Byte offsets, both of errors and of the number of bytes a prefix takes up, are offsets into
+/// the bytes as they were given. A malformed byte sequence is an error of kind
+/// [`ErrorKind::ParseFormatted`] with [`ParseReason::Malformed`] as its reason.
+///
+/// # Examples
+/// ```
+/// use num_format::Locale;
+/// use num_format::parsing::ParseFormattedBytes;
+///
+/// fn main() {
+///     let bytes = b"1.234.567 Bytes empfangen";
+///     let (n, len) = bytes.parse_formatted_prefix::<_, u64>(&Locale::de).unwrap();
+///     assert_eq!(n, 1_234_567);
+///     assert_eq!(len, 9);
+///
+///     let n: i32 = b"-1,234".parse_formatted(&Locale::en).unwrap();
+///     assert_eq!(n, -1_234);
+/// }
+/// ```
+///
+/// [`ErrorKind::ParseFormatted`]: ../enum.ErrorKind.html#variant.ParseFormatted
+/// [`ParseReason::Malformed`]: enum.ParseReason.html#variant.Malformed
+/// [`parse_formatted`]: trait.ParseFormattedBytes.html#method.parse_formatted
+/// [`parse_formatted_encoded`]: trait.ParseFormattedBytes.html#method.parse_formatted_encoded
+/// [`parse_formatted_prefix`]: trait.ParseFormattedBytes.html#method.parse_formatted_prefix
+/// [`parse_formatted_prefix_encoded`]: trait.ParseFormattedBytes.html#method.parse_formatted_prefix_encoded
+pub trait ParseFormattedBytes {
+    /// Converts `self` (typically formatted UTF-8 bytes) into a number (see
+    /// [`ParseFormatted::parse_formatted`]).
+    ///
+    /// [`ParseFormatted::parse_formatted`]: trait.ParseFormatted.html#method.parse_formatted
+    fn parse_formatted<F, N>(&self, format: &F) -> Result<N, Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr;
+
+    /// Converts the longest formatted number at the start of `self` into a number, returning it
+    /// along with the number of bytes it takes up (see [`ParseFormatted::parse_formatted_prefix`]).
+    /// Only the bytes before the first invalid UTF-8 sequence (if any) are looked at.
+    ///
+    /// [`ParseFormatted::parse_formatted_prefix`]: trait.ParseFormatted.html#method.parse_formatted_prefix
+    fn parse_formatted_prefix<F, N>(&self, format: &F) -> Result<(N, usize), Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr;
+
+    /// Converts `self`, formatted bytes in the given encoding, into a number.
+    ///
+    /// # Examples
+    /// ```
+    /// use num_format::{Encoding, Locale};
+    /// use num_format::parsing::ParseFormattedBytes;
+    ///
+    /// fn main() {
+    ///     let windows_1252 = Encoding::for_label(b"windows-1252").unwrap();
+    ///
+    ///     // "1’234’567" with U+2019 as the separator, which is the byte 0x92 in Windows-1252
+    ///     let bytes = b"1\x92234\x92567";
+    ///     let n: u32 = bytes.parse_formatted_encoded(&Locale::de_CH, windows_1252).unwrap();
+    ///     assert_eq!(n, 1_234_567);
+    /// }
+    /// ```
+    #[cfg(feature = "with-encoding")]
+    fn parse_formatted_encoded<F, N>(&self, format: &F, encoding: Encoding) -> Result<N, Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr;
+
+    /// Converts the longest formatted number at the start of `self`, formatted bytes in the
+    /// given encoding, into a number, returning it along with the number of bytes it takes up.
+    /// Only the bytes before the first malformed sequence (if any) are looked at.
+    #[cfg(feature = "with-encoding")]
+    fn parse_formatted_prefix_encoded<F, N>(
+        &self,
+        format: &F,
+        encoding: Encoding,
+    ) -> Result<(N, usize), Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr;
+}
+
+impl ParseFormattedBytes for [u8] {
+    fn parse_formatted<F, N>(&self, format: &F) -> Result<N, Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr,
+    {
+        read_from_bytes(self, format, false).map(|(n, _)| n)
+    }
+
+    fn parse_formatted_prefix<F, N>(&self, format: &F) -> Result<(N, usize), Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr,
+    {
+        read_from_bytes(self, format, true)
+    }
+
+    #[cfg(feature = "with-encoding")]
+    fn parse_formatted_encoded<F, N>(&self, format: &F, encoding: Encoding) -> Result<N, Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr,
+    {
+        read_from_encoded(self, format, encoding, false).map(|(n, _)| n)
+    }
+
+    #[cfg(feature = "with-encoding")]
+    fn parse_formatted_prefix_encoded<F, N>(
+        &self,
+        format: &F,
+        encoding: Encoding,
+    ) -> Result<(N, usize), Error>
+    where
+        F: ParseFormat,
+        N: FromFormattedStr,
+    {
+        read_from_encoded(self, format, encoding, true)
+    }
+}
+
+fn read_from_bytes<F, N>(bytes: &[u8], format: &F, prefix: bool) -> Result<(N, usize), Error>
+where
+    F: ParseFormat,
+    N: FromFormattedStr,
+{
+    // A prefix doesn't go on past a character that ends numbers; so nothing after it needs
+    // validating (which would make reading numbers off the start of a buffer one after another
+    // take time quadratic in its length)
+    let bytes = if prefix {
+        &bytes[..window_len(bytes, number_ends(format))]
+    } else {
+        bytes
+    };
+
+    // ASCII is valid UTF-8; so checking for it (which is cheap) saves validating most input
+    if bytes.is_ascii() {
+        let s = unsafe { str::from_utf8_unchecked(bytes) };
+        return N::read_from_str(s, format, prefix);
+    }
+    match str::from_utf8(bytes) {
+        Ok(s) => N::read_from_str(s, format, prefix),
+        Err(e) => {
+            let valid = e.valid_up_to();
+            let s = unsafe { str::from_utf8_unchecked(&bytes[..valid]) };
+            read_from_decoded(s, Some(valid), format, prefix, |i| i)
+        }
+    }
+}
+
+#[cfg(feature = "with-encoding")]
+fn read_from_encoded<F, N>(
+    bytes: &[u8],
+    format: &F,
+    encoding: Encoding,
+    prefix: bool,
+) -> Result<(N, usize), Error>
+where
+    F: ParseFormat,
+    N: FromFormattedStr,
+{
+    if encoding.is_utf_8() {
+        return read_from_bytes(bytes, format, prefix);
+    }
+
+    // As with UTF-8, a prefix needs no more decoded than up to a character that ends numbers,
+    // which is where an ASCII byte is if all the bytes before it are ASCII too
+    let ends = if prefix { number_ends(format) } else { 0 };
+    let window = &bytes[..window_len(bytes, ends)];
+    if encoding.is_ascii_compatible() && window.is_ascii() {
+        return read_from_bytes(window, format, prefix);
+    }
+    let (text, malformed) = if prefix {
+        encoding.decode_until(bytes, |c| c.is_ascii() && ends & (1 << c as u32) != 0)
+    } else {
+        encoding.decode_valid(bytes)
+    };
+    // Only the length of a prefix or the position of an error needs mapping back to bytes, and
+    // the whole input is read if it isn't a prefix
+    read_from_decoded(&text, malformed, format, prefix, |i| {
+        if !prefix && malformed.is_none() && i == text.len() {
+            bytes.len()
+        } else {
+            encoding.byte_offset(bytes, i)
+        }
+    })
+}
+
+/// Returns the ASCII characters (as a bit set) that no number which prefix parsing under
+/// `format` reads goes on past: those that are neither alphanumeric nor count as part of a
+/// symbol or compact suffix that parsing looks for.
+fn number_ends<F>(format: &F) -> u128
+where
+    F: ParseFormat,
+{
+    let lenience = format.lenience();
+    let locale = format.locale().unwrap_or(Locale::en);
+    let symbols = [
+        format.decimal().into_str(),
+        format.separator().into_str(),
+        format.minus_sign().into_str(),
+        format.plus_sign().into_str(),
+        locale.percent_sign(),
+        locale.exponential_symbol(),
+        // Spaces before a percent sign or compact suffix, and the signs of an exponent
+        " \u{a0}\u{202f}-+",
+        lenience.ignorable(),
+    ];
+    let suffixes = locale.compact_suffixes().iter().map(|(suffix, _)| *suffix);
+
+    let mut ends = (0..128u8)
+        .filter(|b| !b.is_ascii_alphanumeric())
+        .fold(0u128, |ends, b| ends | 1 << b);
+    let mut remove = |c: char| {
+        if c.is_ascii() {
+            ends &= !(1 << c as u32);
+        }
+    };
+    for c in symbols.iter().copied().chain(suffixes).flat_map(str::chars) {
+        remove(c);
+        for class in lenience.classes().iter().filter(|class| class.contains(c)) {
+            class.chars().for_each(&mut remove);
+        }
+    }
+    ends
+}
+
+/// Returns the length in bytes of the start of `bytes` up to and including the first ASCII
+/// character in `ends` (see [`number_ends`]), or of all of `bytes` if there's none.
+///
+/// [`number_ends`]: fn.number_ends.html
+fn window_len(bytes: &[u8], ends: u128) -> usize {
+    bytes
+        .iter()
+        .position(|&b| b < 128 && ends & (1 << b) != 0)
+        .map_or(bytes.len(), |i| i + 1)
+}
+
+/// Reads a number from `text`, which was decoded from bytes up to their first malformed
+/// sequence (at byte offset `malformed`, if any), turning byte offsets into `text` into byte
+/// offsets into the bytes with `offset`.
+fn read_from_decoded<F, N, O>(
+    text: &str,
+    malformed: Option<usize>,
+    format: &F,
+    prefix: bool,
+    offset: O,
+) -> Result<(N, usize), Error>
+where
+    F: ParseFormat,
+    N: FromFormattedStr,
+    O: Fn(usize) -> usize,
+{
+    let malformed_at = |position| Error::parse_formatted(text, position, ParseReason::Malformed);
+    match N::read_from_str(text, format, prefix) {
+        Ok((n, len)) => match malformed {
+            Some(position) if !prefix => Err(malformed_at(position)),
+            _ => Ok((n, offset(len))),
+        },
+        Err(e) => match *e.kind() {
+            ErrorKind::ParseFormatted {
+                position, reason, ..
+            } => match malformed {
+                // Running out of text is the fault of the malformed sequence that ended it
+                Some(m) if position >= text.len() => Err(malformed_at(m)),
+                _ => Err(Error::parse_formatted(text, offset(position), reason)),
+            },
+            _ => Err(e),
+        },
+    }
 }
 
 /// Marker trait for number types (e.g. `u32`) that string-like types can be parsed
 /// into via the [`ParseFormatted`] trait.
 ///
+/// Integers are parsed from an optional minus or plus sign of the format followed by ASCII
+/// digits, which are either not grouped at all or grouped with the format's separator exactly
+/// as they would be written (e.g. `1,000,000` or `1000000`, but not `1,000000` under
+/// [`Locale::en`]). Anything else is an error of kind [`ErrorKind::ParseFormatted`], which tells
+/// where in the input parsing failed and why.
+///
 /// This trait is sealed; so you may not implement it on your own types.
 ///
+/// [`ErrorKind::ParseFormatted`]: ../enum.ErrorKind.html#variant.ParseFormatted
+/// [`Locale::en`]: ../enum.Locale.html#variant.en
 /// [`ParseFormatted`]: trait.ParseFormatted.html
 pub trait FromFormattedStr: crate::private::Sealed + Sized {
     #[allow(missing_docs)]
     fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
     where
-        F: Format;
+        F: ParseFormat,
+    {
+        Self::read_from_str(s, format, false).map(|(n, _)| n)
+    }
+
+    #[allow(missing_docs)]
+    fn from_formatted_prefix<F>(s: &str, format: &F) -> Result<(Self, usize), Error>
+    where
+        F: ParseFormat,
+    {
+        Self::read_from_str(s, format, true)
+    }
+
+    #[doc(hidden)]
+    fn read_from_str<F>(s: &str, format: &F, prefix: bool) -> Result<(Self, usize), Error>
+    where
+        F: ParseFormat;
 }
 
-macro_rules! impl_from_formatted_str {
-    ($type:ty, $max_len:expr) => {
-        impl FromFormattedStr for $type {
-            fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
-            where
-                F: Format,
-            {
-                const BUF_LEN: usize = $max_len;
-                let mut buf: [u8; BUF_LEN] = [0; BUF_LEN];
+/// Table of characters that count as one another when parsing formatted numbers, so that e.g.
+/// a space or U+00A0 counts as the U+202F separator of [`Locale::fr`], or `-` as the U+2212
+/// minus sign of [`Locale::fa`]. Each string of the table is a class of characters that count
+/// as one another, and ignorable characters (e.g. the U+200E of the `"\u{200e}-"` minus sign of
+/// [`Locale::ar_DZ`]) may be left out of the input or added to it.
+///
+/// The [`ParseFormat`] trait's [`lenience`] method tells which table a format parses with, which
+/// is [`Lenience::CLDR`] unless the format is wrapped into a [`Lenient`] format.
+///
+/// # Examples
+/// ```
+/// use num_format::Locale;
+/// use num_format::parsing::{Lenience, Lenient, ParseFormatted};
+///
+/// fn main() {
+///     // U+00A0 counts as U+202F by default...
+///     let n = "1\u{a0}234".parse_formatted::<_, u32>(&Locale::fr).unwrap();
+///     assert_eq!(n, 1_234);
+///
+///     // ...but not under a strict format
+///     let strict = Lenient::new(&Locale::fr, Lenience::STRICT);
+///     assert!("1\u{a0}234".parse_formatted::<_, u32>(&strict).is_err());
+///
+///     // Underscores count as commas under a custom table
+///     let lenience = Lenience::new(&[",_"], "");
+///     let lenient = Lenient::new(&Locale::en, lenience);
+///     let n = "1_000_000".parse_formatted::<_, u32>(&lenient).unwrap();
+///     assert_eq!(n, 1_000_000);
+/// }
+/// ```
+///
+/// [`lenience`]: trait.ParseFormat.html#method.lenience
+/// [`Lenience::CLDR`]: struct.Lenience.html#associatedconstant.CLDR
+/// [`Lenient`]: struct.Lenient.html
+/// [`ParseFormat`]: trait.ParseFormat.html
+/// [`Locale::ar_DZ`]: ../enum.Locale.html#variant.ar_DZ
+/// [`Locale::fa`]: ../enum.Locale.html#variant.fa
+/// [`Locale::fr`]: ../enum.Locale.html#variant.fr
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Lenience<'a> {
+    classes: &'a [&'a str],
+    ignorable: &'a str,
+}
 
-                let minus_sign = format.minus_sign().into_str();
-                let is_negative = s.starts_with(minus_sign);
+impl Lenience<'static> {
+    /// The lenient parse sets of CLDR for numbers: minus signs (e.g. `-` and U+2212), plus signs,
+    /// commas (e.g. `,` and U+060C), periods, apostrophes (e.g. `'` and U+2019), and spaces
+    /// (e.g. ` `, U+00A0, and U+202F), along with the bidi marks as ignorable characters.
+    pub const CLDR: Lenience<'static> = Lenience {
+        classes: &[
+            "-\u{2010}\u{2011}\u{2012}\u{2013}\u{207b}\u{208b}\u{2212}\u{2796}\u{fe63}\u{ff0d}",
+            "+\u{207a}\u{208a}\u{2795}\u{fb29}\u{fe62}\u{ff0b}",
+            ",\u{60c}\u{66b}\u{3001}\u{fe10}\u{fe11}\u{fe50}\u{fe51}\u{ff0c}\u{ff64}",
+            ".\u{2024}\u{3002}\u{fe12}\u{fe52}\u{ff0e}\u{ff61}",
+            "'\u{2018}\u{2019}\u{ff07}",
+            " \u{a0}\u{2000}\u{2001}\u{2002}\u{2003}\u{2004}\u{2005}\u{2006}\u{2007}\u{2008}\u{2009}\
+             \u{200a}\u{202f}\u{205f}\u{3000}",
+        ],
+        ignorable: "\u{61c}\u{200e}\u{200f}",
+    };
 
-                let mut index = 0;
-                if is_negative {
-                    buf[index] = '-' as u8;
-                    index += 1;
-                }
-                for c in s.chars() {
-                    if c.is_numeric() {
-                        if index > BUF_LEN {
-                            return Err(Error::parse_number(&s));
-                        }
-                        buf[index] = c as u8;
-                        index += 1;
-                    }
+    /// No characters count as others and none are ignorable, so that input must have exactly
+    /// the separator, decimal, and signs of the format.
+    pub const STRICT: Lenience<'static> = Lenience {
+        classes: &[],
+        ignorable: "",
+    };
+}
+
+impl<'a> Lenience<'a> {
+    /// Constructs a [`Lenience`] from classes of characters that count as one another and
+    /// characters that are ignorable.
+    ///
+    /// [`Lenience`]: struct.Lenience.html
+    pub const fn new(classes: &'a [&'a str], ignorable: &'a str) -> Lenience<'a> {
+        Lenience { classes, ignorable }
+    }
+
+    /// Returns the classes of characters that count as one another.
+    pub fn classes(&self) -> &'a [&'a str] {
+        self.classes
+    }
+
+    /// Returns the characters that are ignorable.
+    pub fn ignorable(&self) -> &'a str {
+        self.ignorable
+    }
+
+    /// Returns whether `a` and `b` are the same character or are in the same class.
+    pub fn is_equivalent(&self, a: char, b: char) -> bool {
+        a == b
+            || self
+                .classes
+                .iter()
+                .any(|class| class.contains(a) && class.contains(b))
+    }
+
+    /// Returns the length in bytes of the start of `s` that counts as `target`, if any (an
+    /// empty `target`, or one of only ignorable characters, matches nothing).
+    pub(crate) fn match_len(&self, s: &str, target: &str) -> Option<usize> {
+        let is_ignorable = |c: char| self.ignorable.contains(c);
+        let mut chars = s.char_indices().peekable();
+        let mut len = 0;
+        for t in target.chars().filter(|&t| !is_ignorable(t)) {
+            while chars.next_if(|&(_, c)| is_ignorable(c)).is_some() {}
+            match chars.next() {
+                Some((i, c)) if self.is_equivalent(c, t) => len = i + c.len_utf8(),
+                _ => return None,
+            }
+        }
+        // Ignorable characters that end the target belong to it too (e.g. "\u{200e}-\u{200e}")
+        if matches!(target.chars().next_back(), Some(t) if is_ignorable(t)) {
+            while let Some((i, c)) = chars.next_if(|&(_, c)| is_ignorable(c)) {
+                len = i + c.len_utf8();
+            }
+        }
+        if len == 0 {
+            None
+        } else {
+            Some(len)
+        }
+    }
+}
+
+/// Format that parses with a [`Lenience`] of its own, but otherwise is the format it wraps.
+///
+/// [`Lenience`]: struct.Lenience.html
+#[derive(Copy, Clone, Debug)]
+pub struct Lenient<'a, F> {
+    format: &'a F,
+    lenience: Lenience<'a>,
+}
+
+impl<'a, F> Lenient<'a, F> {
+    /// Constructs a [`Lenient`] format from the format to wrap and the [`Lenience`] to parse
+    /// with.
+    ///
+    /// [`Lenience`]: struct.Lenience.html
+    /// [`Lenient`]: struct.Lenient.html
+    pub fn new(format: &'a F, lenience: Lenience<'a>) -> Lenient<'a, F> {
+        Lenient { format, lenience }
+    }
+}
+
+impl<F> Format for Lenient<'_, F>
+where
+    F: Format,
+{
+    #[inline(always)]
+    fn decimal(&self) -> DecimalStr<'_> {
+        self.format.decimal()
+    }
+
+    #[inline(always)]
+    fn grouping(&self) -> Grouping {
+        self.format.grouping()
+    }
+
+    #[inline(always)]
+    fn infinity(&self) -> InfinityStr<'_> {
+        self.format.infinity()
+    }
+
+    #[inline(always)]
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        self.format.minus_sign()
+    }
+
+    #[inline(always)]
+    fn nan(&self) -> NanStr<'_> {
+        self.format.nan()
+    }
+
+    #[inline(always)]
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        self.format.plus_sign()
+    }
+
+    #[inline(always)]
+    fn separator(&self) -> SeparatorStr<'_> {
+        self.format.separator()
+    }
+}
+
+impl<F> ParseFormat for Lenient<'_, F>
+where
+    F: ParseFormat,
+{
+    #[inline(always)]
+    fn locale(&self) -> Option<Locale> {
+        self.format.locale()
+    }
+
+    #[inline(always)]
+    fn lenience(&self) -> Lenience<'_> {
+        self.lenience
+    }
+}
+
+/// Type for parsing a number that may be written in percent, scientific, or compact notation
+/// (e.g. `45 %`, `1.5E3`, or `1.2K`) as well as plainly, with or without fraction digits, into
+/// the number it stands for (e.g. `0.45`, `1500`, or `1200`).
+///
+/// The number is parsed as [`FromFormattedStr`] parses integers, maybe followed by the format's
+/// decimal mark and fraction digits, and then by one of:
+///
+/// - the exponential symbol of the format's [`Locale`] (e.g. `E`, or `e`), an optional sign,
+///   and the digits of the exponent;
+/// - the locale's percent sign (e.g. `%`), maybe after a space, which divides the number by a
+///   hundred (the percent sign may come before the digits instead, as in `%45`); or
+/// - one of the locale's [`compact_suffixes`], maybe after a space, short or long (e.g. `K` or
+///   `thousand` for English, and `Mio.` or `Millionen` for German), which multiplies the number
+///   by the power of ten it stands for.
+///
+/// The compact suffixes are those of the locale's CLDR compact decimal formats; so e.g.
+/// `1,2 Mio.` is parsed under [`Locale::de`], but `1,2K` isn't. Compact formats that put text
+/// before the digits (e.g. `Dubu 0` for Hausa) aren't parsed.
+///
+/// Integer types take the number only if it's a whole number; otherwise parsing fails with
+/// [`ParseReason::Inexact`] rather than dropping digits. [`Decimal`] takes every number exactly.
+///
+/// # Examples
+/// ```
+/// use num_format::{Decimal, Locale};
+/// use num_format::parsing::{Notated, Notation, ParseFormatted};
+///
+/// fn main() {
+///     let n = "1.2K".parse_formatted::<_, Notated<u32>>(&Locale::en).unwrap();
+///     assert_eq!(1_200, *n.value());
+///     assert_eq!(Notation::Compact, n.notation());
+///
+///     let n = "1,2 Mio.".parse_formatted::<_, Notated<u32>>(&Locale::de).unwrap();
+///     assert_eq!(1_200_000, *n.value());
+///
+///     let n = "-1,5E3".parse_formatted::<_, Notated<i32>>(&Locale::de).unwrap();
+///     assert_eq!(-1_500, n.into_value());
+///
+///     // 0.45 isn't an integer, but it is a decimal
+///     assert!("45 %".parse_formatted::<_, Notated<u32>>(&Locale::fr).is_err());
+///     let n = "45 %".parse_formatted::<_, Notated<Decimal>>(&Locale::fr).unwrap();
+///     assert_eq!("0.45", n.value().to_string());
+/// }
+/// ```
+///
+/// [`compact_suffixes`]: ../enum.Locale.html#method.compact_suffixes
+/// [`Decimal`]: ../struct.Decimal.html
+/// [`FromFormattedStr`]: trait.FromFormattedStr.html
+/// [`Locale`]: ../enum.Locale.html
+/// [`Locale::de`]: ../enum.Locale.html#variant.de
+/// [`ParseReason::Inexact`]: enum.ParseReason.html#variant.Inexact
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Notated<N> {
+    value: N,
+    notation: Notation,
+}
+
+impl<N> Notated<N> {
+    /// Returns the number.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the number, consuming the [`Notated`].
+    ///
+    /// [`Notated`]: struct.Notated.html
+    pub fn into_value(self) -> N {
+        self.value
+    }
+
+    /// Returns the notation the number was written in.
+    pub fn notation(&self) -> Notation {
+        self.notation
+    }
+}
+
+/// Notation that a [`Notated`] number was written in.
+///
+/// [`Notated`]: struct.Notated.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum Notation {
+    /// Compact notation (e.g. `1.2K` or `1,2 Mio.`).
+    Compact,
+    /// Percent notation (e.g. `45 %`).
+    Percent,
+    /// Plain notation (e.g. `1,200` or `0.45`).
+    Plain,
+    /// Scientific notation (e.g. `1.5E3`).
+    Scientific,
+}
+
+/// Why parsing a formatted number failed (see [`ErrorKind::ParseFormatted`]).
+///
+/// [`ErrorKind::ParseFormatted`]: ../enum.ErrorKind.html#variant.ParseFormatted
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum ParseReason {
+    /// There are no digits (the input is empty or only a sign).
+    Empty,
+    /// The exponent of a number in scientific notation is too large (e.g. `1E99999`).
+    ExponentOverflow,
+    /// The number isn't a whole number, which the (integer) type can't represent.
+    Inexact,
+    /// A sign is where none can be (e.g. a minus sign for an unsigned type or a second sign).
+    InvalidSign,
+    /// A byte sequence is malformed in the encoding of the input (UTF-8 unless another one was
+    /// given, as with [`ParseFormattedBytes::parse_formatted_encoded`]).
+    ///
+    /// [`ParseFormattedBytes::parse_formatted_encoded`]: trait.ParseFormattedBytes.html#method.parse_formatted_encoded
+    Malformed,
+    /// A separator splits the digits into groups other than those of the format (e.g. the
+    /// second separator of `1,00,000` under [`Locale::en`]).
+    ///
+    /// [`Locale::en`]: ../enum.Locale.html#variant.en
+    MisplacedSeparator,
+    /// The number is too small for the type.
+    NegOverflow,
+    /// The number is too large for the type.
+    PosOverflow,
+    /// A character is where something else was expected.
+    UnexpectedChar {
+        /// What was expected instead.
+        expected: ParseExpected,
+        /// The character found.
+        found: char,
+    },
+    /// The number is zero, which the (non-zero) type can't represent.
+    Zero,
+}
+
+impl fmt::Display for ParseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use self::ParseReason::*;
+        match self {
+            Empty => write!(f, "there are no digits"),
+            ExponentOverflow => write!(f, "the exponent is too large"),
+            Inexact => write!(f, "the number is not a whole number"),
+            InvalidSign => write!(f, "the sign is invalid"),
+            Malformed => write!(f, "the bytes are malformed in their encoding"),
+            MisplacedSeparator => write!(f, "the separator is misplaced"),
+            NegOverflow => write!(f, "the number is too small for the type"),
+            PosOverflow => write!(f, "the number is too large for the type"),
+            UnexpectedChar { expected, found } => {
+                write!(f, "expected {} but found {:?}", expected, found)
+            }
+            Zero => write!(f, "the number is zero"),
+        }
+    }
+}
+
+/// What was expected where parsing a formatted number failed (see
+/// [`ParseReason::UnexpectedChar`]).
+///
+/// [`ParseReason::UnexpectedChar`]: enum.ParseReason.html#variant.UnexpectedChar
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum ParseExpected {
+    /// A digit.
+    Digit,
+    /// A digit or the separator of the format.
+    DigitOrSeparator,
+    /// The end of the input.
+    End,
+    /// A Roman numeral letter (in the same case as the first one).
+    Numeral,
+}
+
+impl fmt::Display for ParseExpected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use self::ParseExpected::*;
+        match self {
+            Digit => write!(f, "a digit"),
+            DigitOrSeparator => write!(f, "a digit or separator"),
+            End => write!(f, "the end of the input"),
+            Numeral => write!(f, "a Roman numeral letter"),
+        }
+    }
+}
+
+/// Scans `s` for an integer formatted under `format`, calling `push` with each of its digits and
+/// returning whether it's negative, or the byte offset where scanning failed and why. If
+/// `prefix` is true, scanning stops at the end of the longest integer at the start of `s`,
+/// whose length in bytes and number of digits are returned too.
+fn scan_integer<F, P>(
+    s: &str,
+    format: &F,
+    prefix: bool,
+    mut push: P,
+) -> Result<(bool, usize, usize), (usize, ParseReason)>
+where
+    F: ParseFormat,
+    P: FnMut(u8),
+{
+    // Signs and separators are whatever counts as them under the format's lenience
+    let lenience = format.lenience();
+    let minus_sign = format.minus_sign().into_str();
+    let plus_sign = format.plus_sign().into_str();
+    let is_sign = |rest: &str| {
+        lenience.match_len(rest, minus_sign).is_some()
+            || lenience.match_len(rest, plus_sign).is_some()
+    };
+
+    let (is_negative, mut index) = match lenience.match_len(s, minus_sign) {
+        Some(len) => (true, len),
+        None => (false, lenience.match_len(s, plus_sign).unwrap_or(0)),
+    };
+
+    // The sizes of the last group of digits and the groups before it, where the last separator
+    // was (if any), and the length and number of digits of the longest integer so far
+    let separator = format.separator().into_str();
+    let sizes = match format.grouping() {
+        _ if separator.is_empty() => None,
+        Grouping::Standard => Some((3, 3)),
+        Grouping::Indian => Some((3, 2)),
+        Grouping::Posix => None,
+    };
+    let mut last_separator = None;
+    let mut group_len = 0;
+    let mut digits = 0;
+    let mut valid = None;
+
+    let error = loop {
+        let c = match s[index..].chars().next() {
+            Some(c) => c,
+            None if digits == 0 => break (index, ParseReason::Empty),
+            None => match (sizes, last_separator) {
+                (Some((last, _)), Some(position)) if group_len != last => {
+                    break (position, ParseReason::MisplacedSeparator)
                 }
+                _ => return Ok((is_negative, index, digits)),
+            },
+        };
+        if c.is_ascii_digit() {
+            push(c as u8 - b'0');
+            group_len += 1;
+            digits += 1;
+            index += 1;
+            if last_separator.is_none() || matches!(sizes, Some((last, _)) if group_len == last) {
+                valid = Some((index, digits));
+            }
+        } else if let Some(separator_len) = lenience.match_len(&s[index..], separator) {
+            // Every group ended by a separator must be full, except the first
+            let is_full = match (sizes, last_separator) {
+                (Some((_, rest)), None) => 1 <= group_len && group_len <= rest,
+                (Some((_, rest)), Some(_)) => group_len == rest,
+                (None, _) => false,
+            };
+            if !is_full {
+                break (index, ParseReason::MisplacedSeparator);
+            }
+            last_separator = Some(index);
+            group_len = 0;
+            index += separator_len;
+        } else if is_sign(&s[index..]) {
+            break (index, ParseReason::InvalidSign);
+        } else {
+            let expected = match sizes {
+                Some(_) if group_len > 0 => ParseExpected::DigitOrSeparator,
+                _ => ParseExpected::Digit,
+            };
+            break (index, ParseReason::UnexpectedChar { expected, found: c });
+        }
+    };
 
-                if index == 0 {
-                    return Err(Error::parse_number(&s));
+    match valid {
+        Some((len, digits)) if prefix => Ok((is_negative, len, digits)),
+        _ => Err(error),
+    }
+}
+
+/// Returns the length in bytes of the format's decimal mark and the digits after it at the start
+/// of `s`, or zero if there aren't any such digits.
+fn scan_fraction<F>(s: &str, format: &F) -> usize
+where
+    F: ParseFormat,
+{
+    let decimal = format.decimal().into_str();
+    match format.lenience().match_len(s, decimal) {
+        Some(len) => {
+            let digits = s[len..].bytes().take_while(u8::is_ascii_digit).count();
+            if digits > 0 {
+                len + digits
+            } else {
+                0
+            }
+        }
+        None => 0,
+    }
+}
+
+/// Where the digits of a number that [`scan_notated`] found are, and how far its notation moves
+/// their decimal point.
+struct NotatedParts {
+    is_negative: bool,
+    integer: Range<usize>,
+    fraction: Range<usize>,
+    shift: i32,
+    notation: Notation,
+    len: usize,
+}
+
+impl NotatedParts {
+    /// Calls `push` with each digit of the number as written and whether it comes after the
+    /// decimal point once the notation has moved it, adding the zeros that moving it takes.
+    /// Returns how many zeros come between the decimal point and the first digit after it.
+    fn digits<P>(&self, s: &str, mut push: P) -> usize
+    where
+        P: FnMut(u8, bool),
+    {
+        let digits = s[self.integer.clone()]
+            .bytes()
+            .chain(s[self.fraction.clone()].bytes())
+            .filter(u8::is_ascii_digit);
+        let integer_len = s[self.integer.clone()]
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .count();
+        let point = integer_len as i64 + i64::from(self.shift);
+        let mut index = 0;
+        for digit in digits {
+            push(digit, index >= point);
+            index += 1;
+        }
+        while index < point {
+            push(b'0', false);
+            index += 1;
+        }
+        if point < 0 {
+            point.unsigned_abs() as usize
+        } else {
+            0
+        }
+    }
+}
+
+/// Scans `s` for a number that may be written in percent, scientific, or compact notation (see
+/// [`Notated`]), returning where its parts are, or the byte offset where scanning failed and
+/// why. If `prefix` is true, scanning stops at the end of the longest such number at the start
+/// of `s`.
+///
+/// [`Notated`]: struct.Notated.html
+fn scan_notated<F>(s: &str, format: &F, prefix: bool) -> Result<NotatedParts, (usize, ParseReason)>
+where
+    F: ParseFormat,
+{
+    let lenience = format.lenience();
+    let locale = format.locale().unwrap_or(Locale::en);
+    let minus_sign = format.minus_sign().into_str();
+    let plus_sign = format.plus_sign().into_str();
+    let percent_sign = locale.percent_sign();
+    let space_len = |rest: &str| {
+        [" ", "\u{a0}", "\u{202f}"]
+            .iter()
+            .find_map(|space| lenience.match_len(rest, space))
+            .unwrap_or(0)
+    };
+
+    // A percent sign before the digits (e.g. `%45` or `-%45`) comes after the sign
+    let (is_negative, sign_len) = match lenience.match_len(s, minus_sign) {
+        Some(len) => (true, len),
+        None => (false, lenience.match_len(s, plus_sign).unwrap_or(0)),
+    };
+    let (leading_percent, start) = match lenience.match_len(&s[sign_len..], percent_sign) {
+        Some(len) => {
+            let start = sign_len + len;
+            let start = start + space_len(&s[start..]);
+            let rest = &s[start..];
+            if lenience.match_len(rest, minus_sign).is_some()
+                || lenience.match_len(rest, plus_sign).is_some()
+            {
+                return Err((start, ParseReason::InvalidSign));
+            }
+            (true, start)
+        }
+        None => (false, 0),
+    };
+
+    let (is_negative, integer_len, _) = match scan_integer(&s[start..], format, true, |_| ()) {
+        Ok((is_negative_integer, len, digits)) => (is_negative || is_negative_integer, len, digits),
+        Err((position, reason)) => return Err((start + position, reason)),
+    };
+    let integer = start..start + integer_len;
+    let fraction = integer.end..integer.end + scan_fraction(&s[integer.end..], format);
+    let mut index = fraction.end;
+
+    let (shift, notation) = if leading_percent {
+        (-2, Notation::Percent)
+    } else if let Some((len, exponent)) = scan_exponent(&s[index..], format, locale)
+        .map_err(|(position, reason)| (index + position, reason))?
+    {
+        index += len;
+        (exponent, Notation::Scientific)
+    } else {
+        let space = space_len(&s[index..]);
+        let rest = &s[index + space..];
+        // The suffixes are longest first; so e.g. `Millionen` is found rather than `Million`
+        let compact = locale
+            .compact_suffixes()
+            .iter()
+            .find_map(|(suffix, shift)| {
+                let len = lenience.match_len(rest, suffix)?;
+                // Unless the whole string is the number, a suffix must not start a word (e.g. `Kb`)
+                let next = rest[len..].chars().next();
+                if prefix && matches!(next, Some(c) if c.is_alphanumeric()) {
+                    None
+                } else {
+                    Some((len, *shift))
                 }
+            });
+        if let Some(len) = lenience.match_len(rest, percent_sign) {
+            index += space + len;
+            (-2, Notation::Percent)
+        } else if let Some((len, shift)) = compact {
+            index += space + len;
+            (shift, Notation::Compact)
+        } else {
+            (0, Notation::Plain)
+        }
+    };
+
+    if !prefix && index < s.len() {
+        if index == integer.end {
+            // Nothing follows the integer; so it's the integer that doesn't go on as it should
+            scan_integer(&s[start..], format, false, |_| ())
+                .map_err(|(position, reason)| (start + position, reason))?;
+        }
+        let found = s[index..].chars().next().unwrap();
+        let expected = ParseExpected::End;
+        return Err((index, ParseReason::UnexpectedChar { expected, found }));
+    }
+
+    Ok(NotatedParts {
+        is_negative,
+        integer,
+        fraction,
+        shift,
+        notation,
+        len: index,
+    })
+}
+
+/// Returns the length in bytes of the locale's exponential symbol, an optional sign, and the
+/// digits after them at the start of `s`, along with the exponent, if `s` starts with them.
+fn scan_exponent<F>(
+    s: &str,
+    format: &F,
+    locale: Locale,
+) -> Result<Option<(usize, i32)>, (usize, ParseReason)>
+where
+    F: ParseFormat,
+{
+    let lenience = format.lenience();
+    let symbol = locale.exponential_symbol();
+    let symbol_len = match lenience.match_len(s, symbol) {
+        Some(len) => len,
+        None if symbol == "E" && s.starts_with('e') => 1,
+        None => return Ok(None),
+    };
+
+    let rest = &s[symbol_len..];
+    let (is_negative, sign_len) = match lenience.match_len(rest, format.minus_sign().into_str()) {
+        Some(len) => (true, len),
+        None => match lenience.match_len(rest, format.plus_sign().into_str()) {
+            Some(len) => (false, len),
+            None => match rest.as_bytes().first() {
+                Some(b'-') => (true, 1),
+                Some(b'+') => (false, 1),
+                _ => (false, 0),
+            },
+        },
+    };
 
-                let s2 = unsafe { str::from_utf8_unchecked(&buf[..index]) };
-                let n = s2.parse::<$type>().map_err(|_| Error::parse_locale(&s))?;
+    let start = symbol_len + sign_len;
+    let mut exponent: i32 = 0;
+    let mut len = start;
+    for digit in s[start..].bytes().take_while(u8::is_ascii_digit) {
+        exponent = exponent * 10 + i32::from(digit - b'0');
+        if exponent > i32::from(i16::MAX) {
+            return Err((start, ParseReason::ExponentOverflow));
+        }
+        len += 1;
+    }
+    if len == start {
+        return Ok(None);
+    }
+    Ok(Some((len, if is_negative { -exponent } else { exponent })))
+}
+
+#[cfg(feature = "std")]
+/// Returns the locales under which every one of `samples` is a formatted number (an integer as
+/// [`ParseFormatted::parse_formatted`] takes, maybe followed by the locale's decimal mark and
+/// fraction digits), most plausible first.
+///
+/// Since samples fit all the locales that share conventions (a decimal mark, separator, grouping,
+/// and signs) alike, locales are ranked by how many locales share theirs, so that ambiguous
+/// samples like `"1.234"` rank the most common conventions that fit them first, and then by name
+/// (shortest first, so that a language comes before its regions).
+///
+/// # Examples
+/// ```
+/// use num_format::Locale;
+/// use num_format::parsing::infer_locales;
+///
+/// fn main() {
+///     let locales = infer_locales(["1.234,5", "-12", "1.000.000"]);
+///     assert!(locales.contains(&Locale::de));
+///     assert!(!locales.contains(&Locale::en));
+///
+///     // Ambiguous samples rank the more common conventions first
+///     let locales = infer_locales(["1.234"]);
+///     assert!(locales.contains(&Locale::de));
+///     assert_eq!(locales[0].decimal(), ".");
+/// }
+/// ```
+///
+/// [`ParseFormatted::parse_formatted`]: trait.ParseFormatted.html#method.parse_formatted
+pub fn infer_locales<I, S>(samples: I) -> Vec<crate::Locale>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    use crate::Locale;
+
+    let samples: Vec<S> = samples.into_iter().collect();
+    let locales: Vec<Locale> = Locale::available_names()
+        .iter()
+        .filter_map(|name| Locale::from_name(name).ok())
+        .collect();
+    let conventions = |locale: &Locale| {
+        (
+            locale.decimal(),
+            locale.separator(),
+            locale.grouping(),
+            locale.minus_sign(),
+            locale.plus_sign(),
+        )
+    };
+
+    let takes = |locale: &Locale, s: &str| match scan_integer(s, locale, true, |_| {}) {
+        Ok((_, len, _)) => len + scan_fraction(&s[len..], locale) == s.len(),
+        Err(_) => false,
+    };
+
+    // Each locale that takes all the samples, with how many locales share its conventions
+    let mut ranked: Vec<(usize, Locale)> = locales
+        .iter()
+        .filter(|locale| samples.iter().all(|sample| takes(locale, sample.as_ref())))
+        .map(|locale| {
+            let sharing = locales
+                .iter()
+                .filter(|other| conventions(other) == conventions(locale))
+                .count();
+            (sharing, *locale)
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| {
+        (b.0, a.1.name().len(), a.1.name()).cmp(&(a.0, b.1.name().len(), b.1.name()))
+    });
+    ranked.into_iter().map(|(_, locale)| locale).collect()
+}
+
+/// Integer types that are parsed by accumulating their magnitude in the unsigned type of the
+/// same size with checked arithmetic, so that no input (e.g. one with many leading zeros or more
+/// digits than the type can have) takes more than a pass over its digits or makes parsing panic.
+trait Accumulate: Sized {
+    type Magnitude: Copy;
+
+    const ZERO: Self::Magnitude;
+
+    /// Returns `magnitude` with `digit` appended to it, or `None` if that (or `magnitude`)
+    /// overflows.
+    fn append(magnitude: Option<Self::Magnitude>, digit: u8) -> Option<Self::Magnitude>;
+
+    /// Returns the number with the given magnitude (`None` if it overflowed) and sign.
+    fn from_magnitude(
+        magnitude: Option<Self::Magnitude>,
+        is_negative: bool,
+    ) -> Result<Self, ParseReason>;
+}
+
+macro_rules! impl_from_formatted_str {
+    ($type:ty, $magnitude:ty) => {
+        impl Accumulate for $type {
+            type Magnitude = $magnitude;
+
+            const ZERO: $magnitude = 0;
+
+            #[inline(always)]
+            fn append(magnitude: Option<$magnitude>, digit: u8) -> Option<$magnitude> {
+                magnitude?
+                    .checked_mul(10)?
+                    .checked_add(<$magnitude>::from(digit))
+            }
+
+            #[allow(trivial_numeric_casts)]
+            fn from_magnitude(
+                magnitude: Option<$magnitude>,
+                is_negative: bool,
+            ) -> Result<Self, ParseReason> {
+                #[allow(unused_comparisons)]
+                let is_unsigned = <$type>::MIN >= 0;
+                if is_negative && is_unsigned {
+                    return Err(ParseReason::InvalidSign);
+                }
+                // The magnitude of the minimum (e.g. 128 for i8), which is zero if unsigned
+                let min_magnitude = (<$type>::MIN as $magnitude).wrapping_neg();
+                match magnitude {
+                    Some(m) if is_negative && m <= min_magnitude => Ok((m as $type).wrapping_neg()),
+                    Some(m) if !is_negative && m <= <$type>::MAX as $magnitude => Ok(m as $type),
+                    _ if is_negative => Err(ParseReason::NegOverflow),
+                    _ => Err(ParseReason::PosOverflow),
+                }
+            }
+        }
 
-                Ok(n)
+        impl FromFormattedStr for $type {
+            fn read_from_str<F>(s: &str, format: &F, prefix: bool) -> Result<(Self, usize), Error>
+            where
+                F: ParseFormat,
+            {
+                let mut magnitude = Some(<$type>::ZERO);
+                let mut pushed = 0;
+                let (is_negative, end, digits) = scan_integer(s, format, prefix, |digit| {
+                    magnitude = <$type>::append(magnitude, digit);
+                    pushed += 1;
+                })
+                .map_err(|(position, reason)| Error::parse_formatted(s, position, reason))?;
+                if pushed != digits {
+                    // The prefix ends before the last digits scanned; so take only its own
+                    magnitude = s[..end]
+                        .bytes()
+                        .filter(u8::is_ascii_digit)
+                        .fold(Some(<$type>::ZERO), |m, digit| {
+                            <$type>::append(m, digit - b'0')
+                        });
+                }
+                let n = <$type>::from_magnitude(magnitude, is_negative)
+                    .map_err(|reason| Error::parse_formatted(s, 0, reason))?;
+                Ok((n, end))
             }
         }
     };
 }
 
-impl_from_formatted_str!(u8, U8_MAX_LEN);
-impl_from_formatted_str!(u16, U16_MAX_LEN);
-impl_from_formatted_str!(u32, U32_MAX_LEN);
-impl_from_formatted_str!(usize, USIZE_MAX_LEN);
-impl_from_formatted_str!(u64, U64_MAX_LEN);
-impl_from_formatted_str!(u128, U128_MAX_LEN);
+impl_from_formatted_str!(u8, u8);
+impl_from_formatted_str!(u16, u16);
+impl_from_formatted_str!(u32, u32);
+impl_from_formatted_str!(usize, usize);
+impl_from_formatted_str!(u64, u64);
+impl_from_formatted_str!(u128, u128);
 
-impl_from_formatted_str!(i8, I8_MAX_LEN);
-impl_from_formatted_str!(i16, I16_MAX_LEN);
-impl_from_formatted_str!(i32, I32_MAX_LEN);
-impl_from_formatted_str!(isize, ISIZE_MAX_LEN);
-impl_from_formatted_str!(i64, I64_MAX_LEN);
-impl_from_formatted_str!(i128, I128_MAX_LEN);
+impl_from_formatted_str!(i8, u8);
+impl_from_formatted_str!(i16, u16);
+impl_from_formatted_str!(i32, u32);
+impl_from_formatted_str!(isize, usize);
+impl_from_formatted_str!(i64, u64);
+impl_from_formatted_str!(i128, u128);
 
 macro_rules! impl_from_formatted_str_non_zero {
-    ($type:ty, $related_type:ty, $max_len:expr) => {
+    ($type:ty, $related_type:ty) => {
         impl FromFormattedStr for $type {
-            fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
+            fn read_from_str<F>(s: &str, format: &F, prefix: bool) -> Result<(Self, usize), Error>
+            where
+                F: ParseFormat,
+            {
+                let (n, end) = <$related_type>::read_from_str(s, format, prefix)?;
+                let n =
+                    Self::new(n).ok_or_else(|| Error::parse_formatted(s, 0, ParseReason::Zero))?;
+                Ok((n, end))
+            }
+        }
+    };
+}
+
+impl_from_formatted_str_non_zero!(NonZeroU8, u8);
+impl_from_formatted_str_non_zero!(NonZeroU16, u16);
+impl_from_formatted_str_non_zero!(NonZeroU32, u32);
+impl_from_formatted_str_non_zero!(NonZeroUsize, usize);
+impl_from_formatted_str_non_zero!(NonZeroU64, u64);
+impl_from_formatted_str_non_zero!(NonZeroU128, u128);
+
+macro_rules! impl_from_formatted_str_notated {
+    ($type:ty) => {
+        impl crate::private::Sealed for Notated<$type> {}
+
+        impl FromFormattedStr for Notated<$type> {
+            fn read_from_str<F>(s: &str, format: &F, prefix: bool) -> Result<(Self, usize), Error>
             where
-                F: Format,
+                F: ParseFormat,
             {
-                let n = s.parse_formatted::<_, $related_type>(format)?;
-                let n = Self::new(n).ok_or_else(|| Error::parse_number(s))?;
-                Ok(n)
+                let parts = scan_notated(s, format, prefix)
+                    .map_err(|(position, reason)| Error::parse_formatted(s, position, reason))?;
+
+                let mut magnitude = Some(<$type>::ZERO);
+                let mut is_inexact = false;
+                let _ = parts.digits(s, |digit, is_fraction| {
+                    if is_fraction {
+                        is_inexact |= digit != b'0';
+                    } else {
+                        magnitude = <$type>::append(magnitude, digit - b'0');
+                    }
+                });
+                let value = <$type>::from_magnitude(magnitude, parts.is_negative)
+                    .map_err(|reason| Error::parse_formatted(s, 0, reason))?;
+                if is_inexact {
+                    return Err(Error::parse_formatted(s, 0, ParseReason::Inexact));
+                }
+
+                let notated = Notated {
+                    value,
+                    notation: parts.notation,
+                };
+                Ok((notated, parts.len))
             }
         }
     };
 }
 
-impl_from_formatted_str_non_zero!(NonZeroU8, u8, U8_MAX_LEN);
-impl_from_formatted_str_non_zero!(NonZeroU16, u16, U16_MAX_LEN);
-impl_from_formatted_str_non_zero!(NonZeroU32, u32, U32_MAX_LEN);
-impl_from_formatted_str_non_zero!(NonZeroUsize, usize, USIZE_MAX_LEN);
-impl_from_formatted_str_non_zero!(NonZeroU64, u64, U64_MAX_LEN);
-impl_from_formatted_str_non_zero!(NonZeroU128, u128, U128_MAX_LEN);
+impl_from_formatted_str_notated!(u8);
+impl_from_formatted_str_notated!(u16);
+impl_from_formatted_str_notated!(u32);
+impl_from_formatted_str_notated!(usize);
+impl_from_formatted_str_notated!(u64);
+impl_from_formatted_str_notated!(u128);
+
+impl_from_formatted_str_notated!(i8);
+impl_from_formatted_str_notated!(i16);
+impl_from_formatted_str_notated!(i32);
+impl_from_formatted_str_notated!(isize);
+impl_from_formatted_str_notated!(i64);
+impl_from_formatted_str_notated!(i128);
+
+#[cfg(feature = "std")]
+impl crate::private::Sealed for Notated<Decimal> {}
+
+#[cfg(feature = "std")]
+impl FromFormattedStr for Notated<Decimal> {
+    fn read_from_str<F>(s: &str, format: &F, prefix: bool) -> Result<(Self, usize), Error>
+    where
+        F: ParseFormat,
+    {
+        let parts = scan_notated(s, format, prefix)
+            .map_err(|(position, reason)| Error::parse_formatted(s, position, reason))?;
+
+        let mut integer = String::new();
+        let mut fraction = String::new();
+        let zeros = parts.digits(s, |digit, is_fraction| {
+            if is_fraction {
+                fraction.push(char::from(digit));
+            } else {
+                integer.push(char::from(digit));
+            }
+        });
+        if integer.is_empty() {
+            integer.push('0');
+        }
+        let sign = if parts.is_negative { "-" } else { "" };
+        let point = if fraction.is_empty() { "" } else { "." };
+        let zeros = "0".repeat(if fraction.is_empty() { 0 } else { zeros });
+        let digits = format!("{}{}{}{}{}", sign, integer, point, zeros, fraction);
+
+        let notated = Notated {
+            value: Decimal::new(digits).unwrap(),
+            notation: parts.notation,
+        };
+        Ok((notated, parts.len))
+    }
+}
+
+macro_rules! impl_from_formatted_str_roman {
+    ($type:ty) => {
+        impl FromFormattedStr for Roman<$type> {
+            /// Parses a Roman numeral in uppercase or lowercase letters (but not both), with an
+            /// overline after each letter of the thousands of numbers from 4,000 on. Only the
+            /// numeral [`Roman`] writes for the value is accepted (e.g. `IV`, but not `IIII`).
+            ///
+            /// [`Roman`]: ../struct.Roman.html
+            fn read_from_str<F>(s: &str, _format: &F, prefix: bool) -> Result<(Self, usize), Error>
+            where
+                F: ParseFormat,
+            {
+                let (n, lowercase, vinculum, end) = parse_roman_prefix(s, prefix)
+                    .map_err(|(position, reason)| Error::parse_formatted(s, position, reason))?;
+                let value = <$type>::try_from(n)
+                    .map_err(|_| Error::parse_formatted(s, 0, ParseReason::PosOverflow))?;
+                let mut roman = Roman::new(value);
+                if lowercase {
+                    roman = roman.lowercase();
+                }
+                if vinculum {
+                    roman = roman.vinculum();
+                }
+                Ok((roman, end))
+            }
+        }
+    };
+}
+
+/// Parses the Roman numeral that is `s` or, if `prefix` is true, the longest one at the start of
+/// `s`, returning its length in bytes along with what [`parse_roman`] returns.
+fn parse_roman_prefix(
+    s: &str,
+    prefix: bool,
+) -> Result<(u32, bool, bool, usize), (usize, ParseReason)> {
+    let error = match parse_roman(s) {
+        Ok((n, lowercase, vinculum)) => return Ok((n, lowercase, vinculum, s.len())),
+        Err(error) => error,
+    };
+    if !prefix {
+        return Err(error);
+    }
+
+    // Letters (each maybe with an overline) come before any other character, and no numeral is
+    // longer than 15 of them
+    let letters = s
+        .char_indices()
+        .take_while(|&(_, c)| "IVXLCDMivxlcdm\u{305}".contains(c))
+        .take(30)
+        .map(|(i, c)| i + c.len_utf8());
+    let mut ends = [0; 30];
+    let mut len = 0;
+    for end in letters {
+        ends[len] = end;
+        len += 1;
+    }
+    for &end in ends[..len].iter().rev() {
+        if let Ok((n, lowercase, vinculum)) = parse_roman(&s[..end]) {
+            return Ok((n, lowercase, vinculum, end));
+        }
+    }
+    Err(error)
+}
+
+impl_from_formatted_str_roman!(u8);
+impl_from_formatted_str_roman!(u16);
+impl_from_formatted_str_roman!(u32);
+impl_from_formatted_str_roman!(usize);
+impl_from_formatted_str_roman!(u64);
+impl_from_formatted_str_roman!(u128);
+
+impl_from_formatted_str_roman!(i8);
+impl_from_formatted_str_roman!(i16);
+impl_from_formatted_str_roman!(i32);
+impl_from_formatted_str_roman!(isize);
+impl_from_formatted_str_roman!(i64);
+impl_from_formatted_str_roman!(i128);
 
 #[cfg(feature = "with-num-bigint")]
 mod num {
@@ -157,32 +1684,28 @@ mod num {
     macro_rules! impl_from_formatted_str_num_bigint {
         ($type:ty) => {
             impl FromFormattedStr for $type {
-                fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
+                fn read_from_str<F>(
+                    s: &str,
+                    format: &F,
+                    prefix: bool,
+                ) -> Result<(Self, usize), Error>
                 where
-                    F: Format,
+                    F: ParseFormat,
                 {
-                    let mut buf = Vec::new();
-
-                    let minus_sign = format.minus_sign().into_str();
-                    let is_negative = s.starts_with(minus_sign);
-
-                    if is_negative {
-                        buf.push('-' as u8);
-                    }
-                    for c in s.chars() {
-                        if c.is_numeric() {
-                            buf.push(c as u8);
-                        }
-                    }
-
-                    if buf.is_empty() {
-                        return Err(Error::parse_number(&s));
-                    }
+                    let mut buf = vec![b'-'];
+                    let (is_negative, end, digits) =
+                        scan_integer(s, format, prefix, |digit| buf.push(b'0' + digit)).map_err(
+                            |(position, reason)| Error::parse_formatted(s, position, reason),
+                        )?;
+                    buf.truncate(1 + digits);
 
-                    let s2 = unsafe { str::from_utf8_unchecked(&buf[..]) };
-                    let n = s2.parse::<$type>().map_err(|_| Error::parse_locale(&s))?;
+                    let start = if is_negative { 0 } else { 1 };
+                    let s2 = unsafe { str::from_utf8_unchecked(&buf[start..]) };
+                    let n = s2
+                        .parse::<$type>()
+                        .map_err(|_| Error::parse_formatted(s, 0, ParseReason::InvalidSign))?;
 
-                    Ok(n)
+                    Ok((n, end))
                 }
             }
         };
@@ -0,0 +1,77 @@
+use crate::constants::{F64_MAX_INT_DIGITS, MAX_BUF_LEN};
+use crate::error::Error;
+use crate::NumberPattern;
+
+/// Type for representing a number written as a [`NumberPattern`] says (e.g. `(1,234.50)` for
+/// `#,##0.00;(#,##0.00)`). Implements [`ToFormattedStr`] for everything that does (and
+/// [`ToFormattedString`] for [`Decimal`], [`Measure`], [`Range`], [`Approximately`], and, with
+/// the `with-num-bigint` feature, `BigInt` and `BigUint`).
+///
+/// Integers, floats, and decimals, including those inside other values (e.g. [`Measure`]s), are
+/// written with the pattern, using the symbols of the [`Format`]. The pattern takes the place of
+/// the format's grouping.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, Locale, NumberPattern, Patterned, ToFormattedString};
+///
+/// fn main() -> Result<(), Error> {
+///     let pattern = NumberPattern::new("#,##0.00;(#,##0.00)")?;
+///     let value = Patterned::new(-1234.5, pattern)?;
+///     assert_eq!("(1,234.50)", value.to_formatted_string(&Locale::en));
+///     assert_eq!("(1.234,50)", value.to_formatted_string(&Locale::de));
+///
+///     let value = Patterned::new(1234567.891, Locale::en_IN.decimal_pattern().parse()?)?;
+///     assert_eq!("12,34,567.891", value.to_formatted_string(&Locale::en_IN));
+///
+///     let value = Patterned::new(0.1234, NumberPattern::new("0.0%")?)?;
+///     assert_eq!("12.3%", value.to_formatted_string(&Locale::en));
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Approximately`]: struct.Approximately.html
+/// [`Decimal`]: struct.Decimal.html
+/// [`Format`]: trait.Format.html
+/// [`Measure`]: struct.Measure.html
+/// [`NumberPattern`]: struct.NumberPattern.html
+/// [`Range`]: struct.Range.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Patterned<T> {
+    value: T,
+    pattern: NumberPattern,
+}
+
+impl<T> Patterned<T> {
+    /// Constructs a [`Patterned`] for the given value, which is written as the pattern says.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the longest `f64` written with the pattern would not fit into a
+    /// [`Buffer`].
+    ///
+    /// [`Buffer`]: struct.Buffer.html
+    /// [`Patterned`]: struct.Patterned.html
+    pub fn new(value: T, pattern: NumberPattern) -> Result<Patterned<T>, Error> {
+        let len = pattern.max_len(F64_MAX_INT_DIGITS);
+        if len > MAX_BUF_LEN {
+            return Err(Error::capacity(len, MAX_BUF_LEN));
+        }
+        Ok(Patterned { value, pattern })
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the [`NumberPattern`] the value is written with.
+    ///
+    /// [`NumberPattern`]: struct.NumberPattern.html
+    pub fn pattern(&self) -> &NumberPattern {
+        &self.pattern
+    }
+}
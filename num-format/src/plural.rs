@@ -0,0 +1,391 @@
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use crate::decimal::Decimal;
+use crate::error::Error;
+use crate::locale::Locale;
+
+// The operands are capped at 10^18 + (value % 10^18), which keeps them in a u64 while
+// preserving both the trailing digits that modulus rules look at and the fact that the value
+// is at least 10^18
+const CAP: u64 = 1_000_000_000_000_000_000;
+
+/// A CLDR plural category, which selects the form of the words written around a number (e.g.
+/// `One` for "1 file" and `Other` for "2 files" in English).
+///
+/// Which categories a language uses, and for which numbers, is given by the language's plural
+/// rules (see [`Locale::plural_category`]). Every language uses `Other`.
+///
+/// [`Locale::plural_category`]: enum.Locale.html#method.plural_category
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PluralCategory {
+    /// The "zero" category (e.g. Arabic 0, Latvian 10)
+    Zero,
+    /// The "one" category (e.g. English 1, French 0 and 1.5)
+    One,
+    /// The "two" category (e.g. Arabic 2, Welsh 2)
+    Two,
+    /// The "few" category (e.g. Polish 2 and 22, Czech 3)
+    Few,
+    /// The "many" category (e.g. Polish 5 and 12, Russian 11)
+    Many,
+    /// The "other" category, which covers every number no other category does
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the CLDR keyword of the category (e.g. `"few"`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// Type for representing a number as written, which is what plural rules are applied to (e.g.
+/// `1` and `1.0` can take different plural forms). Constructed from any of the integer types in
+/// the standard library, from an `f64` written with a given number of fraction digits (see
+/// [`from_f64`]), or from a decimal string (e.g. `"-1234.50"`, see [`from_str`]).
+///
+/// The fields are the CLDR plural operands of the number's absolute value. Integer and fraction
+/// digits beyond the last 18 only count as far as making the operand at least 10^18.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, Locale, PluralCategory, PluralOperands};
+///
+/// fn main() -> Result<(), Error> {
+///     assert_eq!(PluralCategory::One, Locale::en.plural_category(1));
+///     assert_eq!(PluralCategory::Other, Locale::en.plural_category(2));
+///
+///     // "1.0 files", but "1 file"
+///     let operands = PluralOperands::from_f64(1.0, 1);
+///     assert_eq!(PluralCategory::Other, Locale::en.plural_category(operands));
+///
+///     // "5 plików", "22 pliki", and "1,5 pliku"
+///     assert_eq!(PluralCategory::Many, Locale::pl.plural_category(5));
+///     assert_eq!(PluralCategory::Few, Locale::pl.plural_category(22));
+///     let operands: PluralOperands = "1.5".parse()?;
+///     assert_eq!(PluralCategory::Other, Locale::pl.plural_category(operands));
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`from_f64`]: struct.PluralOperands.html#method.from_f64
+/// [`from_str`]: struct.PluralOperands.html#method.from_str
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct PluralOperands {
+    pub(crate) i: u64,
+    pub(crate) v: u64,
+    pub(crate) w: u64,
+    pub(crate) f: u64,
+    pub(crate) t: u64,
+    pub(crate) e: u64,
+    pub(crate) nonfinite: bool,
+}
+
+impl PluralOperands {
+    /// Constructs the [`PluralOperands`] of `value` written with exactly `precision` fraction
+    /// digits, rounded as by the standard library's `{:.precision}` (e.g. `1.5` with a precision
+    /// of `2` is written `1.50`).
+    ///
+    /// Non-finite values are in the `Other` category of every language.
+    ///
+    /// [`PluralOperands`]: struct.PluralOperands.html
+    pub fn from_f64(value: f64, precision: u8) -> PluralOperands {
+        if !value.is_finite() {
+            return PluralOperands::nonfinite();
+        }
+        let mut digits = Digits::default();
+        write!(digits, "{:.*}", precision as usize, value.abs()).unwrap();
+        digits.operands()
+    }
+
+    /// Returns the operands of a non-finite float.
+    pub(crate) fn nonfinite() -> PluralOperands {
+        PluralOperands {
+            nonfinite: true,
+            ..PluralOperands::default()
+        }
+    }
+
+    /// Returns the operands of the float `mantissa * 10^exponent` as written by this crate,
+    /// which writes whole floats with a single fraction digit (e.g. `1.0`).
+    pub(crate) fn from_float(mantissa: u64, exponent: i32) -> PluralOperands {
+        if exponent >= 0 {
+            // Only the last 18 digits of the integer matter, so larger exponents can't change it
+            let i = if mantissa == 0 {
+                0
+            } else if exponent >= 18 {
+                CAP
+            } else {
+                cap(u128::from(mantissa) * 10u128.pow(exponent as u32))
+            };
+            return PluralOperands {
+                i,
+                v: 1,
+                ..PluralOperands::default()
+            };
+        }
+
+        let v = u64::from(exponent.unsigned_abs());
+        let (i, f) = if v >= 20 {
+            (0, mantissa)
+        } else {
+            let pow = 10u64.pow(v as u32);
+            (mantissa / pow, mantissa % pow)
+        };
+        let (mut t, mut w) = if f == 0 { (0, 0) } else { (f, v) };
+        while t != 0 {
+            let (quotient, digit) = (t / 10, t % 10);
+            if digit != 0 {
+                break;
+            }
+            t = quotient;
+            w -= 1;
+        }
+        PluralOperands {
+            i,
+            v,
+            w,
+            f,
+            t,
+            ..PluralOperands::default()
+        }
+    }
+
+    /// Returns the integer digits (CLDR operand `i`).
+    pub fn integer_digits(&self) -> u64 {
+        self.i
+    }
+
+    /// Returns the number of visible fraction digits, with trailing zeros (CLDR operand `v`).
+    pub fn fraction_digit_count(&self) -> u64 {
+        self.v
+    }
+
+    /// Returns the number of visible fraction digits, without trailing zeros (CLDR operand `w`).
+    pub fn significant_fraction_digit_count(&self) -> u64 {
+        self.w
+    }
+
+    /// Returns the visible fraction digits, with trailing zeros (CLDR operand `f`).
+    pub fn fraction_digits(&self) -> u64 {
+        self.f
+    }
+
+    /// Returns the visible fraction digits, without trailing zeros (CLDR operand `t`).
+    pub fn significant_fraction_digits(&self) -> u64 {
+        self.t
+    }
+}
+
+impl FromStr for PluralOperands {
+    type Err = Error;
+
+    /// Parses a decimal string of the form `[+-]digits[.digits]`, where either the integer or
+    /// the fraction digits (but not both) may be omitted. Trailing zeros of the fraction are
+    /// significant (e.g. `"1.50"` has two visible fraction digits).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+        let (int_digits, frac_digits) = match unsigned.find('.') {
+            Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+            None => (unsigned, ""),
+        };
+        let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+        if (int_digits.is_empty() && frac_digits.is_empty())
+            || !is_digits(int_digits)
+            || !is_digits(frac_digits)
+        {
+            return Err(Error::parse_number(s));
+        }
+        let mut digits = Digits::default();
+        digits.push_str(int_digits);
+        if !frac_digits.is_empty() {
+            digits.push_str(".");
+            digits.push_str(frac_digits);
+        }
+        Ok(digits.operands())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&Decimal> for PluralOperands {
+    fn from(decimal: &Decimal) -> PluralOperands {
+        let mut digits = Digits::default();
+        digits.push_str(decimal.integer_digits());
+        if !decimal.fraction_digits().is_empty() {
+            digits.push_str(".");
+            digits.push_str(decimal.fraction_digits());
+        }
+        digits.operands()
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($type:ty) => {
+        impl From<$type> for PluralOperands {
+            #[allow(trivial_numeric_casts, unused_comparisons)]
+            fn from(n: $type) -> PluralOperands {
+                let n = if n < 0 {
+                    (!(n as u128)).wrapping_add(1) // make positive by adding 1 to the 2s complement
+                } else {
+                    n as u128
+                };
+                PluralOperands {
+                    i: cap(n),
+                    ..PluralOperands::default()
+                }
+            }
+        }
+    };
+}
+
+impl_from_integer!(u8);
+impl_from_integer!(u16);
+impl_from_integer!(u32);
+impl_from_integer!(usize);
+impl_from_integer!(u64);
+impl_from_integer!(u128);
+
+impl_from_integer!(i8);
+impl_from_integer!(i16);
+impl_from_integer!(i32);
+impl_from_integer!(isize);
+impl_from_integer!(i64);
+impl_from_integer!(i128);
+
+impl Locale {
+    /// Returns the cardinal plural category of a number as written (e.g. `PluralCategory::Few`
+    /// for Polish "22 pliki"), according to the CLDR plural rules of the locale's language.
+    ///
+    /// Accepts anything that converts into [`PluralOperands`]: any of the integer types in the
+    /// standard library, or operands constructed from a float or a decimal string. See
+    /// [`PluralOperands`] for an example.
+    ///
+    /// [`PluralOperands`]: struct.PluralOperands.html
+    pub fn plural_category<O>(&self, operands: O) -> PluralCategory
+    where
+        O: Into<PluralOperands>,
+    {
+        let operands = operands.into();
+        if operands.nonfinite {
+            return PluralCategory::Other;
+        }
+        self.cardinal_category(&operands)
+    }
+
+    /// Returns the ordinal plural category of a number (e.g. `PluralCategory::Two` for English
+    /// "22nd"), according to the CLDR ordinal plural rules of the locale's language.
+    ///
+    /// Accepts the same operands as [`plural_category`]. To write ordinals, see [`Ordinal`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use num_format::{Locale, PluralCategory};
+    ///
+    /// fn main() {
+    ///     assert_eq!(PluralCategory::One, Locale::en.ordinal_plural_category(21));
+    ///     assert_eq!(PluralCategory::Other, Locale::en.ordinal_plural_category(11));
+    ///     assert_eq!(PluralCategory::One, Locale::fr.ordinal_plural_category(1));
+    ///     assert_eq!(PluralCategory::Other, Locale::fr.ordinal_plural_category(2));
+    /// }
+    /// ```
+    ///
+    /// [`Ordinal`]: struct.Ordinal.html
+    /// [`plural_category`]: enum.Locale.html#method.plural_category
+    pub fn ordinal_plural_category<O>(&self, operands: O) -> PluralCategory
+    where
+        O: Into<PluralOperands>,
+    {
+        let operands = operands.into();
+        if operands.nonfinite {
+            return PluralCategory::Other;
+        }
+        self.ordinal_category(&operands)
+    }
+}
+
+fn cap(n: u128) -> u64 {
+    if n >= u128::from(CAP) {
+        (u128::from(CAP) + n % u128::from(CAP)) as u64
+    } else {
+        n as u64
+    }
+}
+
+/// Collects the plural operands of a number from its digits (and decimal point), given one
+/// piece at a time, keeping only the last 18 digits of each operand.
+#[derive(Default)]
+struct Digits {
+    i: u64,
+    v: u64,
+    f: u64,
+    t: u64,
+    // Fraction zeros not yet added to `t`, since they may be trailing
+    zeros: u64,
+    in_fraction: bool,
+}
+
+impl Digits {
+    fn push_str(&mut self, s: &str) {
+        for b in s.bytes() {
+            if b == b'.' {
+                self.in_fraction = true;
+            } else if self.in_fraction {
+                let digit = u64::from(b - b'0');
+                self.v += 1;
+                self.f = push_digit(self.f, digit);
+                if digit == 0 {
+                    self.zeros += 1;
+                } else {
+                    for _ in 0..self.zeros {
+                        self.t = push_digit(self.t, 0);
+                    }
+                    self.zeros = 0;
+                    self.t = push_digit(self.t, digit);
+                }
+            } else {
+                self.i = push_digit(self.i, u64::from(b - b'0'));
+            }
+        }
+    }
+
+    fn operands(&self) -> PluralOperands {
+        PluralOperands {
+            i: self.i,
+            v: self.v,
+            w: self.v - self.zeros,
+            f: self.f,
+            t: self.t,
+            ..PluralOperands::default()
+        }
+    }
+}
+
+impl Write for Digits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+/// Appends a digit to an operand, keeping it below 2 * 10^18 (see `CAP`).
+fn push_digit(n: u64, digit: u64) -> u64 {
+    let capped = n >= CAP;
+    let n = (n % CAP) * 10 + digit;
+    if capped || n >= CAP {
+        CAP + n % CAP
+    } else {
+        n
+    }
+}
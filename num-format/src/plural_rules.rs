@@ -0,0 +1,1060 @@
+#![doc = r"Note: This module was autogenerated by num-format-dev."]
+#![allow(clippy::all)]
+use crate::locale::Locale;
+use crate::plural::{PluralCategory, PluralOperands};
+impl Locale {
+    #[doc = r" Returns the locale's cardinal plural category for the given operands."]
+    pub(crate) fn cardinal_category(&self, op: &PluralOperands) -> PluralCategory {
+        use self::Locale::*;
+        match self {
+            af | af_NA | asa | az | az_Cyrl | az_Latn | bem | bez | bg | brx | ce | cgg | chr
+            | ckb | ckb_IR | ee | ee_TG | el | el_CY | eo | eu | fo | fo_DK | fur | gsw
+            | gsw_FR | gsw_LI | ha | ha_GH | ha_NE | haw | hu | jgo | jmc | ka | kk | kkj | kl
+            | ks | ksb | ku | ky | lb | lg | mas | mas_TZ | mgo | ml | mn | mr | nb | nb_SJ
+            | nd | ne | ne_IN | nn | nnh | nyn | om | om_KE | or | os | os_RU | ps | ps_PK | rm
+            | rof | rwk | saq | sd | seh | sn | so | so_DJ | so_ET | so_KE | sq | sq_MK | sq_XK
+            | ta | ta_LK | ta_MY | ta_SG | te | teo | teo_KE | tk | tr | tr_CY | ug | uz
+            | uz_Arab | uz_Cyrl | uz_Latn | vo | vun | wae | xh | xog => cardinal_0(op),
+            agq | bas | ccp | ccp_IN | cu | dav | dje | dua | dyo | ebu | ewo | guz | kam | khq
+            | ki | kln | kok | ksf | lrc | lrc_IQ | lu | luo | luy | mer | mfe | mgh | mi | mua
+            | mzn | nds | nds_NL | nmg | nus | qu | qu_BO | qu_EC | rn | rw | sbp | tg | tt
+            | twq | vai | vai_Latn | vai_Vaii | yav | zgh => cardinal_1(op),
+            ak | ln | ln_AO | ln_CF | ln_CG | mg | pa | pa_Arab | pa_Guru | ti | ti_ER => {
+                cardinal_2(op)
+            }
+            am | as_ | bn | bn_IN | fa | fa_AF | gu | hi | kn | zu => cardinal_3(op),
+            ar | ar_AE | ar_BH | ar_DJ | ar_DZ | ar_EG | ar_EH | ar_ER | ar_IL | ar_IQ | ar_JO
+            | ar_KM | ar_KW | ar_LB | ar_LY | ar_MA | ar_MR | ar_OM | ar_PS | ar_QA | ar_SA
+            | ar_SD | ar_SO | ar_SS | ar_SY | ar_TD | ar_TN | ar_YE => cardinal_4(op),
+            ast | de | de_AT | de_BE | de_CH | de_IT | de_LI | de_LU | en | en_001 | en_150
+            | en_AE | en_AG | en_AI | en_AS | en_AT | en_AU | en_BB | en_BE | en_BI | en_BM
+            | en_BS | en_BW | en_BZ | en_CA | en_CC | en_CH | en_CK | en_CM | en_CX | en_CY
+            | en_DE | en_DG | en_DK | en_DM | en_ER | en_FI | en_FJ | en_FK | en_FM | en_GB
+            | en_GD | en_GG | en_GH | en_GI | en_GM | en_GU | en_GY | en_HK | en_IE | en_IL
+            | en_IM | en_IN | en_IO | en_JE | en_JM | en_KE | en_KI | en_KN | en_KY | en_LC
+            | en_LR | en_LS | en_MG | en_MH | en_MO | en_MP | en_MS | en_MT | en_MU | en_MW
+            | en_MY | en_NA | en_NF | en_NG | en_NL | en_NR | en_NU | en_NZ | en_PG | en_PH
+            | en_PK | en_PN | en_PR | en_PW | en_RW | en_SB | en_SC | en_SD | en_SE | en_SG
+            | en_SH | en_SI | en_SL | en_SS | en_SX | en_SZ | en_TC | en_TK | en_TO | en_TT
+            | en_TV | en_TZ | en_UG | en_UM | en_US_POSIX | en_VC | en_VG | en_VI | en_VU
+            | en_WS | en_ZA | en_ZM | en_ZW | et | fi | fy | gl | ia | nl | nl_AW | nl_BE
+            | nl_BQ | nl_CW | nl_SR | nl_SX | sv | sv_AX | sv_FI | sw | sw_CD | sw_KE | sw_UG
+            | ur | ur_IN | yi => cardinal_5(op),
+            be => cardinal_6(op),
+            bm | bo | bo_IN | dz | id | ig | ii | ja | jv | kde | kea | km | ko | ko_KP | lkt
+            | lo | ms | ms_BN | ms_SG | my | root | sah | ses | sg | th | to | vi | wo | yo
+            | yo_BJ | yue | yue_Hans | yue_Hant | zh | zh_Hans | zh_Hans_HK | zh_Hans_MO
+            | zh_Hans_SG | zh_Hant | zh_Hant_HK | zh_Hant_MO => cardinal_7(op),
+            br => cardinal_8(op),
+            bs | bs_Cyrl | bs_Latn | hr | hr_BA | sr | sr_Cyrl | sr_Cyrl_BA | sr_Cyrl_ME
+            | sr_Cyrl_XK | sr_Latn | sr_Latn_BA | sr_Latn_ME | sr_Latn_XK => cardinal_9(op),
+            ca | ca_AD | ca_ES_VALENCIA | ca_FR | ca_IT | it | it_CH | it_SM | it_VA | pt_PT => {
+                cardinal_10(op)
+            }
+            ceb | fil => cardinal_11(op),
+            cs | sk => cardinal_12(op),
+            cy => cardinal_13(op),
+            da | da_GL => cardinal_14(op),
+            dsb | hsb => cardinal_15(op),
+            es | es_419 | es_AR | es_BO | es_BR | es_BZ | es_CL | es_CO | es_CR | es_CU | es_DO
+            | es_EA | es_EC | es_GQ | es_GT | es_HN | es_IC | es_MX | es_NI | es_PA | es_PE
+            | es_PH | es_PR | es_PY | es_SV | es_US | es_UY | es_VE => cardinal_16(op),
+            ff | ff_Latn | ff_Latn_BF | ff_Latn_CM | ff_Latn_GH | ff_Latn_GM | ff_Latn_GN
+            | ff_Latn_GW | ff_Latn_LR | ff_Latn_MR | ff_Latn_NE | ff_Latn_NG | ff_Latn_SL | hy
+            | kab => cardinal_17(op),
+            fr | fr_BE | fr_BF | fr_BI | fr_BJ | fr_BL | fr_CA | fr_CD | fr_CF | fr_CG | fr_CH
+            | fr_CI | fr_CM | fr_DJ | fr_DZ | fr_GA | fr_GF | fr_GN | fr_GP | fr_GQ | fr_HT
+            | fr_KM | fr_LU | fr_MA | fr_MC | fr_MF | fr_MG | fr_ML | fr_MQ | fr_MR | fr_MU
+            | fr_NC | fr_NE | fr_PF | fr_PM | fr_RE | fr_RW | fr_SC | fr_SN | fr_SY | fr_TD
+            | fr_TG | fr_TN | fr_VU | fr_WF | fr_YT => cardinal_18(op),
+            ga | ga_GB => cardinal_19(op),
+            gd => cardinal_20(op),
+            gv => cardinal_21(op),
+            he => cardinal_22(op),
+            is => cardinal_23(op),
+            ksh => cardinal_24(op),
+            kw => cardinal_25(op),
+            lag => cardinal_26(op),
+            lt => cardinal_27(op),
+            lv | prg => cardinal_28(op),
+            mk => cardinal_29(op),
+            mt => cardinal_30(op),
+            naq | se | se_FI | se_SE | smn => cardinal_31(op),
+            pl => cardinal_32(op),
+            pt | pt_AO | pt_CH | pt_CV | pt_GQ | pt_GW | pt_LU | pt_MO | pt_MZ | pt_ST | pt_TL => {
+                cardinal_33(op)
+            }
+            ro | ro_MD => cardinal_34(op),
+            ru | ru_BY | ru_KG | ru_KZ | ru_MD | ru_UA | uk => cardinal_35(op),
+            shi | shi_Latn | shi_Tfng => cardinal_36(op),
+            si => cardinal_37(op),
+            sl => cardinal_38(op),
+            tzm => cardinal_39(op),
+        }
+    }
+    #[doc = r" Returns the locale's ordinal plural category for the given operands."]
+    pub(crate) fn ordinal_category(&self, op: &PluralOperands) -> PluralCategory {
+        use self::Locale::*;
+        match self {
+            af | af_NA | am | ar | ar_AE | ar_BH | ar_DJ | ar_DZ | ar_EG | ar_EH | ar_ER
+            | ar_IL | ar_IQ | ar_JO | ar_KM | ar_KW | ar_LB | ar_LY | ar_MA | ar_MR | ar_OM
+            | ar_PS | ar_QA | ar_SA | ar_SD | ar_SO | ar_SS | ar_SY | ar_TD | ar_TN | ar_YE
+            | ast | bg | bs | bs_Cyrl | bs_Latn | ce | cs | da | da_GL | de | de_AT | de_BE
+            | de_CH | de_IT | de_LI | de_LU | dsb | el | el_CY | es | es_419 | es_AR | es_BO
+            | es_BR | es_BZ | es_CL | es_CO | es_CR | es_CU | es_DO | es_EA | es_EC | es_GQ
+            | es_GT | es_HN | es_IC | es_MX | es_NI | es_PA | es_PE | es_PH | es_PR | es_PY
+            | es_SV | es_US | es_UY | es_VE | et | eu | fa | fa_AF | fi | fy | gl | gsw
+            | gsw_FR | gsw_LI | he | hr | hr_BA | hsb | ia | id | is | ja | km | kn | ko
+            | ko_KP | ky | lt | lv | ml | mn | my | nb | nb_SJ | nl | nl_AW | nl_BE | nl_BQ
+            | nl_CW | nl_SR | nl_SX | pa | pa_Arab | pa_Guru | pl | prg | ps | ps_PK | pt
+            | pt_AO | pt_CH | pt_CV | pt_GQ | pt_GW | pt_LU | pt_MO | pt_MZ | pt_PT | pt_ST
+            | pt_TL | root | ru | ru_BY | ru_KG | ru_KZ | ru_MD | ru_UA | sd | si | sk | sl
+            | sr | sr_Cyrl | sr_Cyrl_BA | sr_Cyrl_ME | sr_Cyrl_XK | sr_Latn | sr_Latn_BA
+            | sr_Latn_ME | sr_Latn_XK | sw | sw_CD | sw_KE | sw_UG | ta | ta_LK | ta_MY | ta_SG
+            | te | th | tr | tr_CY | ur | ur_IN | uz | uz_Arab | uz_Cyrl | uz_Latn | yue
+            | yue_Hans | yue_Hant | zh | zh_Hans | zh_Hans_HK | zh_Hans_MO | zh_Hans_SG
+            | zh_Hant | zh_Hant_HK | zh_Hant_MO | zu => ordinal_0(op),
+            agq | ak | asa | bas | bem | bez | bm | bo | bo_IN | br | brx | ccp | ccp_IN | ceb
+            | cgg | chr | ckb | ckb_IR | cu | dav | dje | dua | dyo | dz | ebu | ee | ee_TG
+            | eo | ewo | ff | ff_Latn | ff_Latn_BF | ff_Latn_CM | ff_Latn_GH | ff_Latn_GM
+            | ff_Latn_GN | ff_Latn_GW | ff_Latn_LR | ff_Latn_MR | ff_Latn_NE | ff_Latn_NG
+            | ff_Latn_SL | fo | fo_DK | fur | guz | gv | ha | ha_GH | ha_NE | haw | ig | ii
+            | jgo | jmc | jv | kab | kam | kde | kea | khq | ki | kkj | kl | kln | kok | ks
+            | ksb | ksf | ksh | ku | lag | lb | lg | lkt | ln | ln_AO | ln_CF | ln_CG | lrc
+            | lrc_IQ | lu | luo | luy | mas | mas_TZ | mer | mfe | mg | mgh | mgo | mi | mt
+            | mua | mzn | naq | nd | nds | nds_NL | nmg | nn | nnh | nus | nyn | om | om_KE
+            | os | os_RU | qu | qu_BO | qu_EC | rm | rn | rof | rw | rwk | sah | saq | sbp | se
+            | se_FI | se_SE | seh | ses | sg | shi | shi_Latn | shi_Tfng | smn | sn | so
+            | so_DJ | so_ET | so_KE | teo | teo_KE | tg | ti | ti_ER | to | tt | twq | tzm | ug
+            | vai | vai_Latn | vai_Vaii | vo | vun | wae | wo | xh | xog | yav | yi | yo
+            | yo_BJ | zgh => ordinal_1(op),
+            as_ | bn | bn_IN => ordinal_2(op),
+            az | az_Cyrl | az_Latn => ordinal_3(op),
+            be => ordinal_4(op),
+            ca | ca_AD | ca_ES_VALENCIA | ca_FR | ca_IT => ordinal_5(op),
+            cy => ordinal_6(op),
+            en | en_001 | en_150 | en_AE | en_AG | en_AI | en_AS | en_AT | en_AU | en_BB
+            | en_BE | en_BI | en_BM | en_BS | en_BW | en_BZ | en_CA | en_CC | en_CH | en_CK
+            | en_CM | en_CX | en_CY | en_DE | en_DG | en_DK | en_DM | en_ER | en_FI | en_FJ
+            | en_FK | en_FM | en_GB | en_GD | en_GG | en_GH | en_GI | en_GM | en_GU | en_GY
+            | en_HK | en_IE | en_IL | en_IM | en_IN | en_IO | en_JE | en_JM | en_KE | en_KI
+            | en_KN | en_KY | en_LC | en_LR | en_LS | en_MG | en_MH | en_MO | en_MP | en_MS
+            | en_MT | en_MU | en_MW | en_MY | en_NA | en_NF | en_NG | en_NL | en_NR | en_NU
+            | en_NZ | en_PG | en_PH | en_PK | en_PN | en_PR | en_PW | en_RW | en_SB | en_SC
+            | en_SD | en_SE | en_SG | en_SH | en_SI | en_SL | en_SS | en_SX | en_SZ | en_TC
+            | en_TK | en_TO | en_TT | en_TV | en_TZ | en_UG | en_UM | en_US_POSIX | en_VC
+            | en_VG | en_VI | en_VU | en_WS | en_ZA | en_ZM | en_ZW => ordinal_7(op),
+            fil | fr | fr_BE | fr_BF | fr_BI | fr_BJ | fr_BL | fr_CA | fr_CD | fr_CF | fr_CG
+            | fr_CH | fr_CI | fr_CM | fr_DJ | fr_DZ | fr_GA | fr_GF | fr_GN | fr_GP | fr_GQ
+            | fr_HT | fr_KM | fr_LU | fr_MA | fr_MC | fr_MF | fr_MG | fr_ML | fr_MQ | fr_MR
+            | fr_MU | fr_NC | fr_NE | fr_PF | fr_PM | fr_RE | fr_RW | fr_SC | fr_SN | fr_SY
+            | fr_TD | fr_TG | fr_TN | fr_VU | fr_WF | fr_YT | ga | ga_GB | hy | lo | ms | ms_BN
+            | ms_SG | ro | ro_MD | vi => ordinal_8(op),
+            gd => ordinal_9(op),
+            gu | hi => ordinal_10(op),
+            hu => ordinal_11(op),
+            it | it_CH | it_SM | it_VA => ordinal_12(op),
+            ka => ordinal_13(op),
+            kk => ordinal_14(op),
+            kw => ordinal_15(op),
+            mk => ordinal_16(op),
+            mr => ordinal_17(op),
+            ne | ne_IN => ordinal_18(op),
+            or => ordinal_19(op),
+            sq | sq_MK | sq_XK => ordinal_20(op),
+            sv | sv_AX | sv_FI => ordinal_21(op),
+            tk => ordinal_22(op),
+            uk => ordinal_23(op),
+        }
+    }
+    #[doc = r" Returns the locale's plural category for a range from a value in the `start`"]
+    #[doc = r" category to a value in the `end` category."]
+    #[cfg(feature = "std")]
+    pub(crate) fn range_category(
+        &self,
+        start: PluralCategory,
+        end: PluralCategory,
+    ) -> PluralCategory {
+        use self::Locale::*;
+        match self {
+            af | af_NA | agq | asa | ast | bas | bem | bez | bg | bm | bo | bo_IN | br | brx
+            | ca | ca_AD | ca_ES_VALENCIA | ca_FR | ca_IT | ccp | ccp_IN | ce | ceb | cgg | chr
+            | ckb | ckb_IR | cu | dav | dje | dsb | dua | dyo | dz | ebu | ee | ee_TG | en
+            | en_001 | en_150 | en_AE | en_AG | en_AI | en_AS | en_AT | en_AU | en_BB | en_BE
+            | en_BI | en_BM | en_BS | en_BW | en_BZ | en_CA | en_CC | en_CH | en_CK | en_CM
+            | en_CX | en_CY | en_DE | en_DG | en_DK | en_DM | en_ER | en_FI | en_FJ | en_FK
+            | en_FM | en_GB | en_GD | en_GG | en_GH | en_GI | en_GM | en_GU | en_GY | en_HK
+            | en_IE | en_IL | en_IM | en_IN | en_IO | en_JE | en_JM | en_KE | en_KI | en_KN
+            | en_KY | en_LC | en_LR | en_LS | en_MG | en_MH | en_MO | en_MP | en_MS | en_MT
+            | en_MU | en_MW | en_MY | en_NA | en_NF | en_NG | en_NL | en_NR | en_NU | en_NZ
+            | en_PG | en_PH | en_PK | en_PN | en_PR | en_PW | en_RW | en_SB | en_SC | en_SD
+            | en_SE | en_SG | en_SH | en_SI | en_SL | en_SS | en_SX | en_SZ | en_TC | en_TK
+            | en_TO | en_TT | en_TV | en_TZ | en_UG | en_UM | en_US_POSIX | en_VC | en_VG
+            | en_VI | en_VU | en_WS | en_ZA | en_ZM | en_ZW | eo | es | es_419 | es_AR | es_BO
+            | es_BR | es_BZ | es_CL | es_CO | es_CR | es_CU | es_DO | es_EA | es_EC | es_GQ
+            | es_GT | es_HN | es_IC | es_MX | es_NI | es_PA | es_PE | es_PH | es_PR | es_PY
+            | es_SV | es_US | es_UY | es_VE | et | eu | ewo | ff | ff_Latn | ff_Latn_BF
+            | ff_Latn_CM | ff_Latn_GH | ff_Latn_GM | ff_Latn_GN | ff_Latn_GW | ff_Latn_LR
+            | ff_Latn_MR | ff_Latn_NE | ff_Latn_NG | ff_Latn_SL | fi | fo | fo_DK | fur | fy
+            | gd | guz | gv | ha | ha_GH | ha_NE | haw | he | hsb | ia | id | ig | ii | ja
+            | jgo | jmc | jv | kab | kam | kde | kea | khq | ki | kkj | kl | kln | km | ko
+            | ko_KP | kok | ks | ksb | ksf | ksh | ku | kw | lag | lb | lg | lkt | ln | ln_AO
+            | ln_CF | ln_CG | lo | lrc | lrc_IQ | lu | luo | luy | mas | mas_TZ | mer | mfe
+            | mg | mgh | mgo | mi | mk | ms | ms_BN | ms_SG | mt | mua | my | mzn | naq | nb
+            | nb_SJ | nd | nds | nds_NL | nmg | nn | nnh | nus | nyn | om | om_KE | os | os_RU
+            | prg | qu | qu_BO | qu_EC | rm | rn | rof | root | rw | rwk | sah | saq | sbp | se
+            | se_FI | se_SE | seh | ses | sg | shi | shi_Latn | shi_Tfng | smn | sn | so
+            | so_DJ | so_ET | so_KE | sv | sv_AX | sv_FI | teo | teo_KE | tg | th | ti | ti_ER
+            | to | tt | twq | tzm | ur | ur_IN | vai | vai_Latn | vai_Vaii | vi | vo | vun
+            | wae | wo | xh | xog | yav | yi | yo | yo_BJ | yue | yue_Hans | yue_Hant | zgh
+            | zh | zh_Hans | zh_Hans_HK | zh_Hans_MO | zh_Hans_SG | zh_Hant | zh_Hant_HK
+            | zh_Hant_MO => range_0(start, end),
+            ak | az | az_Cyrl | az_Latn | de | de_AT | de_BE | de_CH | de_IT | de_LI | de_LU
+            | el | el_CY | fa | fa_AF | gl | gsw | gsw_FR | gsw_LI | hu | it | it_CH | it_SM
+            | it_VA | kk | ky | ml | mn | ne | ne_IN | nl | nl_AW | nl_BE | nl_BQ | nl_CW
+            | nl_SR | nl_SX | or | sd | sq | sq_MK | sq_XK | sw | sw_CD | sw_KE | sw_UG | ta
+            | ta_LK | ta_MY | ta_SG | te | tk | tr | tr_CY | ug | uz | uz_Arab | uz_Cyrl
+            | uz_Latn => range_1(start, end),
+            am | as_ | bn | bn_IN | fr | fr_BE | fr_BF | fr_BI | fr_BJ | fr_BL | fr_CA | fr_CD
+            | fr_CF | fr_CG | fr_CH | fr_CI | fr_CM | fr_DJ | fr_DZ | fr_GA | fr_GF | fr_GN
+            | fr_GP | fr_GQ | fr_HT | fr_KM | fr_LU | fr_MA | fr_MC | fr_MF | fr_MG | fr_ML
+            | fr_MQ | fr_MR | fr_MU | fr_NC | fr_NE | fr_PF | fr_PM | fr_RE | fr_RW | fr_SC
+            | fr_SN | fr_SY | fr_TD | fr_TG | fr_TN | fr_VU | fr_WF | fr_YT | gu | hi | hy | kn
+            | mr | ps | ps_PK | pt | pt_AO | pt_CH | pt_CV | pt_GQ | pt_GW | pt_LU | pt_MO
+            | pt_MZ | pt_PT | pt_ST | pt_TL | si | zu => range_2(start, end),
+            ar | ar_AE | ar_BH | ar_DJ | ar_DZ | ar_EG | ar_EH | ar_ER | ar_IL | ar_IQ | ar_JO
+            | ar_KM | ar_KW | ar_LB | ar_LY | ar_MA | ar_MR | ar_OM | ar_PS | ar_QA | ar_SA
+            | ar_SD | ar_SO | ar_SS | ar_SY | ar_TD | ar_TN | ar_YE => range_3(start, end),
+            be | lt | ru | ru_BY | ru_KG | ru_KZ | ru_MD | ru_UA | uk => range_4(start, end),
+            bs | bs_Cyrl | bs_Latn | hr | hr_BA | sr | sr_Cyrl | sr_Cyrl_BA | sr_Cyrl_ME
+            | sr_Cyrl_XK | sr_Latn | sr_Latn_BA | sr_Latn_ME | sr_Latn_XK => range_5(start, end),
+            cs | pl | sk => range_6(start, end),
+            cy => range_7(start, end),
+            da | da_GL | fil | is | pa | pa_Arab | pa_Guru => range_8(start, end),
+            ga | ga_GB => range_9(start, end),
+            ka => range_10(start, end),
+            lv => range_11(start, end),
+            ro | ro_MD => range_12(start, end),
+            sl => range_13(start, end),
+        }
+    }
+}
+fn cardinal_0(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_1(_op: &PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+fn cardinal_2(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && (0..=1).contains(&op.i) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_3(op: &PluralOperands) -> PluralCategory {
+    if op.i == 0 || (op.t == 0 && op.i == 1) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_4(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 0 {
+        return PluralCategory::Zero;
+    }
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && (3..=10).contains(&(op.i % 100)) {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && (11..=99).contains(&(op.i % 100)) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_5(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_6(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i % 10 == 1 && (op.t != 0 || op.i % 100 != 11) {
+        return PluralCategory::One;
+    }
+    if op.t == 0
+        && (2..=4).contains(&(op.i % 10))
+        && (op.t != 0 || !(12..=14).contains(&(op.i % 100)))
+    {
+        return PluralCategory::Few;
+    }
+    if (op.t == 0 && op.i % 10 == 0)
+        || (op.t == 0 && (5..=9).contains(&(op.i % 10)))
+        || (op.t == 0 && (11..=14).contains(&(op.i % 100)))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_7(_op: &PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+fn cardinal_8(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i % 10 == 1 && (op.t != 0 || !matches!(op.i % 100, 11 | 71 | 91)) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i % 10 == 2 && (op.t != 0 || !matches!(op.i % 100, 12 | 72 | 92)) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0
+        && matches!(op.i % 10, 3..=4 | 9)
+        && (op.t != 0 || !matches ! (op . i % 100 , 10 ..= 19 | 70 ..= 79 | 90 ..= 99))
+    {
+        return PluralCategory::Few;
+    }
+    if (op.t != 0 || op.i != 0) && op.t == 0 && op.i % 1000000 == 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_9(op: &PluralOperands) -> PluralCategory {
+    if (op.v == 0 && op.i % 10 == 1 && op.i % 100 != 11) || (op.f % 10 == 1 && op.f % 100 != 11) {
+        return PluralCategory::One;
+    }
+    if (op.v == 0 && (2..=4).contains(&(op.i % 10)) && !(12..=14).contains(&(op.i % 100)))
+        || ((2..=4).contains(&(op.f % 10)) && !(12..=14).contains(&(op.f % 100)))
+    {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn cardinal_10(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        return PluralCategory::One;
+    }
+    if (op.e == 0 && op.i != 0 && op.i % 1000000 == 0 && op.v == 0) || !(0..=5).contains(&op.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_11(op: &PluralOperands) -> PluralCategory {
+    if (op.v == 0 && matches!(op.i, 1 | 2 | 3))
+        || (op.v == 0 && !matches!(op.i % 10, 4 | 6 | 9))
+        || (op.v != 0 && !matches!(op.f % 10, 4 | 6 | 9))
+    {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_12(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        return PluralCategory::One;
+    }
+    if (2..=4).contains(&op.i) && op.v == 0 {
+        return PluralCategory::Few;
+    }
+    if op.v != 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_13(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 0 {
+        return PluralCategory::Zero;
+    }
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i == 3 {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && op.i == 6 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_14(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && op.i == 1) || (op.t != 0 && matches!(op.i, 0 | 1)) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_15(op: &PluralOperands) -> PluralCategory {
+    if (op.v == 0 && op.i % 100 == 1) || op.f % 100 == 1 {
+        return PluralCategory::One;
+    }
+    if (op.v == 0 && op.i % 100 == 2) || op.f % 100 == 2 {
+        return PluralCategory::Two;
+    }
+    if (op.v == 0 && (3..=4).contains(&(op.i % 100))) || (3..=4).contains(&(op.f % 100)) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn cardinal_16(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if (op.e == 0 && op.i != 0 && op.i % 1000000 == 0 && op.v == 0) || !(0..=5).contains(&op.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_17(op: &PluralOperands) -> PluralCategory {
+    if matches!(op.i, 0 | 1) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_18(op: &PluralOperands) -> PluralCategory {
+    if matches!(op.i, 0 | 1) {
+        return PluralCategory::One;
+    }
+    if (op.e == 0 && op.i != 0 && op.i % 1000000 == 0 && op.v == 0) || !(0..=5).contains(&op.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_19(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && (3..=6).contains(&op.i) {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && (7..=10).contains(&op.i) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_20(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 1 | 11) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && matches!(op.i, 2 | 12) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && matches ! (op . i , 3 ..= 10 | 13 ..= 19) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn cardinal_21(op: &PluralOperands) -> PluralCategory {
+    if op.v == 0 && op.i % 10 == 1 {
+        return PluralCategory::One;
+    }
+    if op.v == 0 && op.i % 10 == 2 {
+        return PluralCategory::Two;
+    }
+    if op.v == 0 && matches!(op.i % 100, 0 | 20 | 40 | 60 | 80) {
+        return PluralCategory::Few;
+    }
+    if op.v != 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_22(op: &PluralOperands) -> PluralCategory {
+    if (op.i == 1 && op.v == 0) || (op.i == 0 && op.v != 0) {
+        return PluralCategory::One;
+    }
+    if op.i == 2 && op.v == 0 {
+        return PluralCategory::Two;
+    }
+    PluralCategory::Other
+}
+fn cardinal_23(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && op.i % 10 == 1 && op.i % 100 != 11) || (op.t % 10 == 1 && op.t % 100 != 11) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_24(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 0 {
+        return PluralCategory::Zero;
+    }
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_25(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 0 {
+        return PluralCategory::Zero;
+    }
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if (op.t == 0 && matches!(op.i % 100, 2 | 22 | 42 | 62 | 82))
+        || ((op.t == 0 && op.i % 1000 == 0)
+            && (op.t == 0 && matches!(op.i % 100000, 1000..=20000 | 40000 | 60000 | 80000)))
+        || ((op.t != 0 || op.i != 0) && (op.t == 0 && op.i % 1000000 == 100000))
+    {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && matches!(op.i % 100, 3 | 23 | 43 | 63 | 83) {
+        return PluralCategory::Few;
+    }
+    if (op.t != 0 || op.i != 1) && op.t == 0 && matches!(op.i % 100, 1 | 21 | 41 | 61 | 81) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_26(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 0 {
+        return PluralCategory::Zero;
+    }
+    if matches!(op.i, 0 | 1) && (op.t != 0 || op.i != 0) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_27(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i % 10 == 1 && (op.t != 0 || !(11..=19).contains(&(op.i % 100))) {
+        return PluralCategory::One;
+    }
+    if op.t == 0
+        && (2..=9).contains(&(op.i % 10))
+        && (op.t != 0 || !(11..=19).contains(&(op.i % 100)))
+    {
+        return PluralCategory::Few;
+    }
+    if op.f != 0 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_28(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && op.i % 10 == 0)
+        || (op.t == 0 && (11..=19).contains(&(op.i % 100)))
+        || (op.v == 2 && (11..=19).contains(&(op.f % 100)))
+    {
+        return PluralCategory::Zero;
+    }
+    if ((op.t == 0 && op.i % 10 == 1) && (op.t != 0 || op.i % 100 != 11))
+        || (op.v == 2 && op.f % 10 == 1 && op.f % 100 != 11)
+        || (op.v != 2 && op.f % 10 == 1)
+    {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_29(op: &PluralOperands) -> PluralCategory {
+    if (op.v == 0 && op.i % 10 == 1 && op.i % 100 != 11) || (op.f % 10 == 1 && op.f % 100 != 11) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_30(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    if (op.t == 0 && op.i == 0) || (op.t == 0 && (3..=10).contains(&(op.i % 100))) {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && (11..=19).contains(&(op.i % 100)) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_31(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    PluralCategory::Other
+}
+fn cardinal_32(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        return PluralCategory::One;
+    }
+    if op.v == 0 && (2..=4).contains(&(op.i % 10)) && !(12..=14).contains(&(op.i % 100)) {
+        return PluralCategory::Few;
+    }
+    if (op.v == 0 && op.i != 1 && (0..=1).contains(&(op.i % 10)))
+        || (op.v == 0 && (5..=9).contains(&(op.i % 10)))
+        || (op.v == 0 && (12..=14).contains(&(op.i % 100)))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_33(op: &PluralOperands) -> PluralCategory {
+    if (0..=1).contains(&op.i) {
+        return PluralCategory::One;
+    }
+    if (op.e == 0 && op.i != 0 && op.i % 1000000 == 0 && op.v == 0) || !(0..=5).contains(&op.e) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_34(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 && op.v == 0 {
+        return PluralCategory::One;
+    }
+    if op.v != 0
+        || (op.t == 0 && op.i == 0)
+        || ((op.t != 0 || op.i != 1) && (op.t == 0 && (1..=19).contains(&(op.i % 100))))
+    {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn cardinal_35(op: &PluralOperands) -> PluralCategory {
+    if op.v == 0 && op.i % 10 == 1 && op.i % 100 != 11 {
+        return PluralCategory::One;
+    }
+    if op.v == 0 && (2..=4).contains(&(op.i % 10)) && !(12..=14).contains(&(op.i % 100)) {
+        return PluralCategory::Few;
+    }
+    if (op.v == 0 && op.i % 10 == 0)
+        || (op.v == 0 && (5..=9).contains(&(op.i % 10)))
+        || (op.v == 0 && (11..=14).contains(&(op.i % 100)))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn cardinal_36(op: &PluralOperands) -> PluralCategory {
+    if op.i == 0 || (op.t == 0 && op.i == 1) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && (2..=10).contains(&op.i) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn cardinal_37(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && matches!(op.i, 0 | 1)) || (op.i == 0 && op.f == 1) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn cardinal_38(op: &PluralOperands) -> PluralCategory {
+    if op.v == 0 && op.i % 100 == 1 {
+        return PluralCategory::One;
+    }
+    if op.v == 0 && op.i % 100 == 2 {
+        return PluralCategory::Two;
+    }
+    if (op.v == 0 && (3..=4).contains(&(op.i % 100))) || op.v != 0 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn cardinal_39(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && (0..=1).contains(&op.i)) || (op.t == 0 && (11..=99).contains(&op.i)) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn ordinal_0(_op: &PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+fn ordinal_1(_op: &PluralOperands) -> PluralCategory {
+    PluralCategory::Other
+}
+fn ordinal_2(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 1 | 5 | 7 | 8 | 9 | 10) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && matches!(op.i, 2 | 3) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i == 4 {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && op.i == 6 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_3(op: &PluralOperands) -> PluralCategory {
+    if matches!(op.i % 10, 1 | 2 | 5 | 7 | 8) || matches!(op.i % 100, 20 | 50 | 70 | 80) {
+        return PluralCategory::One;
+    }
+    if matches!(op.i % 10, 3 | 4)
+        || matches!(
+            op.i % 1000,
+            100 | 200 | 300 | 400 | 500 | 600 | 700 | 800 | 900
+        )
+    {
+        return PluralCategory::Few;
+    }
+    if op.i == 0 || op.i % 10 == 6 || matches!(op.i % 100, 40 | 60 | 90) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_4(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i % 10, 2 | 3) && (op.t != 0 || !matches!(op.i % 100, 12 | 13)) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn ordinal_5(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 1 | 3) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i == 4 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn ordinal_6(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 0 | 7 | 8 | 9) {
+        return PluralCategory::Zero;
+    }
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i == 2 {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && matches!(op.i, 3 | 4) {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && matches!(op.i, 5 | 6) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_7(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i % 10 == 1 && (op.t != 0 || op.i % 100 != 11) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i % 10 == 2 && (op.t != 0 || op.i % 100 != 12) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i % 10 == 3 && (op.t != 0 || op.i % 100 != 13) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn ordinal_8(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn ordinal_9(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 1 | 11) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && matches!(op.i, 2 | 12) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && matches!(op.i, 3 | 13) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn ordinal_10(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && matches!(op.i, 2 | 3) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i == 4 {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && op.i == 6 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_11(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 1 | 5) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn ordinal_12(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 11 | 8 | 80 | 800) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_13(op: &PluralOperands) -> PluralCategory {
+    if op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.i == 0 || matches!(op.i % 100, 2..=20 | 40 | 60 | 80) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_14(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && op.i % 10 == 6)
+        || (op.t == 0 && op.i % 10 == 9)
+        || ((op.t == 0 && op.i % 10 == 0) && (op.t != 0 || op.i != 0))
+    {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_15(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && (1..=4).contains(&op.i))
+        || (op.t == 0
+            && matches ! (op . i % 100 , 1 ..= 4 | 21 ..= 24 | 41 ..= 44 | 61 ..= 64 | 81 ..= 84))
+    {
+        return PluralCategory::One;
+    }
+    if (op.t == 0 && op.i == 5) || (op.t == 0 && op.i % 100 == 5) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_16(op: &PluralOperands) -> PluralCategory {
+    if op.i % 10 == 1 && op.i % 100 != 11 {
+        return PluralCategory::One;
+    }
+    if op.i % 10 == 2 && op.i % 100 != 12 {
+        return PluralCategory::Two;
+    }
+    if matches!(op.i % 10, 7 | 8) && !matches!(op.i % 100, 17 | 18) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_17(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && matches!(op.i, 2 | 3) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i == 4 {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn ordinal_18(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && (1..=4).contains(&op.i) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn ordinal_19(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i, 1 | 5 | 7..=9) {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && matches!(op.i, 2 | 3) {
+        return PluralCategory::Two;
+    }
+    if op.t == 0 && op.i == 4 {
+        return PluralCategory::Few;
+    }
+    if op.t == 0 && op.i == 6 {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_20(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i == 1 {
+        return PluralCategory::One;
+    }
+    if op.t == 0 && op.i % 10 == 4 && (op.t != 0 || op.i % 100 != 14) {
+        return PluralCategory::Many;
+    }
+    PluralCategory::Other
+}
+fn ordinal_21(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && matches!(op.i % 10, 1 | 2) && (op.t != 0 || !matches!(op.i % 100, 11 | 12)) {
+        return PluralCategory::One;
+    }
+    PluralCategory::Other
+}
+fn ordinal_22(op: &PluralOperands) -> PluralCategory {
+    if (op.t == 0 && matches!(op.i % 10, 6 | 9)) || (op.t == 0 && op.i == 10) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn ordinal_23(op: &PluralOperands) -> PluralCategory {
+    if op.t == 0 && op.i % 10 == 3 && (op.t != 0 || op.i % 100 != 13) {
+        return PluralCategory::Few;
+    }
+    PluralCategory::Other
+}
+fn range_0(_start: PluralCategory, _end: PluralCategory) -> PluralCategory {
+    PluralCategory::Other
+}
+fn range_1(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_2(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::One, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_3(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Many, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Many, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Two, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Two, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Zero, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Zero, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Zero, PluralCategory::One) => PluralCategory::Zero,
+        (PluralCategory::Zero, PluralCategory::Two) => PluralCategory::Zero,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_4(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Few, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Many, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Many, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Many, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::One, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_5(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_6(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Many, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Many, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Many, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_7(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::One, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Other, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::Two, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Two, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Zero, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Zero, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Zero, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Zero, PluralCategory::Two) => PluralCategory::Two,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_8(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::One, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_9(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Many, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::One, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Many) => PluralCategory::Many,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Other, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::Two, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Two, PluralCategory::Many) => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_10(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::One, PluralCategory::Other) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_11(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::One, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::One,
+        (PluralCategory::Zero, PluralCategory::One) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_12(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::One) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+fn range_13(start: PluralCategory, end: PluralCategory) -> PluralCategory {
+    match (start, end) {
+        (PluralCategory::Few, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::One) => PluralCategory::Few,
+        (PluralCategory::Few, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::One, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::One) => PluralCategory::Few,
+        (PluralCategory::One, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::Other, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::One) => PluralCategory::Few,
+        (PluralCategory::Other, PluralCategory::Two) => PluralCategory::Two,
+        (PluralCategory::Two, PluralCategory::Few) => PluralCategory::Few,
+        (PluralCategory::Two, PluralCategory::One) => PluralCategory::Few,
+        (PluralCategory::Two, PluralCategory::Two) => PluralCategory::Two,
+        _ => PluralCategory::Other,
+    }
+}
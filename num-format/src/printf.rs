@@ -0,0 +1,398 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::Error;
+
+/// Type for specifying the conversion of a [`PrintfSpec`] (the letter at its end).
+///
+/// [`PrintfSpec`]: struct.PrintfSpec.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PrintfConversion {
+    /// Integers in base 10 (`d` or `i`)
+    Signed,
+    /// Integers in base 10, with negative integers written as their two's complement (`u`)
+    Unsigned,
+    /// Fixed-point notation (`f`, or `F` for `NAN` and `INF`)
+    Fixed {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Scientific notation (`e`, or `E` for an uppercase exponent)
+    Exponent {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Fixed-point or scientific notation depending on the exponent, without trailing zeros
+    /// (`g`, or `G` for uppercase)
+    General {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+    /// Integers in base 16, with negative integers written as their two's complement (`x`, or
+    /// `X` for uppercase)
+    Hex {
+        /// Whether or not to write in uppercase
+        uppercase: bool,
+    },
+}
+
+impl PrintfConversion {
+    /// Returns `true` if the conversion is only for integers (`d`, `i`, `u`, `x`, or `X`).
+    pub fn is_integer(self) -> bool {
+        matches!(
+            self,
+            PrintfConversion::Signed | PrintfConversion::Unsigned | PrintfConversion::Hex { .. }
+        )
+    }
+
+    fn from_char(c: char) -> Option<PrintfConversion> {
+        let conversion = match c {
+            'd' | 'i' => PrintfConversion::Signed,
+            'u' => PrintfConversion::Unsigned,
+            'f' | 'F' => PrintfConversion::Fixed {
+                uppercase: c == 'F',
+            },
+            'e' | 'E' => PrintfConversion::Exponent {
+                uppercase: c == 'E',
+            },
+            'g' | 'G' => PrintfConversion::General {
+                uppercase: c == 'G',
+            },
+            'x' | 'X' => PrintfConversion::Hex {
+                uppercase: c == 'X',
+            },
+            _ => return None,
+        };
+        Some(conversion)
+    }
+
+    fn to_char(self) -> char {
+        let (c, uppercase) = match self {
+            PrintfConversion::Signed => ('d', false),
+            PrintfConversion::Unsigned => ('u', false),
+            PrintfConversion::Fixed { uppercase } => ('f', uppercase),
+            PrintfConversion::Exponent { uppercase } => ('e', uppercase),
+            PrintfConversion::General { uppercase } => ('g', uppercase),
+            PrintfConversion::Hex { uppercase } => ('x', uppercase),
+        };
+        if uppercase {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }
+    }
+}
+
+/// Type for representing a C `printf` conversion specification (e.g. `%'.2f` or `%08x`).
+///
+/// A spec is written `%[flags][width][.precision][length]conversion`:
+///
+/// - `flags` are any of `'` (group integer digits with the grouping and separator of the
+///   [`Format`]), `-` (pad on the right), `+` (write a plus sign for positive numbers), ` `
+///   (write a space for positive numbers), `#` (the alternate form: a `0x` prefix for `x`, a
+///   decimal point that is always written for floats, and trailing zeros kept by `g`), and `0`
+///   (pad with zeros after the sign).
+/// - `width` is the minimum number of bytes (or characters) to write.
+/// - `precision` is the minimum number of digits for integers, the number of fraction digits
+///   for `f` and `e`, and the number of significant digits for `g`.
+/// - `length` is any of C's length modifiers (`hh`, `h`, `l`, `ll`, `L`, `q`, `j`, `z`, or `t`),
+///   which are ignored, since the type of a number says how large it is.
+/// - `conversion` is one of `d`, `i`, `u`, `f`, `F`, `e`, `E`, `g`, `G`, `x`, or `X` (see
+///   [`PrintfConversion`]).
+///
+/// Numbers are written with a spec by wrapping them in [`Printf`]. They come out byte for byte
+/// as glibc's `printf` writes them in a locale whose `LC_NUMERIC` has the decimal, grouping,
+/// and separator of the [`Format`] they're written with (e.g. [`Locale::en_US_POSIX`] for the
+/// C locale, [`Locale::en`] for `en_US.UTF-8`, or a [`SystemLocale`]). Like glibc, the width
+/// counts bytes for integer conversions but characters for float conversions, the `'` flag
+/// groups hexadecimal digits too, and signs are always written as `-` and `+`.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, Locale, Printf, PrintfSpec, ToFormattedString};
+///
+/// fn main() -> Result<(), Error> {
+///     let spec = PrintfSpec::new("%'.2f")?;
+///     let s = Printf::new(1234567.891, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("1,234,567.89", s);
+///
+///     let s = Printf::new(1234567.891, spec).to_formatted_string(&Locale::de);
+///     assert_eq!("1.234.567,89", s);
+///
+///     let spec: PrintfSpec = "%'+12lld".parse()?;
+///     let s = Printf::new(1234567, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("  +1,234,567", s);
+///
+///     let spec = PrintfSpec::new("%#010x")?;
+///     let s = Printf::new(-1i16, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("0x0000ffff", s);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`Locale::en`]: enum.Locale.html#variant.en
+/// [`Locale::en_US_POSIX`]: enum.Locale.html#variant.en_US_POSIX
+/// [`Printf`]: struct.Printf.html
+/// [`PrintfConversion`]: enum.PrintfConversion.html
+/// [`SystemLocale`]: struct.SystemLocale.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    all(feature = "with-serde", feature = "std"),
+    derive(Serialize, Deserialize),
+    serde(try_from = "&str", into = "String")
+)]
+pub struct PrintfSpec {
+    pub(crate) alt: bool,
+    pub(crate) conversion: PrintfConversion,
+    pub(crate) grp: bool,
+    pub(crate) left: bool,
+    pub(crate) plus: bool,
+    pub(crate) prec: Option<usize>,
+    pub(crate) space: bool,
+    pub(crate) width: usize,
+    pub(crate) zero: bool,
+}
+
+impl PrintfSpec {
+    /// Parses a [`PrintfSpec`] from a C `printf` conversion specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spec is not a single valid conversion specification (e.g. if it
+    /// takes its width from an argument with `*`).
+    ///
+    /// [`PrintfSpec`]: struct.PrintfSpec.html
+    pub fn new<S>(s: S) -> Result<PrintfSpec, Error>
+    where
+        S: AsRef<str>,
+    {
+        let s = s.as_ref();
+        parse(s).ok_or_else(|| Error::parse_printf_spec(s))
+    }
+
+    /// Returns `true` if the alternate form (`#`) is asked for; `false` otherwise.
+    pub fn alternate(&self) -> bool {
+        self.alt
+    }
+
+    /// Returns the [`PrintfConversion`].
+    ///
+    /// [`PrintfConversion`]: enum.PrintfConversion.html
+    pub fn conversion(&self) -> PrintfConversion {
+        self.conversion
+    }
+
+    /// Returns `true` if grouping (`'`) is asked for; `false` otherwise.
+    pub fn grouped(&self) -> bool {
+        self.grp
+    }
+
+    /// Returns `true` if padding on the right (`-`) is asked for; `false` otherwise.
+    pub fn left_justified(&self) -> bool {
+        self.left
+    }
+
+    /// Returns `true` if a plus sign for positive numbers (`+`) is asked for; `false`
+    /// otherwise.
+    pub fn plus_sign(&self) -> bool {
+        self.plus
+    }
+
+    /// Returns the precision, if one is given.
+    pub fn precision(&self) -> Option<usize> {
+        self.prec
+    }
+
+    /// Returns `true` if a space for positive numbers (` `) is asked for; `false` otherwise.
+    pub fn space_sign(&self) -> bool {
+        self.space
+    }
+
+    /// Returns the minimum number of bytes (or characters) to write (`0` if no width is given).
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns `true` if zero padding (`0`) is asked for; `false` otherwise.
+    pub fn zero_padded(&self) -> bool {
+        self.zero
+    }
+}
+
+impl fmt::Display for PrintfSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "%")?;
+        let flags = [
+            (self.grp, '\''),
+            (self.left, '-'),
+            (self.plus, '+'),
+            (self.space, ' '),
+            (self.alt, '#'),
+            (self.zero, '0'),
+        ];
+        for &(is_set, flag) in &flags {
+            if is_set {
+                write!(f, "{}", flag)?;
+            }
+        }
+        if self.width != 0 {
+            write!(f, "{}", self.width)?;
+        }
+        if let Some(prec) = self.prec {
+            write!(f, ".{}", prec)?;
+        }
+        write!(f, "{}", self.conversion.to_char())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PrintfSpec {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        PrintfSpec::new(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<PrintfSpec> for String {
+    fn from(spec: PrintfSpec) -> Self {
+        spec.to_string()
+    }
+}
+
+impl FromStr for PrintfSpec {
+    type Err = Error;
+
+    /// Same as [`PrintfSpec::new`].
+    ///
+    /// [`PrintfSpec::new`]: struct.PrintfSpec.html#method.new
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PrintfSpec::new(s)
+    }
+}
+
+/// Type for representing a number written with a C `printf` [`PrintfSpec`] (e.g. `1234567.891`
+/// with `%'.2f`). Implements [`ToFormattedString`] for `f32`, `f64`, and all the integer types
+/// in the standard library.
+///
+/// Integers written with a float conversion (`f`, `e`, or `g`) are converted into an `f64`
+/// first, as if they were cast to a `double`. Negative integers written with `u`, `x`, or `X` are
+/// written as their two's complement in the width of their type, as if they were cast to the
+/// matching unsigned C type. Floats written with an integer conversion, which C leaves
+/// undefined, are written as with `g`.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Locale, Printf, PrintfSpec, ToFormattedString};
+///
+/// fn main() {
+///     let spec = PrintfSpec::new("%u").unwrap();
+///     let s = Printf::new(-1i32, spec).to_formatted_string(&Locale::en);
+///     assert_eq!("4294967295", s);
+/// }
+/// ```
+///
+/// [`PrintfSpec`]: struct.PrintfSpec.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    all(feature = "with-serde", feature = "std"),
+    derive(Serialize, Deserialize)
+)]
+pub struct Printf<N> {
+    value: N,
+    spec: PrintfSpec,
+}
+
+impl<N> Printf<N> {
+    /// Constructs a [`Printf`] for the given value and spec.
+    ///
+    /// [`Printf`]: struct.Printf.html
+    pub fn new(value: N, spec: PrintfSpec) -> Printf<N> {
+        Printf { value, spec }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`PrintfSpec`].
+    ///
+    /// [`PrintfSpec`]: struct.PrintfSpec.html
+    pub fn spec(&self) -> &PrintfSpec {
+        &self.spec
+    }
+}
+
+fn parse(s: &str) -> Option<PrintfSpec> {
+    let mut chars = s.strip_prefix('%')?.chars().peekable();
+    let mut spec = PrintfSpec {
+        alt: false,
+        conversion: PrintfConversion::Signed,
+        grp: false,
+        left: false,
+        plus: false,
+        prec: None,
+        space: false,
+        width: 0,
+        zero: false,
+    };
+
+    // [flags], which can come in any order and be repeated
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\'' => spec.grp = true,
+            '-' => spec.left = true,
+            '+' => spec.plus = true,
+            ' ' => spec.space = true,
+            '#' => spec.alt = true,
+            '0' => spec.zero = true,
+            _ => break,
+        }
+        chars.next();
+    }
+
+    // [width][.precision], where a precision of just `.` is zero
+    spec.width = parse_int(&mut chars)?.unwrap_or(0);
+    if chars.peek() == Some(&'.') {
+        chars.next();
+        spec.prec = Some(parse_int(&mut chars)?.unwrap_or(0));
+    }
+
+    // [length]conversion
+    let mut c = chars.next()?;
+    if matches!(c, 'h' | 'l') && chars.peek() == Some(&c) {
+        chars.next();
+        c = chars.next()?;
+    } else if matches!(c, 'h' | 'l' | 'L' | 'q' | 'j' | 'z' | 't') {
+        c = chars.next()?;
+    }
+    spec.conversion = PrintfConversion::from_char(c)?;
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(spec)
+}
+
+/// Parses the digits at the front of `chars` into an integer, returning `Some(None)` if there
+/// are none and `None` if they overflow.
+fn parse_int<I>(chars: &mut core::iter::Peekable<I>) -> Option<Option<usize>>
+where
+    I: Iterator<Item = char>,
+{
+    let mut n: Option<usize> = None;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        let value = n
+            .unwrap_or(0)
+            .checked_mul(10)?
+            .checked_add(digit as usize)?;
+        n = Some(value);
+        chars.next();
+    }
+    Some(n)
+}
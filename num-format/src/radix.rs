@@ -0,0 +1,33 @@
+/// Type for specifying the base integers are written in by a [`RadixFormat`].
+///
+/// [`RadixFormat`]: struct.RadixFormat.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum Radix {
+    /// Base 2 (e.g. 0b1010_0000)
+    Binary,
+    /// Base 8 (e.g. 0o755)
+    Octal,
+    /// Base 16 (e.g. 0xDEAD_BEEF)
+    Hexadecimal,
+}
+
+impl Radix {
+    /// Returns the numeric base (2, 8, or 16).
+    pub fn base(self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Hexadecimal => 16,
+        }
+    }
+
+    /// Returns the number of bits represented by a single digit.
+    pub(crate) fn bits(self) -> u32 {
+        match self {
+            Radix::Binary => 1,
+            Radix::Octal => 3,
+            Radix::Hexadecimal => 4,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::strings::{PreString, SepString};
+use crate::{Radix, RadixFormatBuilder};
+
+/// Type for specifying how integers are written in binary, octal, or hexadecimal by a
+/// [`Radixed`]: their [`Radix`], prefix, digit grouping, and case.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, Locale, Radix, RadixFormat, Radixed, ToFormattedString};
+///
+/// fn main() -> Result<(), Error> {
+///     let format = RadixFormat::builder()
+///         .radix(Radix::Hexadecimal)
+///         .group_size(4)
+///         .separator("_")
+///         .prefix("0x")
+///         .uppercase(true)
+///         .build()?;
+///
+///     let s = Radixed::new(0b1010_0000_u8, format).to_formatted_string(&Locale::en);
+///     assert_eq!("0xA0", s);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Radix`]: enum.Radix.html
+/// [`Radixed`]: struct.Radixed.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct RadixFormat {
+    pub(crate) grp: u8,
+    pub(crate) pre: PreString,
+    pub(crate) rdx: Radix,
+    pub(crate) sep: SepString,
+    pub(crate) upper: bool,
+}
+
+impl RadixFormat {
+    /// Constructs a [`RadixFormatBuilder`].
+    ///
+    /// [`RadixFormatBuilder`]: struct.RadixFormatBuilder.html
+    pub fn builder() -> RadixFormatBuilder {
+        RadixFormatBuilder::new()
+    }
+
+    /// Turns `self` into a [`RadixFormatBuilder`].
+    ///
+    /// [`RadixFormatBuilder`]: struct.RadixFormatBuilder.html
+    pub fn into_builder(self) -> RadixFormatBuilder {
+        self.into()
+    }
+
+    /// Returns the number of digits in each group, or `0` if digits are not grouped.
+    pub fn group_size(&self) -> u8 {
+        self.grp
+    }
+
+    /// Returns the string written before the digits (e.g. `"0x"`).
+    pub fn prefix(&self) -> &str {
+        &self.pre
+    }
+
+    /// Returns the [`Radix`] integers are written in.
+    ///
+    /// [`Radix`]: enum.Radix.html
+    pub fn radix(&self) -> Radix {
+        self.rdx
+    }
+
+    /// Returns the string written between groups of digits.
+    pub fn separator(&self) -> &str {
+        &self.sep
+    }
+
+    /// Returns `true` if hexadecimal digits are written in uppercase; `false` otherwise.
+    pub fn uppercase(&self) -> bool {
+        self.upper
+    }
+}
+
+impl Default for RadixFormat {
+    /// Returns a `RadixFormat` that writes lowercase hexadecimal digits after a `"0x"` prefix,
+    /// without grouping (the same as the standard library's `{:#x}`).
+    fn default() -> Self {
+        RadixFormatBuilder::new().build().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "with-serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+        let format = RadixFormat::builder().build().unwrap();
+        let s = serde_json::to_string(&format).unwrap();
+        let expected = r#"{"grp":0,"pre":"0x","rdx":"Hexadecimal","sep":"_","upper":false}"#;
+        assert_eq!(expected, &s);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::Error;
+use crate::radix::Radix;
+use crate::radix_format::RadixFormat;
+use crate::strings::{PreString, SepString};
+
+/// Type for building [`RadixFormat`]s.
+///
+/// [`RadixFormat`]: struct.RadixFormat.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct RadixFormatBuilder {
+    grp: u8,
+    pre: Result<PreString, Error>,
+    rdx: Radix,
+    sep: Result<SepString, Error>,
+    upper: bool,
+}
+
+impl RadixFormatBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            grp: 0,
+            pre: PreString::new("0x"),
+            rdx: Radix::Hexadecimal,
+            sep: SepString::new("_"),
+            upper: false,
+        }
+    }
+
+    /// Construct a [`RadixFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Return an error if:
+    /// - The "prefix" is longer than 8 bytes
+    /// - The "separator" is longer than 8 bytes
+    ///
+    /// [`RadixFormat`]: struct.RadixFormat.html
+    pub fn build(self) -> Result<RadixFormat, Error> {
+        Ok(RadixFormat {
+            grp: self.grp,
+            pre: self.pre?,
+            rdx: self.rdx,
+            sep: self.sep?,
+            upper: self.upper,
+        })
+    }
+
+    /// Sets the number of digits in each group. `0` (the default) disables grouping.
+    pub fn group_size(mut self, value: u8) -> Self {
+        self.grp = value;
+        self
+    }
+
+    /// Sets the string written before the digits (e.g. `"0x"`, `"0b"`, or `""`).
+    pub fn prefix<S>(mut self, s: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.pre = PreString::new(s);
+        self
+    }
+
+    /// Sets the [`Radix`] integers are written in.
+    ///
+    /// [`Radix`]: enum.Radix.html
+    pub fn radix(mut self, value: Radix) -> Self {
+        self.rdx = value;
+        self
+    }
+
+    /// Sets the string written between groups of digits.
+    pub fn separator<S>(mut self, s: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.sep = SepString::new(s);
+        self
+    }
+
+    /// Sets whether hexadecimal digits are written in uppercase (`DEAD`) or lowercase (`dead`).
+    pub fn uppercase(mut self, value: bool) -> Self {
+        self.upper = value;
+        self
+    }
+}
+
+impl From<RadixFormat> for RadixFormatBuilder {
+    fn from(format: RadixFormat) -> Self {
+        Self {
+            grp: format.grp,
+            pre: Ok(format.pre),
+            rdx: format.rdx,
+            sep: Ok(format.sep),
+            upper: format.upper,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::RadixFormat;
+
+/// Type for representing an integer written in binary, octal, or hexadecimal. Implements
+/// [`ToFormattedString`] for all the integer types in the standard library (including `NonZero`
+/// integers) and, with the `with-num-bigint` feature, for `BigInt` and `BigUint`.
+///
+/// The integer is written as its [`RadixFormat`] says, with digits grouped from the right.
+/// Negative primitive integers are written as their two's complement bit pattern, just like the
+/// standard library's `{:x}`; negative `BigInt`s are written with the minus sign of the
+/// [`Format`].
+///
+/// # Example
+/// ```rust
+/// use num_format::{Error, Locale, Radix, RadixFormat, Radixed, ToFormattedString};
+///
+/// fn main() -> Result<(), Error> {
+///     let format = RadixFormat::builder()
+///         .radix(Radix::Hexadecimal)
+///         .group_size(4)
+///         .separator("_")
+///         .prefix("0x")
+///         .uppercase(true)
+///         .build()?;
+///
+///     let s = Radixed::new(0xdead_beef_u32, format.clone()).to_formatted_string(&Locale::en);
+///     assert_eq!("0xDEAD_BEEF", s);
+///
+///     let s = Radixed::new(-1i16, format).to_formatted_string(&Locale::en);
+///     assert_eq!("0xFFFF", s);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`RadixFormat`]: struct.RadixFormat.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Radixed<N> {
+    value: N,
+    format: RadixFormat,
+}
+
+impl<N> Radixed<N> {
+    /// Constructs a [`Radixed`] for the given value, which is written as the [`RadixFormat`]
+    /// says.
+    ///
+    /// [`RadixFormat`]: struct.RadixFormat.html
+    /// [`Radixed`]: struct.Radixed.html
+    pub fn new(value: N, format: RadixFormat) -> Radixed<N> {
+        Radixed { value, format }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`RadixFormat`] the value is written with.
+    ///
+    /// [`RadixFormat`]: struct.RadixFormat.html
+    pub fn format(&self) -> &RadixFormat {
+        &self.format
+    }
+}
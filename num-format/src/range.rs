@@ -0,0 +1,150 @@
+use crate::locale::Locale;
+
+/// Type for representing a range of numbers written with the range pattern of a [`Locale`]
+/// (e.g. `1,000–5,000`, `3–5 km`, or `3–5 %`). Implements [`ToFormattedString`] for `f32`,
+/// `f64`, and all the integer types in the standard library, and for [`Measure`]s of them (but
+/// not [`ToFormattedStr`], as a range of two floats may not fit into a [`Buffer`]).
+///
+/// The start and end are written with the minus sign, grouping, and decimal of the [`Format`]
+/// they are formatted with, then put into the CLDR range pattern of the range's [`Locale`].
+/// [`Measure`]s keep the unit patterns of their own locales. As in CLDR, what the start and end
+/// have in common is only written once:
+///
+/// * A unit shared by two [`Measure`]s of the same locale is written after the range (e.g.
+///   `1–5 km` rather than `1 km–5 km`), in the plural form CLDR gives the range (e.g.
+///   `0–1 meters`, but French `0–1 mètre`).
+/// * A minus sign shared by two negative numbers is written before the range if it is more
+///   than one character long (e.g. a Hebrew minus sign, which has a left-to-right mark).
+///
+/// Anything else is written with both the start and the end, with spaces around the range
+/// separator if the start has any (e.g. `-5 – -3` or `1 km – 1 mi`). If the start and end are
+/// written the same, the range is written as an [`Approximately`] of the start (e.g. `~5`).
+///
+/// # Example
+/// ```rust
+/// use num_format::{Locale, Measure, MeasureUnit, Range, ToFormattedString, UnitWidth};
+///
+/// fn main() {
+///     let range = Range::new(1_000, 5_000, Locale::en);
+///     assert_eq!("1,000–5,000", range.to_formatted_string(&Locale::en));
+///     let range = Range::new(-5, -3, Locale::en);
+///     assert_eq!("-5 – -3", range.to_formatted_string(&Locale::en));
+///     let range = Range::new(5, 5, Locale::de);
+///     assert_eq!("≈5", range.to_formatted_string(&Locale::de));
+///
+///     let start = Measure::new(1, MeasureUnit::Meter, UnitWidth::Long, Locale::fr);
+///     let end = Measure::new(5, MeasureUnit::Meter, UnitWidth::Long, Locale::fr);
+///     let range = Range::new(start, end, Locale::fr);
+///     assert_eq!("1–5\u{a0}mètres", range.to_formatted_string(&Locale::fr));
+///
+///     // Percentages are measures in percent
+///     let start = Measure::new(3, MeasureUnit::Percent, UnitWidth::Short, Locale::de);
+///     let end = Measure::new(5, MeasureUnit::Percent, UnitWidth::Short, Locale::de);
+///     let range = Range::new(start, end, Locale::de);
+///     assert_eq!("3–5 %", range.to_formatted_string(&Locale::de));
+/// }
+/// ```
+///
+/// [`Approximately`]: struct.Approximately.html
+/// [`Buffer`]: struct.Buffer.html
+/// [`Format`]: trait.Format.html
+/// [`Locale`]: enum.Locale.html
+/// [`Measure`]: struct.Measure.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Range<N> {
+    start: N,
+    end: N,
+    locale: Locale,
+}
+
+impl<N> Range<N> {
+    /// Constructs a [`Range`] from `start` to `end`, written with the range pattern of the given
+    /// [`Locale`]. They are written in the order given, even if `start` is greater than `end`.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Range`]: struct.Range.html
+    pub fn new(start: N, end: N, locale: Locale) -> Range<N> {
+        Range { start, end, locale }
+    }
+
+    /// Returns the start of the range.
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Returns the end of the range.
+    pub fn end(&self) -> &N {
+        &self.end
+    }
+
+    /// Returns the [`Locale`] whose range pattern the range is written with.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+}
+
+/// Type for representing an approximate number written with the approximately sign of a
+/// [`Locale`] (e.g. `~5`, `≈5`, or `~1 meter`). Implements [`ToFormattedString`] for `f32`,
+/// `f64`, and all the integer types in the standard library, and for [`Measure`]s of them (but
+/// not [`ToFormattedStr`], like [`Range`]).
+///
+/// The approximately sign of its [`Locale`] is written before the minus sign of a negative
+/// number (e.g. `~-5`) and inside the unit pattern of a [`Measure`] (e.g. `~1 meter`).
+///
+/// # Example
+/// ```rust
+/// use num_format::{Approximately, Locale, Measure, MeasureUnit, ToFormattedString, UnitWidth};
+///
+/// fn main() {
+///     let value = Approximately::new(5, Locale::en);
+///     assert_eq!("~5", value.to_formatted_string(&Locale::en));
+///     let value = Approximately::new(-5, Locale::de);
+///     assert_eq!("≈-5", value.to_formatted_string(&Locale::de));
+///     let value = Approximately::new(5_000, Locale::ja);
+///     assert_eq!("約5,000", value.to_formatted_string(&Locale::ja));
+///
+///     let distance = Measure::new(1, MeasureUnit::Meter, UnitWidth::Long, Locale::en);
+///     let value = Approximately::new(distance, Locale::en);
+///     assert_eq!("~1 meter", value.to_formatted_string(&Locale::en));
+/// }
+/// ```
+///
+/// [`Locale`]: enum.Locale.html
+/// [`Measure`]: struct.Measure.html
+/// [`Range`]: struct.Range.html
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Approximately<N> {
+    value: N,
+    locale: Locale,
+}
+
+impl<N> Approximately<N> {
+    /// Constructs an [`Approximately`] for the given value, written with the approximately sign
+    /// of the given [`Locale`].
+    ///
+    /// [`Approximately`]: struct.Approximately.html
+    /// [`Locale`]: enum.Locale.html
+    pub fn new(value: N, locale: Locale) -> Approximately<N> {
+        Approximately { value, locale }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`Locale`] whose approximately sign the value is written with.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+}
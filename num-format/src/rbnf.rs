@@ -0,0 +1,268 @@
+use core::str;
+
+use crate::buffer::Buffer;
+use crate::constants::{MAX_BUF_LEN, U128_MAX_LEN};
+use crate::format::{write_decimal, Format, Plain};
+use crate::locale::Locale;
+use crate::plural::{PluralCategory, PluralOperands};
+use crate::rbnf_rules::RULE_SETS;
+
+/// A list of rules, one of which writes any given integer.
+pub(crate) struct RuleSet {
+    /// The rule for negative numbers, which is given the absolute value. If there is none, the
+    /// rules for non-negative numbers are chosen by the absolute value and pass the number on.
+    pub(crate) negative: Option<&'static [Part]>,
+    /// The rules for non-negative numbers, ordered by base value.
+    pub(crate) rules: &'static [Rule],
+}
+
+/// A rule for the integers from its base value up to the next rule's base value.
+pub(crate) struct Rule {
+    pub(crate) base: u64,
+    /// The power of ten (or of another radix) that substitutions of the remainder divide by.
+    pub(crate) divisor: u64,
+    pub(crate) parts: &'static [Part],
+}
+
+/// A piece of a rule.
+pub(crate) enum Part {
+    /// Literal text.
+    Text(&'static str),
+    /// The format's minus sign.
+    Minus,
+    /// The number divided by the rule's divisor.
+    Quotient(Target),
+    /// The remainder of the number divided by the rule's divisor.
+    Remainder(Target),
+    /// The number itself.
+    Same(Target),
+    /// The text for the ordinal plural category of the number, indexed by category (an empty
+    /// string means "use other").
+    Ordinal(&'static [&'static str; 6]),
+    /// The text for the cardinal plural category of the number divided by the rule's divisor,
+    /// indexed by category (an empty string means "use other").
+    Cardinal(&'static [&'static str; 6]),
+}
+
+/// What a substitution is written with.
+pub(crate) enum Target {
+    /// The rule set the rule belongs to.
+    Owner,
+    /// The rule set at the index in `RULE_SETS`.
+    Set(usize),
+    /// The digits, grouped with the format's separator if `true`.
+    Digits(bool),
+}
+
+/// A non-negative integer that rule sets can write.
+pub(crate) trait RuleValue: Sized {
+    /// Returns `true` if the value is at least `base`.
+    fn is_at_least(&self, base: u64) -> bool;
+
+    /// Returns the quotient and remainder of the value divided by `divisor`.
+    fn div_rem(&self, divisor: u64) -> (Self, Self);
+
+    /// Returns the plural operands of the value.
+    fn plural_operands(&self) -> PluralOperands;
+
+    /// Calls `f` with the decimal digits of the value.
+    fn with_digits<G>(&self, f: G)
+    where
+        G: FnOnce(&str);
+}
+
+impl RuleValue for u128 {
+    fn is_at_least(&self, base: u64) -> bool {
+        *self >= u128::from(base)
+    }
+
+    fn div_rem(&self, divisor: u64) -> (u128, u128) {
+        let divisor = u128::from(divisor);
+        (self / divisor, self % divisor)
+    }
+
+    fn plural_operands(&self) -> PluralOperands {
+        PluralOperands::from(*self)
+    }
+
+    fn with_digits<G>(&self, f: G)
+    where
+        G: FnOnce(&str),
+    {
+        let mut n = *self;
+        let mut digits = [b'0'; U128_MAX_LEN];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        f(unsafe { str::from_utf8_unchecked(&digits[start..]) })
+    }
+}
+
+/// Writes integers with rule sets into `w` piece by piece, using the plural rules of `locale`
+/// and the minus sign and grouping of `format`.
+pub(crate) struct RuleWriter<'a, F, W> {
+    pub(crate) locale: Locale,
+    pub(crate) format: &'a F,
+    pub(crate) w: W,
+}
+
+impl<'a, F, W> RuleWriter<'a, F, W>
+where
+    F: Format,
+    W: FnMut(&str),
+{
+    /// Writes `n`, negated if `is_negative` is `true`, with the rule set.
+    pub(crate) fn write<N>(&mut self, rule_set: &RuleSet, is_negative: bool, n: &N)
+    where
+        N: RuleValue,
+    {
+        if is_negative {
+            if let Some(parts) = rule_set.negative {
+                self.write_parts(rule_set, parts, 1, false, n);
+                return;
+            }
+        }
+        let rule = find_rule(rule_set, n);
+        self.write_parts(rule_set, rule.parts, rule.divisor, is_negative, n);
+    }
+
+    fn write_parts<N>(
+        &mut self,
+        rule_set: &RuleSet,
+        parts: &[Part],
+        divisor: u64,
+        is_negative: bool,
+        n: &N,
+    ) where
+        N: RuleValue,
+    {
+        for part in parts {
+            match part {
+                Part::Text(s) => (self.w)(s),
+                Part::Minus => (self.w)(self.format.minus_sign().into_str()),
+                Part::Quotient(target) => {
+                    let (quotient, _) = n.div_rem(divisor);
+                    self.write_target(rule_set, target, false, &quotient);
+                }
+                Part::Remainder(target) => {
+                    let (_, remainder) = n.div_rem(divisor);
+                    self.write_target(rule_set, target, false, &remainder);
+                }
+                Part::Same(target) => self.write_target(rule_set, target, is_negative, n),
+                Part::Ordinal(forms) => {
+                    let operands = n.div_rem(divisor).0.plural_operands();
+                    self.write_form(forms, self.locale.ordinal_category(&operands));
+                }
+                Part::Cardinal(forms) => {
+                    let operands = n.div_rem(divisor).0.plural_operands();
+                    self.write_form(forms, self.locale.cardinal_category(&operands));
+                }
+            }
+        }
+    }
+
+    fn write_target<N>(&mut self, rule_set: &RuleSet, target: &Target, is_negative: bool, n: &N)
+    where
+        N: RuleValue,
+    {
+        match target {
+            Target::Owner => self.write(rule_set, is_negative, n),
+            Target::Set(i) => self.write(&RULE_SETS[*i], is_negative, n),
+            Target::Digits(grouped) => self.write_digits(*grouped, is_negative, n),
+        }
+    }
+
+    fn write_form(&mut self, forms: &[&str; 6], category: PluralCategory) {
+        match forms[category as usize] {
+            "" => (self.w)(forms[PluralCategory::Other as usize]),
+            form => (self.w)(form),
+        }
+    }
+
+    fn write_digits<N>(&mut self, grouped: bool, is_negative: bool, n: &N)
+    where
+        N: RuleValue,
+    {
+        n.with_digits(|digits| {
+            if grouped {
+                write_decimal(is_negative, digits, "", &Plain(self.format), &mut self.w);
+            } else {
+                if is_negative {
+                    (self.w)(self.format.minus_sign().into_str());
+                }
+                (self.w)(digits);
+            }
+        });
+    }
+}
+
+/// Writes `n`, negated if `is_negative` is `true`, with the rule set at the end of the buffer,
+/// returning the number of bytes written.
+pub(crate) fn write_to_buffer<F>(
+    rule_set: &RuleSet,
+    locale: Locale,
+    is_negative: bool,
+    n: u128,
+    buf: &mut Buffer,
+    format: &F,
+) -> usize
+where
+    F: Format,
+{
+    // Measure, then write so that the output ends at the end of the buffer
+    let mut len = 0;
+    let mut writer = RuleWriter {
+        locale,
+        format,
+        w: |s: &str| len += s.len(),
+    };
+    writer.write(rule_set, is_negative, &n);
+    let start = MAX_BUF_LEN - len;
+    let mut index = start;
+    let mut writer = RuleWriter {
+        locale,
+        format,
+        w: |s: &str| {
+            buf.inner[index..index + s.len()].copy_from_slice(s.as_bytes());
+            index += s.len();
+        },
+    };
+    writer.write(rule_set, is_negative, &n);
+
+    buf.pos = start;
+    buf.end = MAX_BUF_LEN;
+    len
+}
+
+/// Returns the rule with the largest base value that isn't larger than `n`, except that a rule
+/// whose remainder would be written as zero (e.g. "two hundred zero" for 200) rolls back to the
+/// rule before it (e.g. "two hundred").
+fn find_rule<'a, N>(rule_set: &'a RuleSet, n: &N) -> &'a Rule
+where
+    N: RuleValue,
+{
+    let rules = rule_set.rules;
+    let i = rules
+        .iter()
+        .rposition(|rule| n.is_at_least(rule.base))
+        .unwrap_or(0);
+    let rule = &rules[i];
+    let has_remainder = rule
+        .parts
+        .iter()
+        .any(|part| matches!(part, Part::Remainder(_)));
+    let base_remainder = rule.base % rule.divisor;
+    if i > 0 && has_remainder && base_remainder != 0 {
+        let (_, remainder) = n.div_rem(rule.divisor);
+        if !remainder.is_at_least(1) {
+            return &rules[i - 1];
+        }
+    }
+    rule
+}
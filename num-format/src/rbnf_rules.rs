@@ -0,0 +1,7131 @@
+#![doc = r"Note: This module was autogenerated by num-format-dev."]
+use crate::locale::Locale;
+use crate::rbnf::{Part, Rule, RuleSet, Target};
+use crate::strings::{MAX_MIN_LEN, MAX_SEP_LEN};
+#[doc = r" The length of the longest ordinal of any integer type."]
+pub(crate) const MAX_ORDINAL_LEN: usize = 51 + 18 * MAX_SEP_LEN + MAX_MIN_LEN;
+#[doc = r" The length of the longest spelled out number of any integer type."]
+pub(crate) const MAX_SPELLOUT_LEN: usize = 634 + 18 * MAX_SEP_LEN;
+#[doc = r" The rule sets of every locale, which refer to each other by index."]
+pub(crate) static RULE_SETS: [RuleSet; 98] = [
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Same(Target::Set(1))],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("ste")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("ste")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("de")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("ste")],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Remainder(Target::Owner)],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text(".")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("ኛ")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Same(Target::Set(6))],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("'inci")],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(8))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Text("-"),
+                Part::Same(Target::Set(9)),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("и")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("ви")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("ри")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("ти")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("и")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Remainder(Target::Set(10))],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Remainder(Target::Owner)],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("тен")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Remainder(Target::Set(9))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Remainder(Target::Owner)],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(12))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Same(Target::Set(13)),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("è")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("r")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("n")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("r")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("t")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("è")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Remainder(Target::Owner)],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(true)), Part::Text(" lia")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(true)), Part::Text(" tɔ")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(true)), Part::Text(" lia")],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Ordinal(&["", "st", "nd", "rd", "", "th"]),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(17))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text(".º")],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(19))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("º.")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("ika"), Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(22))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Ordinal(&["", "er", "", "", "", "e"]),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("ú")],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(25))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("0")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(false)), Part::Text("ला")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(false)), Part::Text("रा")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(false)), Part::Text("था")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(false)), Part::Text("वा\u{901}")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(false)), Part::Text("ठा")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Same(Target::Digits(true)), Part::Text("वा\u{901}")],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[
+            Part::Minus,
+            Part::Text("ke-"),
+            Part::Same(Target::Digits(true)),
+        ]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("ke-"), Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(28))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Same(Target::Set(29)),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("º")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[
+            Part::Text("第"),
+            Part::Minus,
+            Part::Same(Target::Digits(true)),
+        ]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("第"), Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Text("-"),
+                Part::Ordinal(&["", "", "", "", "шы", "ші"]),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("ទ\u{17b8}"), Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("번째")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Same(Target::Set(35)),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("'инчи")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[
+            Part::Text("ท\u{e35}\u{e48}"),
+            Part::Minus,
+            Part::Same(Target::Digits(true)),
+        ]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Text("ท\u{e35}\u{e48}\u{200b}"),
+                Part::Same(Target::Digits(true)),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[
+            Part::Minus,
+            Part::Text("ke-"),
+            Part::Same(Target::Digits(true)),
+        ]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("ke-"), Part::Same(Target::Digits(true))],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("No. 1")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("ke-"), Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("e")],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(40))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("º")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true)), Part::Text("a")],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(44))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Same(Target::Digits(true)),
+                Part::Ordinal(&["", ":a", "", "", "", ":e"]),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[
+            Part::Text("ท\u{e35}\u{e48}\u{a0}"),
+            Part::Minus,
+            Part::Same(Target::Digits(true)),
+        ]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[
+                Part::Text("ท\u{e35}\u{e48}\u{a0}"),
+                Part::Same(Target::Digits(true)),
+            ],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Minus, Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Text("thứ "), Part::Same(Target::Digits(true))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("null")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("eins")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("zwei")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("drei")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("vier")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("fünf")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("sechs")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sieben")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("acht")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("neun")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("zehn")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("elf")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("zwölf")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Owner), Part::Text("zehn")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("sechzehn")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("siebzehn")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Owner), Part::Text("zehn")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("zwanzig")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("zwanzig"),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("dreißig")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("dreißig"),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("vierzig")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("vierzig"),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("fünfzig")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("fünfzig"),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sechzig")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("sechzig"),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("siebzig")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("siebzig"),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("achtzig")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("achtzig"),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("neunzig")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(48)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("neunzig"),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Set(48)), Part::Text("\u{ad}hundert")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Set(48)),
+                    Part::Text("\u{ad}hundert"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(48)), Part::Text("\u{ad}tausend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(48)),
+                    Part::Text("\u{ad}tausend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("eine Million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("eine Million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Millionen")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Millionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("eine Milliarde")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("eine Milliarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Milliarden")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Milliarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("eine Billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("eine Billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Billionen")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Billionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("eine Billiarde")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("eine Billiarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Billiarden")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Billiarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("null")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("ein")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(47))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Set(48)), Part::Text("\u{ad}hundert")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Set(48)),
+                    Part::Text("\u{ad}hundert"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(48)), Part::Text("\u{ad}tausend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(48)),
+                    Part::Text("\u{ad}tausend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("eine Million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("eine Million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Millionen")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Millionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("eine Milliarde")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("eine Milliarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Milliarden")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Milliarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("eine Billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("eine Billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Billionen")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Billionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("eine Billiarde")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("eine Billiarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Billiarden")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Billiarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("null")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("eine")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(47))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Set(48)), Part::Text("\u{ad}hundert")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Set(48)),
+                    Part::Text("\u{ad}hundert"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(48)), Part::Text("\u{ad}tausend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(48)),
+                    Part::Text("\u{ad}tausend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("eine Million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("eine Million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Millionen")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Millionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("eine Milliarde")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("eine Milliarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Milliarden")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Milliarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("eine Billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("eine Billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Billionen")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Billionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("eine Billiarde")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("eine Billiarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(49)), Part::Text(" Billiarden")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(49)),
+                    Part::Text(" Billiarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("null")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("eins")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("zwei")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("drei")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("vier")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("fünf")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("sechs")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sieben")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("acht")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("neun")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("zehn")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("elf")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("zwölf")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Owner), Part::Text("zehn")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("sechzehn")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("siebzehn")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Owner), Part::Text("zehn")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("zwanzig")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("zwanzig"),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("dreissig")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("dreissig"),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("vierzig")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("vierzig"),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("fünfzig")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("fünfzig"),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sechzig")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("sechzig"),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("siebzig")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("siebzig"),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("achtzig")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("achtzig"),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("neunzig")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Remainder(Target::Set(51)),
+                    Part::Text("\u{ad}und\u{ad}"),
+                    Part::Text("neunzig"),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Set(51)), Part::Text("\u{ad}hundert")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Set(51)),
+                    Part::Text("\u{ad}hundert"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(51)), Part::Text("\u{ad}tausend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(51)),
+                    Part::Text("\u{ad}tausend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("eine Million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("eine Million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Millionen")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Millionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("eine Milliarde")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("eine Milliarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Milliarden")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Milliarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("eine Billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("eine Billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Billionen")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Billionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("eine Billiarde")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("eine Billiarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Billiarden")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Billiarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("null")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("ein")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(50))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Set(51)), Part::Text("\u{ad}hundert")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Set(51)),
+                    Part::Text("\u{ad}hundert"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(51)), Part::Text("\u{ad}tausend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(51)),
+                    Part::Text("\u{ad}tausend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("eine Million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("eine Million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Millionen")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Millionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("eine Milliarde")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("eine Milliarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Milliarden")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Milliarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("eine Billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("eine Billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Billionen")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Billionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("eine Billiarde")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("eine Billiarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Billiarden")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Billiarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("null")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("eine")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(50))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Set(51)), Part::Text("\u{ad}hundert")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Set(51)),
+                    Part::Text("\u{ad}hundert"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(51)), Part::Text("\u{ad}tausend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(51)),
+                    Part::Text("\u{ad}tausend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("eine Million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("eine Million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Millionen")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Millionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("eine Milliarde")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("eine Milliarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Milliarden")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Milliarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("eine Billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("eine Billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Billionen")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Billionen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("eine Billiarde")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("eine Billiarde"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(52)), Part::Text(" Billiarden")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(52)),
+                    Part::Text(" Billiarden"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(54))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("one")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("two")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("three")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("four")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("five")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("six")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("seven")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("eight")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("nine")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("ten")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("eleven")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("twelve")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("thirteen")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("fourteen")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("fifteen")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("sixteen")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("seventeen")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Text("eighteen")],
+            },
+            Rule {
+                base: 19,
+                divisor: 10,
+                parts: &[Part::Text("nineteen")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("twenty")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("twenty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("thirty")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("thirty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("forty")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("forty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("fifty")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("fifty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sixty")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("sixty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("seventy")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("seventy"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("eighty")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("eighty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("ninety")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("ninety"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" hundred")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" hundred"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" thousand")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" thousand"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" billion")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" trillion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" trillion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" quadrillion")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" quadrillion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(56))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("minus "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("one")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("two")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("three")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("four")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("five")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("six")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("seven")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("eight")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("nine")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("ten")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("eleven")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("twelve")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("thirteen")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("fourteen")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("fifteen")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("sixteen")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("seventeen")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Text("eighteen")],
+            },
+            Rule {
+                base: 19,
+                divisor: 10,
+                parts: &[Part::Text("nineteen")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("twenty")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("twenty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("thirty")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("thirty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("forty")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("forty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("fifty")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("fifty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sixty")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("sixty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("seventy")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("seventy"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("eighty")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("eighty"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("ninety")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("ninety"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" hundred")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" hundred"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" thousand")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" thousand"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100000,
+                divisor: 100000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" lakh")],
+            },
+            Rule {
+                base: 100001,
+                divisor: 100000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" lakh"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 10000000,
+                divisor: 10000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" crore")],
+            },
+            Rule {
+                base: 10000001,
+                divisor: 10000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" crore"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" trillion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" trillion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" quadrillion")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" quadrillion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("menos "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("cero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("uno")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("dos")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("tres")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("cuatro")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinco")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("seis")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("siete")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("ocho")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("nueve")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("diez")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("once")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("doce")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("trece")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("catorce")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quince")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("dieciséis")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("dieci"), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("veinte")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[Part::Text("veintiuno")],
+            },
+            Rule {
+                base: 22,
+                divisor: 10,
+                parts: &[Part::Text("veintidós")],
+            },
+            Rule {
+                base: 23,
+                divisor: 10,
+                parts: &[Part::Text("veintitrés")],
+            },
+            Rule {
+                base: 24,
+                divisor: 10,
+                parts: &[Part::Text("veinticuatro")],
+            },
+            Rule {
+                base: 25,
+                divisor: 10,
+                parts: &[Part::Text("veinticinco")],
+            },
+            Rule {
+                base: 26,
+                divisor: 10,
+                parts: &[Part::Text("veintiséis")],
+            },
+            Rule {
+                base: 27,
+                divisor: 10,
+                parts: &[Part::Text("veinti"), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("treinta")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("treinta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("cuarenta")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cuarenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cincuenta")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cincuenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sesenta")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("sesenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("setenta")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("setenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("ochenta")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("ochenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("noventa")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("noventa"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cien")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[Part::Text("ciento "), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Text("doscientos")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Text("doscientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 300,
+                divisor: 100,
+                parts: &[Part::Text("trescientos")],
+            },
+            Rule {
+                base: 301,
+                divisor: 100,
+                parts: &[
+                    Part::Text("trescientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 400,
+                divisor: 100,
+                parts: &[Part::Text("cuatrocientos")],
+            },
+            Rule {
+                base: 401,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cuatrocientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 500,
+                divisor: 100,
+                parts: &[Part::Text("quinientos")],
+            },
+            Rule {
+                base: 501,
+                divisor: 100,
+                parts: &[
+                    Part::Text("quinientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 600,
+                divisor: 100,
+                parts: &[Part::Text("seiscientos")],
+            },
+            Rule {
+                base: 601,
+                divisor: 100,
+                parts: &[
+                    Part::Text("seiscientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 700,
+                divisor: 100,
+                parts: &[Part::Text("setecientos")],
+            },
+            Rule {
+                base: 701,
+                divisor: 100,
+                parts: &[
+                    Part::Text("setecientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 800,
+                divisor: 100,
+                parts: &[Part::Text("ochocientos")],
+            },
+            Rule {
+                base: 801,
+                divisor: 100,
+                parts: &[
+                    Part::Text("ochocientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 900,
+                divisor: 100,
+                parts: &[Part::Text("novecientos")],
+            },
+            Rule {
+                base: 901,
+                divisor: 100,
+                parts: &[
+                    Part::Text("novecientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mil")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mil"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(58)), Part::Text(" mil")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(58)),
+                    Part::Text(" mil"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un millón")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un millón"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(58)), Part::Text(" millones")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(58)),
+                    Part::Text(" millones"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un billón")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un billón"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(58)), Part::Text(" billones")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(58)),
+                    Part::Text(" billones"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("menos "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("cero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(57))],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[Part::Text("veintiún")],
+            },
+            Rule {
+                base: 22,
+                divisor: 10,
+                parts: &[Part::Same(Target::Set(57))],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("treinta")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("treinta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("cuarenta")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cuarenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cincuenta")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cincuenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sesenta")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("sesenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("setenta")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("setenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("ochenta")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("ochenta"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("noventa")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("noventa"),
+                    Part::Text(" y "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cien")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[Part::Text("ciento "), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Text("doscientos")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Text("doscientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 300,
+                divisor: 100,
+                parts: &[Part::Text("trescientos")],
+            },
+            Rule {
+                base: 301,
+                divisor: 100,
+                parts: &[
+                    Part::Text("trescientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 400,
+                divisor: 100,
+                parts: &[Part::Text("cuatrocientos")],
+            },
+            Rule {
+                base: 401,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cuatrocientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 500,
+                divisor: 100,
+                parts: &[Part::Text("quinientos")],
+            },
+            Rule {
+                base: 501,
+                divisor: 100,
+                parts: &[
+                    Part::Text("quinientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 600,
+                divisor: 100,
+                parts: &[Part::Text("seis\u{ad}cientos")],
+            },
+            Rule {
+                base: 601,
+                divisor: 100,
+                parts: &[
+                    Part::Text("seis\u{ad}cientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 700,
+                divisor: 100,
+                parts: &[Part::Text("sete\u{ad}cientos")],
+            },
+            Rule {
+                base: 701,
+                divisor: 100,
+                parts: &[
+                    Part::Text("sete\u{ad}cientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 800,
+                divisor: 100,
+                parts: &[Part::Text("ocho\u{ad}cientos")],
+            },
+            Rule {
+                base: 801,
+                divisor: 100,
+                parts: &[
+                    Part::Text("ocho\u{ad}cientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 900,
+                divisor: 100,
+                parts: &[Part::Text("nove\u{ad}cientos")],
+            },
+            Rule {
+                base: 901,
+                divisor: 100,
+                parts: &[
+                    Part::Text("nove\u{ad}cientos"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mil")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mil"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(58)), Part::Text(" mil")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(58)),
+                    Part::Text(" mil"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un millón")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un millón"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(58)), Part::Text(" millones")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(58)),
+                    Part::Text(" millones"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un billón")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un billón"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(58)), Part::Text(" billones")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(58)),
+                    Part::Text(" billones"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(60))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("moins "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zéro")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("deux")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("trois")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("quatre")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinq")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("six")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sept")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("huit")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("neuf")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("dix")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("douze")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("treize")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("quatorze")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quinze")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("seize")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("dix-"), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vingt")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("vingt"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(61)),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trente")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("trente"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(61)),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarante")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("quarante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(61)),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquante")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cinquante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(61)),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 20,
+                parts: &[Part::Text("soixante")],
+            },
+            Rule {
+                base: 61,
+                divisor: 20,
+                parts: &[
+                    Part::Text("soixante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(61)),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 20,
+                parts: &[Part::Text("quatre-vingt"), Part::Remainder(Target::Set(62))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" cent"),
+                    Part::Remainder(Target::Set(63)),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mille")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mille"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(64)), Part::Text(" mille")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(64)),
+                    Part::Text(" mille"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(64)), Part::Text(" millions")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(64)),
+                    Part::Text(" millions"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("un milliard")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("un milliard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(64)), Part::Text(" milliards")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(64)),
+                    Part::Text(" milliards"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(64)), Part::Text(" billions")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(64)),
+                    Part::Text(" billions"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("un billiard")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("un billiard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(64)), Part::Text(" billiards")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(64)),
+                    Part::Text(" billiards"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("et-un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(60))],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("et-onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Same(Target::Set(60))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("s")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("-"), Part::Same(Target::Set(60))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("s")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text(" "), Part::Same(Target::Set(60))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(60))],
+            },
+            Rule {
+                base: 80,
+                divisor: 20,
+                parts: &[Part::Text("quatre-vingt")],
+            },
+            Rule {
+                base: 81,
+                divisor: 20,
+                parts: &[
+                    Part::Text("quatre-vingt"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" cent")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Same(Target::Set(60))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(66))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("moins "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zéro")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("deux")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("trois")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("quatre")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinq")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("six")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sept")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("huit")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("neuf")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("dix")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("douze")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("treize")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("quatorze")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quinze")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("seize")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("dix-"), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vingt")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("vingt"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trente")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("trente"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarante")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("quarante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquante")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cinquante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("soixante")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("soixante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("septante")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("septante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("quatre-vingt"), Part::Remainder(Target::Set(68))],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("nonante")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("nonante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(67)),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" cent"),
+                    Part::Remainder(Target::Set(68)),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mille")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mille"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(69)), Part::Text(" mille")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(69)),
+                    Part::Text(" mille"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(69)), Part::Text(" millions")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(69)),
+                    Part::Text(" millions"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("un milliard")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("un milliard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(69)), Part::Text(" milliards")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(69)),
+                    Part::Text(" milliards"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(69)), Part::Text(" billions")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(69)),
+                    Part::Text(" billions"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("un billiard")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("un billiard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(69)), Part::Text(" billiards")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(69)),
+                    Part::Text(" billiards"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("et-un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(66))],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("et-onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Same(Target::Set(66))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("s")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text(" "), Part::Same(Target::Set(66))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(66))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" cent")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Same(Target::Set(66))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(71))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("moins "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zéro")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("deux")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("trois")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("quatre")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinq")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("six")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sept")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("huit")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("neuf")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("dix")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("douze")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("treize")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("quatorze")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quinze")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("seize")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("dix-"), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vingt")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("vingt"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trente")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("trente"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarante")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("quarante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquante")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cinquante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("soixante")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("soixante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("septante")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("septante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("huitante")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("huitante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("nonante")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("nonante"),
+                    Part::Text("-"),
+                    Part::Remainder(Target::Set(72)),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" cent"),
+                    Part::Remainder(Target::Set(73)),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mille")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mille"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(74)), Part::Text(" mille")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(74)),
+                    Part::Text(" mille"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un million")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un million"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(74)), Part::Text(" millions")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(74)),
+                    Part::Text(" millions"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("un milliard")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("un milliard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(74)), Part::Text(" milliards")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(74)),
+                    Part::Text(" milliards"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un billion")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un billion"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(74)), Part::Text(" billions")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(74)),
+                    Part::Text(" billions"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("un billiard")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("un billiard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(74)), Part::Text(" billiards")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(74)),
+                    Part::Text(" billiards"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("et-un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(71))],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("et-onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Same(Target::Set(71))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("s")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text(" "), Part::Same(Target::Set(71))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(71))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[
+                    Part::Text("cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" cent")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" cent"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Same(Target::Set(71))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("meno "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("uno")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("due")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("tre")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("quattro")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinque")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("sei")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sette")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("otto")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("nove")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("dieci")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("undici")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("dodici")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("tredici")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("quattordici")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quindici")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("sedici")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("diciassette")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Text("diciotto")],
+            },
+            Rule {
+                base: 19,
+                divisor: 10,
+                parts: &[Part::Text("diciannove")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vent"), Part::Remainder(Target::Set(76))],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trent"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarant"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquant"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sessant"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("settant"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("ottant"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("novant"), Part::Remainder(Target::Set(77))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent"), Part::Remainder(Target::Set(78))],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text("\u{ad}cent"),
+                    Part::Remainder(Target::Set(78)),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mille")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mille"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(79)), Part::Text("\u{ad}mila")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(79)),
+                    Part::Text("\u{ad}mila"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un milione")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un milione"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" milioni")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" milioni"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("un miliardo")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("un miliardo"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" miliardi")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" miliardi"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un bilione")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un bilione"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" bilioni")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" bilioni"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("un biliardo")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("un biliardo"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" biliardi")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" biliardi"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("i")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}uno")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("i\u{ad}due")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("i\u{ad}tré")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("i\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}otto")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("i\u{ad}nove")],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("a")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}uno")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("a\u{ad}due")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("a\u{ad}tré")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("a\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}otto")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("a\u{ad}nove")],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("o")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}uno")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}due")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}tré")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}otto")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("o\u{ad}"), Part::Same(Target::Set(75))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(80))],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vent"), Part::Remainder(Target::Set(84))],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trent"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarant"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquant"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sessant"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("settant"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("ottant"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("novant"), Part::Remainder(Target::Set(85))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent"), Part::Remainder(Target::Set(86))],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text("\u{ad}cent"),
+                    Part::Remainder(Target::Set(86)),
+                ],
+            },
+        ],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("meno "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vent"), Part::Remainder(Target::Set(81))],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trent"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarant"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquant"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sessant"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("settant"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("ottant"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("novant"), Part::Remainder(Target::Set(82))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cent"), Part::Remainder(Target::Set(83))],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text("\u{ad}cent"),
+                    Part::Remainder(Target::Set(83)),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mille")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("mille"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Set(79)), Part::Text("\u{ad}mila")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Set(79)),
+                    Part::Text("\u{ad}mila"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Text("un milione")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Text("un milione"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" milioni")],
+            },
+            Rule {
+                base: 2000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" milioni"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Text("un miliardo")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Text("un miliardo"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" miliardi")],
+            },
+            Rule {
+                base: 2000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" miliardi"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Text("un bilione")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Text("un bilione"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" bilioni")],
+            },
+            Rule {
+                base: 2000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" bilioni"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Text("un biliardo")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Text("un biliardo"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Set(80)), Part::Text(" biliardi")],
+            },
+            Rule {
+                base: 2000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Set(80)),
+                    Part::Text(" biliardi"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("i")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(76))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("a")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}un")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(77))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("o")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}uno")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}due")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}tré")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("\u{ad}otto")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("\u{ad}"), Part::Same(Target::Set(75))],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("o\u{ad}"), Part::Same(Target::Set(75))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(81))],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("i\u{ad}tre")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(81))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(82))],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("a\u{ad}tre")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(82))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(83))],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("o\u{ad}tre")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(83))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(88))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("min "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("nul")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("een")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("twee")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("drie")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("vier")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("vijf")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("zes")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("zeven")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("acht")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("negen")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("tien")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("elf")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("twaalf")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("dertien")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("veertien")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("vijftien")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("zestien")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("zeventien")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Text("achttien")],
+            },
+            Rule {
+                base: 19,
+                divisor: 10,
+                parts: &[Part::Text("negentien")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("twintig")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("twintig")],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("dertig")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("dertig")],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("veertig")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("veertig")],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("vijftig")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("vijftig")],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("zestig")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("zestig")],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("zeventig")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("zeventig")],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("tachtig")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("tachtig")],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("negentig")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[Part::Remainder(Target::Set(89)), Part::Text("negentig")],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("honderd")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[Part::Text("honderd"), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Quotient(Target::Owner), Part::Text("\u{ad}honderd")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text("\u{ad}honderd"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("duizend")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[
+                    Part::Text("duizend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text("\u{ad}duizend")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text("\u{ad}duizend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text("\u{ad}duizend")],
+            },
+            Rule {
+                base: 100001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text("\u{ad}duizend"),
+                    Part::Text("\u{ad}"),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" miljoen")],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" miljoen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" miljard")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" miljard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" biljoen")],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" biljoen"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" biljard")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" biljard"),
+                    Part::Text(" "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("een\u{ad}en\u{ad}")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("twee\u{ad}ën\u{ad}")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("drie\u{ad}ën\u{ad}")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Same(Target::Set(88)), Part::Text("\u{ad}en\u{ad}")],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(91))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("menos "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("um")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("dois")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("três")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("quatro")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinco")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("seis")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sete")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("oito")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("nove")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("dez")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("doze")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("treze")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("catorze")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quinze")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("dezesseis")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("dezessete")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Text("dezoito")],
+            },
+            Rule {
+                base: 19,
+                divisor: 10,
+                parts: &[Part::Text("dezenove")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vinte")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("vinte"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trinta")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("trinta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarenta")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("quarenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquenta")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cinquenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sessenta")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("sessenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("setenta")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("setenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("oitenta")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("oitenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("noventa")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("noventa"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cem")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[Part::Text("cento e "), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Text("duzentos")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Text("duzentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 300,
+                divisor: 100,
+                parts: &[Part::Text("trezentos")],
+            },
+            Rule {
+                base: 301,
+                divisor: 100,
+                parts: &[
+                    Part::Text("trezentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 400,
+                divisor: 100,
+                parts: &[Part::Text("quatrocentos")],
+            },
+            Rule {
+                base: 401,
+                divisor: 100,
+                parts: &[
+                    Part::Text("quatrocentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 500,
+                divisor: 100,
+                parts: &[Part::Text("quinhentos")],
+            },
+            Rule {
+                base: 501,
+                divisor: 100,
+                parts: &[
+                    Part::Text("quinhentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 600,
+                divisor: 100,
+                parts: &[Part::Text("seiscentos")],
+            },
+            Rule {
+                base: 601,
+                divisor: 100,
+                parts: &[
+                    Part::Text("seiscentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 700,
+                divisor: 100,
+                parts: &[Part::Text("setecentos")],
+            },
+            Rule {
+                base: 701,
+                divisor: 100,
+                parts: &[
+                    Part::Text("setecentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 800,
+                divisor: 100,
+                parts: &[Part::Text("oitocentos")],
+            },
+            Rule {
+                base: 801,
+                divisor: 100,
+                parts: &[
+                    Part::Text("oitocentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 900,
+                divisor: 100,
+                parts: &[Part::Text("novecentos")],
+            },
+            Rule {
+                base: 901,
+                divisor: 100,
+                parts: &[
+                    Part::Text("novecentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mil")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[Part::Text("mil"), Part::Remainder(Target::Set(92))],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" mil")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" mil"),
+                    Part::Remainder(Target::Set(92)),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "milhão", "", "", "", "milhões"]),
+                ],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "milhão", "", "", "", "milhões"]),
+                    Part::Remainder(Target::Set(92)),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "bilhão", "", "", "", "bilhões"]),
+                ],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "bilhão", "", "", "", "bilhões"]),
+                    Part::Remainder(Target::Set(92)),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "trilhão", "", "", "", "trilhões"]),
+                ],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "trilhão", "", "", "", "trilhões"]),
+                    Part::Remainder(Target::Set(92)),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "quatrilhão", "", "", "", "quatrilhões"]),
+                ],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "quatrilhão", "", "", "", "quatrilhões"]),
+                    Part::Remainder(Target::Set(92)),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text(" e "), Part::Same(Target::Set(91))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[
+                    Part::Remainder(Target::Set(93)),
+                    Part::Same(Target::Set(91)),
+                ],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text(" e ")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text(" ")],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[Rule {
+            base: 0,
+            divisor: 1,
+            parts: &[Part::Same(Target::Set(95))],
+        }],
+    },
+    RuleSet {
+        negative: Some(&[Part::Text("menos "), Part::Same(Target::Owner)]),
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text("zero")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text("um")],
+            },
+            Rule {
+                base: 2,
+                divisor: 1,
+                parts: &[Part::Text("dois")],
+            },
+            Rule {
+                base: 3,
+                divisor: 1,
+                parts: &[Part::Text("três")],
+            },
+            Rule {
+                base: 4,
+                divisor: 1,
+                parts: &[Part::Text("quatro")],
+            },
+            Rule {
+                base: 5,
+                divisor: 1,
+                parts: &[Part::Text("cinco")],
+            },
+            Rule {
+                base: 6,
+                divisor: 1,
+                parts: &[Part::Text("seis")],
+            },
+            Rule {
+                base: 7,
+                divisor: 1,
+                parts: &[Part::Text("sete")],
+            },
+            Rule {
+                base: 8,
+                divisor: 1,
+                parts: &[Part::Text("oito")],
+            },
+            Rule {
+                base: 9,
+                divisor: 1,
+                parts: &[Part::Text("nove")],
+            },
+            Rule {
+                base: 10,
+                divisor: 10,
+                parts: &[Part::Text("dez")],
+            },
+            Rule {
+                base: 11,
+                divisor: 10,
+                parts: &[Part::Text("onze")],
+            },
+            Rule {
+                base: 12,
+                divisor: 10,
+                parts: &[Part::Text("doze")],
+            },
+            Rule {
+                base: 13,
+                divisor: 10,
+                parts: &[Part::Text("treze")],
+            },
+            Rule {
+                base: 14,
+                divisor: 10,
+                parts: &[Part::Text("catorze")],
+            },
+            Rule {
+                base: 15,
+                divisor: 10,
+                parts: &[Part::Text("quinze")],
+            },
+            Rule {
+                base: 16,
+                divisor: 10,
+                parts: &[Part::Text("dezasseis")],
+            },
+            Rule {
+                base: 17,
+                divisor: 10,
+                parts: &[Part::Text("dezassete")],
+            },
+            Rule {
+                base: 18,
+                divisor: 10,
+                parts: &[Part::Text("dezoito")],
+            },
+            Rule {
+                base: 19,
+                divisor: 10,
+                parts: &[Part::Text("dezanove")],
+            },
+            Rule {
+                base: 20,
+                divisor: 10,
+                parts: &[Part::Text("vinte")],
+            },
+            Rule {
+                base: 21,
+                divisor: 10,
+                parts: &[
+                    Part::Text("vinte"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 30,
+                divisor: 10,
+                parts: &[Part::Text("trinta")],
+            },
+            Rule {
+                base: 31,
+                divisor: 10,
+                parts: &[
+                    Part::Text("trinta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 40,
+                divisor: 10,
+                parts: &[Part::Text("quarenta")],
+            },
+            Rule {
+                base: 41,
+                divisor: 10,
+                parts: &[
+                    Part::Text("quarenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 50,
+                divisor: 10,
+                parts: &[Part::Text("cinquenta")],
+            },
+            Rule {
+                base: 51,
+                divisor: 10,
+                parts: &[
+                    Part::Text("cinquenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 60,
+                divisor: 10,
+                parts: &[Part::Text("sessenta")],
+            },
+            Rule {
+                base: 61,
+                divisor: 10,
+                parts: &[
+                    Part::Text("sessenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 70,
+                divisor: 10,
+                parts: &[Part::Text("setenta")],
+            },
+            Rule {
+                base: 71,
+                divisor: 10,
+                parts: &[
+                    Part::Text("setenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 80,
+                divisor: 10,
+                parts: &[Part::Text("oitenta")],
+            },
+            Rule {
+                base: 81,
+                divisor: 10,
+                parts: &[
+                    Part::Text("oitenta"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 90,
+                divisor: 10,
+                parts: &[Part::Text("noventa")],
+            },
+            Rule {
+                base: 91,
+                divisor: 10,
+                parts: &[
+                    Part::Text("noventa"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[Part::Text("cem")],
+            },
+            Rule {
+                base: 101,
+                divisor: 100,
+                parts: &[Part::Text("cento e "), Part::Remainder(Target::Owner)],
+            },
+            Rule {
+                base: 200,
+                divisor: 100,
+                parts: &[Part::Text("duzentos")],
+            },
+            Rule {
+                base: 201,
+                divisor: 100,
+                parts: &[
+                    Part::Text("duzentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 300,
+                divisor: 100,
+                parts: &[Part::Text("trezentos")],
+            },
+            Rule {
+                base: 301,
+                divisor: 100,
+                parts: &[
+                    Part::Text("trezentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 400,
+                divisor: 100,
+                parts: &[Part::Text("quatrocentos")],
+            },
+            Rule {
+                base: 401,
+                divisor: 100,
+                parts: &[
+                    Part::Text("quatrocentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 500,
+                divisor: 100,
+                parts: &[Part::Text("quinhentos")],
+            },
+            Rule {
+                base: 501,
+                divisor: 100,
+                parts: &[
+                    Part::Text("quinhentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 600,
+                divisor: 100,
+                parts: &[Part::Text("seiscentos")],
+            },
+            Rule {
+                base: 601,
+                divisor: 100,
+                parts: &[
+                    Part::Text("seiscentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 700,
+                divisor: 100,
+                parts: &[Part::Text("setecentos")],
+            },
+            Rule {
+                base: 701,
+                divisor: 100,
+                parts: &[
+                    Part::Text("setecentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 800,
+                divisor: 100,
+                parts: &[Part::Text("oitocentos")],
+            },
+            Rule {
+                base: 801,
+                divisor: 100,
+                parts: &[
+                    Part::Text("oitocentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 900,
+                divisor: 100,
+                parts: &[Part::Text("novecentos")],
+            },
+            Rule {
+                base: 901,
+                divisor: 100,
+                parts: &[
+                    Part::Text("novecentos"),
+                    Part::Text(" e "),
+                    Part::Remainder(Target::Owner),
+                ],
+            },
+            Rule {
+                base: 1000,
+                divisor: 1000,
+                parts: &[Part::Text("mil")],
+            },
+            Rule {
+                base: 1001,
+                divisor: 1000,
+                parts: &[Part::Text("mil"), Part::Remainder(Target::Set(96))],
+            },
+            Rule {
+                base: 2000,
+                divisor: 1000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" mil")],
+            },
+            Rule {
+                base: 2001,
+                divisor: 1000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" mil"),
+                    Part::Remainder(Target::Set(96)),
+                ],
+            },
+            Rule {
+                base: 1000000,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "milhão", "", "", "", "milhões"]),
+                ],
+            },
+            Rule {
+                base: 1000001,
+                divisor: 1000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "milhão", "", "", "", "milhões"]),
+                    Part::Remainder(Target::Set(96)),
+                ],
+            },
+            Rule {
+                base: 1000000000,
+                divisor: 1000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" mil milhões")],
+            },
+            Rule {
+                base: 1000000001,
+                divisor: 1000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" mil milhões"),
+                    Part::Remainder(Target::Set(96)),
+                ],
+            },
+            Rule {
+                base: 1000000000000,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "bilião", "", "", "", "biliões"]),
+                ],
+            },
+            Rule {
+                base: 1000000000001,
+                divisor: 1000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" "),
+                    Part::Cardinal(&["", "bilião", "", "", "", "biliões"]),
+                    Part::Remainder(Target::Set(96)),
+                ],
+            },
+            Rule {
+                base: 1000000000000000,
+                divisor: 1000000000000000,
+                parts: &[Part::Quotient(Target::Owner), Part::Text(" mil biliões")],
+            },
+            Rule {
+                base: 1000000000000001,
+                divisor: 1000000000000000,
+                parts: &[
+                    Part::Quotient(Target::Owner),
+                    Part::Text(" mil biliões"),
+                    Part::Remainder(Target::Set(96)),
+                ],
+            },
+            Rule {
+                base: 1000000000000000000,
+                divisor: 1000000000000000000,
+                parts: &[Part::Same(Target::Digits(true))],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text(" e "), Part::Same(Target::Set(95))],
+            },
+            Rule {
+                base: 100,
+                divisor: 100,
+                parts: &[
+                    Part::Remainder(Target::Set(97)),
+                    Part::Same(Target::Set(95)),
+                ],
+            },
+        ],
+    },
+    RuleSet {
+        negative: None,
+        rules: &[
+            Rule {
+                base: 0,
+                divisor: 1,
+                parts: &[Part::Text(" e ")],
+            },
+            Rule {
+                base: 1,
+                divisor: 1,
+                parts: &[Part::Text(" ")],
+            },
+        ],
+    },
+];
+impl Locale {
+    #[doc = r#" Returns the rule set the locale writes ordinals (e.g. "1st") with."#]
+    pub(crate) fn ordinal_rules(&self) -> &'static RuleSet {
+        use self::Locale::*;
+        match self {
+            af | af_NA => &RULE_SETS[0],
+            agq | ak | as_ | asa | ast | bas | be | bem | bez | bm | bn | bn_IN | bo | bo_IN
+            | br | brx | bs | bs_Cyrl | bs_Latn | ccp | ccp_IN | ce | ceb | cgg | chr | ckb
+            | ckb_IR | cs | cu | cy | da | da_GL | dav | de | de_AT | de_BE | de_CH | de_IT
+            | de_LI | de_LU | dje | dsb | dua | dyo | dz | ebu | eo | et | eu | ewo | fa
+            | fa_AF | ff | ff_Latn | ff_Latn_BF | ff_Latn_CM | ff_Latn_GH | ff_Latn_GM
+            | ff_Latn_GN | ff_Latn_GW | ff_Latn_LR | ff_Latn_MR | ff_Latn_NE | ff_Latn_NG
+            | ff_Latn_SL | fi | fo | fo_DK | fur | fy | gd | gl | gsw | gsw_FR | gsw_LI | gu
+            | guz | gv | ha | ha_GH | ha_NE | haw | hr | hr_BA | hsb | hu | hy | ia | ig | ii
+            | is | jgo | jmc | jv | ka | kab | kam | kde | kea | khq | ki | kkj | kl | kln | kn
+            | kok | ks | ksb | ksf | ksh | ku | kw | lag | lb | lg | lkt | ln | ln_AO | ln_CF
+            | ln_CG | lrc | lrc_IQ | lt | lu | luo | luy | lv | mas | mas_TZ | mer | mfe | mg
+            | mgh | mgo | mi | mk | ml | mn | mr | mt | mua | my | mzn | naq | nb | nb_SJ | nd
+            | nds | nds_NL | ne | ne_IN | nmg | nn | nnh | nus | nyn | om | om_KE | or | os
+            | os_RU | pa | pa_Arab | pa_Guru | pl | prg | ps | ps_PK | qu | qu_BO | qu_EC | rm
+            | rn | rof | root | rw | rwk | sah | saq | sbp | sd | se | se_FI | se_SE | seh
+            | ses | sg | shi | shi_Latn | shi_Tfng | si | sk | sl | smn | sn | so | so_DJ
+            | so_ET | so_KE | sq | sq_MK | sq_XK | sr | sr_Cyrl | sr_Cyrl_BA | sr_Cyrl_ME
+            | sr_Cyrl_XK | sr_Latn | sr_Latn_BA | sr_Latn_ME | sr_Latn_XK | sw | sw_CD | sw_KE
+            | sw_UG | ta | ta_LK | ta_MY | ta_SG | te | teo | teo_KE | tg | ti | ti_ER | tk
+            | to | tr | tr_CY | tt | twq | tzm | ug | ur | ur_IN | uz | uz_Arab | uz_Cyrl
+            | uz_Latn | vai | vai_Latn | vai_Vaii | vo | vun | wae | wo | xh | xog | yav | yi
+            | yo | yo_BJ | zgh | zu => &RULE_SETS[2],
+            am => &RULE_SETS[3],
+            ar | ar_AE | ar_BH | ar_DJ | ar_DZ | ar_EG | ar_EH | ar_ER | ar_IL | ar_IQ | ar_JO
+            | ar_KM | ar_KW | ar_LB | ar_LY | ar_MA | ar_MR | ar_OM | ar_PS | ar_QA | ar_SA
+            | ar_SD | ar_SO | ar_SS | ar_SY | ar_TD | ar_TN | ar_YE | el | el_CY | he | uk => {
+                &RULE_SETS[4]
+            }
+            az | az_Cyrl | az_Latn => &RULE_SETS[5],
+            bg => &RULE_SETS[7],
+            ca | ca_AD | ca_ES_VALENCIA | ca_FR | ca_IT => &RULE_SETS[11],
+            ee | ee_TG => &RULE_SETS[14],
+            en | en_001 | en_150 | en_AE | en_AG | en_AI | en_AS | en_AT | en_AU | en_BB
+            | en_BE | en_BI | en_BM | en_BS | en_BW | en_BZ | en_CA | en_CC | en_CH | en_CK
+            | en_CM | en_CX | en_CY | en_DE | en_DG | en_DK | en_DM | en_ER | en_FI | en_FJ
+            | en_FK | en_FM | en_GB | en_GD | en_GG | en_GH | en_GI | en_GM | en_GU | en_GY
+            | en_HK | en_IE | en_IL | en_IM | en_IN | en_IO | en_JE | en_JM | en_KE | en_KI
+            | en_KN | en_KY | en_LC | en_LR | en_LS | en_MG | en_MH | en_MO | en_MP | en_MS
+            | en_MT | en_MU | en_MW | en_MY | en_NA | en_NF | en_NG | en_NL | en_NR | en_NU
+            | en_NZ | en_PG | en_PH | en_PK | en_PN | en_PR | en_PW | en_RW | en_SB | en_SC
+            | en_SD | en_SE | en_SG | en_SH | en_SI | en_SL | en_SS | en_SX | en_SZ | en_TC
+            | en_TK | en_TO | en_TT | en_TV | en_TZ | en_UG | en_UM | en_US_POSIX | en_VC
+            | en_VG | en_VI | en_VU | en_WS | en_ZA | en_ZM | en_ZW => &RULE_SETS[15],
+            es | es_AR | es_BO | es_BR | es_BZ | es_CL | es_CO | es_CR | es_CU | es_EA | es_EC
+            | es_GQ | es_IC | es_PE | es_PH | es_PY | es_UY | es_VE => &RULE_SETS[16],
+            es_419 | es_DO | es_GT | es_HN | es_MX | es_NI | es_PA | es_PR | es_SV | es_US => {
+                &RULE_SETS[18]
+            }
+            fil => &RULE_SETS[20],
+            fr | fr_BE | fr_BF | fr_BI | fr_BJ | fr_BL | fr_CA | fr_CD | fr_CF | fr_CG | fr_CH
+            | fr_CI | fr_CM | fr_DJ | fr_DZ | fr_GA | fr_GF | fr_GN | fr_GP | fr_GQ | fr_HT
+            | fr_KM | fr_LU | fr_MA | fr_MC | fr_MF | fr_MG | fr_ML | fr_MQ | fr_MR | fr_MU
+            | fr_NC | fr_NE | fr_PF | fr_PM | fr_RE | fr_RW | fr_SC | fr_SN | fr_SY | fr_TD
+            | fr_TG | fr_TN | fr_VU | fr_WF | fr_YT => &RULE_SETS[21],
+            ga | ga_GB => &RULE_SETS[23],
+            hi => &RULE_SETS[24],
+            id => &RULE_SETS[26],
+            it | it_CH | it_SM | it_VA => &RULE_SETS[27],
+            ja | yue | yue_Hans | yue_Hant | zh | zh_Hans | zh_Hans_HK | zh_Hans_MO
+            | zh_Hans_SG | zh_Hant | zh_Hant_HK | zh_Hant_MO => &RULE_SETS[30],
+            kk => &RULE_SETS[31],
+            km => &RULE_SETS[32],
+            ko | ko_KP => &RULE_SETS[33],
+            ky => &RULE_SETS[34],
+            lo => &RULE_SETS[36],
+            ms | ms_BN | ms_SG => &RULE_SETS[37],
+            nl | nl_AW | nl_BE | nl_BQ | nl_CW | nl_SR | nl_SX => &RULE_SETS[38],
+            pt | pt_AO | pt_CH | pt_CV | pt_GQ | pt_GW | pt_LU | pt_MO | pt_MZ | pt_PT | pt_ST
+            | pt_TL => &RULE_SETS[39],
+            ro | ro_MD => &RULE_SETS[41],
+            ru | ru_BY | ru_KG | ru_KZ | ru_MD | ru_UA => &RULE_SETS[42],
+            sv | sv_AX | sv_FI => &RULE_SETS[43],
+            th => &RULE_SETS[45],
+            vi => &RULE_SETS[46],
+        }
+    }
+    #[doc = r#" Returns the rule set the locale spells out numbers (e.g. "twenty-one") with, if"#]
+    #[doc = r" its language has one."]
+    pub(crate) fn spellout_rules(&self) -> Option<&'static RuleSet> {
+        use self::Locale::*;
+        match self {
+            de | de_AT | de_BE | de_IT | de_LI | de_LU => Some(&RULE_SETS[47]),
+            de_CH => Some(&RULE_SETS[50]),
+            en | en_001 | en_150 | en_AE | en_AG | en_AI | en_AS | en_AT | en_AU | en_BB
+            | en_BE | en_BI | en_BM | en_BS | en_BW | en_BZ | en_CA | en_CC | en_CH | en_CK
+            | en_CM | en_CX | en_CY | en_DE | en_DG | en_DK | en_DM | en_ER | en_FI | en_FJ
+            | en_FK | en_FM | en_GB | en_GD | en_GG | en_GH | en_GI | en_GM | en_GU | en_GY
+            | en_HK | en_IE | en_IL | en_IM | en_IO | en_JE | en_JM | en_KE | en_KI | en_KN
+            | en_KY | en_LC | en_LR | en_LS | en_MG | en_MH | en_MO | en_MP | en_MS | en_MT
+            | en_MU | en_MW | en_MY | en_NA | en_NF | en_NG | en_NL | en_NR | en_NU | en_NZ
+            | en_PG | en_PH | en_PK | en_PN | en_PR | en_PW | en_RW | en_SB | en_SC | en_SD
+            | en_SE | en_SG | en_SH | en_SI | en_SL | en_SS | en_SX | en_SZ | en_TC | en_TK
+            | en_TO | en_TT | en_TV | en_TZ | en_UG | en_UM | en_US_POSIX | en_VC | en_VG
+            | en_VI | en_VU | en_WS | en_ZA | en_ZM | en_ZW => Some(&RULE_SETS[53]),
+            en_IN => Some(&RULE_SETS[55]),
+            es | es_419 | es_AR | es_BO | es_BR | es_BZ | es_CL | es_CO | es_CR | es_CU | es_DO
+            | es_EA | es_EC | es_GQ | es_GT | es_HN | es_IC | es_MX | es_NI | es_PA | es_PE
+            | es_PH | es_PR | es_PY | es_SV | es_US | es_UY | es_VE => Some(&RULE_SETS[57]),
+            fr | fr_BF | fr_BI | fr_BJ | fr_BL | fr_CA | fr_CD | fr_CF | fr_CG | fr_CI | fr_CM
+            | fr_DJ | fr_DZ | fr_GA | fr_GF | fr_GN | fr_GP | fr_GQ | fr_HT | fr_KM | fr_LU
+            | fr_MA | fr_MC | fr_MF | fr_MG | fr_ML | fr_MQ | fr_MR | fr_MU | fr_NC | fr_NE
+            | fr_PF | fr_PM | fr_RE | fr_RW | fr_SC | fr_SN | fr_SY | fr_TD | fr_TG | fr_TN
+            | fr_VU | fr_WF | fr_YT => Some(&RULE_SETS[59]),
+            fr_BE => Some(&RULE_SETS[65]),
+            fr_CH => Some(&RULE_SETS[70]),
+            it | it_CH | it_SM | it_VA => Some(&RULE_SETS[75]),
+            nl | nl_AW | nl_BE | nl_BQ | nl_CW | nl_SR | nl_SX => Some(&RULE_SETS[87]),
+            pt | pt_AO | pt_CH | pt_CV | pt_GQ | pt_GW | pt_LU | pt_MO | pt_MZ | pt_ST | pt_TL => {
+                Some(&RULE_SETS[90])
+            }
+            pt_PT => Some(&RULE_SETS[94]),
+            _ => None,
+        }
+    }
+}
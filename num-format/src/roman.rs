@@ -0,0 +1,234 @@
+use crate::parsing::{ParseExpected, ParseReason};
+
+// The numerals of each decimal place, from the units up to the thousands
+const UPPER: [[&str; 10]; 4] = [
+    ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"],
+    ["", "X", "XX", "XXX", "XL", "L", "LX", "LXX", "LXXX", "XC"],
+    ["", "C", "CC", "CCC", "CD", "D", "DC", "DCC", "DCCC", "CM"],
+    ["", "M", "MM", "MMM", "", "", "", "", "", ""],
+];
+const LOWER: [[&str; 10]; 4] = [
+    ["", "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"],
+    ["", "x", "xx", "xxx", "xl", "l", "lx", "lxx", "lxxx", "xc"],
+    ["", "c", "cc", "ccc", "cd", "d", "dc", "dcc", "dccc", "cm"],
+    ["", "m", "mm", "mmm", "", "", "", "", "", ""],
+];
+
+// Combining overline (U+0305), which multiplies the letter before it by a thousand
+const VINCULUM: &str = "\u{305}";
+
+pub(crate) const MAX_ROMAN: u32 = 3_999;
+pub(crate) const MAX_ROMAN_VINCULUM: u32 = 3_999_999;
+
+/// Type for representing an integer written as a Roman numeral (e.g. `XIV` or `xiv`).
+/// Implements [`ToFormattedStr`] for all the integer types in the standard library and
+/// [`FromFormattedStr`] for parsing Roman numerals back into them.
+///
+/// Roman numerals are written in uppercase unless [`lowercase`] is called, and can represent
+/// the integers from 1 to 3,999. In vinculum mode (see [`vinculum`]), the thousands of numbers
+/// from 4,000 to 3,999,999 are written as a Roman numeral with a combining overline (U+0305)
+/// after each letter (e.g. `I̅V̅` for 4,000). Numbers that can't be represented are written in
+/// digits, with the minus sign and grouping of the [`Format`].
+///
+/// # Example
+/// ```rust
+/// use num_format::parsing::ParseFormatted;
+/// use num_format::{Buffer, Locale, Roman};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&Roman::new(2024), &Locale::en);
+///     assert_eq!("MMXXIV", buf.as_str());
+///
+///     buf.write_formatted(&Roman::new(14).lowercase(), &Locale::en);
+///     assert_eq!("xiv", buf.as_str());
+///
+///     buf.write_formatted(&Roman::new(4_001).vinculum(), &Locale::en);
+///     assert_eq!("I\u{305}V\u{305}I", buf.as_str());
+///
+///     let roman: Roman<u16> = "mcmxcix".parse_formatted(&Locale::en).unwrap();
+///     assert_eq!(1999, *roman.value());
+///     assert!(roman.is_lowercase());
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`FromFormattedStr`]: parsing/trait.FromFormattedStr.html
+/// [`lowercase`]: struct.Roman.html#method.lowercase
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`vinculum`]: struct.Roman.html#method.vinculum
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Roman<N> {
+    value: N,
+    lowercase: bool,
+    vinculum: bool,
+}
+
+impl<N> Roman<N> {
+    /// Constructs an uppercase [`Roman`] for the given value, without a vinculum.
+    ///
+    /// [`Roman`]: struct.Roman.html
+    pub fn new(value: N) -> Roman<N> {
+        Roman {
+            value,
+            lowercase: false,
+            vinculum: false,
+        }
+    }
+
+    /// Returns the [`Roman`] written in lowercase letters (e.g. `xiv`).
+    ///
+    /// [`Roman`]: struct.Roman.html
+    pub fn lowercase(self) -> Roman<N> {
+        Roman {
+            lowercase: true,
+            ..self
+        }
+    }
+
+    /// Returns the [`Roman`] written with a vinculum for the thousands of numbers from 4,000 to
+    /// 3,999,999.
+    ///
+    /// [`Roman`]: struct.Roman.html
+    pub fn vinculum(self) -> Roman<N> {
+        Roman {
+            vinculum: true,
+            ..self
+        }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns `true` if the numeral is written in lowercase letters.
+    pub fn is_lowercase(&self) -> bool {
+        self.lowercase
+    }
+
+    /// Returns `true` if the thousands of large numbers are written with a vinculum.
+    pub fn is_vinculum(&self) -> bool {
+        self.vinculum
+    }
+}
+
+/// Writes `n`, which must be between 1 and the largest number the mode can represent, into `w`
+/// piece by piece.
+pub(crate) fn write_roman<W>(n: u32, lowercase: bool, vinculum: bool, mut w: W)
+where
+    W: FnMut(&str),
+{
+    let numerals = if lowercase { &LOWER } else { &UPPER };
+    let (thousands, rest) = (n / 1000, n % 1000);
+    if vinculum && thousands > 3 {
+        write_places(thousands, numerals, VINCULUM, &mut w);
+        write_places(rest, numerals, "", &mut w);
+    } else {
+        write_places(n, numerals, "", &mut w);
+    }
+}
+
+/// Writes the numerals of each decimal place of `n`, with `overline` after each letter.
+fn write_places<W>(n: u32, numerals: &[[&str; 10]; 4], overline: &str, w: &mut W)
+where
+    W: FnMut(&str),
+{
+    let mut place = 1000;
+    for digits in numerals.iter().rev() {
+        let letters = digits[(n / place % 10) as usize];
+        if overline.is_empty() {
+            w(letters);
+        } else {
+            for i in 0..letters.len() {
+                w(&letters[i..i + 1]);
+                w(overline);
+            }
+        }
+        place /= 10;
+    }
+}
+
+/// Parses a Roman numeral written as by [`write_roman`], returning its value and whether it is
+/// lowercase and has a vinculum, or else the byte offset where parsing failed and why.
+pub(crate) fn parse_roman(s: &str) -> Result<(u32, bool, bool), (usize, ParseReason)> {
+    let lowercase = match s.chars().next() {
+        Some(c) => c.is_ascii_lowercase(),
+        None => return Err((0, ParseReason::Empty)),
+    };
+    // The value of each letter, in thousands if it has an overline
+    let mut letters = [(0i64, false); 64];
+    let mut len = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let value = match c.to_ascii_uppercase() {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            'L' => 50,
+            'C' => 100,
+            'D' => 500,
+            'M' => 1000,
+            _ => 0,
+        };
+        if value == 0 || c.is_ascii_lowercase() != lowercase {
+            let expected = ParseExpected::Numeral;
+            return Err((i, ParseReason::UnexpectedChar { expected, found: c }));
+        }
+        if len == letters.len() {
+            return Err((i, ParseReason::PosOverflow));
+        }
+        let overlined = chars.next_if(|&(_, c)| VINCULUM.starts_with(c)).is_some();
+        letters[len] = (value, overlined);
+        len += 1;
+    }
+
+    // A letter before a larger one of the same kind is subtracted (e.g. the "I" of "IV");
+    // whether the numeral is canonical is checked by writing the value back below
+    let (mut thousands, mut n) = (0i64, 0i64);
+    for (i, &(value, overlined)) in letters[..len].iter().enumerate() {
+        let subtract = matches!(
+            letters[i + 1..len].first(),
+            Some(&(next, next_overlined)) if next_overlined == overlined && next > value
+        );
+        let total = if overlined { &mut thousands } else { &mut n };
+        if subtract {
+            *total -= value;
+        } else {
+            *total += value;
+        }
+    }
+    let vinculum = thousands > 0;
+    let n = thousands * 1000 + n;
+    if n > i64::from(MAX_ROMAN_VINCULUM) {
+        return Err((0, ParseReason::PosOverflow));
+    }
+
+    // Only accept the canonical numeral of the value (e.g. not "IIII" or "IC"), failing at the
+    // first letter that differs from it
+    let mut rest = s;
+    let mut matches = n > 0;
+    if matches {
+        write_roman(n as u32, lowercase, vinculum, |piece| {
+            match rest.strip_prefix(piece) {
+                Some(after) if matches => rest = after,
+                _ => matches = false,
+            };
+        });
+    }
+    let position = s.len() - rest.len();
+    match rest.chars().next() {
+        None if matches => Ok((n as u32, lowercase, vinculum)),
+        Some(found) => {
+            let expected = if matches {
+                ParseExpected::End
+            } else {
+                ParseExpected::Numeral
+            };
+            Err((position, ParseReason::UnexpectedChar { expected, found }))
+        }
+        // The numeral would be the start of the canonical one, were there any such numerals
+        None => Err((0, ParseReason::Empty)),
+    }
+}
@@ -11,6 +11,7 @@ pub(crate) mod float;
 mod pretty;
 
 pub use crate::ryu::float::Float;
+pub(crate) use crate::ryu::pretty::{decimal32, decimal64, decimal_digits, format_decimal};
 
 /// Unsafe functions that mirror the API of the C implementation of Ryū.
 pub mod raw {
@@ -3,13 +3,16 @@ mod mantissa;
 
 use self::mantissa::*;
 
-use crate::format::{write_one_byte_with_sep, write_two_bytes_with_sep, Format};
+use crate::constants::F64_MAX_FRAC_DIGITS;
+use crate::format::{
+    write_decimal, write_one_byte_with_sep, write_two_bytes_with_sep, Format, Plain, WriteFormat,
+};
 
 use crate::ryu::common;
 use crate::ryu::d2s::*;
 use crate::ryu::f2s::*;
 use crate::ryu::float::{FloatIeeeData32, FloatIeeeData64};
-use core::ptr;
+use core::{ptr, slice, str};
 
 #[cfg(feature = "no-panic")]
 use no_panic::no_panic;
@@ -60,6 +63,13 @@ where
 {
     let ieee = f.parse_ieee_data();
 
+    // Fall back to the generic writer if the decimal point is not '.'
+    if format.decimal().into_str() != "." {
+        let (mantissa, exponent) = decimal64(&ieee);
+        let format = Plain(format);
+        return format_decimal(result, pos, ieee.is_negative, mantissa, exponent, &format);
+    }
+
     // Bail out early if we can just use ryu
     // (i.e. if we don't have a separator)
     let separator = format.separator().into_str();
@@ -119,6 +129,13 @@ where
 {
     let ieee = f.parse_ieee_data();
 
+    // Fall back to the generic writer if the decimal point is not '.'
+    if format.decimal().into_str() != "." {
+        let (mantissa, exponent) = decimal32(&ieee);
+        let format = Plain(format);
+        return format_decimal(result, pos, ieee.is_negative, mantissa, exponent, &format);
+    }
+
     // Bail out early if we can just use ryu
     // (i.e. if we don't have a separator)
     let separator = format.separator().into_str();
@@ -132,6 +149,71 @@ where
     }
 }
 
+/// Returns the shortest decimal representation of an f64 as `(mantissa, exponent)`, such that
+/// its magnitude is `mantissa * 10^exponent`.
+pub(crate) fn decimal64(ieee: &FloatIeeeData64) -> (u64, i32) {
+    if ieee.exponent == 0 && ieee.mantissa == 0 {
+        return (0, 0);
+    }
+    let v = d2d(ieee.mantissa, ieee.exponent);
+    (v.mantissa, v.exponent)
+}
+
+/// Returns the shortest decimal representation of an f32 as `(mantissa, exponent)`, such that
+/// its magnitude is `mantissa * 10^exponent`.
+pub(crate) fn decimal32(ieee: &FloatIeeeData32) -> (u64, i32) {
+    if ieee.exponent == 0 && ieee.mantissa == 0 {
+        return (0, 0);
+    }
+    let v = f2d(ieee.mantissa, ieee.exponent);
+    (v.mantissa as u64, v.exponent)
+}
+
+/// Writes `mantissa * 10^exponent` in positional notation (e.g. `12340000000.0`, `12.34`, or
+/// `0.001234`) so that it ends at `pos`, using all of the format's symbols, and returns the
+/// index the output starts at.
+#[inline(never)]
+pub(crate) fn format_decimal<Fmt: WriteFormat>(
+    result: *mut u8,
+    pos: usize,
+    sign: bool,
+    mantissa: u64,
+    exponent: i32,
+    format: &Fmt,
+) -> usize {
+    let mut digits = [b'0'; F64_MAX_FRAC_DIGITS];
+    let (int_digits, frac_digits) = decimal_digits(mantissa, exponent, &mut digits);
+
+    // Measure, then write from the computed start index, through a slice so that a `pos` too
+    // small for the output panics rather than writing out of bounds
+    let output = unsafe { slice::from_raw_parts_mut(result, pos) };
+    let mut len = 0;
+    write_decimal(sign, int_digits, frac_digits, format, |s| len += s.len());
+    if len > pos {
+        // A format's fraction separators may be too long to fit between small groups of up to
+        // 324 fraction digits, in which case the fraction digits are written ungrouped
+        let mut len = 0;
+        write_decimal(sign, int_digits, frac_digits, &Plain(format), |s| {
+            len += s.len()
+        });
+        let start = pos - len;
+        let mut index = start;
+        write_decimal(sign, int_digits, frac_digits, &Plain(format), |s| {
+            output[index..index + s.len()].copy_from_slice(s.as_bytes());
+            index += s.len();
+        });
+        return start;
+    }
+    let start = pos - len;
+    let mut index = start;
+    write_decimal(sign, int_digits, frac_digits, format, |s| {
+        output[index..index + s.len()].copy_from_slice(s.as_bytes());
+        index += s.len();
+    });
+
+    start
+}
+
 #[inline(never)]
 fn write_mantissa_with_sep(
     result: *mut u8,
@@ -659,3 +741,51 @@ fn format32_custom(
 
     index as usize
 }
+
+/// Lays out the digits of `mantissa * 10^exponent` around the decimal point in `digits`,
+/// padding with zeros, and returns its integer and fraction digits (e.g. `("12", "34")` for
+/// `1234e-2`). Both have at least one digit.
+pub(crate) fn decimal_digits(
+    mantissa: u64,
+    exponent: i32,
+    digits: &mut [u8; F64_MAX_FRAC_DIGITS],
+) -> (&str, &str) {
+    // Collect the mantissa's digits
+    let mut m = [0u8; 20];
+    let mut start = m.len();
+    let mut n = mantissa;
+    loop {
+        start -= 1;
+        m[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    let m = &m[start..];
+
+    // Lay the digits out around the decimal point, padding with zeros
+    // (an f64 needs more fraction digits than integer digits, so this always fits)
+    let length = m.len() as isize;
+    let k = exponent as isize;
+    let kk = length + k; // 10^(kk-1) <= v < 10^kk
+    let (int_digits, frac_digits): (&[u8], &[u8]) = if mantissa == 0 {
+        (b"0", b"0")
+    } else if k >= 0 {
+        // 1234e7 -> 12340000000.0
+        digits[..m.len()].copy_from_slice(m);
+        (&digits[..kk as usize], b"0")
+    } else if kk > 0 {
+        // 1234e-2 -> 12.34
+        digits[..m.len()].copy_from_slice(m);
+        (&digits[..kk as usize], &digits[kk as usize..m.len()])
+    } else {
+        // 1234e-6 -> 0.001234
+        let zeros = -kk as usize;
+        digits[zeros..zeros + m.len()].copy_from_slice(m);
+        (b"0", &digits[..zeros + m.len()])
+    };
+    let int_digits = unsafe { str::from_utf8_unchecked(int_digits) };
+    let frac_digits = unsafe { str::from_utf8_unchecked(frac_digits) };
+    (int_digits, frac_digits)
+}
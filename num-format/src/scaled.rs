@@ -0,0 +1,48 @@
+/// Type for representing an integer that has been scaled by a power of ten, such as an amount of
+/// cents standing for an amount of dollars. Implements [`ToFormattedStr`] for all the integer
+/// types in the standard library.
+///
+/// The last `scale` digits of the integer are written after the decimal point (e.g. `314159`
+/// with a scale of `5` is written as `3.14159`), which makes it possible to format exact decimal
+/// values without going through a float.
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Locale, Scaled};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&Scaled::new(-123_456_789i64, 2), &Locale::de);
+///     assert_eq!("-1.234.567,89", buf.as_str());
+///
+///     buf.write_formatted(&Scaled::new(5u8, 3), &Locale::en);
+///     assert_eq!("0.005", buf.as_str());
+/// }
+/// ```
+///
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Scaled<N> {
+    value: N,
+    scale: u8,
+}
+
+impl<N> Scaled<N> {
+    /// Constructs a [`Scaled`] representing `value / 10^scale`.
+    ///
+    /// [`Scaled`]: struct.Scaled.html
+    pub fn new(value: N, scale: u8) -> Scaled<N> {
+        Scaled { value, scale }
+    }
+
+    /// Returns the unscaled integer.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the number of digits written after the decimal point.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+}
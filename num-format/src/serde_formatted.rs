@@ -0,0 +1,309 @@
+//! Module with [serde] helpers that write numbers as formatted strings (e.g. `"1,234,567"`) and
+//! read them back with [`parse_formatted`].
+//!
+//! With a format chosen at compile time (see [`StaticFormat`]), [`serialize`] and
+//! [`deserialize`] work with serde's `serialize_with` and `deserialize_with` attributes, and
+//! [`option::serialize`] and [`option::deserialize`] do the same for optional numbers. A
+//! [`Formatted`] number is written and read as a formatted string too. With a format chosen at
+//! run time, [`AsFormatted`] writes a number and [`FormattedSeed`] reads one.
+//!
+//! # Examples
+//! ```
+//! use num_format::{Formatted, Locale, StaticFormat};
+//! use num_format::serde_formatted;
+//! use serde::{Deserialize, Serialize};
+//!
+//! struct English;
+//!
+//! impl StaticFormat for English {
+//!     type Format = Locale;
+//!
+//!     fn format() -> Locale {
+//!         Locale::en
+//!     }
+//! }
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Totals {
+//!     #[serde(
+//!         serialize_with = "serde_formatted::serialize::<English, _, _>",
+//!         deserialize_with = "serde_formatted::deserialize::<English, _, _>"
+//!     )]
+//!     total: u64,
+//!     #[serde(
+//!         serialize_with = "serde_formatted::option::serialize::<English, _, _>",
+//!         deserialize_with = "serde_formatted::option::deserialize::<English, _, _>"
+//!     )]
+//!     missing: Option<i32>,
+//!     average: Formatted<i64, English>,
+//! }
+//!
+//! fn main() {
+//!     let totals = Totals {
+//!         total: 1_234_567,
+//!         missing: None,
+//!         average: Formatted::new(-1234),
+//!     };
+//!     let json = serde_json::to_string(&totals).unwrap();
+//!     assert_eq!(
+//!         r#"{"total":"1,234,567","missing":null,"average":"-1,234"}"#,
+//!         json
+//!     );
+//!
+//!     let json = r#"{"total":"1,234,567","missing":"-89","average":"-1,234"}"#;
+//!     let totals: Totals = serde_json::from_str(json).unwrap();
+//!     assert_eq!(1_234_567, totals.total);
+//!     assert_eq!(Some(-89), totals.missing);
+//! }
+//! ```
+//!
+//! [`AsFormatted`]: struct.AsFormatted.html
+//! [`deserialize`]: fn.deserialize.html
+//! [`Formatted`]: ../struct.Formatted.html
+//! [`FormattedSeed`]: struct.FormattedSeed.html
+//! [`option::deserialize`]: option/fn.deserialize.html
+//! [`option::serialize`]: option/fn.serialize.html
+//! [`parse_formatted`]: ../parsing/trait.ParseFormatted.html#method.parse_formatted
+//! [serde]: https://serde.rs
+//! [`serialize`]: fn.serialize.html
+//! [`StaticFormat`]: ../trait.StaticFormat.html
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::{de, ser};
+
+use crate::format::Format;
+use crate::formatted::{Formatted, StaticFormat};
+use crate::parsing::{FromFormattedStr, ParseFormat, ParseFormatted};
+use crate::to_formatted_string::ToFormattedString;
+
+/// Serializes a number as a string formatted with the format of the [`StaticFormat`] `F` (for
+/// serde's `serialize_with` attribute; see the [module documentation]).
+///
+/// [module documentation]: index.html
+/// [`StaticFormat`]: ../trait.StaticFormat.html
+pub fn serialize<F, N, S>(n: &N, serializer: S) -> Result<S::Ok, S::Error>
+where
+    F: StaticFormat,
+    N: ToFormattedString,
+    S: ser::Serializer,
+{
+    ser::Serialize::serialize(&AsFormatted::new(n, &F::format()), serializer)
+}
+
+/// Deserializes a number from a string formatted with the format of the [`StaticFormat`] `F`
+/// (for serde's `deserialize_with` attribute; see the [module documentation]).
+///
+/// [module documentation]: index.html
+/// [`StaticFormat`]: ../trait.StaticFormat.html
+pub fn deserialize<'de, F, N, D>(deserializer: D) -> Result<N, D::Error>
+where
+    F: StaticFormat,
+    N: FromFormattedStr,
+    D: de::Deserializer<'de>,
+{
+    de::DeserializeSeed::deserialize(FormattedSeed::new(&F::format()), deserializer)
+}
+
+/// Same as the functions of the parent module, but for optional numbers, which are serialized
+/// as formatted strings or as nothing (e.g. `null` in JSON).
+pub mod option {
+    use serde::{de, ser, Deserialize};
+
+    use crate::formatted::{Formatted, StaticFormat};
+    use crate::parsing::FromFormattedStr;
+    use crate::to_formatted_string::ToFormattedString;
+
+    use super::AsFormatted;
+
+    /// Serializes an optional number as a string formatted with the format of the
+    /// [`StaticFormat`] `F`, or as nothing.
+    ///
+    /// [`StaticFormat`]: ../../trait.StaticFormat.html
+    pub fn serialize<F, N, S>(n: &Option<N>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: StaticFormat,
+        N: ToFormattedString,
+        S: ser::Serializer,
+    {
+        match n {
+            Some(n) => serializer.serialize_some(&AsFormatted::new(n, &F::format())),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional number from a string formatted with the format of the
+    /// [`StaticFormat`] `F`, or from nothing.
+    ///
+    /// [`StaticFormat`]: ../../trait.StaticFormat.html
+    pub fn deserialize<'de, F, N, D>(deserializer: D) -> Result<Option<N>, D::Error>
+    where
+        F: StaticFormat,
+        N: FromFormattedStr,
+        D: de::Deserializer<'de>,
+    {
+        let n = Option::<Formatted<N, F>>::deserialize(deserializer)?;
+        Ok(n.map(Formatted::into_value))
+    }
+}
+
+/// Type that serializes the number it borrows as a string formatted with the format it
+/// borrows, for formats that are chosen at run time.
+///
+/// # Example
+/// ```rust
+/// use num_format::{CustomFormat, Locale};
+/// use num_format::serde_formatted::AsFormatted;
+///
+/// fn main() {
+///     let format = CustomFormat::builder().separator("'").build().unwrap();
+///     let json = serde_json::to_string(&AsFormatted::new(&-1234567, &format)).unwrap();
+///     assert_eq!(r#""-1'234'567""#, json);
+///
+///     let totals = [1234, 5678].iter().map(|n| AsFormatted::new(n, &Locale::de));
+///     let json = serde_json::to_string(&totals.collect::<Vec<_>>()).unwrap();
+///     assert_eq!(r#"["1.234","5.678"]"#, json);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsFormatted<'a, N, F> {
+    format: &'a F,
+    value: &'a N,
+}
+
+impl<'a, N, F> AsFormatted<'a, N, F> {
+    /// Constructs an [`AsFormatted`] from the number to serialize and the format to write it
+    /// with.
+    ///
+    /// [`AsFormatted`]: struct.AsFormatted.html
+    pub fn new(value: &'a N, format: &'a F) -> AsFormatted<'a, N, F> {
+        AsFormatted { format, value }
+    }
+}
+
+impl<N, F> Clone for AsFormatted<'_, N, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, F> Copy for AsFormatted<'_, N, F> {}
+
+impl<N, F> ser::Serialize for AsFormatted<'_, N, F>
+where
+    N: ToFormattedString,
+    F: Format,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.value.to_formatted_string(self.format))
+    }
+}
+
+/// Type that deserializes a number from a string formatted with the format it borrows, for
+/// formats that are chosen at run time (see serde's [`DeserializeSeed`]).
+///
+/// # Example
+/// ```rust
+/// use num_format::Locale;
+/// use num_format::serde_formatted::FormattedSeed;
+/// use serde::de::DeserializeSeed;
+///
+/// fn main() {
+///     let mut deserializer = serde_json::Deserializer::from_str(r#""-1.234.567""#);
+///     let n: i32 = FormattedSeed::new(&Locale::de)
+///         .deserialize(&mut deserializer)
+///         .unwrap();
+///     assert_eq!(-1_234_567, n);
+/// }
+/// ```
+///
+/// [`DeserializeSeed`]: https://docs.rs/serde/1/serde/de/trait.DeserializeSeed.html
+#[derive(Debug)]
+pub struct FormattedSeed<'a, N, F> {
+    format: &'a F,
+    phantom: PhantomData<fn() -> N>,
+}
+
+impl<'a, N, F> FormattedSeed<'a, N, F> {
+    /// Constructs a [`FormattedSeed`] from the format to read numbers with.
+    ///
+    /// [`FormattedSeed`]: struct.FormattedSeed.html
+    pub fn new(format: &'a F) -> FormattedSeed<'a, N, F> {
+        FormattedSeed {
+            format,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<N, F> Clone for FormattedSeed<'_, N, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, F> Copy for FormattedSeed<'_, N, F> {}
+
+impl<'de, N, F> de::DeserializeSeed<'de> for FormattedSeed<'_, N, F>
+where
+    N: FromFormattedStr,
+    F: ParseFormat,
+{
+    type Value = N;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<N, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, N, F> de::Visitor<'de> for FormattedSeed<'_, N, F>
+where
+    N: FromFormattedStr,
+    F: ParseFormat,
+{
+    type Value = N;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a formatted number")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<N, E>
+    where
+        E: de::Error,
+    {
+        s.parse_formatted(self.format).map_err(de::Error::custom)
+    }
+}
+
+impl<N, F> ser::Serialize for Formatted<N, F>
+where
+    N: ToFormattedString,
+    F: StaticFormat,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize::<F, N, S>(self.value(), serializer)
+    }
+}
+
+impl<'de, N, F> de::Deserialize<'de> for Formatted<N, F>
+where
+    N: FromFormattedStr,
+    F: StaticFormat,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserialize::<F, N, D>(deserializer).map(Formatted::new)
+    }
+}
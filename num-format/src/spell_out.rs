@@ -0,0 +1,87 @@
+use crate::locale::Locale;
+
+/// Type for representing an integer spelled out in words in the language of a [`Locale`] (e.g.
+/// `one thousand two hundred thirty-four`). Implements [`ToFormattedStr`] for all the integer
+/// types in the standard library and, with the `with-num-bigint` feature, [`ToFormattedString`]
+/// for `BigInt` and `BigUint`.
+///
+/// The number is spelled out with the CLDR rule-based number format rules for spelling out
+/// numbers of its [`Locale`], whose cardinal forms (e.g. `Million` or `Millionen`) are picked
+/// by the language's plural rules. Numbers of a quintillion (10<sup>18</sup>) and above are
+/// written in digits, with the grouping of the [`Format`], as are all numbers in locales whose
+/// language has no spell-out rules (see [`Locale::supports_spell_out`]).
+///
+/// Some languages join the words of a number with soft hyphens (U+00AD), which are only
+/// shown where a line is broken (e.g. `ein­und­zwanzig` in German).
+///
+/// # Example
+/// ```rust
+/// use num_format::{Buffer, Locale, SpellOut};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+///     buf.write_formatted(&SpellOut::new(1234, Locale::en), &Locale::en);
+///     assert_eq!("one thousand two hundred thirty-four", buf.as_str());
+///
+///     buf.write_formatted(&SpellOut::new(-45, Locale::fr), &Locale::fr);
+///     assert_eq!("moins quarante-cinq", buf.as_str());
+///
+///     buf.write_formatted(&SpellOut::new(2_000_000, Locale::de), &Locale::de);
+///     assert_eq!("zwei Millionen", buf.as_str());
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`Locale`]: enum.Locale.html
+/// [`Locale::supports_spell_out`]: enum.Locale.html#method.supports_spell_out
+/// [`ToFormattedStr`]: trait.ToFormattedStr.html
+/// [`ToFormattedString`]: trait.ToFormattedString.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct SpellOut<N> {
+    value: N,
+    locale: Locale,
+}
+
+impl<N> SpellOut<N> {
+    /// Constructs a [`SpellOut`] for the given value, spelled out in the language of the given
+    /// [`Locale`].
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`SpellOut`]: struct.SpellOut.html
+    pub fn new(value: N, locale: Locale) -> SpellOut<N> {
+        SpellOut { value, locale }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns the [`Locale`] whose language the value is spelled out in.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+}
+
+impl Locale {
+    /// Returns `true` if [`SpellOut`] spells out numbers in words in the locale's language, or
+    /// `false` if it writes them in digits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use num_format::Locale;
+    ///
+    /// fn main() {
+    ///     assert!(Locale::en_GB.supports_spell_out());
+    ///     assert!(!Locale::ja.supports_spell_out());
+    /// }
+    /// ```
+    ///
+    /// [`SpellOut`]: struct.SpellOut.html
+    pub fn supports_spell_out(&self) -> bool {
+        self.spellout_rules().is_some()
+    }
+}
@@ -3,13 +3,15 @@ use core::ops::Deref;
 
 use arrayvec::ArrayString;
 
-const MAX_DEC_LEN: usize = 8;
+pub(crate) const MAX_DEC_LEN: usize = 8;
 pub(crate) const MAX_ERR_LEN: usize = 256;
 const MAX_INF_LEN: usize = 128;
 pub(crate) const MAX_MIN_LEN: usize = 8;
 const MAX_NAN_LEN: usize = 64;
-const MAX_PLUS_LEN: usize = 8;
+pub(crate) const MAX_PLUS_LEN: usize = 8;
+pub(crate) const MAX_PRE_LEN: usize = 8;
 pub(crate) const MAX_SEP_LEN: usize = 8;
+pub(crate) const MAX_UNIT_LEN: usize = 16;
 
 #[cfg(feature = "with-serde")]
 use serde::{de, ser};
@@ -282,4 +284,6 @@ create_string!(InfString, InfVisitor, MAX_INF_LEN);
 create_string!(MinString, MinVisitor, MAX_MIN_LEN);
 create_string!(NanString, NanVisitor, MAX_NAN_LEN);
 create_string!(PlusString, PlusVisitor, MAX_PLUS_LEN);
+create_string!(PreString, PreVisitor, MAX_PRE_LEN);
 create_string!(SepString, SepVisitor, MAX_SEP_LEN);
+create_string!(UnitString, UnitVisitor, MAX_UNIT_LEN);
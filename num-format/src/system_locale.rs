@@ -8,6 +8,8 @@ use std::collections::HashSet;
 use crate::error::Error;
 use crate::format::Format;
 use crate::grouping::Grouping;
+use crate::locale::Locale;
+use crate::parsing::ParseFormat;
 use crate::strings::{
     DecString, DecimalStr, InfString, InfinityStr, MinString, MinusSignStr, NanStr, NanString,
     PlusSignStr, PlusString, SepString, SeparatorStr,
@@ -249,3 +251,21 @@ impl Format for SystemLocale {
         SeparatorStr::new(self.separator()).unwrap()
     }
 }
+
+impl ParseFormat for SystemLocale {
+    #[inline(always)]
+    fn locale(&self) -> Option<Locale> {
+        // Drop the encoding and modifier (e.g. "de_DE.UTF-8@euro"), then fall back from the
+        // region to the language until a known locale is found
+        let mut name = self.name.split(['.', '@']).next().unwrap();
+        loop {
+            if let Ok(locale) = Locale::from_name(name) {
+                return Some(locale);
+            }
+            match name.rfind(['_', '-']) {
+                Some(i) => name = &name[..i],
+                None => return None,
+            }
+        }
+    }
+}
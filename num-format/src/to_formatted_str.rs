@@ -1,5 +1,5 @@
 use crate::buffer::Buffer;
-use crate::format::Format;
+use crate::format::WriteFormat;
 
 /// Marker trait for number types that can be formatted without heap allocation (see [`Buffer`]).
 ///
@@ -10,5 +10,5 @@ pub trait ToFormattedStr: crate::private::Sealed + Sized {
     #[doc(hidden)]
     fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
     where
-        F: Format;
+        F: WriteFormat;
 }
@@ -5,6 +5,7 @@ use std::io;
 
 use crate::constants::MAX_BUF_LEN;
 
+use crate::format::{Plain, WriteFormat};
 use crate::{Buffer, Format, ToFormattedStr};
 
 /// <b><u>A key trait</u></b>. Gives numbers the [`to_formatted_string`] method.
@@ -16,13 +17,13 @@ pub trait ToFormattedString: crate::private::Sealed + Sized {
     #[doc(hidden)]
     fn read_to_fmt_writer<F, W>(&self, w: W, format: &F) -> Result<usize, fmt::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: fmt::Write;
 
     #[doc(hidden)]
     fn read_to_io_writer<F, W>(&self, w: W, format: &F) -> Result<usize, io::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: io::Write;
 
     /// Returns a string representation of the number formatted according to the provided format.
@@ -31,7 +32,7 @@ pub trait ToFormattedString: crate::private::Sealed + Sized {
         F: Format,
     {
         let mut s = String::with_capacity(MAX_BUF_LEN);
-        let _ = self.read_to_fmt_writer(&mut s, format).unwrap();
+        let _ = self.read_to_fmt_writer(&mut s, &Plain(format)).unwrap();
         s
     }
 }
@@ -43,7 +44,7 @@ where
     #[inline(never)]
     fn read_to_fmt_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, fmt::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: fmt::Write,
     {
         let mut buf = Buffer::default();
@@ -55,7 +56,7 @@ where
     #[inline(never)]
     fn read_to_io_writer<F, W>(&self, mut w: W, format: &F) -> Result<usize, io::Error>
     where
-        F: Format,
+        F: WriteFormat,
         W: io::Write,
     {
         let mut buf = Buffer::default();
@@ -64,3 +65,58 @@ where
         Ok(c)
     }
 }
+
+/// Trait for values that may not fit into a [`Buffer`] (e.g. a range of two floats, each
+/// written with hundreds of digits), which are written in pieces instead.
+///
+/// [`Buffer`]: struct.Buffer.html
+pub(crate) trait WritePieces {
+    /// Passes the pieces of the value, as written with the format, to `w` in order.
+    fn write_pieces<F>(&self, format: &F, w: &mut dyn FnMut(&str))
+    where
+        F: WriteFormat;
+}
+
+/// Writes a [`WritePieces`] value to a `fmt::Write`, and returns the number of bytes written.
+///
+/// [`WritePieces`]: trait.WritePieces.html
+pub(crate) fn write_pieces_to_fmt<V, F, W>(
+    value: &V,
+    mut w: W,
+    format: &F,
+) -> Result<usize, fmt::Error>
+where
+    V: WritePieces,
+    F: WriteFormat,
+    W: fmt::Write,
+{
+    let mut result = Ok(0);
+    value.write_pieces(format, &mut |s| {
+        if let Ok(c) = result {
+            result = w.write_str(s).map(|_| c + s.len());
+        }
+    });
+    result
+}
+
+/// Writes a [`WritePieces`] value to an `io::Write`, and returns the number of bytes written.
+///
+/// [`WritePieces`]: trait.WritePieces.html
+pub(crate) fn write_pieces_to_io<V, F, W>(
+    value: &V,
+    mut w: W,
+    format: &F,
+) -> Result<usize, io::Error>
+where
+    V: WritePieces,
+    F: WriteFormat,
+    W: io::Write,
+{
+    let mut result = Ok(0);
+    value.write_pieces(format, &mut |s| {
+        if let Ok(c) = result {
+            result = w.write_all(s.as_bytes()).map(|_| c + s.len());
+        }
+    });
+    result
+}
@@ -0,0 +1,941 @@
+#![doc = r"Note: This module was autogenerated by num-format-dev."]
+use crate::locale::Locale;
+use crate::measure::{MeasureUnit, UnitWidth};
+#[doc = r#" Unit patterns, indexed by plural category (an empty pattern means "use other")."#]
+static PATTERNS : [[& str ; 6] ; 2684] = [["" , "{0} kilometer" , "" , "" , "" , "{0} kilometer"] , ["" , "{0} meter" , "" , "" , "" , "{0} meter"] , ["" , "{0} sentimeter" , "" , "" , "" , "{0} sentimeter"] , ["" , "{0} millimeter" , "" , "" , "" , "{0} millimeter"] , ["" , "{0} myl" , "" , "" , "" , "{0} myl"] , ["" , "{0} jaart" , "" , "" , "" , "{0} jaart"] , ["" , "{0} voet" , "" , "" , "" , "{0} voet"] , ["" , "{0} duim" , "" , "" , "" , "{0} duim"] , ["" , "{0} kilogram" , "" , "" , "" , "{0} kilogram"] , ["" , "{0} gram" , "" , "" , "" , "{0} gram"] , ["" , "{0} pond" , "" , "" , "" , "{0} pond"] , ["" , "{0} ons" , "" , "" , "" , "{0} onse"] , ["" , "{0} kilometer per uur" , "" , "" , "" , "{0} kilometer per uur"] , ["" , "{0} meter per sekonde" , "" , "" , "" , "{0} meter per sekonde"] , ["" , "{0} myl per uur" , "" , "" , "" , "{0} myl per uur"] , ["" , "{0} graad Celsius" , "" , "" , "" , "{0} grade Celsius"] , ["" , "{0} graad Fahrenheit" , "" , "" , "" , "{0} grade Fahrenheit"] , ["" , "{0} persent" , "" , "" , "" , "{0} persent"] , ["" , "{0} km" , "" , "" , "" , "{0} km"] , ["" , "{0} m" , "" , "" , "" , "{0} m"] , ["" , "{0} cm" , "" , "" , "" , "{0} cm"] , ["" , "{0} mm" , "" , "" , "" , "{0} mm"] , ["" , "{0} jt." , "" , "" , "" , "{0} jt."] , ["" , "{0} vt." , "" , "" , "" , "{0} vt."] , ["" , "{0} kg" , "" , "" , "" , "{0} kg"] , ["" , "{0} g" , "" , "" , "" , "{0} g"] , ["" , "{0} lb." , "" , "" , "" , "{0} lb."] , ["" , "{0} ons." , "" , "" , "" , "{0} ons."] , ["" , "{0} km/h" , "" , "" , "" , "{0} km/h"] , ["" , "{0} m/s." , "" , "" , "" , "{0} m/s."] , ["" , "{0} myl/h" , "" , "" , "" , "{0} myl/h"] , ["" , "{0} °C" , "" , "" , "" , "{0} °C"] , ["" , "{0} °F" , "" , "" , "" , "{0} °F"] , ["" , "{0}%" , "" , "" , "" , "{0}%"] , ["" , "{0}myl" , "" , "" , "" , "{0}myl"] , ["" , "{0}jt." , "" , "" , "" , "{0}jt."] , ["" , "{0}vt." , "" , "" , "" , "{0}vt."] , ["" , "{0}″" , "" , "" , "" , "{0}″"] , ["" , "{0}kg" , "" , "" , "" , "{0}kg"] , ["" , "{0}g" , "" , "" , "" , "{0}g"] , ["" , "{0}#" , "" , "" , "" , "{0}#"] , ["" , "{0}ons." , "" , "" , "" , "{0}ons."] , ["" , "{0}m/s." , "" , "" , "" , "{0}m/s."] , ["" , "{0}mph" , "" , "" , "" , "{0}mph"] , ["" , "{0}°" , "" , "" , "" , "{0}°"] , ["" , "" , "" , "" , "" , "{0} km"] , ["" , "" , "" , "" , "" , "{0} m"] , ["" , "" , "" , "" , "" , "{0} cm"] , ["" , "" , "" , "" , "" , "{0} mm"] , ["" , "" , "" , "" , "" , "{0} mi"] , ["" , "" , "" , "" , "" , "{0} yd"] , ["" , "" , "" , "" , "" , "{0} ft"] , ["" , "" , "" , "" , "" , "{0} in"] , ["" , "" , "" , "" , "" , "{0} kg"] , ["" , "" , "" , "" , "" , "{0} g"] , ["" , "" , "" , "" , "" , "{0} lb"] , ["" , "" , "" , "" , "" , "{0} oz"] , ["" , "" , "" , "" , "" , "{0} km/h"] , ["" , "" , "" , "" , "" , "{0} m/s"] , ["" , "" , "" , "" , "" , "{0} mi/h"] , ["" , "" , "" , "" , "" , "{0}°C"] , ["" , "" , "" , "" , "" , "{0}°F"] , ["" , "" , "" , "" , "" , "{0}%"] , ["" , "{0} ኪሎሜትር" , "" , "" , "" , "{0} ኪሎሜትር"] , ["" , "{0} ሜትር" , "" , "" , "" , "{0} ሜትር"] , ["" , "{0} ሴንቲሜትር" , "" , "" , "" , "{0} ሴንቲሜትር"] , ["" , "{0} ሚሊሜትር" , "" , "" , "" , "{0} ሚሊሜትር"] , ["" , "{0} ማይል" , "" , "" , "" , "{0} ማይል"] , ["" , "{0} ያርድ" , "" , "" , "" , "{0} ያርድ"] , ["" , "{0} ጫማ" , "" , "" , "" , "{0} ጫማ"] , ["" , "{0} ኢንች" , "" , "" , "" , "{0} ኢንች"] , ["" , "{0} ኪሎግራም" , "" , "" , "" , "{0} ኪሎግራም"] , ["" , "{0} ግራም" , "" , "" , "" , "{0} ግራም"] , ["" , "{0} ፓውንድ" , "" , "" , "" , "{0} ፓውንድ"] , ["" , "{0} አውንስ" , "" , "" , "" , "{0} አውንስ"] , ["" , "{0} ኪሎሜትር በሰዓት" , "" , "" , "" , "{0} ኪሎሜትር በሰዓት"] , ["" , "{0} ሜትር በሰከንድ" , "" , "" , "" , "{0} ሜትር በሰከንድ"] , ["" , "{0} ማይል በሰዓት" , "" , "" , "" , "{0} ማይል በሰዓት"] , ["" , "{0} ዲግሪ ሴልሺየስ" , "" , "" , "" , "{0} ዲግሪ ሴልሺየስ"] , ["" , "{0} ዲግሪ ፋራንሃይት" , "" , "" , "" , "{0} ዲግሪ ፋራንሃይት"] , ["" , "{0} ፐርሰንት" , "" , "" , "" , "{0} ፐርሰንት"] , ["" , "{0} ኪሜ" , "" , "" , "" , "{0} ኪሜ"] , ["" , "{0} ሜ" , "" , "" , "" , "{0} ሜ"] , ["" , "{0} ሴሜ" , "" , "" , "" , "{0} ሴሜ"] , ["" , "{0} ሚሜ" , "" , "" , "" , "{0} ሚሜ"] , ["" , "{0} ኪግ" , "" , "" , "" , "{0} ኪግ"] , ["" , "{0} ግ" , "" , "" , "" , "{0} ግ"] , ["" , "{0} ኪሜ/ሰ" , "" , "" , "" , "{0} ኪሜ/ሰ"] , ["" , "{0} ሜ/ሴ" , "" , "" , "" , "{0} ሜ/ሴ"] , ["" , "{0} ማይል/ሰ" , "" , "" , "" , "{0} ማይል/ሰ"] , ["" , "{0}°ሴ" , "" , "" , "" , "{0}°ሴ"] , ["" , "{0}°ፋ" , "" , "" , "" , "{0}°ፋ"] , ["{0} كيلومتر" , "{0} كيلومتر" , "{0} كيلومتر" , "{0} كيلومتر" , "{0} كيلومتر" , "{0} كيلومتر"] , ["{0} متر" , "متر" , "متران" , "{0} أمتار" , "{0} متر\u{64b}ا" , "{0} متر"] , ["{0} سنتيمتر" , "{0} سنتيمتر" , "{0} سنتيمتر" , "{0} سنتيمتر" , "{0} سنتيمتر" , "{0} سنتيمتر"] , ["{0} مليمتر" , "{0} مليمتر" , "{0} مليمتر" , "{0} مليمتر" , "{0} مليمتر" , "{0} مليمتر"] , ["{0} ميل" , "ميل" , "ميلان" , "{0} أميال" , "{0} ميلا\u{64b}" , "{0} ميل"] , ["{0} ياردة" , "ياردة" , "{0} ياردة" , "{0} ياردة" , "{0} ياردة" , "{0} ياردة"] , ["{0} قدم" , "قدم" , "{0} قدم" , "{0} قدم" , "{0} قدم" , "{0} قدم"] , ["{0} بوصة" , "{0} بوصة" , "{0} بوصة" , "{0} بوصة" , "{0} بوصة" , "{0} بوصة"] , ["{0} كيلوغرام" , "{0} كيلوغرام" , "{0} كيلوغرام" , "{0} كيلوغرام" , "{0} كيلوغرام" , "{0} كيلوغرام"] , ["{0} غرام" , "غرام" , "غرامان" , "{0} غرامات" , "{0} غرام\u{64b}ا" , "{0} غرام"] , ["{0} رطل" , "{0} رطل" , "رطلان" , "{0} رطل" , "{0} رطل\u{64b}ا" , "{0} رطل"] , ["{0} أونصة" , "{0} أونصة" , "{0} أونصة" , "{0} أونصة" , "{0} أونصة" , "{0} أونصة"] , ["{0} كيلومتر في الساعة" , "{0} كيلومتر في الساعة" , "{0} كيلومتر في الساعة" , "{0} كيلومتر في الساعة" , "{0} كيلومتر في الساعة" , "{0} كيلومتر في الساعة"] , ["{0} متر في الثانية" , "{0} متر في الثانية" , "{0} متر في الثانية" , "{0} متر في الثانية" , "{0} متر في الثانية" , "{0} متر في الثانية"] , ["{0} ميل في الساعة" , "{0} ميل في الساعة" , "{0} ميل في الساعة" , "{0} ميل في الساعة" , "{0} ميل في الساعة" , "{0} ميل في الساعة"] , ["{0} درجة مئوية" , "{0} درجة مئوية" , "{0} درجة مئوية" , "{0} درجة مئوية" , "{0} درجة مئوية" , "{0} درجة مئوية"] , ["{0} درجة فهرنهايت" , "{0} درجة فهرنهايت" , "{0} درجة فهرنهايت" , "{0} درجة فهرنهايت" , "{0} درجة فهرنهايت" , "{0} درجة فهرنهايت"] , ["{0}٪" , "{0} بالمائة" , "{0}٪" , "{0}٪" , "{0}٪" , "{0} بالمائة"] , ["{0} كم" , "{0} كم" , "{0} كم" , "{0} كم" , "{0} كم" , "{0} كم"] , ["{0} سم" , "{0} سم" , "{0} سم" , "{0} سم" , "{0} سم" , "{0} سم"] , ["{0} مم" , "{0} مم" , "{0} مم" , "{0} مم" , "{0} مم" , "{0} مم"] , ["{0} ميل" , "ميل" , "{0} ميل" , "{0} ميل" , "{0} ميل" , "{0} ميل"] , ["{0} كغم" , "{0} كغم" , "{0} كغم" , "{0} كغم" , "{0} كغم" , "{0} كغم"] , ["{0} غرام" , "غرام" , "{0} غرام" , "{0} غرام" , "{0} غرام" , "{0} غرام"] , ["{0} رطل" , "{0} رطل" , "{0} رطل" , "{0} رطل" , "{0} رطل" , "{0} رطل"] , ["{0} أونصة" , "أونصة" , "{0} أونصة" , "{0} أونصة" , "{0} أونصة" , "{0} أونصة"] , ["{0} كم/س" , "{0} كم/س" , "{0} كم/س" , "{0} كم/س" , "{0} كم/س" , "{0} كم/س"] , ["{0} م/ث" , "{0} م/ث" , "{0} م/ث" , "{0} م/ث" , "{0} م/ث" , "{0} م/ث"] , ["{0} ميل/س" , "{0} ميل/س" , "{0} ميل/س" , "{0} ميل/س" , "{0} ميل/س" , "{0} ميل/س"] , ["{0}°م" , "{0}°م" , "{0}°م" , "{0}°م" , "{0}°م" , "{0}°م"] , ["{0}°ف" , "{0}°ف" , "{0}°ف" , "{0}°ف" , "{0}°ف" , "{0}°ف"] , ["{0}٪" , "{0}٪" , "{0}٪" , "{0}٪" , "{0}٪" , "{0}٪"] , ["{0} م" , "{0} م" , "{0} م" , "{0} م" , "{0} م" , "{0} م"] , ["{0} ميل" , "{0} ميل" , "{0} ميل" , "{0} أميال" , "{0} ميلا\u{64b}" , "{0} ميل"] , ["{0} ياردة" , "{0} ياردة" , "{0} ياردة" , "{0} ياردة" , "{0} ياردة" , "{0} ياردة"] , ["{0} قدم" , "قدم" , "{0} قدم" , "{0} قدم" , "{0} قدم\u{64b}ا" , "{0} قدم"] , ["{0} كغ" , "{0} كغ" , "{0} كغ" , "{0} كغ" , "{0} كغ" , "{0} كغ"] , ["{0} غ" , "{0} غ" , "{0} غ" , "{0} غ" , "{0} غ" , "{0} غ"] , ["{0} كيلومتر" , "{0} كيلومتر" , "كيلومتران" , "{0} كيلومترات" , "{0} كيلومتر\u{64b}ا" , "{0} كيلومتر"] , ["{0} سنتيمتر" , "{0} سنتيمتر" , "سنتيمتران" , "{0} سنتيمترات" , "{0} سنتيمتر\u{64b}ا" , "{0} سنتيمتر"] , ["{0} مليمتر" , "{0} مليمتر" , "مليمتران" , "{0} مليمتر" , "{0} مليمتر\u{64b}ا" , "{0} مليمتر"] , ["{0} ياردة" , "ياردة" , "ياردتان" , "{0} ياردات" , "{0} ياردة" , "{0} ياردة"] , ["{0} قدم" , "قدم" , "قدمان" , "{0} أقدام" , "{0} قدم\u{64b}ا" , "{0} قدم"] , ["{0} بوصة" , "{0} بوصة" , "بوصتان" , "{0} بوصات" , "{0} بوصة" , "{0} بوصة"] , ["{0} كيلوغرام" , "{0} كيلوغرام" , "كيلوغرامان" , "{0} كيلوغرامات" , "{0} كيلوغرام\u{64b}ا" , "{0} كيلوغرام"] , ["{0} رطل" , "{0} رطل" , "رطلان" , "{0} أرطال" , "{0} رطل\u{64b}ا" , "{0} رطل"] , ["{0} كيلومتر في الساعة" , "{0} كيلومتر في الساعة" , "كيلومتران في الساعة" , "{0} كيلومترات في الساعة" , "{0} كيلومتر\u{64b}ا في الساعة" , "{0} كيلومتر في الساعة"] , ["{0} متر في الثانية" , "{0} متر في الثانية" , "متران في الثانية" , "{0} أمتار في الثانية" , "{0} متر\u{64b}ا في الثانية" , "{0} متر في الثانية"] , ["{0} ميل في الساعة" , "{0} ميل في الساعة" , "ميلان في الساعة" , "{0} أميال في الساعة" , "{0} ميل\u{64b}ا في الساعة" , "{0} ميل في الساعة"] , ["{0} ميل" , "ميل" , "ميلان" , "{0} أميال" , "{0} ميل" , "{0} ميل"] , ["{0} رطل" , "{0} رطل" , "رطلان" , "{0} أرطال" , "{0} رطل\u{64f}ا" , "{0} رطل"] , ["{0} ميل/س" , "{0} ميل/س" , "ميلان/س" , "{0} أميال/س" , "{0} ميل\u{64b}ا/س" , "{0} ميل/س"] , ["{0} ياردة" , "{0} ياردة" , "ياردتان" , "{0} ياردة" , "{0} ياردة" , "{0} ياردة"] , ["{0} غم" , "{0} غم" , "{0} غم" , "{0} غم" , "{0} غم" , "{0} غم"] , ["" , "{0} কিলোমিট\u{9be}ৰ" , "" , "" , "" , "{0} কিলোমিট\u{9be}ৰ"] , ["" , "{0} মিট\u{9be}ৰ" , "" , "" , "" , "{0} মিট\u{9be}ৰ"] , ["" , "{0} ছেণ\u{9cd}টিমিট\u{9be}ৰ" , "" , "" , "" , "{0} ছেণ\u{9cd}টিমিট\u{9be}ৰ"] , ["" , "{0} মিলিমিট\u{9be}ৰ" , "" , "" , "" , "{0} মিলিমিট\u{9be}ৰ"] , ["" , "{0} ম\u{9be}ইল" , "" , "" , "" , "{0} ম\u{9be}ইল"] , ["" , "{0} গজ" , "" , "" , "" , "{0} গজ"] , ["" , "{0} ফ\u{9c1}ট" , "" , "" , "" , "{0} ফ\u{9c1}ট"] , ["" , "{0} ইঞ\u{9cd}চি" , "" , "" , "" , "{0} ইঞ\u{9cd}চি"] , ["" , "{0} কিলোগ\u{9cd}ৰ\u{9be}ম" , "" , "" , "" , "{0} কিলোগ\u{9cd}ৰ\u{9be}ম"] , ["" , "{0} গ\u{9cd}ৰ\u{9be}ম" , "" , "" , "" , "{0} গ\u{9cd}ৰ\u{9be}ম"] , ["" , "{0} প\u{9be}উণ\u{9cd}ড" , "" , "" , "" , "{0} প\u{9be}উণ\u{9cd}ড"] , ["" , "{0} আউন\u{9cd}স" , "" , "" , "" , "{0} আউন\u{9cd}স"] , ["" , "{0} ঘণ\u{9cd}ট\u{9be} প\u{9cd}ৰতি কিলোমিট\u{9be}ৰ" , "" , "" , "" , "{0} ঘণ\u{9cd}ট\u{9be} প\u{9cd}ৰতি কিলোমিট\u{9be}ৰ"] , ["" , "{0} মিট\u{9be}ৰ প\u{9cd}ৰতি ছেকেণ\u{9cd}ড" , "" , "" , "" , "{0} মিট\u{9be}ৰ প\u{9cd}ৰতি ছেকেণ\u{9cd}ড"] , ["" , "{0} ম\u{9be}ইল প\u{9cd}ৰতি ঘণ\u{9cd}ট\u{9be}" , "" , "" , "" , "{0} ম\u{9be}ইল প\u{9cd}ৰতি ঘণ\u{9cd}ট\u{9be}"] , ["" , "{0} ডিগ\u{9cd}ৰী চেলচিয\u{9bc}\u{9be}ছ" , "" , "" , "" , "{0} ডিগ\u{9cd}ৰী চেলচিয\u{9bc}\u{9be}ছ"] , ["" , "{0} ডিগ\u{9cd}ৰী ফ\u{9be}ৰেনহ\u{9be}ইট" , "" , "" , "" , "{0} ডিগ\u{9cd}ৰী ফ\u{9be}ৰেনহ\u{9be}ইট"] , ["" , "{0} শত\u{9be}ংশ" , "" , "" , "" , "{0} শত\u{9be}ংশ"] , ["" , "{0} কিঃ মিঃ" , "" , "" , "" , "{0} কিঃ মিঃ"] , ["" , "{0} মিঃ" , "" , "" , "" , "{0} মিঃ"] , ["" , "{0} ছেঃ মিঃ" , "" , "" , "" , "{0} ছেঃ মিঃ"] , ["" , "{0} মিঃ মিঃ" , "" , "" , "" , "{0} মিঃ মিঃ"] , ["" , "{0} ম\u{9be}ঃ" , "" , "" , "" , "{0} ম\u{9be}ঃ"] , ["" , "{0} ফ\u{9c1}ঃ" , "" , "" , "" , "{0} ফ\u{9c1}ঃ"] , ["" , "{0} ইঃ" , "" , "" , "" , "{0} ইঃ"] , ["" , "{0} কিঃ গ\u{9cd}ৰ\u{9be}ঃ" , "" , "" , "" , "{0} কিঃ গ\u{9cd}ৰ\u{9be}ঃ"] , ["" , "{0} গ\u{9cd}ৰ\u{9be}ঃ" , "" , "" , "" , "{0} গ\u{9cd}ৰ\u{9be}ঃ"] , ["" , "{0} কিঃ মিঃ/ঘঃ" , "" , "" , "" , "{0} কিঃ মিঃ/ঘঃ"] , ["" , "{0} মিঃ/ছেঃ" , "" , "" , "" , "{0} মিঃ/ছেঃ"] , ["" , "{0} ম\u{9be}ঃ/ঘঃ" , "" , "" , "" , "{0} ম\u{9be}ঃ/ঘঃ"] , ["" , "{0}°চেঃ" , "" , "" , "" , "{0}°চেঃ"] , ["" , "{0}°ফ\u{9be}ঃ" , "" , "" , "" , "{0}°ফ\u{9be}ঃ"] , ["" , "{0} quilómetru" , "" , "" , "" , "{0} quilómetros"] , ["" , "{0} metru" , "" , "" , "" , "{0} metros"] , ["" , "{0} centímetru" , "" , "" , "" , "{0} centímetros"] , ["" , "{0} milímetru" , "" , "" , "" , "{0} milímetros"] , ["" , "{0} milla" , "" , "" , "" , "{0} milles"] , ["" , "{0} yarda" , "" , "" , "" , "{0} yardes"] , ["" , "{0} pie" , "" , "" , "" , "{0} pies"] , ["" , "{0} pulgada" , "" , "" , "" , "{0} pulgaes"] , ["" , "{0} quilogramu" , "" , "" , "" , "{0} quilogramos"] , ["" , "{0} gramu" , "" , "" , "" , "{0} gramos"] , ["" , "{0} llibra" , "" , "" , "" , "{0} llibres"] , ["" , "{0} onza" , "" , "" , "" , "{0} onces"] , ["" , "{0} quilómetru per hora" , "" , "" , "" , "{0} quilómetros per hora"] , ["" , "{0} metru per segundu" , "" , "" , "" , "{0} metros per segundu"] , ["" , "{0} milla per hora" , "" , "" , "" , "{0} milles per hora"] , ["" , "{0} grau Celsius" , "" , "" , "" , "{0} graos Celsius"] , ["" , "{0} grau Fahrenheit" , "" , "" , "" , "{0} graos Fahrenheit"] , ["" , "{0} mi" , "" , "" , "" , "{0} mi"] , ["" , "{0} yd" , "" , "" , "" , "{0} yd"] , ["" , "{0} ft" , "" , "" , "" , "{0} ft"] , ["" , "{0} in" , "" , "" , "" , "{0} in"] , ["" , "{0} lb" , "" , "" , "" , "{0} lbs"] , ["" , "{0} oz" , "" , "" , "" , "{0} oz"] , ["" , "{0} m/s" , "" , "" , "" , "{0} m/s"] , ["" , "{0} mi/h" , "" , "" , "" , "{0} mi/h"] , ["" , "{0}°C" , "" , "" , "" , "{0}°C"] , ["" , "{0}°F" , "" , "" , "" , "{0}°F"] , ["" , "{0}km" , "" , "" , "" , "{0}km"] , ["" , "{0}m" , "" , "" , "" , "{0}m"] , ["" , "{0}cm" , "" , "" , "" , "{0}cm"] , ["" , "{0}mm" , "" , "" , "" , "{0}mm"] , ["" , "{0}mi" , "" , "" , "" , "{0}mi"] , ["" , "{0}yd" , "" , "" , "" , "{0}yd"] , ["" , "{0}′" , "" , "" , "" , "{0}′"] , ["" , "{0}oz" , "" , "" , "" , "{0}oz"] , ["" , "{0}km/h" , "" , "" , "" , "{0}km/h"] , ["" , "{0}m/s" , "" , "" , "" , "{0}m/s"] , ["" , "{0} kilometr" , "" , "" , "" , "{0} kilometr"] , ["" , "{0} metr" , "" , "" , "" , "{0} metr"] , ["" , "{0} santimetr" , "" , "" , "" , "{0} santimetr"] , ["" , "{0} millimetr" , "" , "" , "" , "{0} millimetr"] , ["" , "{0} mil" , "" , "" , "" , "{0} mil"] , ["" , "{0} yard" , "" , "" , "" , "{0} yard"] , ["" , "{0} fut" , "" , "" , "" , "{0} fut"] , ["" , "{0} düym" , "" , "" , "" , "{0} düym"] , ["" , "{0} kiloqram" , "" , "" , "" , "{0} kiloqram"] , ["" , "{0} qram" , "" , "" , "" , "{0} qram"] , ["" , "{0} funt" , "" , "" , "" , "{0} funt"] , ["" , "{0} unsiya" , "" , "" , "" , "{0} unsiya"] , ["" , "{0} kilometr/saat" , "" , "" , "" , "{0} kilometr/saat"] , ["" , "{0} metr/saniyə" , "" , "" , "" , "{0} metr/saniyə"] , ["" , "{0} mil/saat" , "" , "" , "" , "{0} mil/saat"] , ["" , "{0} dərəcə Selsi" , "" , "" , "" , "{0} dərəcə Selsi"] , ["" , "{0} dərəcə Farengeyt" , "" , "" , "" , "{0} dərəcə Farengeyt"] , ["" , "{0} faiz" , "" , "" , "" , "{0} faiz"] , ["" , "{0} sm" , "" , "" , "" , "{0} sm"] , ["" , "{0} kq" , "" , "" , "" , "{0} kq"] , ["" , "{0} q" , "" , "" , "" , "{0} q"] , ["" , "{0} lb" , "" , "" , "" , "{0} lb"] , ["" , "{0} km/saat" , "" , "" , "" , "{0} km/saat"] , ["" , "{0} кіламетр" , "" , "{0} кіламетры" , "{0} кіламетраў" , "{0} кіламетра"] , ["" , "{0} метр" , "" , "{0} метры" , "{0} метраў" , "{0} метра"] , ["" , "{0} сантыметр" , "" , "{0} сантыметры" , "{0} сантыметраў" , "{0} сантыметра"] , ["" , "{0} міліметр" , "" , "{0} міліметры" , "{0} міліметраў" , "{0} міліметра"] , ["" , "{0} міля" , "" , "{0} мілі" , "{0} міль" , "{0} мілі"] , ["" , "{0} ярд" , "" , "{0} ярды" , "{0} ярдаў" , "{0} ярда"] , ["" , "{0} фут" , "" , "{0} футы" , "{0} футаў" , "{0} фута"] , ["" , "{0} цаля" , "" , "{0} цалі" , "{0} цаляў" , "{0} цалі"] , ["" , "{0} кг" , "" , "{0} кг" , "{0} кг" , "{0} кг"] , ["" , "{0} г" , "" , "{0} г" , "{0} г" , "{0} г"] , ["" , "{0} фунт" , "" , "{0} фунты" , "{0} фунтаў" , "{0} фунта"] , ["" , "{0} унцыя" , "" , "{0} унцыі" , "{0} унцый" , "{0} унцыі"] , ["" , "{0} км/гадз" , "" , "{0} км/гадз" , "{0} км/гадз" , "{0} км/гадз"] , ["" , "{0} м/с" , "" , "{0} м/с" , "{0} м/с" , "{0} м/с"] , ["" , "{0} міля/гадз" , "" , "{0} мілі/гадз" , "{0} міль/гадз" , "{0} мілі/гадз"] , ["" , "{0} градус Цэльсія" , "" , "{0} градусы Цэльсія" , "{0} градусаў Цэльсія" , "{0} градуса Цэльсія"] , ["" , "{0} градус Фарэнгейта" , "" , "{0} градусы Фарэнгейта" , "{0} градусаў Фарэнгейта" , "{0} градуса Фарэнгейта"] , ["" , "{0} працэнт" , "" , "{0} працэнты" , "{0} працэнтаў" , "{0} працэнта"] , ["" , "{0} км" , "" , "{0} км" , "{0} км" , "{0} км"] , ["" , "{0} м" , "" , "{0} м" , "{0} м" , "{0} м"] , ["" , "{0} см" , "" , "{0} см" , "{0} см" , "{0} см"] , ["" , "{0} мм" , "" , "{0} мм" , "{0} мм" , "{0} мм"] , ["" , "{0} унц." , "" , "{0} унц." , "{0} унц." , "{0} унц."] , ["" , "{0} °C" , "" , "{0} °C" , "{0} °C" , "{0} °C"] , ["" , "{0} °F" , "" , "{0} °F" , "{0} °F" , "{0} °F"] , ["" , "{0} %" , "" , "{0} %" , "{0} %" , "{0} %"] , ["" , "{0}%" , "" , "{0}%" , "{0}%" , "{0}%"] , ["" , "{0} километър" , "" , "" , "" , "{0} километра"] , ["" , "{0} метър" , "" , "" , "" , "{0} метра"] , ["" , "{0} сантиметър" , "" , "" , "" , "{0} сантиметра"] , ["" , "{0} милиметър" , "" , "" , "" , "{0} милиметра"] , ["" , "{0} миля" , "" , "" , "" , "{0} мили"] , ["" , "{0} ярд" , "" , "" , "" , "{0} ярда"] , ["" , "{0} фут" , "" , "" , "" , "{0} фута"] , ["" , "{0} инч" , "" , "" , "" , "{0} инча"] , ["" , "{0} килограм" , "" , "" , "" , "{0} килограма"] , ["" , "{0} грам" , "" , "" , "" , "{0} грама"] , ["" , "{0} фунт" , "" , "" , "" , "{0} фунта"] , ["" , "{0} унция" , "" , "" , "" , "{0} унции"] , ["" , "{0} километър в час" , "" , "" , "" , "{0} километра в час"] , ["" , "{0} метър за секунда" , "" , "" , "" , "{0} метра за секунда"] , ["" , "{0} миля в час" , "" , "" , "" , "{0} мили в час"] , ["" , "{0} градус Целзий" , "" , "" , "" , "{0} градуса Целзий"] , ["" , "{0} градус по Фаренхайт" , "" , "" , "" , "{0} градуса по Фаренхайт"] , ["" , "{0} процент" , "" , "" , "" , "{0} процента"] , ["" , "{0} mph" , "" , "" , "" , "{0} mph"] , ["" , "{0}\\" , "" , "" , "" , "{0}\\"] , ["" , "{0} унц." , "" , "" , "" , "{0} унц."] , ["" , "{0} миля/ч" , "" , "" , "" , "{0} мили/ч"] , ["" , "{0} কিলোমিট\u{9be}র" , "" , "" , "" , "{0} কিলোমিট\u{9be}র"] , ["" , "{0} মিট\u{9be}র" , "" , "" , "" , "{0} মিট\u{9be}র"] , ["" , "{0} সেন\u{9cd}টিমিট\u{9be}র" , "" , "" , "" , "{0} সেন\u{9cd}টিমিট\u{9be}র"] , ["" , "{0} মিলিমিট\u{9be}র" , "" , "" , "" , "{0} মিলিমিট\u{9be}র"] , ["" , "{0} কিলোগ\u{9cd}র\u{9be}ম" , "" , "" , "" , "{0} কিলোগ\u{9cd}র\u{9be}ম"] , ["" , "{0} গ\u{9cd}র\u{9be}ম" , "" , "" , "" , "{0} গ\u{9cd}র\u{9be}ম"] , ["" , "{0} প\u{9be}উন\u{9cd}ড" , "" , "" , "" , "{0} প\u{9be}উন\u{9cd}ড"] , ["" , "{0} ঘন\u{9cd}ট\u{9be} প\u{9cd}রতি কিলোমিট\u{9be}র" , "" , "" , "" , "{0} ঘন\u{9cd}ট\u{9be} প\u{9cd}রতি কিলোমিট\u{9be}র"] , ["" , "{0} মিট\u{9be}র প\u{9cd}রতি সেকেন\u{9cd}ড" , "" , "" , "" , "{0} মিট\u{9be}র প\u{9cd}রতি সেকেন\u{9cd}ড"] , ["" , "{0} ঘন\u{9cd}ট\u{9be} প\u{9cd}রতি ম\u{9be}ইল" , "" , "" , "" , "{0} ঘন\u{9cd}ট\u{9be} প\u{9cd}রতি ম\u{9be}ইল"] , ["" , "{0} ডিগ\u{9cd}রী সেলসিয\u{9bc}\u{9be}স" , "" , "" , "" , "{0} ডিগ\u{9cd}রী সেলসিয\u{9bc}\u{9be}স"] , ["" , "{0} ডিগ\u{9cd}রী ফ\u{9be}রেনহ\u{9be}ইট" , "" , "" , "" , "{0} ডিগ\u{9cd}রী ফ\u{9be}রেনহ\u{9be}ইট"] , ["" , "{0}শত\u{9be}ংশ" , "" , "" , "" , "{0}শত\u{9be}ংশ"] , ["" , "{0} কিমি" , "" , "" , "" , "{0} কিমি"] , ["" , "{0} মি" , "" , "" , "" , "{0} মি"] , ["" , "{0} সেমি" , "" , "" , "" , "{0} সেমি"] , ["" , "{0} মিমি" , "" , "" , "" , "{0} মিমি"] , ["" , "{0} কেজি" , "" , "" , "" , "{0} কেজি"] , ["" , "{0}গ\u{9cd}র\u{9be}ম" , "" , "" , "" , "{0} গ\u{9cd}র\u{9be}ম"] , ["" , "{0} প\u{9be}উন\u{9cd}ড" , "" , "" , "" , "{0} lb"] , ["" , "{0} kph" , "" , "" , "" , "{0} km/h"] , ["" , "{0}kph" , "" , "" , "" , "{0}kph"] , ["" , "{0} c'hilometr" , "{0} gilometr" , "{0} c'hilometr" , "{0} a gilometroù" , "{0} kilometr"] , ["" , "{0} metr" , "{0} vetr" , "{0} metr" , "{0} a vetroù" , "{0} metr"] , ["" , "{0} santimetr" , "{0} santimetr" , "{0} santimetr" , "{0} a santimetroù" , "{0} santimetr"] , ["" , "{0} milimetr" , "{0} vilimetr" , "{0} milimetr" , "{0} a vilimetroù" , "{0} milimetr"] , ["" , "{0} miltir" , "{0} viltir" , "{0} miltir" , "{0} a viltirioù" , "{0} miltir"] , ["" , "{0} yard" , "{0} yard" , "{0} yard" , "{0} a yardoù" , "{0} yard"] , ["" , "{0} troatad" , "{0} droatad" , "{0} zroatad" , "{0} a droatadoù" , "{0} troatad"] , ["" , "{0} meutad" , "{0} veutad" , "{0} meutad" , "{0} a veutadoù" , "{0} meutad"] , ["" , "{0} c'hilogramm" , "{0} gilogramm" , "{0} c'hilogramm" , "{0} a gilogrammoù" , "{0} kilogramm"] , ["" , "{0} gramm" , "{0} c'hramm" , "{0} gramm" , "{0} a c'hrammoù" , "{0} gramm"] , ["" , "{0} lur" , "{0} lur" , "{0} lur" , "{0} a lurioù" , "{0} lur"] , ["" , "{0} oñs" , "{0} oñs" , "{0} oñs" , "{0} a oñsoù" , "{0} oñs"] , ["" , "{0} c'hilometr dre eur" , "{0} gilometr dre eur" , "{0} c'hilometr dre eur" , "{0} a gilometroù dre eur" , "{0} kilometr dre eur"] , ["" , "{0} metr dre eilenn" , "{0} vetr dre eilenn" , "{0} metr dre eilenn" , "{0} a vetroù dre eilenn" , "{0} metr dre eilenn"] , ["" , "{0} miltir dre eur" , "{0} viltir dre eur" , "{0} miltir dre eur" , "{0} a viltirioù dre eur" , "{0} miltir dre eur"] , ["" , "{0} derez Celsius" , "{0} zerez Celsius" , "{0} derez Celsius" , "{0} a zerezioù Celsius" , "{0} derez Celsius"] , ["" , "{0} derez Fahrenheit" , "{0} zerez Fahrenheit" , "{0} derez Fahrenheit" , "{0} a zerezioù Fahrenheit" , "{0} derez Fahrenheit"] , ["" , "{0} dre gant" , "{0} dre gant" , "{0} dre gant" , "{0} dre gant" , "{0} dre gant"] , ["" , "{0} km" , "{0} km" , "{0} km" , "{0} km" , "{0} km"] , ["" , "{0} m" , "{0} m" , "{0} m" , "{0} m" , "{0} m"] , ["" , "{0} cm" , "{0} cm" , "{0} cm" , "{0} cm" , "{0} cm"] , ["" , "{0} mm" , "{0} mm" , "{0} mm" , "{0} mm" , "{0} mm"] , ["" , "{0} mi" , "{0} mi" , "{0} mi" , "{0} mi" , "{0} mi"] , ["" , "{0} yd" , "{0} yd" , "{0} yd" , "{0} yd" , "{0} yd"] , ["" , "{0} ft" , "{0} ft" , "{0} ft" , "{0} ft" , "{0} ft"] , ["" , "{0} in" , "{0} in" , "{0} in" , "{0} in" , "{0} in"] , ["" , "{0} kg" , "{0} kg" , "{0} kg" , "{0} kg" , "{0} kg"] , ["" , "{0} g" , "{0} g" , "{0} g" , "{0} g" , "{0} g"] , ["" , "{0} lb" , "{0} lb" , "{0} lb" , "{0} lb" , "{0} lb"] , ["" , "{0} oz" , "{0} oz" , "{0} oz" , "{0} oz" , "{0} oz"] , ["" , "{0} km/h" , "{0} km/h" , "{0} km/h" , "{0} km/h" , "{0} km/h"] , ["" , "{0} m/s" , "{0} m/s" , "{0} m/s" , "{0} m/s" , "{0} m/s"] , ["" , "{0} mi/h" , "{0} mi/h" , "{0} mi/h" , "{0} mi/h" , "{0} mi/h"] , ["" , "{0}°C" , "{0}°C" , "{0}°C" , "{0}°C" , "{0}°C"] , ["" , "{0}°F" , "{0}°F" , "{0}°F" , "{0}°F" , "{0}°F"] , ["" , "{0} %" , "{0} %" , "{0} %" , "{0} %" , "{0} %"] , ["" , "{0}km" , "{0}km" , "{0}km" , "{0}km" , "{0}km"] , ["" , "{0}m" , "{0}m" , "{0}m" , "{0}m" , "{0}m"] , ["" , "{0}cm" , "{0}cm" , "{0}cm" , "{0}cm" , "{0}cm"] , ["" , "{0}mm" , "{0}mm" , "{0}mm" , "{0}mm" , "{0}mm"] , ["" , "{0}mi" , "{0}mi" , "{0}mi" , "{0}mi" , "{0}mi"] , ["" , "{0}yd" , "{0}yd" , "{0}yd" , "{0}yd" , "{0}yd"] , ["" , "{0}′" , "{0}′" , "{0}′" , "{0}′" , "{0}′"] , ["" , "{0}″" , "{0}″" , "{0}″" , "{0}″" , "{0}″"] , ["" , "{0}kg" , "{0}kg" , "{0}kg" , "{0}kg" , "{0}kg"] , ["" , "{0}g" , "{0}g" , "{0}g" , "{0}g" , "{0}g"] , ["" , "{0}lb" , "{0}lb" , "{0}lb" , "{0}lb" , "{0}lb"] , ["" , "{0}oz" , "{0}oz" , "{0}oz" , "{0}oz" , "{0}oz"] , ["" , "{0}km/h" , "{0}km/h" , "{0}km/h" , "{0}km/h" , "{0}km/h"] , ["" , "{0}m/s" , "{0}m/s" , "{0}m/s" , "{0}m/s" , "{0}m/s"] , ["" , "{0}mi/h" , "{0}mi/h" , "{0}mi/h" , "{0}mi/h" , "{0}mi/h"] , ["" , "{0}%" , "{0}%" , "{0}%" , "{0}%" , "{0}%"] , ["" , "{0} kilometar" , "" , "{0} kilometra" , "" , "{0} kilometara"] , ["" , "{0} metar" , "" , "{0} metra" , "" , "{0} metara"] , ["" , "{0} centimetar" , "" , "{0} centimetra" , "" , "{0} centimetara"] , ["" , "{0} milimetar" , "" , "{0} milimetra" , "" , "{0} milimetara"] , ["" , "{0} milja" , "" , "{0} milje" , "" , "{0} milja"] , ["" , "{0} jard" , "" , "{0} jarda" , "" , "{0} jardi"] , ["" , "{0} stopa" , "" , "{0} stope" , "" , "{0} stopa"] , ["" , "{0} inč" , "" , "{0} inča" , "" , "{0} inča"] , ["" , "{0} kilogram" , "" , "{0} kilograma" , "" , "{0} kilograma"] , ["" , "{0} gram" , "" , "{0} grama" , "" , "{0} grama"] , ["" , "{0} funta" , "" , "{0} funte" , "" , "{0} funti"] , ["" , "{0} unca" , "" , "{0} unce" , "" , "{0} unci"] , ["" , "{0} kilometar na sat" , "" , "{0} kilometra na sat" , "" , "{0} kilometara na sat"] , ["" , "{0} metar u sekundi" , "" , "{0} metra u sekundi" , "" , "{0} metara u sekundi"] , ["" , "{0} milja na sat" , "" , "{0} milje na sat" , "" , "{0} milja na sat"] , ["" , "{0} stepen Celzijusa" , "" , "{0} stepena Celzijusa" , "" , "{0} stepeni Celzijusa"] , ["" , "{0} stepen Farenhajta" , "" , "{0} stepena Farenhajta" , "" , "{0} stepeni Farenhajta"] , ["" , "{0} procenat" , "" , "{0} procenta" , "" , "{0} procenata"] , ["" , "{0} km" , "" , "{0} km" , "" , "{0} km"] , ["" , "{0} m" , "" , "{0} m" , "" , "{0} m"] , ["" , "{0} cm" , "" , "{0} cm" , "" , "{0} cm"] , ["" , "{0} mm" , "" , "{0} mm" , "" , "{0} mm"] , ["" , "{0} mi" , "" , "{0} mi" , "" , "{0} mi"] , ["" , "{0} yd" , "" , "{0} yd" , "" , "{0} yd"] , ["" , "{0} ft" , "" , "{0} ft" , "" , "{0} ft"] , ["" , "{0} in" , "" , "{0} in" , "" , "{0} in"] , ["" , "{0} kg" , "" , "{0} kg" , "" , "{0} kg"] , ["" , "{0} g" , "" , "{0} g" , "" , "{0} g"] , ["" , "{0} lb" , "" , "{0} lb" , "" , "{0} lbs"] , ["" , "{0} oz" , "" , "{0} oz" , "" , "{0} oz"] , ["" , "{0} km/h" , "" , "{0} km/h" , "" , "{0} km/h"] , ["" , "{0} m/s" , "" , "{0} m/s" , "" , "{0} m/s"] , ["" , "{0} mi/h" , "" , "{0} mi/h" , "" , "{0} mi/h"] , ["" , "{0}°C" , "" , "{0}°C" , "" , "{0}°C"] , ["" , "{0}°F" , "" , "{0}°F" , "" , "{0}°F"] , ["" , "{0}%" , "" , "{0}%" , "" , "{0}%"] , ["" , "{0} lb" , "" , "{0} lb" , "" , "{0} lb"] , ["" , "{0}°" , "" , "{0}°" , "" , "{0}°"] , ["" , "{0} километар" , "" , "{0} километра" , "" , "{0} километара"] , ["" , "{0} метар" , "" , "{0} метра" , "" , "{0} метара"] , ["" , "{0} центиметар" , "" , "{0} центиметра" , "" , "{0} центиметара"] , ["" , "{0} милиметар" , "" , "{0} милиметра" , "" , "{0} милиметара"] , ["" , "{0} миља" , "" , "{0} миље" , "" , "{0} миља"] , ["" , "{0} јард" , "" , "{0} јарда" , "" , "{0} јарди"] , ["" , "{0} quilòmetre" , "" , "" , "" , "{0} quilòmetres"] , ["" , "{0} metre" , "" , "" , "" , "{0} metres"] , ["" , "{0} centímetre" , "" , "" , "" , "{0} centímetres"] , ["" , "{0} mil·límetre" , "" , "" , "" , "{0} mil·límetres"] , ["" , "{0} iarda" , "" , "" , "" , "{0} iardes"] , ["" , "{0} peu" , "" , "" , "" , "{0} peus"] , ["" , "{0} polzada" , "" , "" , "" , "{0} polzades"] , ["" , "{0} quilogram" , "" , "" , "" , "{0} quilograms"] , ["" , "{0} gram" , "" , "" , "" , "{0} grams"] , ["" , "{0} lliura" , "" , "" , "" , "{0} lliures"] , ["" , "{0} unça" , "" , "" , "" , "{0} unces"] , ["" , "{0} quilòmetre per hora" , "" , "" , "" , "{0} quilòmetres per hora"] , ["" , "{0} metre per segon" , "" , "" , "" , "{0} metres per segon"] , ["" , "{0} grau Celsius" , "" , "" , "" , "{0} graus Celsius"] , ["" , "{0} grau Fahrenheit" , "" , "" , "" , "{0} graus Fahrenheit"] , ["" , "{0} per cent" , "" , "" , "" , "{0} per cent"] , ["" , "{0} %" , "" , "" , "" , "{0} %"] , ["" , "{0} 𑄇\u{11128}𑄣\u{1112e}𑄟\u{11128}𑄑𑄢\u{11134}" , "" , "" , "" , "{0} 𑄇\u{11128}𑄣\u{1112e}𑄟\u{11128}𑄑𑄢\u{11134}"] , ["" , "{0} 𑄟\u{11128}𑄑𑄢\u{11134}" , "" , "" , "" , "{0} 𑄟\u{11128}𑄑𑄢\u{11134}"] , ["" , "{0} 𑄥𑄬𑄚\u{11134}𑄑\u{11128}𑄟\u{11128}𑄑𑄢\u{11134}" , "" , "" , "" , "{0} 𑄥𑄬𑄚\u{11134}𑄑\u{11128}𑄟\u{11128}𑄑𑄢\u{11134}"] , ["" , "{0} 𑄟\u{11128}𑄣\u{11128}𑄟\u{11128}𑄑𑄢\u{11134}" , "" , "" , "" , "{0} 𑄟\u{11128}𑄣\u{11128}𑄟\u{11128}𑄑𑄢\u{11134}"] , ["" , "{0} 𑄟𑄬𑄣\u{11134}" , "" , "" , "" , "{0} 𑄟𑄬𑄣\u{11134}"] , ["" , "{0} 𑄉\u{11127}𑄌\u{11134}" , "" , "" , "" , "{0} 𑄉\u{11127}𑄌\u{11134}"] , ["" , "{0} 𑄜\u{1112a}𑄖\u{11134}" , "" , "" , "" , "{0} 𑄜\u{1112a}𑄖\u{11134}"] , ["" , "{0} 𑄃\u{11128}𑄚\u{11134}𑄌\u{11128}" , "" , "" , "" , "{0} 𑄃\u{11128}𑄚\u{11134}𑄌\u{11128}"] , ["" , "{0} 𑄇\u{11128}𑄣\u{1112e}𑄉\u{11133}𑄢𑄟\u{11134}" , "" , "" , "" , "{0} 𑄇\u{11128}𑄣\u{1112e}𑄉\u{11133}𑄢𑄟\u{11134}"] , ["" , "{0} 𑄉\u{11133}𑄢𑄟\u{11134}" , "" , "" , "" , "{0} 𑄉\u{11133}𑄢𑄟\u{11134}"] , ["" , "{0} 𑄛𑄅\u{1112a}𑄚\u{11133}𑄓\u{11134}" , "" , "" , "" , "{0} 𑄛𑄅\u{1112a}𑄚\u{11133}𑄓\u{11134}"] , ["" , "{0} 𑄃𑄅\u{1112a}𑄚\u{11133}𑄥\u{11134}" , "" , "" , "" , "{0} 𑄃𑄅\u{1112a}𑄚\u{11133}𑄥\u{11134}"] , ["" , "{0} 𑄊\u{11127}𑄚\u{11133}𑄑 𑄛\u{11133}𑄢\u{11127}𑄖\u{11128} 𑄇\u{11128}𑄣\u{1112e}𑄟\u{11128}𑄑𑄢\u{11134}" , "" , "" , "" , "{0} 𑄊\u{11127}𑄚\u{11133}𑄑 𑄛\u{11133}𑄢\u{11127}𑄖\u{11128} 𑄇\u{11128}𑄣\u{1112e}𑄟\u{11128}𑄑𑄢\u{11134}"] , ["" , "{0} 𑄟\u{11128}𑄑𑄢\u{11134} 𑄛\u{11133}𑄢\u{11127}𑄖\u{11128} 𑄥𑄬𑄇𑄬𑄚\u{11133}𑄓\u{11134}" , "" , "" , "" , "{0} 𑄟\u{11128}𑄑𑄢\u{11134} 𑄛\u{11133}𑄢\u{11127}𑄖\u{11128} 𑄥𑄬𑄇𑄬𑄚\u{11133}𑄓\u{11134}"] , ["" , "{0} 𑄊\u{11127}𑄚\u{11133}𑄑 𑄛\u{11133}𑄢\u{11127}𑄖\u{11128} 𑄟𑄬𑄣\u{11134}" , "" , "" , "" , "{0} 𑄊\u{11127}𑄚\u{11133}𑄑 𑄛\u{11133}𑄢\u{11127}𑄖\u{11128} 𑄟𑄬𑄣\u{11134}"] , ["" , "{0} 𑄓\u{11128}𑄉\u{11133}𑄢\u{11129} 𑄥𑄬𑄣\u{11134}𑄥\u{11128}𑄠𑄌\u{11134}" , "" , "" , "" , "{0} 𑄓\u{11128}𑄉\u{11133}𑄢\u{11129} 𑄥𑄬𑄣\u{11134}𑄥\u{11128}𑄠𑄌\u{11134}"] , ["" , "{0} 𑄓\u{11128}𑄉\u{11133}𑄢\u{11129} 𑄜𑄢𑄬𑄚\u{11134}𑄦\u{1112d}𑄖\u{11134}" , "" , "" , "" , "{0} 𑄓\u{11128}𑄉\u{11133}𑄢\u{11129} 𑄜𑄢𑄬𑄚\u{11134}𑄦\u{1112d}𑄖\u{11134}"] , ["" , "{0} 𑄇\u{11128}𑄟\u{11128}" , "" , "" , "" , "{0} 𑄇\u{11128}𑄟\u{11128}"] , ["" , "{0} 𑄟\u{11128}" , "" , "" , "" , "{0} 𑄟\u{11128}"] , ["" , "{0} 𑄥𑄬𑄟\u{11128}" , "" , "" , "" , "{0} 𑄥𑄬𑄟\u{11128}"] , ["" , "{0} 𑄟\u{11128}𑄟\u{11128}" , "" , "" , "" , "{0} 𑄟\u{11128}𑄟\u{11128}"] , ["" , "{0} 𑄇𑄬𑄎\u{11128}" , "" , "" , "" , "{0} 𑄇𑄬𑄎\u{11128}"] , ["" , "{0}𑄉\u{11133}𑄢𑄟\u{11134}" , "" , "" , "" , "{0} 𑄉\u{11133}𑄢𑄟\u{11134}"] , ["" , "{0} 𑄛𑄅\u{1112a}𑄚\u{11133}𑄓\u{11134}" , "" , "" , "" , "{0} lb"] , ["" , "{0} 𑄃𑄅\u{1112a}𑄚\u{11133}𑄌\u{11134}" , "" , "" , "" , "{0} 𑄃𑄅\u{1112a}𑄚\u{11133}𑄌\u{11134}"] , ["" , "{0} километр" , "" , "" , "" , "{0} километр"] , ["" , "{0} сантиметр" , "" , "" , "" , "{0} сантиметр"] , ["" , "{0} фут" , "" , "" , "" , "{0} фут"] , ["" , "{0} дюйм" , "" , "" , "" , "{0} дюйм"] , ["" , "{0} км" , "" , "" , "" , "{0} км"] , ["" , "{0} см" , "" , "" , "" , "{0} см"] , ["" , "{0} дюйм." , "" , "" , "" , "{0} дюйм."] , ["" , "{0} см" , "" , "" , "" , "{0} cm"] , ["" , "{0} ka kilometro" , "" , "" , "" , "{0} ka mga kilometro"] , ["" , "{0} ka metro" , "" , "" , "" , "{0} ka mga metro"] , ["" , "{0} ka sentimetro" , "" , "" , "" , "{0} ka mga sentimetro"] , ["" , "{0} ka millimetro" , "" , "" , "" , "{0} ka millimetro"] , ["" , "{0} ka milya" , "" , "" , "" , "{0} ka mga milya"] , ["" , "{0} ka yarda" , "" , "" , "" , "{0} ka mga yarda"] , ["" , "{0} ka piye" , "" , "" , "" , "{0} ka mga piye"] , ["" , "{0} ka pulgada" , "" , "" , "" , "{0} ka pulgada"] , ["" , "{0} ka kilogramo" , "" , "" , "" , "{0} ka mga kilogramo"] , ["" , "{0} ka gramo" , "" , "" , "" , "{0} ka mga gramo"] , ["" , "{0} ka pound" , "" , "" , "" , "{0} ka mga pound"] , ["" , "{0} ka ounce" , "" , "" , "" , "{0} ka mga ounce"] , ["" , "{0} ka kilometro kada oras" , "" , "" , "" , "{0} ka mga kilometro kada oras"] , ["" , "{0} ka metro kada segundo" , "" , "" , "" , "{0} ka mga metro kada segundo"] , ["" , "{0} ka milya kada oras" , "" , "" , "" , "{0} ka mga milya kada oras"] , ["" , "{0} ka degree Celsius" , "" , "" , "" , "{0} degree Celsius"] , ["" , "{0} degree Fahrenheit" , "" , "" , "" , "{0} degree Fahrenheit"] , ["" , "{0} ka porsyento" , "" , "" , "" , "{0} ka porsyento"] , ["" , "{0} ᎠᎦᏴᎵ ᎠᏟᎶᏍᏗ" , "" , "" , "" , "{0} ᎠᎦᏴᎵ ᏗᏟᎶᏍᏗ"] , ["" , "{0} ᎠᏟᎶᏍᏗ" , "" , "" , "" , "{0} ᏗᏟᎶᏍᏗ"] , ["" , "{0} ᎤᏍᏗ ᎠᏟᎶᏗ" , "" , "" , "" , "{0} ᏧᏍᏗ ᏗᏟᎶᏗ"] , ["" , "ᏌᏉ ᎢᏯᎦᎨᎵᏁᎢ ᎠᏟᎶᏍᏗ" , "" , "" , "" , "{0} ᏌᏉ ᎢᏯᎦᎨᎵᏁᎢ ᏗᏟᎶᏍᏗ"] , ["" , "{0} ᏑᏟᎶᏓ" , "" , "" , "" , "{0} ᎢᏳᏟᎶᏓ"] , ["" , "{0} ᏯᏗ" , "" , "" , "" , "{0} ᎢᏯᏯᏗ"] , ["" , "{0} ᎢᎳᏏᏗ" , "" , "" , "" , "{0} ᎢᏗᎳᏏᏗ"] , ["" , "{0} ᎢᏏᏔᏗᏍᏗ" , "" , "" , "" , "{0} ᎢᏗᏏᏔᏗᏍᏗ"] , ["" , "{0} ᎠᎦᏴᎵ ᎤᏍᏗ ᎤᏓᎨᏒ" , "" , "" , "" , "{0} ᎠᎦᏴᎵ ᎤᏍᏗ ᏂᏚᏓᎨᏒ"] , ["" , "{0} ᎤᏍᏗ ᎤᏓᎨᏒ" , "" , "" , "" , "{0} ᎤᏍᏗ ᏂᏚᏓᎨᏒ"] , ["" , "{0} ᏑᏓᎨᏓ" , "" , "" , "" , "{0} ᎢᏧᏓᎨᏓ"] , ["" , "{0} ᎣᏂᏏ" , "" , "" , "" , "{0} ᎢᏯᎣᏂᏏ"] , ["" , "{0} ᎠᎦᏴᎵ ᎠᏟᎶᏍᏗ ᏑᏟᎶᏓ ᎢᏳᏓᎵ" , "" , "" , "" , "{0} ᎠᎦᏴᎵ ᏗᏟᎶᏍᏗ ᏑᏟᎶᏓ ᎢᏳᏓᎵ"] , ["" , "{0} ᎠᏟᎶᏗ ᎠᏎᏢ ᎢᏳᏓᎵ" , "" , "" , "" , "{0} ᏗᏟᎶᏗ ᎠᏎᏢ ᎢᏳᏓᎵ"] , ["" , "{0} ᏑᏟᎶᏓ ᏑᏟᎶᏛ ᎢᏳᏓᎵ" , "" , "" , "" , "{0} ᎢᏧᏟᎶᏓ ᏑᏟᎶᏛ ᎢᏳᏓᎵ"] , ["" , "{0} ᎢᎦᎢ ᎢᎦ ᎠᏤ ᎠᏟᎶᏍᏙᏗ" , "" , "" , "" , "{0} ᎢᎦᎢ ᎢᏗᎦᏘ ᎠᏤ ᎠᏟᎶᏍᏙᏗ"] , ["" , "ᎢᎦᎢ ᎢᎦ ᏅᎦᏃᏋ ᎠᎴ ᏅᏴᏢ ᎠᏟᎶᏍᏙᏗ" , "" , "" , "" , "{0} ᎢᎦᎢ ᎢᏗᎦᏘ ᏅᎦᏃᏋ ᎠᎴ ᏅᏴᏢ ᎠᏟᎶᏍᏙᏗ"] , ["" , "{0} ᏓᎬᏩᎶᏛ" , "" , "" , "" , "{0} ᏓᎬᏩᎶᏛ"] , ["" , "{0} ᎠᏗ" , "" , "" , "" , "{0} ᎠᏗ"] , ["" , "{0} ᎠᏟ" , "" , "" , "" , "{0} ᎠᏟ"] , ["" , "{0} ᏌᎢᎠ" , "" , "" , "" , "{0} ᏌᎢᎠ"] , ["" , "{0}ᎠᏗ" , "" , "" , "" , "{0}ᎠᏗ"] , ["" , "{0}ᎠᏟ" , "" , "" , "" , "{0}ᎠᏟ"] , ["" , "{0}ᏌᎢᎠ" , "" , "" , "" , "{0}ᏌᎢᎠ"] , ["" , "{0} kilometr" , "" , "{0} kilometry" , "{0} kilometru" , "{0} kilometrů"] , ["" , "{0} metr" , "" , "{0} metry" , "{0} metru" , "{0} metrů"] , ["" , "{0} centimetr" , "" , "{0} centimetry" , "{0} centimetru" , "{0} centimetrů"] , ["" , "{0} milimetr" , "" , "{0} milimetry" , "{0} milimetru" , "{0} milimetrů"] , ["" , "{0} míle" , "" , "{0} míle" , "{0} míle" , "{0} mil"] , ["" , "{0} yard" , "" , "{0} yardy" , "{0} yardu" , "{0} yardů"] , ["" , "{0} stopa" , "" , "{0} stopy" , "{0} stopy" , "{0} stop"] , ["" , "{0} palec" , "" , "{0} palce" , "{0} palce" , "{0} palců"] , ["" , "{0} kilogram" , "" , "{0} kilogramy" , "{0} kilogramu" , "{0} kilogramů"] , ["" , "{0} gram" , "" , "{0} gramy" , "{0} gramu" , "{0} gramů"] , ["" , "{0} libra" , "" , "{0} libry" , "{0} libry" , "{0} liber"] , ["" , "{0} unce" , "" , "{0} unce" , "{0} unce" , "{0} uncí"] , ["" , "{0} kilometr za hodinu" , "" , "{0} kilometry za hodinu" , "{0} kilometru za hodinu" , "{0} kilometrů za hodinu"] , ["" , "{0} metr za sekundu" , "" , "{0} metry za sekundu" , "{0} metru za sekundu" , "{0} metrů za sekundu"] , ["" , "{0} míle za hodinu" , "" , "{0} míle za hodinu" , "{0} míle za hodinu" , "{0} mil za hodinu"] , ["" , "{0} stupeň Celsia" , "" , "{0} stupně Celsia" , "{0} stupně Celsia" , "{0} stupňů Celsia"] , ["" , "{0} stupeň Fahrenheita" , "" , "{0} stupně Fahrenheita" , "{0} stupně Fahrenheita" , "{0} stupňů Fahrenheita"] , ["" , "{0} procento" , "" , "{0} procenta" , "{0} procenta" , "{0} procent"] , ["" , "{0} km" , "" , "{0} km" , "{0} km" , "{0} km"] , ["" , "{0} m" , "" , "{0} m" , "{0} m" , "{0} m"] , ["" , "{0} cm" , "" , "{0} cm" , "{0} cm" , "{0} cm"] , ["" , "{0} mm" , "" , "{0} mm" , "{0} mm" , "{0} mm"] , ["" , "{0} mi" , "" , "{0} mi" , "{0} mi" , "{0} mi"] , ["" , "{0} yd" , "" , "{0} yd" , "{0} yd" , "{0} yd"] , ["" , "{0} ft" , "" , "{0} ft" , "{0} ft" , "{0} ft"] , ["" , "{0} in" , "" , "{0} in" , "{0} in" , "{0} in"] , ["" , "{0} kg" , "" , "{0} kg" , "{0} kg" , "{0} kg"] , ["" , "{0} g" , "" , "{0} g" , "{0} g" , "{0} g"] , ["" , "{0} lb" , "" , "{0} lb" , "{0} lb" , "{0} lb"] , ["" , "{0} oz" , "" , "{0} oz" , "{0} oz" , "{0} oz"] , ["" , "{0} km/h" , "" , "{0} km/h" , "{0} km/h" , "{0} km/h"] , ["" , "{0} m/s" , "" , "{0} m/s" , "{0} m/s" , "{0} m/s"] , ["" , "{0} mi/h" , "" , "{0} mi/h" , "{0} mi/h" , "{0} mi/h"] , ["" , "{0}′" , "" , "{0}′" , "{0}′" , "{0}′"] , ["" , "{0}″" , "" , "{0}″" , "{0}″" , "{0}″"] , ["{0} km" , "{0} cilometr" , "{0} km" , "{0} km" , "{0} km" , "{0} cilometr"] , ["{0} m" , "{0} metr" , "{0} m" , "{0} m" , "{0} m" , "{0} metr"] , ["{0} cm" , "{0} centimetr" , "{0} cm" , "{0} cm" , "{0} cm" , "{0} centimetr"] , ["{0} mm" , "{0} milimetr" , "{0} filimetr" , "{0} mm" , "{0} mm" , "{0} milimetr"] , ["{0} mi" , "{0} filltir" , "{0} filltir" , "{0} milltir" , "{0} milltir" , "{0} milltir"] , ["{0} llath" , "{0} llath" , "{0} lath" , "{0} llath" , "{0} llath" , "{0} llath"] , ["{0} troedfedd" , "{0} droedfedd" , "{0} droedfedd" , "{0} troedfedd" , "{0} throedfedd" , "{0} troedfedd"] , ["{0} modfedd" , "{0} fodfedd" , "{0} fodfedd" , "{0} modfedd" , "{0} modfedd" , "{0} modfedd"] , ["{0} kg" , "{0} cilogram" , "{0} kg" , "{0} kg" , "{0} kg" , "{0} cilogram"] , ["{0} g" , "{0} gram" , "{0} g" , "{0} g" , "{0} g" , "{0} gram"] , ["{0} pwys" , "{0} pwys" , "{0} bwys" , "{0} phwys" , "{0} phwys" , "{0} pwys"] , ["{0} owns" , "{0} owns" , "{0} owns" , "{0} owns" , "{0} owns" , "{0} owns"] , ["{0} cilometr yr awr" , "{0} cilometr yr awr" , "{0} gilometr yr awr" , "{0} chilometr yr awr" , "{0} chilometr yr awr" , "{0} cilometr yr awr"] , ["{0} metr yr eiliad" , "{0} metr yr eiliad" , "{0} fetr yr eiliad" , "{0} metr yr eiliad" , "{0} metr yr eiliad" , "{0} metr yr eiliad"] , ["{0} milltir yr awr" , "{0} filltir yr awr" , "{0} filltir yr awr" , "{0} milltir yr awr" , "{0} milltir yr awr" , "{0} milltir yr awr"] , ["{0} gradd Celsius" , "{0} radd Celsius" , "{0}°C" , "{0}°C" , "{0}°C" , "{0} gradd Celsius"] , ["{0}°F" , "{0} radd Fahrenheit" , "{0}°F" , "{0}°F" , "{0}°F" , "{0} gradd Fahrenheit"] , ["{0}%" , "{0} y cant" , "{0}%" , "{0}%" , "{0}%" , "{0} y cant"] , ["{0} km" , "{0} km" , "{0} km" , "{0} km" , "{0} km" , "{0} km"] , ["{0} m" , "{0} m" , "{0} m" , "{0} m" , "{0} m" , "{0} m"] , ["{0} cm" , "{0} cm" , "{0} cm" , "{0} cm" , "{0} cm" , "{0} cm"] , ["{0} mm" , "{0} mm" , "{0} mm" , "{0} mm" , "{0} mm" , "{0} mm"] , ["{0} mi" , "{0} mi" , "{0} mi" , "{0} mi" , "{0} mi" , "{0} mi"] , ["{0}′" , "{0}′" , "{0}′" , "{0}′" , "{0}′" , "{0}′"] , ["{0} kg" , "{0} kg" , "{0} kg" , "{0} kg" , "{0} kg" , "{0} kg"] , ["{0} g" , "{0} g" , "{0} g" , "{0} g" , "{0} g" , "{0} g"] , ["{0} km/h" , "{0} km/h" , "{0} km/h" , "{0} km/h" , "{0} km/h" , "{0} km/h"] , ["{0} m/s" , "{0} m/s" , "{0} m/s" , "{0} m/s" , "{0} m/s" , "{0} m/s"] , ["{0} m.y.a." , "{0} m.y.a." , "{0} m.y.a." , "{0} m.y.a." , "{0} m.y.a." , "{0} m.y.a."] , ["{0}°C" , "{0}°C" , "{0}°C" , "{0}°C" , "{0}°C" , "{0}°C"] , ["{0}°F" , "{0}°F" , "{0}°F" , "{0}°F" , "{0}°F" , "{0}°F"] , ["{0}%" , "{0}%" , "{0}%" , "{0}%" , "{0}%" , "{0}%"] , ["{0}km" , "{0}km" , "{0}km" , "{0}km" , "{0}km" , "{0}km"] , ["{0}m" , "{0}m" , "{0}m" , "{0}m" , "{0}m" , "{0}m"] , ["{0}cm" , "{0}cm" , "{0}cm" , "{0}cm" , "{0}cm" , "{0}cm"] , ["{0}mm" , "{0}mm" , "{0}mm" , "{0}mm" , "{0}mm" , "{0}mm"] , ["{0}mi" , "{0}mi" , "{0}mi" , "{0}mi" , "{0}mi" , "{0}mi"] , ["{0}llath" , "{0}llath" , "{0}lath" , "{0}llath" , "{0}llath" , "{0}llath"] , ["{0}″" , "{0}″" , "{0}″" , "{0}″" , "{0}″" , "{0}″"] , ["{0} kg" , "{0} kg" , "{0} kg" , "{0} kg" , "{0} kg" , "{0}kg"] , ["{0}g" , "{0}g" , "{0}g" , "{0}g" , "{0}g" , "{0}g"] , ["{0}pwys" , "{0}pwys" , "{0}bwys" , "{0}phwys" , "{0}phwys" , "{0}pwys"] , ["{0}owns" , "{0}owns" , "{0}owns" , "{0}owns" , "{0}owns" , "{0}owns"] , ["{0}km/h" , "{0}km/h" , "{0}km/h" , "{0}km/h" , "{0}km/h" , "{0}km/h"] , ["{0}m/s" , "{0}m/s" , "{0}m/s" , "{0}m/s" , "{0}m/s" , "{0}m/s"] , ["{0}m.y.a." , "{0}m.y.a." , "{0}m.y.a." , "{0}m.y.a." , "{0}m.y.a." , "{0}m.y.a."] , ["{0}°" , "{0}°C" , "{0}°C" , "{0}°C" , "{0}°C" , "{0}°C"] , ["" , "{0} centimeter" , "" , "" , "" , "{0} centimeter"] , ["" , "{0} engelsk mil" , "" , "" , "" , "{0} engelske mil"] , ["" , "{0} engelsk yard" , "" , "" , "" , "{0} engelske yard"] , ["" , "{0} fod" , "" , "" , "" , "{0} fod"] , ["" , "{0} tomme" , "" , "" , "" , "{0} tommer"] , ["" , "{0} pund" , "" , "" , "" , "{0} pund"] , ["" , "{0} ounce" , "" , "" , "" , "{0} ounces"] , ["" , "kilometer i timen" , "" , "" , "" , "{0} kilometer i timen"] , ["" , "{0} meter i sekundet" , "" , "" , "" , "{0} meter i sekundet"] , ["" , "{0} engelsk mil i timen" , "" , "" , "" , "{0} engelske mil i timen"] , ["" , "{0} grad celsius" , "" , "" , "" , "{0} grader celsius"] , ["" , "{0} grad fahrenheit" , "" , "" , "" , "{0} grader fahrenheit"] , ["" , "{0} procent" , "" , "" , "" , "{0} procent"] , ["" , "{0} km/t." , "" , "" , "" , "{0} km/t."] , ["" , "{0} pct." , "" , "" , "" , "{0} pct."] , ["" , "{0} km/t" , "" , "" , "" , "{0} km/t"] , ["" , "{0} Kilometer" , "" , "" , "" , "{0} Kilometer"] , ["" , "{0} Meter" , "" , "" , "" , "{0} Meter"] , ["" , "{0} Zentimeter" , "" , "" , "" , "{0} Zentimeter"] , ["" , "{0} Millimeter" , "" , "" , "" , "{0} Millimeter"] , ["" , "{0} Meile" , "" , "" , "" , "{0} Meilen"] , ["" , "{0} Yard" , "" , "" , "" , "{0} Yards"] , ["" , "{0} Fuß" , "" , "" , "" , "{0} Fuß"] , ["" , "{0} Zoll" , "" , "" , "" , "{0} Zoll"] , ["" , "{0} Kilogramm" , "" , "" , "" , "{0} Kilogramm"] , ["" , "{0} Gramm" , "" , "" , "" , "{0} Gramm"] , ["" , "{0} Pfund" , "" , "" , "" , "{0} Pfund"] , ["" , "{0} Unze" , "" , "" , "" , "{0} Unzen"] , ["" , "{0} Kilometer pro Stunde" , "" , "" , "" , "{0} Kilometer pro Stunde"] , ["" , "{0} Meter pro Sekunde" , "" , "" , "" , "{0} Meter pro Sekunde"] , ["" , "{0} Meile pro Stunde" , "" , "" , "" , "{0} Meilen pro Stunde"] , ["" , "{0} Grad Celsius" , "" , "" , "" , "{0} Grad Celsius"] , ["" , "{0} Grad Fahrenheit" , "" , "" , "" , "{0} Grad Fahrenheit"] , ["" , "{0} Prozent" , "" , "" , "" , "{0} Prozent"] , ["" , "{0}\u{a0}in" , "" , "" , "" , "{0} in"] , ["" , "{0} Fuss" , "" , "" , "" , "{0} Fuss"] , ["" , "{0} kilometer" , "{0} kilometra" , "{0} kilometry" , "" , "{0} kilometrow"] , ["" , "{0} meter" , "{0} metra" , "{0} metry" , "" , "{0} metrow"] , ["" , "{0} centimeter" , "{0} centimetra" , "{0} centimetry" , "" , "{0} centimetrow"] , ["" , "{0} milimeter" , "{0} milimetra" , "{0} milimetry" , "" , "{0} milimetrow"] , ["" , "{0} mila" , "{0} mili" , "{0} mile" , "" , "{0} milow"] , ["" , "{0} yard" , "{0} yarda" , "{0} yardy" , "" , "{0} yardow"] , ["" , "{0} crjej" , "{0} crjeja" , "{0} crjeje" , "" , "{0} crjej"] , ["" , "{0} col" , "{0} cola" , "{0} cole" , "" , "{0} colow"] , ["" , "{0} kilogram" , "{0} kilograma" , "{0} kilogramy" , "" , "{0} kilogramow"] , ["" , "{0} gram" , "{0} grama" , "{0} gramy" , "" , "{0} gramow"] , ["" , "{0} punt" , "{0} punta" , "{0} punty" , "" , "{0} puntow"] , ["" , "{0} unca" , "{0} uncy" , "{0} unce" , "" , "{0} uncow"] , ["" , "{0} kilometer na góźinu" , "{0} kilometra na góźinu" , "{0} kilometry na góźinu" , "" , "{0} kilometrow na góźinu"] , ["" , "{0} meter na sekundu" , "{0} metra na sekundu" , "{0} metry na sekundu" , "" , "{0} metrow na sekundu"] , ["" , "{0} mila na góźinu" , "{0} mili na góźinu" , "{0} mile na góźinu" , "" , "{0} milow na góźinu"] , ["" , "{0} stopjeń celsiusa" , "{0} stopnja celsiusa" , "{0} stopnje celsiusa" , "" , "{0} stopnjow celsiusa"] , ["" , "{0} stopjeń Fahrenheita" , "{0} stopnja Fahrenheita" , "{0} stopnje Fahrenheita" , "" , "{0} stopnjow Fahrenheita"] , ["" , "{0} procent" , "{0} procenta" , "{0} procenty" , "" , "{0} procentow"] , ["" , "{0} km" , "{0} km" , "{0} km" , "" , "{0} km"] , ["" , "{0} m" , "{0} m" , "{0} m" , "" , "{0} m"] , ["" , "{0} cm" , "{0} cm" , "{0} cm" , "" , "{0} cm"] , ["" , "{0} mm" , "{0} mm" , "{0} mm" , "" , "{0} mm"] , ["" , "{0} mi" , "{0} mi" , "{0} mi" , "" , "{0} mi"] , ["" , "{0} yd" , "{0} yd" , "{0} yd" , "" , "{0} yd"] , ["" , "{0} ft" , "{0} ft" , "{0} ft" , "" , "{0} ft"] , ["" , "{0} in" , "{0} in" , "{0} in" , "" , "{0} in"] , ["" , "{0} kg" , "{0} kg" , "{0} kg" , "" , "{0} kg"] , ["" , "{0} g" , "{0} g" , "{0} g" , "" , "{0} g"] , ["" , "{0} lb" , "{0} lb" , "{0} lb" , "" , "{0} lb"] , ["" , "{0} oz" , "{0} oz" , "{0} oz" , "" , "{0} oz"] , ["" , "{0} km/h" , "{0} km/h" , "{0} km/h" , "" , "{0} km/h"] , ["" , "{0} m/s" , "{0} m/s" , "{0} m/s" , "" , "{0} m/s"] , ["" , "{0} mph" , "{0} mph" , "{0} mph" , "" , "{0} mph"] , ["" , "{0}°C" , "{0}°C" , "{0}°C" , "" , "{0}°C"] , ["" , "{0}°F" , "{0}°F" , "{0}°F" , "" , "{0}°F"] , ["" , "{0} %" , "{0} %" , "{0} %" , "" , "{0} %"] , ["" , "{0} χιλιόμετρο" , "" , "" , "" , "{0} χιλιόμετρα"] , ["" , "{0} μέτρο" , "" , "" , "" , "{0} μέτρα"] , ["" , "{0} εκατοστό" , "" , "" , "" , "{0} εκατοστά"] , ["" , "{0} χιλιοστόμετρο" , "" , "" , "" , "{0} χιλιοστόμετρα"] , ["" , "{0} μίλι" , "" , "" , "" , "{0} μίλια"] , ["" , "{0} γιάρδα" , "" , "" , "" , "{0} γιάρδες"] , ["" , "{0} πόδι" , "" , "" , "" , "{0} πόδια"] , ["" , "{0} ίντσα" , "" , "" , "" , "{0} ίντσες"] , ["" , "{0} χιλιόγραμμο" , "" , "" , "" , "{0} χιλιόγραμμα"] , ["" , "{0} γραμμάριο" , "" , "" , "" , "{0} γραμμάρια"] , ["" , "{0} λίβρα" , "" , "" , "" , "{0} λίβρες"] , ["" , "{0} ουγγιά" , "" , "" , "" , "{0} ουγγιές"] , ["" , "{0} χιλιόμετρο ανά ώρα" , "" , "" , "" , "{0} χιλιόμετρα ανά ώρα"] , ["" , "{0} μέτρο ανά δευτερόλεπτο" , "" , "" , "" , "{0} μέτρα ανά δευτερόλεπτο"] , ["" , "{0} μίλι ανά ώρα" , "" , "" , "" , "{0} μίλια ανά ώρα"] , ["" , "{0} βαθμός Κελσίου" , "" , "" , "" , "{0} βαθμοί Κελσίου"] , ["" , "{0} βαθμός Φαρενάιτ" , "" , "" , "" , "{0} βαθμοί Φαρενάιτ"] , ["" , "{0} τοις εκατό" , "" , "" , "" , "{0} τοις εκατό"] , ["" , "{0} χλμ." , "" , "" , "" , "{0} χλμ."] , ["" , "{0} μ." , "" , "" , "" , "{0} μ."] , ["" , "{0} εκ." , "" , "" , "" , "{0} εκ."] , ["" , "{0} χλστ." , "" , "" , "" , "{0} χλστ."] , ["" , "{0} μίλ." , "" , "" , "" , "{0} μίλ."] , ["" , "{0} γρδ" , "" , "" , "" , "{0} γρδ"] , ["" , "{0} πδ" , "" , "" , "" , "{0} πδ"] , ["" , "{0} ίν." , "" , "" , "" , "{0} ίν."] , ["" , "{0} κιλό" , "" , "" , "" , "{0} κιλά"] , ["" , "{0} γρ." , "" , "" , "" , "{0} γρ."] , ["" , "{0} λβ" , "" , "" , "" , "{0} λβ"] , ["" , "{0} χλμ./ώρα" , "" , "" , "" , "{0} χλμ./ώρα"] , ["" , "{0} μέτρο/δευτ." , "" , "" , "" , "{0} μέτρα/δευτ."] , ["" , "{0} μίλι/ώρα" , "" , "" , "" , "{0} μίλια/ώρα"] , ["" , "{0} χλμ/ώ." , "" , "" , "" , "{0} χλμ/ώ."] , ["" , "{0} μ./δ." , "" , "" , "" , "{0} μ./δ."] , ["" , "{0} μίλι/ώ." , "" , "" , "" , "{0} μίλια/ώ."] , ["" , "{0} kilometer" , "" , "" , "" , "{0} kilometers"] , ["" , "{0} meter" , "" , "" , "" , "{0} meters"] , ["" , "{0} centimeter" , "" , "" , "" , "{0} centimeters"] , ["" , "{0} millimeter" , "" , "" , "" , "{0} millimeters"] , ["" , "{0} mile" , "" , "" , "" , "{0} miles"] , ["" , "{0} yard" , "" , "" , "" , "{0} yards"] , ["" , "{0} foot" , "" , "" , "" , "{0} feet"] , ["" , "{0} inch" , "" , "" , "" , "{0} inches"] , ["" , "{0} kilogram" , "" , "" , "" , "{0} kilograms"] , ["" , "{0} pound" , "" , "" , "" , "{0} pounds"] , ["" , "{0} kilometer per hour" , "" , "" , "" , "{0} kilometers per hour"] , ["" , "{0} meter per second" , "" , "" , "" , "{0} meters per second"] , ["" , "{0} mile per hour" , "" , "" , "" , "{0} miles per hour"] , ["" , "{0} degree Celsius" , "" , "" , "" , "{0} degrees Celsius"] , ["" , "{0} degree Fahrenheit" , "" , "" , "" , "{0} degrees Fahrenheit"] , ["" , "{0} percent" , "" , "" , "" , "{0} percent"] , ["" , "{0} kilometre" , "" , "" , "" , "{0} kilometres"] , ["" , "{0} centimetre" , "" , "" , "" , "{0} centimetres"] , ["" , "{0} millimetre" , "" , "" , "" , "{0} millimetres"] , ["" , "{0} kilometre per hour" , "" , "" , "" , "{0} kilometres per hour"] , ["" , "{0} metre per second" , "" , "" , "" , "{0} metres per second"] , ["" , "{0}lb" , "" , "" , "" , "{0}lb"] , ["" , "{0} kph" , "" , "" , "" , "{0} kph"] , ["" , "{0} kilómetro" , "" , "" , "" , "{0} kilómetros"] , ["" , "{0} metro" , "" , "" , "" , "{0} metros"] , ["" , "{0} centímetro" , "" , "" , "" , "{0} centímetros"] , ["" , "{0} milímetro" , "" , "" , "" , "{0} milímetros"] , ["" , "{0} milla" , "" , "" , "" , "{0} millas"] , ["" , "{0} yarda" , "" , "" , "" , "{0} yardas"] , ["" , "{0} pulgada" , "" , "" , "" , "{0} pulgadas"] , ["" , "{0} kilogramo" , "" , "" , "" , "{0} kilogramos"] , ["" , "{0} gramo" , "" , "" , "" , "{0} gramos"] , ["" , "{0} libra" , "" , "" , "" , "{0} libras"] , ["" , "{0} onza" , "" , "" , "" , "{0} onzas"] , ["" , "{0} kilómetro por hora" , "" , "" , "" , "{0} kilómetros por hora"] , ["" , "{0} metro por segundo" , "" , "" , "" , "{0} metros por segundo"] , ["" , "{0} milla por hora" , "" , "" , "" , "{0} millas por hora"] , ["" , "{0} grado Celsius" , "" , "" , "" , "{0} grados Celsius"] , ["" , "{0} grado Fahrenheit" , "" , "" , "" , "{0} grados Fahrenheit"] , ["" , "{0} por ciento" , "" , "" , "" , "{0} por ciento"] , ["" , "{0}\u{a0}%" , "" , "" , "" , "{0}\u{a0}%"] , ["" , "{0}ft" , "" , "" , "" , "{0}ft"] , ["" , "{0}in" , "" , "" , "" , "{0}in"] , ["" , "{0}mi/h" , "" , "" , "" , "{0}mi/h"] , ["" , "{0} kilomeeter" , "" , "" , "" , "{0} kilomeetrit"] , ["" , "{0} meeter" , "" , "" , "" , "{0} meetrit"] , ["" , "{0} sentimeeter" , "" , "" , "" , "{0} sentimeetrit"] , ["" , "{0} millimeeter" , "" , "" , "" , "{0} millimeetrit"] , ["" , "{0} miil" , "" , "" , "" , "{0} miili"] , ["" , "{0} jard" , "" , "" , "" , "{0} jardi"] , ["" , "{0} jalg" , "" , "" , "" , "{0} jalga"] , ["" , "{0} toll" , "" , "" , "" , "{0} tolli"] , ["" , "{0} kilogramm" , "" , "" , "" , "{0} kilogrammi"] , ["" , "{0} gramm" , "" , "" , "" , "{0} grammi"] , ["" , "{0} nael" , "" , "" , "" , "{0} naela"] , ["" , "{0} unts" , "" , "" , "" , "{0} untsi"] , ["" , "{0} kilomeeter tunnis" , "" , "" , "" , "{0} kilomeetrit tunnis"] , ["" , "{0} meeter sekundis" , "" , "" , "" , "{0} meetrit sekundis"] , ["" , "{0} miil tunnis" , "" , "" , "" , "{0} miili tunnis"] , ["" , "{0} Celsiuse kraad" , "" , "" , "" , "{0} Celsiuse kraadi"] , ["" , "{0} Fahrenheiti kraad" , "" , "" , "" , "{0} Fahrenheiti kraadi"] , ["" , "{0} protsent" , "" , "" , "" , "{0} protsenti"] , ["" , "{0} kilometro" , "" , "" , "" , "{0} kilometro"] , ["" , "{0} metro" , "" , "" , "" , "{0} metro"] , ["" , "{0} zentimetro" , "" , "" , "" , "{0} zentimetro"] , ["" , "{0} milimetro" , "" , "" , "" , "{0} milimetro"] , ["" , "{0} milia" , "" , "" , "" , "{0} milia"] , ["" , "{0} yarda" , "" , "" , "" , "{0} yarda"] , ["" , "{0} oin" , "" , "" , "" , "{0} oin"] , ["" , "{0} hazbete" , "" , "" , "" , "{0} hazbete"] , ["" , "{0} kilogramo" , "" , "" , "" , "{0} kilogramo"] , ["" , "{0} gramo" , "" , "" , "" , "{0} gramo"] , ["" , "{0} libra" , "" , "" , "" , "{0} libra"] , ["" , "{0} ontza" , "" , "" , "" , "{0} ontza"] , ["" , "{0} kilometro orduko" , "" , "" , "" , "{0} kilometro orduko"] , ["" , "{0} metro segundoko" , "" , "" , "" , "{0} metro segundoko"] , ["" , "{0} milia orduko" , "" , "" , "" , "{0} milia orduko"] , ["" , "{0} Celsius gradu" , "" , "" , "" , "{0} Celsius gradu"] , ["" , "{0} Fahrenheit gradu" , "" , "" , "" , "{0} Fahrenheit gradu"] , ["" , "ehuneko {0}" , "" , "" , "" , "ehuneko {0}"] , ["" , "% {0}" , "" , "" , "" , "% {0}"] , ["" , "{0} کیلومتر" , "" , "" , "" , "{0} کیلومتر"] , ["" , "{0} متر" , "" , "" , "" , "{0} متر"] , ["" , "{0} سانتی\u{200c}متر" , "" , "" , "" , "{0} سانتی\u{200c}متر"] , ["" , "{0} میلی\u{200c}متر" , "" , "" , "" , "{0} میلی\u{200c}متر"] , ["" , "{0} مایل" , "" , "" , "" , "{0} مایل"] , ["" , "{0} یارد" , "" , "" , "" , "{0} یارد"] , ["" , "{0} فوت" , "" , "" , "" , "{0} فوت"] , ["" , "{0} اینچ" , "" , "" , "" , "{0} اینچ"] , ["" , "{0} کیلوگرم" , "" , "" , "" , "{0} کیلوگرم"] , ["" , "{0} گرم" , "" , "" , "" , "{0} گرم"] , ["" , "{0} پوند" , "" , "" , "" , "{0} پوند"] , ["" , "{0} اونس" , "" , "" , "" , "{0} اونس"] , ["" , "{0} کیلومتر در ساعت" , "" , "" , "" , "{0} کیلومتر در ساعت"] , ["" , "{0} متر در ثانیه" , "" , "" , "" , "{0} متر در ثانیه"] , ["" , "{0} مایل در ساعت" , "" , "" , "" , "{0} مایل در ساعت"] , ["" , "{0} درجه\u{654} سلسیوس" , "" , "" , "" , "{0} درجه\u{654} سلسیوس"] , ["" , "{0} درجه\u{654} فارنهایت" , "" , "" , "" , "{0} درجه\u{654} فارنهایت"] , ["" , "{0} درصد" , "" , "" , "" , "{0} درصد"] , ["" , "{0} س.م" , "" , "" , "" , "{0} س.م"] , ["" , "{0}\u{200e} km/h" , "" , "" , "" , "{0}\u{200e} km/h"] , ["" , "{0}\u{200e} mph" , "" , "" , "" , "{0}\u{200e} mph"] , ["" , "{0}٪" , "" , "" , "" , "{0}٪"] , ["" , "{0} kilometri" , "" , "" , "" , "{0} kilometriä"] , ["" , "{0} metri" , "" , "" , "" , "{0} metriä"] , ["" , "{0} senttimetri" , "" , "" , "" , "{0} senttimetriä"] , ["" , "{0} millimetri" , "" , "" , "" , "{0} millimetriä"] , ["" , "{0} maili" , "" , "" , "" , "{0} mailia"] , ["" , "{0} jaardi" , "" , "" , "" , "{0} jaardia"] , ["" , "{0} jalka" , "" , "" , "" , "{0} jalkaa"] , ["" , "{0} tuuma" , "" , "" , "" , "{0} tuumaa"] , ["" , "{0} kilogramma" , "" , "" , "" , "{0} kilogrammaa"] , ["" , "{0} gramma" , "" , "" , "" , "{0} grammaa"] , ["" , "{0} pauna" , "" , "" , "" , "{0} paunaa"] , ["" , "{0} unssi" , "" , "" , "" , "{0} unssia"] , ["" , "{0} kilometri tunnissa" , "" , "" , "" , "{0} kilometriä tunnissa"] , ["" , "{0} metri sekunnissa" , "" , "" , "" , "{0} metriä sekunnissa"] , ["" , "{0} maili tunnissa" , "" , "" , "" , "{0} mailia tunnissa"] , ["" , "{0} celsiusaste" , "" , "" , "" , "{0} celsiusastetta"] , ["" , "{0} fahrenheitaste" , "" , "" , "" , "{0} fahrenheitastetta"] , ["" , "{0} prosentti" , "" , "" , "" , "{0} prosenttia"] , ["" , "{0} kilometro" , "" , "" , "" , "{0} na kilometro"] , ["" , "{0} metro" , "" , "" , "" , "{0} na metro"] , ["" , "{0} sentimetro" , "" , "" , "" , "{0} sentimetro"] , ["" , "{0} milimetro" , "" , "" , "" , "{0} na milimetro"] , ["" , "{0} milya" , "" , "" , "" , "{0} na milya"] , ["" , "{0} yarda" , "" , "" , "" , "{0} na yarda"] , ["" , "{0} talampakan" , "" , "" , "" , "{0} na talampakan"] , ["" , "{0} pulgada" , "" , "" , "" , "{0} na pulgada"] , ["" , "{0} kilo" , "" , "" , "" , "{0} kilo"] , ["" , "{0} gramo" , "" , "" , "" , "{0} na gramo"] , ["" , "{0} libra" , "" , "" , "" , "{0} na libra"] , ["" , "{0} onsa" , "" , "" , "" , "{0} na onsa"] , ["" , "{0} kilometro kada oras" , "" , "" , "" , "{0} na kilometro kada oras"] , ["" , "{0} metro kada segundo" , "" , "" , "" , "{0} metro kada segundo"] , ["" , "{0} milya kada oras" , "" , "" , "" , "{0} milya kada oras"] , ["" , "{0} porsyento" , "" , "" , "" , "{0} na porsyento"] , ["" , "{0} yd" , "" , "" , "" , "{0} na yd"] , ["" , "{0} kilometur" , "" , "" , "" , "{0} kilometrar"] , ["" , "{0} metur" , "" , "" , "" , "{0} metrar"] , ["" , "{0} sentimetur" , "" , "" , "" , "{0} sentimetrar"] , ["" , "{0} millimetur" , "" , "" , "" , "{0} millimetrar"] , ["" , "{0} míl" , "" , "" , "" , "{0} míl"] , ["" , "{0} fótur" , "" , "" , "" , "{0} føtur"] , ["" , "{0} tummi" , "" , "" , "" , "{0} tummar"] , ["" , "{0} kilogramm" , "" , "" , "" , "{0} kilogramm"] , ["" , "{0} gramm" , "" , "" , "" , "{0} gramm"] , ["" , "{0} unsa" , "" , "" , "" , "{0} unsur"] , ["" , "{0} kilometur um tíman" , "" , "" , "" , "{0} kilometrar um tíman"] , ["" , "{0} metur um sekundi" , "" , "" , "" , "{0} metrar um sekundi"] , ["" , "{0} míl/t" , "" , "" , "" , "{0} míl/t"] , ["" , "{0} stig Celsius" , "" , "" , "" , "{0} stig Celsius"] , ["" , "{0} stig Fahrenheit" , "" , "" , "" , "{0} stig Fahrenheit"] , ["" , "{0} prosent" , "" , "" , "" , "{0} prosent"] , ["" , "{0} tum." , "" , "" , "" , "{0} tum."] , ["" , "{0}km/t" , "" , "" , "" , "{0}km/t"] , ["" , "{0}\u{a0}kilomètre" , "" , "" , "" , "{0}\u{a0}kilomètres"] , ["" , "{0}\u{a0}mètre" , "" , "" , "" , "{0}\u{a0}mètres"] , ["" , "{0}\u{a0}centimètre" , "" , "" , "" , "{0}\u{a0}centimètres"] , ["" , "{0}\u{a0}millimètre" , "" , "" , "" , "{0}\u{a0}millimètres"] , ["" , "{0}\u{a0}mile" , "" , "" , "" , "{0}\u{a0}miles"] , ["" , "{0}\u{a0}yard" , "" , "" , "" , "{0}\u{a0}yards"] , ["" , "{0}\u{a0}pied" , "" , "" , "" , "{0} pieds"] , ["" , "{0}\u{a0}pouce" , "" , "" , "" , "{0}\u{a0}pouces"] , ["" , "{0}\u{a0}kilogramme" , "" , "" , "" , "{0}\u{a0}kilogrammes"] , ["" , "{0}\u{a0}gramme" , "" , "" , "" , "{0}\u{a0}grammes"] , ["" , "{0}\u{a0}livre" , "" , "" , "" , "{0}\u{a0}livres"] , ["" , "{0}\u{a0}once" , "" , "" , "" , "{0}\u{a0}onces"] , ["" , "{0}\u{a0}kilomètre par heure" , "" , "" , "" , "{0}\u{a0}kilomètres par heure"] , ["" , "{0}\u{a0}mètre par seconde" , "" , "" , "" , "{0}\u{a0}mètres par seconde"] , ["" , "{0}\u{a0}mile par heure" , "" , "" , "" , "{0}\u{a0}miles par heure"] , ["" , "{0}\u{a0}degré Celsius" , "" , "" , "" , "{0}\u{a0}degrés Celsius"] , ["" , "{0}\u{a0}degré Fahrenheit" , "" , "" , "" , "{0}\u{a0}degrés Fahrenheit"] , ["" , "{0} pour cent" , "" , "" , "" , "{0} pour cent"] , ["" , "{0}\u{202f}km" , "" , "" , "" , "{0}\u{202f}km"] , ["" , "{0}\u{202f}m" , "" , "" , "" , "{0}\u{202f}m"] , ["" , "{0}\u{202f}cm" , "" , "" , "" , "{0}\u{202f}cm"] , ["" , "{0}\u{202f}mm" , "" , "" , "" , "{0}\u{202f}mm"] , ["" , "{0}\u{202f}mi" , "" , "" , "" , "{0}\u{202f}mi"] , ["" , "{0}\u{202f}yd" , "" , "" , "" , "{0}\u{202f}yd"] , ["" , "{0}\u{202f}pi" , "" , "" , "" , "{0}\u{202f}pi"] , ["" , "{0}\u{202f}po" , "" , "" , "" , "{0}\u{202f}po"] , ["" , "{0}\u{202f}kg" , "" , "" , "" , "{0}\u{202f}kg"] , ["" , "{0}\u{202f}g" , "" , "" , "" , "{0}\u{202f}g"] , ["" , "{0}\u{202f}lb" , "" , "" , "" , "{0}\u{202f}lb"] , ["" , "{0}\u{202f}oz" , "" , "" , "" , "{0}\u{202f}oz"] , ["" , "{0}\u{202f}km/h" , "" , "" , "" , "{0}\u{202f}km/h"] , ["" , "{0}\u{202f}m/s" , "" , "" , "" , "{0}\u{202f}m/s"] , ["" , "{0}\u{202f}mi/h" , "" , "" , "" , "{0}\u{202f}mi/h"] , ["" , "{0}\u{202f}°C" , "" , "" , "" , "{0}\u{202f}°C"] , ["" , "{0}\u{202f}°F" , "" , "" , "" , "{0}\u{202f}°F"] , ["" , "{0} kilomètre" , "" , "" , "" , "{0} kilomètres"] , ["" , "{0} mètre" , "" , "" , "" , "{0} mètres"] , ["" , "{0} centimètre" , "" , "" , "" , "{0} centimètres"] , ["" , "{0} millimètre" , "" , "" , "" , "{0} millimètres"] , ["" , "{0} mille" , "" , "" , "" , "{0} milles"] , ["" , "{0}\u{a0}verge" , "" , "" , "" , "{0} verges"] , ["" , "{0} pied" , "" , "" , "" , "{0} pieds"] , ["" , "{0} pouce" , "" , "" , "" , "{0} pouces"] , ["" , "{0} kilogramme" , "" , "" , "" , "{0} kilogrammes"] , ["" , "{0} gramme" , "" , "" , "" , "{0} grammes"] , ["" , "{0} livre" , "" , "" , "" , "{0} livres"] , ["" , "{0} once" , "" , "" , "" , "{0} onces"] , ["" , "{0} kilomètre par heure" , "" , "" , "" , "{0} kilomètres par heure"] , ["" , "{0} mètre par seconde" , "" , "" , "" , "{0} mètres par seconde"] , ["" , "{0} mille à l’heure" , "" , "" , "" , "{0} milles à l’heure"] , ["" , "{0} degré Celsius" , "" , "" , "" , "{0} degrés Celsius"] , ["" , "{0} degré Fahrenheit" , "" , "" , "" , "{0} degrés Fahrenheit"] , ["" , "{0}\u{a0}vg" , "" , "" , "" , "{0} vg"] , ["" , "{0}\u{a0}pi" , "" , "" , "" , "{0} pi"] , ["" , "{0} po" , "" , "" , "" , "{0} po"] , ["" , "{0}vg" , "" , "" , "" , "{0}vg"] , ["" , "{0}pi" , "" , "" , "" , "{0}pi"] , ["" , "{0}po" , "" , "" , "" , "{0}po"] , ["" , "{0}\u{a0}°C" , "" , "" , "" , "{0} °C"] , ["" , "{0}gr" , "" , "" , "" , "{0}gr"] , ["" , "{0} foet" , "" , "" , "" , "{0} foet"] , ["" , "{0} tomme" , "" , "" , "" , "{0} tommen"] , ["" , "{0} kilometer de oere" , "" , "" , "" , "{0} kilometer de oere"] , ["" , "{0} meter de sekonde" , "" , "" , "" , "{0} meter de sekonde"] , ["" , "{0} myl de oere" , "" , "" , "" , "{0} myl de oere"] , ["" , "{0} graad Celsius" , "" , "" , "" , "{0} graden Celsius"] , ["" , "{0} graad Fahrenheit" , "" , "" , "" , "{0} graden Fahrenheit"] , ["" , "{0} tm" , "" , "" , "" , "{0} tm"] , ["" , "{0} chiliméadar" , "{0} chiliméadar" , "{0} chiliméadar" , "{0} gciliméadar" , "{0} ciliméadar"] , ["" , "{0} mhéadar" , "{0} mhéadar" , "{0} mhéadar" , "{0} méadar" , "{0} méadar"] , ["" , "{0} cheintiméadar" , "{0} cheintiméadar" , "{0} cheintiméadar" , "{0} gceintiméadar" , "{0} ceintiméadar"] , ["" , "{0} mhilliméadar" , "{0} mhilliméadar" , "{0} mhilliméadar" , "{0} milliméadar" , "{0} milliméadar"] , ["" , "{0} mhíle" , "{0} mhíle" , "{0} mhíle" , "{0} míle" , "{0} míle"] , ["" , "{0} slat" , "{0} shlat" , "{0} shlat" , "{0} slat" , "{0} slat"] , ["" , "{0} troigh" , "{0} throigh" , "{0} throigh" , "{0} dtroigh" , "{0} troigh"] , ["" , "{0} orlach" , "{0} orlach" , "{0} orlach" , "{0} n-orlach" , "{0} orlach"] , ["" , "{0} chileagram" , "{0} chileagram" , "{0} chileagram" , "{0} gcileagram" , "{0} cileagram"] , ["" , "{0} ghram" , "{0} ghram" , "{0} ghram" , "{0} ngram" , "{0} gram"] , ["" , "{0} phunt" , "{0} phunt" , "{0} phunt" , "{0} bpunt" , "{0} punt"] , ["" , "{0} unsa" , "{0} unsa" , "{0} unsa" , "{0} n-unsa" , "{0} unsa"] , ["" , "{0} chiliméadar san uair" , "{0} chiliméadar san uair" , "{0} chiliméadar san uair" , "{0} gciliméadar san uair" , "{0} ciliméadar san uair"] , ["" , "{0} mhéadar sa soicind" , "{0} mhéadar sa soicind" , "{0} mhéadar sa soicind" , "{0} méadar sa soicind" , "{0} méadar sa soicind"] , ["" , "{0} mhíle san uair" , "{0} mhíle san uair" , "{0} mhíle san uair" , "{0} míle san uair" , "{0} míle san uair"] , ["" , "{0} chéim Celsius" , "{0} chéim Celsius" , "{0} chéim Celsius" , "{0} gcéim Celsius" , "{0} céim Celsius"] , ["" , "{0} chéim Fahrenheit" , "{0} chéim Fahrenheit" , "{0} chéim Fahrenheit" , "{0} gcéim Fahrenheit" , "{0} céim Fahrenheit"] , ["" , "{0} faoin gcéad" , "{0}%" , "{0}%" , "{0}%" , "{0} faoin gcéad"] , ["" , "{0} mhíle" , "{0} mhíle" , "{0} mhíle" , "{0} míle" , "{0} mi"] , ["" , "{0} sl." , "{0} shl." , "{0} shl." , "{0} sl." , "{0} sl."] , ["" , "{0} tr." , "{0} thr." , "{0} thr." , "{0} dtr." , "{0} tr."] , ["" , "{0} or." , "{0} or." , "{0} or." , "{0} n-or." , "{0} or."] , ["" , "{0} km/u" , "{0} km/u" , "{0} km/u" , "{0} km/u" , "{0} km/u"] , ["" , "{0} msu" , "{0} msu" , "{0} msu" , "{0} msu" , "{0} msu"] , ["" , "{0}sl" , "{0}sl" , "{0}sl" , "{0}sl" , "{0}sl"] , ["" , "{0}km/u" , "{0}km/u" , "{0}km/u" , "{0}km/u" , "{0}km/u"] , ["" , "{0}msu" , "{0}msu" , "{0}msu" , "{0}msu" , "{0}msu"] , ["" , "{0} chilemeatair" , "{0} chilemeatair" , "{0} cilemeatairean" , "" , "{0} cilemeatair"] , ["" , "{0} mheatair" , "{0} mheatair" , "{0} meatairean" , "" , "{0} meatair"] , ["" , "{0} cheudameatair" , "{0} cheudameatair" , "{0} ceudameatairean" , "" , "{0} ceudameatair"] , ["" , "{0} mhili-meatair" , "{0} mhili-meatair" , "{0} mili-meatairean" , "" , "{0} mili-meatair"] , ["" , "{0} mhìle" , "{0} mhìle" , "{0} mìltean" , "" , "{0} mìle"] , ["" , "{0} slat" , "{0} shlat" , "{0} slatan" , "" , "{0} slat"] , ["" , "{0} troigh" , "{0} throigh" , "{0} troighean" , "" , "{0} troigh"] , ["" , "{0} òirleach" , "{0} òirleach" , "{0} òirlich" , "" , "{0} òirleach"] , ["" , "{0} chileagram" , "{0} chileagram" , "{0} cileagramaichean" , "" , "{0} cileagram"] , ["" , "{0} ghram" , "{0} ghram" , "{0} gramaichean" , "" , "{0} gram"] , ["" , "{0} phunnd" , "{0} phunnd" , "{0} puinnd" , "" , "{0} punnd"] , ["" , "{0} unnsa" , "{0} unnsa" , "{0} unnsachan" , "" , "{0} unnsa"] , ["" , "{0} chilemeatair san uair" , "{0} chilemeatair san uair" , "{0} cilemeatairean san uair" , "" , "{0} cilemeatair san uair"] , ["" , "{0} mheatair san diog" , "{0} mheatair san diog" , "{0} meatairean san diog" , "" , "{0} meatair san diog"] , ["" , "{0} mhìle san uair" , "{0} mhìle san uair" , "{0} mìltean san uair" , "" , "{0} mìle san uair"] , ["" , "{0} cheum Celsius" , "{0} cheum Celsius" , "{0} ceuman Celsius" , "" , "{0} ceum Celsius"] , ["" , "{0} cheum Fahrenheit" , "{0} cheum Fahrenheit" , "{0} ceuman Fahrenheit" , "" , "{0} ceum Fahrenheit"] , ["" , "{0} sa cheud" , "{0} sa cheud" , "{0} sa cheud" , "" , "{0} sa cheud"] , ["" , "{0} mì" , "{0} mì" , "{0} mì" , "" , "{0} mì"] , ["" , "{0} slat" , "{0} shlat" , "{0} slat" , "" , "{0} slat"] , ["" , "{0} troigh" , "{0} throigh" , "{0} troigh" , "" , "{0} troigh"] , ["" , "{0} òirl" , "{0} òirl" , "{0} òirl" , "" , "{0} òirl"] , ["" , "{0} unnsa" , "{0} unnsa" , "{0} unnsa" , "" , "{0} unnsa"] , ["" , "{0} mì/h" , "{0} mì/h" , "{0} mì/h" , "" , "{0} mì/h"] , ["" , "{0}%" , "{0}%" , "{0}%" , "" , "{0}%"] , ["" , "{0}km" , "{0}km" , "{0}km" , "" , "{0}km"] , ["" , "{0}m" , "{0}m" , "{0}m" , "" , "{0}m"] , ["" , "{0}cm" , "{0}cm" , "{0}cm" , "" , "{0}cm"] , ["" , "{0}mm" , "{0}mm" , "{0}mm" , "" , "{0}mm"] , ["" , "{0}mì" , "{0}mì" , "{0}mì" , "" , "{0}mì"] , ["" , "{0}yd" , "{0}yd" , "{0}yd" , "" , "{0}yd"] , ["" , "{0}′" , "{0}′" , "{0}′" , "" , "{0}′"] , ["" , "{0}″" , "{0}″" , "{0}″" , "" , "{0}″"] , ["" , "{0}kg" , "{0}kg" , "{0}kg" , "" , "{0}kg"] , ["" , "{0}g" , "{0}g" , "{0}g" , "" , "{0}g"] , ["" , "{0}lb" , "{0}lb" , "{0}lb" , "" , "{0}lb"] , ["" , "{0}oz" , "{0}oz" , "{0}oz" , "" , "{0}oz"] , ["" , "{0}km/h" , "{0}km/h" , "{0}km/h" , "" , "{0}km/h"] , ["" , "{0}m/s" , "{0}m/s" , "{0}m/s" , "" , "{0}m/s"] , ["" , "{0}mì/h" , "{0}mì/h" , "{0}mì/h" , "" , "{0}mì/h"] , ["" , "{0} quilómetro" , "" , "" , "" , "{0} quilómetros"] , ["" , "{0} iarda" , "" , "" , "" , "{0} iardas"] , ["" , "{0} pé" , "" , "" , "" , "{0} pés"] , ["" , "{0} polgada" , "" , "" , "" , "{0} polgadas"] , ["" , "{0} quilogramo" , "" , "" , "" , "{0} quilogramos"] , ["" , "{0} quilómetro por hora" , "" , "" , "" , "{0} quilómetros por hora"] , ["" , "{0} grao Celsius" , "" , "" , "" , "{0} graos Celsius"] , ["" , "{0} grao Fahrenheit" , "" , "" , "" , "{0} graos Fahrenheit"] , ["" , "{0} કિલોમીટર" , "" , "" , "" , "{0} કિલોમીટર"] , ["" , "{0} મીટર" , "" , "" , "" , "{0} મીટર"] , ["" , "{0} સ\u{ac7}ન\u{acd}ટિમીટર" , "" , "" , "" , "{0} સ\u{ac7}ન\u{acd}ટિમીટર"] , ["" , "{0} મિલિમીટર" , "" , "" , "" , "{0} મિલિમીટર"] , ["" , "{0} માઇલ" , "" , "" , "" , "{0} માઇલ"] , ["" , "{0} વાર" , "" , "" , "" , "{0} વાર"] , ["" , "{0} ફ\u{ac2}ટ" , "" , "" , "" , "{0} ફ\u{ac2}ટ"] , ["" , "{0} ઇ\u{a82}ચ" , "" , "" , "" , "{0} ઇ\u{a82}ચ"] , ["" , "{0} કિલોગ\u{acd}રામ" , "" , "" , "" , "{0} કિલોગ\u{acd}રામ"] , ["" , "{0} ગ\u{acd}રામ" , "" , "" , "" , "{0} ગ\u{acd}રામ"] , ["" , "{0} પાઉન\u{acd}ડ" , "" , "" , "" , "{0} પાઉન\u{acd}ડ"] , ["" , "{0} ઔ\u{a82}સ" , "" , "" , "" , "{0} ઔ\u{a82}સ"] , ["" , "{0} કિલોમીટર પ\u{acd}રતિ કલાક" , "" , "" , "" , "{0} કિલોમીટર પ\u{acd}રતિ કલાક"] , ["" , "{0} મીટર પ\u{acd}રતિ સ\u{ac7}કન\u{acd}ડ" , "" , "" , "" , "{0} મીટર પ\u{acd}રતિ સ\u{ac7}કન\u{acd}ડ"] , ["" , "{0} માઇલ પ\u{acd}રતિ કલાક" , "" , "" , "" , "{0} માઇલ પ\u{acd}રતિ કલાક"] , ["" , "{0} ડિગ\u{acd}રી સ\u{ac7}લ\u{acd}સિયસ" , "" , "" , "" , "{0} ડિગ\u{acd}રી સ\u{ac7}લ\u{acd}સિયસ"] , ["" , "{0} ડિગ\u{acd}રી ફ\u{ac7}રનહીટ" , "" , "" , "" , "{0} ડિગ\u{acd}રી ફ\u{ac7}રનહીટ"] , ["" , "{0} ટકા" , "" , "" , "" , "{0} ટકા"] , ["" , "{0} કિમી" , "" , "" , "" , "{0} કિમી"] , ["" , "{0} મી" , "" , "" , "" , "{0} મી"] , ["" , "{0} સ\u{ac7}મી" , "" , "" , "" , "{0} સ\u{ac7}મી"] , ["" , "{0} મિમી" , "" , "" , "" , "{0} મિમી"] , ["" , "{0} કિગ\u{acd}રા" , "" , "" , "" , "{0} કિગ\u{acd}રા"] , ["" , "{0} ગ\u{acd}રા" , "" , "" , "" , "{0} ગ\u{acd}રા"] , ["" , "{0} કિમી/કલાક" , "" , "" , "" , "{0} કિમી/કલાક"] , ["" , "{0} મીટર/સ\u{ac7}કન\u{acd}ડ" , "" , "" , "" , "{0} મીટર/સ\u{ac7}કન\u{acd}ડ"] , ["" , "{0} માઇલ/કલાક" , "" , "" , "" , "{0} માઇલ/કલાક"] , ["" , "{0} '" , "" , "" , "" , "{0} '"] , ["" , "{0} \\" , "" , "" , "" , "{0} \\"] , ["" , "{0} કિપ\u{acd}રક" , "" , "" , "" , "{0} કિપ\u{acd}રક"] , ["" , "kilomita {0}" , "" , "" , "" , "kilomitoci {0}"] , ["" , "mita {0}" , "" , "" , "" , "mitoci {0}"] , ["" , "sentimita {0}" , "" , "" , "" , "sentimitoci {0}"] , ["" , "milimita {0}" , "" , "" , "" , "milimitoci {0}"] , ["" , "mil {0}" , "" , "" , "" , "mil-mil {0}"] , ["" , "yadi {0}" , "" , "" , "" , "yaduka {0}"] , ["" , "ƙafa {0}" , "" , "" , "" , "ƙafafu {0}"] , ["" , "inci {0}" , "" , "" , "" , "incina {0}"] , ["" , "kilogiram {0}" , "" , "" , "" , "kilogiramgiram {0}"] , ["" , "giram {0}" , "" , "" , "" , "giram-giram {0}"] , ["" , "Laba {0}" , "" , "" , "" , "laba-laba {0}"] , ["" , "oza {0}" , "" , "" , "" , "oza-oza {0}"] , ["" , "kilomita {0} a sa′a" , "" , "" , "" , "kilomitoci {0} a sa′a"] , ["" , "mita a daƙiƙa {0}" , "" , "" , "" , "mitoci a daƙiƙa {0}"] , ["" , "mil {0} a sa′a" , "" , "" , "" , "mil-mil {0} a sa′a"] , ["" , "Digiri Selsiyas {0}" , "" , "" , "" , "digiri-digiri Selsiyas {0}"] , ["" , "Digiri Faranhit {0}" , "" , "" , "" , "digiri-digiri faranhit {0}"] , ["" , "kaso {0}" , "" , "" , "" , "Kaso {0}"] , ["" , "km {0}" , "" , "" , "" , "{0} km"] , ["" , "m {0}" , "" , "" , "" , "m {0}"] , ["" , "cm {0}" , "" , "" , "" , "cm {0}"] , ["" , "mm {0}" , "" , "" , "" , "mm {0}"] , ["" , "mi {0}" , "" , "" , "" , "mi {0}"] , ["" , "yd {0}" , "" , "" , "" , "yd {0}"] , ["" , "ƙf {0}" , "" , "" , "" , "ƙff {0}"] , ["" , "in {0}" , "" , "" , "" , "in {0}"] , ["" , "kg {0}" , "" , "" , "" , "kg {0}"] , ["" , "g {0}" , "" , "" , "" , "g {0}"] , ["" , "lb {0}" , "" , "" , "" , "lb {0}"] , ["" , "oz {0}" , "" , "" , "" , "oz {0}"] , ["" , "km/s {0}" , "" , "" , "" , "km/s {0}"] , ["" , "m/s {0}" , "" , "" , "" , "m/s {0}"] , ["" , "mas {0}" , "" , "" , "" , "mas {0}"] , ["" , "°S{0}" , "" , "" , "" , "°S{0}"] , ["" , "F°{0}" , "" , "" , "" , "F°{0}"] , ["" , "km{0}" , "" , "" , "" , "km{0}"] , ["" , "m{0}" , "" , "" , "" , "m{0}"] , ["" , "cm{0}" , "" , "" , "" , "cm{0}"] , ["" , "mm{0}" , "" , "" , "" , "mm{0}"] , ["" , "mi{0}" , "" , "" , "" , "mil-mil{0}"] , ["" , "yd{0}" , "" , "" , "" , "ydk{0}"] , ["" , "ƙf{0}" , "" , "" , "" , "ƙff{0}"] , ["" , "kg{0}" , "" , "" , "" , "kg{0}"] , ["" , "g{0}" , "" , "" , "" , "g{0}"] , ["" , "oz{0}" , "" , "" , "" , "oz{0}"] , ["" , "km/s{0}" , "" , "" , "" , "km/s{0}"] , ["" , "m/d{0}" , "" , "" , "" , "m/d{0}"] , ["" , "mas{0}" , "" , "" , "" , "mas{0}"] , ["" , "S°{0}" , "" , "" , "" , "S°{0}"] , ["" , "°{0}" , "" , "" , "" , "°{0}"] , ["" , "%{0}" , "" , "" , "" , "%{0}"] , ["" , "קילומטר {0}" , "{0} קילומטרים" , "" , "{0} קילומטרים" , "{0} קילומטרים"] , ["" , "מטר {0}" , "{0} מטרים" , "" , "{0} מטרים" , "{0} מטרים"] , ["" , "סנטימטר {0}" , "{0} סנטימטרים" , "" , "{0} סנטימטרים" , "{0} סנטימטרים"] , ["" , "מילימטר {0}" , "{0} מילימטרים" , "" , "{0} מילימטרים" , "{0} מילימטרים"] , ["" , "מייל {0}" , "{0} מייל" , "" , "{0} מייל" , "{0} מייל"] , ["" , "יארד {0}" , "{0} יארד" , "" , "{0} יארד" , "{0} יארד"] , ["" , "רגל {0}" , "{0} רגל" , "" , "{0} רגל" , "{0} רגל"] , ["" , "אינץ׳ {0}" , "{0} אינץ׳" , "" , "{0} אינץ׳" , "{0} אינץ׳"] , ["" , "קילוגרם {0}" , "{0} קילוגרם" , "" , "{0} קילוגרם" , "{0} קילוגרם"] , ["" , "גרם {0}" , "{0} גרם" , "" , "{0} גרם" , "{0} גרם"] , ["" , "פאונד אחד" , "שני פאונד" , "" , "{0} פאונד" , "{0} פאונד"] , ["" , "אונקיה {0}" , "{0} אונקיות" , "" , "{0} אונקיות" , "{0} אונקיות"] , ["" , "{0} קילומטר לשעה" , "{0} קילומטר לשעה" , "" , "{0} קילומטר לשעה" , "{0} קילומטר לשעה"] , ["" , "{0} מטר לשנייה" , "{0} מטר לשנייה" , "" , "{0} מטר לשנייה" , "{0} מטר לשנייה"] , ["" , "{0} מייל לשעה" , "{0} מייל לשעה" , "" , "{0} מייל לשעה" , "{0} מייל לשעה"] , ["" , "מעלת צלזיוס {0}" , "{0} מעלות צלזיוס" , "" , "{0} מעלות צלזיוס" , "{0} מעלות צלזיוס"] , ["" , "מעלת פרנהייט {0}" , "{0} מעלות פרנהייט" , "" , "{0} מעלות פרנהייט" , "{0} מעלות פרנהייט"] , ["" , "{0} אחוז" , "{0} אחוז" , "" , "{0} אחוז" , "{0} אחוז"] , ["" , "{0} ק״מ" , "{0} ק״מ" , "" , "{0} ק״מ" , "{0} ק״מ"] , ["" , "{0} מ׳" , "{0} מ׳" , "" , "{0} מ׳" , "{0} מ׳"] , ["" , "{0} ס״מ" , "{0} ס״מ" , "" , "{0} ס״מ" , "{0} ס״מ"] , ["" , "מ″מ {0}" , "{0} מ״מ" , "" , "{0} מ״מ" , "{0} מ״מ"] , ["" , "{0} מייל" , "{0} מייל" , "" , "{0} מייל" , "{0} מייל"] , ["" , "\u{200e}{0} ft" , "\u{200e}{0} ft" , "" , "\u{200e}{0} ft" , "\u{200e}{0} ft"] , ["" , "\u{200e}{0} in" , "\u{200e}{0} in" , "" , "\u{200e}{0} in" , "\u{200e}{0} in"] , ["" , "ק״ג {0}" , "{0} ק״ג" , "" , "{0} ק״ג" , "{0} ק״ג"] , ["" , "גר׳ {0}" , "{0} גר׳" , "" , "{0} גר׳" , "{0} גר׳"] , ["" , "{0} lb" , "{0} lbs" , "" , "{0} lbs" , "{0} lbs"] , ["" , "{0} oz" , "{0} oz" , "" , "{0} oz" , "{0} oz"] , ["" , "{0} קמ״ש" , "{0} קמ״ש" , "" , "{0} קמ״ש" , "{0} קמ״ש"] , ["" , "{0} מ׳/שנ׳" , "{0} מ׳/שנ׳" , "" , "{0} מ׳/שנ׳" , "{0} מ׳/שנ׳"] , ["" , "{0} mph" , "{0} mph" , "" , "{0} mph" , "{0} mph"] , ["" , "{0}°C" , "{0}°C" , "" , "{0}°C" , "{0}°C"] , ["" , "{0}°F" , "{0}°F" , "" , "{0}°F" , "{0}°F"] , ["" , "{0}%" , "{0}%" , "" , "{0}%" , "{0}%"] , ["" , "{0} מ׳" , "{0} מ′" , "" , "{0} מ׳" , "{0} מ׳"] , ["" , "ס\"מ {0" , "{0} ס״מ" , "" , "{0} ס״מ" , "{0} ס״מ"] , ["" , "מ״מ {0}" , "{0} מ״מ" , "" , "{0} מ״מ" , "{0} מ״מ"] , ["" , "{0}kg" , "{0}kg" , "" , "{0}kg" , "{0}kg"] , ["" , "{0}g" , "{0}g" , "" , "{0}g" , "{0}g"] , ["" , "{0} מ/ש" , "{0} מ/ש" , "" , "{0} מ/ש" , "{0} מ/ש"] , ["" , "{0} किलोमीटर" , "" , "" , "" , "{0} किलोमीटर"] , ["" , "{0} मीटर" , "" , "" , "" , "{0} मीटर"] , ["" , "{0} स\u{947}\u{902}टीमीटर" , "" , "" , "" , "{0} स\u{947}\u{902}टीमीटर"] , ["" , "{0} मिलीमीटर" , "" , "" , "" , "{0} मिलीमीटर"] , ["" , "{0} मील" , "" , "" , "" , "{0} मील"] , ["" , "{0} यार\u{94d}ड" , "" , "" , "" , "{0} यार\u{94d}ड"] , ["" , "{0} फ\u{93c}\u{941}ट" , "" , "" , "" , "{0} फ\u{93c}ीट"] , ["" , "{0} इ\u{902}च" , "" , "" , "" , "{0} इ\u{902}च"] , ["" , "{0} किलोग\u{94d}राम" , "" , "" , "" , "{0} किलोग\u{94d}राम"] , ["" , "{0} ग\u{94d}राम" , "" , "" , "" , "{0} ग\u{94d}राम"] , ["" , "{0} पौ\u{902}ड" , "" , "" , "" , "{0} पौ\u{902}ड"] , ["" , "{0} औ\u{902}स" , "" , "" , "" , "{0} औ\u{902}स"] , ["" , "{0} किलोमीटर प\u{94d}रति घ\u{902}टा" , "" , "" , "" , "{0} किलोमीटर प\u{94d}रति घ\u{902}टा"] , ["" , "{0} मीटर प\u{94d}रति स\u{947}क\u{902}ड" , "" , "" , "" , "{0} मीटर प\u{94d}रति स\u{947}क\u{902}ड"] , ["" , "{0} मील प\u{94d}रति घ\u{902}टा" , "" , "" , "" , "{0} मील प\u{94d}रति घ\u{902}टा"] , ["" , "{0} डिग\u{94d}री स\u{947}ल\u{94d}सियस" , "" , "" , "" , "{0} डिग\u{94d}री स\u{947}ल\u{94d}सियस"] , ["" , "{0} डिग\u{94d}री फ\u{93c}\u{947}रनहाइट" , "" , "" , "" , "{0} डिग\u{94d}री फ\u{93c}\u{947}रनहाइट"] , ["" , "{0} प\u{94d}रतिशत" , "" , "" , "" , "{0} प\u{94d}रतिशत"] , ["" , "{0} कि॰मी॰" , "" , "" , "" , "{0} कि॰मी॰"] , ["" , "{0} मी" , "" , "" , "" , "{0} मी"] , ["" , "{0} स\u{947}\u{902}॰मी॰" , "" , "" , "" , "{0} स\u{947}\u{902}॰मी॰"] , ["" , "{0} मि॰मी॰" , "" , "" , "" , "{0} मि॰मी॰"] , ["" , "{0} फ\u{93c}ीट" , "" , "" , "" , "{0} फ\u{93c}ीट"] , ["" , "{0} कि॰ग\u{94d}रा॰" , "" , "" , "" , "{0} कि॰ग\u{94d}रा॰"] , ["" , "{0} ग\u{94d}रा॰" , "" , "" , "" , "{0} ग\u{94d}रा॰"] , ["" , "{0} कि॰मी॰/घ\u{902}॰" , "" , "" , "" , "{0} कि॰मी॰/घ\u{902}॰"] , ["" , "{0} मी॰/स\u{947}॰" , "" , "" , "" , "{0} मी॰/स\u{947}॰"] , ["" , "{0} मी॰प\u{94d}र॰घ\u{902}॰" , "" , "" , "" , "{0} मी॰प\u{94d}र॰घ\u{902}॰"] , ["" , "{0}°स\u{947}॰" , "" , "" , "" , "{0}°स\u{947}॰"] , ["" , "{0}°फ\u{93c}\u{947}रन" , "" , "" , "" , "{0}°फ\u{93c}\u{947}रन"] , ["" , "{0} किमी" , "" , "" , "" , "{0} किमी"] , ["" , "{0} मिमी" , "" , "" , "" , "{0} मिमी"] , ["" , "{0} किग\u{94d}रा" , "" , "" , "" , "{0} किग\u{94d}रा"] , ["" , "{0} औ\u{902}॰" , "" , "" , "" , "{0} औ\u{902}॰"] , ["" , "{0} किमी/घ\u{902}" , "" , "" , "" , "{0} किमी/घ\u{902}"] , ["" , "{0} मी/स\u{947}" , "" , "" , "" , "{0}मी॰/स\u{947}॰"] , ["" , "{0} मीप\u{94d}रघ\u{902}" , "" , "" , "" , "{0} मीप\u{94d}रघ\u{902}"] , ["" , "{0} Celzijev stupanj" , "" , "{0} Celzijeva stupnja" , "" , "{0} Celzijevih stupnjeva"] , ["" , "{0} Fahrenheitov stupanj" , "" , "{0} Fahrenheitova stupnja" , "" , "{0} Fahrenheitovih stupnjeva"] , ["" , "{0} posto" , "" , "{0} posto" , "" , "{0} posto"] , ["" , "{0} °C" , "" , "{0} °C" , "" , "{0} °C"] , ["" , "{0} °F" , "" , "{0} °F" , "" , "{0} °F"] , ["" , "{0} %" , "" , "{0} %" , "" , "{0} %"] , ["" , "{0}′" , "" , "{0}′" , "" , "{0}′"] , ["" , "{0}″" , "" , "{0}″" , "" , "{0}″"] , ["" , "{0} kilometer" , "{0} kilometraj" , "{0} kilometry" , "" , "{0} kilometrow"] , ["" , "{0} meter" , "{0} metraj" , "{0} metry" , "" , "{0} metrow"] , ["" , "{0} centimeter" , "{0} centimetraj" , "{0} centimetry" , "" , "{0} centimetrow"] , ["" , "{0} milimeter" , "{0} milimetraj" , "{0} milimetry" , "" , "{0} milimetrow"] , ["" , "{0} yard" , "{0} yardaj" , "{0} yardy" , "" , "{0} yardow"] , ["" , "{0} stopa" , "{0} stopje" , "{0} stopy" , "" , "{0} stopow"] , ["" , "{0} cól" , "{0} cólej" , "{0} cóle" , "" , "{0} cólow"] , ["" , "{0} kilogram" , "{0} kilogramaj" , "{0} kilogramy" , "" , "{0} kilogramow"] , ["" , "{0} gram" , "{0} gramaj" , "{0} gramy" , "" , "{0} gramow"] , ["" , "{0} punt" , "{0} puntaj" , "{0} punty" , "" , "{0} puntow"] , ["" , "{0} unca" , "{0} uncy" , "{0} uncy" , "" , "{0} uncow"] , ["" , "{0} kilometer na hodźinu" , "{0} kilometraj na hodźinu" , "{0} kilometry na hodźinu" , "" , "{0} kilometrow na hodźinu"] , ["" , "{0} meter na sekundu" , "{0} metraj na sekundu" , "{0} metry na sekundu" , "" , "{0} metrow na sekundu"] , ["" , "{0} mila na hodźinu" , "{0} mili na hodźinu" , "{0} mile na hodźinu" , "" , "{0} milow na hodźinu"] , ["" , "{0} stopjeń Celsiusa" , "{0} stopnjej Celsiusa" , "{0} stopnje Celsiusa" , "" , "{0} stopnjow Celsiusa"] , ["" , "{0} stopjeń Fahrenheita" , "{0} stopnjej Fahrenheita" , "{0} stopnje Fahrenheita" , "" , "{0} stopnjow Fahrenheita"] , ["" , "{0} procent" , "{0} procentaj" , "{0} procenty" , "" , "{0} procentow"] , ["" , "{0} kilométer" , "" , "" , "" , "{0} kilométer"] , ["" , "{0} méter" , "" , "" , "" , "{0} méter"] , ["" , "{0} centiméter" , "" , "" , "" , "{0} centiméter"] , ["" , "{0} milliméter" , "" , "" , "" , "{0} milliméter"] , ["" , "{0} mérföld" , "" , "" , "" , "{0} mérföld"] , ["" , "{0} láb" , "" , "" , "" , "{0} láb"] , ["" , "{0} hüvelyk" , "" , "" , "" , "{0} hüvelyk"] , ["" , "{0} font" , "" , "" , "" , "{0} font"] , ["" , "{0} uncia" , "" , "" , "" , "{0} uncia"] , ["" , "{0} kilométer per óra" , "" , "" , "" , "{0} kilométer per óra"] , ["" , "{0} méter per másodperc" , "" , "" , "" , "{0} méter per másodperc"] , ["" , "{0} mérföld per óra" , "" , "" , "" , "{0} mérföld per óra"] , ["" , "{0} Celsius-fok" , "" , "" , "" , "{0} Celsius-fok"] , ["" , "{0} Fahrenheit-fok" , "" , "" , "" , "{0} Fahrenheit-fok"] , ["" , "{0} százalék" , "" , "" , "" , "{0} százalék"] , ["" , "{0} mf" , "" , "" , "" , "{0} mf"] , ["" , "{0} lb" , "" , "" , "" , "{0} font"] , ["" , "{0} oz" , "" , "" , "" , "{0} uncia"] , ["" , "{0} կիլոմետր" , "" , "" , "" , "{0} կիլոմետր"] , ["" , "{0} մետր" , "" , "" , "" , "{0} մետր"] , ["" , "{0} սանտիմետր" , "" , "" , "" , "{0} սանտիմետր"] , ["" , "{0} միլիմետր" , "" , "" , "" , "{0} միլիմետր"] , ["" , "{0} մղոն" , "" , "" , "" , "{0} մղոն"] , ["" , "{0} յարդ" , "" , "" , "" , "{0} յարդ"] , ["" , "{0} ֆուտ" , "" , "" , "" , "{0} ֆուտ"] , ["" , "{0} մատնաչափ" , "" , "" , "" , "{0} մատնաչափ"] , ["" , "{0} կիլոգրամ" , "" , "" , "" , "{0} կիլոգրամ"] , ["" , "{0} գրամ" , "" , "" , "" , "{0} գրամ"] , ["" , "{0} ֆունտ" , "" , "" , "" , "{0} ֆունտ"] , ["" , "{0} ունկի" , "" , "" , "" , "{0} ունկի"] , ["" , "{0} կիլոմետր ժամում" , "" , "" , "" , "{0} կիլոմետր ժամում"] , ["" , "{0} մետր վայրկյանում" , "" , "" , "" , "{0} մետր վայրկյանում"] , ["" , "{0} մղոն ժամում" , "" , "" , "" , "{0} մղոն ժամում"] , ["" , "{0} աստիճան ըստ Ցելսիուսի" , "" , "" , "" , "{0} աստիճան ըստ Ցելսիուսի"] , ["" , "{0} աստիճան ըստ Ֆարենհայթի" , "" , "" , "" , "{0} աստիճան ըստ Ֆարենհայթի"] , ["" , "{0} տոկոս" , "" , "" , "" , "{0} տոկոս"] , ["" , "{0} կմ" , "" , "" , "" , "{0} կմ"] , ["" , "{0} մ" , "" , "" , "" , "{0} մ"] , ["" , "{0} սմ" , "" , "" , "" , "{0} սմ"] , ["" , "{0} մմ" , "" , "" , "" , "{0} մմ"] , ["" , "{0} մղ" , "" , "" , "" , "{0} մղ"] , ["" , "{0} յրդ" , "" , "" , "" , "{0} յրդ"] , ["" , "{0} ֆտ" , "" , "" , "" , "{0} ֆտ"] , ["" , "{0} մատ" , "" , "" , "" , "{0} մատ"] , ["" , "{0} կգ" , "" , "" , "" , "{0} կգ"] , ["" , "{0} գ" , "" , "" , "" , "{0} գ"] , ["" , "{0} ունկ." , "" , "" , "" , "{0} ունկ."] , ["" , "{0} կմ/ժ" , "" , "" , "" , "{0} կմ/ժ"] , ["" , "{0} մ/վ" , "" , "" , "" , "{0} մ/վ"] , ["" , "{0} մղ/ժ" , "" , "" , "" , "{0} մղ/ժ"] , ["" , "{0} յդ." , "" , "" , "" , "{0} յդ."] , ["" , "{0} ունց" , "" , "" , "" , "{0} ունց"] , ["" , "{0}մ/վ" , "" , "" , "" , "{0}մ/վ"] , ["" , "{0}մղ/ժ" , "" , "" , "" , "{0}մղ/ժ"] , ["" , "{0} kilometro" , "" , "" , "" , "{0} kilometros"] , ["" , "{0} centimetro" , "" , "" , "" , "{0} centimetros"] , ["" , "{0} millimetro" , "" , "" , "" , "{0} millimetros"] , ["" , "{0} millia" , "" , "" , "" , "{0} millias"] , ["" , "{0} pede" , "" , "" , "" , "{0} pedes"] , ["" , "{0} pollice" , "" , "" , "" , "{0} pollices"] , ["" , "{0} kilogramma" , "" , "" , "" , "{0} kilogrammas"] , ["" , "{0} gramma" , "" , "" , "" , "{0} grammas"] , ["" , "{0} uncia" , "" , "" , "" , "{0} uncias"] , ["" , "{0} kilometro per hora" , "" , "" , "" , "{0} kilometros per hora"] , ["" , "{0} metro per secunda" , "" , "" , "" , "{0} metros per secunda"] , ["" , "{0} millia per hora" , "" , "" , "" , "{0} millias per hora"] , ["" , "{0} grado Celcius" , "" , "" , "" , "{0} grados Celcius"] , ["" , "{0} per cento" , "" , "" , "" , "{0} per cento"] , ["" , "" , "" , "" , "" , "{0} kilometer"] , ["" , "" , "" , "" , "" , "{0} meter"] , ["" , "" , "" , "" , "" , "{0} sentimeter"] , ["" , "" , "" , "" , "" , "{0} milimeter"] , ["" , "" , "" , "" , "" , "{0} mil"] , ["" , "" , "" , "" , "" , "{0} yard"] , ["" , "" , "" , "" , "" , "{0} kaki"] , ["" , "" , "" , "" , "" , "{0} inci"] , ["" , "" , "" , "" , "" , "{0} kilogram"] , ["" , "" , "" , "" , "" , "{0} gram"] , ["" , "" , "" , "" , "" , "{0} pound"] , ["" , "" , "" , "" , "" , "{0} ounce"] , ["" , "" , "" , "" , "" , "{0} kilometer per jam"] , ["" , "" , "" , "" , "" , "{0} meter per detik"] , ["" , "" , "" , "" , "" , "{0} mil per jam"] , ["" , "" , "" , "" , "" , "{0} derajat Celsius"] , ["" , "" , "" , "" , "" , "{0} derajat Fahrenheit"] , ["" , "" , "" , "" , "" , "{0} persen"] , ["" , "" , "" , "" , "" , "{0} km/j"] , ["" , "" , "" , "" , "" , "{0} m/dtk"] , ["" , "" , "" , "" , "" , "{0} mpj"] , ["" , "" , "" , "" , "" , "{0}′"] , ["" , "" , "" , "" , "" , "{0}″"] , ["" , "" , "" , "" , "" , "{0}#"] , ["" , "" , "" , "" , "" , "{0}km/j"] , ["" , "" , "" , "" , "" , "{0}°"] , ["" , "{0} kílómetri" , "" , "" , "" , "{0} kílómetrar"] , ["" , "{0} metri" , "" , "" , "" , "{0} metrar"] , ["" , "{0} sentimetri" , "" , "" , "" , "{0} sentimetrar"] , ["" , "{0} millimetri" , "" , "" , "" , "{0} millimetrar"] , ["" , "{0} míla" , "" , "" , "" , "{0} mílur"] , ["" , "{0} yard" , "" , "" , "" , "{0} yardar"] , ["" , "{0} fet" , "" , "" , "" , "{0} fet"] , ["" , "{0} tomma" , "" , "" , "" , "{0} tommur"] , ["" , "{0} kílógramm" , "" , "" , "" , "{0} kílógrömm"] , ["" , "{0} gramm" , "" , "" , "" , "{0} grömm"] , ["" , "{0} únsa" , "" , "" , "" , "{0} únsur"] , ["" , "{0} kílómetri á klukkustund" , "" , "" , "" , "{0} kílómetrar á klukkustund"] , ["" , "{0} metri á sekúndu" , "" , "" , "" , "{0} metrar á sekúndu"] , ["" , "{0} míla á klukkustund" , "" , "" , "" , "{0} mílur á klukkustund"] , ["" , "{0} gráða á Celsíus" , "" , "" , "" , "{0} gráður á Celsíus"] , ["" , "{0} gráða á Fahrenheit" , "" , "" , "" , "{0} gráður á Fahrenheit"] , ["" , "{0} prósent" , "" , "" , "" , "{0} prósent"] , ["" , "{0} mí" , "" , "" , "" , "{0} mí"] , ["" , "{0} t." , "" , "" , "" , "{0} t."] , ["" , "{0} km/klst." , "" , "" , "" , "{0} km/klst."] , ["" , "{0} míla/klst." , "" , "" , "" , "{0} mílur/klst."] , ["" , "{0}%" , "" , "" , "" , "{0} %"] , ["" , "{0} p." , "" , "" , "" , "{0} p."] , ["" , "{0} míla/klst." , "" , "" , "" , "{0} míl./klst."] , ["" , "{0} chilometro" , "" , "" , "" , "{0} chilometri"] , ["" , "{0} metro" , "" , "" , "" , "{0} metri"] , ["" , "{0} centimetro" , "" , "" , "" , "{0} centimetri"] , ["" , "{0} millimetro" , "" , "" , "" , "{0} millimetri"] , ["" , "{0} miglio" , "" , "" , "" , "{0} miglia"] , ["" , "{0} iarda" , "" , "" , "" , "{0} iarde"] , ["" , "{0} piede" , "" , "" , "" , "{0} piedi"] , ["" , "{0} pollice" , "" , "" , "" , "{0} pollici"] , ["" , "{0} chilogrammo" , "" , "" , "" , "{0} chilogrammi"] , ["" , "{0} grammo" , "" , "" , "" , "{0} grammi"] , ["" , "{0} libbra" , "" , "" , "" , "{0} libbre"] , ["" , "{0} oncia" , "" , "" , "" , "{0} once"] , ["" , "{0} chilometro orario" , "" , "" , "" , "{0} chilometri orari"] , ["" , "{0} metro al secondo" , "" , "" , "" , "{0} metri al secondo"] , ["" , "{0} miglio all’ora" , "" , "" , "" , "{0} miglia all’ora"] , ["" , "{0} grado Celsius" , "" , "" , "" , "{0} gradi Celsius"] , ["" , "{0} grado Fahrenheit" , "" , "" , "" , "{0} gradi Fahrenheit"] , ["" , "{0} percento" , "" , "" , "" , "{0} percento"] , ["" , "" , "" , "" , "" , "{0} キロメートル"] , ["" , "" , "" , "" , "" , "{0} メートル"] , ["" , "" , "" , "" , "" , "{0} センチメートル"] , ["" , "" , "" , "" , "" , "{0} ミリメートル"] , ["" , "" , "" , "" , "" , "{0} マイル"] , ["" , "" , "" , "" , "" , "{0} ヤード"] , ["" , "" , "" , "" , "" , "{0} フィート"] , ["" , "" , "" , "" , "" , "{0} インチ"] , ["" , "" , "" , "" , "" , "{0} キログラム"] , ["" , "" , "" , "" , "" , "{0} グラム"] , ["" , "" , "" , "" , "" , "{0} ポンド"] , ["" , "" , "" , "" , "" , "{0} オンス"] , ["" , "" , "" , "" , "" , "時速 {0} キロメートル"] , ["" , "" , "" , "" , "" , "秒速 {0} メートル"] , ["" , "" , "" , "" , "" , "時速 {0} マイル"] , ["" , "" , "" , "" , "" , "摂氏 {0} 度"] , ["" , "" , "" , "" , "" , "華氏 {0} 度"] , ["" , "" , "" , "" , "" , "{0} パーセント"] , ["" , "" , "" , "" , "" , "{0} mph"] , ["" , "" , "" , "" , "" , "{0}km"] , ["" , "" , "" , "" , "" , "{0}m"] , ["" , "" , "" , "" , "" , "{0}cm"] , ["" , "" , "" , "" , "" , "{0}mm"] , ["" , "" , "" , "" , "" , "{0}mi"] , ["" , "" , "" , "" , "" , "{0}yd"] , ["" , "" , "" , "" , "" , "{0}kg"] , ["" , "" , "" , "" , "" , "{0}g"] , ["" , "" , "" , "" , "" , "{0}lb"] , ["" , "" , "" , "" , "" , "{0}oz"] , ["" , "" , "" , "" , "" , "{0}km/h"] , ["" , "" , "" , "" , "" , "{0}m/s"] , ["" , "" , "" , "" , "" , "{0}mi/h"] , ["" , "" , "" , "" , "" , "{0} pon"] , ["" , "" , "" , "" , "" , "{0} ons"] , ["" , "" , "" , "" , "" , "{0} kilometer saben jam"] , ["" , "" , "" , "" , "" , "{0} meter saben detik"] , ["" , "" , "" , "" , "" , "{0} mil saben jam"] , ["" , "" , "" , "" , "" , "{0} derajat celsius"] , ["" , "" , "" , "" , "" , "{0} derajat Fahrenhet"] , ["" , "" , "" , "" , "" , "{0} km/jam"] , ["" , "" , "" , "" , "" , "{0} mil/jam"] , ["" , "{0} კილომეტრი" , "" , "" , "" , "{0} კილომეტრი"] , ["" , "{0} მეტრი" , "" , "" , "" , "{0} მეტრი"] , ["" , "{0} სანტიმეტრი" , "" , "" , "" , "{0} სანტიმეტრი"] , ["" , "{0} მილიმეტრი" , "" , "" , "" , "{0} მილიმეტრი"] , ["" , "{0} მილი" , "" , "" , "" , "{0} მილი"] , ["" , "{0} იარდი" , "" , "" , "" , "{0} იარდი"] , ["" , "{0} ფუტი" , "" , "" , "" , "{0} ფუტი"] , ["" , "{0} დუიმი" , "" , "" , "" , "{0} დუიმი"] , ["" , "{0} კილოგრამი" , "" , "" , "" , "{0} კილოგრამი"] , ["" , "{0} გრამი" , "" , "" , "" , "{0} გრამი"] , ["" , "{0} ფუნტი" , "" , "" , "" , "{0} ფუნტი"] , ["" , "{0} უნცია" , "" , "" , "" , "{0} უნცია"] , ["" , "{0} კილომეტრი საათში" , "" , "" , "" , "{0} კილომეტრი საათში"] , ["" , "{0} მეტრი წამში" , "" , "" , "" , "{0} მეტრი წამში"] , ["" , "{0} მილი საათში" , "" , "" , "" , "{0} მილი საათში"] , ["" , "{0} გრადუსი ცელსიუსით" , "" , "" , "" , "{0} გრადუსი ცელსიუსით"] , ["" , "{0} გრადუსი ფარენჰეიტით" , "" , "" , "" , "{0} გრადუსი ფარენჰეიტით"] , ["" , "{0} პროცენტი" , "" , "" , "" , "{0} პროცენტი"] , ["" , "{0} კმ" , "" , "" , "" , "{0} კმ"] , ["" , "{0} მ" , "" , "" , "" , "{0} მ"] , ["" , "{0} სმ" , "" , "" , "" , "{0} სმ"] , ["" , "{0} მმ" , "" , "" , "" , "{0} მმ"] , ["" , "{0} ფტ" , "" , "" , "" , "{0} ფტ"] , ["" , "{0} კგ" , "" , "" , "" , "{0} კგ"] , ["" , "{0} გ" , "" , "" , "" , "{0} გ"] , ["" , "{0} ფნტ" , "" , "" , "" , "{0} ფნტ"] , ["" , "{0} უნც" , "" , "" , "" , "{0} უნც"] , ["" , "{0} კმ/სთ" , "" , "" , "" , "{0} კმ/სთ"] , ["" , "{0} მ/წმ" , "" , "" , "" , "{0} მ/წმ"] , ["" , "{0} მილი/სთ" , "" , "" , "" , "{0} მილი/სთ"] , ["" , "{0}მ" , "" , "" , "" , "{0}მ"] , ["" , "{0} დმ" , "" , "" , "" , "{0} დმ"] , ["" , "{0}კგ" , "" , "" , "" , "{0}კგ"] , ["" , "{0}გ" , "" , "" , "" , "{0}გ"] , ["" , "" , "" , "" , "" , "{0} kilómetru"] , ["" , "" , "" , "" , "" , "{0} metru"] , ["" , "" , "" , "" , "" , "{0} sentímetru"] , ["" , "" , "" , "" , "" , "{0} milímetru"] , ["" , "" , "" , "" , "" , "{0} milha"] , ["" , "" , "" , "" , "" , "{0} jarda"] , ["" , "" , "" , "" , "" , "{0} pe"] , ["" , "" , "" , "" , "" , "{0} pulegada"] , ["" , "" , "" , "" , "" , "{0} kilograma"] , ["" , "" , "" , "" , "" , "{0} grama"] , ["" , "" , "" , "" , "" , "{0} libra"] , ["" , "" , "" , "" , "" , "{0} ónsa"] , ["" , "" , "" , "" , "" , "{0} kilómetru pur ora"] , ["" , "" , "" , "" , "" , "{0} metru pur sigundu"] , ["" , "" , "" , "" , "" , "{0} milha pur ora"] , ["" , "" , "" , "" , "" , "{0} grau Celsius"] , ["" , "" , "" , "" , "" , "{0} grau Fahrenheit"] , ["" , "" , "" , "" , "" , "{0} pursentu"] , ["" , "" , "" , "" , "" , "{0} °C"] , ["" , "" , "" , "" , "" , "{0} °F"] , ["" , "{0} метр" , "" , "" , "" , "{0} метр"] , ["" , "{0} миллиметр" , "" , "" , "" , "{0} миллиметр"] , ["" , "{0} миля" , "" , "" , "" , "{0} миля"] , ["" , "{0} ярд" , "" , "" , "" , "{0} ярд"] , ["" , "{0} килограмм" , "" , "" , "" , "{0} килограмм"] , ["" , "{0} грамм" , "" , "" , "" , "{0} грамм"] , ["" , "{0} фунт" , "" , "" , "" , "{0} фунт"] , ["" , "{0} унция" , "" , "" , "" , "{0} унция"] , ["" , "{0} километр/сағат" , "" , "" , "" , "{0} километр/сағат"] , ["" , "{0} метр/секунд" , "" , "" , "" , "{0} метр/секунд"] , ["" , "{0} миля/сағат" , "" , "" , "" , "{0} миля/сағат"] , ["" , "{0} Цельсий градусы" , "" , "" , "" , "{0} Цельсий градусы"] , ["" , "{0} Фаренгейт градусы" , "" , "" , "" , "{0} Фаренгейт градусы"] , ["" , "{0} пайыз" , "" , "" , "" , "{0} пайыз"] , ["" , "{0} м" , "" , "" , "" , "{0} м"] , ["" , "{0} мм" , "" , "" , "" , "{0} мм"] , ["" , "{0} кг" , "" , "" , "" , "{0} кг"] , ["" , "{0} г" , "" , "" , "" , "{0} г"] , ["" , "{0} км/сағ" , "" , "" , "" , "{0} км/сағ"] , ["" , "{0} м/с" , "" , "" , "" , "{0} м/с"] , ["" , "{0} миля/сағ" , "" , "" , "" , "{0} миля/сағ"] , ["" , "" , "" , "" , "" , "{0} គ\u{17b8}ឡ\u{17bc}ម\u{17c9}ែត\u{17d2}រ"] , ["" , "" , "" , "" , "" , "{0} ម\u{17c9}ែត\u{17d2}រ"] , ["" , "" , "" , "" , "" , "{0} សង\u{17cb}ទ\u{17b8}ម\u{17c9}ែត\u{17d2}រ"] , ["" , "" , "" , "" , "" , "{0} ម\u{17b7}ល\u{17d2}ល\u{17b8}ម\u{17c9}ែត\u{17d2}រ"] , ["" , "" , "" , "" , "" , "{0} ម\u{17c9}ាយ"] , ["" , "" , "" , "" , "" , "{0} យ\u{17c9}ាត"] , ["" , "" , "" , "" , "" , "{0} ហ\u{17d2}វ\u{17b8}ត"] , ["" , "" , "" , "" , "" , "{0} អ\u{17ca}\u{17b8}ញ"] , ["" , "" , "" , "" , "" , "{0} គ\u{17b8}ឡ\u{17bc}ក\u{17d2}រាម"] , ["" , "" , "" , "" , "" , "{0} ក\u{17d2}រាម"] , ["" , "" , "" , "" , "" , "{0} ផោន"] , ["" , "" , "" , "" , "" , "{0} អោន"] , ["" , "" , "" , "" , "" , "{0} គ\u{17b8}ឡ\u{17bc}ម\u{17c9}ែត\u{17d2}រ\u{200b}ក\u{17d2}ន\u{17bb}ង\u{200b}ម\u{17bd}យ\u{200b}ម\u{17c9}ោង"] , ["" , "" , "" , "" , "" , "{0} ម\u{17c9}ែត\u{17d2}រ\u{200b}ក\u{17d2}ន\u{17bb}ង\u{200b}ម\u{17bd}យ\u{200b}វ\u{17b7}នាទ\u{17b8}"] , ["" , "" , "" , "" , "" , "{0} ម\u{17c9}ាយក\u{17d2}ន\u{17bb}ងម\u{17bd}យម\u{17c9}ោង"] , ["" , "" , "" , "" , "" , "{0} អង\u{17d2}សាសេ"] , ["" , "" , "" , "" , "" , "{0}\u{a0}អង\u{17d2}សា\u{200b}ហ\u{17d2}វារ\u{17b7}នហៃ"] , ["" , "" , "" , "" , "" , "{0} ភាគរយ"] , ["" , "" , "" , "" , "" , "{0} គម"] , ["" , "" , "" , "" , "" , "{0} ម"] , ["" , "" , "" , "" , "" , "{0} សម"] , ["" , "" , "" , "" , "" , "{0} kph"] , ["" , "" , "" , "" , "" , "{0} មម"] , ["" , "" , "" , "" , "" , "{0} ម\u{17c9}."] , ["" , "" , "" , "" , "" , "{0} យ\u{17c9}."] , ["" , "" , "" , "" , "" , "{0} អ."] , ["" , "" , "" , "" , "" , "{0} ម./វ\u{17b7}."] , ["" , "" , "" , "" , "" , "{0} ម\u{17c9}./ម\u{17c9}"] , ["" , "{0} ಕ\u{cbf}ಲ\u{ccb}ಮ\u{cc0}ಟರ\u{ccd}" , "" , "" , "" , "{0} ಕ\u{cbf}ಲ\u{ccb}ಮ\u{cc0}ಟರ\u{ccd}\u{200c}ಗಳು"] , ["" , "{0} ಮ\u{cc0}ಟರ\u{ccd}" , "" , "" , "" , "{0} ಮ\u{cc0}ಟರ\u{ccd}\u{200c}ಗಳು"] , ["" , "{0} ಸ\u{cc6}ಂಟ\u{cbf}ಮ\u{cc0}ಟರ\u{ccd}" , "" , "" , "" , "{0} ಸ\u{cc6}ಂಟ\u{cbf}ಮ\u{cc0}ಟರ\u{ccd}\u{200c}ಗಳು"] , ["" , "{0} ಮ\u{cbf}ಲ\u{cbf}ಮ\u{cc0}ಟರ\u{ccd}" , "" , "" , "" , "{0} ಮ\u{cbf}ಲ\u{cbf}ಮ\u{cc0}ಟರ\u{ccd}\u{200c}ಗಳು"] , ["" , "{0} ಮ\u{cc8}ಲು" , "" , "" , "" , "{0} ಮ\u{cc8}ಲುಗಳು"] , ["" , "{0} ಗಜ" , "" , "" , "" , "{0} ಗಜಗಳು"] , ["" , "{0} ಅಡ\u{cbf}" , "" , "" , "" , "{0} ಅಡ\u{cbf}"] , ["" , "{0} ಅಂಗುಲ" , "" , "" , "" , "{0} ಅಂಗುಲಗಳು"] , ["" , "{0} ಕ\u{cbf}ಲ\u{ccb}ಗ\u{ccd}ರಾಂ" , "" , "" , "" , "{0} ಕ\u{cbf}ಲ\u{ccb}ಗ\u{ccd}ರಾಂಗಳು"] , ["" , "{0} ಗ\u{ccd}ರಾಂ" , "" , "" , "" , "{0} ಗ\u{ccd}ರಾಂಗಳು"] , ["" , "{0} ಪ\u{ccc}ಂಡ\u{ccd}" , "" , "" , "" , "{0} ಪ\u{ccc}ಂಡ\u{ccd}\u{200c}ಗಳು"] , ["" , "{0} ಔನ\u{ccd}ಸ\u{ccd}" , "" , "" , "" , "{0} ಔನ\u{ccd}ಸ\u{ccd}\u{200c}ಗಳು"] , ["" , "{0} ಕ\u{cbf}ಲ\u{ccb}ಮ\u{cc0}ಟರ\u{ccd} ಪ\u{ccd}ರತ\u{cbf} ಗಂಟ\u{cc6}ಗ\u{cc6}" , "" , "" , "" , "{0} ಕ\u{cbf}ಲ\u{ccb}ಮ\u{cc0}ಟರ\u{ccd}\u{200c}ಗಳು ಪ\u{ccd}ರತ\u{cbf} ಗಂಟ\u{cc6}ಗ\u{cc6}"] , ["" , "{0} ಮ\u{cc0}ಟರ\u{ccd}\u{200c} ಪ\u{ccd}ರತ\u{cbf} ಸ\u{cc6}ಕ\u{cc6}ಂಡ\u{ccd}\u{200c}ಗ\u{cc6}" , "" , "" , "" , "{0} ಮ\u{cc0}ಟರ\u{ccd}\u{200c}ಗಳು ಪ\u{ccd}ರತ\u{cbf} ಸ\u{cc6}ಕ\u{cc6}ಂಡ\u{ccd}\u{200c}ಗ\u{cc6}"] , ["" , "{0} ಮ\u{cc8}ಲು ಪ\u{ccd}ರತ\u{cbf} ಗಂಟ\u{cc6}ಗ\u{cc6}" , "" , "" , "" , "{0} ಗಂಟ\u{cc6}ಗ\u{cc6} ಮ\u{cc8}ಲುಗಳು"] , ["" , "{0} ಡ\u{cbf}ಗ\u{ccd}ರ\u{cbf} ಸ\u{cc6}ಲ\u{ccd}ಶ\u{cbf}ಯಸ\u{ccd}" , "" , "" , "" , "{0} ಡ\u{cbf}ಗ\u{ccd}ರ\u{cbf} ಸ\u{cc6}ಲ\u{ccd}ಶ\u{cbf}ಯಸ\u{ccd}"] , ["" , "{0}ಡ\u{cbf}ಗ\u{ccd}ರ\u{cbf} ಫ\u{ccd}ಯಾರ\u{cc6}ನ\u{ccd}\u{200c}ಹ\u{cbf}ಟ\u{ccd}" , "" , "" , "" , "{0}ಡ\u{cbf}ಗ\u{ccd}ರ\u{cbf} ಫ\u{ccd}ಯಾರ\u{cc6}ನ\u{ccd}\u{200c}ಹ\u{cbf}ಟ\u{ccd}"] , ["" , "{0} ಶ\u{cc7}ಕಡಾವು" , "" , "" , "" , "{0} ಶ\u{cc7}ಕಡಾ"] , ["" , "{0} ಕ\u{cbf}ಮ\u{cc0}" , "" , "" , "" , "{0} ಕ\u{cbf}ಮ\u{cc0}"] , ["" , "{0} ಮ\u{cc0}" , "" , "" , "" , "{0} ಮ\u{cc0}"] , ["" , "{0} ಸ\u{cc6}ಮ\u{cc0}" , "" , "" , "" , "{0} ಸ\u{cc6}ಮ\u{cc0}"] , ["" , "{0} ಮ\u{cbf}ಮ\u{cc0}" , "" , "" , "" , "{0} ಮ\u{cbf}ಮ\u{cc0}"] , ["" , "{0} ಮ\u{cc8}." , "" , "" , "" , "{0} ಮ\u{cc8}."] , ["" , "{0} ಗ" , "" , "" , "" , "{0} ಗ"] , ["" , "{0} ಅ" , "" , "" , "" , "{0} ಅ"] , ["" , "{0} ಅಂ" , "" , "" , "" , "{0} ಅಂ"] , ["" , "{0} ಕ\u{cc6}ಜ\u{cbf}" , "" , "" , "" , "{0} ಕ\u{cc6}ಜ\u{cbf}"] , ["" , "{0} ಗ\u{ccd}ರಾ" , "" , "" , "" , "{0} ಗ\u{ccd}ರಾ"] , ["" , "{0} ಪ\u{ccc}ಂ" , "" , "" , "" , "{0} ಪ\u{ccc}ಂ"] , ["" , "{0} ಔ" , "" , "" , "" , "{0} ಔ"] , ["" , "{0} ಕ\u{cbf}.ಮ\u{cc0}./ಗಂ" , "" , "" , "" , "{0} ಕ\u{cbf}.ಮ\u{cc0}./ಗಂ"] , ["" , "{0} ಮ\u{cc0}/ಸ\u{cc6}." , "" , "" , "" , "{0} ಮ\u{cc0}/ಸ\u{cc6}."] , ["" , "{0} ಮ\u{cc8}/ಗಂ" , "" , "" , "" , "{0} ಮ\u{cc8}/ಗಂ"] , ["" , "{0}°ಸ\u{cc6}" , "" , "" , "" , "{0}°ಸ\u{cc6}"] , ["" , "{0}°ಫ\u{ccd}ಯಾ" , "" , "" , "" , "{0}°ಫ\u{ccd}ಯಾ"] , ["" , "{0}ಕ\u{cbf}ಮ\u{cc0}" , "" , "" , "" , "{0}ಕ\u{cbf}ಮ\u{cc0}"] , ["" , "{0}ಮ\u{cc0}" , "" , "" , "" , "{0}ಮ\u{cc0}"] , ["" , "{0}ಮ\u{cbf}ಮ\u{cc0}" , "" , "" , "" , "{0}ಮ\u{cbf}ಮ\u{cc0}"] , ["" , "{0}ಮ\u{cc8}." , "" , "" , "" , "{0}ಮ\u{cc8}."] , ["" , "{0}ಗ" , "" , "" , "" , "{0} ಗ"] , ["" , "{0} ′" , "" , "" , "" , "{0} ′"] , ["" , "{0} ″" , "" , "" , "" , "{0} ″"] , ["" , "{0}ಕ\u{cc6}ಜ\u{cbf}" , "" , "" , "" , "{0}ಕ\u{cc6}ಜ\u{cbf}"] , ["" , "{0}ಗ\u{ccd}ರಾ" , "" , "" , "" , "{0}ಗ\u{ccd}ರಾ"] , ["" , "{0}ಔ" , "" , "" , "" , "{0}ಔ"] , ["" , "{0}ಮ\u{cc0}/ಸ\u{cc6}." , "" , "" , "" , "{0}ಮ\u{cc0}/ಸ\u{cc6}."] , ["" , "{0}ಮ\u{cc8}/ಗಂ" , "" , "" , "" , "{0}ಮ\u{cc8}/ಗಂ"] , ["" , "" , "" , "" , "" , "{0}킬로미터"] , ["" , "" , "" , "" , "" , "{0}미터"] , ["" , "" , "" , "" , "" , "{0}센티미터"] , ["" , "" , "" , "" , "" , "{0}밀리미터"] , ["" , "" , "" , "" , "" , "{0}마일"] , ["" , "" , "" , "" , "" , "{0}야드"] , ["" , "" , "" , "" , "" , "{0}피트"] , ["" , "" , "" , "" , "" , "{0}인치"] , ["" , "" , "" , "" , "" , "{0}킬로그램"] , ["" , "" , "" , "" , "" , "{0}그램"] , ["" , "" , "" , "" , "" , "{0}파운드"] , ["" , "" , "" , "" , "" , "{0}온스"] , ["" , "" , "" , "" , "" , "시속 {0}킬로미터"] , ["" , "" , "" , "" , "" , "초속 {0}미터"] , ["" , "" , "" , "" , "" , "시속 {0}마일"] , ["" , "" , "" , "" , "" , "섭씨 {0}도"] , ["" , "" , "" , "" , "" , "화씨 {0}도"] , ["" , "" , "" , "" , "" , "{0}ft"] , ["" , "" , "" , "" , "" , "{0}in"] , ["" , "" , "" , "" , "" , "{0}mph"] , ["" , "" , "" , "" , "" , "{0} किलोमीटर"] , ["" , "" , "" , "" , "" , "{0} मीटर"] , ["" , "" , "" , "" , "" , "{0} स\u{947}\u{902}टिमीटर"] , ["" , "" , "" , "" , "" , "{0} मिलिमिटर"] , ["" , "" , "" , "" , "" , "{0} मायल\u{94d}स"] , ["" , "" , "" , "" , "" , "{0} यार\u{94d}ड"] , ["" , "" , "" , "" , "" , "{0} फ\u{942}ट"] , ["" , "" , "" , "" , "" , "{0} इ\u{902}च"] , ["" , "" , "" , "" , "" , "{0} किलोग\u{94d}राम"] , ["" , "" , "" , "" , "" , "{0} ग\u{94d}राम"] , ["" , "" , "" , "" , "" , "{0} पौ\u{902}ड"] , ["" , "" , "" , "" , "" , "{0} औ\u{902}स"] , ["" , "" , "" , "" , "" , "{0} किमी/व"] , ["" , "" , "" , "" , "" , "{0} मी/स\u{947}"] , ["" , "" , "" , "" , "" , "{0} मा/व"] , ["" , "" , "" , "" , "" , "{0} अ\u{902}श स\u{947}ल\u{94d}सियस"] , ["" , "" , "" , "" , "" , "{0} अ\u{902}श फार\u{947}नहायट"] , ["" , "" , "" , "" , "" , "{0} टक\u{94d}को"] , ["" , "" , "" , "" , "" , "{0} किमी"] , ["" , "" , "" , "" , "" , "{0} मी"] , ["" , "" , "" , "" , "" , "{0} स\u{947}मी"] , ["" , "" , "" , "" , "" , "{0} मिमी"] , ["" , "" , "" , "" , "" , "{0} किग\u{94d}रा"] , ["" , "" , "" , "" , "" , "{0} अ\u{902}स\u{947}"] , ["" , "" , "" , "" , "" , "{0}°फा"] , ["" , "" , "" , "" , "" , "{0}किमी"] , ["" , "" , "" , "" , "" , "{0}मी"] , ["" , "" , "" , "" , "" , "{0}स\u{947}मी"] , ["" , "" , "" , "" , "" , "{0}फ\u{942}ट"] , ["" , "" , "" , "" , "" , "{0}किग\u{94d}रा"] , ["" , "" , "" , "" , "" , "{0}ग\u{94d}रा"] , ["" , "" , "" , "" , "" , "{0}औ\u{902}स"] , ["" , "" , "" , "" , "" , "{0}मी/स\u{947}"] , ["" , "" , "" , "" , "" , "{0}मा/व"] , ["" , "" , "" , "" , "" , "{0}°स\u{947}"] , ["{0} Kilometer" , "{0} Kilometer" , "" , "" , "" , "{0} Kilometer"] , ["{0} Meter" , "{0} Meter" , "" , "" , "" , "{0} Meter"] , ["{0} Zentimeter" , "{0} Zentimeter" , "" , "" , "" , "{0} Zentimeter"] , ["{0} Millimeter" , "{0} Millimeter" , "" , "" , "" , "{0} Millimeter"] , ["{0} englischen Meilen" , "{0} englische Meile" , "" , "" , "" , "{0} englischen Meilen"] , ["{0} Voss" , "{0} Voss" , "" , "" , "" , "{0} Voss"] , ["{0} Dumm" , "{0} Dumm" , "" , "" , "" , "{0} Dumm"] , ["{0} Kilogramm" , "{0} Kilogramm" , "" , "" , "" , "{0} Kilogramm"] , ["{0} Gramm" , "{0} Gramm" , "" , "" , "" , "{0} Gramm"] , ["{0} Pfund" , "{0} Pfund" , "" , "" , "" , "{0} Pfund"] , ["{0} Unzen" , "{0} Unze" , "" , "" , "" , "{0} Unzen"] , ["{0} Kilometer pro Schtunde" , "{0} Kilometer pro Schtunde" , "" , "" , "" , "{0} Kilometer pro Schtunde"] , ["{0} Meter pro Sekunde" , "{0} Meter pro Sekunde" , "" , "" , "" , "{0} Meter pro Sekunde"] , ["{0} englischen Meilen pro Schtunde" , "{0} englische Meile pro Schtunde" , "" , "" , "" , "{0} englischen Meilen pro Schtunde"] , ["{0} Graad Celsius" , "{0} Graad Celsius" , "" , "" , "" , "{0} Graad Celsius"] , ["{0} Graad Fahrenheit" , "{0} Graad Fahrenheit" , "" , "" , "" , "{0} Graad Fahrenheit"] , ["{0} km" , "{0} km" , "" , "" , "" , "{0} km"] , ["{0} m" , "{0} m" , "" , "" , "" , "{0} m"] , ["{0} cm" , "{0} cm" , "" , "" , "" , "{0} cm"] , ["{0} mm" , "{0} mm" , "" , "" , "" , "{0} mm"] , ["{0} mi" , "{0} mi" , "" , "" , "" , "{0} mi"] , ["{0} kg" , "{0} kg" , "" , "" , "" , "{0} kg"] , ["{0} g" , "{0} g" , "" , "" , "" , "{0} g"] , ["{0} km/h" , "{0} km/h" , "" , "" , "" , "{0} km/h"] , ["{0} m/s" , "{0} m/s" , "" , "" , "" , "{0} m/s"] , ["{0} mi/h" , "{0} mi/h" , "" , "" , "" , "{0} mi/h"] , ["{0}°C" , "{0}°C" , "" , "" , "" , "{0}°C"] , ["{0}°F" , "{0}°F" , "" , "" , "" , "{0}°F"] , ["{0}km" , "{0}km" , "" , "" , "" , "{0}km"] , ["{0}m" , "{0}m" , "" , "" , "" , "{0}m"] , ["{0}cm" , "{0}cm" , "" , "" , "" , "{0}cm"] , ["{0}mm" , "{0}mm" , "" , "" , "" , "{0}mm"] , ["{0}kg" , "{0}kg" , "" , "" , "" , "{0}kg"] , ["{0}g" , "{0}g" , "" , "" , "" , "{0}g"] , ["{0}km/h" , "{0}km/h" , "" , "" , "" , "{0}km/h"] , ["{0}m/s" , "{0}m/s" , "" , "" , "" , "{0}m/s"] , ["{0}°" , "{0}°" , "" , "" , "" , "{0}°"] , ["" , "ji sedî {0}" , "" , "" , "" , "ji sedî {0}"] , ["" , "саатына {0} километр" , "" , "" , "" , "саатына {0} километр"] , ["" , "секундасына {0} метр" , "" , "" , "" , "секундасына {0} метр"] , ["" , "саатына {0} миля" , "" , "" , "" , "саатына {0} миля"] , ["" , "{0} Цельсий градусу" , "" , "" , "" , "{0} Цельсий градусу"] , ["" , "{0} Фаренгейт градусу" , "" , "" , "" , "{0} Фаренгейт градусу"] , ["" , "{0} гр" , "" , "" , "" , "{0} гр"] , ["" , "{0} км/с" , "" , "" , "" , "{0} км/с"] , ["" , "{0} м/сек." , "" , "" , "" , "{0} м/сек."] , ["" , "{0} миля/с" , "" , "" , "" , "{0} миля/с"] , ["" , "{0}м" , "" , "" , "" , "{0}м"] , ["" , "{0} чак/с" , "" , "" , "" , "{0} чак/с"] , ["" , "{0} Meil" , "" , "" , "" , "{0} Meilen"] , ["" , "{0} Fouss" , "" , "" , "" , "{0} Fouss"] , ["" , "{0} Pond" , "" , "" , "" , "{0} Pond"] , ["" , "{0} Onz" , "" , "" , "" , "{0} Onzen"] , ["" , "{0} Kilometer pro Stonn" , "" , "" , "" , "{0} Kilometer pro Stonn"] , ["" , "{0} Meter pro Sekonn" , "" , "" , "" , "{0} Meter pro Sekonn"] , ["" , "{0} Meil pro Stonn" , "" , "" , "" , "{0} Meile pro Stonn"] , ["" , "" , "" , "" , "" , "makhíyutȟapi {0}"] , ["" , "" , "" , "" , "" , "čhaéglepi {0}"] , ["" , "" , "" , "" , "" , "siíyutȟapi {0}"] , ["" , "" , "" , "" , "" , "oíyutȟe čísčila {0}"] , ["" , "" , "" , "" , "" , "tkeíyutȟapi {0}"] , ["" , "" , "" , "" , "" , "{0} makh"] , ["" , "" , "" , "" , "" , "{0} ກ\u{eb4}ໂລແມ\u{eb1}ດ"] , ["" , "" , "" , "" , "" , "{0} ແມ\u{eb1}ດ"] , ["" , "" , "" , "" , "" , "{0} ຊ\u{eb1}ງຕ\u{eb5}ແມ\u{eb1}ດ"] , ["" , "" , "" , "" , "" , "{0} ມ\u{eb4}ນລ\u{eb4}ແມ\u{eb1}ດ"] , ["" , "" , "" , "" , "" , "{0} ໄມລ\u{ecc}"] , ["" , "" , "" , "" , "" , "{0} ຫລາ"] , ["" , "" , "" , "" , "" , "{0} ຟ\u{eb8}ດ"] , ["" , "" , "" , "" , "" , "{0} ນ\u{eb4}\u{ec9}ວ"] , ["" , "" , "" , "" , "" , "{0} ກ\u{eb4}ໂລກຣາມ"] , ["" , "" , "" , "" , "" , "{0} ກຣາມ"] , ["" , "" , "" , "" , "" , "{0} ປອນ"] , ["" , "" , "" , "" , "" , "{0} ອອນສ"] , ["" , "" , "" , "" , "" , "{0} ກ\u{eb4}ໂລແມ\u{eb1}ດຕ\u{ecd}\u{ec8}ຊ\u{ebb}\u{ec8}ວໂມງ"] , ["" , "" , "" , "" , "" , "{0} ແມ\u{eb1}ດຕ\u{ecd}\u{ec8}ນາທ\u{eb5}"] , ["" , "" , "" , "" , "" , "{0} ໄມລ\u{ecc}ຕ\u{ecd}\u{ec8}ຊ\u{ebb}\u{ec8}ວໂມງ"] , ["" , "" , "" , "" , "" , "{0} ອ\u{ebb}ງສາ ຊ\u{eb5}."] , ["" , "" , "" , "" , "" , "{0} ອ\u{ebb}ງສາຟາເຣນຮາຍ"] , ["" , "" , "" , "" , "" , "{0} ເປ\u{eb5}ເຊ\u{eb1}ນ"] , ["" , "" , "" , "" , "" , "{0} ກມ"] , ["" , "" , "" , "" , "" , "{0} ມ"] , ["" , "" , "" , "" , "" , "{0} ຊມ"] , ["" , "" , "" , "" , "" , "{0} ມມ"] , ["" , "" , "" , "" , "" , "{0} ໄມ"] , ["" , "" , "" , "" , "" , "{0} ຟ"] , ["" , "" , "" , "" , "" , "{0} ນ\u{eb4}"] , ["" , "" , "" , "" , "" , "{0} ກ\u{eb4}ໂລ"] , ["" , "" , "" , "" , "" , "{0} ກ\u{ebc}"] , ["" , "" , "" , "" , "" , "{0} ປ"] , ["" , "" , "" , "" , "" , "{0} ອ"] , ["" , "" , "" , "" , "" , "{0} ກມ/ຊມ"] , ["" , "" , "" , "" , "" , "{0} ມ/ນທ."] , ["" , "" , "" , "" , "" , "{0} ມ/ຊມ"] , ["" , "" , "" , "" , "" , "{0} ອ\u{ebb}ງສາ ຟ."] , ["" , "" , "" , "" , "" , "{0} ກລ"] , ["" , "{0} kilometras" , "" , "{0} kilometrai" , "{0} kilometro" , "{0} kilometrų"] , ["" , "{0} metras" , "" , "{0} metrai" , "{0} metro" , "{0} metrų"] , ["" , "{0} centimetras" , "" , "{0} centimetrai" , "{0} centimetro" , "{0} centimetrų"] , ["" , "{0} milimetras" , "" , "{0} milimetrai" , "{0} milimetro" , "{0} milimetrų"] , ["" , "{0} mylia" , "" , "{0} mylios" , "{0} mylios" , "{0} mylių"] , ["" , "{0} jardas" , "" , "{0} jardai" , "{0} jardo" , "{0} jardų"] , ["" , "{0} pėda" , "" , "{0} pėdos" , "{0} pėdos" , "{0} pėdų"] , ["" , "{0} colis" , "" , "{0} coliai" , "{0} colio" , "{0} colių"] , ["" , "{0} kilogramas" , "" , "{0} kilogramai" , "{0} kilogramo" , "{0} kilogramų"] , ["" , "{0} gramas" , "" , "{0} gramai" , "{0} gramo" , "{0} gramų"] , ["" , "{0} svaras" , "" , "{0} svarai" , "{0} svaro" , "{0} svarų"] , ["" , "{0} uncija" , "" , "{0} uncijos" , "{0} uncijos" , "{0} uncijų"] , ["" , "{0} kilometras per valandą" , "" , "{0} kilometrai per valandą" , "{0} kilometro per valandą" , "{0} kilometrų per valandą"] , ["" , "{0} metras per sekundę" , "" , "{0} metrai per sekundę" , "{0} metro per sekundę" , "{0} metrų per sekundę"] , ["" , "{0} mylia per valandą" , "" , "{0} mylios per valandą" , "{0} mylios per valandą" , "{0} mylių per valandą"] , ["" , "{0} Celsijaus laipsnis" , "" , "{0} Celsijaus laipsniai" , "{0} Celsijaus laipsnio" , "{0} Celsijaus laipsnių"] , ["" , "{0} Farenheito laipsnis" , "" , "{0} Farenheito laipsniai" , "{0} Farenheito laipsnio" , "{0} Farenheito laipsnių"] , ["" , "{0} procentas" , "" , "{0} procentai" , "{0} procento" , "{0} procentas"] , ["" , "{0} km/val." , "" , "{0} km/val." , "{0} km/val." , "{0} km/val."] , ["" , "{0} m/sek." , "" , "{0} m/sek." , "{0} m/sek." , "{0} m/sek."] , ["" , "{0}°C" , "" , "{0}°C" , "{0}°C" , "{0}°C"] , ["" , "{0}°F" , "" , "{0}°F" , "{0}°F" , "{0}°F"] , ["" , "{0}°" , "" , "{0}°" , "{0}°" , "{0}°"] , ["{0} kilometru" , "{0} kilometrs" , "" , "" , "" , "{0} kilometri"] , ["{0} metru" , "{0} metrs" , "" , "" , "" , "{0} metri"] , ["{0} centimetru" , "{0} centimetrs" , "" , "" , "" , "{0} centimetri"] , ["{0} milimetru" , "{0} milimetrs" , "" , "" , "" , "{0} milimetri"] , ["{0} jūdžu" , "{0} jūdze" , "" , "" , "" , "{0} jūdzes"] , ["{0} jardu" , "{0} jards" , "" , "" , "" , "{0} jardi"] , ["{0} pēdu" , "{0} pēda" , "" , "" , "" , "{0} pēdas"] , ["{0} collu" , "{0} colla" , "" , "" , "" , "{0} collas"] , ["{0} kilogramu" , "{0} kilograms" , "" , "" , "" , "{0} kilogrami"] , ["{0} gramu" , "{0} grams" , "" , "" , "" , "{0} grami"] , ["{0} mārciņu" , "{0} mārciņa" , "" , "" , "" , "{0} mārciņas"] , ["{0} unču" , "{0} unce" , "" , "" , "" , "{0} unces"] , ["{0} kilometru stundā" , "{0} kilometrs stundā" , "" , "" , "" , "{0} kilometri stundā"] , ["{0} metru sekundē" , "{0} metrs sekundē" , "" , "" , "" , "{0} metri sekundē"] , ["{0} jūdžu stundā" , "{0} jūdze stundā" , "" , "" , "" , "{0} jūdzes stundā"] , ["{0} Celsija grādu" , "{0} Celsija grāds" , "" , "" , "" , "{0} Celsija grādi"] , ["{0} Fārenheita grādu" , "{0} Fārenheita grāds" , "" , "" , "" , "{0} Fārenheita grādi"] , ["{0} procentu" , "{0} procents" , "" , "" , "" , "{0} procenti"] , ["{0} jūdzes" , "{0} jūdze" , "" , "" , "" , "{0} jūdzes"] , ["{0} jardi" , "{0} jards" , "" , "" , "" , "{0} jardi"] , ["{0} ft" , "{0} ft" , "" , "" , "" , "{0} ft"] , ["{0} in" , "{0} in" , "" , "" , "" , "{0} in"] , ["{0} lb" , "{0} lb" , "" , "" , "" , "{0} lb"] , ["{0}\u{a0}unču" , "{0} unce" , "" , "" , "" , "{0} unces"] , ["{0} km/st." , "{0} km/st." , "" , "" , "" , "{0} km/st."] , ["{0} °C" , "{0} °C" , "" , "" , "" , "{0} °C"] , ["{0} °F" , "{0} °F" , "" , "" , "" , "{0} °F"] , ["{0}%" , "{0}%" , "" , "" , "" , "{0}%"] , ["{0} cm" , "{0}cm" , "" , "" , "" , "{0} cm"] , ["{0} mm" , "{0}mm" , "" , "" , "" , "{0} mm"] , ["{0}mi" , "{0}mi" , "" , "" , "" , "{0}mi"] , ["{0}yd" , "{0}yd" , "" , "" , "" , "{0}yd"] , ["{0}ft" , "{0}ft" , "" , "" , "" , "{0}ft"] , ["{0}in" , "{0}in" , "" , "" , "" , "{0}in"] , ["{0}lb" , "{0}lb" , "" , "" , "" , "{0}lb"] , ["{0}oz" , "{0}oz" , "" , "" , "" , "{0}oz"] , ["{0}mi/h" , "{0}mi/h" , "" , "" , "" , "{0}mi/h"] , ["" , "{0} километар" , "" , "" , "" , "{0} километри"] , ["" , "{0} метар" , "" , "" , "" , "{0} метри"] , ["" , "{0} сантиметар" , "" , "" , "" , "{0} сантиметри"] , ["" , "{0} милиметар" , "" , "" , "" , "{0} милиметри"] , ["" , "{0} милја" , "" , "" , "" , "{0} милји"] , ["" , "{0} јард" , "" , "" , "" , "{0} јарди"] , ["" , "{0} стапка" , "" , "" , "" , "{0} стапки"] , ["" , "{0} инч" , "" , "" , "" , "{0} инчи"] , ["" , "{0} килограм" , "" , "" , "" , "{0} килограми"] , ["" , "{0} фунта" , "" , "" , "" , "{0} фунти"] , ["" , "{0} унца" , "" , "" , "" , "{0} унци"] , ["" , "{0} километар на час" , "" , "" , "" , "{0} километри на час"] , ["" , "{0} метар во секунда" , "" , "" , "" , "{0} метри во секунда"] , ["" , "{0} милја на час" , "" , "" , "" , "{0} милји на час"] , ["" , "{0} целзиусов степен" , "" , "" , "" , "{0} целзиусови степени"] , ["" , "{0} фаренхајтов степен" , "" , "" , "" , "{0} фаренхајтови степени"] , ["" , "{0} процент" , "" , "" , "" , "{0} проценти"] , ["" , "{0} കിലോമീറ\u{d4d}റർ" , "" , "" , "" , "{0} കിലോമീറ\u{d4d}റർ"] , ["" , "{0} മീറ\u{d4d}റർ" , "" , "" , "" , "{0} മീറ\u{d4d}റർ"] , ["" , "{0} സെന\u{d4d}റീമീറ\u{d4d}റർ" , "" , "" , "" , "{0} സെന\u{d4d}റീമീറ\u{d4d}റർ"] , ["" , "{0} മില\u{d4d}ലീമീറ\u{d4d}റർ" , "" , "" , "" , "{0} മില\u{d4d}ലീമീറ\u{d4d}റർ"] , ["" , "{0} മൈൽ" , "" , "" , "" , "{0} മൈൽ"] , ["" , "{0} വ\u{d3e}ര" , "" , "" , "" , "{0} വ\u{d3e}ര"] , ["" , "{0} അടി" , "" , "" , "" , "{0} അടി"] , ["" , "{0} ഇഞ\u{d4d}ച\u{d4d}" , "" , "" , "" , "{0} ഇഞ\u{d4d}ച\u{d4d}"] , ["" , "{0} കിലോഗ\u{d4d}ര\u{d3e}ം" , "" , "" , "" , "{0} കിലോഗ\u{d4d}ര\u{d3e}ം"] , ["" , "{0} ഗ\u{d4d}ര\u{d3e}ം" , "" , "" , "" , "{0} ഗ\u{d4d}ര\u{d3e}ം"] , ["" , "{0} പ\u{d57}ണ\u{d4d}ട\u{d4d}" , "" , "" , "" , "{0} പ\u{d57}ണ\u{d4d}ട\u{d4d}"] , ["" , "{0} ഔൺസ\u{d4d}" , "" , "" , "" , "{0} ഔൺസ\u{d4d}"] , ["" , "{0} കിലോമീറ\u{d4d}റർ/മണിക\u{d4d}ക\u{d42}ർ" , "" , "" , "" , "{0} കിലോമീറ\u{d4d}റർ/മണിക\u{d4d}ക\u{d42}ർ"] , ["" , "{0} മീറ\u{d4d}റർ/സെക\u{d4d}കൻഡ\u{d4d}" , "" , "" , "" , "{0} മീറ\u{d4d}റർ/സെക\u{d4d}കൻഡ\u{d4d}"] , ["" , "{0} മൈൽ/മണിക\u{d4d}ക\u{d42}ർ" , "" , "" , "" , "{0} മൈൽ/മണിക\u{d4d}ക\u{d42}ർ"] , ["" , "{0} ഡിഗ\u{d4d}രി സെൽഷ\u{d4d}യസ\u{d4d}" , "" , "" , "" , "{0} ഡിഗ\u{d4d}രി സെൽഷ\u{d4d}യസ\u{d4d}"] , ["" , "{0} ഡിഗ\u{d4d}രി ഫ\u{d3e}രൻഹീറ\u{d4d}റ\u{d4d}" , "" , "" , "" , "{0} ഡിഗ\u{d4d}രി ഫ\u{d3e}രൻഹീറ\u{d4d}റ\u{d4d}"] , ["" , "{0} കി.മീ." , "" , "" , "" , "{0} കി.മീ."] , ["" , "{0} മീ." , "" , "" , "" , "{0} മീ."] , ["" , "{0} സെ.മീ." , "" , "" , "" , "{0} സെ.മീ."] , ["" , "{0} മി.മീ." , "" , "" , "" , "{0} മി.മീ."] , ["" , "{0} കി.ഗ\u{d4d}ര\u{d3e}ം" , "" , "" , "" , "{0} കി.ഗ\u{d4d}ര\u{d3e}ം"] , ["" , "{0} പ\u{d57}" , "" , "" , "" , "{0} പ\u{d57}"] , ["" , "{0} ഔ." , "" , "" , "" , "{0} ഔ."] , ["" , "{0} കി.മീ/മ." , "" , "" , "" , "{0} കി.മീ/മ."] , ["" , "{0} മീ/സെ." , "" , "" , "" , "{0} മീ/സെ."] , ["" , "{0} മൈ/മ." , "" , "" , "" , "{0} മൈ/മ."] , ["" , "{0}°സെ" , "" , "" , "" , "{0}°സെ"] , ["" , "{0}° ഫ\u{d3e}" , "" , "" , "" , "{0}°ഫ\u{d3e}"] , ["" , "{0} മൈ" , "" , "" , "" , "{0} മൈ"] , ["" , "{0} യ\u{d3e}" , "" , "" , "" , "{0} യ\u{d3e}"] , ["" , "{0}കി.ഗ\u{d4d}ര\u{d3e}ം" , "" , "" , "" , "{0}കി.ഗ\u{d4d}ര\u{d3e}ം"] , ["" , "{0} ഔ" , "" , "" , "" , "{0} ഔ"] , ["" , "{0}കി.മീ/മ." , "" , "" , "" , "{0}കി.മീ/മ."] , ["" , "{0} മീ/സെ" , "" , "" , "" , "{0} മീ/സെ"] , ["" , "{0} മൈ/മ" , "" , "" , "" , "{0} മൈ/മ"] , ["" , "{0}°ഫ\u{d3e}" , "" , "" , "" , "{0}°ഫ\u{d3e}"] , ["" , "{0} сантиметр" , "" , "" , "" , "{0} см"] , ["" , "{0} миль" , "" , "" , "" , "{0} миль"] , ["" , "{0} инч" , "" , "" , "" , "{0} инч"] , ["" , "{0} унц" , "" , "" , "" , "{0} унц"] , ["" , "цагт {0} километр" , "" , "" , "" , "цагт {0} километр"] , ["" , "секундэд {0} метр" , "" , "" , "" , "секундэд {0} метр"] , ["" , "цагт {0} миль" , "" , "" , "" , "цагт {0} миль"] , ["" , "{0} Цельсийн хэм" , "" , "" , "" , "{0} Цельсийн хэм"] , ["" , "{0} Фаренгейтын хэм" , "" , "" , "" , "{0} Фаренгейтын хэм"] , ["" , "{0} хувь" , "" , "" , "" , "{0} хувь"] , ["" , "{0} ми" , "" , "" , "" , "{0} ми"] , ["" , "{0} км/ц" , "" , "" , "" , "{0} км/ц"] , ["" , "{0} ми/ц" , "" , "" , "" , "{0} ми/ц"] , ["" , "{0} मिलिमीटर" , "" , "" , "" , "{0} मिलिमीटर"] , ["" , "{0} म\u{948}ल" , "" , "" , "" , "{0} म\u{948}ल"] , ["" , "{0} फ\u{942}ट" , "" , "" , "" , "{0} फ\u{942}ट"] , ["" , "{0} किलोग\u{94d}र\u{945}म" , "" , "" , "" , "{0} किलोग\u{94d}र\u{945}म"] , ["" , "{0} ग\u{94d}र\u{945}म" , "" , "" , "" , "{0} ग\u{94d}र\u{945}म"] , ["" , "{0} पाउ\u{902}ड" , "" , "" , "" , "{0} पाउ\u{902}ड"] , ["" , "{0} किलोमीटर प\u{94d}रति तास" , "" , "" , "" , "{0} किलोमीटर प\u{94d}रति तास"] , ["" , "{0} मीटर प\u{94d}रति स\u{947}क\u{902}द" , "" , "" , "" , "{0} मीटर प\u{94d}रति स\u{947}क\u{902}द"] , ["" , "{0} म\u{948}ल प\u{94d}रति तास" , "" , "" , "" , "{0} म\u{948}ल प\u{94d}रति तास"] , ["" , "{0} अ\u{902}श स\u{947}ल\u{94d}सिअस" , "" , "" , "" , "{0} अ\u{902}श स\u{947}ल\u{94d}सिअस"] , ["" , "{0} अ\u{902}श फ\u{945}रनहाईट" , "" , "" , "" , "{0} अ\u{902}श फ\u{945}रनहाईट"] , ["" , "{0} टक\u{94d}क\u{947}" , "" , "" , "" , "{0} टक\u{94d}क\u{947}"] , ["" , "{0} स\u{947}\u{902}मी" , "" , "" , "" , "{0} स\u{947}\u{902}मी"] , ["" , "{0} किग\u{94d}र\u{945}" , "" , "" , "" , "{0} किग\u{94d}र\u{945}"] , ["" , "{0} ग\u{94d}र\u{945}" , "" , "" , "" , "{0} ग\u{94d}र\u{945}"] , ["" , "{0} किप\u{94d}रता" , "" , "" , "" , "{0} किप\u{94d}रता"] , ["" , "{0} मी/स\u{947}" , "" , "" , "" , "{0} मी/स\u{947}"] , ["" , "{0} म\u{948}प\u{94d}रता" , "" , "" , "" , "{0} म\u{948}प\u{94d}रता"] , ["" , "{0}°स\u{947}" , "" , "" , "" , "{0}°स\u{947}"] , ["" , "{0}°फ\u{945}" , "" , "" , "" , "{0}°फ\u{945}"] , ["" , "{0}किमी" , "" , "" , "" , "{0}किमी"] , ["" , "{0}मी" , "" , "" , "" , "{0}मी"] , ["" , "{0}स\u{947}\u{902}मी" , "" , "" , "" , "{0}स\u{947}\u{902}मी"] , ["" , "{0}मिमी" , "" , "" , "" , "{0}मिमी"] , ["" , "{0}म\u{948}ल" , "" , "" , "" , "{0}म\u{948}ल"] , ["" , "{0}यार\u{94d}ड" , "" , "" , "" , "{0}यार\u{94d}ड"] , ["" , "{0}'" , "" , "" , "" , "{0}'"] , ["" , "{0}किग\u{94d}र\u{945}" , "" , "" , "" , "{0}किग\u{94d}र\u{945}"] , ["" , "{0}ग\u{94d}र\u{945}" , "" , "" , "" , "{0}ग\u{94d}र\u{945}"] , ["" , "{0}औ\u{902}स" , "" , "" , "" , "{0}औ\u{902}स"] , ["" , "{0}किप\u{94d}रता" , "" , "" , "" , "{0}किप\u{94d}रता"] , ["" , "{0}मी/स\u{947}" , "" , "" , "" , "{0}मी/स\u{947}"] , ["" , "{0}म\u{948}प\u{94d}रता" , "" , "" , "" , "{0}म\u{948}प\u{94d}रता"] , ["" , "" , "" , "" , "" , "{0} batu"] , ["" , "" , "" , "" , "" , "{0} ela"] , ["" , "" , "" , "" , "" , "{0} paun"] , ["" , "" , "" , "" , "" , "{0} auns"] , ["" , "" , "" , "" , "" , "{0} kilometer sejam"] , ["" , "" , "" , "" , "" , "{0} meter sesaat"] , ["" , "" , "" , "" , "" , "{0} batu sejam"] , ["" , "" , "" , "" , "" , "{0} darjah Celsius"] , ["" , "" , "" , "" , "" , "{0} darjah Fahrenheit"] , ["" , "" , "" , "" , "" , "{0} peratus"] , ["" , "" , "" , "" , "" , "{0} bt"] , ["" , "" , "" , "" , "" , "{0} ka"] , ["" , "" , "" , "" , "" , "{0} kmj"] , ["" , "" , "" , "" , "" , "{0} bsj"] , ["" , "" , "" , "" , "" , "{0}'"] , ["" , "" , "" , "" , "" , "{0}\\"] , ["" , "" , "" , "" , "" , "{0} က\u{102e}လ\u{102d}\u{102f}မ\u{102e}တာ"] , ["" , "" , "" , "" , "" , "{0} မ\u{102e}တာ"] , ["" , "" , "" , "" , "" , "{0} စင\u{103a}တ\u{102e}မ\u{102e}တာ"] , ["" , "" , "" , "" , "" , "{0} မ\u{102e}လ\u{102e}မ\u{102e}တာ"] , ["" , "" , "" , "" , "" , "{0} မ\u{102d}\u{102f}င\u{103a}"] , ["" , "" , "" , "" , "" , "{0} က\u{102d}\u{102f}က\u{103a}"] , ["" , "" , "" , "" , "" , "{0} ပေ"] , ["" , "" , "" , "" , "" , "{0} လက\u{103a}မ"] , ["" , "" , "" , "" , "" , "{0} က\u{102e}လ\u{102d}\u{102f}ဂရမ\u{103a}"] , ["" , "" , "" , "" , "" , "{0} ဂရမ\u{103a}"] , ["" , "" , "" , "" , "" , "{0} ပေါင\u{103a}"] , ["" , "" , "" , "" , "" , "{0} အောင\u{103a}စ"] , ["" , "" , "" , "" , "" , "တစ\u{103a}နာရ\u{102e}လျ\u{103e}င\u{103a} {0} က\u{102e}လ\u{102d}\u{102f}မ\u{102e}တာ"] , ["" , "" , "" , "" , "" , "တစ\u{103a}စက\u{1039}ကန\u{1037}\u{103a}လျ\u{103e}င\u{103a} {0} မ\u{102e}တာ"] , ["" , "" , "" , "" , "" , "တစ\u{103a}နာရ\u{102e}လျ\u{103e}င\u{103a} {0} မ\u{102d}\u{102f}င\u{103a}"] , ["" , "" , "" , "" , "" , "{0} ဒ\u{102e}ဂရ\u{102e} စင\u{103a}တ\u{102e}ဂရ\u{102d}တ\u{103a}"] , ["" , "" , "" , "" , "" , "{0} ဒ\u{102e}ဂရ\u{102e} ဖာရင\u{103a}ဟ\u{102d}\u{102f}က\u{103a}"] , ["" , "" , "" , "" , "" , "{0} ရာခ\u{102d}\u{102f}င\u{103a}န\u{103e}\u{102f}န\u{103a}း"] , ["" , "" , "" , "" , "" , "{0} کیلوگرم"] , ["" , "" , "" , "" , "" , "{0} گرم"] , ["" , "" , "" , "" , "" , "{0} پوند"] , ["" , "" , "" , "" , "" , "{0} اونس"] , ["" , "" , "" , "" , "" , "{0} کیلومتر بر ساعت"] , ["" , "" , "" , "" , "" , "{0} متر بر ثانیه"] , ["" , "" , "" , "" , "" , "{0} مایل بر ساعت"] , ["" , "" , "" , "" , "" , "{0} درجه سلسیوس"] , ["" , "" , "" , "" , "" , "{0} فارنهایت"] , ["" , "{0} fot" , "" , "" , "" , "{0} fot"] , ["" , "{0} unse" , "" , "" , "" , "{0} unser"] , ["" , "{0} kilometer per time" , "" , "" , "" , "{0} kilometer per time"] , ["" , "{0} meter per sekund" , "" , "" , "" , "{0} meter per sekund"] , ["" , "{0} engelsk mil per time" , "" , "" , "" , "{0} engelske mil per time"] , ["" , "{0} mile/t" , "" , "" , "" , "{0} miles/t"] , ["" , "{0}mi/t" , "" , "" , "" , "{0}mi/t"] , ["" , "{0} किलोमिटर" , "" , "" , "" , "{0}किलोमिटर"] , ["" , "{0} मिटर" , "" , "" , "" , "{0} मिटर"] , ["" , "{0} स\u{947}न\u{94d}टिमिटर" , "" , "" , "" , "{0} स\u{947}न\u{94d}टिमिटर"] , ["" , "{0} मिलिमिटर" , "" , "" , "" , "{0} मिलिमिटर"] , ["" , "माइल" , "" , "" , "" , "{0} माइल"] , ["" , "{0} गज" , "" , "" , "" , "{0} गज"] , ["" , "{0} फ\u{941}ट" , "" , "" , "" , "{0} फ\u{941}ट"] , ["" , "{0}इन\u{94d}च" , "" , "" , "" , "{0}इन\u{94d}च"] , ["" , "{0}पाउन\u{94d}ड" , "" , "" , "" , "{0}पाउन\u{94d}ड"] , ["" , "{0} आउन\u{94d}स" , "" , "" , "" , "{0} आउन\u{94d}स"] , ["" , "{0} किलोमिटर प\u{94d}रति घण\u{94d}टा" , "" , "" , "" , "{0} किलोमिटर प\u{94d}रति घण\u{94d}टा"] , ["" , "{0} मिटर प\u{94d}रति स\u{947}क\u{947}ण\u{94d}ड" , "" , "" , "" , "{0} मिटर प\u{94d}रति स\u{947}क\u{947}ण\u{94d}ड"] , ["" , "माइल प\u{94d}रति घण\u{94d}टा" , "" , "" , "" , "{0} माइल प\u{94d}रति घण\u{94d}टा"] , ["" , "{0} डिग\u{94d}री स\u{947}ल\u{94d}सियस\u{94d}" , "" , "" , "" , "{0} डिग\u{94d}री स\u{947}ल\u{94d}सियस\u{94d}"] , ["" , "{0}डिग\u{94d}री फर\u{947}नहाइट" , "" , "" , "" , "{0}डिग\u{94d}री फर\u{947}नहाइट"] , ["" , "{0}कि.मि." , "" , "" , "" , "{0}कि.मि."] , ["" , "{0} मि." , "" , "" , "" , "{0} मि."] , ["" , "{0}स\u{947}.मि." , "" , "" , "" , "{0}स\u{947}.मि."] , ["" , "मि.मि." , "" , "" , "" , "{0}मि.मि."] , ["" , "{0} माइल" , "" , "" , "" , "{0} माइल"] , ["" , "{0}फिट" , "" , "" , "" , "{0}फिट"] , ["" , "इन\u{94d}च" , "" , "" , "" , "{0}इन\u{94d}च"] , ["" , "{0} किलो" , "" , "" , "" , "{0} किलो"] , ["" , "{0}°फ" , "" , "" , "" , "{0}°फ"] , ["" , "{0}मि.मि." , "" , "" , "" , "{0}मि.मि."] , ["" , "{0} माईल" , "" , "" , "" , "{0} माईल"] , ["" , "{0} पाउण\u{94d}ड" , "" , "" , "" , "{0} पाउण\u{94d}ड"] , ["" , "{0} आऊन\u{94d}स" , "" , "" , "" , "{0} आऊन\u{94d}स"] , ["" , "{0} mijl" , "" , "" , "" , "{0} mijl"] , ["" , "{0} pound" , "" , "" , "" , "{0} pound"] , ["" , "{0} ounce" , "" , "" , "" , "{0} ounce"] , ["" , "{0} meter per seconde" , "" , "" , "" , "{0} meter per seconde"] , ["" , "{0} mijl per uur" , "" , "" , "" , "{0} mijl per uur"] , ["" , "{0} km/u" , "" , "" , "" , "{0} km/u"] , ["" , "{0} tomme" , "" , "" , "" , "{0} tommar"] , ["" , "{0} mile/t" , "" , "" , "" , "{0} mile/t"] , ["" , "{0} କ\u{b3f}ଲୋମ\u{b3f}ଟର\u{b4d}" , "" , "" , "" , "{0} କ\u{b3f}ଲୋମ\u{b3f}ଟର\u{b4d}"] , ["" , "{0} ମ\u{b3f}ଟର\u{b4d}" , "" , "" , "" , "{0} ମ\u{b3f}ଟର\u{b4d}"] , ["" , "{0} ସେଣ\u{b4d}ଟ\u{b3f}ମ\u{b3f}ଟର\u{b4d}" , "" , "" , "" , "{0} ସେଣ\u{b4d}ଟ\u{b3f}ମ\u{b3f}ଟର\u{b4d}"] , ["" , "{0} ମ\u{b3f}ଲ\u{b3f}ମ\u{b3f}ଟର\u{b4d}" , "" , "" , "" , "{0} ମ\u{b3f}ଲ\u{b3f}ମ\u{b3f}ଟର\u{b4d}"] , ["" , "{0} ମ\u{b3e}ଇଲ\u{b4d}" , "" , "" , "" , "{0} ମ\u{b3e}ଇଲ\u{b4d}"] , ["" , "{0} ୟ\u{b3e}ର\u{b4d}ଡ\u{b4d}" , "" , "" , "" , "{0} ୟ\u{b3e}ର\u{b4d}ଡ\u{b4d}"] , ["" , "{0} ଫ\u{b41}ଟ\u{b4d}" , "" , "" , "" , "{0} ଫ\u{b3f}ଟ\u{b4d}"] , ["" , "{0} ଇଞ\u{b4d}ଚ\u{b4d}" , "" , "" , "" , "{0} ଇଞ\u{b4d}ଚ\u{b4d}"] , ["" , "{0} କ\u{b3f}ଲୋଗ\u{b4d}ର\u{b3e}ମ\u{b4d}" , "" , "" , "" , "{0} କ\u{b3f}ଲୋଗ\u{b4d}ର\u{b3e}ମ\u{b4d}"] , ["" , "{0} ଗ\u{b4d}ର\u{b3e}ମ\u{b4d}" , "" , "" , "" , "{0} ଗ\u{b4d}ର\u{b3e}ମ\u{b4d}"] , ["" , "{0} ପ\u{b3e}ଉଣ\u{b4d}ଡ\u{b4d}" , "" , "" , "" , "{0} ପ\u{b3e}ଉଣ\u{b4d}ଡ\u{b4d}"] , ["" , "{0} ଆଉନ\u{b4d}ସ" , "" , "" , "" , "{0} ଆଉନ\u{b4d}ସ"] , ["" , "{0} କ\u{b3f}ଲୋମ\u{b3f}ଟର\u{b4d} ପ\u{b4d}ରତ\u{b3f} ଘଣ\u{b4d}ଟ\u{b3e}" , "" , "" , "" , "{0} କ\u{b3f}ଲୋମ\u{b3f}ଟର\u{b4d} ପ\u{b4d}ରତ\u{b3f} ଘଣ\u{b4d}ଟ\u{b3e}"] , ["" , "{0} ମ\u{b3f}ଟର\u{b4d} ପ\u{b4d}ରତ\u{b3f} ସେକେଣ\u{b4d}ଡ\u{b4d}" , "" , "" , "" , "{0} ମ\u{b3f}ଟର\u{b4d} ପ\u{b4d}ରତ\u{b3f} ସେକେଣ\u{b4d}ଡ\u{b4d}"] , ["" , "{0} ମ\u{b3e}ଇଲ\u{b4d} ପ\u{b4d}ରତ\u{b3f} ଘଣ\u{b4d}ଟ\u{b3e}" , "" , "" , "" , "{0} ମ\u{b3e}ଇଲ\u{b4d} ପ\u{b4d}ରତ\u{b3f} ଘଣ\u{b4d}ଟ\u{b3e}"] , ["" , "{0} ଡ\u{b3f}ଗ\u{b4d}ରୀ ସେଲସ\u{b3f}ୟସ\u{b4d}" , "" , "" , "" , "{0} ଡ\u{b3f}ଗ\u{b4d}ରୀ ସେଲସ\u{b3f}ୟସ\u{b4d}"] , ["" , "{0} ଡ\u{b3f}ଗ\u{b4d}ରୀ ଫ\u{b3e}ରେନହ\u{b3e}ଇଟ\u{b4d}" , "" , "" , "" , "{0} ଡ\u{b3f}ଗ\u{b4d}ରୀ ଫ\u{b3e}ରେନହ\u{b3e}ଇଟ\u{b4d}"] , ["" , "{0} ଶତକଡ\u{b3c}\u{b3e}" , "" , "" , "" , "{0} ଶତକଡ\u{b3c}\u{b3e}"] , ["" , "{0} କ\u{b3f}ମ\u{b3f}" , "" , "" , "" , "{0} କ\u{b3f}ମ\u{b3f}"] , ["" , "{0} ମ\u{b3f}" , "" , "" , "" , "{0} ମ\u{b3f}"] , ["" , "{0} ସେମ\u{b3f}" , "" , "" , "" , "{0} ସେମ\u{b3f}"] , ["" , "{0} ମ\u{b3f}ମ\u{b3f}" , "" , "" , "" , "{0} ମ\u{b3f}ମ\u{b3f}"] , ["" , "{0} ମ\u{b3e}" , "" , "" , "" , "{0} ମ\u{b3e}"] , ["" , "{0} ଫ\u{b3f}ଟ\u{b4d}" , "" , "" , "" , "{0} ଫ\u{b3f}ଟ\u{b4d}"] , ["" , "{0} କ\u{b3f}ଗ\u{b4d}ର\u{b3e}" , "" , "" , "" , "{0} କ\u{b3f}ଗ\u{b4d}ର\u{b3e}"] , ["" , "{0} ଗ\u{b4d}ର\u{b3e}" , "" , "" , "" , "{0} ଗ\u{b4d}ର\u{b3e}"] , ["" , "{0} ଓଜ\u{b4d}" , "" , "" , "" , "{0} ଓଜ\u{b4d}"] , ["" , "{0} କେପ\u{b3f}ଏଚ\u{b4d}" , "" , "" , "" , "{0} କେପ\u{b3f}ଏଚ\u{b4d}"] , ["" , "{0} ମ\u{b3f}/ସେ" , "" , "" , "" , "{0} ମ\u{b3f}/ସେ"] , ["" , "{0} ଏମପ\u{b3f}ଏଚ\u{b4d}" , "" , "" , "" , "{0} ଏମପ\u{b3f}ଏଚ\u{b4d}"] , ["" , "{0}°ସେ" , "" , "" , "" , "{0}°ସେ"] , ["" , "{0}°ଫ\u{b3e}" , "" , "" , "" , "{0}°ଫ\u{b3e}"] , ["" , "{0} ସେମ\u{b3f}" , "" , "" , "" , "{0}ସେମ\u{b3f}"] , ["" , "{0}ମ\u{b3f}ମ\u{b3f}" , "" , "" , "" , "{0}ମ\u{b3f}ମ\u{b3f}"] , ["" , "{0}ମ\u{b3e}ଇଲ" , "" , "" , "" , "{0}ମ\u{b3e}ଇଲ"] , ["" , "{0}ଗଜ" , "" , "" , "" , "{0}ଗଜ"] , ["" , "{0}କ\u{b3f}ଗ\u{b4d}ର\u{b3e}" , "" , "" , "" , "{0}କ\u{b3f}ଗ\u{b4d}ର\u{b3e}"] , ["" , "{0}ଗ\u{b4d}ର\u{b3e}" , "" , "" , "" , "{0}ଗ\u{b4d}ର\u{b3e}"] , ["" , "{0}ଅ." , "" , "" , "" , "{0}ଅ."] , ["" , "{0}କେପ\u{b3f}ଏଚ\u{b4d}" , "" , "" , "" , "{0}କେପ\u{b3f}ଏଚ\u{b4d}"] , ["" , "{0} ਕਿਲ\u{a4b}ਮੀਟਰ" , "" , "" , "" , "{0} ਕਿਲ\u{a4b}ਮੀਟਰ"] , ["" , "{0} ਮੀਟਰ" , "" , "" , "" , "{0} ਮੀਟਰ"] , ["" , "{0} ਸ\u{a48}\u{a02}ਟੀਮੀਟਰ" , "" , "" , "" , "{0} ਸ\u{a48}\u{a02}ਟੀਮੀਟਰ"] , ["" , "{0} ਮਿਲੀਮੀਟਰ" , "" , "" , "" , "{0} ਮਿਲੀਮੀਟਰ"] , ["" , "{0} ਮੀਲ" , "" , "" , "" , "{0} ਮੀਲ"] , ["" , "{0} ਗਜ\u{a3c}" , "" , "" , "" , "{0} ਗਜ\u{a3c}"] , ["" , "{0} ਫ\u{a41}\u{a71}ਟ" , "" , "" , "" , "{0} ਫ\u{a41}\u{a71}ਟ"] , ["" , "{0} ਇ\u{a70}ਚ" , "" , "" , "" , "{0} ਇ\u{a70}ਚ"] , ["" , "{0} ਕਿਲ\u{a4b}ਗ\u{a4d}ਰਾਮ" , "" , "" , "" , "{0} ਕਿਲ\u{a4b}ਗ\u{a4d}ਰਾਮ"] , ["" , "{0} ਗ\u{a4d}ਰਾਮ" , "" , "" , "" , "{0} ਗ\u{a4d}ਰਾਮ"] , ["" , "{0} ਪ\u{a4c}\u{a02}ਡ" , "" , "" , "" , "{0} ਪ\u{a4c}\u{a02}ਡ"] , ["" , "{0} ਔ\u{a02}ਸ" , "" , "" , "" , "{0} ਔ\u{a02}ਸ"] , ["" , "{0} ਕਿਲ\u{a4b}ਮੀਟਰ ਪ\u{a4d}ਰਤੀ ਘ\u{a70}ਟਾ" , "" , "" , "" , "{0} ਕਿਲ\u{a4b}ਮੀਟਰ ਪ\u{a4d}ਰਤੀ ਘ\u{a70}ਟਾ"] , ["" , "{0} ਮੀਟਰ ਪ\u{a4d}ਰਤੀ ਸਕਿ\u{a70}ਟ" , "" , "" , "" , "{0} ਮੀਟਰ ਪ\u{a4d}ਰਤੀ ਸਕਿ\u{a70}ਟ"] , ["" , "{0} ਮੀਲ ਪ\u{a4d}ਰਤੀ ਘ\u{a70}ਟਾ" , "" , "" , "" , "{0} ਮੀਲ ਪ\u{a4d}ਰਤੀ ਘ\u{a70}ਟਾ"] , ["" , "{0} ਡਿਗਰੀ ਸ\u{a48}ਲਸੀਅਸ" , "" , "" , "" , "{0} ਡਿਗਰੀ ਸ\u{a48}ਲਸੀਅਸ"] , ["" , "{0} ਡਿਗਰੀ ਫਾਰ\u{a47}ਨਹਾਈਟ" , "" , "" , "" , "{0} ਡਿਗਰੀ ਫਾਰ\u{a47}ਨਹਾਈਟ"] , ["" , "{0} ਪ\u{a4d}ਰਤੀਸ\u{a3c}ਤ" , "" , "" , "" , "{0} ਪ\u{a4d}ਰਤੀਸ\u{a3c}ਤ"] , ["" , "{0} ਕਿ.ਮੀ." , "" , "" , "" , "{0} ਕਿ.ਮੀ."] , ["" , "{0} ਮੀ." , "" , "" , "" , "{0} ਮੀ."] , ["" , "{0} ਸ\u{a48}\u{a02}.ਮੀ." , "" , "" , "" , "{0} ਸ\u{a48}\u{a02}.ਮੀ."] , ["" , "{0} ਮਿ.ਮੀ." , "" , "" , "" , "{0} ਮਿ.ਮੀ."] , ["" , "{0} ਕਿ.ਗ\u{a4d}ਰਾ." , "" , "" , "" , "{0} ਕਿ.ਗ\u{a4d}ਰਾ."] , ["" , "{0} ਗ\u{a4d}ਰਾ." , "" , "" , "" , "{0} ਗ\u{a4d}ਰਾ."] , ["" , "{0} ਕਿ.ਪ\u{a4d}ਰ.ਘ\u{a70}." , "" , "" , "" , "{0} ਕਿ.ਪ\u{a4d}ਰ.ਘ\u{a70}."] , ["" , "{0} ਮੀ./ਸ." , "" , "" , "" , "{0} ਮੀ./ਸ."] , ["" , "{0} ਮੀ.ਪ\u{a4d}ਰ.ਘ\u{a70}." , "" , "" , "" , "{0} ਮੀ.ਪ\u{a4d}ਰ.ਘ\u{a70}."] , ["" , "{0}ਸ\u{a48}\u{a02}.ਮੀ." , "" , "" , "" , "{0}ਸ\u{a48}\u{a02}.ਮੀ."] , ["" , "{0}ਮੀ/ਸ" , "" , "" , "" , "{0}ਮੀ/ਸ"] , ["" , "{0} kilometr" , "" , "{0} kilometry" , "{0} kilometrów" , "{0} kilometra"] , ["" , "{0} metr" , "" , "{0} metry" , "{0} metrów" , "{0} metra"] , ["" , "{0} centymetr" , "" , "{0} centymetry" , "{0} centymetrów" , "{0} centymetra"] , ["" , "{0} milimetr" , "" , "{0} milimetry" , "{0} milimetrów" , "{0} milimetra"] , ["" , "{0} mila" , "" , "{0} mile" , "{0} mil" , "{0} mili"] , ["" , "{0} jard" , "" , "{0} jardy" , "{0} jardów" , "{0} jarda"] , ["" , "{0} stopa" , "" , "{0} stopy" , "{0} stóp" , "{0} stopy"] , ["" , "{0} cal" , "" , "{0} cale" , "{0} cali" , "{0} cala"] , ["" , "{0} kilogram" , "" , "{0} kilogramy" , "{0} kilogramów" , "{0} kilograma"] , ["" , "{0} gram" , "" , "{0} gramy" , "{0} gramów" , "{0} grama"] , ["" , "{0} funt" , "" , "{0} funty" , "{0} funtów" , "{0} funta"] , ["" , "{0} uncja" , "" , "{0} uncje" , "{0} uncji" , "{0} uncji"] , ["" , "{0} kilometr na godzinę" , "" , "{0} kilometry na godzinę" , "{0} kilometrów na godzinę" , "{0} kilometra na godzinę"] , ["" , "{0} metr na sekundę" , "" , "{0} metry na sekundę" , "{0} metrów na sekundę" , "{0} metra na sekundę"] , ["" , "{0} mila na godzinę" , "" , "{0} mile na godzinę" , "{0} mil na godzinę" , "{0} mili na godzinę"] , ["" , "{0} stopień Celsjusza" , "" , "{0} stopnie Celsjusza" , "{0} stopni Celsjusza" , "{0} stopnia Celsjusza"] , ["" , "{0} stopień Fahrenheita" , "" , "{0} stopnie Fahrenheita" , "{0} stopni Fahrenheita" , "{0} stopnia Fahrenheita"] , ["" , "{0} procent" , "" , "{0} procent" , "{0} procent" , "{0} procent"] , ["" , "{0} km/godz." , "" , "{0} km/godz." , "{0} km/godz." , "{0} km/godz."] , ["" , "{0} mila/h" , "" , "{0} mile/h" , "{0} mil/h" , "{0} mili/h"] , ["" , "{0} st. C" , "" , "{0} st. C" , "{0} st. C" , "{0} st. C"] , ["" , "{0} mph" , "" , "{0} mph" , "{0} mph" , "{0} mph"] , ["" , "{0} کيلومتر" , "" , "" , "" , "{0} کيلومتره"] , ["" , "{0} متر" , "" , "" , "" , "{0} متره"] , ["" , "{0} سنتي متر" , "" , "" , "" , "{0} سنتي متره"] , ["" , "{0} ملي متر" , "" , "" , "" , "{0} ملي متره"] , ["" , "{0} ميل" , "" , "" , "" , "{0} ميلونه"] , ["" , "{0} ګز" , "" , "" , "" , "{0} ګزه"] , ["" , "{0} فټ" , "" , "" , "" , "{0} فټه"] , ["" , "{0} انچ" , "" , "" , "" , "{0} انچې"] , ["" , "{0} کيلو ګرام" , "" , "" , "" , "{0} کيلو ګرامز"] , ["" , "{0} ګرام" , "" , "" , "" , "{0} ګرامز"] , ["" , "{0} پاونډ" , "" , "" , "" , "{0} پاونډز"] , ["" , "{0} اونس" , "" , "" , "" , "{0} اونسز"] , ["" , "{0} کيلو متر في ساعت" , "" , "" , "" , "{0} کيلو متر في ساعت"] , ["" , "{0} متر في ثانيه" , "" , "" , "" , "{0} متر في ثانيه"] , ["" , "{0} ميل في ساعت" , "" , "" , "" , "{0} ميل في ساعت"] , ["" , "{0} درجې سيلسيس" , "" , "" , "" , "{0} درجې سيلسيس"] , ["" , "{0} درجې فارنهايټ" , "" , "" , "" , "{0} درجې فارنهايټ"] , ["" , "{0} سلنه" , "" , "" , "" , "{0} سلنه"] , ["" , "{0} انچ" , "" , "" , "" , "{0} انچے"] , ["" , "{0} درجے سيلسيس" , "" , "" , "" , "{0} درجے سيلسيس"] , ["" , "{0} درجے فارنهايټ" , "" , "" , "" , "{0} درجے فارنهايټ"] , ["" , "{0} quilômetro" , "" , "" , "" , "{0} quilômetros"] , ["" , "{0} milha" , "" , "" , "" , "{0} milhas"] , ["" , "{0} jarda" , "" , "" , "" , "{0} jardas"] , ["" , "{0} polegada" , "" , "" , "" , "{0} polegadas"] , ["" , "{0} quilograma" , "" , "" , "" , "{0} quilogramas"] , ["" , "{0} grama" , "" , "" , "" , "{0} gramas"] , ["" , "{0} onça" , "" , "" , "" , "{0} onças"] , ["" , "{0} quilômetro por hora" , "" , "" , "" , "{0} quilômetros por hora"] , ["" , "{0} milha por hora" , "" , "" , "" , "{0} milhas por hora"] , ["" , "{0} por cento" , "" , "" , "" , "{0} por cento"] , ["" , "{0} pol." , "" , "" , "" , "{0} pol."] , ["" , "" , "" , "" , "" , "{0} kilómetro"] , ["" , "" , "" , "" , "" , "{0} metro"] , ["" , "" , "" , "" , "" , "{0} centímetro"] , ["" , "" , "" , "" , "" , "{0} milímetro"] , ["" , "" , "" , "" , "" , "{0} milla"] , ["" , "" , "" , "" , "" , "{0} yarda"] , ["" , "" , "" , "" , "" , "{0} pie"] , ["" , "" , "" , "" , "" , "{0} pulgada"] , ["" , "{0}\u{a0}kilogram" , "" , "" , "" , "{0}\u{a0}kilograms"] , ["" , "{0}\u{a0}gram" , "" , "" , "" , "{0}\u{a0}grams"] , ["" , "{0}\u{a0}kilometer per ura" , "" , "" , "" , "{0}\u{a0}kilometers per ura"] , ["" , "{0} grad celsius" , "" , "" , "" , "{0} grads celsius"] , ["" , "{0}\u{a0}°C" , "" , "" , "" , "{0}\u{a0}°C"] , ["" , "{0} kilometru" , "" , "{0} kilometri" , "" , "{0} de kilometri"] , ["" , "{0} metru" , "" , "{0} metri" , "" , "{0} de metri"] , ["" , "{0} centimetru" , "" , "{0} centimetri" , "" , "{0} de centimetri"] , ["" , "{0} milimetru" , "" , "{0} milimetri" , "" , "{0} de milimetri"] , ["" , "{0} milă" , "" , "{0} mile" , "" , "{0} de mile"] , ["" , "{0} iard" , "" , "{0} iarzi" , "" , "{0} de iarzi"] , ["" , "{0} picior" , "" , "{0} picioare" , "" , "{0} de picioare"] , ["" , "{0} inch" , "" , "{0} inchi" , "" , "{0} de inchi"] , ["" , "{0} kilogram" , "" , "{0} kilograme" , "" , "{0} de kilograme"] , ["" , "{0} gram" , "" , "{0} grame" , "" , "{0} de grame"] , ["" , "{0} livră" , "" , "{0} livre" , "" , "{0} de livre"] , ["" , "{0} uncie" , "" , "{0} uncii" , "" , "{0} de uncii"] , ["" , "{0} kilometru pe oră" , "" , "{0} kilometri pe oră" , "" , "{0} de kilometri pe oră"] , ["" , "{0} metru pe secundă" , "" , "{0} metri pe secundă" , "" , "{0} de metri pe secundă"] , ["" , "{0} milă pe oră" , "" , "{0} mile pe oră" , "" , "{0} de mile pe oră"] , ["" , "{0} grad Celsius" , "" , "{0} grade Celsius" , "" , "{0} de grade Celsius"] , ["" , "{0} grad Fahrenheit" , "" , "{0} grade Fahrenheit" , "" , "{0} de grade Fahrenheit"] , ["" , "{0} procent" , "" , "{0} procente" , "" , "{0} de procente"] , ["" , "{0} километр" , "" , "{0} километра" , "{0} километров" , "{0} километра"] , ["" , "{0} метр" , "" , "{0} метра" , "{0} метров" , "{0} метра"] , ["" , "{0} сантиметр" , "" , "{0} сантиметра" , "{0} сантиметров" , "{0} сантиметра"] , ["" , "{0} миллиметр" , "" , "{0} миллиметра" , "{0} миллиметров" , "{0} миллиметра"] , ["" , "{0} миля" , "" , "{0} мили" , "{0} миль" , "{0} мили"] , ["" , "{0} ярд" , "" , "{0} ярда" , "{0} ярдов" , "{0} ярда"] , ["" , "{0} фут" , "" , "{0} фута" , "{0} футов" , "{0} фута"] , ["" , "{0} дюйм" , "" , "{0} дюйма" , "{0} дюймов" , "{0} дюйма"] , ["" , "{0} килограмм" , "" , "{0} килограмма" , "{0} килограмм" , "{0} килограмма"] , ["" , "{0} грамм" , "" , "{0} грамма" , "{0} грамм" , "{0} грамма"] , ["" , "{0} фунт" , "" , "{0} фунта" , "{0} фунтов" , "{0} фунта"] , ["" , "{0} унция" , "" , "{0} унции" , "{0} унций" , "{0} унции"] , ["" , "{0} километр в час" , "" , "{0} километра в час" , "{0} километров в час" , "{0} километра в час"] , ["" , "{0} метр в секунду" , "" , "{0} метра в секунду" , "{0} метров в секунду" , "{0} метра в секунду"] , ["" , "{0} миля в час" , "" , "{0} мили в час" , "{0} миль в час" , "{0} мили в час"] , ["" , "{0} градус Цельсия" , "" , "{0} градуса Цельсия" , "{0} градусов Цельсия" , "{0} градуса Цельсия"] , ["" , "{0} градус Фаренгейта" , "" , "{0} градуса Фаренгейта" , "{0} градусов Фаренгейта" , "{0} градуса Фаренгейта"] , ["" , "{0} процент" , "" , "{0} процента" , "{0} процентов" , "{0} процента"] , ["" , "{0} ми" , "" , "{0} ми" , "{0} ми" , "{0} ми"] , ["" , "{0} ярд" , "" , "{0} ярд." , "{0} ярд." , "{0} ярд."] , ["" , "{0} фт" , "" , "{0} фт" , "{0} фт" , "{0} фт"] , ["" , "{0} дюйм" , "" , "{0} дюйм." , "{0} дюйм." , "{0} дюйм."] , ["" , "{0} фнт" , "" , "{0} фнт" , "{0} фнт" , "{0} фнт"] , ["" , "{0} км/ч" , "" , "{0} км/ч" , "{0} км/ч" , "{0} км/ч"] , ["" , "{0} ми/ч" , "" , "{0} ми/ч" , "{0} ми/ч" , "{0} ми/ч"] , ["" , "{0} дюйм." , "" , "{0} дюйм." , "{0} дюйм." , "{0} дюйм."] , ["" , "{0}°F" , "" , "{0} °F" , "{0}°F" , "{0}°F"] , ["" , "" , "" , "" , "" , "{0} км"] , ["" , "" , "" , "" , "" , "{0} миэтэрэ"] , ["" , "" , "" , "" , "" , "{0} см"] , ["" , "" , "" , "" , "" , "{0} мм"] , ["" , "" , "" , "" , "" , "{0} миилэ"] , ["" , "" , "" , "" , "" , "{0} киилэ"] , ["" , "" , "" , "" , "" , "{0} кыраам"] , ["" , "" , "" , "" , "" , "{0} Сиэлсий кыраадыһа"] , ["" , "" , "" , "" , "" , "{0} Фаренгейт кыраадыһа"] , ["" , "" , "" , "" , "" , "{0} м"] , ["" , "" , "" , "" , "" , "{0} кг"] , ["" , "" , "" , "" , "" , "{0} г"] , ["" , "{0} ڪلوميٽر" , "" , "" , "" , "{0} ڪلوميٽر"] , ["" , "{0} ميٽر" , "" , "" , "" , "{0} ميٽر"] , ["" , "{0} سينٽي ميٽر" , "" , "" , "" , "{0} سينٽي ميٽر"] , ["" , "{0} ملي ميٽر" , "" , "" , "" , "{0} ملي ميٽر"] , ["" , "{0} ميل" , "" , "" , "" , "{0} ميل"] , ["" , "{0} گز" , "" , "" , "" , "{0} گز"] , ["" , "{0} فوٽ" , "" , "" , "" , "{0} فوٽ"] , ["" , "{0} انچ" , "" , "" , "" , "{0} انچ"] , ["" , "{0} ڪلوگرام" , "" , "" , "" , "{0}ڪلوگرام\u{64e}"] , ["" , "{0} گرام" , "" , "" , "" , "{0} گرام\u{64e}"] , ["" , "{0} پائونڊ" , "" , "" , "" , "{0} پائونڊ"] , ["" , "{0} ڪلوميٽر في ڪلاڪ" , "" , "" , "" , "{0} ڪلوميٽر في ڪلاڪ"] , ["" , "{0} ميٽر في سيڪنڊ" , "" , "" , "" , "{0} ميٽر في سيڪنڊ"] , ["" , "{0} ميل في ڪلاڪ" , "" , "" , "" , "{0} ميل في ڪلاڪ"] , ["" , "{0} ڊگري سيلسيس" , "" , "" , "" , "{0} ڊگري سيلسيس"] , ["" , "{0} ڊگريز فارن هائيٽ" , "" , "" , "" , "{0} ڊگريز فارن هائيٽ"] , ["" , "{0} ڪلو گرام" , "" , "" , "" , "{0} kg"] , ["" , "{0}kg" , "" , "" , "" , "{0} kg"] , ["" , "{0} kilomehter" , "{0} kilomehtera" , "" , "" , "{0} kilomehtera"] , ["" , "{0} mehter" , "{0} mehtera" , "" , "" , "{0} mehtera"] , ["" , "{0} sentimehter" , "{0} sentimehtera" , "" , "" , "{0} sentimehtera"] , ["" , "{0} millimehter" , "{0} millimehtera" , "" , "" , "{0} millimehtera"] , ["" , "{0} eangas miil" , "{0} eangas miila" , "" , "" , "{0} eangas miila"] , ["" , "{0} eangas yard" , "{0} eangas yard" , "" , "" , "{0} eangas yard"] , ["" , "{0} juolgi" , "{0} juolgi" , "" , "" , "{0} juolgi"] , ["" , "{0} bealgi" , "{0} bealgi" , "" , "" , "{0} bealgi"] , ["" , "{0} kilogram" , "{0} kilogram" , "" , "" , "{0} kilogram"] , ["" , "{0} gram" , "{0} gram" , "" , "" , "{0} gram"] , ["" , "{0} pauna" , "{0} pauna" , "" , "" , "{0} pauna"] , ["" , "{0} unssi" , "{0} unssi" , "" , "" , "{0} unssi"] , ["" , "{0} kilomehter kohti diibmu" , "{0} kilomehtera kohti diibmu" , "" , "" , "{0} kilomehtera kohti diibmu"] , ["" , "{0} mehter kohti sekunti" , "{0} mehtera kohti sekunti" , "" , "" , "{0} mehtera kohti sekunti"] , ["" , "{0} eangas miil kohti diibmu" , "{0} eangas miila kohti diibmu" , "" , "" , "{0} eangas miila kohti diibmu"] , ["" , "{0} grádat Celsius" , "{0} grádat Celsius" , "" , "" , "{0} grádat Celsius"] , ["" , "{0} grádat Fahrenheit" , "{0} grádat Fahrenheit" , "" , "" , "{0} grádat Fahrenheit"] , ["" , "{0} km" , "{0} km" , "" , "" , "{0} km"] , ["" , "{0} m" , "{0} m" , "" , "" , "{0} m"] , ["" , "{0} cm" , "{0} cm" , "" , "" , "{0} cm"] , ["" , "{0} mm" , "{0} mm" , "" , "" , "{0} mm"] , ["" , "{0} mi" , "{0} mi" , "" , "" , "{0} mi"] , ["" , "{0} yd" , "{0} yd" , "" , "" , "{0} yd"] , ["" , "{0} kg" , "{0} kg" , "" , "" , "{0} kg"] , ["" , "{0} g" , "{0} g" , "" , "" , "{0} g"] , ["" , "{0} km/h" , "{0} km/h" , "" , "" , "{0} km/h"] , ["" , "{0} m/s" , "{0} m/s" , "" , "" , "{0} m/s"] , ["" , "{0} mi/h" , "{0} mi/h" , "" , "" , "{0} mi/h"] , ["" , "{0}°C" , "{0}°C" , "" , "" , "{0}°C"] , ["" , "{0}°F" , "{0}°F" , "" , "" , "{0}°F"] , ["" , "{0}km" , "{0}km" , "" , "" , "{0}km"] , ["" , "{0}m" , "{0}m" , "" , "" , "{0}m"] , ["" , "{0}cm" , "{0}cm" , "" , "" , "{0}cm"] , ["" , "{0}mm" , "{0}mm" , "" , "" , "{0}mm"] , ["" , "{0}kg" , "{0}kg" , "" , "" , "{0}kg"] , ["" , "{0}g" , "{0}g" , "" , "" , "{0}g"] , ["" , "{0}km/h" , "{0}km/h" , "" , "" , "{0}km/h"] , ["" , "{0}m/s" , "{0}m/s" , "" , "" , "{0}m/s"] , ["" , "{0}°" , "{0}°" , "" , "" , "{0}°"] , ["" , "ක\u{dd2}ලෝම\u{dd3}ටර\u{dca} {0}" , "" , "" , "" , "ක\u{dd2}ලෝම\u{dd3}ටර\u{dca} {0}"] , ["" , "ම\u{dd3}ටර\u{dca} {0}" , "" , "" , "" , "ම\u{dd3}ටර\u{dca} {0}"] , ["" , "සෙන\u{dca}ට\u{dd2}ම\u{dd3}ටර\u{dca} {0}" , "" , "" , "" , "සෙන\u{dca}ට\u{dd2}ම\u{dd3}ටර\u{dca} {0}"] , ["" , "ම\u{dd2}ල\u{dd2}ම\u{dd3}ටර\u{dca} {0}" , "" , "" , "" , "ම\u{dd2}ල\u{dd2}ම\u{dd3}ටර\u{dca} {0}"] , ["" , "සැතප\u{dd4}ම\u{dca} {0}" , "" , "" , "" , "සැතප\u{dd4}ම\u{dca} {0}"] , ["" , "ය\u{dcf}ර {0}" , "" , "" , "" , "ය\u{dcf}ර {0}"] , ["" , "අඩ\u{dd2} {0}" , "" , "" , "" , "අඩ\u{dd2} {0}"] , ["" , "අඟල\u{dca} {0}" , "" , "" , "" , "අඟල\u{dca} {0}"] , ["" , "ක\u{dd2}ලෝග\u{dca}\u{200d}රෑම\u{dca} {0}" , "" , "" , "" , "ක\u{dd2}ලෝග\u{dca}\u{200d}රෑම\u{dca} {0}"] , ["" , "ග\u{dca}\u{200d}රෑම\u{dca} {0}" , "" , "" , "" , "ග\u{dca}\u{200d}රෑම\u{dca} {0}"] , ["" , "ර\u{dcf}ත\u{dca}තල\u{dca} {0}" , "" , "" , "" , "ර\u{dcf}ත\u{dca}තල\u{dca} {0}"] , ["" , "අව\u{dd4}න\u{dca}ස {0}" , "" , "" , "" , "අව\u{dd4}න\u{dca}ස {0}"] , ["" , "පැයට ක\u{dd2}ලෝම\u{dd3}ටර\u{dca} {0}" , "" , "" , "" , "පැයට ක\u{dd2}ලෝම\u{dd3}ටර\u{dca} {0}"] , ["" , "තත\u{dca}පරයට ම\u{dd3}ටර\u{dca} {0}" , "" , "" , "" , "තත\u{dca}පරයට ම\u{dd3}ටර\u{dca} {0}"] , ["" , "පැයට සැතප\u{dd4}ම\u{dca} {0}" , "" , "" , "" , "පැයට සැතප\u{dd4}ම\u{dca} {0}"] , ["" , "සෙල\u{dca}ස\u{dd2}යස\u{dca} අංශක {0}" , "" , "" , "" , "සෙල\u{dca}ස\u{dd2}යස\u{dca} අංශක {0}"] , ["" , "ෆැරන\u{dca}හය\u{dd2}ට\u{dca} අංශක {0}" , "" , "" , "" , "ෆැරන\u{dca}හය\u{dd2}ට\u{dca} අංශක {0}"] , ["" , "ප\u{dca}\u{200d}රත\u{dd2}ශතය {0}" , "" , "" , "" , "ප\u{dca}\u{200d}රත\u{dd2}ශතය {0}"] , ["" , "ක\u{dd2}.ම\u{dd3} {0}" , "" , "" , "" , "ක\u{dd2}.ම\u{dd3} {0}"] , ["" , "ම\u{dd3} {0}" , "" , "" , "" , "ම\u{dd3} {0}"] , ["" , "සෙ.ම\u{dd3} {0}" , "" , "" , "" , "සෙ.ම\u{dd3} {0}"] , ["" , "ම\u{dd2}ම\u{dd3} {0}" , "" , "" , "" , "ම\u{dd2}ම\u{dd3} {0}"] , ["" , "සැත {0}" , "" , "" , "" , "සැත {0}"] , ["" , "ක\u{dd2}.ග\u{dca}\u{200d}රෑ. {0}" , "" , "" , "" , "ක\u{dd2}.ග\u{dca}\u{200d}රෑ. {0}"] , ["" , "ග\u{dca}\u{200d}රෑ. {0}" , "" , "" , "" , "ග\u{dca}\u{200d}රෑ. {0}"] , ["" , "ර\u{dcf} {0}" , "" , "" , "" , "ර\u{dcf} {0}"] , ["" , "අව\u{dd4}ස {0}" , "" , "" , "" , "අව\u{dd4}ස {0}"] , ["" , "ක\u{dd2}ම\u{dd3}/පැ {0}" , "" , "" , "" , "ක\u{dd2}ම\u{dd3}/පැ {0}"] , ["" , "ම\u{dd3}/තත\u{dca} {0}" , "" , "" , "" , "ම\u{dd3}/තත\u{dca} {0}"] , ["" , "පැ/සැ {0}" , "" , "" , "" , "පැ/සැ {0}"] , ["" , "සෙල\u{dca}. {0}°" , "" , "" , "" , "සෙල\u{dca}. {0}°"] , ["" , "ෆැර. {0}°" , "" , "" , "" , "ෆැර. {0}°"] , ["" , "ක\u{dd2}ග\u{dca}\u{200d}රෑ{0}" , "" , "" , "" , "ක\u{dd2}ග\u{dca}\u{200d}රෑ{0}"] , ["" , "ර\u{dcf}{0}" , "" , "" , "" , "ර\u{dcf}{0}"] , ["" , "අව\u{dd4}ස{0}" , "" , "" , "" , "අව\u{dd4}ස{0}"] , ["" , "පැසැ {0}" , "" , "" , "" , "පැසැ {0}"] , ["" , "{0} kilometer" , "" , "{0} kilometre" , "{0} kilometra" , "{0} kilometrov"] , ["" , "{0} meter" , "" , "{0} metre" , "{0} metra" , "{0} metrov"] , ["" , "{0} centimeter" , "" , "{0} centimetre" , "{0} centimetra" , "{0} centimetrov"] , ["" , "{0} milimeter" , "" , "{0} milimetre" , "{0} milimetra" , "{0} milimetrov"] , ["" , "{0} míľa" , "" , "{0} míle" , "{0} míle" , "{0} míľ"] , ["" , "{0} yard" , "" , "{0} yardy" , "{0} yardu" , "{0} yardov"] , ["" , "{0} stopa" , "" , "{0} stopy" , "{0} stopy" , "{0} stôp"] , ["" , "{0} palec" , "" , "{0} palce" , "{0} palca" , "{0} palcov"] , ["" , "{0} kilogram" , "" , "{0} kilogramy" , "{0} kilogramu" , "{0} kilogramov"] , ["" , "{0} gram" , "" , "{0} gramy" , "{0} gramu" , "{0} gramov"] , ["" , "{0} libra" , "" , "{0} libry" , "{0} libry" , "{0} libier"] , ["" , "{0} unca" , "" , "{0} unce" , "{0} unce" , "{0} uncí"] , ["" , "{0} kilometer za hodinu" , "" , "{0} kilometre za hodinu" , "{0} kilometra za hodinu" , "{0} kilometrov za hodinu"] , ["" , "{0} meter za sekundu" , "" , "{0} metre za sekundu" , "{0} metra za sekundu" , "{0} metrov za sekundu"] , ["" , "{0} míľa za hodinu" , "" , "{0} míle za hodinu" , "{0} míle za hodinu" , "{0} míľ za hodinu"] , ["" , "{0} stupeň Celzia" , "" , "{0} stupne Celzia" , "{0} stupňa Celzia" , "{0} stupňov Celzia"] , ["" , "{0} stupeň Fahrenheita" , "" , "{0} stupne Fahrenheita" , "{0} stupňa Fahrenheita" , "{0} stupňov Fahrenheita"] , ["" , "{0} percento" , "" , "{0} percentá" , "{0} percenta" , "{0} percent"] , ["" , "{0} kilometer" , "{0} kilometra" , "{0} kilometri" , "" , "{0} kilometrov"] , ["" , "{0} meter" , "{0} metra" , "{0} metri" , "" , "{0} metrov"] , ["" , "{0} centimeter" , "{0} centimetra" , "{0} centimetri" , "" , "{0} centimetrov"] , ["" , "{0} milimeter" , "{0} milimetra" , "{0} milimetri" , "" , "{0} milimetrov"] , ["" , "{0} milja" , "{0} milji" , "{0} milje" , "" , "{0} milj"] , ["" , "{0} jard" , "{0} jarda" , "{0} jardi" , "" , "{0} jardov"] , ["" , "{0} čevelj" , "{0} čevlja" , "{0} čevlji" , "" , "{0} čevljev"] , ["" , "{0} palec" , "{0} palca" , "{0} palci" , "" , "{0} palcev"] , ["" , "{0} kilogram" , "{0} kilograma" , "{0} kilogrami" , "" , "{0} kilogramov"] , ["" , "{0} gram" , "{0} grama" , "{0} grami" , "" , "{0} gramov"] , ["" , "{0} funt" , "{0} funta" , "{0} funti" , "" , "{0} funtov"] , ["" , "{0} unča" , "{0} unči" , "{0} unče" , "" , "{0} unč"] , ["" , "{0} kilometer na uro" , "{0} kilometra na uro" , "{0} kilometri na uro" , "" , "{0} kilometrov na uro"] , ["" , "{0} meter na sekundo" , "{0} metra na sekundo" , "{0} metri na sekundo" , "" , "{0} metrov na sekundo"] , ["" , "{0} milja na uro" , "{0} milji na uro" , "{0} milje na uro" , "" , "{0} milj na uro"] , ["" , "{0} stopinja Celzija" , "{0} stopinji Celzija" , "{0} stopinje Celzija" , "" , "{0} stopinj Celzija"] , ["" , "{0} stopinja Farenheita" , "{0} stopinji Farenheita" , "{0} stopinje Farenheita" , "" , "{0} stopinj Farenheita"] , ["" , "{0} odstotek" , "{0} odstotka" , "{0} odstotki" , "" , "{0} odstotkov"] , ["" , "{0} mi/h" , "{0} mi/h" , "{0} mi/h" , "" , "{0} mi/h"] , ["" , "{0} °C" , "{0} °C" , "{0} °C" , "" , "{0} °C"] , ["" , "{0} °F" , "{0} °F" , "{0} °F" , "" , "{0} °F"] , ["" , "{0} °" , "{0} °" , "{0} °" , "" , "{0} °"] , ["" , "{0} kiilo mitir" , "" , "" , "" , "{0} kiilo mitir"] , ["" , "{0} mitir" , "" , "" , "" , "{0} mitir"] , ["" , "{0} sentimitir" , "" , "" , "" , "{0} sentimitir"] , ["" , "{0} milimitir" , "" , "" , "" , "{0} milimitir"] , ["" , "{0} meyl" , "" , "" , "" , "{0} meyl"] , ["" , "{0} yaardi" , "" , "" , "" , "{0} yaardi"] , ["" , "Fuudh" , "" , "" , "" , "{0} fiit"] , ["" , "Injis" , "" , "" , "" , "{0} injis"] , ["" , "{0} kiilogaraam" , "" , "" , "" , "{0} kiilogaraam"] , ["" , "{0} garaam" , "" , "" , "" , "{0} garaam"] , ["" , "{0} bownd" , "" , "" , "" , "{0} bownd"] , ["" , "{0} ownis" , "" , "" , "" , "{0} ownis"] , ["" , "{0} kiilomitir saacadiiba" , "" , "" , "" , "{0} kiilomitir saacadiiba"] , ["" , "mitir ilbiriqsigiiba" , "" , "" , "" , "{0} mitir ilbiriqsigiiba"] , ["" , "{0} meyl saacadiiba" , "" , "" , "" , "{0} meyl saacadiiba"] , ["" , "{0} degrii Selsiyaas" , "" , "" , "" , "{0} degrii Selsiyaas"] , ["" , "{0} degrii Faahrenheyt" , "" , "" , "" , "{0} degrii Faahrenheyt"] , ["" , "boqolkiiba {0}" , "" , "" , "" , "boqolkiiba {0}"] , ["" , "{0} my" , "" , "" , "" , "{0} my"] , ["" , "{0} bw" , "" , "" , "" , "{0} bw"] , ["" , "{0} ow" , "" , "" , "" , "{0} ow"] , ["" , "{0} km/s" , "" , "" , "" , "{0} km/s"] , ["" , "{0} m/i" , "" , "" , "" , "{0} m/i"] , ["" , "{0} my/s" , "" , "" , "" , "{0} my/s"] , ["" , "{0} kilometër" , "" , "" , "" , "{0} kilometra"] , ["" , "{0} metër" , "" , "" , "" , "{0} metra"] , ["" , "{0} centimetër" , "" , "" , "" , "{0} centimetra"] , ["" , "{0} milimetër" , "" , "" , "" , "{0} milimetra"] , ["" , "{0} milje" , "" , "" , "" , "{0} milje"] , ["" , "{0} jard" , "" , "" , "" , "{0} jardë"] , ["" , "{0} këmbë" , "" , "" , "" , "{0} këmbë"] , ["" , "{0} inç" , "" , "" , "" , "{0} inç"] , ["" , "{0} kilogram" , "" , "" , "" , "{0} kilogramë"] , ["" , "{0} gram" , "" , "" , "" , "{0} gramë"] , ["" , "{0} paund" , "" , "" , "" , "{0} paund"] , ["" , "{0} ons" , "" , "" , "" , "{0} onsë"] , ["" , "{0} kilomentër në orë" , "" , "" , "" , "{0} kilometra në orë"] , ["" , "{0} metër në sekondë" , "" , "" , "" , "{0} metra në sekondë"] , ["" , "{0} milje në orë" , "" , "" , "" , "{0} milje në orë"] , ["" , "{0} gradë Celsius" , "" , "" , "" , "{0} gradë Celsius"] , ["" , "{0} gradë Farenhait" , "" , "" , "" , "{0} gradë Farenhait"] , ["" , "{0} përqind" , "" , "" , "" , "{0} përqind"] , ["" , "{0} km/orë" , "" , "" , "" , "{0} km/orë"] , ["" , "{0} gradë C" , "" , "" , "" , "{0} gradë C"] , ["" , "{0} стопа" , "" , "{0} стопе" , "" , "{0} стопа"] , ["" , "{0} инч" , "" , "{0} инча" , "" , "{0} инча"] , ["" , "{0} килограм" , "" , "{0} килограма" , "" , "{0} килограма"] , ["" , "{0} грам" , "" , "{0} грама" , "" , "{0} грама"] , ["" , "{0} фунта" , "" , "{0} фунте" , "" , "{0} фунти"] , ["" , "{0} унца" , "" , "{0} унце" , "" , "{0} унци"] , ["" , "{0} километар на сат" , "" , "{0} километра на сат" , "" , "{0} километара на сат"] , ["" , "{0} метар у секунди" , "" , "{0} метра у секунди" , "" , "{0} метара у секунди"] , ["" , "{0} миља на сат" , "" , "{0} миље на сат" , "" , "{0} миља на сат"] , ["" , "{0} степен Целзијуса" , "" , "{0} степена Целзијуса" , "" , "{0} степени Целзијуса"] , ["" , "{0} степен Фаренхајта" , "" , "{0} степена Фаренхајта" , "" , "{0} степени Фаренхајта"] , ["" , "{0} проценат" , "" , "{0} процената" , "" , "{0} процената"] , ["" , "{0} јрд" , "" , "{0} јрд" , "" , "{0} јрд"] , ["" , "{0} procenat" , "" , "{0} procenata" , "" , "{0} procenata"] , ["" , "{0} jrd" , "" , "{0} jrd" , "" , "{0} jrd"] , ["" , "{0} mile" , "" , "" , "" , "{0} mile"] , ["" , "{0} tum" , "" , "" , "" , "{0} tum"] , ["" , "{0} uns" , "" , "" , "" , "{0} uns"] , ["" , "{0} kilometer per timme" , "" , "" , "" , "{0} kilometer per timme"] , ["" , "{0} mile per timme" , "" , "" , "" , "{0} mile per timme"] , ["" , "{0} grad Celsius" , "" , "" , "" , "{0} grader Celsius"] , ["" , "{0} grad Fahrenheit" , "" , "" , "" , "{0} grader Fahrenheit"] , ["" , "{0} yd" , "" , "" , "" , "{0}yd"] , ["" , "{0}fot" , "" , "" , "" , "{0}fot"] , ["" , "{0} tum" , "" , "" , "" , "{0}\\"] , ["" , "{0}uns" , "" , "" , "" , "{0}uns"] , ["" , "kilomita {0}" , "" , "" , "" , "kilomita {0}"] , ["" , "mita {0}" , "" , "" , "" , "mita {0}"] , ["" , "sentimita {0}" , "" , "" , "" , "sentimita {0}"] , ["" , "milimita {0}" , "" , "" , "" , "milimita {0}"] , ["" , "maili {0}" , "" , "" , "" , "maili {0}"] , ["" , "yadi {0}" , "" , "" , "" , "yadi {0}"] , ["" , "futi {0}" , "" , "" , "" , "futi {0}"] , ["" , "inchi {0}" , "" , "" , "" , "inchi {0}"] , ["" , "kilogramu {0}" , "" , "" , "" , "kilogramu {0}"] , ["" , "gramu {0}" , "" , "" , "" , "gramu {0}"] , ["" , "ratili {0}" , "" , "" , "" , "ratili {0}"] , ["" , "aunsi {0}" , "" , "" , "" , "aunsi {0}"] , ["" , "kilomita {0} kwa saa" , "" , "" , "" , "kilomita {0} kwa saa"] , ["" , "mita {0} kwa sekunde" , "" , "" , "" , "mita {0} kwa sekunde"] , ["" , "maili {0} kwa saa" , "" , "" , "" , "maili {0} kwa saa"] , ["" , "nyuzi {0}" , "" , "" , "" , "nyuzi {0}"] , ["" , "nyuzi za farenheiti {0}" , "" , "" , "" , "nyuzi za farenheiti {0}"] , ["" , "asilimia {0}" , "" , "" , "" , "asilimia {0}"] , ["" , "km {0}" , "" , "" , "" , "km {0}"] , ["" , "km {0}/saa" , "" , "" , "" , "km {0}/saa"] , ["" , "mph {0}" , "" , "" , "" , "mph {0}"] , ["" , "Ratili {0}" , "" , "" , "" , "Ratili {0}"] , ["" , "Aunsi {0}" , "" , "" , "" , "Aunsi {0}"] , ["" , "m {0}/s" , "" , "" , "" , "m {0}/s"] , ["" , "mi {0}/saa" , "" , "" , "" , "mi {0}/saa"] , ["" , "{0} கிலோம\u{bc0}ட\u{bcd}டர\u{bcd}" , "" , "" , "" , "{0} கிலோம\u{bc0}ட\u{bcd}டர\u{bcd}கள\u{bcd}"] , ["" , "{0} ம\u{bc0}ட\u{bcd}டர\u{bcd}" , "" , "" , "" , "{0} ம\u{bc0}ட\u{bcd}டர\u{bcd}கள\u{bcd}"] , ["" , "{0} சென\u{bcd}டிம\u{bc0}ட\u{bcd}டர\u{bcd}" , "" , "" , "" , "{0} சென\u{bcd}டிம\u{bc0}ட\u{bcd}டர\u{bcd}கள\u{bcd}"] , ["" , "{0} மில\u{bcd}லிம\u{bc0}ட\u{bcd}டர\u{bcd}" , "" , "" , "" , "{0} மில\u{bcd}லிம\u{bc0}ட\u{bcd}டர\u{bcd}கள\u{bcd}"] , ["" , "{0} மைல\u{bcd}" , "" , "" , "" , "{0} மைல\u{bcd}கள\u{bcd}"] , ["" , "{0} கெஜம\u{bcd}" , "" , "" , "" , "{0} கெஜம\u{bcd}"] , ["" , "{0} அடி" , "" , "" , "" , "{0} அடி"] , ["" , "{0} அங\u{bcd}குலம\u{bcd}" , "" , "" , "" , "{0} அங\u{bcd}குலங\u{bcd}கள\u{bcd}"] , ["" , "{0} கிலோகிர\u{bbe}ம\u{bcd}" , "" , "" , "" , "{0} கிலோகிர\u{bbe}ம\u{bcd}கள\u{bcd}"] , ["" , "{0} கிர\u{bbe}ம\u{bcd}" , "" , "" , "" , "{0} கிர\u{bbe}ம\u{bcd}கள\u{bcd}"] , ["" , "{0} பவுண\u{bcd}டு" , "" , "" , "" , "{0} பவுண\u{bcd}டுகள\u{bcd}"] , ["" , "{0} அவுன\u{bcd}ஸ\u{bcd}" , "" , "" , "" , "{0} அவுன\u{bcd}ஸ\u{bcd}கள\u{bcd}"] , ["" , "{0} கிலோம\u{bc0}ட\u{bcd}டர\u{bcd}/மணிநேரம\u{bcd}" , "" , "" , "" , "{0} கிலோம\u{bc0}ட\u{bcd}டர\u{bcd}கள\u{bcd}/மணிநேரம\u{bcd}"] , ["" , "{0} ம\u{bc0}ட\u{bcd}டர\u{bcd}/விந\u{bbe}டி" , "" , "" , "" , "{0} ம\u{bc0}ட\u{bcd}டர\u{bcd}கள\u{bcd}/விந\u{bbe}டி"] , ["" , "{0} மைல\u{bcd}/மணிநேரம\u{bcd}" , "" , "" , "" , "{0} மைல\u{bcd}கள\u{bcd}/மணிநேரம\u{bcd}"] , ["" , "{0} டிகிரி செல\u{bcd}சியஸ\u{bcd}" , "" , "" , "" , "{0} டிகிரி செல\u{bcd}சியஸ\u{bcd}"] , ["" , "{0} டிகிரி ஃப\u{bbe}ரன\u{bcd}ஹ\u{bc0}ட\u{bcd}" , "" , "" , "" , "{0} டிகிரி ஃப\u{bbe}ரன\u{bcd}ஹ\u{bc0}ட\u{bcd}"] , ["" , "{0} சதவ\u{bc0}தம\u{bcd}" , "" , "" , "" , "{0} சதவ\u{bc0}தம\u{bcd}"] , ["" , "{0} கி.ம\u{bc0}." , "" , "" , "" , "{0} கி.ம\u{bc0}."] , ["" , "{0} ம\u{bc0}." , "" , "" , "" , "{0} ம\u{bc0}."] , ["" , "{0} செ.ம\u{bc0}." , "" , "" , "" , "{0} செ.ம\u{bc0}."] , ["" , "{0} மி.ம\u{bc0}." , "" , "" , "" , "{0} மி.ம\u{bc0}."] , ["" , "{0} மை." , "" , "" , "" , "{0} மை."] , ["" , "{0} கெஜ." , "" , "" , "" , "{0} கெஜ."] , ["" , "{0} அங\u{bcd}." , "" , "" , "" , "{0} அங\u{bcd}."] , ["" , "{0} கி.கி." , "" , "" , "" , "{0} கி.கி."] , ["" , "{0} கி." , "" , "" , "" , "{0} கி."] , ["" , "{0} பவு." , "" , "" , "" , "{0} பவு."] , ["" , "{0} அவு." , "" , "" , "" , "{0} அவு."] , ["" , "{0} கி.ம\u{bc0}./ம.நே." , "" , "" , "" , "{0} கி.ம\u{bc0}./ம.நே."] , ["" , "{0} ம\u{bc0}/வி" , "" , "" , "" , "{0} ம\u{bc0}/வி"] , ["" , "{0} மை/ம.நே." , "" , "" , "" , "{0} மை/ம.நே."] , ["" , "{0}°செ." , "" , "" , "" , "{0}°செ."] , ["" , "{0}°ஃப\u{bbe}." , "" , "" , "" , "{0}°ஃப\u{bbe}."] , ["" , "{0}கி.ம\u{bc0}." , "" , "" , "" , "{0}கி.ம\u{bc0}."] , ["" , "{0}ம\u{bc0}." , "" , "" , "" , "{0}ம\u{bc0}."] , ["" , "{0}செ.ம\u{bc0}." , "" , "" , "" , "{0}செ.ம\u{bc0}."] , ["" , "{0}மி.ம\u{bc0}." , "" , "" , "" , "{0}மி.ம\u{bc0}."] , ["" , "{0}கி.கி." , "" , "" , "" , "{0}கி.கி."] , ["" , "{0}கி.ம\u{bc0}./ம." , "" , "" , "" , "{0}கி.ம\u{bc0}./ம."] , ["" , "{0} క\u{c3f}ల\u{c4b}మ\u{c40}టరు" , "" , "" , "" , "{0} క\u{c3f}ల\u{c4b}మ\u{c40}టర\u{c4d}లు"] , ["" , "{0} మ\u{c40}టరు" , "" , "" , "" , "{0} మ\u{c40}టర\u{c4d}లు"] , ["" , "{0} స\u{c46}ంట\u{c40}మ\u{c40}టరు" , "" , "" , "" , "{0} స\u{c46}ంట\u{c40}మ\u{c40}టర\u{c4d}లు"] , ["" , "{0} మ\u{c3f}ల\u{c4d}ల\u{c40}మ\u{c40}టరు" , "" , "" , "" , "{0} మ\u{c3f}ల\u{c4d}ల\u{c40}మ\u{c40}టర\u{c4d}లు"] , ["" , "{0} మ\u{c48}లు" , "" , "" , "" , "{0} మ\u{c48}ళ\u{c4d}లు"] , ["" , "{0} గజం" , "" , "" , "" , "{0} గజ\u{c3e}లు"] , ["" , "{0} అడుగు" , "" , "" , "" , "{0} అడుగులు"] , ["" , "{0} అంగుళం" , "" , "" , "" , "{0} అంగుళ\u{c3e}లు"] , ["" , "{0} క\u{c3f}ల\u{c4b}గ\u{c4d}ర\u{c3e}ము" , "" , "" , "" , "{0} క\u{c3f}ల\u{c4b}గ\u{c4d}ర\u{c3e}ములు"] , ["" , "{0} గ\u{c4d}ర\u{c3e}ము" , "" , "" , "" , "{0} గ\u{c4d}ర\u{c3e}ములు"] , ["" , "{0} ప\u{c4c}ండు" , "" , "" , "" , "{0} ప\u{c4c}ండ\u{c4d}లు"] , ["" , "{0} ఔన\u{c4d}సు" , "" , "" , "" , "{0} ఔన\u{c4d}సులు"] , ["" , "గంటకు {0} క\u{c3f}ల\u{c4b}మ\u{c40}టరు" , "" , "" , "" , "గంటకు {0} క\u{c3f}ల\u{c4b}మ\u{c40}టర\u{c4d}లు"] , ["" , "స\u{c46}కనుకు {0} మ\u{c40}టరు" , "" , "" , "" , "స\u{c46}కనుకు {0} మ\u{c40}టర\u{c4d}లు"] , ["" , "గంటకు {0} మ\u{c48}లు" , "" , "" , "" , "గంటకు {0} మ\u{c48}ళ\u{c4d}లు"] , ["" , "{0} డ\u{c3f}గ\u{c4d}ర\u{c40} స\u{c46}ల\u{c4d}స\u{c3f}యస\u{c4d}" , "" , "" , "" , "{0} డ\u{c3f}గ\u{c4d}ర\u{c40}ల స\u{c46}ల\u{c4d}స\u{c3f}యస\u{c4d}"] , ["" , "{0} డ\u{c3f}గ\u{c4d}ర\u{c40}ల ఫ\u{c3e}ర\u{c46}న\u{c4d}\u{200c}హ\u{c40}ట\u{c4d}" , "" , "" , "" , "{0} డ\u{c3f}గ\u{c4d}ర\u{c40}ల ఫ\u{c3e}ర\u{c46}న\u{c4d}\u{200c}హ\u{c40}ట\u{c4d}"] , ["" , "{0} శ\u{c3e}తం" , "" , "" , "" , "{0} శ\u{c3e}తం"] , ["" , "{0} క\u{c3f}.మ\u{c40}." , "" , "" , "" , "{0} క\u{c3f}.మ\u{c40}."] , ["" , "{0} మ\u{c40}." , "" , "" , "" , "{0} మ\u{c40}."] , ["" , "{0} స\u{c46}ం.మ\u{c40}." , "" , "" , "" , "{0} స\u{c46}ం.మ\u{c40}."] , ["" , "{0} మ\u{c3f}.మ\u{c40}." , "" , "" , "" , "{0} మ\u{c3f}.మ\u{c40}."] , ["" , "{0} మ\u{c48}." , "" , "" , "" , "{0} మ\u{c48}."] , ["" , "{0} గ." , "" , "" , "" , "{0} గ."] , ["" , "{0} అ." , "" , "" , "" , "{0} అ."] , ["" , "{0} అం." , "" , "" , "" , "{0} అం."] , ["" , "{0} క\u{c3f}.గ\u{c4d}ర\u{c3e}." , "" , "" , "" , "{0} క\u{c3f}.గ\u{c4d}ర\u{c3e}."] , ["" , "{0} గ\u{c4d}ర\u{c3e}." , "" , "" , "" , "{0} గ\u{c4d}ర\u{c3e}."] , ["" , "{0} ప\u{c4c}." , "" , "" , "" , "{0} ప\u{c4c}."] , ["" , "{0} ఔ." , "" , "" , "" , "{0} ఔ."] , ["" , "{0} క\u{c3f}.మ\u{c40}/గం" , "" , "" , "" , "{0} క\u{c3f}.మ\u{c40}/గం"] , ["" , "{0} మ\u{c40}/స\u{c46}" , "" , "" , "" , "{0} మ\u{c40}/స\u{c46}"] , ["" , "{0} మ\u{c48}/గం" , "" , "" , "" , "{0} మ\u{c48}/గం"] , ["" , "{0}°స\u{c46}ల\u{c4d}స\u{c3f}" , "" , "" , "" , "{0}°స\u{c46}ల\u{c4d}స\u{c3f}"] , ["" , "{0}°ఫ\u{c3e}" , "" , "" , "" , "{0}°ఫ\u{c3e}"] , ["" , "{0}క\u{c3f}.మ\u{c40}" , "" , "" , "" , "{0}క\u{c3f}.మ\u{c40}"] , ["" , "{0}మ\u{c40}" , "" , "" , "" , "{0}మ\u{c40}"] , ["" , "{0}స\u{c46}ం.మ\u{c40}" , "" , "" , "" , "{0}స\u{c46}ం.మ\u{c40}"] , ["" , "{0}మ\u{c3f}.మ\u{c40}" , "" , "" , "" , "{0}మ\u{c3f}.మ\u{c40}"] , ["" , "{0} మ\u{c48}" , "" , "" , "" , "{0} మ\u{c48}"] , ["" , "{0}క\u{c3f}.గ\u{c4d}ర\u{c3e}" , "" , "" , "" , "{0}క\u{c3f}.గ\u{c4d}ర\u{c3e}"] , ["" , "{0}గ\u{c4d}ర\u{c3e}" , "" , "" , "" , "{0}గ\u{c4d}ర\u{c3e}"] , ["" , "{0}క\u{c3f}.మ\u{c40}/గం" , "" , "" , "" , "{0}క\u{c3f}.మ\u{c40}/గం"] , ["" , "{0} మ\u{c48}/గం." , "" , "" , "" , "{0} మ\u{c48}/గం."] , ["" , "" , "" , "" , "" , "{0} ก\u{e34}โลเมตร"] , ["" , "" , "" , "" , "" , "{0} เมตร"] , ["" , "" , "" , "" , "" , "{0} เซนต\u{e34}เมตร"] , ["" , "" , "" , "" , "" , "{0} ม\u{e34}ลล\u{e34}เมตร"] , ["" , "" , "" , "" , "" , "{0} ไมล\u{e4c}"] , ["" , "" , "" , "" , "" , "{0} หลา"] , ["" , "" , "" , "" , "" , "{0} ฟ\u{e38}ต"] , ["" , "" , "" , "" , "" , "{0} น\u{e34}\u{e49}ว"] , ["" , "" , "" , "" , "" , "{0} ก\u{e34}โลกร\u{e31}ม"] , ["" , "" , "" , "" , "" , "{0} กร\u{e31}ม"] , ["" , "" , "" , "" , "" , "{0} ปอนด\u{e4c}"] , ["" , "" , "" , "" , "" , "{0} ออนซ\u{e4c}"] , ["" , "" , "" , "" , "" , "{0} ก\u{e34}โลเมตรต\u{e48}อช\u{e31}\u{e48}วโมง"] , ["" , "" , "" , "" , "" , "{0} เมตรต\u{e48}อว\u{e34}นาท\u{e35}"] , ["" , "" , "" , "" , "" , "{0} ไมล\u{e4c}ต\u{e48}อช\u{e31}\u{e48}วโมง"] , ["" , "" , "" , "" , "" , "{0} องศาเซลเซ\u{e35}ยส"] , ["" , "" , "" , "" , "" , "{0} องศาฟาเรนไฮต\u{e4c}"] , ["" , "" , "" , "" , "" , "{0}\u{a0}เปอร\u{e4c}เซ\u{e47}นต\u{e4c}"] , ["" , "" , "" , "" , "" , "{0} กม."] , ["" , "" , "" , "" , "" , "{0} ม."] , ["" , "" , "" , "" , "" , "{0} ซม."] , ["" , "" , "" , "" , "" , "{0} มม."] , ["" , "" , "" , "" , "" , "{0} กก."] , ["" , "" , "" , "" , "" , "{0} ก."] , ["" , "" , "" , "" , "" , "{0} กม./ชม."] , ["" , "" , "" , "" , "" , "{0} ม./ว\u{e34}"] , ["" , "" , "" , "" , "" , "{0} ไมล\u{e4c}/ชม."] , ["" , "" , "" , "" , "" , "{0}กม."] , ["" , "" , "" , "" , "" , "{0}ม."] , ["" , "" , "" , "" , "" , "{0}ซม."] , ["" , "" , "" , "" , "" , "{0}มม."] , ["" , "" , "" , "" , "" , "{0}ไมล\u{e4c}"] , ["" , "" , "" , "" , "" , "{0}หลา"] , ["" , "" , "" , "" , "" , "{0}กก."] , ["" , "" , "" , "" , "" , "{0}ก."] , ["" , "" , "" , "" , "" , "{0}ออนซ\u{e4c}"] , ["" , "" , "" , "" , "" , "{0}กม./ชม."] , ["" , "" , "" , "" , "" , "{0}ม./ว\u{e34}"] , ["" , "" , "" , "" , "" , "{0}ไมล\u{e4c}/ชม."] , ["" , "{0} ýard" , "" , "" , "" , "{0} ýard"] , ["" , "{0} dýuým" , "" , "" , "" , "{0} dýuým"] , ["" , "{0} unsiýa" , "" , "" , "" , "{0} unsiýa"] , ["" , "{0} kilometr/sagat" , "" , "" , "" , "{0} kilometr/sagat"] , ["" , "{0} metr/sekunt" , "" , "" , "" , "{0} metr/sekunt"] , ["" , "{0} mil/sagat" , "" , "" , "" , "{0} mil/sagat"] , ["" , "{0} Selsiý gradusy" , "" , "" , "" , "{0} Selsiý gradusy"] , ["" , "{0} Farengeýt gradusy" , "" , "" , "" , "{0} Farengeýt gradusy"] , ["" , "{0} göterim" , "" , "" , "" , "{0} göterim"] , ["" , "{0} ýd" , "" , "" , "" , "{0} ýd"] , ["" , "{0} dý" , "" , "" , "" , "{0} dý"] , ["" , "{0} uns." , "" , "" , "" , "{0} uns."] , ["" , "{0} km/sag" , "" , "" , "" , "{0} km/sag"] , ["" , "{0} mil/sag" , "" , "" , "" , "{0} mil/sag"] , ["" , "{0}sm" , "" , "" , "" , "{0}sm"] , ["" , "{0}km/sag" , "" , "" , "" , "{0}km/sag"] , ["" , "" , "" , "" , "" , "kilomita ʻe {0}"] , ["" , "" , "" , "" , "" , "mita ʻe {0}"] , ["" , "" , "" , "" , "" , "senitimita ʻe {0}"] , ["" , "" , "" , "" , "" , "milimita ʻe {0}"] , ["" , "" , "" , "" , "" , "maile ʻe {0}"] , ["" , "" , "" , "" , "" , "iate ʻe {0}"] , ["" , "" , "" , "" , "" , "fute ʻe {0}"] , ["" , "" , "" , "" , "" , "ʻinisi ʻe {0}"] , ["" , "" , "" , "" , "" , "kilokalami ʻe {0}"] , ["" , "" , "" , "" , "" , "kalami ʻe {0}"] , ["" , "" , "" , "" , "" , "pāuni ʻe {0}"] , ["" , "" , "" , "" , "" , "ʻaunisi ʻe {0}"] , ["" , "" , "" , "" , "" , "kilomita he houa ʻe {0}"] , ["" , "" , "" , "" , "" , "mita he sekoni ʻe {0}"] , ["" , "" , "" , "" , "" , "maile he houa ʻe {0}"] , ["" , "" , "" , "" , "" , "tikili selisiasi ʻe {0}"] , ["" , "" , "" , "" , "" , "tikili felenihaiti ʻe {0}"] , ["" , "" , "" , "" , "" , "peseti ʻe {0}"] , ["" , "" , "" , "" , "" , "km ʻe {0}"] , ["" , "" , "" , "" , "" , "m ʻe {0}"] , ["" , "" , "" , "" , "" , "sm ʻe {0}"] , ["" , "" , "" , "" , "" , "mm ʻe {0}"] , ["" , "" , "" , "" , "" , "mi ʻe {0}"] , ["" , "" , "" , "" , "" , "it ʻe {0}"] , ["" , "" , "" , "" , "" , "ft ʻe {0}"] , ["" , "" , "" , "" , "" , "in ʻe {0}"] , ["" , "" , "" , "" , "" , "kk ʻe {0}"] , ["" , "" , "" , "" , "" , "k ʻe {0}"] , ["" , "" , "" , "" , "" , "pāu ʻe {0}"] , ["" , "" , "" , "" , "" , "ʻau ʻe {0}"] , ["" , "" , "" , "" , "" , "km/h ʻe {0}"] , ["" , "" , "" , "" , "" , "m/s ʻe {0}"] , ["" , "" , "" , "" , "" , "mi/h ʻe {0}"] , ["" , "" , "" , "" , "" , "°S ʻe {0}"] , ["" , "" , "" , "" , "" , "°F ʻe {0}"] , ["" , "" , "" , "" , "" , "% ʻe {0}"] , ["" , "" , "" , "" , "" , "{0} sm"] , ["" , "" , "" , "" , "" , "{0} it"] , ["" , "" , "" , "" , "" , "{0} kk"] , ["" , "" , "" , "" , "" , "{0} k"] , ["" , "" , "" , "" , "" , "{0} pāu"] , ["" , "" , "" , "" , "" , "{0} ʻau"] , ["" , "" , "" , "" , "" , "{0}°S"] , ["" , "{0} kilometre" , "" , "" , "" , "{0} kilometre"] , ["" , "{0} metre" , "" , "" , "" , "{0} metre"] , ["" , "{0} santimetre" , "" , "" , "" , "{0} santimetre"] , ["" , "{0} milimetre" , "" , "" , "" , "{0} milimetre"] , ["" , "{0} fit" , "" , "" , "" , "{0} fit"] , ["" , "{0} libre" , "" , "" , "" , "{0} libre"] , ["" , "{0} ons" , "" , "" , "" , "{0} ons"] , ["" , "{0} kilometre/saat" , "" , "" , "" , "{0} kilometre/saat"] , ["" , "{0} metre/saniye" , "" , "" , "" , "{0} metre/saniye"] , ["" , "{0} santigrat derece" , "" , "" , "" , "{0} santigrat derece"] , ["" , "{0} fahrenhayt derece" , "" , "" , "" , "{0} fahrenhayt derece"] , ["" , "yüzde {0}" , "" , "" , "" , "yüzde {0}"] , ["" , "{0} km/sa" , "" , "" , "" , "{0} km/sa"] , ["" , "{0} m/sn" , "" , "" , "" , "{0} m/sn"] , ["" , "{0} mil/sa" , "" , "" , "" , "{0} mil/sa"] , ["" , "{0} °C" , "" , "" , "" , "{0}°C"] , ["" , "{0} °F" , "" , "" , "" , "{0}°F"] , ["" , "{0}°C" , "" , "" , "" , "{0} °C"] , ["" , "{0} كىلومېتىر" , "" , "" , "" , "{0} كىلومېتىر"] , ["" , "{0} مېتىر" , "" , "" , "" , "{0} مېتىر"] , ["" , "{0} سانتىمېتىر" , "" , "" , "" , "{0} سانتىمېتىر"] , ["" , "{0} مىللىمېتىر" , "" , "" , "" , "{0} مىللىمېتىر"] , ["" , "{0} مىل" , "" , "" , "" , "{0} مىل"] , ["" , "{0} يارد" , "" , "" , "" , "{0} يارد"] , ["" , "{0} فۇت" , "" , "" , "" , "{0} فۇت"] , ["" , "{0} ديۇيم" , "" , "" , "" , "{0} ديۇيم"] , ["" , "{0} كىلوگرام" , "" , "" , "" , "{0} كىلوگرام"] , ["" , "{0} گرام" , "" , "" , "" , "{0} گرام"] , ["" , "{0} قاداق" , "" , "" , "" , "{0} قاداق"] , ["" , "{0} ئۇنسىيە" , "" , "" , "" , "{0} ئۇنسىيە"] , ["" , "{0} كىلومېتىر ھەر سائەت" , "" , "" , "" , "{0} كىلومېتىر ھەر سائەت"] , ["" , "{0} مېتىر ھەر سېكۇنت" , "" , "" , "" , "{0} مېتىر ھەر سېكۇنت"] , ["" , "{0} مىل ھەر سائەت" , "" , "" , "" , "{0} مىل ھەر سائەت"] , ["" , "{0} سېلسىيە گرادۇس" , "" , "" , "" , "{0} سېلسىيە گرادۇس"] , ["" , "{0} فارېنگېيت گرادۇس" , "" , "" , "" , "{0} فارېنگېيت گرادۇس"] , ["" , "{0} پۇت" , "" , "" , "" , "{0} پۇت"] , ["" , "{0} ئىنگلىز چىسى" , "" , "" , "" , "{0} ئىنگلىز چىسى"] , ["" , "{0} سائەت/كىلومېتىر" , "" , "" , "" , "{0} سائەت/كىلومېتىر"] , ["" , "{0} سېكۇنت/مېتىر" , "" , "" , "" , "{0} سېكۇنت/مېتىر"] , ["" , "{0} سائەت/مىل" , "" , "" , "" , "{0} سائەت/مىل"] , ["" , "{0} кілометр" , "" , "{0} кілометри" , "{0} кілометрів" , "{0} кілометра"] , ["" , "{0} метр" , "" , "{0} метри" , "{0} метрів" , "{0} метра"] , ["" , "{0} сантиметр" , "" , "{0} сантиметри" , "{0} сантиметрів" , "{0} сантиметра"] , ["" , "{0} міліметр" , "" , "{0} міліметри" , "{0} міліметрів" , "{0} міліметра"] , ["" , "{0} миля" , "" , "{0} милі" , "{0} миль" , "{0} милі"] , ["" , "{0} ярд" , "" , "{0} ярди" , "{0} ярдів" , "{0} ярда"] , ["" , "{0} фут" , "" , "{0} фути" , "{0} футів" , "{0} фута"] , ["" , "{0} дюйм" , "" , "{0} дюйми" , "{0} дюймів" , "{0} дюйма"] , ["" , "{0} кілограм" , "" , "{0} кілограми" , "{0} кілограмів" , "{0} кілограма"] , ["" , "{0} грам" , "" , "{0} грами" , "{0} грамів" , "{0} грама"] , ["" , "{0} фунт" , "" , "{0} фунти" , "{0} фунтів" , "{0} фунта"] , ["" , "{0} унція" , "" , "{0} унції" , "{0} унцій" , "{0} унції"] , ["" , "{0} кілометр на годину" , "" , "{0} кілометри на годину" , "{0} кілометрів на годину" , "{0} кілометра на годину"] , ["" , "{0} метр на секунду" , "" , "{0} метри на секунду" , "{0} метрів на секунду" , "{0} метра на секунду"] , ["" , "{0} миля на годину" , "" , "{0} милі на годину" , "{0} миль на годину" , "{0} милі на годину"] , ["" , "{0} градус Цельсія" , "" , "{0} градуси Цельсія" , "{0} градусів Цельсія" , "{0} градуса Цельсія"] , ["" , "{0} градус Фаренгейта" , "" , "{0} градуси Фаренгейта" , "{0} градусів Фаренгейта" , "{0} градуса Фаренгейта"] , ["" , "{0} відсоток" , "" , "{0} відсотки" , "{0} відсотків" , "{0} відсотка"] , ["" , "{0} км/год" , "" , "{0} км/год" , "{0} км/год" , "{0} км/год"] , ["" , "{0} миля/год" , "" , "{0} милі/год" , "{0} миль/год" , "{0} милі/год"] , ["" , "{0}\u{a0}°C" , "" , "{0}\u{a0}°C" , "{0}\u{a0}°C" , "{0}\u{a0}°C"] , ["" , "{0}\u{a0}°F" , "" , "{0}\u{a0}°F" , "{0}\u{a0}°F" , "{0}\u{a0}°F"] , ["" , "{0}м" , "" , "{0}м" , "{0}м" , "{0}м"] , ["" , "{0} мл" , "" , "{0} мл" , "{0} мл" , "{0} мл"] , ["" , "{0} дм" , "" , "{0} дм" , "{0} дм" , "{0} дм"] , ["" , "{0}кг" , "" , "{0}кг" , "{0}кг" , "{0}кг"] , ["" , "{0}г" , "" , "{0}г" , "{0}г" , "{0}г"] , ["" , "{0} ун." , "" , "{0} ун." , "{0} ун." , "{0} ун."] , ["" , "{0} мл/год" , "" , "{0} мл/год" , "{0} мл/год" , "{0} мл/год"] , ["" , "{0} کلو میٹر" , "" , "" , "" , "{0} کلو میٹر"] , ["" , "{0} میٹر" , "" , "" , "" , "{0} میٹر"] , ["" , "{0} سینٹی میٹر" , "" , "" , "" , "{0} سینٹی میٹر"] , ["" , "{0} ملی میٹر" , "" , "" , "" , "{0} ملیمیٹر"] , ["" , "{0} میل" , "" , "" , "" , "{0} میل"] , ["" , "{0} یارڈ" , "" , "" , "" , "{0} یارڈ"] , ["" , "{0} فیٹ" , "" , "" , "" , "{0} فیٹ"] , ["" , "{0} کلو گرام" , "" , "" , "" , "{0} کلو گرام"] , ["" , "{0} پونڈ" , "" , "" , "" , "{0} پونڈ"] , ["" , "{0} کلومیٹر فی گھنٹہ" , "" , "" , "" , "{0} کلومیٹر فی گھنٹہ"] , ["" , "{0} میٹر فی سیکنڈ" , "" , "" , "" , "{0} میٹر فی سیکنڈ"] , ["" , "{0} میل فی گھنٹہ" , "" , "" , "" , "{0} میل فی گھنٹہ"] , ["" , "{0} ڈگری سیلسیس" , "" , "" , "" , "{0} ڈگری سیلسیس"] , ["" , "{0} ڈگری فارن ہائیٹ" , "" , "" , "" , "{0} ڈگری فارن ہائیٹ"] , ["" , "{0} فیصد" , "" , "" , "" , "{0} فیصد"] , ["" , "{0} ملیمیٹر" , "" , "" , "" , "{0} ملیمیٹر"] , ["" , "{0} انچ" , "" , "" , "" , "{0} in"] , ["" , "{0}\u{200e}°C" , "" , "" , "" , "{0}\u{200e}°C"] , ["" , "{0}\u{200e}°F" , "" , "" , "" , "{0}\u{200e}°F"] , ["" , "{0} ونس" , "" , "" , "" , "{0} ونس"] , ["" , "{0}\u{200e}°" , "" , "" , "" , "{0}\u{200e}°"] , ["" , "{0} duym" , "" , "" , "" , "{0} duym"] , ["" , "{0} km/soat" , "" , "" , "" , "{0} km/soat"] , ["" , "{0} metr/soniya" , "" , "" , "" , "{0} metr/soniya"] , ["" , "{0} mil/soat" , "" , "" , "" , "{0} mil/soat"] , ["" , "{0} Selsiy darajasi" , "" , "" , "" , "{0} Selsiy darajasi"] , ["" , "{0} Farengeyt darajasi" , "" , "" , "" , "{0} Farengeyt darajasi"] , ["" , "{0} foiz" , "" , "" , "" , "{0} foiz"] , ["" , "{0} dy" , "" , "" , "" , "{0} dy"] , ["" , "{0} milya" , "" , "" , "" , "{0} milya"] , ["" , "{0} dyuym" , "" , "" , "" , "{0} dyuym"] , ["" , "{0} untsiya" , "" , "" , "" , "{0} untsiya"] , ["" , "" , "" , "" , "" , "{0} kilômét"] , ["" , "" , "" , "" , "" , "{0} mét"] , ["" , "" , "" , "" , "" , "{0} xentimét"] , ["" , "" , "" , "" , "" , "{0} milimét"] , ["" , "" , "" , "" , "" , "{0} dặm"] , ["" , "" , "" , "" , "" , "{0} feet"] , ["" , "" , "" , "" , "" , "{0} inch"] , ["" , "" , "" , "" , "" , "{0} kilôgam"] , ["" , "" , "" , "" , "" , "{0} gam"] , ["" , "" , "" , "" , "" , "{0} pao"] , ["" , "" , "" , "" , "" , "{0} aoxơ"] , ["" , "" , "" , "" , "" , "{0} kilômét/giờ"] , ["" , "" , "" , "" , "" , "{0} mét/giây"] , ["" , "" , "" , "" , "" , "{0} dặm/giờ"] , ["" , "" , "" , "" , "" , "{0} độ C"] , ["" , "" , "" , "" , "" , "{0} độ F"] , ["" , "" , "" , "" , "" , "{0} 公里"] , ["" , "" , "" , "" , "" , "{0} 米"] , ["" , "" , "" , "" , "" , "{0} 厘米"] , ["" , "" , "" , "" , "" , "{0} 毫米"] , ["" , "" , "" , "" , "" , "{0} 英里"] , ["" , "" , "" , "" , "" , "{0} 碼"] , ["" , "" , "" , "" , "" , "{0} 英呎"] , ["" , "" , "" , "" , "" , "{0} 英吋"] , ["" , "" , "" , "" , "" , "{0} 公斤"] , ["" , "" , "" , "" , "" , "{0} 克"] , ["" , "" , "" , "" , "" , "{0} 磅"] , ["" , "" , "" , "" , "" , "{0} 安士"] , ["" , "" , "" , "" , "" , "每小時 {0} 公里"] , ["" , "" , "" , "" , "" , "每秒 {0} 米"] , ["" , "" , "" , "" , "" , "每小時 {0} 英里"] , ["" , "" , "" , "" , "" , "攝氏 {0} 度"] , ["" , "" , "" , "" , "" , "每小時{0}公里"] , ["" , "" , "" , "" , "" , "每秒{0}米"] , ["" , "" , "" , "" , "" , "每小時{0}英里"] , ["" , "" , "" , "" , "" , "{0} 公尺"] , ["" , "" , "" , "" , "" , "{0} 公分"] , ["" , "" , "" , "" , "" , "{0} 公厘"] , ["" , "" , "" , "" , "" , "{0} 码"] , ["" , "" , "" , "" , "" , "{0} 英尺"] , ["" , "" , "" , "" , "" , "{0} 英寸"] , ["" , "" , "" , "" , "" , "{0} 盎司"] , ["" , "" , "" , "" , "" , "每小时 {0} 公里"] , ["" , "" , "" , "" , "" , "每小时 {0} 英里"] , ["" , "" , "" , "" , "" , "摄氏 {0} 度"] , ["" , "" , "" , "" , "" , "华氏 {0} 度"] , ["" , "" , "" , "" , "" , "{0} 呎"] , ["" , "" , "" , "" , "" , "{0} 吋"] , ["" , "" , "" , "" , "" , "每小时{0}公里"] , ["" , "" , "" , "" , "" , "每小时{0}英里"] , ["" , "" , "" , "" , "" , "{0}公里/小时"] , ["" , "" , "" , "" , "" , "{0}英里/小时"] , ["" , "" , "" , "" , "" , "{0}公里"] , ["" , "" , "" , "" , "" , "{0}米"] , ["" , "" , "" , "" , "" , "{0}厘米"] , ["" , "" , "" , "" , "" , "{0}毫米"] , ["" , "" , "" , "" , "" , "{0}英里"] , ["" , "" , "" , "" , "" , "{0}码"] , ["" , "" , "" , "" , "" , "{0}英尺"] , ["" , "" , "" , "" , "" , "{0}英寸"] , ["" , "" , "" , "" , "" , "{0}千克"] , ["" , "" , "" , "" , "" , "{0}克"] , ["" , "" , "" , "" , "" , "{0}磅"] , ["" , "" , "" , "" , "" , "{0}盎司"] , ["" , "" , "" , "" , "" , "{0}摄氏度"] , ["" , "" , "" , "" , "" , "{0}华氏度"] , ["" , "" , "" , "" , "" , "{0} 公釐"] , ["" , "" , "" , "" , "" , "每秒 {0} 公尺"] , ["" , "" , "" , "" , "" , "{0} 公里/小時"] , ["" , "" , "" , "" , "" , "{0} 公尺/秒"] , ["" , "" , "" , "" , "" , "{0} 英里/小時"] , ["" , "" , "" , "" , "" , "{0}公尺"] , ["" , "" , "" , "" , "" , "{0}公分"] , ["" , "" , "" , "" , "" , "{0}公釐"] , ["" , "" , "" , "" , "" , "{0}碼"] , ["" , "" , "" , "" , "" , "{0}呎"] , ["" , "" , "" , "" , "" , "{0}吋"] , ["" , "" , "" , "" , "" , "{0}公里/小時"] , ["" , "" , "" , "" , "" , "{0}公尺/秒"] , ["" , "" , "" , "" , "" , "{0}英里/小時"] , ["" , "" , "" , "" , "" , "{0} 米/秒"] , ["" , "" , "" , "" , "" , "{0} 哩"] , ["" , "" , "" , "" , "" , "{0} 公里每小時"] , ["" , "" , "" , "" , "" , "{0} 英里每小時"] , ["" , "" , "" , "" , "" , "{0}哩"] , ["" , "" , "" , "" , "" , "{0}kph"] , ["" , "{0}kph" , "" , "" , "" , "{0} km/h"]] ;
+#[doc = r" Indices into `PATTERNS`, ordered by width, then unit."]
+static TABLES: [[u16; 54]; 134] = [
+    [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 4, 22, 23, 7,
+        24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 18, 19, 20, 21, 34, 35, 36, 37, 38, 39, 40, 41, 28,
+        42, 43, 31, 44, 33,
+    ],
+    [
+        45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49,
+        50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+        55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 67,
+        68, 69, 70, 85, 86, 73, 74, 87, 88, 89, 90, 91, 33, 81, 82, 83, 84, 67, 68, 69, 70, 85, 86,
+        73, 74, 87, 88, 89, 44, 91, 33,
+    ],
+    [
+        92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 93,
+        111, 112, 113, 97, 98, 99, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 110, 124, 111,
+        112, 125, 126, 127, 99, 128, 129, 116, 117, 118, 119, 120, 121, 122, 123,
+    ],
+    [
+        130, 93, 131, 132, 96, 133, 134, 135, 136, 101, 137, 103, 138, 139, 140, 107, 108, 109,
+        110, 93, 111, 112, 141, 133, 134, 135, 114, 101, 142, 117, 118, 119, 143, 121, 122, 123,
+        110, 124, 111, 112, 125, 144, 134, 99, 114, 145, 142, 117, 118, 119, 120, 121, 122, 123,
+    ],
+    [
+        146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163,
+        164, 165, 166, 167, 168, 151, 169, 170, 171, 172, 156, 157, 173, 174, 175, 176, 177, 33,
+        164, 165, 166, 167, 168, 151, 169, 170, 171, 172, 156, 157, 28, 174, 175, 176, 177, 33,
+    ],
+    [
+        178, 179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192, 193, 194, 62,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 199, 200, 28, 201, 202, 203, 204, 62, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 40, 212, 213, 214, 43, 203, 204, 62,
+    ],
+    [
+        215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232,
+        18, 19, 233, 21, 219, 196, 197, 198, 234, 235, 236, 200, 237, 201, 229, 203, 204, 33, 18,
+        19, 233, 21, 219, 196, 197, 198, 234, 235, 236, 200, 237, 201, 229, 44, 204, 33,
+    ],
+    [
+        238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
+        256, 257, 258, 259, 242, 243, 244, 245, 246, 247, 248, 260, 250, 251, 252, 261, 262, 263,
+        256, 257, 258, 259, 242, 243, 244, 245, 246, 247, 248, 260, 250, 251, 252, 261, 262, 264,
+    ],
+    [
+        265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 203, 204, 33, 18, 19,
+        20, 21, 269, 270, 271, 284, 24, 25, 275, 285, 28, 201, 286, 203, 32, 33,
+    ],
+    [
+        287, 288, 289, 290, 150, 151, 152, 153, 291, 292, 293, 157, 294, 295, 296, 297, 298, 299,
+        300, 301, 302, 303, 150, 151, 152, 153, 304, 305, 306, 157, 307, 201, 283, 203, 204, 33,
+        300, 301, 302, 303, 150, 151, 152, 153, 304, 292, 293, 157, 308, 214, 43, 203, 204, 33,
+    ],
+    [
+        309, 310, 311, 312, 313, 314, 315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326,
+        327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344,
+        345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 342, 343, 360,
+    ],
+    [
+        361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378,
+        379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391, 392, 393, 394, 395, 396,
+        379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 397, 390, 391, 392, 393, 398, 395, 396,
+    ],
+    [
+        399, 400, 401, 402, 403, 404, 385, 386, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 379, 380,
+        381, 382, 383, 384, 385, 386, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 379, 380, 381, 382,
+        383, 384, 385, 386, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        405, 406, 407, 408, 182, 409, 410, 411, 412, 413, 414, 415, 416, 417, 192, 418, 419, 420,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 32, 421, 18, 19,
+        20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 204, 421,
+    ],
+    [
+        422, 423, 424, 425, 426, 427, 428, 429, 430, 431, 432, 433, 434, 435, 436, 437, 438, 62,
+        439, 440, 441, 442, 426, 427, 428, 429, 443, 444, 445, 446, 307, 201, 283, 203, 204, 62,
+        439, 440, 441, 442, 426, 427, 428, 429, 443, 431, 432, 446, 308, 214, 43, 203, 204, 62,
+    ],
+    [
+        447, 46, 448, 48, 49, 50, 449, 450, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 451, 46, 452,
+        48, 49, 50, 449, 453, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 451, 46, 454, 48, 49, 50,
+        449, 453, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        455, 456, 457, 458, 459, 460, 461, 462, 463, 464, 465, 466, 467, 468, 469, 470, 471, 472,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 203, 204, 33, 205, 206,
+        207, 208, 195, 196, 197, 198, 38, 25, 236, 200, 213, 201, 283, 203, 204, 33,
+    ],
+    [
+        473, 474, 475, 476, 477, 478, 479, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489, 490,
+        491, 492, 20, 493, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 203, 204, 33, 494,
+        495, 207, 496, 209, 210, 211, 37, 38, 39, 40, 212, 213, 214, 43, 203, 44, 33,
+    ],
+    [
+        497, 498, 499, 500, 501, 502, 503, 504, 505, 506, 507, 508, 509, 510, 511, 512, 513, 514,
+        515, 516, 517, 518, 519, 520, 521, 522, 523, 524, 525, 526, 527, 528, 529, 261, 262, 263,
+        515, 516, 517, 518, 519, 520, 530, 531, 523, 524, 525, 526, 527, 528, 529, 261, 262, 263,
+    ],
+    [
+        532, 533, 534, 535, 536, 537, 538, 539, 540, 541, 542, 543, 544, 545, 546, 547, 548, 549,
+        550, 551, 552, 553, 554, 537, 555, 539, 556, 557, 542, 543, 558, 559, 560, 561, 562, 563,
+        564, 565, 566, 567, 568, 569, 555, 570, 571, 572, 573, 574, 575, 576, 577, 578, 562, 563,
+    ],
+    [
+        0, 1, 579, 3, 580, 581, 582, 583, 8, 9, 584, 585, 586, 587, 588, 589, 590, 591, 18, 19, 20,
+        21, 219, 220, 582, 583, 24, 25, 236, 200, 592, 201, 283, 203, 204, 593, 18, 19, 20, 21,
+        219, 220, 582, 284, 24, 25, 584, 200, 594, 214, 283, 203, 204, 421,
+    ],
+    [
+        595, 596, 597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 610, 611, 612,
+        18, 19, 20, 21, 195, 196, 197, 613, 24, 25, 236, 200, 28, 201, 202, 31, 32, 421, 18, 19,
+        20, 21, 195, 196, 197, 613, 24, 25, 236, 200, 28, 201, 202, 31, 204, 421,
+    ],
+    [
+        595, 596, 597, 598, 599, 600, 614, 602, 603, 604, 605, 606, 607, 608, 609, 610, 611, 612,
+        18, 19, 20, 21, 195, 196, 197, 613, 24, 25, 236, 200, 28, 201, 202, 31, 32, 421, 18, 19,
+        20, 21, 195, 196, 197, 613, 24, 25, 236, 200, 28, 201, 202, 31, 204, 421,
+    ],
+    [
+        615, 616, 617, 618, 619, 620, 621, 622, 623, 624, 625, 626, 627, 628, 629, 630, 631, 632,
+        633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645, 646, 647, 648, 649, 650,
+        633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645, 646, 647, 648, 649, 650,
+    ],
+    [
+        651, 652, 653, 654, 655, 656, 657, 658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668,
+        669, 670, 671, 672, 673, 674, 675, 676, 677, 678, 679, 200, 680, 681, 682, 203, 204, 33,
+        669, 670, 671, 672, 195, 196, 197, 198, 24, 678, 679, 200, 683, 684, 685, 203, 204, 33,
+    ],
+    [
+        686, 687, 688, 689, 690, 691, 692, 693, 694, 413, 695, 585, 696, 697, 698, 699, 700, 701,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 203, 204, 33, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 40, 212, 213, 214, 43, 203, 44, 33,
+    ],
+    [
+        702, 406, 703, 704, 690, 691, 692, 693, 694, 413, 695, 585, 705, 706, 698, 699, 700, 420,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 203, 204, 33, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 213, 214, 43, 44, 204, 33,
+    ],
+    [
+        702, 406, 703, 704, 690, 691, 692, 693, 694, 413, 695, 585, 705, 706, 698, 699, 700, 420,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 29, 283, 203, 204, 33, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 28, 214, 43, 203, 204, 33,
+    ],
+    [
+        702, 406, 703, 704, 690, 691, 692, 693, 694, 413, 695, 585, 705, 706, 698, 699, 700, 420,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 203, 204, 33, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 213, 214, 43, 203, 44, 33,
+    ],
+    [
+        702, 406, 703, 704, 690, 691, 692, 693, 694, 413, 695, 585, 705, 706, 698, 699, 700, 701,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 31, 32, 33, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 213, 214, 43, 44, 204, 33,
+    ],
+    [
+        702, 406, 703, 704, 690, 691, 692, 693, 694, 413, 695, 585, 705, 706, 698, 699, 700, 420,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 708, 201, 283, 203, 204, 33, 205,
+        206, 207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 308, 214, 43, 44, 204, 33,
+    ],
+    [
+        709, 710, 711, 712, 713, 714, 184, 715, 716, 717, 718, 719, 720, 721, 722, 723, 724, 725,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 32, 726, 205, 206,
+        207, 208, 209, 210, 727, 728, 38, 39, 707, 212, 213, 214, 729, 203, 204, 33,
+    ],
+    [
+        709, 710, 711, 712, 713, 714, 184, 715, 716, 717, 718, 719, 720, 721, 722, 723, 724, 725,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 32, 726, 205, 206,
+        207, 208, 209, 210, 727, 728, 38, 39, 707, 212, 213, 214, 729, 203, 44, 33,
+    ],
+    [
+        730, 731, 732, 733, 734, 735, 736, 737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 32, 33, 18, 19, 20,
+        21, 734, 735, 736, 737, 24, 25, 236, 200, 28, 201, 202, 31, 32, 33,
+    ],
+    [
+        748, 749, 750, 751, 752, 753, 754, 755, 756, 757, 758, 759, 760, 761, 762, 763, 764, 765,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 759, 28, 201, 283, 31, 32, 766, 18, 19,
+        20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 31, 204, 766,
+    ],
+    [
+        767, 768, 769, 770, 771, 772, 773, 774, 775, 776, 777, 778, 779, 780, 781, 782, 783, 784,
+        18, 19, 785, 21, 771, 772, 773, 774, 775, 776, 777, 778, 786, 201, 787, 203, 204, 788, 205,
+        19, 207, 208, 209, 772, 727, 728, 38, 776, 707, 212, 786, 214, 43, 203, 204, 788,
+    ],
+    [
+        789, 790, 791, 792, 793, 794, 795, 796, 797, 798, 799, 800, 801, 802, 803, 804, 805, 806,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 32, 421, 205, 206,
+        207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 213, 214, 729, 44, 204, 421,
+    ],
+    [
+        807, 808, 809, 810, 811, 812, 813, 814, 815, 816, 817, 818, 819, 820, 821, 699, 700, 822,
+        18, 19, 20, 21, 195, 823, 197, 198, 24, 25, 199, 200, 708, 201, 283, 203, 204, 33, 205,
+        206, 207, 208, 209, 210, 211, 37, 38, 39, 40, 212, 308, 214, 43, 203, 204, 33,
+    ],
+    [
+        824, 825, 826, 827, 828, 691, 829, 830, 831, 832, 584, 833, 834, 835, 836, 837, 838, 839,
+        18, 19, 20, 21, 828, 196, 829, 840, 24, 25, 584, 833, 594, 201, 836, 203, 204, 421, 205,
+        206, 207, 208, 828, 196, 829, 840, 38, 39, 584, 833, 841, 214, 836, 44, 204, 421,
+    ],
+    [
+        842, 843, 844, 845, 846, 847, 848, 849, 850, 851, 852, 853, 854, 855, 856, 857, 858, 859,
+        860, 861, 862, 863, 864, 865, 866, 867, 868, 869, 870, 871, 872, 873, 874, 875, 876, 421,
+        205, 206, 207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 213, 214, 729, 203, 204, 421,
+    ],
+    [
+        877, 878, 879, 880, 881, 882, 883, 884, 885, 886, 887, 888, 889, 890, 891, 892, 893, 859,
+        18, 19, 20, 21, 195, 894, 895, 896, 24, 25, 236, 200, 28, 201, 202, 31, 32, 726, 205, 206,
+        207, 208, 209, 897, 898, 899, 38, 39, 707, 212, 213, 214, 729, 900, 204, 726,
+    ],
+    [
+        842, 843, 844, 845, 846, 847, 848, 849, 850, 851, 852, 853, 854, 855, 856, 857, 858, 859,
+        860, 861, 862, 863, 864, 865, 866, 867, 868, 901, 870, 871, 872, 873, 874, 875, 876, 421,
+        205, 206, 207, 208, 209, 210, 211, 37, 38, 39, 707, 212, 213, 214, 729, 203, 204, 421,
+    ],
+    [
+        0, 1, 2, 3, 4, 691, 902, 903, 8, 9, 236, 200, 904, 905, 906, 907, 908, 62, 18, 19, 233, 21,
+        195, 196, 197, 909, 24, 25, 236, 200, 28, 201, 202, 203, 204, 62, 18, 19, 20, 21, 195, 196,
+        197, 284, 24, 25, 236, 200, 28, 201, 202, 44, 204, 62,
+    ],
+    [
+        910, 911, 912, 913, 914, 915, 916, 917, 918, 919, 920, 921, 922, 923, 924, 925, 926, 927,
+        327, 328, 329, 330, 928, 929, 930, 931, 335, 336, 920, 921, 932, 340, 933, 342, 343, 360,
+        345, 346, 347, 348, 914, 934, 351, 352, 353, 354, 920, 921, 935, 358, 936, 342, 343, 360,
+    ],
+    [
+        937, 938, 939, 940, 941, 942, 943, 944, 945, 946, 947, 948, 949, 950, 951, 952, 953, 954,
+        633, 634, 635, 636, 955, 956, 957, 958, 641, 642, 643, 959, 645, 646, 960, 648, 649, 961,
+        962, 963, 964, 965, 966, 967, 968, 969, 970, 971, 972, 973, 974, 975, 976, 648, 649, 961,
+    ],
+    [
+        977, 710, 711, 712, 713, 978, 979, 980, 981, 717, 718, 719, 982, 721, 722, 983, 984, 421,
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 32, 421, 18, 19,
+        20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31, 204, 421,
+    ],
+    [
+        45, 46, 47, 48, 195, 196, 51, 52, 53, 54, 55, 56, 57, 58, 202, 60, 61, 62, 45, 46, 47, 48,
+        195, 196, 51, 52, 53, 54, 55, 56, 57, 58, 202, 60, 61, 62, 45, 46, 47, 48, 195, 196, 51,
+        52, 53, 54, 55, 56, 57, 58, 202, 60, 61, 62,
+    ],
+    [
+        985, 986, 987, 988, 989, 990, 991, 992, 993, 994, 995, 996, 997, 998, 999, 1000, 1001,
+        1002, 1003, 1004, 1005, 1006, 989, 990, 991, 992, 1007, 1008, 995, 996, 1009, 1010, 1011,
+        203, 204, 33, 1003, 1004, 1005, 1006, 989, 990, 1012, 1013, 1007, 1008, 995, 996, 1014,
+        1010, 1011, 203, 204, 33,
+    ],
+    [
+        1015, 1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023, 1024, 1025, 1026, 1027, 1028, 1029,
+        1030, 1031, 1032, 1033, 1034, 1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042, 1043, 1044,
+        1045, 1046, 1047, 1048, 1049, 33, 1050, 1051, 1052, 1053, 1054, 1055, 1056, 37, 1057, 1058,
+        40, 1059, 1060, 1061, 1062, 1063, 1064, 1065,
+    ],
+    [
+        45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49,
+        50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+        55, 56, 57, 58, 59, 203, 44, 62,
+    ],
+    [
+        1066, 1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 1075, 1076, 1077, 1078, 1079, 1080,
+        1081, 1082, 1083, 1084, 1085, 1086, 1087, 1088, 1071, 1089, 1090, 1091, 1092, 1093, 1094,
+        1095, 1096, 1097, 1098, 1099, 1100, 1084, 1101, 1102, 1103, 1070, 1071, 1072, 1073, 1104,
+        1105, 1093, 1094, 1095, 1106, 1097, 1098, 1099, 1100,
+    ],
+    [
+        1107, 1108, 1109, 1110, 1111, 1112, 1113, 1114, 1115, 1116, 1117, 1118, 1119, 1120, 1121,
+        1122, 1123, 1124, 1125, 1126, 1127, 1128, 1111, 1112, 1129, 1114, 1130, 1131, 1117, 1118,
+        1132, 1133, 1134, 1135, 1136, 33, 1137, 1126, 1127, 1138, 1111, 1112, 211, 37, 1139, 1131,
+        40, 1140, 1141, 1142, 1143, 1135, 204, 33,
+    ],
+    [
+        361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375, 1144, 1145,
+        1146, 379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 397, 390, 391, 392, 393, 1147,
+        1148, 1149, 379, 380, 381, 382, 383, 384, 1150, 1151, 387, 388, 397, 390, 391, 392, 393,
+        1147, 1148, 1149,
+    ],
+    [
+        1152, 1153, 1154, 1155, 619, 1156, 1157, 1158, 1159, 1160, 1161, 1162, 1163, 1164, 1165,
+        1166, 1167, 1168, 633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645, 646,
+        647, 648, 649, 650, 633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645, 646,
+        647, 648, 649, 650,
+    ],
+    [
+        1169, 1170, 1171, 1172, 1173, 220, 1174, 1175, 831, 832, 1176, 1177, 1178, 1179, 1180,
+        1181, 1182, 1183, 18, 19, 20, 21, 1184, 196, 1174, 1175, 24, 25, 236, 200, 28, 201, 283,
+        31, 32, 33, 18, 19, 20, 21, 1184, 196, 1174, 1175, 24, 25, 1185, 1186, 28, 201, 283, 31,
+        32, 33,
+    ],
+    [
+        1187, 1188, 1189, 1190, 1191, 1192, 1193, 1194, 1195, 1196, 1197, 1198, 1199, 1200, 1201,
+        1202, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 1214, 1197, 1215,
+        1216, 1217, 1218, 31, 204, 33, 1205, 1206, 1207, 1208, 1209, 1219, 211, 37, 1213, 1214, 40,
+        1220, 1216, 1221, 1222, 31, 204, 33,
+    ],
+    [
+        1223, 710, 1224, 1225, 1226, 691, 1227, 1228, 1229, 1230, 718, 1231, 1232, 1233, 1234,
+        1235, 724, 1236, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 203,
+        204, 33, 205, 206, 207, 208, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 203, 204,
+        33,
+    ],
+    [
+        1237, 1238, 1239, 1240, 1241, 1242, 1243, 1244, 1245, 1246, 1247, 1248, 1249, 1250, 1251,
+        1252, 1253, 1254, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 1255, 1256, 1257, 60, 61,
+        62, 45, 46, 47, 48, 49, 50, 1258, 1259, 53, 54, 1260, 56, 1261, 1256, 1257, 60, 1262, 62,
+    ],
+    [
+        1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 584, 1273, 1274, 1275, 1276,
+        1277, 1278, 1279, 18, 19, 20, 21, 1280, 196, 1269, 1281, 24, 25, 236, 200, 1282, 201, 1283,
+        203, 204, 1284, 18, 206, 207, 208, 1280, 196, 1269, 37, 24, 25, 1285, 1273, 1282, 201,
+        1286, 203, 204, 33,
+    ],
+    [
+        1287, 1288, 1289, 1290, 1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298, 1299, 1300, 1301,
+        1302, 1303, 1304, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 31,
+        32, 33, 205, 206, 207, 208, 209, 210, 727, 37, 38, 39, 707, 212, 213, 214, 729, 203, 204,
+        33,
+    ],
+    [
+        1305, 1306, 1307, 1308, 1309, 1310, 1311, 1312, 1313, 1314, 1315, 1316, 1317, 1318, 1319,
+        1320, 1321, 1322, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 1323, 60, 61, 62,
+        1324, 1325, 1326, 1327, 1328, 1329, 1258, 1259, 1330, 1331, 1332, 1333, 1334, 1335, 1336,
+        60, 61, 62,
+    ],
+    [
+        1237, 1238, 1239, 1240, 1241, 1242, 1243, 1244, 1245, 1246, 1337, 1338, 1339, 1340, 1341,
+        1342, 1343, 1254, 45, 46, 47, 48, 1241, 1242, 1243, 52, 53, 54, 1337, 1338, 1344, 1256,
+        1345, 60, 61, 62, 45, 46, 47, 48, 1241, 1242, 1243, 52, 53, 54, 1337, 1338, 1344, 1256,
+        1345, 60, 61, 62,
+    ],
+    [
+        1346, 1347, 1348, 1349, 1350, 1351, 1352, 1353, 1354, 1355, 1356, 1357, 1358, 1359, 1360,
+        1361, 1362, 1363, 1364, 1365, 1366, 1367, 1350, 1351, 1368, 1353, 1369, 1370, 1371, 1372,
+        1373, 1374, 1375, 203, 204, 33, 1364, 1376, 1366, 1367, 1350, 1351, 1368, 1377, 1378, 1379,
+        1371, 1372, 1373, 1374, 1375, 203, 204, 33,
+    ],
+    [
+        1380, 1381, 1382, 1383, 1384, 1385, 1386, 1387, 1388, 1389, 1390, 1391, 1392, 1393, 1394,
+        1395, 1396, 1397, 45, 46, 47, 48, 49, 50, 1386, 52, 53, 54, 55, 56, 57, 58, 59, 1398, 1399,
+        62, 45, 46, 47, 48, 49, 50, 1386, 52, 1330, 54, 55, 56, 57, 58, 59, 1398, 1399, 62,
+    ],
+    [
+        447, 1400, 448, 1401, 1402, 1403, 449, 450, 1404, 1405, 1406, 1407, 1408, 1409, 1410, 1411,
+        1412, 1413, 451, 1414, 452, 1415, 1402, 1403, 449, 450, 1416, 1417, 1406, 285, 1418, 1419,
+        1420, 203, 204, 33, 451, 1414, 452, 1415, 1402, 1403, 449, 450, 1416, 1417, 1406, 1407,
+        1418, 1419, 1420, 203, 44, 33,
+    ],
+    [
+        1421, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1430, 1431, 1432, 1433, 1434, 1435,
+        1436, 1437, 1438, 1439, 1440, 1441, 1424, 49, 50, 51, 52, 53, 54, 55, 56, 1442, 58, 1323,
+        60, 61, 62, 1439, 1440, 1441, 1443, 1444, 1445, 1258, 1259, 1330, 1331, 1260, 1446, 1442,
+        1447, 1448, 60, 61, 62,
+    ],
+    [
+        1449, 1450, 1451, 1452, 1453, 1454, 1455, 1456, 1457, 1458, 1459, 1460, 1461, 1462, 1463,
+        1464, 1465, 1466, 1467, 1468, 1469, 1470, 1471, 1472, 1473, 1474, 1475, 1476, 1477, 1478,
+        1479, 1480, 1481, 1482, 1483, 33, 1484, 1485, 1469, 1486, 1487, 1488, 1489, 1490, 1491,
+        1492, 40, 1493, 1479, 1494, 1495, 1482, 1483, 33,
+    ],
+    [
+        1496, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1505, 1506, 1507, 1508, 1509, 1510,
+        1511, 1512, 62, 1324, 1325, 1326, 1327, 1328, 1329, 1513, 1514, 1330, 1331, 1332, 1333,
+        1334, 1335, 1336, 60, 61, 62, 1324, 1325, 1326, 1327, 1328, 1329, 1258, 1259, 1330, 1331,
+        1332, 1333, 1334, 1335, 1515, 60, 61, 62,
+    ],
+    [
+        1516, 1517, 1518, 1519, 1520, 1521, 1522, 1523, 1524, 1525, 1526, 1527, 1528, 1529, 1530,
+        1531, 1532, 1533, 1534, 1535, 1536, 1537, 49, 50, 1522, 52, 1538, 1525, 55, 56, 1528, 1529,
+        1530, 1539, 1540, 62, 1541, 1542, 1543, 1537, 1328, 1329, 1544, 1514, 1545, 1546, 1260,
+        1547, 1528, 1548, 1549, 1550, 1262, 62,
+    ],
+    [
+        1551, 1552, 1553, 1554, 1555, 50, 1556, 1557, 1558, 1559, 1560, 1561, 1562, 1563, 1564,
+        1565, 1566, 62, 1567, 1568, 1569, 1570, 1571, 50, 1556, 1557, 1572, 1573, 1560, 1561, 1574,
+        1575, 1576, 1577, 1578, 62, 1579, 1580, 1581, 1582, 1571, 50, 1556, 1557, 1583, 1584, 1560,
+        1561, 1585, 1586, 1576, 1587, 1578, 62,
+    ],
+    [
+        45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 1588, 45, 46, 47, 48,
+        49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 1065, 45, 46, 47, 48, 49, 50, 51, 52,
+        53, 54, 55, 56, 57, 58, 59, 60, 61, 1065,
+    ],
+    [
+        447, 1400, 448, 1401, 1402, 1403, 449, 450, 1404, 1405, 1406, 1407, 1589, 1590, 1591, 1592,
+        1593, 1413, 451, 1414, 452, 1415, 1402, 1403, 449, 450, 1416, 1594, 1406, 285, 1595, 1596,
+        1597, 203, 204, 33, 451, 1598, 452, 1415, 1402, 1403, 449, 450, 1416, 1594, 40, 285, 1595,
+        1419, 1599, 44, 204, 33,
+    ],
+    [
+        595, 596, 597, 598, 1600, 600, 1601, 602, 603, 604, 1602, 1603, 1604, 1605, 1606, 610, 611,
+        62, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 203, 204, 62, 18,
+        19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 44, 204, 62,
+    ],
+    [
+        45, 46, 47, 48, 1607, 1608, 1609, 1610, 53, 54, 1611, 56, 57, 58, 59, 60, 61, 62, 45, 46,
+        47, 48, 1607, 1608, 1609, 1610, 53, 54, 1611, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48,
+        1612, 1608, 1609, 1259, 53, 54, 1260, 56, 57, 58, 59, 60, 1262, 62,
+    ],
+    [
+        1613, 1614, 1615, 1616, 1617, 1618, 1619, 1620, 1621, 1622, 1623, 1624, 1625, 1626, 1627,
+        1628, 1629, 1630, 1631, 1632, 1633, 1634, 1635, 1618, 1636, 1637, 1638, 1639, 1640, 1641,
+        1642, 1643, 1644, 1628, 1645, 62, 1631, 1632, 1633, 1634, 1635, 1618, 1636, 1637, 1646,
+        1639, 1640, 1641, 1642, 58, 1644, 1262, 61, 62,
+    ],
+    [
+        1647, 1648, 1649, 1650, 1651, 1652, 1653, 1654, 1655, 1656, 1657, 1658, 1659, 1660, 1661,
+        1662, 1663, 1664, 515, 516, 517, 518, 519, 520, 521, 522, 523, 524, 525, 526, 1665, 1666,
+        529, 1667, 1668, 263, 515, 516, 517, 518, 519, 520, 521, 522, 523, 524, 525, 526, 527, 528,
+        529, 1669, 1668, 263,
+    ],
+    [
+        1670, 1671, 1672, 1673, 1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682, 1683, 1684,
+        1685, 1686, 1687, 1567, 1568, 1569, 1570, 1688, 1689, 1690, 1691, 1572, 1573, 1692, 1693,
+        1694, 1575, 1576, 1695, 1696, 1697, 1567, 1568, 1698, 1699, 1700, 1701, 1702, 1703, 1572,
+        1573, 1704, 1705, 1574, 1586, 1706, 1695, 1578, 1697,
+    ],
+    [
+        1707, 1708, 1709, 1710, 1711, 1712, 1713, 1714, 1715, 274, 1716, 1717, 1718, 1719, 1720,
+        1721, 1722, 1723, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 31,
+        32, 421, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 283, 31, 32, 421,
+    ],
+    [
+        1724, 1725, 1726, 1727, 1728, 1729, 1730, 1731, 1732, 1733, 1734, 1735, 1736, 1737, 1738,
+        1739, 1740, 33, 1741, 1742, 1743, 1744, 1728, 1729, 1730, 1731, 1745, 1733, 1746, 1747,
+        1748, 1749, 1750, 1751, 1752, 33, 1741, 1742, 1743, 1744, 1753, 1754, 211, 37, 1755, 1733,
+        1746, 1756, 1757, 1758, 1759, 1751, 1760, 33,
+    ],
+    [
+        447, 1400, 1761, 1401, 1762, 1403, 449, 1763, 1404, 1405, 1406, 1764, 1765, 1766, 1767,
+        1768, 1769, 1770, 451, 1414, 452, 1415, 1771, 1403, 449, 1763, 1416, 1594, 1406, 1764,
+        1772, 1419, 1773, 203, 204, 33, 451, 1414, 452, 1415, 1762, 1403, 211, 37, 1416, 1594, 40,
+        1764, 1772, 1419, 1773, 203, 44, 33,
+    ],
+    [
+        1107, 1108, 1109, 1774, 1775, 1112, 1776, 1114, 1777, 1778, 1779, 1118, 1780, 1781, 1782,
+        1783, 1784, 1785, 1137, 1126, 1786, 1138, 1775, 1112, 1776, 1114, 1787, 1788, 1779, 1118,
+        1789, 1790, 1791, 1792, 1793, 33, 1794, 1795, 1796, 1797, 1798, 1799, 1800, 37, 1801, 1802,
+        40, 1803, 1804, 1805, 1806, 1792, 44, 33,
+    ],
+    [
+        1237, 1238, 1239, 1240, 1807, 1808, 1243, 1244, 1245, 1246, 1809, 1810, 1811, 1812, 1813,
+        1814, 1815, 1816, 45, 46, 47, 48, 1817, 1808, 1818, 52, 53, 54, 55, 56, 1819, 58, 1820, 60,
+        61, 62, 45, 46, 47, 48, 1817, 1808, 1821, 1822, 53, 54, 1809, 1810, 1819, 58, 1820, 60, 61,
+        62,
+    ],
+    [
+        1823, 1824, 1825, 1826, 1827, 1828, 1829, 1830, 1831, 1832, 1833, 1834, 1835, 1836, 1837,
+        1838, 1839, 1840, 45, 46, 47, 48, 49, 1828, 51, 1830, 53, 54, 55, 56, 57, 58, 59, 60, 61,
+        62, 1324, 46, 47, 48, 49, 50, 1258, 1259, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        45, 46, 47, 48, 49, 50, 51, 52, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 62,
+        45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49,
+        50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        0, 1, 579, 3, 690, 220, 1850, 583, 8, 9, 584, 1851, 1852, 1853, 1854, 589, 590, 839, 18,
+        19, 20, 21, 690, 220, 1850, 583, 24, 25, 584, 1851, 594, 201, 1855, 31, 32, 421, 205, 206,
+        207, 208, 209, 210, 727, 728, 38, 39, 584, 212, 841, 214, 1856, 31, 204, 421,
+    ],
+    [
+        1857, 1858, 1859, 1860, 1861, 1862, 1863, 1864, 1115, 1116, 1865, 1866, 1867, 1868, 1869,
+        1870, 1871, 1124, 1872, 1873, 1874, 1875, 1876, 1862, 1877, 1878, 1879, 1116, 1865, 1866,
+        708, 201, 283, 1792, 1880, 33, 1872, 1873, 1874, 1881, 1882, 1862, 211, 37, 1879, 1116,
+        1883, 1884, 28, 201, 202, 44, 1880, 33,
+    ],
+    [
+        0, 1, 579, 3, 1885, 691, 6, 693, 8, 9, 1886, 1887, 12, 1888, 1889, 907, 908, 591, 18, 19,
+        20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 1890, 201, 202, 203, 204, 33, 18, 19, 20, 21,
+        195, 196, 197, 37, 24, 25, 236, 200, 1890, 201, 202, 44, 204, 33,
+    ],
+    [
+        0, 1, 579, 3, 580, 581, 1850, 1891, 8, 9, 584, 1851, 1852, 1853, 1854, 589, 590, 839, 18,
+        19, 20, 21, 195, 220, 1850, 1891, 24, 25, 584, 1851, 594, 201, 1892, 31, 32, 421, 205, 206,
+        207, 208, 209, 210, 727, 728, 38, 39, 584, 1851, 841, 214, 1856, 44, 204, 421,
+    ],
+    [
+        1893, 1894, 1895, 1896, 1897, 1898, 1899, 1900, 1901, 1902, 1903, 1904, 1905, 1906, 1907,
+        1908, 1909, 1910, 1911, 1912, 1913, 1914, 1915, 1898, 1916, 1900, 1917, 1918, 1903, 1919,
+        1920, 1921, 1922, 1923, 1924, 33, 1911, 1912, 1925, 1926, 1927, 1928, 1916, 1900, 1929,
+        1930, 1903, 1931, 1932, 1921, 1922, 1923, 1924, 33,
+    ],
+    [
+        1933, 1934, 1935, 1936, 1937, 1938, 1939, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947,
+        1948, 1949, 1950, 1951, 1952, 1953, 1954, 1937, 1938, 1939, 1940, 1955, 1956, 1943, 1944,
+        1957, 1958, 1959, 203, 204, 33, 1951, 1952, 1960, 1954, 1937, 1938, 1800, 284, 1955, 1956,
+        40, 1944, 1957, 1961, 1959, 203, 44, 33,
+    ],
+    [
+        1962, 1963, 1964, 1965, 1966, 1967, 1968, 1969, 1970, 1971, 1972, 1973, 1974, 1975, 1976,
+        1977, 1978, 1979, 515, 516, 517, 518, 1966, 520, 521, 1969, 523, 524, 1972, 526, 1980, 528,
+        1981, 1982, 1668, 264, 515, 516, 517, 518, 1966, 520, 521, 531, 523, 524, 1972, 526, 1980,
+        528, 1983, 1667, 1668, 264,
+    ],
+    [
+        1984, 1985, 1986, 1987, 1988, 1989, 1990, 1991, 1992, 1993, 1994, 1995, 1996, 1997, 1998,
+        1999, 2000, 2001, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 708, 201, 283, 203,
+        204, 33, 18, 19, 207, 208, 195, 196, 197, 198, 38, 39, 236, 200, 308, 201, 283, 203, 204,
+        33,
+    ],
+    [
+        1984, 1985, 1986, 1987, 1988, 1989, 1990, 2002, 1992, 1993, 1994, 1995, 1996, 1997, 1998,
+        2003, 2004, 2001, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 708, 201, 283, 203,
+        204, 33, 18, 19, 207, 208, 195, 196, 197, 198, 38, 39, 236, 200, 308, 201, 283, 203, 204,
+        33,
+    ],
+    [
+        2005, 710, 711, 712, 2006, 2007, 979, 2008, 2009, 2010, 718, 2011, 2012, 721, 2013, 418,
+        419, 2014, 18, 19, 20, 21, 195, 196, 979, 2015, 24, 25, 236, 200, 28, 201, 283, 31, 32, 33,
+        18, 19, 20, 21, 195, 196, 979, 37, 38, 39, 236, 200, 213, 201, 283, 31, 32, 33,
+    ],
+    [
+        977, 710, 711, 712, 2006, 2007, 979, 2008, 2009, 2010, 718, 2011, 982, 721, 2013, 418, 419,
+        2014, 18, 19, 20, 21, 2006, 196, 979, 2015, 24, 25, 236, 200, 28, 201, 202, 31, 32, 33, 18,
+        19, 20, 21, 2006, 196, 211, 37, 24, 25, 236, 200, 28, 201, 202, 203, 32, 33,
+    ],
+    [
+        2016, 2017, 2018, 2019, 2020, 2021, 2022, 2023, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45,
+        46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 1324, 46, 47, 48, 49,
+        50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        686, 687, 688, 689, 49, 50, 51, 52, 2024, 2025, 55, 56, 2026, 58, 59, 2027, 61, 62, 18, 19,
+        20, 21, 49, 50, 51, 52, 24, 25, 55, 56, 28, 58, 59, 2028, 61, 62, 205, 206, 207, 208, 49,
+        50, 51, 52, 24, 25, 55, 56, 28, 58, 59, 203, 61, 62,
+    ],
+    [
+        2029, 2030, 2031, 2032, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2042, 2043,
+        2044, 2045, 2046, 379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 397, 390, 391, 392,
+        393, 1147, 1148, 396, 379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 397, 390, 391, 392,
+        393, 1147, 1148, 396,
+    ],
+    [
+        2047, 2048, 2049, 2050, 2051, 2052, 2053, 2054, 2055, 2056, 2057, 2058, 2059, 2060, 2061,
+        2062, 2063, 2064, 256, 257, 258, 259, 2065, 2066, 2067, 2068, 246, 247, 2069, 260, 2070,
+        251, 2071, 261, 262, 263, 256, 257, 258, 259, 2065, 2066, 2067, 2072, 246, 247, 2069, 260,
+        2070, 251, 2071, 261, 2073, 264,
+    ],
+    [
+        2074, 2075, 2076, 2077, 2078, 50, 51, 52, 2079, 2080, 55, 56, 57, 58, 59, 2081, 2082, 62,
+        2074, 2083, 2076, 2077, 49, 50, 51, 52, 2084, 2085, 55, 56, 57, 58, 59, 60, 61, 62, 2074,
+        2083, 2076, 2077, 49, 50, 51, 52, 2084, 2085, 55, 56, 57, 58, 59, 60, 61, 62,
+    ],
+    [
+        2086, 2087, 2088, 2089, 2090, 2091, 2092, 2093, 2094, 2095, 2096, 778, 2097, 2098, 2099,
+        2100, 2101, 33, 2086, 2087, 2088, 2089, 2090, 2091, 2092, 2093, 2102, 25, 2096, 778, 2097,
+        2098, 2099, 203, 204, 33, 2086, 2087, 20, 2089, 2090, 2091, 2092, 2093, 2103, 39, 2096,
+        778, 28, 2098, 2099, 203, 204, 33,
+    ],
+    [
+        2104, 2105, 2106, 2107, 2108, 2109, 2110, 2111, 2112, 2113, 2114, 2115, 2116, 2117, 2118,
+        2119, 2120, 62, 2121, 2122, 2123, 2124, 2125, 2126, 2110, 2111, 2127, 2128, 2114, 2115,
+        2129, 2130, 2131, 2132, 2133, 62, 2134, 2135, 2136, 2137, 2125, 2126, 2110, 2111, 2138,
+        2139, 2114, 2115, 2140, 2141, 2131, 2142, 2133, 62,
+    ],
+    [
+        2143, 2144, 2145, 2146, 2147, 2148, 2149, 2150, 2151, 2152, 2153, 2154, 2155, 2156, 2157,
+        2158, 2159, 2160, 2161, 2162, 2163, 2164, 2165, 2148, 2149, 2150, 2166, 2167, 2168, 2169,
+        2170, 2171, 2172, 2173, 2174, 33, 2161, 2162, 2163, 2164, 2165, 2148, 1800, 37, 2175, 2167,
+        2176, 2177, 2170, 2171, 2178, 44, 2174, 33,
+    ],
+    [
+        2179, 2180, 2181, 2182, 2183, 2184, 2185, 2186, 2187, 2188, 2189, 2190, 2191, 2192, 2193,
+        2194, 2195, 2196, 515, 516, 517, 518, 519, 520, 521, 522, 523, 524, 525, 526, 527, 528,
+        529, 261, 262, 263, 515, 516, 517, 518, 519, 520, 530, 531, 523, 524, 525, 526, 527, 528,
+        529, 261, 262, 263,
+    ],
+    [
+        2197, 2198, 2199, 2200, 2201, 2202, 2203, 2204, 2205, 2206, 2207, 2208, 2209, 2210, 2211,
+        2212, 2213, 2214, 633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645, 646,
+        2215, 2216, 2217, 650, 633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643, 644, 645,
+        646, 2215, 2218, 2217, 650,
+    ],
+    [
+        2219, 2220, 2221, 2222, 2223, 2224, 2225, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233,
+        2234, 2235, 2236, 18, 19, 20, 21, 2237, 196, 197, 198, 24, 25, 2238, 2239, 2240, 2241,
+        2242, 203, 204, 33, 205, 206, 207, 208, 2237, 196, 197, 198, 38, 39, 2238, 2239, 2240,
+        2241, 2242, 203, 204, 33,
+    ],
+    [
+        2243, 2244, 2245, 2246, 2247, 2248, 2249, 2250, 2251, 2252, 2253, 2254, 2255, 2256, 2257,
+        2258, 2259, 2260, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 2261, 201, 202,
+        2262, 204, 33, 18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 2261, 201, 202, 203,
+        204, 33,
+    ],
+    [
+        399, 400, 401, 402, 403, 404, 2263, 2264, 2265, 2266, 2267, 2268, 2269, 2270, 2271, 2272,
+        2273, 2274, 379, 380, 381, 382, 383, 2275, 385, 2264, 387, 388, 397, 390, 391, 392, 393,
+        394, 395, 396, 379, 380, 381, 382, 403, 384, 385, 386, 387, 388, 397, 2268, 391, 392, 393,
+        394, 395, 396,
+    ],
+    [
+        361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 2276,
+        379, 380, 381, 382, 383, 2277, 385, 368, 387, 388, 397, 390, 391, 392, 393, 394, 395, 396,
+        379, 380, 381, 382, 365, 384, 385, 386, 387, 388, 397, 372, 391, 392, 393, 394, 395, 396,
+    ],
+    [
+        0, 1, 579, 3, 2278, 220, 1850, 2279, 8, 9, 584, 2280, 2281, 1853, 2282, 2283, 2284, 591,
+        18, 19, 20, 21, 195, 196, 1850, 2279, 24, 25, 584, 2280, 28, 201, 202, 31, 32, 421, 205,
+        206, 207, 208, 209, 2285, 2286, 2287, 38, 39, 707, 2288, 213, 214, 729, 203, 204, 33,
+    ],
+    [
+        0, 1, 579, 3, 2278, 220, 1850, 2279, 8, 9, 584, 2280, 2281, 1853, 2282, 2283, 2284, 591,
+        18, 19, 20, 21, 195, 196, 1850, 2279, 24, 25, 584, 2280, 28, 201, 202, 31, 32, 421, 205,
+        206, 207, 208, 209, 2285, 2286, 2287, 38, 39, 707, 2288, 28, 214, 729, 203, 204, 33,
+    ],
+    [
+        2289, 2290, 2291, 2292, 2293, 2294, 2295, 2296, 2297, 2298, 2299, 2300, 2301, 2302, 2303,
+        2304, 2305, 2306, 2307, 2290, 2291, 2292, 2293, 2294, 2295, 2296, 1041, 2298, 2299, 2300,
+        2308, 1046, 2309, 203, 204, 2306, 2307, 2290, 1035, 1036, 2293, 2294, 2295, 2296, 1041,
+        2298, 2310, 2311, 2308, 2312, 2313, 203, 204, 2306,
+    ],
+    [
+        2289, 2290, 2291, 2292, 2293, 2294, 2295, 2296, 2297, 2298, 2299, 2300, 2301, 2302, 2303,
+        2304, 2305, 2306, 2307, 2290, 2291, 2292, 2293, 2294, 2295, 2296, 1041, 2298, 2299, 2300,
+        2308, 1046, 2309, 203, 204, 2306, 2307, 2290, 1035, 1053, 2293, 2294, 2295, 2296, 1041,
+        2298, 2310, 2311, 2308, 2312, 2313, 203, 204, 2306,
+    ],
+    [
+        2314, 2315, 2316, 2317, 2318, 2319, 2320, 2321, 2322, 2323, 2324, 2325, 2326, 2327, 2328,
+        2329, 2330, 2331, 2332, 2333, 2334, 2335, 2336, 2337, 2320, 2338, 2339, 2340, 2341, 2342,
+        2343, 2344, 2345, 2346, 2347, 33, 2348, 2349, 2350, 2351, 2336, 2337, 2320, 2338, 2352,
+        2340, 2341, 2342, 2353, 2344, 2345, 2346, 2347, 33,
+    ],
+    [
+        2354, 2355, 2356, 2357, 2358, 2359, 2360, 2361, 2362, 2363, 2364, 2365, 2366, 2367, 2368,
+        2369, 2370, 2371, 2372, 2373, 2374, 2375, 2376, 2377, 2378, 2379, 2380, 2381, 2382, 2383,
+        2384, 2385, 2386, 2387, 2388, 33, 2389, 2390, 2391, 2392, 2393, 2377, 211, 37, 2394, 2395,
+        2382, 2383, 2396, 2385, 2397, 2387, 2388, 33,
+    ],
+    [
+        2398, 2399, 2400, 2401, 2402, 2403, 2404, 2405, 2406, 2407, 2408, 2409, 2410, 2411, 2412,
+        2413, 2414, 2415, 2416, 2417, 2418, 2419, 2402, 2403, 2404, 2405, 2420, 2421, 2408, 2409,
+        2422, 2423, 2424, 60, 61, 62, 2425, 2426, 2427, 2428, 2429, 2430, 1258, 1259, 2431, 2432,
+        1260, 2433, 2434, 2435, 2436, 60, 61, 62,
+    ],
+    [
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 55, 56, 28, 201, 202, 60, 61, 62, 18, 19, 20,
+        21, 195, 196, 197, 198, 24, 25, 55, 56, 28, 201, 202, 60, 61, 62, 18, 19, 20, 21, 195, 196,
+        197, 198, 24, 25, 55, 56, 28, 201, 202, 60, 61, 62,
+    ],
+    [
+        215, 216, 217, 218, 219, 2437, 221, 2438, 8, 9, 225, 2439, 2440, 2441, 2442, 2443, 2444,
+        2445, 18, 19, 233, 21, 195, 2446, 197, 2447, 24, 25, 225, 2448, 2449, 201, 2450, 203, 204,
+        33, 205, 206, 2451, 208, 195, 2446, 197, 2447, 38, 39, 225, 2448, 2452, 201, 2450, 203,
+        204, 33,
+    ],
+    [
+        2453, 2454, 2455, 2456, 2457, 2458, 2459, 2460, 2461, 2462, 2463, 2464, 2465, 2466, 2467,
+        2468, 2469, 2470, 2471, 2472, 2473, 2474, 2475, 2476, 2477, 2478, 2479, 2480, 2481, 2482,
+        2483, 2484, 2485, 2486, 2487, 2488, 45, 46, 2489, 48, 49, 2490, 51, 52, 2491, 2492, 2493,
+        2494, 57, 58, 59, 2495, 61, 62,
+    ],
+    [
+        2496, 2497, 2498, 2499, 219, 753, 2500, 2250, 8, 9, 2501, 2502, 2503, 2504, 229, 2505,
+        2506, 2507, 18, 19, 20, 21, 219, 196, 2500, 2250, 24, 25, 236, 200, 2508, 2509, 2510, 2511,
+        2512, 1065, 18, 19, 20, 21, 219, 196, 211, 37, 24, 25, 236, 200, 2508, 2509, 2510, 2513,
+        32, 1065,
+    ],
+    [
+        2514, 2515, 2516, 2517, 2518, 2519, 2520, 2521, 2522, 2523, 2524, 2525, 2526, 2527, 2528,
+        2529, 2530, 62, 2514, 2515, 2516, 2517, 2518, 2519, 2531, 2532, 24, 25, 2524, 2525, 2533,
+        2534, 2535, 203, 204, 62, 205, 206, 207, 208, 209, 210, 211, 37, 38, 39, 40, 212, 308, 214,
+        43, 44, 204, 62,
+    ],
+    [
+        2536, 2537, 2538, 2539, 2540, 2541, 2542, 2543, 2544, 2545, 2546, 2547, 2548, 2549, 2550,
+        2551, 2552, 2553, 256, 257, 258, 259, 2540, 2541, 2067, 2543, 246, 247, 2546, 260, 2554,
+        251, 2555, 2556, 2557, 264, 256, 2558, 258, 259, 2559, 2541, 2067, 2560, 2561, 2562, 2069,
+        2563, 2554, 251, 2564, 1667, 1668, 264,
+    ],
+    [
+        2565, 2566, 2567, 2568, 2569, 2570, 2571, 2093, 2572, 2523, 2573, 778, 2574, 2575, 2576,
+        2577, 2578, 2579, 2565, 2566, 2567, 2580, 2569, 2570, 2571, 2581, 24, 25, 236, 200, 708,
+        201, 283, 2582, 2583, 33, 2565, 2566, 207, 2580, 2569, 2570, 211, 2093, 38, 2523, 2573,
+        2584, 308, 214, 43, 2585, 2583, 33,
+    ],
+    [
+        215, 216, 217, 218, 219, 220, 221, 2586, 831, 832, 225, 226, 2587, 2588, 2589, 2590, 2591,
+        2592, 18, 19, 233, 21, 219, 220, 221, 2593, 24, 25, 225, 226, 2587, 201, 2589, 203, 204,
+        33, 18, 19, 233, 21, 2594, 220, 221, 2595, 24, 25, 225, 2596, 2587, 201, 202, 203, 204, 33,
+    ],
+    [
+        451, 1414, 452, 1415, 1402, 1403, 449, 450, 1416, 1417, 1406, 1407, 28, 201, 202, 203, 204,
+        62, 451, 1414, 452, 1415, 1402, 1403, 449, 450, 1416, 1417, 1406, 1407, 28, 201, 202, 203,
+        204, 62, 451, 1414, 452, 1415, 1402, 1403, 449, 450, 1416, 1417, 1406, 1407, 28, 201, 202,
+        44, 204, 62,
+    ],
+    [
+        2597, 2598, 2599, 2600, 2601, 1242, 2602, 2603, 2604, 2605, 2606, 2607, 2608, 2609, 2610,
+        2611, 2612, 62, 45, 46, 47, 48, 2601, 50, 51, 2603, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+        1324, 1325, 1326, 1327, 1328, 1329, 1821, 1822, 1330, 1331, 1332, 1333, 1334, 1335, 1515,
+        60, 61, 62,
+    ],
+    [
+        45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49,
+        50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+        55, 1333, 57, 1335, 1515, 60, 1262, 62,
+    ],
+    [
+        2613, 2614, 2615, 2616, 2617, 2618, 2619, 2620, 2621, 2622, 2623, 2624, 2625, 2626, 2627,
+        2628, 1321, 62, 2613, 2614, 2615, 2616, 2617, 2618, 2619, 2620, 2621, 2622, 2623, 2624,
+        2629, 2630, 2631, 60, 61, 62, 2613, 2614, 2615, 2616, 2617, 2618, 2619, 2620, 2621, 2622,
+        2623, 2624, 2625, 2626, 2627, 60, 61, 62,
+    ],
+    [
+        2613, 2632, 2633, 2634, 2617, 2635, 2636, 2637, 2621, 2622, 2623, 2638, 2639, 2626, 2640,
+        2641, 2642, 62, 2613, 2632, 2633, 2634, 2617, 2635, 2643, 2644, 2621, 2622, 2623, 2638,
+        2645, 2630, 2646, 60, 61, 62, 2613, 2632, 2633, 2634, 2617, 2635, 2643, 2644, 2621, 2622,
+        2623, 2638, 2647, 1335, 2648, 60, 61, 62,
+    ],
+    [
+        2649, 2650, 2651, 2652, 2653, 2654, 2655, 2656, 2657, 2658, 2659, 2660, 2645, 2630, 2646,
+        2661, 2662, 62, 2649, 2650, 2651, 2652, 2653, 2654, 2655, 2656, 53, 54, 55, 56, 57, 58, 59,
+        60, 61, 62, 2649, 2650, 2651, 2652, 1328, 1329, 1258, 1259, 1330, 1331, 1260, 1333, 1334,
+        1335, 1515, 60, 61, 62,
+    ],
+    [
+        2613, 2632, 2633, 2663, 2617, 2618, 2636, 2637, 2621, 2622, 2623, 2638, 2625, 2664, 2627,
+        2628, 1321, 62, 2613, 2632, 2633, 2663, 2617, 2618, 2643, 2644, 2621, 2622, 2623, 2638,
+        2665, 2666, 2667, 60, 61, 62, 2649, 2668, 2669, 2670, 2653, 2671, 2672, 2673, 2621, 2658,
+        2659, 2660, 2674, 2675, 2676, 60, 61, 62,
+    ],
+    [
+        2613, 2614, 2615, 2616, 2617, 2618, 2643, 2644, 2621, 2622, 2623, 2624, 2625, 2677, 2627,
+        2628, 1321, 62, 2613, 2614, 2615, 2616, 2678, 2618, 2643, 2644, 2621, 2622, 2623, 2624,
+        2679, 2677, 2680, 60, 61, 62, 2649, 2650, 2651, 2652, 2681, 2671, 2672, 2673, 2621, 2658,
+        2659, 2624, 2682, 1335, 1515, 60, 61, 62,
+    ],
+    [
+        18, 19, 20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 203, 204, 33, 18, 19,
+        20, 21, 195, 196, 197, 198, 24, 25, 236, 200, 28, 201, 202, 203, 204, 33, 18, 19, 20, 21,
+        195, 196, 197, 198, 24, 25, 236, 200, 2683, 201, 202, 44, 204, 33,
+    ],
+];
+impl Locale {
+    #[doc = r" Returns the locale's patterns for the given unit and width, indexed by plural"]
+    #[doc = r" category."]
+    pub(crate) fn unit_patterns(
+        &self,
+        unit: MeasureUnit,
+        width: UnitWidth,
+    ) -> &'static [&'static str; 6] {
+        use self::Locale::*;
+        let table = match self {
+            af | af_NA => 0,
+            agq | ak | asa | az_Cyrl | bas | bem | bez | bm | bo | bo_IN | brx | cgg | ckb
+            | ckb_IR | cu | dav | dje | dua | dyo | dz | ebu | ee | ee_TG | eo | ewo | ff
+            | ff_Latn | ff_Latn_BF | ff_Latn_CM | ff_Latn_GH | ff_Latn_GM | ff_Latn_GN
+            | ff_Latn_GW | ff_Latn_LR | ff_Latn_MR | ff_Latn_NE | ff_Latn_NG | ff_Latn_SL | fur
+            | guz | gv | ig | ii | jgo | jmc | kab | kam | kde | khq | ki | kkj | kln | ks
+            | ksb | ksf | kw | lag | lg | ln | ln_AO | ln_CF | ln_CG | lrc | lrc_IQ | lu | luo
+            | luy | mas | mas_TZ | mer | mfe | mg | mgh | mgo | mi | mt | mua | naq | nd | nds
+            | nds_NL | nmg | nnh | nus | nyn | om | om_KE | os | os_RU | pa_Arab | prg | rn
+            | rof | root | rw | rwk | saq | sbp | seh | ses | sg | shi | shi_Latn | shi_Tfng
+            | smn | sn | teo | teo_KE | tg | tt | twq | tzm | uz_Arab | vai | vai_Latn
+            | vai_Vaii | vo | vun | wae | wo | xh | xog | yav | yi | zgh => 1,
+            am => 2,
+            ar | ar_AE | ar_BH | ar_DJ | ar_DZ | ar_EG | ar_EH | ar_ER | ar_IL | ar_IQ | ar_JO
+            | ar_KM | ar_KW | ar_LB | ar_LY | ar_MA | ar_MR | ar_OM | ar_PS | ar_QA | ar_SD
+            | ar_SO | ar_SS | ar_SY | ar_TD | ar_TN | ar_YE => 3,
+            ar_SA => 4,
+            as_ => 5,
+            ast => 6,
+            az | az_Latn => 7,
+            be => 8,
+            bg => 9,
+            bn | bn_IN => 10,
+            br => 11,
+            bs | bs_Latn => 12,
+            bs_Cyrl => 13,
+            ca | ca_AD | ca_ES_VALENCIA | ca_FR | ca_IT => 14,
+            ccp | ccp_IN => 15,
+            ce => 16,
+            ceb => 17,
+            chr => 18,
+            cs => 19,
+            cy => 20,
+            da | da_GL => 21,
+            de | de_AT | de_BE | de_IT | de_LI | de_LU => 22,
+            de_CH => 23,
+            dsb => 24,
+            el | el_CY => 25,
+            en | en_AE | en_AS | en_BI | en_GU | en_MH | en_MP | en_PH | en_PR | en_UM
+            | en_US_POSIX | en_VI => 26,
+            en_001 | en_150 | en_AG | en_AI | en_AT | en_BB | en_BE | en_BM | en_BW | en_CC
+            | en_CH | en_CK | en_CM | en_CX | en_CY | en_DE | en_DG | en_DK | en_DM | en_ER
+            | en_FI | en_FJ | en_FK | en_FM | en_GB | en_GD | en_GG | en_GH | en_GI | en_GM
+            | en_GY | en_HK | en_IE | en_IL | en_IM | en_IO | en_JE | en_JM | en_KE | en_KI
+            | en_KN | en_LC | en_LR | en_LS | en_MG | en_MO | en_MS | en_MT | en_MU | en_MW
+            | en_MY | en_NA | en_NF | en_NG | en_NL | en_NR | en_NU | en_NZ | en_PG | en_PK
+            | en_PN | en_RW | en_SB | en_SC | en_SD | en_SE | en_SG | en_SH | en_SI | en_SL
+            | en_SS | en_SX | en_SZ | en_TC | en_TK | en_TO | en_TT | en_TV | en_TZ | en_UG
+            | en_VC | en_VG | en_VU | en_WS | en_ZA | en_ZM | en_ZW => 27,
+            en_AU => 28,
+            en_BS | en_BZ | en_KY | en_PW => 29,
+            en_CA => 30,
+            en_IN => 31,
+            es | es_419 | es_AR | es_BO | es_BR | es_BZ | es_CL | es_CO | es_CR | es_CU | es_DO
+            | es_EA | es_EC | es_GQ | es_GT | es_HN | es_IC | es_MX | es_NI | es_PA | es_PE
+            | es_PH | es_PY | es_SV | es_UY | es_VE => 32,
+            es_PR | es_US => 33,
+            et => 34,
+            eu => 35,
+            fa | fa_AF => 36,
+            fi => 37,
+            fil => 38,
+            fo | fo_DK => 39,
+            fr | fr_BE | fr_BF | fr_BI | fr_BJ | fr_BL | fr_CD | fr_CF | fr_CG | fr_CH | fr_CI
+            | fr_CM | fr_DJ | fr_DZ | fr_GA | fr_GF | fr_GN | fr_GP | fr_GQ | fr_KM | fr_LU
+            | fr_MA | fr_MC | fr_MF | fr_MG | fr_ML | fr_MQ | fr_MR | fr_MU | fr_NC | fr_NE
+            | fr_PF | fr_PM | fr_RE | fr_RW | fr_SC | fr_SN | fr_SY | fr_TD | fr_TG | fr_TN
+            | fr_VU | fr_WF | fr_YT => 40,
+            fr_CA => 41,
+            fr_HT => 42,
+            fy => 43,
+            ga | ga_GB => 44,
+            gd => 45,
+            gl => 46,
+            gsw | gsw_FR | gsw_LI | kl => 47,
+            gu => 48,
+            ha | ha_GH | ha_NE => 49,
+            haw => 50,
+            he => 51,
+            hi => 52,
+            hr | hr_BA => 53,
+            hsb => 54,
+            hu => 55,
+            hy => 56,
+            ia => 57,
+            id => 58,
+            is => 59,
+            it | it_CH | it_SM | it_VA => 60,
+            ja => 61,
+            jv => 62,
+            ka => 63,
+            kea => 64,
+            kk => 65,
+            km => 66,
+            kn => 67,
+            ko | ko_KP => 68,
+            kok => 69,
+            ksh => 70,
+            ku => 71,
+            ky => 72,
+            lb => 73,
+            lkt => 74,
+            lo => 75,
+            lt => 76,
+            lv => 77,
+            mk => 78,
+            ml => 79,
+            mn => 80,
+            mr => 81,
+            ms | ms_BN | ms_SG => 82,
+            my => 83,
+            mzn => 84,
+            nb | nb_SJ => 85,
+            ne | ne_IN => 86,
+            nl | nl_AW | nl_BE | nl_BQ | nl_CW | nl_SR | nl_SX => 87,
+            nn => 88,
+            or => 89,
+            pa | pa_Guru => 90,
+            pl => 91,
+            ps => 92,
+            ps_PK => 93,
+            pt => 94,
+            pt_AO | pt_CH | pt_CV | pt_GQ | pt_GW | pt_LU | pt_MO | pt_MZ | pt_PT | pt_ST
+            | pt_TL => 95,
+            qu | qu_BO | qu_EC => 96,
+            rm => 97,
+            ro | ro_MD => 98,
+            ru | ru_BY | ru_KG | ru_KZ | ru_MD | ru_UA => 99,
+            sah => 100,
+            sd => 101,
+            se | se_FI | se_SE => 102,
+            si => 103,
+            sk => 104,
+            sl => 105,
+            so | so_DJ | so_ET | so_KE => 106,
+            sq | sq_MK | sq_XK => 107,
+            sr | sr_Cyrl | sr_Cyrl_BA | sr_Cyrl_ME | sr_Cyrl_XK => 108,
+            sr_Latn | sr_Latn_BA | sr_Latn_ME | sr_Latn_XK => 109,
+            sv | sv_AX => 110,
+            sv_FI => 111,
+            sw | sw_CD | sw_UG => 112,
+            sw_KE => 113,
+            ta | ta_LK | ta_MY | ta_SG => 114,
+            te => 115,
+            th => 116,
+            ti | ti_ER => 117,
+            tk => 118,
+            to => 119,
+            tr | tr_CY => 120,
+            ug => 121,
+            uk => 122,
+            ur | ur_IN => 123,
+            uz | uz_Latn => 124,
+            uz_Cyrl => 125,
+            vi => 126,
+            yo | yo_BJ => 127,
+            yue | yue_Hant => 128,
+            yue_Hans => 129,
+            zh | zh_Hans | zh_Hans_HK | zh_Hans_MO | zh_Hans_SG => 130,
+            zh_Hant => 131,
+            zh_Hant_HK | zh_Hant_MO => 132,
+            zu => 133,
+        };
+        let unit = match unit {
+            MeasureUnit::Kilometer => 0,
+            MeasureUnit::Meter => 1,
+            MeasureUnit::Centimeter => 2,
+            MeasureUnit::Millimeter => 3,
+            MeasureUnit::Mile => 4,
+            MeasureUnit::Yard => 5,
+            MeasureUnit::Foot => 6,
+            MeasureUnit::Inch => 7,
+            MeasureUnit::Kilogram => 8,
+            MeasureUnit::Gram => 9,
+            MeasureUnit::Pound => 10,
+            MeasureUnit::Ounce => 11,
+            MeasureUnit::KilometerPerHour => 12,
+            MeasureUnit::MeterPerSecond => 13,
+            MeasureUnit::MilePerHour => 14,
+            MeasureUnit::Celsius => 15,
+            MeasureUnit::Fahrenheit => 16,
+            MeasureUnit::Percent => 17,
+        };
+        let width = match width {
+            UnitWidth::Long => 0,
+            UnitWidth::Short => 1,
+            UnitWidth::Narrow => 2,
+        };
+        &PATTERNS[TABLES[table][width * 18 + unit] as usize]
+    }
+}
@@ -3,6 +3,7 @@
 use std::fmt;
 use std::io;
 
+use crate::format::Plain;
 use crate::{Format, ToFormattedString};
 
 /// <b><u>A key trait</u></b>. Gives types in the standard library that implement [`io::Write`]
@@ -40,7 +41,7 @@ macro_rules! impl_for_fmt_write {
             F: Format,
             N: ToFormattedString,
         {
-            n.read_to_fmt_writer(self, format)
+            n.read_to_fmt_writer(self, &Plain(format))
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
         }
     };
@@ -54,7 +55,7 @@ macro_rules! impl_for_io_write {
             F: Format,
             N: ToFormattedString,
         {
-            n.read_to_io_writer(self, format)
+            n.read_to_io_writer(self, &Plain(format))
         }
     };
 }
@@ -42,3 +42,59 @@ lazy_static! {
         ]
     };
 }
+
+// Checks that each `(expected, input, format)` test case is written as expected into a Buffer
+// and, with the `std` feature, into a String and a Vec. Test cases of `(expected, (input,
+// format))` are checked with `check!(pairs: test_cases)`, and test cases of `(expected, input)`
+// that share a format with `check!(test_cases, format)`.
+#[allow(unused_macros)]
+macro_rules! check {
+    (pairs: $test_cases:expr) => {
+        check!($test_cases.iter().map(|(expected, (input, format))| (expected, input, format)))
+    };
+    ($test_cases:expr, $format:expr) => {
+        check!($test_cases.iter().map(|(expected, input)| (expected, input, $format)))
+    };
+    ($test_cases:expr) => {
+        for (expected, input, format) in $test_cases {
+            // Buffer
+            let mut buf = num_format::Buffer::default();
+            buf.write_formatted(input, format);
+            assert_eq!(*expected, buf.as_str());
+
+            #[cfg(feature = "std")]
+            check_string!(@case expected, input, format);
+        }
+    };
+}
+
+// Same as `check!`, but for values that are only written into strings and writers.
+#[allow(unused_macros)]
+macro_rules! check_string {
+    (pairs: $test_cases:expr) => {
+        check_string!(
+            $test_cases.iter().map(|(expected, (input, format))| (expected, input, format))
+        )
+    };
+    (@case $expected:expr, $input:expr, $format:expr) => {{
+        use num_format::{ToFormattedString, WriteFormatted};
+
+        // ToFormattedString
+        assert_eq!($expected.to_string(), $input.to_formatted_string($format));
+
+        // WriteFormatted (fmt::Write)
+        let mut s = String::new();
+        s.write_formatted($input, $format).unwrap();
+        assert_eq!($expected.to_string(), s);
+
+        // WriteFormatted (io::Write)
+        let mut v = Vec::new();
+        v.write_formatted($input, $format).unwrap();
+        assert_eq!($expected.as_bytes(), v.as_slice());
+    }};
+    ($test_cases:expr) => {
+        for (expected, input, format) in $test_cases {
+            check_string!(@case expected, input, format);
+        }
+    };
+}
@@ -0,0 +1,67 @@
+#[macro_use]
+mod common;
+
+use num_format::{ByteSize, ByteUnits, CustomFormat, FractionGrouped, Locale};
+
+#[test]
+fn test_byte_size_iec() {
+    use ByteUnits::Iec;
+
+    let test_cases: &[(&str, ByteSize, Locale)] = &[
+        ("0 B", ByteSize::new(0, Iec, 1), Locale::en),
+        ("1,023 B", ByteSize::new(1023, Iec, 1), Locale::en),
+        ("1.0 KiB", ByteSize::new(1024, Iec, 1), Locale::en),
+        ("1.5 KiB", ByteSize::new(1536, Iec, 1), Locale::en),
+        ("1,5 GiB", ByteSize::new(1_610_612_736, Iec, 1), Locale::de),
+        ("2 GiB", ByteSize::new(1_610_612_736, Iec, 0), Locale::en),
+        ("1.50 GiB", ByteSize::new(1_610_612_736, Iec, 2), Locale::en),
+        ("1.0 MiB", ByteSize::new(1_048_575, Iec, 1), Locale::en),
+        ("1,023.9 KiB", ByteSize::new(1_048_473, Iec, 1), Locale::en),
+        ("16.0 EiB", ByteSize::new(std::u64::MAX, Iec, 1), Locale::en),
+        (
+            "15.9999999999999999905 EiB",
+            ByteSize::new(std::u64::MAX - 10, Iec, 30),
+            Locale::en,
+        ),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_byte_size_si() {
+    use ByteUnits::Si;
+
+    let test_cases: &[(&str, ByteSize, Locale)] = &[
+        ("999 B", ByteSize::new(999, Si, 2), Locale::en),
+        ("1.00 kB", ByteSize::new(1000, Si, 2), Locale::en),
+        ("1.6 GB", ByteSize::new(1_610_612_736, Si, 1), Locale::en),
+        ("1,61 GB", ByteSize::new(1_610_612_736, Si, 2), Locale::de),
+        ("1.0 MB", ByteSize::new(999_950, Si, 1), Locale::en),
+        ("999.9 kB", ByteSize::new(999_949, Si, 1), Locale::en),
+        ("18.4 EB", ByteSize::new(std::u64::MAX, Si, 1), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_byte_size_custom_format() {
+    let format = CustomFormat::builder()
+        .decimal("·")
+        .separator("\u{202f}")
+        .build()
+        .unwrap();
+    let g = |size| FractionGrouped::new(size, 3, "\u{202f}").unwrap();
+    let test_cases: &[(&str, FractionGrouped<ByteSize>, CustomFormat)] = &[
+        (
+            "1·500\u{202f}000 GiB",
+            g(ByteSize::new(1_610_612_736, ByteUnits::Iec, 6)),
+            format.clone(),
+        ),
+        (
+            "1\u{202f}000 B",
+            g(ByteSize::new(1000, ByteUnits::Iec, 6)),
+            format,
+        ),
+    ];
+    check!(test_cases);
+}
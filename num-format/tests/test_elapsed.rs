@@ -0,0 +1,151 @@
+#[macro_use]
+mod common;
+
+use core::time::Duration;
+
+use num_format::{CustomFormat, DurationUnit, Elapsed, FractionGrouped, Locale};
+
+#[test]
+fn test_elapsed_auto() {
+    let test_cases: &[(&str, Elapsed, Locale)] = &[
+        ("0 s", Elapsed::new(Duration::from_secs(0), 0), Locale::en),
+        ("0.0 s", Elapsed::new(Duration::from_secs(0), 1), Locale::en),
+        (
+            "999.0 ns",
+            Elapsed::new(Duration::from_nanos(999), 1),
+            Locale::en,
+        ),
+        (
+            "1.5 µs",
+            Elapsed::new(Duration::from_nanos(1500), 1),
+            Locale::en,
+        ),
+        (
+            "1.2 s",
+            Elapsed::new(Duration::from_micros(1_234_500), 1),
+            Locale::en,
+        ),
+        (
+            "1,23 s",
+            Elapsed::new(Duration::from_micros(1_234_500), 2),
+            Locale::de,
+        ),
+        (
+            "1.0 s",
+            Elapsed::new(Duration::from_micros(999_960), 1),
+            Locale::en,
+        ),
+        (
+            "1.0 min",
+            Elapsed::new(Duration::from_millis(59_960), 1),
+            Locale::en,
+        ),
+        (
+            "2.1 h",
+            Elapsed::new(Duration::from_secs(7503), 1),
+            Locale::en,
+        ),
+        (
+            "1,000 d",
+            Elapsed::new(Duration::from_secs(86_400_000), 0),
+            Locale::en,
+        ),
+        (
+            "213,503,982,334,601.291851852 d",
+            Elapsed::new(Duration::new(std::u64::MAX, 999_999_999), 20),
+            Locale::en,
+        ),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_elapsed_in_unit() {
+    use DurationUnit::*;
+
+    let d = Duration::from_micros(1_234_500);
+    let test_cases: &[(&str, Elapsed, Locale)] = &[
+        (
+            "1,234,500,000 ns",
+            Elapsed::in_unit(d, Nanoseconds, 0),
+            Locale::en,
+        ),
+        (
+            "1,234.5 ms",
+            Elapsed::in_unit(d, Milliseconds, 1),
+            Locale::en,
+        ),
+        (
+            "1.234,50 ms",
+            Elapsed::in_unit(d, Milliseconds, 2),
+            Locale::de,
+        ),
+        ("1 s", Elapsed::in_unit(d, Seconds, 0), Locale::en),
+        ("0.02 min", Elapsed::in_unit(d, Minutes, 2), Locale::en),
+        ("0.0 d", Elapsed::in_unit(d, Days, 1), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_elapsed_compound() {
+    use DurationUnit::*;
+
+    let test_cases: &[(&str, Elapsed, Locale)] = &[
+        (
+            "2 h 5 min 3 s",
+            Elapsed::compound(Duration::from_secs(7503), Seconds),
+            Locale::en,
+        ),
+        (
+            "2 h 5 min",
+            Elapsed::compound(Duration::from_secs(7503), Minutes),
+            Locale::en,
+        ),
+        (
+            "2 h 3 s",
+            Elapsed::compound(Duration::from_secs(7203), Seconds),
+            Locale::en,
+        ),
+        (
+            "2 h",
+            Elapsed::compound(Duration::from_secs(7200), Seconds),
+            Locale::en,
+        ),
+        (
+            "0 s",
+            Elapsed::compound(Duration::from_millis(999), Seconds),
+            Locale::en,
+        ),
+        (
+            "0 ms",
+            Elapsed::compound(Duration::from_secs(0), Milliseconds),
+            Locale::en,
+        ),
+        (
+            "1 s 234 ms 500 µs",
+            Elapsed::compound(Duration::from_micros(1_234_500), Nanoseconds),
+            Locale::en,
+        ),
+        (
+            "1.157 d 9 h 46 min 40 s",
+            Elapsed::compound(Duration::from_secs(100_000_000), Seconds),
+            Locale::de,
+        ),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_elapsed_custom_format() {
+    let format = CustomFormat::builder()
+        .decimal(",")
+        .separator("\u{202f}")
+        .build()
+        .unwrap();
+    let elapsed = Elapsed::in_unit(Duration::new(1234, 567_891_234), DurationUnit::Seconds, 6);
+    let elapsed = FractionGrouped::new(elapsed, 3, "\u{202f}").unwrap();
+    let test_cases: &[(&str, FractionGrouped<Elapsed>, CustomFormat)] =
+        &[("1\u{202f}234,567\u{202f}891 s", elapsed, format)];
+    check!(test_cases);
+}
@@ -1,6 +1,6 @@
 mod common;
 
-use num_format::{Buffer, CustomFormat};
+use num_format::{Buffer, CustomFormat, Locale};
 #[cfg(feature = "std")]
 use num_format::{ToFormattedString, WriteFormatted};
 
@@ -61,3 +61,25 @@ fn test_f64() {
         }
     }
 }
+
+#[test]
+fn test_float_positional() {
+    // Formats with a decimal other than '.' write small and large numbers without an exponent
+    let test_cases: &[(&str, f64, Locale)] = &[
+        ("0,0000001", 1e-7, Locale::de),
+        ("0,00000000015", 1.5e-10, Locale::de),
+        ("-0,00000000015", -1.5e-10, Locale::de),
+        ("10.000.000.000.000.000.000.000,0", 1e22, Locale::de),
+        ("12.340.000.000,0", 1.234e10, Locale::de),
+        ("12,340,000,000.0", 1.234e10, Locale::en),
+    ];
+    for (expected, input, locale) in test_cases {
+        let mut buf = Buffer::default();
+        buf.write_formatted(input, locale);
+        assert_eq!(*expected, buf.as_str());
+    }
+
+    let mut buf = Buffer::default();
+    buf.write_formatted(&1.5e-10_f32, &Locale::de);
+    assert_eq!("0,00000000015", buf.as_str());
+}
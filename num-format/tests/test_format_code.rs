@@ -0,0 +1,258 @@
+#[macro_use]
+mod common;
+
+use num_format::{Coded, CustomFormat, ErrorKind, FormatCode, Locale};
+
+fn c<N>(value: N, code: &str) -> Coded<N> {
+    Coded::new(value, FormatCode::new(code).unwrap())
+}
+
+#[test]
+fn test_format_code_parse() {
+    let code = FormatCode::new("#,##0.00_);[Red](#,##0.00)").unwrap();
+    assert_eq!("#,##0.00_);[Red](#,##0.00)", code.as_str());
+    assert_eq!("#,##0.00_);[Red](#,##0.00)", code.to_string());
+    assert_eq!(2, code.section_count());
+    assert_eq!(code, "#,##0.00_);[Red](#,##0.00)".parse().unwrap());
+
+    assert_eq!(None, code.color(1.0));
+    assert_eq!(Some("Red"), code.color(-1.0));
+    let code = FormatCode::new("[Blue][>100]0;[color10]0").unwrap();
+    assert_eq!(Some("Blue"), code.color(150.0));
+    assert_eq!(Some("color10"), code.color(5.0));
+
+    let code = FormatCode::new("0;-0;0;\"Name: \"@").unwrap();
+    let mut s = String::new();
+    code.write_text("Bob", &mut s).unwrap();
+    assert_eq!("Name: Bob", s);
+    let mut s = String::new();
+    FormatCode::new("0.00")
+        .unwrap()
+        .write_text("Bob", &mut s)
+        .unwrap();
+    assert_eq!("Bob", s);
+}
+
+#[test]
+fn test_format_code_errors() {
+    let test_cases: &[&str] = &[
+        "",
+        "0.0.0",
+        "yyyy-mm-dd",
+        "0;0;0;@;0",
+        "\"abc",
+        "0E",
+        "E+0",
+        "0E+",
+        "0\\",
+        "0_",
+        "[Purple]0",
+        "[h]:mm",
+        "[>abc]0",
+        "[Red][Blue]0",
+        "?/",
+        "? /?",
+        "?/?/?",
+        "# ?/??????????",
+        "0.0/?",
+        "General0",
+        "@0",
+        "0.0E+00.0",
+    ];
+    for input in test_cases {
+        let error = FormatCode::new(input).unwrap_err();
+        match error.kind() {
+            ErrorKind::ParseFormatCode(_) => (),
+            kind => panic!("unexpected error kind {:?} for {:?}", kind, input),
+        }
+    }
+
+    let error = FormatCode::new("0".repeat(256)).unwrap_err();
+    match error.kind() {
+        ErrorKind::Capacity { .. } => (),
+        kind => panic!("unexpected error kind {:?}", kind),
+    }
+}
+
+#[test]
+fn test_format_code_sections() {
+    let accounting = "_(* #,##0.00_);_(* (#,##0.00);_(* \"-\"??_);_(@_)";
+    let test_cases: &[(&str, Coded<f64>, Locale)] = &[
+        (
+            "1,234.50 ",
+            c(1234.5, "#,##0.00_);[Red](#,##0.00)"),
+            Locale::en,
+        ),
+        (
+            "(1,234.50)",
+            c(-1234.5, "#,##0.00_);[Red](#,##0.00)"),
+            Locale::en,
+        ),
+        ("0.00 ", c(0.0, "#,##0.00_);[Red](#,##0.00)"), Locale::en),
+        (
+            "(1.234,50)",
+            c(-1234.5, "#,##0.00_);[Red](#,##0.00)"),
+            Locale::de,
+        ),
+        (" 1,234.50 ", c(1234.5, accounting), Locale::en),
+        (" (1,234.50)", c(-1234.5, accounting), Locale::en),
+        (" -   ", c(0.0, accounting), Locale::en),
+        ("-5", c(-5.0, "0"), Locale::en),
+        ("-0", c(-0.4, "0"), Locale::en),
+        ("0", c(-0.0, "0"), Locale::en),
+        ("zero", c(0.0, "0.00;-0.00;\"zero\""), Locale::en),
+        ("", c(-5.0, "0;;"), Locale::en),
+        ("25K", c(25300.0, "[>=1000]#,##0,\"K\";0"), Locale::en),
+        ("999", c(999.0, "[>=1000]#,##0,\"K\";0"), Locale::en),
+        (
+            "big",
+            c(150.0, "[>100]\"big\";[<-100]\"small\""),
+            Locale::en,
+        ),
+        ("5", c(5.0, "[>100]\"big\";[<-100]\"small\""), Locale::en),
+        ("€ 1.234,50", c(1234.5, "[$€-407] #,##0.00"), Locale::de),
+        ("5", c(5.0, "[$-409]0"), Locale::en),
+        ("Total: 1234.5", c(1234.5, "\"Total: \"General"), Locale::en),
+        ("1234.5", c(1234.5, "@"), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_format_code_numbers() {
+    let test_cases: &[(&str, Coded<f64>, Locale)] = &[
+        ("1,234,568", c(1234567.891, "#,##0"), Locale::en),
+        ("12,34,568", c(1234567.891, "#,##0"), Locale::en_IN),
+        ("1234568", c(1234567.891, "#,##0"), Locale::en_US_POSIX),
+        ("0,005", c(5.0, "0,000"), Locale::en),
+        ("1,235", c(1234567.0, "#,##0,"), Locale::en),
+        ("1.2M", c(1234567.0, "0.0,,\"M\""), Locale::en),
+        ("12.3%", c(0.1234, "0.0%"), Locale::en),
+        ("2.68", c(2.675, "0.00"), Locale::en),
+        ("3", c(2.5, "0"), Locale::en),
+        ("-3", c(-2.5, "0"), Locale::en),
+        ("0.1", c(0.05, "0.0"), Locale::en),
+        (
+            "0.30000000000000000",
+            c(0.1 + 0.2, "0.00000000000000000"),
+            Locale::en,
+        ),
+        ("1.5", c(1.5, "0.0#"), Locale::en),
+        ("1.25", c(1.25, "0.0#"), Locale::en),
+        ("1.0", c(1.0, "0.0#"), Locale::en),
+        ("5.", c(5.0, "#.##"), Locale::en),
+        (".5", c(0.5, "#.##"), Locale::en),
+        ("", c(0.0, "#"), Locale::en),
+        ("1.5 ", c(1.5, "?.??"), Locale::en),
+        ("  5", c(5.0, "??0"), Locale::en),
+        ("12.50", c(12.5, ".00"), Locale::en),
+        ("123-45-6789", c(123456789.0, "000-00-0000"), Locale::en),
+        (
+            "(555) 123-4567",
+            c(5551234567.0, "(###) ###-####"),
+            Locale::en,
+        ),
+        ("$1,234.50", c(1234.5, "$#,##0.00"), Locale::en),
+        ("-$1,234.50", c(-1234.5, "$#,##0.00"), Locale::en),
+        ("1234.50 €", c(1234.5, "#,##0.00 €"), Locale::en_US_POSIX),
+        ("1\u{202f}234,50", c(1234.5, "#,##0.00"), Locale::fr_CH),
+        ("∞", c(std::f64::INFINITY, "0.00"), Locale::en),
+        ("-∞", c(std::f64::NEG_INFINITY, "0.00"), Locale::en),
+        ("NaN", c(std::f64::NAN, "0.00"), Locale::en),
+        ("∞", c(1.7e308, "0%"), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_format_code_scientific() {
+    let test_cases: &[(&str, Coded<f64>, Locale)] = &[
+        ("1.23E+04", c(12345.0, "0.00E+00"), Locale::en),
+        ("1.23E-04", c(0.000123, "0.00E+00"), Locale::en),
+        ("0.00E+00", c(0.0, "0.00E+00"), Locale::en),
+        ("1.00E+01", c(9.999, "0.00E+00"), Locale::en),
+        ("-1.5e5", c(-150000.0, "0.0e-0"), Locale::en),
+        ("1.5e-5", c(0.000015, "0.0e-0"), Locale::en),
+        ("12.3E+3", c(12345.0, "##0.0E+0"), Locale::en),
+        ("1.2E-3", c(0.00123, "##0.0E+0"), Locale::en),
+        ("123.5E+3", c(123456.0, "##0.0E+0"), Locale::en),
+        ("12.35E+03", c(12345.0, "00.00E+00"), Locale::en),
+        ("1,23E+308", c(1.2345e308, "0.00E+00"), Locale::de),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_format_code_fractions() {
+    let test_cases: &[(&str, Coded<f64>, Locale)] = &[
+        ("5 1/4", c(5.25, "# ?/?"), Locale::en),
+        (" 1/2", c(0.5, "# ?/?"), Locale::en),
+        ("0 1/2", c(0.5, "0 ?/?"), Locale::en),
+        ("1    ", c(1.0, "# ?/?"), Locale::en),
+        ("0    ", c(0.0, "# ?/?"), Locale::en),
+        ("1    ", c(0.99, "# ?/?"), Locale::en),
+        (" 1/3", c(0.333, "# ?/?"), Locale::en),
+        ("3  3/4 ", c(3.75, "# ??/??"), Locale::en),
+        ("1 224/955", c(1.23456, "# ???/???"), Locale::en),
+        ("-2 2/8", c(-2.3, "# ?/8"), Locale::en),
+        ("12/8", c(1.5, "?/8"), Locale::en),
+        ("3/2", c(1.5, "?/?"), Locale::en),
+        ("0/1", c(0.0, "?/?"), Locale::en),
+        ("1 1/10", c(1.1, "# ?/10"), Locale::en),
+        ("1,234 1/2", c(1234.5, "#,##0 ?/?"), Locale::en),
+        ("50 1/2%", c(0.505, "# ?/?%"), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_format_code_general() {
+    let test_cases: &[(&str, Coded<f64>, Locale)] = &[
+        ("0.333333333", c(1.0 / 3.0, "General"), Locale::en),
+        ("-0,333333333", c(-1.0 / 3.0, "General"), Locale::de),
+        ("0.3", c(0.1 + 0.2, "General"), Locale::en),
+        ("0", c(0.0, "General"), Locale::en),
+        ("12345678901", c(12345678901.0, "General"), Locale::en),
+        ("1.23457E+11", c(123456789012.0, "General"), Locale::en),
+        ("1E+11", c(99999999999.6, "General"), Locale::en),
+        ("1E+15", c(1e15, "General"), Locale::en),
+        ("0.000123457", c(0.000123456789, "General"), Locale::en),
+        ("1.23E-10", c(1.23e-10, "General"), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_format_code_types() {
+    let test_cases: &[(&str, Coded<u128>, Locale)] = &[(
+        "340282366920938000000000000000000000000",
+        c(std::u128::MAX, "0"),
+        Locale::en,
+    )];
+    check!(test_cases);
+
+    let test_cases: &[(&str, Coded<i64>, Locale)] = &[
+        (
+            "-9,223,372,036,854,780,000",
+            c(std::i64::MIN, "#,##0"),
+            Locale::en,
+        ),
+        ("1.23E+04", c(12345, "0.00E+00"), Locale::en),
+        ("(42)", c(-42, "0;(0)"), Locale::en),
+    ];
+    check!(test_cases);
+
+    let test_cases: &[(&str, Coded<f32>, Locale)] =
+        &[("0.100000000", c(0.1, "0.000000000"), Locale::en)];
+    check!(test_cases);
+
+    let format = CustomFormat::builder()
+        .decimal("·")
+        .minus_sign("−")
+        .separator("'")
+        .build()
+        .unwrap();
+    let test_cases: &[(&str, Coded<f64>, CustomFormat)] =
+        &[("−1'234·50", c(-1234.5, "#,##0.00"), format)];
+    check!(test_cases);
+}
@@ -0,0 +1,255 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+mod common;
+
+use num_format::{
+    CustomFormat, ErrorKind, FormatSpec, Locale, SpecAlign, SpecSign, SpecType, Specified,
+    ToFormattedString,
+};
+
+fn s<N>(value: N, spec: &str) -> Specified<N> {
+    Specified::new(value, FormatSpec::new(spec).unwrap())
+}
+
+#[test]
+fn test_format_spec_parse() {
+    let spec = FormatSpec::new("*^+#012_.3f").unwrap();
+    assert_eq!(Some('*'), spec.fill());
+    assert_eq!(Some(SpecAlign::Center), spec.align());
+    assert_eq!(SpecSign::Always, spec.sign());
+    assert!(spec.alternate());
+    assert!(!spec.zero_padded());
+    assert_eq!(12, spec.width());
+    assert_eq!(Some('_'), spec.grouping());
+    assert_eq!(Some(3), spec.precision());
+    assert_eq!(Some(SpecType::Fixed { uppercase: false }), spec.spec_type());
+
+    let spec: FormatSpec = "08,".parse().unwrap();
+    assert_eq!(None, spec.fill());
+    assert_eq!(None, spec.align());
+    assert_eq!(SpecSign::Negative, spec.sign());
+    assert!(spec.zero_padded());
+    assert_eq!(8, spec.width());
+    assert_eq!(Some(','), spec.grouping());
+    assert_eq!(None, spec.precision());
+    assert_eq!(None, spec.spec_type());
+
+    // A zero after a fill is part of the width
+    let spec = FormatSpec::new("x<05").unwrap();
+    assert!(!spec.zero_padded());
+    assert_eq!(5, spec.width());
+
+    let test_cases: &[(&str, &str)] = &[
+        ("", ""),
+        ("*^+#012_.3f", "*^+#12_.3f"),
+        ("+#012_.3f", "+#012_.3f"),
+        ("-d", "d"),
+        (" >10", " >10"),
+        ("x<05", "x<5"),
+        ("<05", "<05"),
+        ("#X", "#X"),
+        ("=+,.2%", "=+,.2%"),
+        ("G", "G"),
+        ("^^10", "^^10"),
+        ("<", "<"),
+    ];
+    for (input, expected) in test_cases {
+        let spec = FormatSpec::new(input).unwrap();
+        assert_eq!(*expected, spec.to_string());
+        assert_eq!(spec, expected.parse().unwrap());
+    }
+}
+
+#[test]
+fn test_format_spec_errors() {
+    let test_cases: &[&str] = &[
+        "z",
+        "dd",
+        ".f",
+        ",_",
+        "_,",
+        ",,",
+        "+-d",
+        ".2d",
+        ".2x",
+        ",x",
+        ",b",
+        ",o",
+        ",n",
+        "_n",
+        "10.2.3f",
+        "^^^",
+        "99999999999999999999999",
+    ];
+    for input in test_cases {
+        let error = FormatSpec::new(input).unwrap_err();
+        match error.kind() {
+            ErrorKind::ParseFormatSpec(_) => (),
+            kind => panic!("unexpected error kind {:?} for {:?}", kind, input),
+        }
+    }
+}
+
+#[test]
+fn test_format_spec_integers() {
+    let test_cases: &[(&str, Specified<i128>, Locale)] = &[
+        ("1,234,567", s(1234567, ","), Locale::en),
+        ("1_234_567", s(1234567, "_d"), Locale::en),
+        ("-42", s(-42, "+d"), Locale::en),
+        ("+42", s(42, "+d"), Locale::en),
+        (" 42", s(42, " d"), Locale::en),
+        ("***42****", s(42, "*^9"), Locale::en),
+        ("42    ", s(42, "<6"), Locale::en),
+        ("    42", s(42, "6"), Locale::en),
+        ("-     42", s(-42, "=8"), Locale::en),
+        ("-0000042", s(-42, "08"), Locale::en),
+        ("0,001,234", s(1234, "08,"), Locale::en),
+        ("00,001,234", s(1234, "0=10,"), Locale::en),
+        ("xxxxx1,234", s(1234, "x=10,"), Locale::en),
+        ("ff", s(255, "x"), Locale::en),
+        ("0XFF", s(255, "#X"), Locale::en),
+        ("0b100_1101_0010", s(1234, "#_b"), Locale::en),
+        ("-0x00_00ff", s(-255, "#010_x"), Locale::en),
+        ("0o10", s(8, "#o"), Locale::en),
+        ("5xxxx", s(5, "x<05"), Locale::en),
+        ("50000", s(5, "<05"), Locale::en),
+        ("300.000000%", s(3, "%"), Locale::en),
+        (
+            "100,000,000,000,000,000,000.00",
+            s(100000000000000000000, ",.2f"),
+            Locale::en,
+        ),
+        ("1.23e+04", s(12345, ".2e"), Locale::en),
+        ("5", s(5, ".2"), Locale::en),
+        (
+            "-170141183460469231731687303715884105728",
+            s(std::i128::MIN, ""),
+            Locale::en,
+        ),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Specified<u8>, Locale)] = &[
+        ("0x000000ff", s(255, "#010x"), Locale::en),
+        ("11111111", s(255, "b"), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Specified<u128>, Locale)] = &[(
+        "ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff",
+        s(std::u128::MAX, "_x"),
+        Locale::en,
+    )];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_format_spec_floats() {
+    let test_cases: &[(&str, Specified<f64>, Locale)] = &[
+        ("1,234,567.89", s(1234567.891, ">12,.2f"), Locale::en),
+        ("-1,234.50", s(-1234.5, ",.2f"), Locale::en),
+        ("2.67", s(2.675, ".2f"), Locale::en),
+        ("0.12", s(0.125, ".2f"), Locale::en),
+        ("2", s(2.5, ".0f"), Locale::en),
+        ("1.", s(1.0, "#.0f"), Locale::en),
+        ("-0.0", s(-0.0, ""), Locale::en),
+        ("0.1", s(0.1, ""), Locale::en),
+        ("1e+16", s(1e16, ""), Locale::en),
+        ("1000000000000000.0", s(1e15, ""), Locale::en),
+        ("1.5e-05", s(1.5e-5, ""), Locale::en),
+        ("1.23e+02", s(123.0, ".3"), Locale::en),
+        ("12.0", s(12.0, ".3"), Locale::en),
+        ("1.23e+03", s(1234.5, ".2e"), Locale::en),
+        ("0.000000E+00", s(0.0, "E"), Locale::en),
+        ("1.e+00", s(1.0, "#.0e"), Locale::en),
+        ("99999.5", s(99999.5, "g"), Locale::en),
+        ("100", s(100.0, "g"), Locale::en),
+        ("1e-05", s(1e-5, "g"), Locale::en),
+        ("10", s(9.9999, ".3g"), Locale::en),
+        ("1.00000", s(1.0, "#g"), Locale::en),
+        ("1.23457E+06", s(1234567.0, "G"), Locale::en),
+        ("+25.6%", s(0.256, "+.1%"), Locale::en),
+        ("50.%", s(0.5, "#.0%"), Locale::en),
+        ("inf%", s(1.7e308, "%"), Locale::en),
+        ("0,001,234.50", s(1234.5, "012,.2f"), Locale::en),
+        ("-    1.235", s(-1.23456, "=+10.3f"), Locale::en),
+        ("   +1.23    ", s(1.23456, "^+12.2f"), Locale::en),
+        ("INF", s(std::f64::INFINITY, "F"), Locale::en),
+        ("-000000inf", s(std::f64::NEG_INFINITY, "010"), Locale::en),
+        ("+nan", s(std::f64::NAN, "+"), Locale::en),
+        ("12_345.678", s(12345.678, "_"), Locale::en),
+        ("0.10000000000000000555", s(0.1, ".20f"), Locale::en),
+        ("4.941e-324", s(5e-324, ".3e"), Locale::en),
+        // Integer types are ignored for floats
+        ("1.5", s(1.5, "d"), Locale::en),
+        ("1_234.5", s(1234.5, "_x"), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    // f32s are written with their own shortest representation
+    let test_cases: &[(&str, Specified<f32>, Locale)] = &[
+        ("0.1", s(0.1, ""), Locale::en),
+        ("0.100000001", s(0.1, ".9f"), Locale::en),
+    ];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_format_spec_number() {
+    let test_cases: &[(&str, Specified<i64>, Locale)] = &[
+        ("-1.234.567", s(-1234567, "n"), Locale::de),
+        ("12,34,567", s(1234567, "n"), Locale::en_IN),
+        ("1234567", s(1234567, "n"), Locale::en_US_POSIX),
+        ("+1\u{202f}234\u{202f}567", s(1234567, "+n"), Locale::fr),
+        ("00.001.234", s(1234, "010n"), Locale::de),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Specified<f64>, Locale)] = &[
+        ("1.234,5", s(1234.5, "n"), Locale::de),
+        ("1,23,456.8", s(123456.75, ".7n"), Locale::en_IN),
+        ("1,23457e+06", s(1234567.0, "n"), Locale::de),
+        ("-∞", s(std::f64::NEG_INFINITY, "n"), Locale::de),
+        ("NaN", s(std::f64::NAN, "n"), Locale::de),
+    ];
+    check_string!(test_cases);
+
+    let format = CustomFormat::builder()
+        .decimal("·")
+        .minus_sign("−")
+        .separator("'")
+        .build()
+        .unwrap();
+    let test_cases: &[(&str, Specified<f64>, CustomFormat)] =
+        &[("  −1'234·5", s(-1234.5, "10n"), format)];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_format_spec_wide() {
+    let pad = |c: &str, n: usize| c.repeat(n);
+    let test_cases: &[(String, Specified<i128>, Locale)] = &[
+        (format!("{}42", pad(" ", 63)), s(42, ">65d"), Locale::en),
+        (format!("{}1", pad(" ", 79)), s(1, ">80"), Locale::en),
+        (format!("-{}42", pad("0", 997)), s(-42, "01000"), Locale::en),
+        (
+            format!("{}7{}", pad("·", 100), pad("·", 100)),
+            s(7, "·^201"),
+            Locale::en,
+        ),
+    ];
+    check_string!(test_cases);
+
+    let expected = format!("1.{}e+00", pad("0", 300));
+    let test_cases: &[(String, Specified<f64>, Locale)] = &[
+        (expected, s(1.0, ".300e"), Locale::en),
+        (format!("{}1.0", pad(" ", 997)), s(1.0, "1000"), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    // 309 integer digits in 103 groups, a decimal, and 300 fraction digits
+    let s = s(std::f64::MAX, ",.300f").to_formatted_string(&Locale::en);
+    assert_eq!(309 + 102 + 1 + 300, s.len());
+    assert!(s.starts_with("179,769,313,486,231,570,"));
+}
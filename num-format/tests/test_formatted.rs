@@ -0,0 +1,129 @@
+#![cfg(feature = "std")]
+
+use core::num::NonZeroU64;
+
+use num_format::parsing::ParseReason;
+use num_format::{CustomFormat, ErrorKind, Formatted, Grouping, Locale, StaticFormat};
+
+struct English;
+
+impl StaticFormat for English {
+    type Format = Locale;
+
+    fn format() -> Locale {
+        Locale::en
+    }
+}
+
+struct Indian;
+
+impl StaticFormat for Indian {
+    type Format = Locale;
+
+    fn format() -> Locale {
+        Locale::en_IN
+    }
+}
+
+struct Apostrophes;
+
+impl StaticFormat for Apostrophes {
+    type Format = CustomFormat;
+
+    fn format() -> CustomFormat {
+        CustomFormat::builder()
+            .grouping(Grouping::Standard)
+            .minus_sign("\u{2212}")
+            .separator("'")
+            .build()
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_formatted_display() {
+    assert_eq!(
+        "1,234,567",
+        Formatted::<u32, English>::new(1_234_567).to_string()
+    );
+    assert_eq!(
+        "-12,34,567",
+        Formatted::<i64, Indian>::from(-1_234_567).to_string()
+    );
+    assert_eq!(
+        "\u{2212}1'234",
+        Formatted::<i16, Apostrophes>::new(-1234).to_string()
+    );
+    assert_eq!(
+        "18,446,744,073,709,551,615",
+        Formatted::<u64, English>::new(std::u64::MAX).to_string()
+    );
+    assert_eq!(
+        "1,000",
+        Formatted::<_, English>::new(NonZeroU64::new(1000).unwrap()).to_string()
+    );
+
+    // Width, fill, and alignment count characters, not bytes
+    let n = Formatted::<i32, Apostrophes>::new(-1234);
+    assert_eq!("  \u{2212}1'234", format!("{:>8}", n));
+    assert_eq!("\u{2212}1'234**", format!("{:*<8}", n));
+    assert_eq!(
+        "1,234",
+        format!("{:2}", Formatted::<u16, English>::new(1234))
+    );
+}
+
+#[test]
+fn test_formatted_from_str() {
+    let n: Formatted<u32, English> = "1,234,567".parse().unwrap();
+    assert_eq!(1_234_567, n.into_value());
+    let n: Formatted<i64, Indian> = "-12,34,567".parse().unwrap();
+    assert_eq!(-1_234_567, *n.value());
+    let n: Formatted<i16, Apostrophes> = "\u{2212}1'234".parse().unwrap();
+    assert_eq!(-1234, *n.value());
+    let n: Formatted<NonZeroU64, English> = "1,000".parse().unwrap();
+    assert_eq!(1000, n.value().get());
+
+    // The format is the one of the marker type
+    assert!("12,34,567".parse::<Formatted<u32, English>>().is_err());
+    assert!("1'234".parse::<Formatted<u32, English>>().is_err());
+    match "-1".parse::<Formatted<u8, English>>().unwrap_err().kind() {
+        ErrorKind::ParseFormatted {
+            reason: ParseReason::InvalidSign,
+            ..
+        } => (),
+        kind => panic!("unexpected error kind {:?}", kind),
+    }
+}
+
+#[test]
+fn test_formatted_roundtrip() {
+    for n in &[
+        std::i128::MIN,
+        -1_234_567_890,
+        -1,
+        0,
+        1,
+        999,
+        1000,
+        std::i128::MAX,
+    ] {
+        let english = Formatted::<i128, English>::new(*n);
+        assert_eq!(english, english.to_string().parse().unwrap());
+        let indian = Formatted::<i128, Indian>::new(*n);
+        assert_eq!(indian, indian.to_string().parse().unwrap());
+        let apostrophes = Formatted::<i128, Apostrophes>::new(*n);
+        assert_eq!(apostrophes, apostrophes.to_string().parse().unwrap());
+    }
+}
+
+#[test]
+fn test_formatted_traits() {
+    // Marker types need not implement anything
+    let a = Formatted::<u8, Apostrophes>::new(1);
+    let b = a;
+    assert_eq!(a, b);
+    assert!(a < Formatted::new(2));
+    assert_eq!(Formatted::new(0), Formatted::<u8, Apostrophes>::default());
+    assert_eq!("Formatted(1)", format!("{:?}", a));
+}
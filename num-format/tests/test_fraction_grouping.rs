@@ -0,0 +1,147 @@
+#[macro_use]
+mod common;
+
+use num_format::{
+    Buffer, CustomFormat, FractionGrouped, Grouping, Locale, NumberPattern, Patterned, Scaled,
+};
+#[cfg(feature = "std")]
+use num_format::{Decimal, ToFormattedString, WriteFormatted};
+
+type Grouped<T> = (FractionGrouped<T>, CustomFormat);
+
+fn thin_space<T>(value: T) -> Grouped<T> {
+    let format = CustomFormat::builder()
+        .separator("\u{202f}")
+        .build()
+        .unwrap();
+    (FractionGrouped::new(value, 3, "\u{202f}").unwrap(), format)
+}
+
+fn underscore<T>(value: T) -> Grouped<T> {
+    let format = CustomFormat::builder()
+        .grouping(Grouping::Posix)
+        .build()
+        .unwrap();
+    (FractionGrouped::new(value, 2, "_").unwrap(), format)
+}
+
+fn de<T>(value: T) -> Grouped<T> {
+    let format = CustomFormat::from(Locale::de);
+    (FractionGrouped::new(value, 3, ".").unwrap(), format)
+}
+
+fn ungrouped<T>(value: T) -> Grouped<T> {
+    let format = CustomFormat::from(Locale::de);
+    (FractionGrouped::new(value, 0, ".").unwrap(), format)
+}
+
+#[test]
+fn test_fraction_grouping_float() {
+    let test_cases: &[(&str, Grouped<f64>)] = &[
+        ("0.0", thin_space(0.0)),
+        ("1\u{202f}234.567\u{202f}89", thin_space(1234.56789)),
+        (
+            "-3.141\u{202f}592\u{202f}653\u{202f}589\u{202f}793",
+            thin_space(-std::f64::consts::PI),
+        ),
+        ("0.000\u{202f}012\u{202f}5", thin_space(0.0000125)),
+        ("1234.56_78_9", underscore(1234.56789)),
+        ("1.234,567.89", de(1234.56789)),
+        ("1.234,56789", ungrouped(1234.56789)),
+        ("1\u{202f}000\u{202f}000.0", thin_space(1e6)),
+    ];
+    check!(pairs: test_cases);
+
+    let test_cases: &[(&str, Grouped<f32>)] = &[
+        ("1.25", thin_space(1.25)),
+        ("0.123\u{202f}4", thin_space(0.1234)),
+        ("0,5", de(0.5)),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_fraction_grouping_scaled() {
+    let test_cases: &[(&str, Grouped<Scaled<i64>>)] = &[
+        ("0", thin_space(Scaled::new(0, 0))),
+        ("0.000", thin_space(Scaled::new(0, 3))),
+        (
+            "-1\u{202f}234.567\u{202f}8",
+            thin_space(Scaled::new(-12_345_678, 4)),
+        ),
+        ("0.000\u{202f}05", thin_space(Scaled::new(5, 5))),
+        ("12.34_56", underscore(Scaled::new(123_456, 4))),
+        (
+            "-92.233.720.368.547.758,08",
+            de(Scaled::new(std::i64::MIN, 2)),
+        ),
+        ("2,55", ungrouped(Scaled::new(255, 2))),
+    ];
+    check!(pairs: test_cases);
+
+    let test_cases: &[(&str, Grouped<Scaled<u128>>)] = &[(
+        "340\u{202f}282\u{202f}366\u{202f}920.938\u{202f}463\u{202f}463\u{202f}374\u{202f}607\u{202f}431\u{202f}768\u{202f}211\u{202f}455",
+        thin_space(Scaled::new(std::u128::MAX, 27)),
+    )];
+    check!(pairs: test_cases);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_fraction_grouping_decimal() {
+    type Group = fn(Decimal) -> Grouped<Decimal>;
+
+    let test_cases: &[(&str, Group, &str)] = &[
+        ("1\u{202f}234.567\u{202f}890", thin_space, "1234.567890"),
+        ("-0.5", thin_space, "-.5"),
+        ("12", thin_space, "+0012"),
+        ("0", thin_space, "-0"),
+        ("1.234.567,891.2", de, "1234567.8912"),
+        ("1234567.89_12", underscore, "1234567.8912"),
+    ];
+    for (expected, grouped, input) in test_cases {
+        let (decimal, format) = grouped(input.parse().unwrap());
+        assert_eq!(*expected, decimal.to_formatted_string(&format));
+
+        let mut s = String::new();
+        s.write_formatted(&decimal, &format).unwrap();
+        assert_eq!(*expected, s);
+    }
+
+    let decimal = Decimal::new("-0012.3400").unwrap();
+    assert!(decimal.is_negative());
+    assert_eq!("12", decimal.integer_digits());
+    assert_eq!("3400", decimal.fraction_digits());
+    assert_eq!("-12.3400", decimal.to_string());
+
+    for input in &["", "-", ".", "1.2.3", "1e5", "١٢", " 1", "1,000"] {
+        assert!(Decimal::new(input).is_err(), "{:?}", input);
+    }
+}
+
+#[test]
+fn test_fraction_grouping_capacity() {
+    assert!(FractionGrouped::new(1.25, 1, "𠜱𠜱").is_err());
+    assert!(FractionGrouped::new(1.25, 3, "xxxxxxxxx").is_err());
+
+    let format = CustomFormat::builder().separator("𠜱𠜱").build().unwrap();
+    let mut buf = Buffer::default();
+    let value = FractionGrouped::new(std::f64::MAX, 3, "𠜱𠜱").unwrap();
+    buf.write_formatted(&value, &format);
+    assert!(buf.as_str().starts_with("179𠜱𠜱769𠜱𠜱313"));
+    let value = FractionGrouped::new(-std::f64::MIN_POSITIVE, 3, "𠜱𠜱").unwrap();
+    buf.write_formatted(&value, &format);
+    assert!(buf.as_str().starts_with("-0.000𠜱𠜱000"));
+}
+
+#[test]
+fn test_fraction_grouping_overflow() {
+    // A pattern may ask for more fraction digits than fit into a buffer once they are grouped;
+    // the fraction digits are then written ungrouped
+    let pattern = NumberPattern::new(format!("0.{}", "0".repeat(700))).unwrap();
+    let value = Patterned::new(1.25, pattern).unwrap();
+    let value = FractionGrouped::new(value, 3, "𠜱𠜱").unwrap();
+    let mut buf = Buffer::default();
+    buf.write_formatted(&value, &Locale::en);
+    assert_eq!(format!("1.25{}", "0".repeat(698)), buf.as_str());
+}
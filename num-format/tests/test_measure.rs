@@ -0,0 +1,150 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+mod common;
+
+use num_format::MeasureUnit::*;
+use num_format::UnitWidth::*;
+use num_format::{CustomFormat, Locale, Measure, MeasureUnit, UnitWidth};
+
+fn m<N>(value: N, unit: MeasureUnit, width: UnitWidth, locale: Locale) -> (Measure<N>, Locale) {
+    (Measure::new(value, unit, width, locale), locale)
+}
+
+#[test]
+fn test_measure_integer() {
+    let test_cases: &[(&str, (Measure<i64>, Locale))] = &[
+        ("1 km", m(1, Kilometer, Short, Locale::en)),
+        ("1 kilometer", m(1, Kilometer, Long, Locale::en)),
+        ("-1 kilometer", m(-1, Kilometer, Long, Locale::en)),
+        ("0 kilometers", m(0, Kilometer, Long, Locale::en)),
+        ("2 kilometers", m(2, Kilometer, Long, Locale::en)),
+        (
+            "1,000,000 kilometers",
+            m(1_000_000, Kilometer, Long, Locale::en),
+        ),
+        ("1 foot", m(1, Foot, Long, Locale::en)),
+        ("6 feet", m(6, Foot, Long, Locale::en)),
+        ("6′", m(6, Foot, Narrow, Locale::en)),
+        ("55 mph", m(55, MilePerHour, Short, Locale::en)),
+        ("-3°C", m(-3, Celsius, Narrow, Locale::en)),
+        ("1 degree Fahrenheit", m(1, Fahrenheit, Long, Locale::en)),
+        ("72 km/h", m(72, KilometerPerHour, Short, Locale::de)),
+        (
+            "72 Kilometer pro Stunde",
+            m(72, KilometerPerHour, Long, Locale::de),
+        ),
+        ("1 kilometr", m(1, Kilometer, Long, Locale::pl)),
+        ("2 kilometry", m(2, Kilometer, Long, Locale::pl)),
+        ("5 kilometrów", m(5, Kilometer, Long, Locale::pl)),
+        ("12 kilometrów", m(12, Kilometer, Long, Locale::pl)),
+        ("22 kilometry", m(22, Kilometer, Long, Locale::pl)),
+        (
+            "1\u{a0}000 kilometrów",
+            m(1000, Kilometer, Long, Locale::pl),
+        ),
+        ("5 kg", m(5, Kilogram, Short, Locale::pl)),
+        ("1 килограмм", m(1, Kilogram, Long, Locale::ru)),
+        ("3 килограмма", m(3, Kilogram, Long, Locale::ru)),
+        ("11 килограмм", m(11, Kilogram, Long, Locale::ru)),
+        ("2\u{a0}kilomètres", m(2, Kilometer, Long, Locale::fr)),
+        (
+            "1\u{202f}000\u{202f}000\u{a0}kilomètres",
+            m(1_000_000, Kilometer, Long, Locale::fr),
+        ),
+        ("متر", m(1, Meter, Long, Locale::ar)),
+        ("متران", m(2, Meter, Long, Locale::ar)),
+        ("3 أمتار", m(3, Meter, Long, Locale::ar)),
+        ("11 مترًا", m(11, Meter, Long, Locale::ar)),
+        ("100 متر", m(100, Meter, Long, Locale::ar)),
+        ("5 キロメートル", m(5, Kilometer, Long, Locale::ja)),
+        ("12%", m(12, Percent, Short, Locale::en)),
+        ("12 percent", m(12, Percent, Long, Locale::en)),
+        ("12 %", m(12, Percent, Short, Locale::de)),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[(&str, (Measure<u128>, Locale))] = &[
+        (
+            "340,282,366,920,938,463,463,374,607,431,768,211,455 kilometers",
+            m(std::u128::MAX, Kilometer, Long, Locale::en),
+        ),
+        (
+            "340\u{a0}282\u{a0}366\u{a0}920\u{a0}938\u{a0}463\u{a0}463\u{a0}374\u{a0}607\u{a0}431\u{a0}768\u{a0}211\u{a0}455 kilometrów",
+            m(std::u128::MAX, Kilometer, Long, Locale::pl),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[(&str, (Measure<u8>, Locale))] = &[
+        ("0 in", m(0, Inch, Short, Locale::en)),
+        ("255 inches", m(std::u8::MAX, Inch, Long, Locale::en)),
+    ];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_measure_float() {
+    let test_cases: &[(&str, (Measure<f64>, Locale))] = &[
+        ("12.5 km", m(12.5, Kilometer, Short, Locale::en)),
+        ("1.0 kilometers", m(1.0, Kilometer, Long, Locale::en)),
+        ("0.5 kilometers", m(0.5, Kilometer, Long, Locale::en)),
+        ("3,5 kg", m(3.5, Kilogram, Short, Locale::de)),
+        ("1,5 kilometra", m(1.5, Kilometer, Long, Locale::pl)),
+        ("1,5\u{a0}kilomètre", m(1.5, Kilometer, Long, Locale::fr)),
+        ("2,5\u{a0}kilomètres", m(2.5, Kilometer, Long, Locale::fr)),
+        ("21.5°C", m(21.5, Celsius, Narrow, Locale::en)),
+        ("متر", m(1.0, Meter, Long, Locale::ar)),
+        ("NaN km", m(std::f64::NAN, Kilometer, Short, Locale::en)),
+        (
+            "inf kilometers",
+            m(std::f64::INFINITY, Kilometer, Long, Locale::en),
+        ),
+        (
+            "-inf kilometers",
+            m(std::f64::NEG_INFINITY, Kilometer, Long, Locale::en),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[(&str, (Measure<f32>, Locale))] = &[
+        ("0.1 lb", m(0.1, Pound, Short, Locale::en)),
+        ("2.0 ounces", m(2.0, Ounce, Long, Locale::en)),
+    ];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_measure_custom_format() {
+    let test_cases: &[(&str, (Measure<i32>, CustomFormat))] = &[
+        (
+            "1,000 meters",
+            (
+                Measure::new(1000, Meter, Long, Locale::en),
+                CustomFormat::default(),
+            ),
+        ),
+        (
+            "1_000 meters",
+            (
+                Measure::new(1000, Meter, Long, Locale::en),
+                CustomFormat::builder().separator("_").build().unwrap(),
+            ),
+        ),
+        (
+            "1_000 metrów",
+            (
+                Measure::new(1000, Meter, Long, Locale::pl),
+                CustomFormat::builder().separator("_").build().unwrap(),
+            ),
+        ),
+        (
+            "1\u{a0}000 metrów",
+            (
+                Measure::new(1000, Meter, Long, Locale::pl),
+                CustomFormat::from(Locale::pl),
+            ),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+}
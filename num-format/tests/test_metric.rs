@@ -0,0 +1,84 @@
+#[macro_use]
+mod common;
+
+use num_format::{CustomFormat, FractionGrouped, Locale, Metric};
+
+fn m<N>(value: N, unit: &str) -> Metric<N> {
+    Metric::new(value, unit).unwrap()
+}
+
+#[test]
+fn test_metric_f64() {
+    let test_cases: &[(&str, Metric<f64>, Locale)] = &[
+        ("0.0 Ω", m(0.0, "Ω"), Locale::en),
+        ("4.7 kΩ", m(4700.0, "Ω"), Locale::en),
+        ("4,7 kΩ", m(4700.0, "Ω"), Locale::de),
+        ("12.3 µs", m(12.3e-6, "s"), Locale::en),
+        ("2.1 GW", m(2.1e9, "W"), Locale::en),
+        ("-2.1 GW", m(-2.1e9, "W"), Locale::en),
+        ("1.0 k", m(1000.0, ""), Locale::en),
+        ("999.0 m", m(999.0, "m"), Locale::en),
+        ("999.9", m(999.9, ""), Locale::en),
+        ("100.0 mV", m(0.1, "V"), Locale::en),
+        ("1.0 mV", m(0.001, "V"), Locale::en),
+        ("999.0 µV", m(0.000999, "V"), Locale::en),
+        ("470.0 pF", m(4.7e-10, "F"), Locale::en),
+        ("3.3 nH", m(3.3e-9, "H"), Locale::en),
+        ("1.0 qg", m(1e-30, "g"), Locale::en),
+        ("0.001 qg", m(1e-33, "g"), Locale::en),
+        ("1,000.0 QB", m(1e33, "B"), Locale::en),
+        ("NaN V", m(std::f64::NAN, "V"), Locale::en),
+        ("inf V", m(std::f64::INFINITY, "V"), Locale::en),
+        ("NaN", m(std::f64::NAN, ""), Locale::en),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_metric_f32() {
+    let test_cases: &[(&str, Metric<f32>, Locale)] = &[
+        ("4.7 kΩ", m(4700.0, "Ω"), Locale::en),
+        ("12.3 µs", m(12.3e-6, "s"), Locale::en),
+        ("1,5 mA", m(0.0015, "A"), Locale::de),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_metric_integer() {
+    let test_cases: &[(&str, Metric<i64>, Locale)] = &[
+        ("0 W", m(0, "W"), Locale::en),
+        ("999 W", m(999, "W"), Locale::en),
+        ("1 kW", m(1000, "W"), Locale::en),
+        ("-4.7 kΩ", m(-4700, "Ω"), Locale::en),
+        ("2.1 GW", m(2_100_000_000, "W"), Locale::en),
+        ("1,234567 MHz", m(1_234_567, "Hz"), Locale::de),
+        ("-9.223372036854775808 E", m(std::i64::MIN, ""), Locale::en),
+    ];
+    check!(test_cases);
+
+    let test_cases: &[(&str, Metric<u128>, Locale)] = &[(
+        "340,282,366.920938463463374607431768211455 QB",
+        m(std::u128::MAX, "B"),
+        Locale::en,
+    )];
+    check!(test_cases);
+
+    let test_cases: &[(&str, Metric<u8>, Locale)] = &[("255 g", m(255, "g"), Locale::en)];
+    check!(test_cases);
+}
+
+#[test]
+fn test_metric_custom_format() {
+    let format = CustomFormat::builder()
+        .minus_sign("−")
+        .separator(" ")
+        .build()
+        .unwrap();
+    let metric = FractionGrouped::new(m(-1234.567, "V"), 3, " ").unwrap();
+    let test_cases: &[(&str, FractionGrouped<Metric<f64>>, CustomFormat)] =
+        &[("−1.234 567 kV", metric, format)];
+    check!(test_cases);
+
+    assert!(Metric::new(1.0, "xxxxxxxxxxxxxxxxx").is_err());
+}
@@ -3,7 +3,10 @@
 mod common;
 
 use num_bigint::{BigInt, BigUint, Sign};
-use num_format::{CustomFormat, ToFormattedString, WriteFormatted};
+use num_format::{
+    CustomFormat, Locale, NumberPattern, Patterned, Radix, RadixFormat, Radixed, ToFormattedString,
+    WriteFormatted,
+};
 
 use crate::common::POLICIES;
 
@@ -193,3 +196,90 @@ fn test_num_big_uint() {
         assert_eq!(expected.to_string(), s);
     }
 }
+
+#[test]
+fn test_num_big_int_radix() {
+    let format = RadixFormat::builder()
+        .radix(Radix::Hexadecimal)
+        .group_size(4)
+        .separator("_")
+        .uppercase(true)
+        .build()
+        .unwrap();
+
+    let test_cases: &[(&str, BigInt)] = &[
+        ("0x0", BigInt::new(Sign::NoSign, vec![])),
+        ("0xDEAD_BEEF", BigInt::new(Sign::Plus, vec![0xdead_beef])),
+        ("-0xDEAD_BEEF", BigInt::new(Sign::Minus, vec![0xdead_beef])),
+        ("0x1_0000_0000", BigInt::new(Sign::Plus, vec![0, 1])),
+    ];
+
+    for (expected, input) in test_cases {
+        let input = &Radixed::new(input.clone(), format.clone());
+
+        // ToFormattedString
+        assert_eq!(expected.to_string(), input.to_formatted_string(&Locale::en));
+
+        // WriteFormatted (io::Write)
+        let mut v = Vec::new();
+        v.write_formatted(input, &Locale::en).unwrap();
+        let s = String::from_utf8(v).unwrap();
+        assert_eq!(expected.to_string(), s);
+
+        // WriteFormatted (fmt::Write)
+        let mut s = String::new();
+        s.write_formatted(input, &Locale::en).unwrap();
+        assert_eq!(expected.to_string(), s);
+    }
+
+    let n = BigUint::new(vec![0o755]);
+    let format = format
+        .into_builder()
+        .radix(Radix::Octal)
+        .group_size(0)
+        .prefix("0o")
+        .build()
+        .unwrap();
+    let n = Radixed::new(n, format);
+    assert_eq!("0o755", n.to_formatted_string(&Locale::en));
+}
+
+#[test]
+fn test_num_big_int_pattern() {
+    let pattern = NumberPattern::new("#,##0.00;(#,##0.00)").unwrap();
+
+    let test_cases: &[(&str, BigInt)] = &[
+        ("0.00", BigInt::new(Sign::NoSign, vec![])),
+        (
+            "3,735,928,559.00",
+            BigInt::new(Sign::Plus, vec![0xdead_beef]),
+        ),
+        (
+            "(3,735,928,559.00)",
+            BigInt::new(Sign::Minus, vec![0xdead_beef]),
+        ),
+        ("4,294,967,296.00", BigInt::new(Sign::Plus, vec![0, 1])),
+    ];
+
+    for (expected, input) in test_cases {
+        let input = &Patterned::new(input.clone(), pattern).unwrap();
+
+        // ToFormattedString
+        assert_eq!(expected.to_string(), input.to_formatted_string(&Locale::en));
+
+        // WriteFormatted (io::Write)
+        let mut v = Vec::new();
+        v.write_formatted(input, &Locale::en).unwrap();
+        let s = String::from_utf8(v).unwrap();
+        assert_eq!(expected.to_string(), s);
+
+        // WriteFormatted (fmt::Write)
+        let mut s = String::new();
+        s.write_formatted(input, &Locale::en).unwrap();
+        assert_eq!(expected.to_string(), s);
+    }
+
+    let n = BigUint::new(vec![0, 1]);
+    let n = Patterned::new(n, NumberPattern::new("#,##,##0%").unwrap()).unwrap();
+    assert_eq!("4,29,49,67,29,600%", n.to_formatted_string(&Locale::en));
+}
@@ -0,0 +1,152 @@
+#[macro_use]
+mod common;
+
+use num_format::{CustomFormat, Grouping, Locale, Ordinal, PluralCategory};
+
+fn o<N>(value: N, locale: Locale) -> (Ordinal<N>, Locale) {
+    (Ordinal::new(value, locale), locale)
+}
+
+#[test]
+fn test_ordinal_en() {
+    let test_cases: &[(&str, (Ordinal<i64>, Locale))] = &[
+        ("0th", o(0, Locale::en)),
+        ("1st", o(1, Locale::en)),
+        ("2nd", o(2, Locale::en)),
+        ("3rd", o(3, Locale::en)),
+        ("4th", o(4, Locale::en)),
+        ("11th", o(11, Locale::en)),
+        ("12th", o(12, Locale::en)),
+        ("13th", o(13, Locale::en)),
+        ("21st", o(21, Locale::en)),
+        ("22nd", o(22, Locale::en)),
+        ("23rd", o(23, Locale::en)),
+        ("101st", o(101, Locale::en)),
+        ("111th", o(111, Locale::en)),
+        ("112th", o(112, Locale::en)),
+        ("1,001st", o(1001, Locale::en)),
+        ("1,000,000th", o(1_000_000, Locale::en)),
+        ("-1st", o(-1, Locale::en)),
+        ("-22nd", o(-22, Locale::en)),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_ordinal_locales() {
+    let test_cases: &[(&str, (Ordinal<i32>, Locale))] = &[
+        ("1er", o(1, Locale::fr)),
+        ("2e", o(2, Locale::fr)),
+        ("21e", o(21, Locale::fr)),
+        ("1\u{202f}000e", o(1000, Locale::fr)),
+        ("-3e", o(-3, Locale::fr)),
+        ("1.º", o(1, Locale::es)),
+        ("2.º", o(2, Locale::es)),
+        ("1.000.º", o(1000, Locale::es)),
+        ("1º", o(1, Locale::it)),
+        ("8º", o(8, Locale::it)),
+        ("1.000º", o(1000, Locale::it)),
+        ("3º", o(3, Locale::pt)),
+        ("3.", o(3, Locale::de)),
+        ("1.000.", o(1000, Locale::de)),
+        ("8e", o(8, Locale::nl)),
+        ("1:a", o(1, Locale::sv)),
+        ("3:e", o(3, Locale::sv)),
+        ("21:a", o(21, Locale::sv)),
+        ("1r", o(1, Locale::ca)),
+        ("2n", o(2, Locale::ca)),
+        ("4t", o(4, Locale::ca)),
+        ("11è", o(11, Locale::ca)),
+        ("21r", o(21, Locale::ca)),
+        ("1-ви", o(1, Locale::bg)),
+        ("100-тен", o(100, Locale::bg)),
+        ("101-и", o(101, Locale::bg)),
+        ("1\u{a0}001-ви", o(1001, Locale::bg)),
+        ("2de", o(2, Locale::af)),
+        ("20ste", o(20, Locale::af)),
+        ("102de", o(102, Locale::af)),
+        ("1'inci", o(1, Locale::az)),
+        ("6ठा", o(6, Locale::hi)),
+        ("1,000वाँ", o(1000, Locale::hi)),
+        ("第3", o(3, Locale::ja)),
+        ("第-3", o(-3, Locale::ja)),
+    ];
+    check!(pairs: test_cases);
+
+    let test_cases: &[(&str, (Ordinal<u128>, Locale))] = &[
+        (
+            "340,282,366,920,938,463,463,374,607,431,768,211,455th",
+            o(std::u128::MAX, Locale::en),
+        ),
+        (
+            "340.282.366.920.938.463.463.374.607.431.768.211.455º",
+            o(std::u128::MAX, Locale::it),
+        ),
+    ];
+    check!(pairs: test_cases);
+
+    let test_cases: &[(&str, (Ordinal<i8>, Locale))] = &[("-128th", o(std::i8::MIN, Locale::en))];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_ordinal_custom_format() {
+    let test_cases: &[(&str, (Ordinal<u32>, CustomFormat))] = &[
+        (
+            "1,001st",
+            (Ordinal::new(1001, Locale::en), CustomFormat::default()),
+        ),
+        (
+            "1001st",
+            (
+                Ordinal::new(1001, Locale::en),
+                CustomFormat::builder()
+                    .grouping(Grouping::Posix)
+                    .build()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "1_001e",
+            (
+                Ordinal::new(1001, Locale::fr),
+                CustomFormat::builder().separator("_").build().unwrap(),
+            ),
+        ),
+        (
+            "1,00,001st",
+            (
+                Ordinal::new(100_001, Locale::en),
+                CustomFormat::builder()
+                    .grouping(Grouping::Indian)
+                    .build()
+                    .unwrap(),
+            ),
+        ),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_ordinal_plural_category() {
+    let test_cases: &[(PluralCategory, i64, Locale)] = &[
+        (PluralCategory::One, 1, Locale::en),
+        (PluralCategory::Two, 22, Locale::en),
+        (PluralCategory::Few, 103, Locale::en),
+        (PluralCategory::Other, 111, Locale::en),
+        (PluralCategory::One, 1, Locale::fr),
+        (PluralCategory::Other, 21, Locale::fr),
+        (PluralCategory::Other, 1, Locale::es),
+        (PluralCategory::Many, 8, Locale::it),
+        (PluralCategory::Other, 1, Locale::de),
+    ];
+    for (expected, n, locale) in test_cases {
+        assert_eq!(
+            *expected,
+            locale.ordinal_plural_category(*n),
+            "{} {:?}",
+            n,
+            locale
+        );
+    }
+}
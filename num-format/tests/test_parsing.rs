@@ -0,0 +1,752 @@
+use core::num::NonZeroU32;
+
+use num_format::parsing::{
+    Lenience, Lenient, Notated, Notation, ParseExpected, ParseFormatted, ParseFormattedBytes,
+    ParseReason,
+};
+use num_format::{ErrorKind, Locale, Roman};
+
+fn error<N>(result: Result<N, num_format::Error>) -> (usize, ParseReason) {
+    match result {
+        Ok(_) => panic!("parsed successfully"),
+        Err(e) => match e.kind() {
+            ErrorKind::ParseFormatted {
+                position, reason, ..
+            } => (*position, *reason),
+            kind => panic!("unexpected error kind {:?}", kind),
+        },
+    }
+}
+
+#[test]
+fn test_parsing_integers() {
+    let test_cases: &[(i64, &str, Locale)] = &[
+        (0, "0", Locale::en),
+        (1_000_000, "1,000,000", Locale::en),
+        (1_000_000, "1000000", Locale::en),
+        (-1_000_000, "-1,000,000", Locale::en),
+        (1_000_000, "+1,000,000", Locale::en),
+        (999, "999", Locale::en),
+        (-1_234_567, "-1.234.567", Locale::de),
+        (12_345_678, "1,23,45,678", Locale::en_IN),
+        (1234, "1\u{202f}234", Locale::fr),
+        (1234, "1234", Locale::en_US_POSIX),
+        (std::i64::MIN, "-9,223,372,036,854,775,808", Locale::en),
+    ];
+    for (expected, input, locale) in test_cases {
+        assert_eq!(*expected, input.parse_formatted::<_, i64>(locale).unwrap());
+    }
+
+    assert_eq!(255, "255".parse_formatted::<_, u8>(&Locale::en).unwrap());
+    assert_eq!(-128, "-128".parse_formatted::<_, i8>(&Locale::en).unwrap());
+    assert_eq!(
+        std::u128::MAX,
+        "340,282,366,920,938,463,463,374,607,431,768,211,455"
+            .parse_formatted::<_, u128>(&Locale::en)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_parsing_errors() {
+    use ParseReason::*;
+
+    let digit = |found| UnexpectedChar {
+        expected: ParseExpected::Digit,
+        found,
+    };
+    let digit_or_separator = |found| UnexpectedChar {
+        expected: ParseExpected::DigitOrSeparator,
+        found,
+    };
+    let test_cases: &[((usize, ParseReason), &str, Locale)] = &[
+        ((0, Empty), "", Locale::en),
+        ((1, Empty), "-", Locale::en),
+        ((0, digit('a')), "abc", Locale::en),
+        ((0, digit(' ')), " 1", Locale::en),
+        ((1, digit_or_separator(' ')), "1 ", Locale::en),
+        ((5, digit_or_separator('.')), "1,000.5", Locale::en),
+        ((5, digit_or_separator('a')), "1,000abc", Locale::en),
+        ((1, digit('a')), "1a", Locale::en_US_POSIX),
+        ((1, InvalidSign), "--1", Locale::en),
+        ((3, InvalidSign), "100-", Locale::en),
+        ((0, MisplacedSeparator), ",100", Locale::en),
+        ((1, MisplacedSeparator), "1,", Locale::en),
+        ((2, MisplacedSeparator), "1,,000", Locale::en),
+        ((4, MisplacedSeparator), "1234,567", Locale::en),
+        ((4, MisplacedSeparator), "1,00,000", Locale::en),
+        ((1, MisplacedSeparator), "1,0000", Locale::en),
+        ((3, MisplacedSeparator), "123,456", Locale::en_IN),
+        ((1, MisplacedSeparator), "1,000", Locale::en_US_POSIX),
+        ((1, digit_or_separator(',')), "1,000", Locale::de),
+        ((7, digit_or_separator('_')), "1\u{202f}234_567", Locale::fr),
+        ((0, PosOverflow), "9,223,372,036,854,775,808", Locale::en),
+        ((0, NegOverflow), "-9,223,372,036,854,775,809", Locale::en),
+        ((0, PosOverflow), "100000000000000000000000", Locale::en),
+    ];
+    for (expected, input, locale) in test_cases {
+        assert_eq!(
+            *expected,
+            error(input.parse_formatted::<_, i64>(locale)),
+            "{:?}",
+            input
+        );
+    }
+
+    assert_eq!(
+        (0, PosOverflow),
+        error("256".parse_formatted::<_, u8>(&Locale::en))
+    );
+    assert_eq!(
+        (0, NegOverflow),
+        error("-129".parse_formatted::<_, i8>(&Locale::en))
+    );
+    assert_eq!(
+        (0, InvalidSign),
+        error("-1".parse_formatted::<_, u8>(&Locale::en))
+    );
+    assert_eq!(
+        (0, Zero),
+        error("0".parse_formatted::<_, NonZeroU32>(&Locale::en))
+    );
+
+    // Roman numerals
+    let numeral = |found| UnexpectedChar {
+        expected: ParseExpected::Numeral,
+        found,
+    };
+    let test_cases: &[((usize, ParseReason), &str)] = &[
+        ((0, Empty), ""),
+        ((1, numeral('4')), "X4"),
+        ((1, numeral('i')), "Xi"),
+        ((0, numeral('I')), "IIII"),
+        ((0, numeral('I')), "IXX"),
+        ((1, numeral('I')), "XIIII"),
+    ];
+    for (expected, input) in test_cases {
+        assert_eq!(
+            *expected,
+            error(input.parse_formatted::<_, Roman<u32>>(&Locale::en)),
+            "{:?}",
+            input
+        );
+    }
+    assert_eq!(
+        (0, PosOverflow),
+        error("CCLVI".parse_formatted::<_, Roman<u8>>(&Locale::en))
+    );
+}
+
+#[test]
+fn test_parsing_prefix() {
+    let test_cases: &[((i64, usize), &str, Locale)] = &[
+        ((1234, 5), "1,234 bytes received", Locale::en),
+        ((1234, 5), "1,234", Locale::en),
+        ((-1234, 6), "-1,234.5", Locale::en),
+        ((1, 1), "1,23", Locale::en),
+        ((1, 1), "1, 2", Locale::en),
+        ((1, 1), "1,00,000", Locale::en),
+        ((1234, 4), "1234,567", Locale::en),
+        ((1234, 5), "1,2345", Locale::en),
+        ((12_345, 6), "12,345,67", Locale::en),
+        ((5, 1), "5-3", Locale::en),
+        ((1000, 4), "1000abc", Locale::en),
+        ((1, 1), "1,000", Locale::en_US_POSIX),
+        ((1_234_567, 9), "1.234.567,89", Locale::de),
+        ((1, 1), "1,234", Locale::de),
+        ((1_234, 7), "1\u{202f}234_567", Locale::fr),
+        ((12_345_678, 11), "1,23,45,678,9", Locale::en_IN),
+        ((1_234, 5), "1,234,56", Locale::en_IN),
+    ];
+    for (expected, input, locale) in test_cases {
+        assert_eq!(
+            *expected,
+            input.parse_formatted_prefix::<_, i64>(locale).unwrap(),
+            "{:?}",
+            input
+        );
+        assert_eq!(
+            *expected,
+            input
+                .as_bytes()
+                .parse_formatted_prefix::<_, i64>(locale)
+                .unwrap(),
+            "{:?}",
+            input
+        );
+    }
+
+    // Inputs that don't start with a number fail as if parsed whole
+    use ParseReason::*;
+    let test_cases: &[((usize, ParseReason), &str)] = &[
+        ((0, Empty), ""),
+        ((1, Empty), "-"),
+        ((0, MisplacedSeparator), ",123"),
+        ((1, InvalidSign), "--1"),
+        (
+            (
+                1,
+                UnexpectedChar {
+                    expected: ParseExpected::Digit,
+                    found: 'x',
+                },
+            ),
+            "-x1",
+        ),
+    ];
+    for (expected, input) in test_cases {
+        assert_eq!(
+            *expected,
+            error(input.parse_formatted_prefix::<_, i64>(&Locale::en)),
+            "{:?}",
+            input
+        );
+    }
+    assert_eq!(
+        (0, PosOverflow),
+        error("256 bytes".parse_formatted_prefix::<_, u8>(&Locale::en))
+    );
+    assert_eq!(
+        (0, Zero),
+        error("0 bytes".parse_formatted_prefix::<_, NonZeroU32>(&Locale::en))
+    );
+
+    // Bytes are only looked at up to the first invalid UTF-8 sequence
+    let bytes = b"1,234\xff";
+    assert_eq!(
+        (1234, 5),
+        bytes.parse_formatted_prefix::<_, u32>(&Locale::en).unwrap()
+    );
+    let bytes = b"12\xff34";
+    assert_eq!(
+        (12, 2),
+        bytes.parse_formatted_prefix::<_, u32>(&Locale::en).unwrap()
+    );
+
+    // Roman numerals
+    let test_cases: &[((u32, usize), &str)] = &[
+        ((14, 3), "XIV. Chapter"),
+        ((13, 4), "XIIII"),
+        ((4000, 6), "I\u{305}V\u{305}"),
+        ((1, 1), "iX"),
+    ];
+    for (expected, input) in test_cases {
+        let (roman, len) = input
+            .parse_formatted_prefix::<_, Roman<u32>>(&Locale::en)
+            .unwrap();
+        assert_eq!(*expected, (*roman.value(), len), "{:?}", input);
+    }
+    assert_eq!(
+        (
+            0,
+            UnexpectedChar {
+                expected: ParseExpected::Numeral,
+                found: 'a',
+            }
+        ),
+        error("a".parse_formatted_prefix::<_, Roman<u32>>(&Locale::en))
+    );
+}
+
+#[test]
+fn test_parsing_notated() {
+    use ParseReason::*;
+
+    let test_cases: &[((i64, Notation), &str, Locale)] = &[
+        ((1_234, Notation::Plain), "1,234", Locale::en),
+        ((1_234, Notation::Plain), "1,234.000", Locale::en),
+        ((1_200, Notation::Compact), "1.2K", Locale::en),
+        ((1_200, Notation::Compact), "1.2 thousand", Locale::en),
+        ((-3_000_000, Notation::Compact), "-3M", Locale::en),
+        ((2_500_000_000, Notation::Compact), "2.5B", Locale::en),
+        ((1_000_000_000_000, Notation::Compact), "1T", Locale::en),
+        ((1_200_000, Notation::Compact), "1,2\u{a0}Mio.", Locale::de),
+        ((1_200_000, Notation::Compact), "1,2 Mio.", Locale::de),
+        ((3_000_000, Notation::Compact), "3 Millionen", Locale::de),
+        ((-1_500_000_000, Notation::Compact), "-1,5 Mrd.", Locale::de),
+        ((120_000, Notation::Compact), "12万", Locale::ja),
+        ((2_000, Notation::Compact), "2 tys.", Locale::pl),
+        ((1_500, Notation::Scientific), "1.5E3", Locale::en),
+        ((1_500, Notation::Scientific), "1.5e+3", Locale::en),
+        ((-1_500, Notation::Scientific), "-1,5E3", Locale::de),
+        ((12, Notation::Scientific), "1,200E-2", Locale::en),
+        ((0, Notation::Scientific), "0E99", Locale::en),
+        ((45, Notation::Percent), "4,500%", Locale::en),
+        ((1, Notation::Percent), "100\u{a0}%", Locale::fr),
+        ((-1, Notation::Percent), "-%100", Locale::tr),
+        (
+            (std::i64::MIN, Notation::Scientific),
+            "-9,223,372,036,854,775,808E0",
+            Locale::en,
+        ),
+    ];
+    for (expected, input, format) in test_cases {
+        let n = input
+            .parse_formatted::<_, Notated<i64>>(format)
+            .unwrap_or_else(|e| panic!("{:?}: {}", input, e));
+        assert_eq!(*expected, (*n.value(), n.notation()), "{:?}", input);
+    }
+
+    let test_cases: &[((usize, ParseReason), &str, Locale)] = &[
+        ((0, Inexact), "1.2345K", Locale::en),
+        ((0, Inexact), "45%", Locale::en),
+        ((0, Inexact), "1.5", Locale::en),
+        ((2, ExponentOverflow), "1E99999", Locale::en),
+        ((0, PosOverflow), "1E19", Locale::en),
+        ((0, NegOverflow), "-1E19", Locale::en),
+        ((4, MisplacedSeparator), "1,00,000K", Locale::en),
+        (
+            (
+                4,
+                UnexpectedChar {
+                    expected: ParseExpected::End,
+                    found: 'b',
+                },
+            ),
+            "1.2Kb",
+            Locale::en,
+        ),
+        ((1, InvalidSign), "%-5", Locale::en),
+        // Compact suffixes are the locale's own
+        (
+            (
+                3,
+                UnexpectedChar {
+                    expected: ParseExpected::End,
+                    found: 'K',
+                },
+            ),
+            "1,2K",
+            Locale::de,
+        ),
+    ];
+    for (expected, input, format) in test_cases {
+        assert_eq!(
+            *expected,
+            error(input.parse_formatted::<_, Notated<i64>>(format)),
+            "{:?}",
+            input
+        );
+    }
+    assert_eq!(
+        (0, InvalidSign),
+        error("-5K".parse_formatted::<_, Notated<u32>>(&Locale::en))
+    );
+
+    // A compact suffix that starts a word isn't one
+    assert_eq!(
+        ((2_500, Notation::Compact), 4),
+        "2.5K users"
+            .parse_formatted_prefix::<_, Notated<u32>>(&Locale::en)
+            .map(|(n, len)| ((*n.value(), n.notation()), len))
+            .unwrap()
+    );
+    assert_eq!(
+        ((12, Notation::Plain), 2),
+        "12Kb"
+            .parse_formatted_prefix::<_, Notated<u32>>(&Locale::en)
+            .map(|(n, len)| ((*n.value(), n.notation()), len))
+            .unwrap()
+    );
+    assert_eq!(
+        ((1_200_000, Notation::Compact), 8),
+        "1,2 Mio. Einwohner"
+            .parse_formatted_prefix::<_, Notated<u32>>(&Locale::de)
+            .map(|(n, len)| ((*n.value(), n.notation()), len))
+            .unwrap()
+    );
+
+    // The suffixes come from CLDR's compact decimal formats, longest first
+    assert_eq!(
+        &[("万", 4), ("億", 8), ("兆", 12)],
+        Locale::ja.compact_suffixes()
+    );
+    assert_eq!(
+        Some(&("Millionen", 6)),
+        Locale::de.compact_suffixes().get(3)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parsing_notated_decimal() {
+    use num_format::Decimal;
+
+    let test_cases: &[(&str, &str, Locale)] = &[
+        ("0.45", "45 %", Locale::fr),
+        ("-0.005", "-0.5%", Locale::en),
+        ("0.00125", "1.25E-3", Locale::en),
+        ("15.0", "1.50E1", Locale::en),
+        ("1234.5", "1.2345K", Locale::en),
+        ("1234.5", "1.234,5", Locale::de),
+        ("0", "-0E5", Locale::en),
+    ];
+    for (expected, input, format) in test_cases {
+        let n = input
+            .parse_formatted::<_, Notated<Decimal>>(format)
+            .unwrap_or_else(|e| panic!("{:?}: {}", input, e));
+        assert_eq!(*expected, n.value().to_string(), "{:?}", input);
+    }
+
+    let (n, len) = "1.2Kb"
+        .parse_formatted_prefix::<_, Notated<Decimal>>(&Locale::en)
+        .unwrap();
+    assert_eq!(("1.2".to_string(), 3), (n.value().to_string(), len));
+}
+
+#[test]
+fn test_parsing_bytes() {
+    use ParseReason::*;
+
+    let test_cases: &[(i64, &[u8], Locale)] = &[
+        (1_234_567, b"1,234,567", Locale::en),
+        (-1_234_567, b"-1.234.567", Locale::de),
+        (1234, "1\u{202f}234".as_bytes(), Locale::fr),
+        (-1234, "\u{200e}\u{2212}1\u{66c}234".as_bytes(), Locale::fa),
+    ];
+    for (expected, input, format) in test_cases {
+        assert_eq!(*expected, input.parse_formatted::<_, i64>(format).unwrap());
+    }
+
+    // Errors are at byte offsets into the bytes
+    assert_eq!(
+        (
+            2,
+            UnexpectedChar {
+                expected: ParseExpected::DigitOrSeparator,
+                found: 'x',
+            }
+        ),
+        error(b"12x\xff".parse_formatted::<_, u32>(&Locale::en))
+    );
+    assert_eq!(
+        (2, Malformed),
+        error(b"12\xff".parse_formatted::<_, u32>(&Locale::en))
+    );
+    assert_eq!(
+        (0, Malformed),
+        error(b"\xff12".parse_formatted::<_, u32>(&Locale::en))
+    );
+    assert_eq!(
+        (0, Malformed),
+        error(b"\xff12".parse_formatted_prefix::<_, u32>(&Locale::en))
+    );
+
+    // Prefixes are read without validating what comes after the character that ends them
+    let mut bytes = b"1,234\n".repeat(10_000);
+    bytes.push(b'\xff');
+    let mut rest = bytes.as_slice();
+    let mut sum = 0;
+    while let Ok((n, len)) = rest.parse_formatted_prefix::<_, u32>(&Locale::en) {
+        sum += n;
+        rest = &rest[len + 1..];
+    }
+    assert_eq!(12_340_000, sum);
+    assert_eq!(b"\xff", rest);
+}
+
+#[cfg(feature = "with-encoding")]
+#[test]
+fn test_parsing_encoded() {
+    use num_format::Encoding;
+    use ParseReason::*;
+
+    let latin_1 = Encoding::for_label(b"latin1").unwrap();
+    let shift_jis = Encoding::for_label(b"Shift_JIS").unwrap();
+    let utf_16le = Encoding::for_label(b"UTF-16LE").unwrap();
+    let utf_8 = Encoding::for_label(b"utf8").unwrap();
+    let windows_1252 = Encoding::for_label(b"cp1252").unwrap();
+    assert_eq!("windows-1252", latin_1.name());
+    assert_eq!(latin_1, windows_1252);
+    assert_eq!("EUC-JP", Encoding::for_label(b"eucJP").unwrap().name());
+    assert_eq!(None, Encoding::for_label(b"EBCDIC"));
+
+    let test_cases: &[(i64, &[u8], Locale, Encoding)] = &[
+        (1_234_567, b"1,234,567", Locale::en, latin_1),
+        (1_234_567, b"1\xa0234\xa0567", Locale::fr, latin_1),
+        (-1_234_567, b"-1\x92234\x92567", Locale::de_CH, windows_1252),
+        (1234, b"1\xe2\x80\xaf234", Locale::fr, utf_8),
+        (-12, b"-\x001\x002\x00", Locale::en, utf_16le),
+        (1234, b"1,234", Locale::en, shift_jis),
+    ];
+    for (expected, input, format, encoding) in test_cases {
+        let n = input
+            .parse_formatted_encoded::<_, i64>(format, *encoding)
+            .unwrap_or_else(|e| panic!("{:?}: {}", input, e));
+        assert_eq!(*expected, n);
+    }
+
+    // Lengths and errors are in bytes of the encoded input
+    assert_eq!(
+        (1234, 5),
+        b"1\xa0234\xa0octets"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::fr, latin_1)
+            .unwrap()
+    );
+    assert_eq!(
+        (12, 4),
+        b"1\x002\x00a\x00"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::en, utf_16le)
+            .unwrap()
+    );
+    assert_eq!(
+        (
+            5,
+            UnexpectedChar {
+                expected: ParseExpected::DigitOrSeparator,
+                found: '\u{e9}',
+            }
+        ),
+        error(b"1\xa0234\xe9".parse_formatted_encoded::<_, u32>(&Locale::fr, latin_1))
+    );
+    assert_eq!(
+        (2, Malformed),
+        error(b"12\x81".parse_formatted_encoded::<_, u32>(&Locale::en, shift_jis))
+    );
+    assert_eq!(
+        (
+            4,
+            UnexpectedChar {
+                expected: ParseExpected::DigitOrSeparator,
+                found: 'x',
+            }
+        ),
+        error(b"1\x002\x00x\x00".parse_formatted_encoded::<_, u32>(&Locale::en, utf_16le))
+    );
+    assert_eq!(
+        (4, Malformed),
+        error(b"1\x002\x00\x00\xd8".parse_formatted_encoded::<_, u32>(&Locale::en, utf_16le))
+    );
+    assert_eq!(
+        (1234, 5),
+        b"1,234\x83\x41"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::en, shift_jis)
+            .unwrap()
+    );
+    assert_eq!(
+        (12, 2),
+        b"12\x81"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::en, shift_jis)
+            .unwrap()
+    );
+
+    // Prefixes are decoded only up to the character that ends them
+    assert_eq!(
+        (1234, 5),
+        b"1,234\n\x81"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::en, shift_jis)
+            .unwrap()
+    );
+    assert_eq!(
+        (1234, 5),
+        b"1\xa0234\n\x81"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::fr, windows_1252)
+            .unwrap()
+    );
+    assert_eq!(
+        (12, 4),
+        b"1\x002\x00\n\x00\x00\xd8"
+            .parse_formatted_prefix_encoded::<_, u32>(&Locale::en, utf_16le)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_parsing_find() {
+    let s = "Von 1.234,5 Punkten blieben -12 übrig, also 1.000.";
+    let found: Vec<_> = s.find_formatted(&Locale::de).collect();
+    let ranges: Vec<_> = found.iter().map(|found| found.range()).collect();
+    assert_eq!(vec![4..11, 28..31, 45..50], ranges);
+    assert_eq!("1.234,5", found[0].as_str());
+    assert!(found[0].has_fraction());
+    assert!(found[0].parse::<u32>().is_err());
+    assert!(found[1].is_negative());
+    assert_eq!(-12, found[1].parse::<i32>().unwrap());
+    assert_eq!(1000, found[2].parse::<u64>().unwrap());
+
+    // Signs right after letters or digits are hyphens, and separators and decimal marks only
+    // count when followed by digits
+    let s = "pages 5-7, A-4, +3 and -2; 1,000, 2,5 or 10.5.";
+    let found: Vec<_> = s
+        .find_formatted(&Locale::en)
+        .map(|found| found.as_str())
+        .collect();
+    assert_eq!(
+        vec!["5", "7", "4", "+3", "-2", "1,000", "2", "5", "10.5"],
+        found
+    );
+
+    // Numbers are found the way they're parsed, so each one parses
+    let s = "1,23,45,678 (12,345,678) -\u{2212}5";
+    let found: Vec<_> = s.find_formatted(&Locale::en_IN).collect();
+    let numbers: Vec<_> = found
+        .iter()
+        .map(|found| found.parse::<i64>().unwrap())
+        .collect();
+    assert_eq!(vec![12_345_678, 12_345, 678, -5], numbers);
+
+    // Digits that aren't grouped like a number aren't one, and neither are the digits after them
+    let s = "ratio 1,2345 and 1,234 or 12.34567";
+    let found: Vec<_> = s
+        .find_formatted(&Locale::en)
+        .map(|found| found.range())
+        .collect();
+    assert_eq!(vec![17..22, 26..34], found);
+
+    assert_eq!(0, "".find_formatted(&Locale::en).count());
+    assert_eq!(0, "no numbers - here".find_formatted(&Locale::en).count());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parsing_find_decimal() {
+    let s = "-1,234,567,890,123,456,789,012.50 and 0.001";
+    let decimals: Vec<_> = s
+        .find_formatted(&Locale::en)
+        .map(|found| found.decimal().to_string())
+        .collect();
+    assert_eq!(vec!["-1234567890123456789012.50", "0.001"], decimals);
+}
+
+#[test]
+fn test_parsing_lenience() {
+    // Characters that count as the separator and signs under the default CLDR lenience
+    let test_cases: &[(i64, &str, Locale)] = &[
+        (1234, "1\u{202f}234", Locale::fr),
+        (1234, "1 234", Locale::fr),
+        (1234, "1\u{a0}234", Locale::fr),
+        (-1234, "\u{2212}1 234", Locale::fr),
+        (1234, "1'234", Locale::de_CH),
+        (1234, "1\u{2019}234", Locale::de_CH),
+        (-5, "-5", Locale::ar_DZ),
+        (-5, "\u{200e}-5", Locale::ar_DZ),
+        (-5, "\u{200e}\u{2212}5", Locale::ar_DZ),
+        (-5, "-5", Locale::fa),
+        (-5, "\u{2212}5", Locale::fa),
+        (-5, "\u{61c}-5", Locale::ar),
+        (-1234, "\u{ff0d}1\u{ff0c}234", Locale::en),
+        (5, "\u{ff0b}5", Locale::en),
+    ];
+    for (expected, input, locale) in test_cases {
+        assert_eq!(
+            *expected,
+            input.parse_formatted::<_, i64>(locale).unwrap(),
+            "{:?}",
+            input
+        );
+    }
+
+    // Characters of different classes don't count as one another
+    let test_cases: &[(&str, Locale)] = &[
+        ("1 234", Locale::en),
+        ("1.234", Locale::fr),
+        ("1'234", Locale::fr),
+        ("\u{200e}5", Locale::ar_DZ),
+    ];
+    for (input, locale) in test_cases {
+        assert!(
+            input.parse_formatted::<_, i64>(locale).is_err(),
+            "{:?}",
+            input
+        );
+    }
+
+    // Strict formats take only exactly the separator and signs of the format
+    let strict = Lenient::new(&Locale::fr, Lenience::STRICT);
+    assert_eq!(
+        1234,
+        "1\u{202f}234".parse_formatted::<_, i64>(&strict).unwrap()
+    );
+    let reason = ParseReason::UnexpectedChar {
+        expected: ParseExpected::DigitOrSeparator,
+        found: ' ',
+    };
+    assert_eq!(
+        (1, reason),
+        error("1 234".parse_formatted::<_, i64>(&strict))
+    );
+    let strict = Lenient::new(&Locale::ar_DZ, Lenience::STRICT);
+    assert!("-5".parse_formatted::<_, i64>(&strict).is_err());
+    assert_eq!(-5, "\u{200e}-5".parse_formatted::<_, i64>(&strict).unwrap());
+
+    // Custom tables
+    let lenience = Lenience::new(&[",_", ".;"], "*");
+    let lenient = Lenient::new(&Locale::en, lenience);
+    assert_eq!(
+        1_000_000,
+        "1_000,000".parse_formatted::<_, i64>(&lenient).unwrap()
+    );
+    assert_eq!(
+        -1_000,
+        "*-1*,000".parse_formatted::<_, i64>(&lenient).unwrap()
+    );
+    assert!(lenience.is_equivalent('.', ';'));
+    assert!(!lenience.is_equivalent(',', ';'));
+    assert_eq!("*", lenience.ignorable());
+
+    // Lenient formats keep the locale of the format they wrap
+    let strict = Lenient::new(&Locale::tr, Lenience::STRICT);
+    let n = "-%100".parse_formatted::<_, Notated<i64>>(&strict).unwrap();
+    assert_eq!((-1, Notation::Percent), (*n.value(), n.notation()));
+
+    // Finding numbers uses the lenience too
+    let s = "De 1 234,5 à \u{2212}12";
+    let found: Vec<_> = s
+        .find_formatted(&Locale::fr)
+        .map(|found| found.as_str())
+        .collect();
+    assert_eq!(vec!["1 234,5", "\u{2212}12"], found);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parsing_infer_locales() {
+    use num_format::parsing::infer_locales;
+
+    let locales = infer_locales(["1.234,5", "-12", "1.000.000"]);
+    assert!(locales.contains(&Locale::de));
+    assert!(locales.contains(&Locale::es));
+    assert!(!locales.contains(&Locale::en));
+    assert!(!locales.contains(&Locale::fr));
+    assert!(locales.iter().all(|locale| locale.decimal() == ","));
+
+    let locales = infer_locales(["1,23,456.7"]);
+    assert!(locales.contains(&Locale::hi));
+    assert!(locales.contains(&Locale::en_IN));
+    assert!(!locales.contains(&Locale::en));
+
+    let locales = infer_locales(vec![String::from("1\u{202f}234,5")]);
+    assert!(locales.contains(&Locale::fr));
+    assert!(!locales.contains(&Locale::de));
+
+    // Ambiguous samples rank the more common conventions first, and languages before regions
+    let locales = infer_locales(["1.234"]);
+    assert_eq!(".", locales[0].decimal());
+    let en = locales.iter().position(|&locale| locale == Locale::en);
+    let en_gb = locales.iter().position(|&locale| locale == Locale::en_GB);
+    assert!(en < en_gb);
+
+    assert!(infer_locales(["1,2,3"]).is_empty());
+    assert_eq!(
+        Locale::available_names().len(),
+        infer_locales(Vec::<&str>::new()).len()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parsing_error_display() {
+    let e = "1,00,000"
+        .parse_formatted::<_, u32>(&Locale::en)
+        .unwrap_err();
+    assert_eq!(
+        "Failed to parse 1,00,000 into a number at byte 4: the separator is misplaced.",
+        e.to_string()
+    );
+    let e = "12x".parse_formatted::<_, u32>(&Locale::en).unwrap_err();
+    assert_eq!(
+        "Failed to parse 12x into a number at byte 2: expected a digit or separator but found \
+         'x'.",
+        e.to_string()
+    );
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use core::fmt::Debug;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use num_format::parsing::{
+    FromFormattedStr, Notated, ParseFormat, ParseFormatted, ParseFormattedBytes, ParseReason,
+};
+use num_format::{Decimal, Error, ErrorKind, Locale, ToFormattedString};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::common::POLICIES;
+
+fn locales() -> Vec<Locale> {
+    Locale::available_names()
+        .iter()
+        .map(|name| Locale::from_name(name).unwrap())
+        .collect()
+}
+
+/// Checks that `n` comes back from its formatted string under `format`, both as a whole and as
+/// the prefix of a longer string.
+fn check_roundtrip<F, N>(n: N, format: &F)
+where
+    F: ParseFormat,
+    N: FromFormattedStr + ToFormattedString + Copy + Debug + PartialEq,
+{
+    let s = n.to_formatted_string(format);
+    let parsed = s
+        .parse_formatted::<_, N>(format)
+        .unwrap_or_else(|e| panic!("{:?} ({:?}): {}", s, n, e));
+    assert_eq!(n, parsed, "{:?}", s);
+
+    let longer = format!("{} apples", s);
+    let (parsed, len) = longer
+        .parse_formatted_prefix::<_, N>(format)
+        .unwrap_or_else(|e| panic!("{:?} ({:?}): {}", longer, n, e));
+    assert_eq!((n, s.len()), (parsed, len), "{:?}", longer);
+}
+
+macro_rules! check_roundtrips {
+    ($rng:expr, $format:expr, $($type:ty),*) => {
+        $(
+            // The extremes and the numbers around each power of ten
+            let mut values = vec![<$type>::MIN, <$type>::MAX, 0, 1];
+            let mut power: $type = 1;
+            while let Some(next) = power.checked_mul(10) {
+                power = next;
+                values.extend_from_slice(&[power - 1, power, power + 1]);
+            }
+            values.extend(values.clone().into_iter().filter_map(|n| n.checked_neg()));
+
+            // And random numbers of every size
+            for _ in 0..16 {
+                let n = $rng.gen::<$type>();
+                values.push(n);
+                values.push(n >> $rng.gen_range(0..<$type>::BITS));
+            }
+
+            for n in values {
+                check_roundtrip(n, $format);
+            }
+        )*
+    };
+}
+
+macro_rules! check_non_zero_roundtrips {
+    ($rng:expr, $format:expr, $($type:ty),*) => {
+        $(
+            // The smallest, the largest (all ones once truncated), and random numbers
+            let mut values = vec![<$type>::new(1).unwrap(), <$type>::new(!0u128 as _).unwrap()];
+            values.extend((0..4).filter_map(|_| <$type>::new($rng.gen::<u128>() as _)));
+            for n in values {
+                check_roundtrip(n, $format);
+            }
+        )*
+    };
+}
+
+#[test]
+fn test_parsing_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0x6e75_6d2d_666f_726d);
+    for locale in locales() {
+        check_roundtrips!(rng, &locale, u8, u16, u32, usize, u64, u128);
+        check_roundtrips!(rng, &locale, i8, i16, i32, isize, i64, i128);
+        check_non_zero_roundtrips!(
+            rng,
+            &locale,
+            NonZeroU8,
+            NonZeroU16,
+            NonZeroU32,
+            NonZeroUsize,
+            NonZeroU64,
+            NonZeroU128
+        );
+    }
+    for format in POLICIES.iter() {
+        check_roundtrips!(rng, format, u8, u16, u32, usize, u64, u128);
+        check_roundtrips!(rng, format, i8, i16, i32, isize, i64, i128);
+    }
+}
+
+#[test]
+fn test_parsing_leading_zeros() {
+    // Leading zeros don't count toward the number of digits a type can have
+    let zeros = "0".repeat(1_000);
+    let format = Locale::en_US_POSIX;
+    let parse = |digits: &str| format!("{}{}", zeros, digits).parse_formatted::<_, u8>(&format);
+    assert_eq!(255, parse("255").unwrap());
+    assert_eq!(0, parse("").unwrap());
+    assert!(parse("256").is_err());
+
+    let parse = |digits: &str| format!("-{}{}", zeros, digits).parse_formatted::<_, i8>(&format);
+    assert_eq!(-128, parse("128").unwrap());
+    assert!(parse("129").is_err());
+
+    let s = format!("{}{}", zeros, std::u128::MAX);
+    assert_eq!(
+        std::u128::MAX,
+        s.parse_formatted::<_, u128>(&format).unwrap()
+    );
+    let s = format!("{}{}0", zeros, std::u128::MAX);
+    match s.parse_formatted::<_, u128>(&format).unwrap_err().kind() {
+        ErrorKind::ParseFormatted {
+            position: 0,
+            reason: ParseReason::PosOverflow,
+            ..
+        } => (),
+        kind => panic!("unexpected error kind {:?}", kind),
+    }
+}
+
+/// Checks that `s` parses into `T` exactly when it parses into an `i128` that fits `T` (or into
+/// a `u128` too large for `i128`).
+fn check_consistent<F, T>(s: &str, format: &F, wide: &Result<i128, Error>)
+where
+    F: ParseFormat,
+    T: FromFormattedStr + Copy + Debug + TryFrom<i128>,
+    i128: TryFrom<T>,
+{
+    let narrow = s.parse_formatted::<_, T>(format);
+    match (wide, &narrow) {
+        (Ok(v), Ok(t)) => assert_eq!(Some(*v), i128::try_from(*t).ok(), "{:?}", s),
+        (Ok(v), Err(e)) => {
+            let is_invalid_sign = matches!(
+                e.kind(),
+                ErrorKind::ParseFormatted {
+                    reason: ParseReason::InvalidSign,
+                    ..
+                }
+            );
+            assert!(
+                T::try_from(*v).is_err() || is_invalid_sign,
+                "{:?}: {}",
+                s,
+                e
+            );
+        }
+        (Err(_), Ok(t)) => assert!(i128::try_from(*t).is_err(), "{:?}", s),
+        (Err(_), Err(_)) => (),
+    }
+}
+
+/// Parses `s` in every way there is, which must never panic.
+fn check_parses<F>(s: &str, format: &F)
+where
+    F: ParseFormat,
+{
+    let wide = s.parse_formatted::<_, i128>(format);
+    check_consistent::<_, u8>(s, format, &wide);
+    check_consistent::<_, u16>(s, format, &wide);
+    check_consistent::<_, u32>(s, format, &wide);
+    check_consistent::<_, usize>(s, format, &wide);
+    check_consistent::<_, u64>(s, format, &wide);
+    check_consistent::<_, u128>(s, format, &wide);
+    check_consistent::<_, i8>(s, format, &wide);
+    check_consistent::<_, i16>(s, format, &wide);
+    check_consistent::<_, i32>(s, format, &wide);
+    check_consistent::<_, isize>(s, format, &wide);
+    check_consistent::<_, i64>(s, format, &wide);
+
+    // A prefix is a number of its own
+    if let Ok((n, len)) = s.parse_formatted_prefix::<_, i128>(format) {
+        assert_eq!(
+            n,
+            (&s[..len]).parse_formatted::<_, i128>(format).unwrap(),
+            "{:?}",
+            s
+        );
+    }
+    // Bytes are read as far as the string is
+    assert_eq!(
+        s.parse_formatted_prefix::<_, i128>(format).ok(),
+        s.as_bytes().parse_formatted_prefix::<_, i128>(format).ok(),
+        "{:?}",
+        s
+    );
+    assert_eq!(
+        s.parse_formatted_prefix::<_, Notated<i64>>(format).ok(),
+        s.as_bytes()
+            .parse_formatted_prefix::<_, Notated<i64>>(format)
+            .ok(),
+        "{:?}",
+        s
+    );
+    let _ = s.parse_formatted::<_, NonZeroU32>(format);
+    let _ = s.parse_formatted::<_, Notated<i64>>(format);
+    let _ = s.parse_formatted_prefix::<_, Notated<Decimal>>(format);
+    let _ = s.as_bytes().parse_formatted::<_, i64>(format);
+    let _ = s.as_bytes()[..s.len() / 2].parse_formatted_prefix::<_, u64>(format);
+    for found in s.find_formatted(format) {
+        let _ = found.parse::<i32>();
+        let _ = found.decimal();
+    }
+}
+
+#[test]
+fn test_parsing_fuzz() {
+    let alphabet: Vec<char> = "0123456789000111999,,..  -+%EeK\u{a0}\u{202f}\u{2212}\u{200e}\
+                               \u{61c}\u{66b}\u{66c}\u{2019}'x\u{305}I𠜱"
+        .chars()
+        .collect();
+    let locales = locales();
+    let mut rng = StdRng::seed_from_u64(0x0070_6172_7369_6e67);
+    for _ in 0..20_000 {
+        let len = rng.gen_range(0..48);
+        let s: String = (0..len)
+            .map(|_| *alphabet.choose(&mut rng).unwrap())
+            .collect();
+        check_parses(&s, locales.choose(&mut rng).unwrap());
+        check_parses(&s, POLICIES.choose(&mut rng).unwrap());
+    }
+
+    // Arbitrary bytes
+    for _ in 0..5_000 {
+        let len = rng.gen_range(0..32);
+        let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        let locale = locales.choose(&mut rng).unwrap();
+        let _ = bytes.parse_formatted::<_, i64>(locale);
+        let _ = bytes.parse_formatted_prefix::<_, u128>(locale);
+    }
+}
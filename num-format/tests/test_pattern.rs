@@ -0,0 +1,274 @@
+#[macro_use]
+mod common;
+
+use num_format::{CustomFormat, ErrorKind, FractionGrouped, Locale, NumberPattern, Patterned};
+#[cfg(feature = "std")]
+use num_format::{Decimal, ToFormattedString, WriteFormatted};
+
+fn p<T>(value: T, pattern: &str) -> Patterned<T> {
+    Patterned::new(value, NumberPattern::new(pattern).unwrap()).unwrap()
+}
+
+#[test]
+fn test_pattern_parse() {
+    let pattern: NumberPattern = "#,##0.00;(#,##0.00)".parse().unwrap();
+    assert_eq!(1, pattern.minimum_integer_digits());
+    assert_eq!(2, pattern.minimum_fraction_digits());
+    assert_eq!(2, pattern.maximum_fraction_digits());
+    assert_eq!(3, pattern.grouping_size());
+    assert_eq!(3, pattern.secondary_grouping_size());
+    assert_eq!(1, pattern.multiplier());
+    assert_eq!("", pattern.positive_prefix());
+    assert_eq!("", pattern.positive_suffix());
+    assert_eq!(Some("("), pattern.negative_prefix());
+    assert_eq!(Some(")"), pattern.negative_suffix());
+    assert!(!pattern.decimal_always_shown());
+
+    let pattern = NumberPattern::new("#,##,##0.###").unwrap();
+    assert_eq!(0, pattern.minimum_fraction_digits());
+    assert_eq!(3, pattern.maximum_fraction_digits());
+    assert_eq!(3, pattern.grouping_size());
+    assert_eq!(2, pattern.secondary_grouping_size());
+    assert_eq!(None, pattern.negative_prefix());
+
+    assert_eq!(100, NumberPattern::new("0%").unwrap().multiplier());
+    assert_eq!(1000, NumberPattern::new("0‰").unwrap().multiplier());
+    assert_eq!(1, NumberPattern::new("0'%'").unwrap().multiplier());
+    assert_eq!(
+        4,
+        NumberPattern::new("0000").unwrap().minimum_integer_digits()
+    );
+    assert_eq!(
+        0,
+        NumberPattern::new("#.00").unwrap().minimum_integer_digits()
+    );
+    assert_eq!(
+        1,
+        NumberPattern::new("#.##").unwrap().minimum_integer_digits()
+    );
+    assert!(NumberPattern::new("#,##0.").unwrap().decimal_always_shown());
+}
+
+#[test]
+fn test_pattern_round_trip() {
+    let test_cases: &[(&str, &str)] = &[
+        ("#,##0.00;(#,##0.00)", "#,##0.00;(#,##0.00)"),
+        ("#,##,##0.###", "#,##,##0.###"),
+        ("#,###,##0.###", "#,##0.###"),
+        ("##0", "0"),
+        ("#", "0"),
+        ("#.##", "0.##"),
+        (".##", "#.0#"),
+        ("#.00", "#.00"),
+        ("00,000", "00,000"),
+        ("#0,00", "0,00"),
+        ("#,#0", "#,#0"),
+        ("#,##0.", "#,##0."),
+        ("0.00%", "0.00%"),
+        ("#,##0‰", "#,##0‰"),
+        ("#,##0.###;-#", "#,##0.###"),
+        ("#,##0;", "#,##0"),
+        ("'#'#,##0;'-'0", "'#'#,##0;'-'#,##0"),
+        ("x'it''s'0 y", "x'it''s'0 y"),
+        ("0;(0", "0;(0"),
+        ("+0;-0", "+0;-0"),
+    ];
+    for (input, expected) in test_cases {
+        let pattern = NumberPattern::new(input).unwrap();
+        assert_eq!(*expected, pattern.to_string());
+        assert_eq!(pattern, expected.parse().unwrap());
+    }
+}
+
+#[test]
+fn test_pattern_errors() {
+    let test_cases: &[&str] = &[
+        "",
+        "x",
+        ";0",
+        "0#",
+        "#,##0.##0",
+        "#,##0,",
+        "#,,##0",
+        ",##0",
+        "#.#.#",
+        "0 5",
+        "0 m.",
+        "0E0",
+        "@@",
+        "0@",
+        "0*x",
+        "¤0",
+        "#,##0.05",
+        "0 'x",
+        "0;0;0",
+        "0%‰",
+    ];
+    for input in test_cases {
+        let error = NumberPattern::new(input).unwrap_err();
+        match error.kind() {
+            ErrorKind::ParsePattern(_) => (),
+            kind => panic!("unexpected error kind {:?} for {:?}", kind, input),
+        }
+    }
+
+    let error = NumberPattern::new("'a very long prefix that does not fit'0").unwrap_err();
+    match error.kind() {
+        ErrorKind::Capacity { .. } => (),
+        kind => panic!("unexpected error kind {:?}", kind),
+    }
+
+    // The longest f64 written with the pattern has to fit into a buffer
+    let pattern = NumberPattern::new(format!("#,##0.{}", "#".repeat(4000))).unwrap();
+    let error = Patterned::new(1.0, pattern).unwrap_err();
+    match error.kind() {
+        ErrorKind::Capacity { .. } => (),
+        kind => panic!("unexpected error kind {:?}", kind),
+    }
+    assert!(Patterned::new(1.0, NumberPattern::new("(#)").unwrap()).is_ok());
+}
+
+#[test]
+fn test_pattern_integers() {
+    let test_cases: &[(&str, Patterned<i64>)] = &[
+        ("0.00", p(0, "#,##0.00;(#,##0.00)")),
+        ("1,234.00", p(1234, "#,##0.00;(#,##0.00)")),
+        ("(1,234.00)", p(-1234, "#,##0.00;(#,##0.00)")),
+        ("12,34,567", p(1234567, "#,##,##0.###")),
+        ("0005", p(5, "0000")),
+        ("-0042", p(-42, "0000")),
+        ("123456", p(123456, "0000")),
+        ("1,234.", p(1234, "#,##0.")),
+        ("#5", p(5, "'#'0;'-'0")),
+        ("-5", p(-5, "'#'0;'-'0")),
+        ("+5", p(5, "+0;-0")),
+        ("-5", p(-5, "+0;-0")),
+        ("+0", p(0, "+0;-0")),
+        ("1,23,45", p(12345, "#,#0")),
+        ("1,500 km", p(1500, "#,##0 'km'")),
+        ("100.0%", p(1, "0.0%")),
+        ("xit's5 y", p(5, "x'it''s'0 y")),
+        ("-xit's5 y", p(-5, "x'it''s'0 y")),
+    ];
+    check!(test_cases, &Locale::en);
+
+    let test_cases: &[(&str, Patterned<i128>)] = &[(
+        "(170,141,183,460,469,231,731,687,303,715,884,105,728.00)",
+        p(std::i128::MIN, "#,##0.00;(#,##0.00)"),
+    )];
+    check!(test_cases, &Locale::en);
+
+    let test_cases: &[(&str, Patterned<u8>)] = &[("25500%", p(255, "#%"))];
+    check!(test_cases, &Locale::en);
+
+    let test_cases: &[(&str, Patterned<std::num::NonZeroU32>)] = &[(
+        "1,000.00",
+        p(std::num::NonZeroU32::new(1000).unwrap(), "#,##0.00"),
+    )];
+    check!(test_cases, &Locale::en);
+}
+
+#[test]
+fn test_pattern_floats() {
+    let test_cases: &[(&str, Patterned<f64>)] = &[
+        ("1,234.50", p(1234.5, "#,##0.00;(#,##0.00)")),
+        ("(1,234.57)", p(-1234.567, "#,##0.00;(#,##0.00)")),
+        ("0.00", p(0.005, "#,##0.00;(#,##0.00)")),
+        ("0.02", p(0.015, "#,##0.00;(#,##0.00)")),
+        ("(0.00)", p(-0.001, "#,##0.00;(#,##0.00)")),
+        (
+            "100,000,000,000,000,000,000.00",
+            p(1e20, "#,##0.00;(#,##0.00)"),
+        ),
+        ("NaN", p(std::f64::NAN, "#,##0.00;(#,##0.00)")),
+        ("∞", p(std::f64::INFINITY, "#,##0.00;(#,##0.00)")),
+        ("(∞)", p(std::f64::NEG_INFINITY, "#,##0.00;(#,##0.00)")),
+        ("-12,34,567.892", p(-1234567.8915, "#,##,##0.###")),
+        ("0", p(0.0005, "#,##,##0.###")),
+        ("12.3%", p(0.1234, "0.0%")),
+        ("-50.0%", p(-0.5, "0.0%")),
+        ("0.0%", p(0.00049, "0.0%")),
+        ("1,234‰", p(1.2345, "#,##0‰")),
+        (".50", p(0.5, "#.00")),
+        ("-.25", p(-0.25, "#.00")),
+        ("3.00", p(3.0, "#.00")),
+        ("1.25", p(1.25, "0.0#")),
+        ("0.0", p(0.005, "0.0#")),
+        ("3.0", p(2.999, "0.0#")),
+        ("0", p(0.5, "0")),
+        ("2", p(1.5, "0")),
+        ("2", p(2.5, "0")),
+        ("-2", p(-2.5, "0")),
+        ("-0", p(-0.0, "0")),
+        ("0.00", p(1e-7, "0.00")),
+        ("10.00", p(9.995, "0.00")),
+        ("0.5", p(0.5, "#.##")),
+    ];
+    check!(test_cases, &Locale::en);
+
+    let test_cases: &[(&str, Patterned<f32>)] =
+        &[("0.1", p(0.1, "0.0###")), ("33.3%", p(0.333, "#,##0.0%"))];
+    check!(test_cases, &Locale::en);
+
+    let max = format!("179,769,313,486,231,570{}", ",000".repeat(97));
+    let test_cases: &[(&str, Patterned<f64>)] = &[(&max, p(std::f64::MAX, "#,##0.###"))];
+    check!(test_cases, &Locale::en);
+}
+
+#[test]
+fn test_pattern_custom_format() {
+    // The pattern is written with the symbols of the format
+    let test_cases: &[(&str, Patterned<f64>)] = &[
+        ("1.234,50", p(1234.5, "#,##0.00")),
+        ("-1.234,50", p(-1234.5, "#,##0.00")),
+    ];
+    check!(test_cases, &Locale::de);
+
+    let format = CustomFormat::builder()
+        .minus_sign("−")
+        .separator("\u{202f}")
+        .build()
+        .unwrap();
+    let value = FractionGrouped::new(p(-1234.5678, "#,##0.0000"), 3, "\u{202f}").unwrap();
+    let test_cases: &[(&str, FractionGrouped<Patterned<f64>>)] =
+        &[("−1\u{202f}234.567\u{202f}8", value)];
+    check!(test_cases, &format);
+
+    // The pattern takes the place of the format's grouping
+    let test_cases: &[(&str, Patterned<u32>)] = &[("1,23,45", p(12345, "#,#0"))];
+    check!(test_cases, &Locale::en_IN);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_pattern_decimal() {
+    let test_cases: &[(&str, &str, &str)] = &[
+        ("(1,234,567.13)", "-1234567.125001", "#,##0.00;(#,##0.00)"),
+        ("1,234,567.12", "1234567.125", "#,##0.00"),
+        ("99.5%", "0.995", "0.0%"),
+        (
+            "1,000,000,000,000,000,000,000,000,000,000,000,000,000,000",
+            "999999999999999999999999999999999999999999.5",
+            "#,##0",
+        ),
+    ];
+    for (expected, input, pattern) in test_cases {
+        let decimal: Decimal = input.parse().unwrap();
+        let decimal = p(decimal, pattern);
+        assert_eq!(*expected, decimal.to_formatted_string(&Locale::en));
+
+        let mut s = String::new();
+        s.write_formatted(&decimal, &Locale::en).unwrap();
+        assert_eq!(*expected, s);
+    }
+}
+
+#[test]
+fn test_pattern_locale() {
+    assert_eq!("#,##0.###", Locale::en.decimal_pattern());
+    assert_eq!("#,##,##0.###", Locale::en_IN.decimal_pattern());
+
+    let test_cases: &[(&str, Patterned<f64>)] =
+        &[("12,34,567.5", p(1234567.5, Locale::en_IN.decimal_pattern()))];
+    check!(test_cases, &Locale::en_IN);
+}
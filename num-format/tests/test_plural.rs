@@ -0,0 +1,169 @@
+#[cfg(feature = "std")]
+use num_format::Decimal;
+use num_format::PluralCategory::*;
+use num_format::{ErrorKind, Locale, PluralCategory, PluralOperands};
+
+fn parse(s: &str) -> PluralOperands {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_plural_category_integer() {
+    let test_cases: &[(PluralCategory, i64, Locale)] = &[
+        (Other, 0, Locale::en),
+        (One, 1, Locale::en),
+        (One, -1, Locale::en),
+        (Other, 2, Locale::en),
+        (One, 0, Locale::fr),
+        (One, 1, Locale::fr),
+        (Other, 2, Locale::fr),
+        (Many, 1_000_000, Locale::fr),
+        (One, 1, Locale::pl),
+        (Few, 2, Locale::pl),
+        (Many, 5, Locale::pl),
+        (Many, 12, Locale::pl),
+        (Few, 22, Locale::pl),
+        (Many, 112, Locale::pl),
+        (One, 21, Locale::ru),
+        (Few, 3, Locale::ru),
+        (Many, 11, Locale::ru),
+        (Zero, 0, Locale::ar),
+        (One, 1, Locale::ar),
+        (Two, 2, Locale::ar),
+        (Few, 3, Locale::ar),
+        (Many, 11, Locale::ar),
+        (Other, 100, Locale::ar),
+        (Other, 102, Locale::ar),
+        (Zero, 0, Locale::cy),
+        (Two, 2, Locale::cy),
+        (Few, 3, Locale::cy),
+        (Other, 4, Locale::cy),
+        (Many, 6, Locale::cy),
+        (Zero, 10, Locale::lv),
+        (One, 21, Locale::lv),
+        (Other, 2, Locale::lv),
+        (Other, 1, Locale::ja),
+    ];
+    for (expected, n, locale) in test_cases {
+        assert_eq!(*expected, locale.plural_category(*n), "{} {:?}", n, locale);
+        assert_eq!(*expected, locale.plural_category(parse(&n.to_string())));
+    }
+
+    // Only the last 18 digits matter to modulus rules
+    assert_eq!(Many, Locale::pl.plural_category(std::u128::MAX));
+    assert_eq!(
+        Few,
+        Locale::pl.plural_category(100_000_000_000_000_000_022u128)
+    );
+    assert_eq!(Few, Locale::pl.plural_category(std::i8::MIN + 6));
+    assert_eq!(
+        Other,
+        Locale::en.plural_category(1_000_000_000_000_000_001u64)
+    );
+}
+
+#[test]
+fn test_plural_category_fraction() {
+    let test_cases: &[(PluralCategory, &str, Locale)] = &[
+        (One, "1", Locale::en),
+        (Other, "1.0", Locale::en),
+        (Other, "1.00", Locale::en),
+        (Other, "0.5", Locale::en),
+        (One, "1.5", Locale::fr),
+        (Other, "2.5", Locale::fr),
+        (Other, "1000000.0", Locale::fr),
+        (Other, "1.5", Locale::pl),
+        (Other, "1.5", Locale::ru),
+        (One, "0.1", Locale::lv),
+        (One, "0.01", Locale::lv),
+        (Zero, "0.11", Locale::lv),
+        (One, "1.0", Locale::ar),
+        (One, "+1", Locale::en),
+        (Other, ".5", Locale::en),
+        (One, "1.", Locale::en),
+    ];
+    for (expected, s, locale) in test_cases {
+        assert_eq!(
+            *expected,
+            locale.plural_category(parse(s)),
+            "{} {:?}",
+            s,
+            locale
+        );
+        #[cfg(feature = "std")]
+        {
+            let decimal: Decimal = s.parse().unwrap();
+            assert_eq!(*expected, locale.plural_category(&decimal));
+        }
+    }
+}
+
+#[test]
+fn test_plural_category_f64() {
+    let test_cases: &[(PluralCategory, f64, u8, Locale)] = &[
+        (One, 1.0, 0, Locale::en),
+        (Other, 1.0, 1, Locale::en),
+        (One, 0.999, 0, Locale::en),
+        (Other, 0.999, 2, Locale::en),
+        (Other, 1.5, 0, Locale::en),
+        (One, -1.0, 0, Locale::en),
+        (One, 1.5, 1, Locale::fr),
+        (Other, 2.5, 1, Locale::pl),
+        (Few, 2.5, 0, Locale::pl),
+        (Other, std::f64::NAN, 0, Locale::fr),
+        (Other, std::f64::INFINITY, 0, Locale::fr),
+        (Other, std::f64::MAX, 0, Locale::en),
+    ];
+    for (expected, f, precision, locale) in test_cases {
+        let operands = PluralOperands::from_f64(*f, *precision);
+        assert_eq!(
+            *expected,
+            locale.plural_category(operands),
+            "{} {}",
+            f,
+            precision
+        );
+    }
+}
+
+#[test]
+fn test_plural_operands() {
+    let operands = parse("012.3400");
+    assert_eq!(12, operands.integer_digits());
+    assert_eq!(4, operands.fraction_digit_count());
+    assert_eq!(2, operands.significant_fraction_digit_count());
+    assert_eq!(3400, operands.fraction_digits());
+    assert_eq!(34, operands.significant_fraction_digits());
+
+    let operands = parse("0.000000000000000000000001");
+    assert_eq!(24, operands.fraction_digit_count());
+    assert_eq!(24, operands.significant_fraction_digit_count());
+    assert_eq!(1, operands.fraction_digits());
+    assert_eq!(1, operands.significant_fraction_digits());
+
+    let operands = parse("0.1111111111111111111111110");
+    assert_eq!(25, operands.fraction_digit_count());
+    assert_eq!(24, operands.significant_fraction_digit_count());
+    assert_eq!(1_111_111_111_111_111_110, operands.fraction_digits());
+    assert_eq!(
+        1_111_111_111_111_111_111,
+        operands.significant_fraction_digits()
+    );
+
+    let operands = PluralOperands::from_f64(1.5, 2);
+    assert_eq!(parse("1.50"), operands);
+
+    assert_eq!(PluralOperands::from(7u8), parse("7"));
+    assert_eq!("few", Few.as_str());
+}
+
+#[test]
+fn test_plural_operands_errors() {
+    for s in &["", ".", "-", "1.2.3", "abc", "1e5", "1,000", " 1", "--1"] {
+        let error = s.parse::<PluralOperands>().unwrap_err();
+        match error.kind() {
+            ErrorKind::ParseNumber(_) => {}
+            kind => panic!("unexpected error kind {:?} for {:?}", kind, s),
+        }
+    }
+}
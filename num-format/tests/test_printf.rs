@@ -0,0 +1,337 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+mod common;
+
+use num_format::{ErrorKind, Locale, Printf, PrintfConversion, PrintfSpec, ToFormattedString};
+
+fn p<N>(value: N, spec: &str) -> Printf<N> {
+    Printf::new(value, PrintfSpec::new(spec).unwrap())
+}
+
+#[test]
+fn test_printf_parse() {
+    let spec = PrintfSpec::new("%'-+ #012.3f").unwrap();
+    assert!(spec.grouped());
+    assert!(spec.left_justified());
+    assert!(spec.plus_sign());
+    assert!(spec.space_sign());
+    assert!(spec.alternate());
+    assert!(spec.zero_padded());
+    assert_eq!(12, spec.width());
+    assert_eq!(Some(3), spec.precision());
+    assert_eq!(
+        PrintfConversion::Fixed { uppercase: false },
+        spec.conversion()
+    );
+
+    let spec: PrintfSpec = "%llu".parse().unwrap();
+    assert!(!spec.grouped());
+    assert_eq!(0, spec.width());
+    assert_eq!(None, spec.precision());
+    assert_eq!(PrintfConversion::Unsigned, spec.conversion());
+    assert!(spec.conversion().is_integer());
+
+    // A precision of just `.` is zero
+    let spec = PrintfSpec::new("%.G").unwrap();
+    assert_eq!(Some(0), spec.precision());
+    assert_eq!(
+        PrintfConversion::General { uppercase: true },
+        spec.conversion()
+    );
+    assert!(!spec.conversion().is_integer());
+
+    // Specs are displayed with their flags in a fixed order and without length modifiers
+    for (expected, input) in &[
+        ("%d", "%i"),
+        ("%d", "%hhd"),
+        ("%'#08.2e", "%0#'8.2Le"),
+        ("%x", "%zx"),
+        ("%-5X", "%--5X"),
+        ("%.0f", "%.f"),
+    ] {
+        assert_eq!(*expected, PrintfSpec::new(input).unwrap().to_string());
+    }
+}
+
+#[test]
+fn test_printf_errors() {
+    for input in &[
+        "",
+        "%",
+        "d",
+        "%%",
+        "%y",
+        "%5",
+        "%.3",
+        "%lll",
+        "%dd",
+        "%d ",
+        " %d",
+        "%*d",
+        "%.*f",
+        "%hlld",
+        "%99999999999999999999999d",
+    ] {
+        let error = PrintfSpec::new(input).unwrap_err();
+        match error.kind() {
+            ErrorKind::ParsePrintfSpec(_) => (),
+            kind => panic!("unexpected error kind {:?} for {:?}", kind, input),
+        }
+    }
+}
+
+#[test]
+fn test_printf_integers() {
+    // Expected values are those of glibc in the en_US.UTF-8 locale
+    let test_cases: &[(&str, Printf<i64>, Locale)] = &[
+        ("1,234,567", p(1_234_567, "%'lld"), Locale::en),
+        ("-1,234,567", p(-1_234_567, "%'lld"), Locale::en),
+        ("  +1,234,567", p(1_234_567, "%'+12lld"), Locale::en),
+        ("-1,234,567  ", p(-1_234_567, "%'-12lld"), Locale::en),
+        ("-001,234,567", p(-1_234_567, "%'012lld"), Locale::en),
+        ("1,234,567", p(1_234_567, "%'.9lld"), Locale::en),
+        (" 42", p(42, "% d"), Locale::en),
+        ("", p(0, "%.0d"), Locale::en),
+        ("     ", p(0, "%5.0d"), Locale::en),
+        ("1234567", p(1_234_567, "%'d"), Locale::en_US_POSIX),
+        ("-1.234.567", p(-1_234_567, "%'d"), Locale::de),
+        ("1,23,45,67,890", p(1_234_567_890, "%'d"), Locale::en_IN),
+        ("     1\u{202f}234", p(1234, "%'12d"), Locale::fr),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Printf<u64>, Locale)] = &[
+        ("18446744073709551615", p(std::u64::MAX, "%llu"), Locale::en),
+        (
+            "18,446,744,073,709,551,615",
+            p(std::u64::MAX, "%'llu"),
+            Locale::en,
+        ),
+        ("7", p(7, "%+llu"), Locale::en),
+        ("ff", p(255, "%llx"), Locale::en),
+        ("0XFF", p(255, "%#llX"), Locale::en),
+        ("0x000000ff", p(255, "%#010llx"), Locale::en),
+        ("0", p(0, "%#llx"), Locale::en),
+        ("12,345,678", p(0x1234_5678, "%'llx"), Locale::en),
+        ("000a", p(10, "%.4llx"), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    // Unsigned conversions of negative numbers use the two's complement of their own type
+    let test_cases: &[(&str, Printf<i16>, Locale)] = &[
+        ("0x0000ffff", p(-1, "%#010x"), Locale::en),
+        ("65535", p(-1, "%u"), Locale::en),
+        ("-1", p(-1, "%d"), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Printf<i128>, Locale)] = &[
+        (
+            "-170141183460469231731687303715884105728",
+            p(std::i128::MIN, "%d"),
+            Locale::en,
+        ),
+        (
+            "80000000000000000000000000000000",
+            p(std::i128::MIN, "%x"),
+            Locale::en,
+        ),
+    ];
+    check_string!(test_cases);
+
+    // Integers written with a float conversion are converted into floats
+    let test_cases: &[(&str, Printf<i32>, Locale)] = &[
+        ("-1,234,567.00", p(-1_234_567, "%'.2f"), Locale::en),
+        ("1.23457e+06", p(1_234_567, "%g"), Locale::en),
+    ];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_printf_floats() {
+    // Expected values are those of glibc in the corresponding locales
+    let test_cases: &[(&str, Printf<f64>, Locale)] = &[
+        ("1,234,567.89", p(1_234_567.891, "%'.2f"), Locale::en),
+        ("-0.500000", p(-0.5, "%'f"), Locale::en),
+        ("2", p(2.5, "%.0f"), Locale::en),
+        ("4", p(3.5, "%.0f"), Locale::en),
+        ("3.", p(3.0, "%#.0f"), Locale::en),
+        ("-001,234,567.89", p(-1_234_567.891, "%'015.2f"), Locale::en),
+        ("1,234,567.89   ", p(1_234_567.891, "%'-15.2f"), Locale::en),
+        ("+1.235e+06", p(1_234_567.891, "%+.3e"), Locale::en),
+        ("1.230000E-04", p(0.000123, "%E"), Locale::en),
+        ("0.00e+00", p(0.0, "%.2e"), Locale::en),
+        ("1.23457e+06", p(1_234_567.891, "%g"), Locale::en),
+        ("123,457", p(123_456.7, "%'g"), Locale::en),
+        ("0.0001", p(0.0001, "%g"), Locale::en),
+        ("1e-05", p(0.00001, "%g"), Locale::en),
+        ("1.50000", p(1.5, "%#g"), Locale::en),
+        ("1E-10", p(1e-10, "%G"), Locale::en),
+        ("2e+01", p(15.0, "%.0g"), Locale::en),
+        ("     inf", p(std::f64::INFINITY, "%08f"), Locale::en),
+        ("-INF  ", p(std::f64::NEG_INFINITY, "%-6F"), Locale::en),
+        ("+nan", p(std::f64::NAN, "%+f"), Locale::en),
+        (" NAN", p(std::f64::NAN, "% E"), Locale::en),
+        ("1234567.89", p(1_234_567.891, "%'.2f"), Locale::en_US_POSIX),
+        ("123457", p(123_456.7, "%'g"), Locale::en_US_POSIX),
+        ("1.234.567,89", p(1_234_567.891, "%'.2f"), Locale::de),
+        ("     1.234,6", p(1234.56, "%'12.1f"), Locale::de),
+        ("-12,34,567.89", p(-1_234_567.891, "%'.2f"), Locale::en_IN),
+        ("     1\u{202f}234,6", p(1234.56, "%'12.1f"), Locale::fr),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Printf<f32>, Locale)] = &[
+        ("0.100000", p(0.1, "%f"), Locale::en),
+        ("0.100000001490116", p(0.1, "%.15f"), Locale::en),
+    ];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_printf_wide() {
+    let test_cases: &[(String, Printf<i64>, Locale)] = &[
+        (format!("{}42", " ".repeat(298)), p(42, "%300d"), Locale::en),
+        (
+            format!("-{}42", "0".repeat(297)),
+            p(-42, "%0300d"),
+            Locale::en,
+        ),
+        (
+            format!("0x{}ff", "0".repeat(298)),
+            p(255, "%#.300x"),
+            Locale::en,
+        ),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(String, Printf<f64>, Locale)] = &[
+        (
+            format!("0.{}", "0".repeat(300)),
+            p(0.0, "%.300f"),
+            Locale::en,
+        ),
+        (
+            format!("{:<400.300}", 1.5),
+            p(1.5, "%-400.300f"),
+            Locale::en,
+        ),
+    ];
+    check_string!(test_cases);
+
+    // 309 integer digits in 103 groups, a decimal, and 300 fraction digits
+    let s = p(std::f64::MAX, "%'.300f").to_formatted_string(&Locale::en);
+    assert_eq!(309 + 102 + 1 + 300, s.len());
+    assert!(s.starts_with("179,769,313,486,231,570,"));
+}
+
+#[cfg(all(unix, target_env = "gnu"))]
+#[test]
+fn test_printf_glibc() {
+    use std::ffi::CString;
+    use std::os::raw::c_char;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Returns what glibc's `snprintf` writes for `value` with `spec` in the current locale.
+    fn snprintf<T>(spec: &str, value: T) -> String {
+        let spec = CString::new(spec).unwrap();
+        let mut buf = vec![0u8; 4096];
+        let len = unsafe {
+            libc::snprintf(
+                buf.as_mut_ptr() as *mut c_char,
+                buf.len(),
+                spec.as_ptr(),
+                value,
+            )
+        };
+        assert!(0 <= len && (len as usize) < buf.len(), "{}", len);
+        buf.truncate(len as usize);
+        String::from_utf8(buf).unwrap()
+    }
+
+    /// Returns a random spec of flags, width, and precision for the conversion.
+    fn spec(rng: &mut StdRng, length: &str, conversion: char) -> String {
+        let mut spec = String::from("%");
+        for flag in &['\'', '-', '+', ' ', '#', '0'] {
+            // The alternate form of a decimal integer is undefined
+            if rng.gen_bool(0.25) && !(*flag == '#' && "diu".contains(conversion)) {
+                spec.push(*flag);
+            }
+        }
+        // Widths and precisions are mostly small, but sometimes wider than any number
+        let max = if rng.gen_bool(0.05) { 400 } else { 30 };
+        if rng.gen_bool(0.5) {
+            spec.push_str(&rng.gen_range(0..max).to_string());
+        }
+        if rng.gen_bool(0.5) {
+            spec.push('.');
+            spec.push_str(&rng.gen_range(0..max).to_string());
+        }
+        spec.push_str(length);
+        spec.push(conversion);
+        spec
+    }
+
+    /// Checks that random integers and floats are written just as glibc writes them in the
+    /// current locale, whose symbols are those of `format`.
+    fn compare(rng: &mut StdRng, format: &Locale) {
+        for _ in 0..5_000 {
+            let value: i64 = match rng.gen_range(0..3) {
+                0 => rng.gen_range(-1_000..1_000),
+                1 => rng.gen_range(-10_000_000_000..10_000_000_000),
+                _ => rng.gen(),
+            };
+            let conversion = ['d', 'i', 'u', 'x', 'X'][rng.gen_range(0..5)];
+            let spec = spec(rng, "ll", conversion);
+            assert_eq!(
+                snprintf(&spec, value as libc::c_longlong),
+                p(value, &spec).to_formatted_string(format),
+                "{} {}",
+                spec,
+                value
+            );
+        }
+
+        for _ in 0..5_000 {
+            let value: f64 = match rng.gen_range(0..4) {
+                0 => rng.gen_range(-1_000.0..1_000.0),
+                1 => rng.gen_range(-1e-5..1e-5),
+                2 => f64::from_bits(rng.gen()),
+                _ => [
+                    0.0,
+                    -0.0,
+                    0.5,
+                    2.5,
+                    9.9999,
+                    std::f64::INFINITY,
+                    std::f64::NAN,
+                ][rng.gen_range(0..7)],
+            };
+            let conversion = ['f', 'F', 'e', 'E', 'g', 'G'][rng.gen_range(0..6)];
+            let spec = spec(rng, "", conversion);
+            assert_eq!(
+                snprintf(&spec, value as libc::c_double),
+                p(value, &spec).to_formatted_string(format),
+                "{} {:?}",
+                spec,
+                value
+            );
+        }
+    }
+
+    // The C locale that glibc starts in has the symbols of Locale::en_US_POSIX
+    let mut rng = StdRng::seed_from_u64(0x7072_696e_7466);
+    compare(&mut rng, &Locale::en_US_POSIX);
+
+    // en_US.UTF-8 has those of Locale::en, but it isn't installed everywhere
+    let locale = CString::new("en_US.UTF-8").unwrap();
+    if unsafe { libc::setlocale(libc::LC_NUMERIC, locale.as_ptr()) }.is_null() {
+        return;
+    }
+    compare(&mut rng, &Locale::en);
+    let locale = CString::new("C").unwrap();
+    unsafe { libc::setlocale(libc::LC_NUMERIC, locale.as_ptr()) };
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+mod common;
+
+use core::num::{NonZeroU128, NonZeroU8};
+
+use num_format::{Locale, Radix, RadixFormat, Radixed, ToFormattedString};
+
+fn r<N>(value: N, format: RadixFormat) -> Radixed<N> {
+    Radixed::new(value, format)
+}
+
+fn hex() -> RadixFormat {
+    RadixFormat::builder()
+        .radix(Radix::Hexadecimal)
+        .group_size(4)
+        .separator("_")
+        .prefix("0x")
+        .uppercase(true)
+        .build()
+        .unwrap()
+}
+
+fn octal() -> RadixFormat {
+    RadixFormat::builder()
+        .radix(Radix::Octal)
+        .group_size(3)
+        .separator(" ")
+        .prefix("0o")
+        .build()
+        .unwrap()
+}
+
+fn binary() -> RadixFormat {
+    RadixFormat::builder()
+        .radix(Radix::Binary)
+        .group_size(4)
+        .separator("_")
+        .prefix("0b")
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_radix_unsigned() {
+    let test_cases: &[(&str, Radixed<u32>, Locale)] = &[
+        ("0x0", r(0, hex()), Locale::en),
+        ("0xDEAD_BEEF", r(0xdead_beef, hex()), Locale::en),
+        (
+            "0xdeadbeef",
+            r(0xdead_beef, RadixFormat::default()),
+            Locale::en,
+        ),
+        ("0o0", r(0, octal()), Locale::en),
+        ("0o755", r(0o755, octal()), Locale::en),
+        ("0o1 000", r(0o1000, octal()), Locale::en),
+        ("0b0", r(0, binary()), Locale::en),
+        (
+            "0b1010_0000_1111_0000",
+            r(0b1010_0000_1111_0000, binary()),
+            Locale::en,
+        ),
+        ("0b1_0000", r(0b1_0000, binary()), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Radixed<u8>, Locale)] = &[
+        ("0xFF", r(std::u8::MAX, hex()), Locale::en),
+        ("0o377", r(std::u8::MAX, octal()), Locale::en),
+        ("0b1111_1111", r(std::u8::MAX, binary()), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Radixed<u128>, Locale)] = &[
+        (
+            "0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF",
+            r(std::u128::MAX, hex()),
+            Locale::en,
+        ),
+        (
+            "0o3 777 777 777 777 777 777 777 777 777 777 777 777 777 777",
+            r(std::u128::MAX, octal()),
+            Locale::en,
+        ),
+    ];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_radix_signed() {
+    let test_cases: &[(&str, Radixed<i8>, Locale)] = &[
+        ("0x7F", r(std::i8::MAX, hex()), Locale::en),
+        ("0x80", r(std::i8::MIN, hex()), Locale::en),
+        ("0b1111_1111", r(-1, binary()), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Radixed<i32>, Locale)] = &[
+        ("0xFFFF_FFFF", r(-1, hex()), Locale::en),
+        ("0o37 777 777 777", r(-1, octal()), Locale::en),
+    ];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Radixed<i128>, Locale)] = &[(
+        "0x8000_0000_0000_0000_0000_0000_0000_0000",
+        r(std::i128::MIN, hex()),
+        Locale::en,
+    )];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_radix_non_zero() {
+    let test_cases: &[(&str, Radixed<NonZeroU8>, Locale)] = &[(
+        "0b1000_0000",
+        r(NonZeroU8::new(0x80).unwrap(), binary()),
+        Locale::en,
+    )];
+    check_string!(test_cases);
+
+    let test_cases: &[(&str, Radixed<NonZeroU128>, Locale)] = &[(
+        "0x1_0000",
+        r(NonZeroU128::new(0x1_0000).unwrap(), hex()),
+        Locale::en,
+    )];
+    check_string!(test_cases);
+}
+
+#[test]
+fn test_radix_longest() {
+    let format = RadixFormat::builder()
+        .radix(Radix::Binary)
+        .group_size(4)
+        .separator("𠜱𠜱")
+        .prefix("𠜱𠜱")
+        .build()
+        .unwrap();
+    let s = Radixed::new(std::u128::MAX, format).to_formatted_string(&Locale::en);
+    assert_eq!(s.len(), 128 + 31 * 8 + 8);
+
+    let format = RadixFormat::builder()
+        .radix(Radix::Binary)
+        .group_size(1)
+        .separator("𠜱𠜱")
+        .prefix("𠜱𠜱")
+        .build()
+        .unwrap();
+    let s = Radixed::new(std::u128::MAX, format).to_formatted_string(&Locale::en);
+    assert_eq!(s.len(), 128 + 127 * 8 + 8);
+}
@@ -0,0 +1,326 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+mod common;
+
+use num_format::{
+    Approximately, CustomFormat, FractionGrouped, Grouping, Locale, Measure, MeasureUnit, Range,
+    ToFormattedString, UnitWidth,
+};
+
+/// A test case: the expected string, and the value and the format to write it with.
+type Case<'a, T, F = Locale> = (&'a str, (T, F));
+
+fn r<N>(start: N, end: N, locale: Locale) -> (Range<N>, Locale) {
+    (Range::new(start, end, locale), locale)
+}
+
+fn a<N>(value: N, locale: Locale) -> (Approximately<N>, Locale) {
+    (Approximately::new(value, locale), locale)
+}
+
+fn m<N>(value: N, unit: MeasureUnit, width: UnitWidth, locale: Locale) -> Measure<N> {
+    Measure::new(value, unit, width, locale)
+}
+
+#[test]
+fn test_range() {
+    let test_cases: &[Case<Range<i32>>] = &[
+        ("1,000–5,000", r(1000, 5000, Locale::en)),
+        ("-5 – -3", r(-5, -3, Locale::en)),
+        ("-3 – 5", r(-3, 5, Locale::en)),
+        ("3–-5", r(3, -5, Locale::en)),
+        ("5–3", r(5, 3, Locale::en)),
+        ("~5", r(5, 5, Locale::en)),
+        ("~0", r(0, 0, Locale::en)),
+        ("~-1", r(-1, -1, Locale::en)),
+        ("1.000–5.000", r(1000, 5000, Locale::de)),
+        ("≈5", r(5, 5, Locale::de)),
+        ("-5 – -3", r(-5, -3, Locale::de)),
+        ("1\u{202f}000–5\u{202f}000", r(1000, 5000, Locale::fr)),
+        ("≃5", r(5, 5, Locale::fr)),
+        ("1～5", r(1, 5, Locale::ja)),
+        ("約5", r(5, 5, Locale::ja)),
+        ("\u{200e}-5–3", r(-5, -3, Locale::he)),
+        ("~\u{200e}-5", r(-5, -5, Locale::he)),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[Case<Range<f64>>] = &[
+        ("1.5–2.25", r(1.5, 2.25, Locale::en)),
+        ("-0.5 – 0.5", r(-0.5, 0.5, Locale::en)),
+        ("-0.0 – 0.0", r(-0.0, 0.0, Locale::en)),
+        ("0.0–inf", r(0.0, std::f64::INFINITY, Locale::en)),
+        (
+            "-inf – inf",
+            r(std::f64::NEG_INFINITY, std::f64::INFINITY, Locale::en),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[Case<Range<i128>>] = &[(
+        "-170,141,183,460,469,231,731,687,303,715,884,105,728 – \
+         170,141,183,460,469,231,731,687,303,715,884,105,727",
+        r(std::i128::MIN, std::i128::MAX, Locale::en),
+    )];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[Case<Range<u8>>] = &[("0–255", r(0, 255, Locale::en))];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_range_measure() {
+    let meters = |start, end, locale| {
+        let meter = |n| m(n, MeasureUnit::Meter, UnitWidth::Long, locale);
+        r(meter(start), meter(end), locale)
+    };
+    let celsius = |start, end| {
+        let celsius = |n| m(n, MeasureUnit::Celsius, UnitWidth::Short, Locale::en);
+        r(celsius(start), celsius(end), Locale::en)
+    };
+    let kilometer = |n| m(n, MeasureUnit::Kilometer, UnitWidth::Short, Locale::en);
+    let test_cases: &[Case<Range<Measure<i32>>>] = &[
+        ("0–1 meters", meters(0, 1, Locale::en)),
+        ("1–5 meters", meters(1, 5, Locale::en)),
+        ("-1 – 1 meters", meters(-1, 1, Locale::en)),
+        ("~1 meter", meters(1, 1, Locale::en)),
+        ("0–1\u{a0}mètre", meters(0, 1, Locale::fr)),
+        ("1–5\u{a0}mètres", meters(1, 5, Locale::fr)),
+        ("≃1\u{a0}mètre", meters(1, 1, Locale::fr)),
+        ("3–5°C", celsius(3, 5)),
+        ("-5 – 5°C", celsius(-5, 5)),
+        ("-5 – -3°C", celsius(-5, -3)),
+        ("1–5 km", r(kilometer(1), kilometer(5), Locale::en)),
+        (
+            "1,000–2,000 km",
+            r(kilometer(1000), kilometer(2000), Locale::en),
+        ),
+        (
+            "1 m – 5 km",
+            r(
+                m(1, MeasureUnit::Meter, UnitWidth::Short, Locale::en),
+                kilometer(5),
+                Locale::en,
+            ),
+        ),
+        (
+            "1 meter – 1 m",
+            r(
+                m(1, MeasureUnit::Meter, UnitWidth::Long, Locale::en),
+                m(1, MeasureUnit::Meter, UnitWidth::Short, Locale::en),
+                Locale::en,
+            ),
+        ),
+        (
+            "1–5 Kilometer",
+            r(
+                m(1, MeasureUnit::Kilometer, UnitWidth::Long, Locale::de),
+                m(5, MeasureUnit::Kilometer, UnitWidth::Long, Locale::de),
+                Locale::de,
+            ),
+        ),
+        // Measures keep their own locale, and only share a unit within one locale
+        (
+            "1–5 Kilometer",
+            r(
+                m(1, MeasureUnit::Kilometer, UnitWidth::Long, Locale::de),
+                m(5, MeasureUnit::Kilometer, UnitWidth::Long, Locale::de),
+                Locale::en,
+            ),
+        ),
+        (
+            "1 kilometer – 5 Kilometer",
+            r(
+                m(1, MeasureUnit::Kilometer, UnitWidth::Long, Locale::en),
+                m(5, MeasureUnit::Kilometer, UnitWidth::Long, Locale::de),
+                Locale::en,
+            ),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+
+    let meter = |n| m(n, MeasureUnit::Meter, UnitWidth::Long, Locale::en);
+    let test_cases: &[Case<Range<Measure<f64>>>] = &[
+        ("~1.5 meters", r(meter(1.5), meter(1.5), Locale::en)),
+        ("0.5–1.5 meters", r(meter(0.5), meter(1.5), Locale::en)),
+    ];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_approximately() {
+    let test_cases: &[Case<Approximately<i64>>] = &[
+        ("~5", a(5, Locale::en)),
+        ("~-5", a(-5, Locale::en)),
+        ("~1,000", a(1000, Locale::en)),
+        ("≈5", a(5, Locale::de)),
+        ("≈-5", a(-5, Locale::de)),
+        ("≃5", a(5, Locale::fr)),
+        ("約5", a(5, Locale::ja)),
+        ("~\u{200e}-5", a(-5, Locale::he)),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[Case<Approximately<f32>>] = &[
+        ("~1.5", a(1.5, Locale::en)),
+        ("≈1,5", a(1.5, Locale::de)),
+        ("~NaN", a(std::f32::NAN, Locale::en)),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[Case<Approximately<Measure<u32>>>] = &[
+        (
+            "~1 meter",
+            a(
+                m(1, MeasureUnit::Meter, UnitWidth::Long, Locale::en),
+                Locale::en,
+            ),
+        ),
+        (
+            "~5 meters",
+            a(
+                m(5, MeasureUnit::Meter, UnitWidth::Long, Locale::en),
+                Locale::en,
+            ),
+        ),
+        (
+            "~5 km",
+            a(
+                m(5, MeasureUnit::Kilometer, UnitWidth::Short, Locale::en),
+                Locale::en,
+            ),
+        ),
+        (
+            "≈1 Kilometer",
+            a(
+                m(1, MeasureUnit::Kilometer, UnitWidth::Long, Locale::de),
+                Locale::de,
+            ),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_range_percent() {
+    let percents = |start, end, locale| {
+        let percent = |n| m(n, MeasureUnit::Percent, UnitWidth::Short, locale);
+        r(percent(start), percent(end), locale)
+    };
+    let test_cases: &[Case<Range<Measure<i32>>>] = &[
+        ("3–5%", percents(3, 5, Locale::en)),
+        ("-5 – -3%", percents(-5, -3, Locale::en)),
+        ("~5%", percents(5, 5, Locale::en)),
+        ("3–5 %", percents(3, 5, Locale::de)),
+        ("3–5 %", percents(3, 5, Locale::fr)),
+        ("%3–5", percents(3, 5, Locale::tr)),
+        ("3～5%", percents(3, 5, Locale::ja)),
+    ];
+    check_string!(pairs: test_cases);
+
+    let percents = |start, end, locale| {
+        let percent = |n| m(n, MeasureUnit::Percent, UnitWidth::Long, locale);
+        r(percent(start), percent(end), locale)
+    };
+    let test_cases: &[Case<Range<Measure<f64>>>] = &[
+        ("2.5–7.5 percent", percents(2.5, 7.5, Locale::en)),
+        ("2,5–7,5 Prozent", percents(2.5, 7.5, Locale::de)),
+    ];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_range_custom_format() {
+    let en = |start, end| Range::new(start, end, Locale::en);
+    let test_cases: &[Case<Range<u32>, CustomFormat>] = &[
+        ("1,000–5,000", (en(1000, 5000), CustomFormat::default())),
+        (
+            "1000–5000",
+            (
+                en(1000, 5000),
+                CustomFormat::builder()
+                    .grouping(Grouping::Posix)
+                    .build()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "1_000～5_000",
+            (
+                Range::new(1000, 5000, Locale::ja),
+                CustomFormat::builder().separator("_").build().unwrap(),
+            ),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases: &[Case<Range<i32>, CustomFormat>] = &[(
+        "−−5–3",
+        (
+            Range::new(-5, -3, Locale::en),
+            CustomFormat::builder().minus_sign("−−").build().unwrap(),
+        ),
+    )];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_range_longest() {
+    // Ranges of the longest floats are longer than a Buffer, which only fits one of them
+    fn g<T>(value: T) -> FractionGrouped<T> {
+        FractionGrouped::new(value, 3, "\u{202f}\u{202f}").unwrap()
+    }
+
+    fn en<N>(start: N, end: N) -> FractionGrouped<Range<N>> {
+        g(Range::new(start, end, Locale::en))
+    }
+
+    let format = CustomFormat::builder()
+        .separator("\u{202f}\u{202f}")
+        .build()
+        .unwrap();
+    let smallest = g(5e-324_f64).to_formatted_string(&format);
+    let largest = g(std::f64::MAX).to_formatted_string(&format);
+    let test_cases = &[
+        (
+            format!("{}–{}", smallest, largest),
+            (en(5e-324, std::f64::MAX), format.clone()),
+        ),
+        (
+            format!("-{} – -{}", largest, smallest),
+            (en(-std::f64::MAX, -5e-324), format.clone()),
+        ),
+    ];
+    check_string!(pairs: test_cases);
+
+    let test_cases = &[(
+        format!("{} kilometers – {} miles", largest, largest),
+        (
+            en(
+                m(
+                    std::f64::MAX,
+                    MeasureUnit::Kilometer,
+                    UnitWidth::Long,
+                    Locale::en,
+                ),
+                m(
+                    std::f64::MAX,
+                    MeasureUnit::Mile,
+                    UnitWidth::Long,
+                    Locale::en,
+                ),
+            ),
+            format,
+        ),
+    )];
+    check_string!(pairs: test_cases);
+}
+
+#[test]
+fn test_locale_range_symbols() {
+    assert_eq!("~", Locale::en.approximately_sign());
+    assert_eq!("≈", Locale::de.approximately_sign());
+    assert_eq!("{0}–{1}", Locale::en.range_pattern());
+    assert_eq!("{0}～{1}", Locale::ja.range_pattern());
+}
@@ -0,0 +1,132 @@
+#[macro_use]
+mod common;
+
+use num_format::parsing::ParseFormatted;
+use num_format::{Buffer, CustomFormat, Locale, Roman};
+
+fn r<N>(value: N) -> Roman<N> {
+    Roman::new(value)
+}
+
+#[test]
+fn test_roman() {
+    let test_cases: &[(&str, Roman<i32>, Locale)] = &[
+        ("I", r(1), Locale::en),
+        ("IV", r(4), Locale::en),
+        ("IX", r(9), Locale::en),
+        ("XIV", r(14), Locale::en),
+        ("XL", r(40), Locale::en),
+        ("XC", r(90), Locale::en),
+        ("CD", r(400), Locale::en),
+        ("CM", r(900), Locale::en),
+        ("MCMXCIX", r(1999), Locale::en),
+        ("MMXXIV", r(2024), Locale::en),
+        ("MMMDCCCLXXXVIII", r(3888), Locale::en),
+        ("MMMCMXCIX", r(3999), Locale::en),
+        ("xiv", r(14).lowercase(), Locale::en),
+        ("mmmcmxcix", r(3999).lowercase(), Locale::en),
+        ("MMMCMXCIX", r(3999).vinculum(), Locale::en),
+        ("I\u{305}V\u{305}", r(4000).vinculum(), Locale::en),
+        ("I\u{305}V\u{305}I", r(4001).vinculum(), Locale::en),
+        ("X\u{305}CCXXXIV", r(10_234).vinculum(), Locale::en),
+        ("m\u{305}", r(1_000_000).vinculum().lowercase(), Locale::en),
+        (
+            "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX",
+            r(3_999_999).vinculum(),
+            Locale::en,
+        ),
+    ];
+    check!(test_cases);
+}
+
+#[test]
+fn test_roman_out_of_range() {
+    // Numbers that can't be written as Roman numerals are written in digits
+    let test_cases: &[(&str, Roman<i64>, Locale)] = &[
+        ("0", r(0), Locale::en),
+        ("-5", r(-5), Locale::en),
+        ("4,000", r(4000), Locale::en),
+        ("4.000", r(4000), Locale::de),
+        ("4,000,000", r(4_000_000).vinculum(), Locale::en),
+    ];
+    check!(test_cases);
+
+    let format = CustomFormat::builder()
+        .minus_sign("−")
+        .separator("_")
+        .build()
+        .unwrap();
+    let test_cases: &[(&str, Roman<i64>, CustomFormat)] = &[
+        ("−1", r(-1), format.clone()),
+        ("12_345", r(12_345), format.clone()),
+    ];
+    check!(test_cases);
+
+    let mut buf = Buffer::new();
+    buf.write_formatted(&r(std::u128::MAX), &Locale::en);
+    assert_eq!(
+        "340,282,366,920,938,463,463,374,607,431,768,211,455",
+        buf.as_str()
+    );
+}
+
+#[test]
+fn test_roman_parse() {
+    // Every numeral parses back into its value
+    let mut buf = Buffer::new();
+    for n in 1..=3999u32 {
+        for roman in [r(n), r(n).lowercase()] {
+            buf.write_formatted(&roman, &Locale::en);
+            let parsed = buf.as_str().parse_formatted::<_, Roman<u32>>(&Locale::en);
+            assert_eq!(roman, parsed.unwrap());
+        }
+    }
+    for n in (4000..=3_999_999u32).step_by(997) {
+        let roman = r(n).vinculum();
+        buf.write_formatted(&roman, &Locale::en);
+        let parsed = buf.as_str().parse_formatted::<_, Roman<u32>>(&Locale::en);
+        assert_eq!(roman, parsed.unwrap());
+    }
+
+    let roman: Roman<u16> = "mcmxcix".parse_formatted(&Locale::en).unwrap();
+    assert_eq!(1999, *roman.value());
+    assert!(roman.is_lowercase());
+    assert!(!roman.is_vinculum());
+
+    let roman: Roman<i64> = "V\u{305}I".parse_formatted(&Locale::en).unwrap();
+    assert_eq!(5001, *roman.value());
+    assert!(roman.is_vinculum());
+}
+
+#[test]
+fn test_roman_parse_errors() {
+    let inputs = [
+        "",
+        " XIV",
+        "XIV ",
+        "IIII",
+        "IC",
+        "VX",
+        "XIIX",
+        "MMMM",
+        "Xiv",
+        "XIVa",
+        "14",
+        "I\u{305}",
+        "\u{305}I",
+        "I\u{305}I\u{305}I\u{305}",
+    ];
+    for input in inputs.iter() {
+        let result = input.parse_formatted::<_, Roman<u32>>(&Locale::en);
+        assert!(result.is_err(), "{:?} parsed as {:?}", input, result);
+    }
+
+    // The value must fit the type
+    assert!("CCLV".parse_formatted::<_, Roman<u8>>(&Locale::en).is_ok());
+    assert!("CCLVI"
+        .parse_formatted::<_, Roman<u8>>(&Locale::en)
+        .is_err());
+    assert!("CXXVIII"
+        .parse_formatted::<_, Roman<i8>>(&Locale::en)
+        .is_err());
+}
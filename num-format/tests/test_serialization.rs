@@ -2,30 +2,116 @@
 
 #[test]
 fn test_serialization() {
+    use num_format::parsing::{Notated, Notation, ParseExpected, ParseReason};
     use num_format::{
-        Buffer, CustomFormat, CustomFormatBuilder, Error, ErrorKind, Grouping, Locale,
+        Approximately, Buffer, ByteSize, ByteUnits, Coded, CustomFormat, CustomFormatBuilder,
+        DurationUnit, Elapsed, Error, ErrorKind, FormatCode, FormatSpec, Formatted, Grouping,
+        Locale, Measure, MeasureUnit, Metric, NumberPattern, Ordinal, PluralCategory,
+        PluralOperands, Printf, PrintfConversion, PrintfSpec, Radix, RadixFormat,
+        RadixFormatBuilder, Radixed, Range, Roman, SpecAlign, SpecSign, SpecType, Specified,
+        SpellOut, StaticFormat, UnitWidth,
     };
     use serde::{Deserialize, Serialize};
 
+    struct English;
+
+    impl StaticFormat for English {
+        type Format = Locale;
+
+        fn format() -> Locale {
+            Locale::en
+        }
+    }
+
     fn serializable<T: Serialize>() {}
 
+    serializable::<Approximately<f64>>();
     serializable::<Buffer>();
+    serializable::<ByteSize>();
+    serializable::<ByteUnits>();
+    serializable::<Coded<f64>>();
     serializable::<CustomFormat>();
     serializable::<CustomFormatBuilder>();
+    serializable::<DurationUnit>();
+    serializable::<Elapsed>();
     serializable::<Error>();
     serializable::<ErrorKind>();
+    serializable::<FormatCode>();
+    serializable::<FormatSpec>();
+    serializable::<Formatted<u32, English>>();
     serializable::<Grouping>();
     serializable::<Locale>();
+    serializable::<Measure<f64>>();
+    serializable::<MeasureUnit>();
+    serializable::<Metric<f64>>();
+    serializable::<Notated<u32>>();
+    serializable::<Notation>();
+    serializable::<NumberPattern>();
+    serializable::<ParseExpected>();
+    serializable::<ParseReason>();
+    serializable::<Ordinal<u64>>();
+    serializable::<PluralCategory>();
+    serializable::<PluralOperands>();
+    serializable::<Printf<f64>>();
+    serializable::<PrintfConversion>();
+    serializable::<PrintfSpec>();
+    serializable::<Radix>();
+    serializable::<RadixFormat>();
+    serializable::<RadixFormatBuilder>();
+    serializable::<Radixed<u32>>();
+    serializable::<Range<Measure<i32>>>();
+    serializable::<Roman<u32>>();
+    serializable::<SpecAlign>();
+    serializable::<SpecSign>();
+    serializable::<SpecType>();
+    serializable::<Specified<f64>>();
+    serializable::<SpellOut<u64>>();
+    serializable::<UnitWidth>();
 
     fn deserializable<'de, T: Deserialize<'de>>() {}
 
+    deserializable::<Approximately<f64>>();
     deserializable::<Buffer>();
+    deserializable::<ByteSize>();
+    deserializable::<ByteUnits>();
+    deserializable::<Coded<f64>>();
     deserializable::<CustomFormat>();
     deserializable::<CustomFormatBuilder>();
+    deserializable::<DurationUnit>();
+    deserializable::<Elapsed>();
     deserializable::<Error>();
     deserializable::<ErrorKind>();
+    deserializable::<FormatCode>();
+    deserializable::<FormatSpec>();
+    deserializable::<Formatted<u32, English>>();
     deserializable::<Grouping>();
     deserializable::<Locale>();
+    deserializable::<Measure<f64>>();
+    deserializable::<MeasureUnit>();
+    deserializable::<Metric<f64>>();
+    deserializable::<Notated<u32>>();
+    deserializable::<Notation>();
+    deserializable::<NumberPattern>();
+    deserializable::<ParseExpected>();
+    deserializable::<ParseReason>();
+    deserializable::<Ordinal<u64>>();
+    deserializable::<PluralCategory>();
+    deserializable::<PluralOperands>();
+    deserializable::<Printf<f64>>();
+    deserializable::<PrintfConversion>();
+    deserializable::<PrintfSpec>();
+    deserializable::<Radix>();
+    deserializable::<RadixFormat>();
+    deserializable::<RadixFormatBuilder>();
+    deserializable::<Radixed<u32>>();
+    deserializable::<Range<Measure<i32>>>();
+    deserializable::<Roman<u32>>();
+    deserializable::<SpecAlign>();
+    deserializable::<SpecSign>();
+    deserializable::<SpecType>();
+    deserializable::<Specified<f64>>();
+    deserializable::<SpellOut<u64>>();
+    deserializable::<UnitWidth>();
 }
 
 #[cfg(feature = "with-system-locale")]
@@ -40,3 +126,207 @@ fn test_serialization_system() {
     fn deserializable<'de, T: Deserialize<'de>>() {}
     deserializable::<SystemLocale>();
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialization_formatted() {
+    use num_format::serde_formatted::{self, AsFormatted, FormattedSeed};
+    use num_format::{CustomFormat, Formatted, Grouping, Locale, StaticFormat};
+    use serde::de::DeserializeSeed;
+    use serde::{Deserialize, Serialize};
+
+    struct Indian;
+
+    impl StaticFormat for Indian {
+        type Format = Locale;
+
+        fn format() -> Locale {
+            Locale::en_IN
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Export {
+        #[serde(
+            serialize_with = "serde_formatted::serialize::<Indian, _, _>",
+            deserialize_with = "serde_formatted::deserialize::<Indian, _, _>"
+        )]
+        total: u64,
+        #[serde(
+            serialize_with = "serde_formatted::option::serialize::<Indian, _, _>",
+            deserialize_with = "serde_formatted::option::deserialize::<Indian, _, _>"
+        )]
+        change: Option<i64>,
+        count: Formatted<i128, Indian>,
+    }
+
+    let export = Export {
+        total: 1_234_567,
+        change: Some(-1000),
+        count: Formatted::new(-123_456_789_012),
+    };
+    let json = serde_json::to_string(&export).unwrap();
+    assert_eq!(
+        r#"{"total":"12,34,567","change":"-1,000","count":"-1,23,45,67,89,012"}"#,
+        json
+    );
+    assert_eq!(export, serde_json::from_str(&json).unwrap());
+
+    let export = Export {
+        change: None,
+        ..export
+    };
+    let json = serde_json::to_string(&export).unwrap();
+    assert!(json.contains(r#""change":null"#));
+    assert_eq!(export, serde_json::from_str(&json).unwrap());
+
+    // Numbers must be formatted strings under the format
+    for json in &[
+        r#"{"total":1234567,"change":null,"count":"0"}"#,
+        r#"{"total":"1,234,567","change":null,"count":"0"}"#,
+        r#"{"total":"-12,34,567","change":null,"count":"0"}"#,
+        r#"{"total":"12,34,567","change":"x","count":"0"}"#,
+    ] {
+        assert!(serde_json::from_str::<Export>(json).is_err(), "{}", json);
+    }
+    let error =
+        serde_json::from_str::<Export>(r#"{"total":"1,234,567","change":null,"count":"0"}"#)
+            .unwrap_err()
+            .to_string();
+    assert!(error.contains("1,234,567"), "{}", error);
+
+    // Formats chosen at run time
+    let format = CustomFormat::builder()
+        .grouping(Grouping::Standard)
+        .minus_sign("\u{2212}")
+        .separator("\u{a0}")
+        .build()
+        .unwrap();
+    let values = [-1234, 0, 1_000_000];
+    let formatted: Vec<_> = values
+        .iter()
+        .map(|n| AsFormatted::new(n, &format))
+        .collect();
+    let json = serde_json::to_string(&formatted).unwrap();
+    assert_eq!(
+        "[\"\u{2212}1\u{a0}234\",\"0\",\"1\u{a0}000\u{a0}000\"]",
+        json
+    );
+
+    let mut deserializer = serde_json::Deserializer::from_str("\"\u{2212}1\u{a0}234\"");
+    let n: i32 = FormattedSeed::new(&format)
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(-1234, n);
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#""1.234""#);
+    let n: u16 = FormattedSeed::new(&Locale::de)
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(1234, n);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialization_custom_format() {
+    use num_format::CustomFormat;
+
+    let format = CustomFormat::builder()
+        .separator("\u{202f}")
+        .build()
+        .unwrap();
+    let json = serde_json::to_string(&format).unwrap();
+    assert_eq!(format, serde_json::from_str(&json).unwrap());
+
+    // Deserialized formats are checked like built ones
+    let json = json.replace("\u{202f}", "𠜱𠜱𠜱");
+    assert!(
+        serde_json::from_str::<CustomFormat>(&json).is_err(),
+        "{}",
+        json
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialization_format_code() {
+    use num_format::{Coded, FormatCode};
+
+    let code = FormatCode::new("#,##0.00;[Red]-#,##0.00").unwrap();
+    let json = serde_json::to_string(&code).unwrap();
+    assert_eq!(r##""#,##0.00;[Red]-#,##0.00""##, json);
+    assert_eq!(code, serde_json::from_str(&json).unwrap());
+
+    let coded = Coded::new(-1234.5, code);
+    let json = serde_json::to_string(&coded).unwrap();
+    assert_eq!(coded, serde_json::from_str(&json).unwrap());
+
+    // Deserialized codes are checked like parsed ones
+    for json in &[
+        r#""0.0.0""#,
+        r#""0;0;0;@;0""#,
+        r#"{"code":"0","sections":[[0,200],[0,0],[0,0],[0,0]],"len":1}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<FormatCode>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialization_format_spec() {
+    use num_format::{FormatSpec, Specified};
+
+    let spec = FormatSpec::new("*^+#12,.3f").unwrap();
+    let json = serde_json::to_string(&spec).unwrap();
+    assert_eq!(r#""*^+#12,.3f""#, json);
+    assert_eq!(spec, serde_json::from_str(&json).unwrap());
+
+    let specified = Specified::new(1234.5, spec);
+    let json = serde_json::to_string(&specified).unwrap();
+    assert_eq!(specified, serde_json::from_str(&json).unwrap());
+
+    // Deserialized specs are checked like parsed ones
+    for json in &[
+        r#""z""#,
+        r#"".2d""#,
+        r#"{"align":null,"alt":false,"fill":null,"grp":"x","prec":null,"sign":"Negative","ty":null,"width":0,"zero":false}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<FormatSpec>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_serialization_printf_spec() {
+    use num_format::{Printf, PrintfSpec};
+
+    let spec = PrintfSpec::new("%'-+12.3Lf").unwrap();
+    let json = serde_json::to_string(&spec).unwrap();
+    assert_eq!(r#""%'-+12.3f""#, json);
+    assert_eq!(spec, serde_json::from_str(&json).unwrap());
+
+    let printf = Printf::new(1234.5, spec);
+    let json = serde_json::to_string(&printf).unwrap();
+    assert_eq!(printf, serde_json::from_str(&json).unwrap());
+
+    // Deserialized specs are checked like parsed ones
+    for json in &[
+        r#""%y""#,
+        r#""%*d""#,
+        r#"{"alt":false,"conversion":"Signed","grp":false,"left":false,"plus":false,"prec":null,"space":false,"width":60000,"zero":false}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<PrintfSpec>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
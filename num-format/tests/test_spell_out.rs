@@ -0,0 +1,172 @@
+#[macro_use]
+mod common;
+
+use num_format::{Buffer, CustomFormat, Locale, SpellOut};
+
+fn s<N>(value: N, locale: Locale) -> (SpellOut<N>, Locale) {
+    (SpellOut::new(value, locale), locale)
+}
+
+#[test]
+fn test_spell_out_en() {
+    let test_cases: &[(&str, (SpellOut<i64>, Locale))] = &[
+        ("zero", s(0, Locale::en)),
+        ("seven", s(7, Locale::en)),
+        ("twenty-one", s(21, Locale::en)),
+        ("ninety-nine", s(99, Locale::en)),
+        ("one hundred", s(100, Locale::en)),
+        ("one hundred one", s(101, Locale::en)),
+        ("one thousand two hundred thirty-four", s(1234, Locale::en)),
+        ("one million", s(1_000_000, Locale::en)),
+        ("two million", s(2_000_000, Locale::en)),
+        ("one trillion", s(1_000_000_000_000, Locale::en)),
+        ("minus forty-five", s(-45, Locale::en)),
+        ("nine hundred ninety-nine quadrillion nine hundred ninety-nine trillion nine hundred ninety-nine billion nine hundred ninety-nine million nine hundred ninety-nine thousand nine hundred ninety-nine", s(999_999_999_999_999_999, Locale::en)),
+        ("1,000,000,000,000,000,000", s(1_000_000_000_000_000_000, Locale::en)),
+        ("minus 9,223,372,036,854,775,807", s(-9_223_372_036_854_775_807, Locale::en)),
+        ("twelve lakh thirty-four thousand five hundred sixty-seven", s(1_234_567, Locale::en_IN)),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_spell_out_locales() {
+    let test_cases: &[(&str, (SpellOut<i64>, Locale))] = &[
+        ("null", s(0, Locale::de)),
+        ("ein\u{ad}und\u{ad}zwanzig", s(21, Locale::de)),
+        (
+            "ein\u{ad}tausend\u{ad}zwei\u{ad}hundert\u{ad}vier\u{ad}und\u{ad}dreißig",
+            s(1234, Locale::de),
+        ),
+        (
+            "ein\u{ad}tausend\u{ad}zwei\u{ad}hundert\u{ad}vier\u{ad}und\u{ad}dreissig",
+            s(1234, Locale::de_CH),
+        ),
+        ("eine Million eins", s(1_000_001, Locale::de)),
+        ("zwei Millionen", s(2_000_000, Locale::de)),
+        ("minus fünf\u{ad}und\u{ad}vierzig", s(-45, Locale::de)),
+        ("vingt-et-un", s(21, Locale::fr)),
+        ("soixante-et-onze", s(71, Locale::fr)),
+        ("quatre-vingts", s(80, Locale::fr)),
+        ("quatre-vingt-un", s(81, Locale::fr)),
+        ("quatre-vingt-dix-neuf", s(99, Locale::fr)),
+        ("nonante-neuf", s(99, Locale::fr_BE)),
+        ("deux cents", s(200, Locale::fr)),
+        ("deux cent un", s(201, Locale::fr)),
+        ("mille deux cent trente-quatre", s(1234, Locale::fr)),
+        ("deux millions", s(2_000_000, Locale::fr)),
+        ("moins quarante-cinq", s(-45, Locale::fr)),
+        ("veintiuno", s(21, Locale::es)),
+        ("cien", s(100, Locale::es)),
+        ("ciento uno", s(101, Locale::es)),
+        ("quinientos", s(500, Locale::es)),
+        ("veintiún mil", s(21_000, Locale::es)),
+        ("mil millones", s(1_000_000_000, Locale::es)),
+        ("vent\u{ad}un\u{ad}mila", s(21_000, Locale::it)),
+        (
+            "mille\u{ad}due\u{ad}cento\u{ad}trenta\u{ad}quattro",
+            s(1234, Locale::it),
+        ),
+        ("honderdeen", s(101, Locale::nl)),
+        ("een miljoen een", s(1_000_001, Locale::nl)),
+        ("mil duzentos e trinta e quatro", s(1234, Locale::pt)),
+        ("um bilhão", s(1_000_000_000, Locale::pt)),
+        ("dois trilhões", s(2_000_000_000_000, Locale::pt)),
+        (
+            "1\u{a0}000\u{a0}000\u{a0}000\u{a0}000\u{a0}000\u{a0}000",
+            s(1_000_000_000_000_000_000, Locale::pt_PT),
+        ),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_spell_out_integer_types() {
+    let mut buf = Buffer::new();
+    buf.write_formatted(&SpellOut::new(255u8, Locale::en), &Locale::en);
+    assert_eq!("two hundred fifty-five", buf.as_str());
+    buf.write_formatted(&SpellOut::new(-128i8, Locale::en), &Locale::en);
+    assert_eq!("minus one hundred twenty-eight", buf.as_str());
+    buf.write_formatted(&SpellOut::new(std::u128::MAX, Locale::en), &Locale::en);
+    assert_eq!(
+        "340,282,366,920,938,463,463,374,607,431,768,211,455",
+        buf.as_str()
+    );
+    buf.write_formatted(&SpellOut::new(std::i128::MIN, Locale::en), &Locale::en);
+    assert_eq!(
+        "minus 170,141,183,460,469,231,731,687,303,715,884,105,728",
+        buf.as_str()
+    );
+}
+
+#[test]
+fn test_spell_out_unsupported() {
+    assert!(Locale::en.supports_spell_out());
+    assert!(Locale::pt_PT.supports_spell_out());
+    assert!(!Locale::ja.supports_spell_out());
+
+    // Locales without spell-out rules write the digits
+    let test_cases: &[(&str, (SpellOut<i32>, Locale))] = &[
+        ("1,234", s(1234, Locale::ja)),
+        ("-1,234", s(-1234, Locale::ja)),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[test]
+fn test_spell_out_custom_format() {
+    let format = CustomFormat::builder()
+        .minus_sign("−")
+        .separator("_")
+        .build()
+        .unwrap();
+    let en = |value| (SpellOut::new(value, Locale::en), format.clone());
+    let test_cases: &[(&str, (SpellOut<i64>, CustomFormat))] = &[
+        ("twenty-one", en(21)),
+        ("minus forty-five", en(-45)),
+        ("1_000_000_000_000_000_000", en(1_000_000_000_000_000_000)),
+        (
+            "minus 1_000_000_000_000_000_000",
+            en(-1_000_000_000_000_000_000),
+        ),
+        (
+            "−12_345",
+            (SpellOut::new(-12_345, Locale::ja), format.clone()),
+        ),
+    ];
+    check!(pairs: test_cases);
+}
+
+#[cfg(feature = "with-num-bigint")]
+#[test]
+fn test_spell_out_num_bigint() {
+    use num_bigint::{BigInt, BigUint};
+    use num_format::ToFormattedString;
+
+    let test_cases: &[(&str, BigInt, Locale)] = &[
+        (
+            "minus one thousand two hundred thirty-four",
+            BigInt::from(-1234),
+            Locale::en,
+        ),
+        ("zwei Millionen", BigInt::from(2_000_000), Locale::de),
+        (
+            "minus 100,000,000,000,000,000,000,000,000,000,000,000,000,000",
+            BigInt::from(10).pow(41) * -1,
+            Locale::en,
+        ),
+        ("-1,234", BigInt::from(-1234), Locale::ja),
+    ];
+    for (expected, input, format) in test_cases {
+        assert_eq!(
+            expected.to_string(),
+            SpellOut::new(input.clone(), *format).to_formatted_string(format)
+        );
+    }
+
+    let value = BigUint::from(21u32);
+    assert_eq!(
+        "vingt-et-un",
+        SpellOut::new(value, Locale::fr).to_formatted_string(&Locale::fr)
+    );
+}